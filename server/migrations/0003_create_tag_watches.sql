@@ -0,0 +1,11 @@
+-- One-shot watches for a specific tag to be published on a repository
+CREATE TABLE IF NOT EXISTS tag_watches (
+    id TEXT PRIMARY KEY NOT NULL,
+    chat_id INTEGER NOT NULL,
+    repository_url TEXT NOT NULL,
+    tag_name TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    UNIQUE (chat_id, repository_url, tag_name)
+);
+
+CREATE INDEX IF NOT EXISTS idx_tag_watches_chat_id ON tag_watches(chat_id);
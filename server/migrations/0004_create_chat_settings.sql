@@ -0,0 +1,6 @@
+-- Per-chat preferences
+CREATE TABLE IF NOT EXISTS chat_settings (
+    chat_id INTEGER PRIMARY KEY NOT NULL,
+    message_format TEXT NOT NULL DEFAULT 'html',
+    updated_at TEXT NOT NULL
+);
@@ -0,0 +1,12 @@
+-- Ordered fallback URLs polled when the primary repository yields no release
+CREATE TABLE IF NOT EXISTS tracked_repository_mirrors (
+    id TEXT PRIMARY KEY NOT NULL,
+    tracked_repository_id TEXT NOT NULL,
+    repository_url TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    created_at TEXT NOT NULL,
+    UNIQUE (tracked_repository_id, repository_url),
+    FOREIGN KEY (tracked_repository_id) REFERENCES tracked_repositories(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_tracked_repository_mirrors_tracked_repository_id ON tracked_repository_mirrors(tracked_repository_id);
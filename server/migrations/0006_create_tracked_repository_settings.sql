@@ -0,0 +1,9 @@
+-- Per-repository notification preferences and the state they need
+CREATE TABLE IF NOT EXISTS tracked_repository_settings (
+    tracked_repository_id TEXT PRIMARY KEY NOT NULL,
+    prerelease_collapse_secs INTEGER,
+    last_prerelease_base TEXT,
+    last_prerelease_notified_at TEXT,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (tracked_repository_id) REFERENCES tracked_repositories(id) ON DELETE CASCADE
+);
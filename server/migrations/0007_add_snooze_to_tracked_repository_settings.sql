@@ -0,0 +1,3 @@
+-- Temporarily silence a repository; the newest release seen meanwhile is kept for a catch-up
+ALTER TABLE tracked_repository_settings ADD COLUMN snoozed_until TEXT;
+ALTER TABLE tracked_repository_settings ADD COLUMN snooze_missed_tag TEXT;
@@ -0,0 +1,3 @@
+-- Release notes: hash of the last seen body and per-chat inclusion mode
+ALTER TABLE tracked_repository_releases ADD COLUMN body_hash TEXT;
+ALTER TABLE chat_settings ADD COLUMN release_notes TEXT NOT NULL DEFAULT 'off';
@@ -0,0 +1,3 @@
+-- Optional text placed around every release notification sent to a chat
+ALTER TABLE chat_settings ADD COLUMN message_prefix TEXT;
+ALTER TABLE chat_settings ADD COLUMN message_suffix TEXT;
@@ -0,0 +1,2 @@
+-- Follow the newest git tag instead of GitHub Releases
+ALTER TABLE tracked_repository_settings ADD COLUMN tags_only INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,12 @@
+-- Delivery receipts of release notifications, keyed by the Telegram message that carried them
+CREATE TABLE IF NOT EXISTS notifications (
+    id TEXT PRIMARY KEY NOT NULL,
+    tracked_repository_id TEXT NOT NULL,
+    chat_id INTEGER NOT NULL,
+    tag_name TEXT NOT NULL,
+    telegram_message_id INTEGER NOT NULL,
+    sent_at TEXT NOT NULL,
+    FOREIGN KEY (tracked_repository_id) REFERENCES tracked_repositories(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_notifications_tracked_repository_id ON notifications(tracked_repository_id);
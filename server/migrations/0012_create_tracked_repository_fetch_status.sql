@@ -0,0 +1,8 @@
+-- Outcome of the latest poll of each repository. Kept apart from the release cache because
+-- repositories without any release have no cache row.
+CREATE TABLE IF NOT EXISTS tracked_repository_fetch_status (
+    tracked_repository_id TEXT PRIMARY KEY NOT NULL,
+    status TEXT NOT NULL,
+    checked_at TEXT NOT NULL,
+    FOREIGN KEY (tracked_repository_id) REFERENCES tracked_repositories(id) ON DELETE CASCADE
+);
@@ -0,0 +1,2 @@
+-- Keep polling the repository but never notify about it
+ALTER TABLE tracked_repository_settings ADD COLUMN muted INTEGER NOT NULL DEFAULT 0;
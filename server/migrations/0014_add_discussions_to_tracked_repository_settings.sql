@@ -0,0 +1,3 @@
+-- Follow announcements posted in a GitHub Discussions category instead of releases
+ALTER TABLE tracked_repository_settings ADD COLUMN discussion_category TEXT;
+ALTER TABLE tracked_repository_settings ADD COLUMN last_discussion_number INTEGER;
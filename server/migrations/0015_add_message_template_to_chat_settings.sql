@@ -0,0 +1,2 @@
+-- Custom text of release notifications, with placeholders such as {tag}
+ALTER TABLE chat_settings ADD COLUMN message_template TEXT;
@@ -0,0 +1,3 @@
+-- Slack or Discord webhook that mirrors a chat's release notifications
+ALTER TABLE chat_settings ADD COLUMN webhook_kind TEXT;
+ALTER TABLE chat_settings ADD COLUMN webhook_url TEXT;
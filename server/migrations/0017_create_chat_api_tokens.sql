@@ -0,0 +1,6 @@
+-- Per-chat REST API tokens, stored as SHA-256 hashes
+CREATE TABLE IF NOT EXISTS chat_api_tokens (
+    chat_id INTEGER PRIMARY KEY NOT NULL,
+    token_hash TEXT NOT NULL UNIQUE,
+    created_at TEXT NOT NULL
+);
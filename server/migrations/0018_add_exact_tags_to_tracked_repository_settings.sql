@@ -0,0 +1,2 @@
+-- Compare tags exactly instead of treating 1.2.3 and v1.2.3 as the same version
+ALTER TABLE tracked_repository_settings ADD COLUMN exact_tags INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,2 @@
+-- React to successful /track commands in the chat
+ALTER TABLE chat_settings ADD COLUMN track_reactions INTEGER NOT NULL DEFAULT 1;
@@ -0,0 +1,14 @@
+-- Details of releases detected by the poller, kept when RELEASE_HISTORY_LIMIT is set
+CREATE TABLE IF NOT EXISTS release_history (
+    id TEXT PRIMARY KEY NOT NULL,
+    tracked_repository_id TEXT NOT NULL,
+    tag_name TEXT NOT NULL,
+    body TEXT,
+    html_url TEXT,
+    published_at TEXT,
+    author TEXT,
+    detected_at TEXT NOT NULL,
+    FOREIGN KEY (tracked_repository_id) REFERENCES tracked_repositories(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_release_history_tracked_repository_id ON release_history(tracked_repository_id);
@@ -0,0 +1,3 @@
+-- The bot that serves a tracked repository or tag watch; '' is the bot of TELOXIDE_TOKEN
+ALTER TABLE tracked_repositories ADD COLUMN bot_id TEXT NOT NULL DEFAULT '';
+ALTER TABLE tag_watches ADD COLUMN bot_id TEXT NOT NULL DEFAULT '';
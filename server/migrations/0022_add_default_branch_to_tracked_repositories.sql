@@ -0,0 +1,2 @@
+-- The repository's default branch as GitHub reported it when the repository was tracked
+ALTER TABLE tracked_repositories ADD COLUMN default_branch TEXT;
@@ -0,0 +1,5 @@
+-- Releases younger than this are held back until they have existed long enough
+ALTER TABLE tracked_repository_settings ADD COLUMN min_release_age_secs INTEGER;
+-- The tag held back for being too young and when the poller first saw it
+ALTER TABLE tracked_repository_settings ADD COLUMN young_release_tag TEXT;
+ALTER TABLE tracked_repository_settings ADD COLUMN young_release_seen_at TEXT;
@@ -0,0 +1,4 @@
+-- Announce every time the default branch's commit count crosses a multiple of this
+ALTER TABLE tracked_repository_settings ADD COLUMN commit_milestone INTEGER;
+-- The commit count seen by the last poll in milestone mode
+ALTER TABLE tracked_repository_settings ADD COLUMN last_notified_count INTEGER;
@@ -0,0 +1,2 @@
+-- Releases and tags matching this glob are skipped in favour of the newest one that doesn't
+ALTER TABLE tracked_repository_settings ADD COLUMN tag_ignore TEXT;
@@ -0,0 +1,2 @@
+-- Language of the messages the bot writes to the chat
+ALTER TABLE chat_settings ADD COLUMN language TEXT NOT NULL DEFAULT 'en';
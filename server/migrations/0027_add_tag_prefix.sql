@@ -0,0 +1,2 @@
+-- Only releases and tags starting with this prefix are followed, e.g. one component of a monorepo
+ALTER TABLE tracked_repository_settings ADD COLUMN tag_prefix TEXT;
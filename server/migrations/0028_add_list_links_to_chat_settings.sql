@@ -0,0 +1,2 @@
+-- Whether /list links repositories and releases or sends plain text
+ALTER TABLE chat_settings ADD COLUMN list_links BOOLEAN NOT NULL DEFAULT 1;
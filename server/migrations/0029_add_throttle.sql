@@ -0,0 +1,3 @@
+-- At most one notification per window for noisy repositories, and when the last one was sent
+ALTER TABLE tracked_repository_settings ADD COLUMN throttle_window_secs INTEGER;
+ALTER TABLE tracked_repository_settings ADD COLUMN last_notified_at TEXT;
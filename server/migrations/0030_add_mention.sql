@@ -0,0 +1,2 @@
+-- Telegram user pinged by a repository's release notifications
+ALTER TABLE tracked_repository_settings ADD COLUMN mention TEXT;
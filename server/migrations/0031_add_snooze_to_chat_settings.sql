@@ -0,0 +1,4 @@
+-- Silence a whole chat for a while; the names of repositories that released meanwhile
+-- are kept, one per line, for a catch-up summary
+ALTER TABLE chat_settings ADD COLUMN snoozed_until TEXT;
+ALTER TABLE chat_settings ADD COLUMN snooze_missed TEXT;
@@ -0,0 +1,2 @@
+-- When GitHub stopped answering for a repository that used to have releases
+ALTER TABLE tracked_repository_settings ADD COLUMN inaccessible_since TEXT;
@@ -0,0 +1,2 @@
+-- How long requests for a slow repository may take, instead of the global default
+ALTER TABLE tracked_repository_settings ADD COLUMN request_timeout_secs INTEGER;
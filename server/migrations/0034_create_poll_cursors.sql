@@ -0,0 +1,6 @@
+-- The repository each bot's poller finished last, so a restart resumes a cycle after it
+CREATE TABLE IF NOT EXISTS poll_cursors (
+    bot_id TEXT PRIMARY KEY NOT NULL,
+    tracked_repository_id TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
@@ -0,0 +1,2 @@
+-- Whether the chat is told when a release it was notified about is deleted on GitHub
+ALTER TABLE chat_settings ADD COLUMN notify_removed_releases BOOLEAN NOT NULL DEFAULT 0;
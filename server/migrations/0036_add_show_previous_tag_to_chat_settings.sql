@@ -0,0 +1,2 @@
+-- Whether release notifications name the tag they follow, as in v1.0.0 → v1.1.0
+ALTER TABLE chat_settings ADD COLUMN show_previous_tag BOOLEAN NOT NULL DEFAULT 1;
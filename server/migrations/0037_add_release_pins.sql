@@ -0,0 +1,3 @@
+-- Whether the chat pins its latest release notification, and which notification is pinned
+ALTER TABLE chat_settings ADD COLUMN pin_releases BOOLEAN NOT NULL DEFAULT 0;
+ALTER TABLE notifications ADD COLUMN pinned BOOLEAN NOT NULL DEFAULT 0;
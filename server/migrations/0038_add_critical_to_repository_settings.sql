@@ -0,0 +1,2 @@
+-- Critical repositories notify even while muted, snoozed or held back
+ALTER TABLE tracked_repository_settings ADD COLUMN critical BOOLEAN NOT NULL DEFAULT 0;
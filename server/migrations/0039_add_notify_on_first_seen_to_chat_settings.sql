@@ -0,0 +1,2 @@
+-- Whether a newly tracked repository announces its current release instead of seeding it silently
+ALTER TABLE chat_settings ADD COLUMN notify_on_first_seen BOOLEAN NOT NULL DEFAULT 0;
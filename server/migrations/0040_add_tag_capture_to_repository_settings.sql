@@ -0,0 +1,2 @@
+-- Regex whose capture group names the version followed in a repository's tags
+ALTER TABLE tracked_repository_settings ADD COLUMN tag_capture TEXT;
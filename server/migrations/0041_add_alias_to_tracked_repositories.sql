@@ -0,0 +1,4 @@
+-- Short names chats give their repositories, unique within a chat
+ALTER TABLE tracked_repositories ADD COLUMN alias TEXT;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_tracked_repositories_chat_alias
+    ON tracked_repositories(chat_id, alias) WHERE alias IS NOT NULL;
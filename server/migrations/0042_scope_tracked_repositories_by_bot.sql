@@ -0,0 +1,60 @@
+-- A repository URL is unique per bot and chat rather than globally, so bots sharing the
+-- database do not take repositories from each other. SQLite cannot drop the UNIQUE of
+-- repository_url in place, so the table is rebuilt. Migrations run in a transaction,
+-- where foreign keys cannot be switched off, so the rows that cascade from
+-- tracked_repositories are set aside while it is dropped and put back afterwards.
+CREATE TEMP TABLE keep_releases AS SELECT * FROM tracked_repository_releases;
+CREATE TEMP TABLE keep_mirrors AS SELECT * FROM tracked_repository_mirrors;
+CREATE TEMP TABLE keep_settings AS SELECT * FROM tracked_repository_settings;
+CREATE TEMP TABLE keep_notifications AS SELECT * FROM notifications;
+CREATE TEMP TABLE keep_fetch_status AS SELECT * FROM tracked_repository_fetch_status;
+CREATE TEMP TABLE keep_release_history AS SELECT * FROM release_history;
+
+CREATE TABLE tracked_repositories_new (
+    id TEXT PRIMARY KEY NOT NULL,
+    repository_name TEXT NOT NULL,
+    repository_url TEXT NOT NULL,
+    chat_id INTEGER NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    bot_id TEXT NOT NULL DEFAULT '',
+    default_branch TEXT,
+    alias TEXT,
+    UNIQUE (bot_id, chat_id, repository_url)
+);
+
+INSERT INTO tracked_repositories_new (
+    id, repository_name, repository_url, chat_id, created_at, updated_at, bot_id,
+    default_branch, alias
+)
+SELECT id, repository_name, repository_url, chat_id, created_at, updated_at, bot_id,
+    default_branch, alias
+FROM tracked_repositories;
+
+DROP TABLE tracked_repositories;
+ALTER TABLE tracked_repositories_new RENAME TO tracked_repositories;
+
+CREATE INDEX IF NOT EXISTS idx_tracked_repositories_repository_name
+    ON tracked_repositories(repository_name);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_tracked_repositories_chat_alias
+    ON tracked_repositories(chat_id, alias) WHERE alias IS NOT NULL;
+
+INSERT INTO tracked_repository_releases SELECT * FROM keep_releases
+    WHERE tracked_repository_id IN (SELECT id FROM tracked_repositories);
+INSERT INTO tracked_repository_mirrors SELECT * FROM keep_mirrors
+    WHERE tracked_repository_id IN (SELECT id FROM tracked_repositories);
+INSERT INTO tracked_repository_settings SELECT * FROM keep_settings
+    WHERE tracked_repository_id IN (SELECT id FROM tracked_repositories);
+INSERT INTO notifications SELECT * FROM keep_notifications
+    WHERE tracked_repository_id IN (SELECT id FROM tracked_repositories);
+INSERT INTO tracked_repository_fetch_status SELECT * FROM keep_fetch_status
+    WHERE tracked_repository_id IN (SELECT id FROM tracked_repositories);
+INSERT INTO release_history SELECT * FROM keep_release_history
+    WHERE tracked_repository_id IN (SELECT id FROM tracked_repositories);
+
+DROP TABLE keep_releases;
+DROP TABLE keep_mirrors;
+DROP TABLE keep_settings;
+DROP TABLE keep_notifications;
+DROP TABLE keep_fetch_status;
+DROP TABLE keep_release_history;
@@ -0,0 +1,3 @@
+-- Whether the cached tag was a published release rather than a tag found through the
+-- tags fallback. Rows cached before this are not known to be releases.
+ALTER TABLE tracked_repository_releases ADD COLUMN from_release INTEGER NOT NULL DEFAULT 0;
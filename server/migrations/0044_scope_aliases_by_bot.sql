@@ -0,0 +1,4 @@
+-- Each bot keeps its own trackings of a chat, so aliases are unique per bot within a chat
+DROP INDEX IF EXISTS idx_tracked_repositories_chat_alias;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_tracked_repositories_bot_chat_alias
+    ON tracked_repositories(bot_id, chat_id, alias) WHERE alias IS NOT NULL;
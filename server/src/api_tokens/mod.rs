@@ -0,0 +1,57 @@
+pub mod repository;
+
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{FromRow, Row};
+use uuid::Uuid;
+
+/// The access token a chat uses for the REST API. Only the hash of the token is kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatApiToken {
+    pub chat_id: i64,
+    pub token_hash: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl FromRow<'_, SqliteRow> for ChatApiToken {
+    fn from_row(row: &SqliteRow) -> Result<Self, sqlx::Error> {
+        let chat_id: i64 = row.try_get("chat_id")?;
+        let token_hash: String = row.try_get("token_hash")?;
+        let created_at: DateTime<Utc> = row.try_get("created_at")?;
+
+        Ok(Self {
+            chat_id,
+            token_hash,
+            created_at,
+        })
+    }
+}
+
+/// A new opaque token with 244 random bits.
+pub fn generate_token() -> String {
+    format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+}
+
+/// The form a token is stored and looked up in.
+pub fn hash_token(token: &str) -> String {
+    Sha256::digest(token.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_tokens_are_unique_and_hash_stably() {
+        let a = generate_token();
+        let b = generate_token();
+        assert_ne!(a, b);
+        assert_eq!(a.len(), 64);
+        assert_eq!(hash_token(&a), hash_token(&a));
+        assert_ne!(hash_token(&a), a);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::api_tokens::ChatApiToken;
+use async_trait::async_trait;
+use sqlx::{self, sqlite::SqlitePool};
+use std::error::Error;
+
+#[async_trait]
+pub trait ChatApiTokensRepository: Send + Sync {
+    /// Stores the chat's token, replacing (and so invalidating) any previous one.
+    async fn save(&self, token: &ChatApiToken) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn find_by_chat_id(
+        &self,
+        chat_id: i64,
+    ) -> Result<Option<ChatApiToken>, Box<dyn Error + Send + Sync>>;
+    /// Used by the REST API to authenticate chat tokens.
+    #[allow(dead_code)]
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<ChatApiToken>, Box<dyn Error + Send + Sync>>;
+}
+
+pub struct SqliteChatApiTokensRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteChatApiTokensRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ChatApiTokensRepository for SqliteChatApiTokensRepository {
+    async fn save(&self, token: &ChatApiToken) -> Result<(), Box<dyn Error + Send + Sync>> {
+        sqlx::query(
+            r#"
+            INSERT INTO chat_api_tokens (chat_id, token_hash, created_at)
+            VALUES (?1, ?2, ?3)
+            ON CONFLICT(chat_id) DO UPDATE SET
+                token_hash = excluded.token_hash,
+                created_at = excluded.created_at
+            "#,
+        )
+        .bind(token.chat_id)
+        .bind(&token.token_hash)
+        .bind(token.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_chat_id(
+        &self,
+        chat_id: i64,
+    ) -> Result<Option<ChatApiToken>, Box<dyn Error + Send + Sync>> {
+        let rec = sqlx::query_as::<_, ChatApiToken>(
+            r#"
+            SELECT chat_id, token_hash, created_at
+            FROM chat_api_tokens
+            WHERE chat_id = ?1
+            "#,
+        )
+        .bind(chat_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(rec)
+    }
+
+    async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<ChatApiToken>, Box<dyn Error + Send + Sync>> {
+        let rec = sqlx::query_as::<_, ChatApiToken>(
+            r#"
+            SELECT chat_id, token_hash, created_at
+            FROM chat_api_tokens
+            WHERE token_hash = ?1
+            "#,
+        )
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(rec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api_tokens::{generate_token, hash_token};
+    use chrono::Utc;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_repo() -> SqliteChatApiTokensRepository {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        SqliteChatApiTokensRepository::new(pool)
+    }
+
+    #[tokio::test]
+    async fn rotation_invalidates_previous_token() {
+        let repo = setup_repo().await;
+        let old = generate_token();
+        repo.save(&ChatApiToken {
+            chat_id: 3,
+            token_hash: hash_token(&old),
+            created_at: Utc::now(),
+        })
+        .await
+        .unwrap();
+        assert_eq!(
+            repo.find_by_hash(&hash_token(&old))
+                .await
+                .unwrap()
+                .map(|t| t.chat_id),
+            Some(3)
+        );
+
+        let new = generate_token();
+        repo.save(&ChatApiToken {
+            chat_id: 3,
+            token_hash: hash_token(&new),
+            created_at: Utc::now(),
+        })
+        .await
+        .unwrap();
+
+        assert!(
+            repo.find_by_hash(&hash_token(&old))
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            repo.find_by_hash(&hash_token(&new))
+                .await
+                .unwrap()
+                .is_some()
+        );
+        assert_eq!(
+            repo.find_by_chat_id(3).await.unwrap().unwrap().token_hash,
+            hash_token(&new)
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::messages::{CommandText, text};
+
+/// Replies with a refusal and returns `false` if the chat may not use the bot.
+/// Administrators are always let in.
+pub(super) async fn require_access(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+) -> ResponseResult<bool> {
+    let chat_id = msg.chat.id.0;
+    if sent_by_admin(msg, state) || state.config.chat_access.permits(chat_id) {
+        return Ok(true);
+    }
+    log::info!("Ignoring message from unauthorized chat {}", chat_id);
+    let lang = chat_language(&state.db, chat_id).await;
+    bot.send_message(msg.chat.id, text(lang, CommandText::NotAuthorized))
+        .await?;
+    Ok(false)
+}
+
+/// The same check as `require_access` for a button press: the refusal is shown as the
+/// query's answer, and presses on messages too old to carry their chat are refused.
+pub(super) async fn require_callback_access(
+    bot: &Bot,
+    q: &CallbackQuery,
+    state: &BotState,
+) -> ResponseResult<bool> {
+    let chat_id = q.message.as_ref().map(|message| message.chat().id.0);
+    if state.config.is_admin(q.from.id.0 as i64)
+        || chat_id.is_some_and(|chat_id| state.config.chat_access.permits(chat_id))
+    {
+        return Ok(true);
+    }
+    log::info!("Ignoring button press from unauthorized chat {:?}", chat_id);
+    let lang = match chat_id {
+        Some(chat_id) => chat_language(&state.db, chat_id).await,
+        None => Default::default(),
+    };
+    bot.answer_callback_query(q.id.clone())
+        .text(text(lang, CommandText::NotAuthorized))
+        .await?;
+    Ok(false)
+}
+
+/// Replies with a refusal and returns `false` unless the sender is a configured admin.
+/// Whoever else shares the chat with an admin is still refused.
+pub(super) async fn require_admin(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+) -> ResponseResult<bool> {
+    if sent_by_admin(msg, state) {
+        return Ok(true);
+    }
+    let lang = chat_language(&state.db, msg.chat.id.0).await;
+    bot.send_message(msg.chat.id, text(lang, CommandText::AdminsOnly))
+        .await?;
+    Ok(false)
+}
+
+/// Whether the user who sent `msg` is a configured admin. Messages posted on behalf of a
+/// channel or an anonymous group admin carry no user and never count.
+fn sent_by_admin(msg: &Message, state: &BotState) -> bool {
+    msg.from
+        .as_ref()
+        .is_some_and(|user| state.config.is_admin(user.id.0 as i64))
+}
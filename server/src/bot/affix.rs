@@ -0,0 +1,161 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::chat_settings::Lang;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::messages::{CommandText, DeliveryText, render, text};
+
+/// Longest prefix or suffix accepted, to leave room for the notifications themselves.
+const MAX_AFFIX_CHARS: usize = 100;
+
+/// Which end of release notifications a text is attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Affix {
+    Prefix,
+    Suffix,
+}
+
+impl Affix {
+    /// `prefix` when this is the prefix, else `suffix`.
+    fn pick(&self, prefix: DeliveryText, suffix: DeliveryText) -> DeliveryText {
+        match self {
+            Self::Prefix => prefix,
+            Self::Suffix => suffix,
+        }
+    }
+}
+
+/// Shows, sets or (with `clear`) removes the chat's notification prefix or suffix.
+pub(crate) async fn handle_affix(
+    db: &SqlitePool,
+    chat_id: i64,
+    affix: Affix,
+    value: &str,
+) -> Result<String, String> {
+    let repository = SqliteChatSettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(chat_id).await.map_err(|e| {
+        render(
+            Lang::default(),
+            CommandText::LoadChatSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    let lang = settings.language;
+    let slot = match affix {
+        Affix::Prefix => &mut settings.message_prefix,
+        Affix::Suffix => &mut settings.message_suffix,
+    };
+
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(match slot {
+            Some(current) => render(
+                lang,
+                affix.pick(DeliveryText::PrefixCurrent, DeliveryText::SuffixCurrent),
+                &[("text", current)],
+            ),
+            None => text(
+                lang,
+                affix.pick(DeliveryText::NoPrefix, DeliveryText::NoSuffix),
+            )
+            .to_string(),
+        });
+    }
+    if value.chars().count() > MAX_AFFIX_CHARS {
+        return Err(render(
+            lang,
+            affix.pick(DeliveryText::PrefixTooLong, DeliveryText::SuffixTooLong),
+            &[("max", &MAX_AFFIX_CHARS.to_string())],
+        ));
+    }
+
+    *slot = (!value.eq_ignore_ascii_case("clear")).then(|| value.to_string());
+    let reply = match slot {
+        Some(_) => render(
+            lang,
+            affix.pick(DeliveryText::PrefixSet, DeliveryText::SuffixSet),
+            &[("text", value)],
+        ),
+        None => text(
+            lang,
+            affix.pick(DeliveryText::PrefixRemoved, DeliveryText::SuffixRemoved),
+        )
+        .to_string(),
+    };
+    settings.updated_at = chrono::Utc::now();
+    repository.save(&settings).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::SaveChatSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    Ok(reply)
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    affix: Affix,
+    value: String,
+) -> ResponseResult<()> {
+    let text = match handle_affix(&state.db, msg.chat.id.0, affix, &value).await {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn sets_and_clears_prefix() {
+        let db = setup_db().await;
+        let repository = SqliteChatSettingsRepository::new(db.clone());
+
+        handle_affix(&db, 3, Affix::Prefix, " [Releases] ")
+            .await
+            .expect("should succeed");
+        let settings = repository.find_or_default(3).await.unwrap();
+        assert_eq!(settings.message_prefix.as_deref(), Some("[Releases]"));
+        assert_eq!(settings.message_suffix, None);
+
+        handle_affix(&db, 3, Affix::Prefix, "clear")
+            .await
+            .expect("should succeed");
+        let settings = repository.find_or_default(3).await.unwrap();
+        assert_eq!(settings.message_prefix, None);
+    }
+
+    #[tokio::test]
+    async fn rejects_overlong_suffix() {
+        let db = setup_db().await;
+        let err = handle_affix(&db, 3, Affix::Suffix, &"x".repeat(MAX_AFFIX_CHARS + 1))
+            .await
+            .expect_err("too long");
+        assert!(err.contains("at most"));
+    }
+}
@@ -0,0 +1,188 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::db::is_unique_violation;
+use crate::messages::{CommandText, SettingText, render};
+use crate::tracked_repositories::repository::{
+    SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+};
+
+/// Longest alias accepted, in characters.
+const MAX_ALIAS_CHARS: usize = 32;
+
+/// The alias `input` names, lowercased: letters, digits, `-`, `_` and `.`, starting with a
+/// letter or digit. Anything else, like a URL, is not an alias.
+pub(crate) fn parse_alias(input: &str) -> Option<String> {
+    let input = input.trim();
+    let valid = (1..=MAX_ALIAS_CHARS).contains(&input.chars().count())
+        && input.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && input
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    valid.then(|| input.to_ascii_lowercase())
+}
+
+/// Gives a tracked repository a short name the chat can use instead of its URL, or
+/// removes it with `off`. Every repository of a chat has its own alias.
+pub(crate) async fn handle_alias(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    url: &str,
+    alias: &str,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let alias = alias.trim();
+    if alias.is_empty() {
+        return Err(render(
+            lang,
+            CommandText::UsageExample,
+            &[
+                ("usage", "/alias <url> <short name|off>"),
+                ("example", "/alias https://github.com/tokio-rs/tokio tokio"),
+            ],
+        ));
+    }
+    let alias = if alias.eq_ignore_ascii_case("off") {
+        None
+    } else {
+        Some(parse_alias(alias).ok_or_else(|| {
+            render(
+                lang,
+                SettingText::AliasInvalid,
+                &[("max", &MAX_ALIAS_CHARS.to_string())],
+            )
+        })?)
+    };
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+
+    let repository = SqliteTrackedRepositoriesRepository::new(db.clone());
+    match repository.set_alias(&tracked.id, alias.as_deref()).await {
+        Ok(()) => {}
+        Err(e) if is_unique_violation(&*e) => {
+            return Err(render(
+                lang,
+                SettingText::AliasTaken,
+                &[("alias", &alias.unwrap_or_default())],
+            ));
+        }
+        Err(e) => {
+            return Err(render(
+                lang,
+                SettingText::AliasSaveFailed,
+                &[("error", &e.to_string())],
+            ));
+        }
+    }
+
+    let repository = tracked.repository_name.as_str();
+    match alias {
+        Some(alias) => Ok(render(
+            lang,
+            SettingText::AliasSet,
+            &[("repository", repository), ("alias", &alias)],
+        )),
+        None => Ok(render(
+            lang,
+            SettingText::AliasCleared,
+            &[("repository", repository)],
+        )),
+    }
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+    alias: String,
+) -> ResponseResult<()> {
+    let text = match handle_alias(&state.db, &state.bot_id, msg.chat.id.0, &url, &alias).await {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::handle_track;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[test]
+    fn urls_are_not_aliases() {
+        assert_eq!(parse_alias(" Tokio-RS ").as_deref(), Some("tokio-rs"));
+        assert_eq!(parse_alias("v1.x").as_deref(), Some("v1.x"));
+        for input in [
+            "",
+            "https://github.com/owner/repo",
+            "owner/repo",
+            "-x",
+            "a b",
+        ] {
+            assert_eq!(parse_alias(input), None, "{input}");
+        }
+    }
+
+    #[tokio::test]
+    async fn alias_resolves_where_a_url_is_expected() {
+        let db = setup_db().await;
+        let url = "https://github.com/tokio-rs/tokio";
+        let id = handle_track(&db, "", 1, "tokio", url).await.unwrap().id();
+
+        handle_alias(&db, "", 1, url, "Tokio").await.unwrap();
+
+        let tracked = find_tracked_for_chat(&db, "", 1, "tokio").await.unwrap();
+        assert_eq!(tracked.id, id);
+        // Aliases belong to their chat
+        assert!(find_tracked_for_chat(&db, "", 2, "tokio").await.is_err());
+        // An alias works in place of the URL when setting another one
+        handle_alias(&db, "", 1, "tokio", "rt").await.unwrap();
+        assert!(find_tracked_for_chat(&db, "", 1, "tokio").await.is_err());
+        assert_eq!(
+            find_tracked_for_chat(&db, "", 1, "rt").await.unwrap().id,
+            id
+        );
+
+        handle_alias(&db, "", 1, "rt", "off").await.unwrap();
+        assert!(find_tracked_for_chat(&db, "", 1, "rt").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn aliases_must_be_unique_within_the_chat() {
+        let db = setup_db().await;
+        let serde = "https://github.com/serde-rs/serde";
+        let json = "https://github.com/serde-rs/json";
+        handle_track(&db, "", 1, "serde", serde).await.unwrap();
+        handle_track(&db, "", 1, "json", json).await.unwrap();
+        handle_alias(&db, "", 1, serde, "serde").await.unwrap();
+
+        let err = handle_alias(&db, "", 1, json, "SERDE").await.unwrap_err();
+        assert!(err.contains("already called serde"), "{err}");
+        assert!(handle_alias(&db, "", 1, json, "serde json").await.is_err());
+        assert!(handle_alias(&db, "", 1, json, "").await.is_err());
+        // Setting the alias a repository already has again is fine
+        handle_alias(&db, "", 1, serde, "serde").await.unwrap();
+    }
+}
@@ -0,0 +1,60 @@
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::chat_settings::Lang;
+use crate::configuration::{Configuration, TokenSource};
+use crate::messages::{CommandText, InfoText, render};
+use crate::tracked_repositories::RepositoryUrl;
+
+/// Tells which credentials requests for `url` are sent with, never the secret itself.
+pub(crate) fn describe_auth(
+    config: &Configuration,
+    url: &str,
+    lang: Lang,
+) -> Result<String, String> {
+    let url = RepositoryUrl::new(url.trim().to_string()).map_err(|e| e.message(lang))?;
+    if let Some(package) = url.package() {
+        return Ok(render(
+            lang,
+            InfoText::AuthPackage,
+            &[
+                ("registry", package.registry.name()),
+                ("package", &package.name),
+            ],
+        ));
+    }
+    let (owner, repo) = url.owner_and_repo().ok_or_else(|| {
+        render(
+            lang,
+            CommandText::NoOwnerAndRepository,
+            &[("url", &url.to_string())],
+        )
+    })?;
+
+    let key = match config.resolve_token() {
+        TokenSource::Global(_) => InfoText::AuthGlobal,
+        TokenSource::Anonymous => InfoText::AuthAnonymous,
+    };
+    Ok(render(
+        lang,
+        key,
+        &[("repository", &format!("{owner}/{repo}"))],
+    ))
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+) -> ResponseResult<()> {
+    let lang = chat_language(&state.db, msg.chat.id.0).await;
+    let text = match describe_auth(&state.config, &url, lang) {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
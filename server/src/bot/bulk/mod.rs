@@ -0,0 +1,284 @@
+mod pending;
+
+use std::error::Error;
+use std::sync::Arc;
+
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+use uuid::Uuid;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::chat_settings::Lang;
+use crate::messages::{CommandText, TrackingText, render, text};
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::repository::{
+    SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+};
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+use crate::utils::glob_matches;
+
+pub use pending::PendingUntracks;
+
+/// Names listed in the confirmation prompt before the rest is summarised.
+const MAX_LISTED_NAMES: usize = 20;
+const CONFIRM_PREFIX: &str = "untrack:";
+const CANCEL_PREFIX: &str = "untrack_cancel:";
+
+/// The repositories the bot tracks for the chat whose name or URL matches `glob`.
+async fn find_matching(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    glob: &str,
+) -> Result<Vec<TrackedRelease>, Box<dyn Error + Send + Sync>> {
+    let repos = SqliteTrackedRepositoriesRepository::new(db.clone())
+        .find_all_by_chat_id(bot_id, chat_id)
+        .await?;
+    Ok(repos
+        .into_iter()
+        .filter(|r| {
+            glob_matches(glob, &r.repository_name)
+                || glob_matches(glob, &r.repository_url.to_string())
+        })
+        .collect())
+}
+
+fn require_glob(lang: Lang, glob: &str, command: &str) -> Result<(), String> {
+    if glob.is_empty() {
+        return Err(render(
+            lang,
+            TrackingText::PatternMissing,
+            &[("command", command)],
+        ));
+    }
+    Ok(())
+}
+
+/// The repositories matching `glob`, with a failed lookup explained in `lang`.
+async fn find_matching_in(
+    db: &SqlitePool,
+    lang: Lang,
+    bot_id: &str,
+    chat_id: i64,
+    glob: &str,
+) -> Result<Vec<TrackedRelease>, String> {
+    find_matching(db, bot_id, chat_id, glob)
+        .await
+        .map_err(|e| render(lang, TrackingText::ListFailed, &[("error", &e.to_string())]))
+}
+
+pub(crate) async fn handle_mute_matching(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    glob: &str,
+    muted: bool,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let glob = glob.trim();
+    let command = if muted {
+        "mute_matching"
+    } else {
+        "unmute_matching"
+    };
+    require_glob(lang, glob, command)?;
+    let repos = find_matching_in(db, lang, bot_id, chat_id, glob).await?;
+    if repos.is_empty() {
+        return Ok(render(lang, TrackingText::NoMatches, &[("pattern", glob)]));
+    }
+
+    let repository = SqliteRepositorySettingsRepository::new(db.clone());
+    for r in &repos {
+        let mut settings = repository.find_or_default(&r.id).await.map_err(|e| {
+            render(
+                lang,
+                CommandText::LoadSettingsFailed,
+                &[("error", &e.to_string())],
+            )
+        })?;
+        settings.muted = muted;
+        settings.updated_at = chrono::Utc::now();
+        repository.save(&settings).await.map_err(|e| {
+            render(
+                lang,
+                CommandText::SaveSettingsFailed,
+                &[("error", &e.to_string())],
+            )
+        })?;
+    }
+
+    let key = if muted {
+        TrackingText::Muted
+    } else {
+        TrackingText::Unmuted
+    };
+    Ok(render(lang, key, &[("count", &repos.len().to_string())]))
+}
+
+/// Removes the repositories of a confirmed bulk untrack, or drops it when `confirmed` is false.
+pub(crate) async fn handle_untrack_confirmation(
+    db: &SqlitePool,
+    pending: &PendingUntracks,
+    chat_id: i64,
+    key: &Uuid,
+    confirmed: bool,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let Some(ids) = pending.take(chat_id, key) else {
+        return Err(text(lang, TrackingText::ConfirmationExpired).to_string());
+    };
+    if !confirmed {
+        return Ok(text(lang, TrackingText::UntrackCancelled).to_string());
+    }
+
+    let deleted = SqliteTrackedRepositoriesRepository::new(db.clone())
+        .delete_all(&ids)
+        .await
+        .map_err(|e| {
+            render(
+                lang,
+                TrackingText::UntrackFailed,
+                &[("error", &e.to_string())],
+            )
+        })?;
+    Ok(render(
+        lang,
+        TrackingText::Untracked,
+        &[("count", &deleted.to_string())],
+    ))
+}
+
+pub(super) async fn answer_mute(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    glob: String,
+    muted: bool,
+) -> ResponseResult<()> {
+    let text =
+        match handle_mute_matching(&state.db, &state.bot_id, msg.chat.id.0, &glob, muted).await {
+            Ok(message) => message,
+            Err(err_msg) => err_msg,
+        };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+pub(super) async fn answer_untrack(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    glob: String,
+) -> ResponseResult<()> {
+    let lang = chat_language(&state.db, msg.chat.id.0).await;
+    let glob = glob.trim();
+    let repos = match require_glob(lang, glob, "untrack_matching") {
+        Ok(()) => find_matching_in(&state.db, lang, &state.bot_id, msg.chat.id.0, glob).await,
+        Err(e) => Err(e),
+    };
+    let repos = match repos {
+        Ok(repos) if repos.is_empty() => {
+            let reply = render(lang, TrackingText::NoMatches, &[("pattern", glob)]);
+            bot.send_message(msg.chat.id, reply).await?;
+            return Ok(());
+        }
+        Ok(repos) => repos,
+        Err(err_msg) => {
+            bot.send_message(msg.chat.id, err_msg).await?;
+            return Ok(());
+        }
+    };
+
+    let intro = render(
+        lang,
+        TrackingText::UntrackIntro,
+        &[("count", &repos.len().to_string())],
+    );
+    ask_untrack_confirmation(bot, msg, state, intro, repos).await
+}
+
+/// Lists `repos` under `intro` with buttons to untrack all of them or cancel.
+pub(super) async fn ask_untrack_confirmation(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    intro: String,
+    repos: Vec<TrackedRelease>,
+) -> ResponseResult<()> {
+    let lang = chat_language(&state.db, msg.chat.id.0).await;
+    let mut prompt = intro;
+    for r in repos.iter().take(MAX_LISTED_NAMES) {
+        prompt.push_str(&format!("\n- {}", r.repository_name));
+    }
+    if repos.len() > MAX_LISTED_NAMES {
+        let more = (repos.len() - MAX_LISTED_NAMES).to_string();
+        prompt.push('\n');
+        prompt.push_str(&render(lang, TrackingText::AndMore, &[("count", &more)]));
+    }
+
+    let count = repos.len().to_string();
+    let key = state
+        .pending_untracks
+        .insert(msg.chat.id.0, repos.into_iter().map(|r| r.id).collect());
+    let keyboard = InlineKeyboardMarkup::new(vec![vec![
+        InlineKeyboardButton::callback(
+            render(lang, TrackingText::ConfirmUntrack, &[("count", &count)]),
+            format!("{CONFIRM_PREFIX}{key}"),
+        ),
+        InlineKeyboardButton::callback(
+            text(lang, TrackingText::Cancel),
+            format!("{CANCEL_PREFIX}{key}"),
+        ),
+    ]]);
+    bot.send_message(msg.chat.id, prompt)
+        .reply_markup(keyboard)
+        .await?;
+
+    Ok(())
+}
+
+/// Handles presses of the bulk untrack confirmation buttons.
+pub(super) async fn callback(
+    bot: Bot,
+    q: CallbackQuery,
+    state: Arc<BotState>,
+) -> ResponseResult<()> {
+    let data = q.data.as_deref().unwrap_or_default();
+    let parsed = if let Some(key) = data.strip_prefix(CONFIRM_PREFIX) {
+        Uuid::parse_str(key).ok().map(|key| (key, true))
+    } else if let Some(key) = data.strip_prefix(CANCEL_PREFIX) {
+        Uuid::parse_str(key).ok().map(|key| (key, false))
+    } else {
+        None
+    };
+
+    bot.answer_callback_query(q.id.clone()).await?;
+    let (Some((key, confirmed)), Some(message)) = (parsed, q.message.as_ref()) else {
+        return Ok(());
+    };
+
+    let chat_id = message.chat().id;
+    let text = match handle_untrack_confirmation(
+        &state.db,
+        &state.pending_untracks,
+        chat_id.0,
+        &key,
+        confirmed,
+    )
+    .await
+    {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.edit_message_text(chat_id, message.id(), text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use uuid::Uuid;
+
+/// Unconfirmed untracks are forgotten after this long.
+const CONFIRMATION_TTL: Duration = Duration::from_secs(10 * 60);
+
+struct PendingUntrack {
+    chat_id: i64,
+    ids: Vec<Uuid>,
+    created_at: Instant,
+}
+
+/// Bulk untracks waiting for the user to press the confirmation button.
+#[derive(Default)]
+pub struct PendingUntracks(Mutex<HashMap<Uuid, PendingUntrack>>);
+
+impl PendingUntracks {
+    pub(super) fn insert(&self, chat_id: i64, ids: Vec<Uuid>) -> Uuid {
+        let key = Uuid::now_v7();
+        if let Ok(mut pending) = self.0.lock() {
+            pending.retain(|_, p| p.created_at.elapsed() < CONFIRMATION_TTL);
+            pending.insert(
+                key,
+                PendingUntrack {
+                    chat_id,
+                    ids,
+                    created_at: Instant::now(),
+                },
+            );
+        }
+        key
+    }
+
+    pub(super) fn take(&self, chat_id: i64, key: &Uuid) -> Option<Vec<Uuid>> {
+        let mut pending = self.0.lock().ok()?;
+        match pending.get(key) {
+            Some(p) if p.chat_id == chat_id && p.created_at.elapsed() < CONFIRMATION_TTL => {
+                pending.remove(key).map(|p| p.ids)
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use super::*;
+use crate::bot::track::handle_track;
+use sqlx::sqlite::SqlitePoolOptions;
+
+async fn setup_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("failed to create in-memory sqlite pool");
+
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("failed to run migrations");
+
+    for name in ["tokio", "tokio-util", "serde"] {
+        let url = format!("https://github.com/owner/{name}");
+        handle_track(&pool, "", 1, name, &url).await.unwrap();
+    }
+    pool
+}
+
+#[tokio::test]
+async fn mutes_matching_repositories() {
+    let db = setup_db().await;
+    let message = handle_mute_matching(&db, "", 1, "tokio*", true)
+        .await
+        .unwrap();
+    assert_eq!(message, "Muted 2 repositories.");
+
+    let repos = find_matching(&db, "", 1, "*").await.unwrap();
+    let settings = SqliteRepositorySettingsRepository::new(db.clone());
+    for r in repos {
+        let muted = settings.find_or_default(&r.id).await.unwrap().muted;
+        assert_eq!(muted, r.repository_name.starts_with("tokio"));
+    }
+}
+
+#[tokio::test]
+async fn untrack_requires_confirmation_from_the_same_chat() {
+    let db = setup_db().await;
+    let pending = PendingUntracks::default();
+    let ids = find_matching(&db, "", 1, "*/tokio*")
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+    let key = pending.insert(1, ids);
+
+    assert!(
+        handle_untrack_confirmation(&db, &pending, 2, &key, true)
+            .await
+            .is_err()
+    );
+    let message = handle_untrack_confirmation(&db, &pending, 1, &key, true)
+        .await
+        .unwrap();
+    assert_eq!(message, "Stopped tracking 2 repositories.");
+    assert!(
+        handle_untrack_confirmation(&db, &pending, 1, &key, true)
+            .await
+            .is_err()
+    );
+
+    let remaining = find_matching(&db, "", 1, "*").await.unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].repository_name, "serde");
+}
+
+#[tokio::test]
+async fn untrack_leaves_the_rows_of_other_bots() {
+    let db = setup_db().await;
+    handle_track(&db, "222", 1, "tokio", "https://github.com/owner/tokio")
+        .await
+        .unwrap();
+    let pending = PendingUntracks::default();
+    let ids = find_matching(&db, "", 1, "*/tokio")
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+    let key = pending.insert(1, ids);
+    handle_untrack_confirmation(&db, &pending, 1, &key, true)
+        .await
+        .unwrap();
+
+    assert!(
+        find_matching(&db, "", 1, "*/tokio")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    let other = find_matching(&db, "222", 1, "*/tokio").await.unwrap();
+    assert_eq!(other.len(), 1);
+    assert_eq!(other[0].bot_id, "222");
+}
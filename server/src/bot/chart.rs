@@ -0,0 +1,234 @@
+use chrono::{DateTime, Duration, Utc};
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::messages::{CommandText, ReleaseText, render, text};
+use crate::tracked_repositories::release_history::repository::{
+    ReleaseHistoryRepository, SqliteReleaseHistoryRepository,
+};
+
+/// Weeks shown by /chart, oldest on the left.
+const CHART_WEEKS: usize = 12;
+
+/// Bar heights from an empty week to the busiest one.
+const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Counts the releases in each of the `weeks` weeks up to `now`, oldest week first. The
+/// last week is the seven days ending at `now`; older releases are left out.
+fn weekly_counts(times: &[DateTime<Utc>], now: DateTime<Utc>, weeks: usize) -> Vec<u32> {
+    let mut counts = vec![0; weeks];
+    for time in times {
+        let weeks_ago = (now - *time).num_weeks().max(0) as usize;
+        if weeks_ago < weeks {
+            counts[weeks - 1 - weeks_ago] += 1;
+        }
+    }
+    counts
+}
+
+/// Draws one bar per count, scaled to the largest count. Empty weeks get the lowest bar
+/// and any week with a release at least the second lowest, so the two never look alike;
+/// the busiest week always gets the full bar.
+fn sparkline(counts: &[u32]) -> String {
+    let max = counts.iter().copied().max().unwrap_or(0);
+    counts
+        .iter()
+        .map(|&count| {
+            if count == 0 {
+                return BARS[0];
+            }
+            if count == max {
+                return BARS[BARS.len() - 1];
+            }
+            let steps = (BARS.len() - 2) as u32;
+            BARS[1 + ((count - 1) * steps / (max - 1)) as usize]
+        })
+        .collect()
+}
+
+/// Draws the repository's releases per week over the last twelve weeks, from the
+/// release history the poller recorded.
+pub(crate) async fn handle_chart(
+    db: &SqlitePool,
+    bot_id: &str,
+    history_limit: usize,
+    chat_id: i64,
+    url: &str,
+    now: DateTime<Utc>,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    if history_limit == 0 {
+        return Err(text(lang, CommandText::HistoryOff).to_string());
+    }
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+
+    let since = now - Duration::weeks(CHART_WEEKS as i64);
+    let times = SqliteReleaseHistoryRepository::new(db.clone())
+        .release_times_since(&tracked.id, since)
+        .await
+        .map_err(|e| {
+            render(
+                lang,
+                CommandText::HistoryFailed,
+                &[("error", &e.to_string())],
+            )
+        })?;
+    let counts = weekly_counts(&times, now, CHART_WEEKS);
+    let total: u32 = counts.iter().sum();
+    if total == 0 {
+        return Ok(render(
+            lang,
+            ReleaseText::NoChartReleases,
+            &[
+                ("repository", &tracked.repository_name),
+                ("weeks", &CHART_WEEKS.to_string()),
+            ],
+        ));
+    }
+
+    let busiest = counts.iter().copied().max().unwrap_or(0);
+    Ok(render(
+        lang,
+        ReleaseText::Chart,
+        &[
+            ("repository", &tracked.repository_name),
+            ("weeks", &CHART_WEEKS.to_string()),
+            ("chart", &sparkline(&counts)),
+            ("total", &total.to_string()),
+            ("busiest", &busiest.to_string()),
+        ],
+    ))
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+) -> ResponseResult<()> {
+    let text = match handle_chart(
+        &state.db,
+        &state.bot_id,
+        state.config.release_history_limit,
+        msg.chat.id.0,
+        &url,
+        Utc::now(),
+    )
+    .await
+    {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use crate::chat_settings::Lang;
+    use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+    use crate::tracked_repositories::release_history::ReleaseHistoryEntry;
+    use sqlx::sqlite::SqlitePoolOptions;
+    use uuid::Uuid;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[test]
+    fn scales_bars_to_the_busiest_week() {
+        assert_eq!(sparkline(&[0, 1, 2, 3, 4, 5, 6, 7]), "▁▂▃▄▅▆▇█");
+        assert_eq!(sparkline(&[0, 1, 0, 1]), "▁█▁█");
+        assert_eq!(sparkline(&[1, 10]), "▂█");
+        assert_eq!(sparkline(&[0, 0, 0]), "▁▁▁");
+        assert_eq!(sparkline(&[]), "");
+    }
+
+    #[test]
+    fn buckets_releases_by_week_oldest_first() {
+        let now = Utc::now();
+        let times = [
+            now - Duration::days(1),
+            now - Duration::days(6),
+            now - Duration::days(8),
+            now - Duration::days(27),
+            now - Duration::days(29),
+            // Outside the window, or reported from the future by a skewed clock
+            now - Duration::days(200),
+            now + Duration::hours(1),
+        ];
+        assert_eq!(weekly_counts(&times, now, 4), vec![1, 0, 1, 3]);
+    }
+
+    #[tokio::test]
+    async fn charts_recorded_releases() {
+        let db = setup_db().await;
+        let HandleTrackResult::Created { id, .. } =
+            handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+                .await
+                .unwrap()
+        else {
+            panic!("expected Created");
+        };
+        let url = "https://github.com/owner/repo";
+        let now = Utc::now();
+
+        assert_eq!(
+            handle_chart(&db, "", 10, 1, url, now).await.unwrap(),
+            "No releases of repo were recorded in the last 12 weeks."
+        );
+
+        let entries: Vec<_> = [2, 3, 20]
+            .iter()
+            .map(|days_ago| ReleaseHistoryEntry {
+                id: Uuid::now_v7(),
+                tracked_repository_id: id,
+                tag_name: format!("v{days_ago}"),
+                body: None,
+                html_url: None,
+                published_at: Some(now - Duration::days(*days_ago)),
+                author: None,
+                detected_at: now,
+            })
+            .collect();
+        SqliteReleaseHistoryRepository::new(db.clone())
+            .save_all(&entries, 10)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            handle_chart(&db, "", 10, 1, url, now).await.unwrap(),
+            "Releases of repo per week, last 12 weeks:\n▁▁▁▁▁▁▁▁▁▂▁█\n\
+             3 in total, at most 2 in a week. The newest week is on the right."
+        );
+        assert!(handle_chart(&db, "", 0, 1, url, now).await.is_err());
+
+        let chat_settings = SqliteChatSettingsRepository::new(db.clone());
+        let mut settings = chat_settings.find_or_default(1).await.unwrap();
+        settings.language = Lang::De;
+        chat_settings.save(&settings).await.unwrap();
+        assert!(
+            handle_chart(&db, "", 10, 1, url, now)
+                .await
+                .unwrap()
+                .starts_with("Releases von repo pro Woche, letzte 12 Wochen:")
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::chat_settings::Lang;
+use crate::github::{
+    HttpReleaseSource, ReleaseSource, fetch_latest_release_tag_with_base, github_api_base,
+};
+use crate::messages::{CommandText, InfoText, render, text};
+use crate::tracked_repositories::RepositoryUrl;
+
+/// Explains how a URL would be tracked, querying GitHub but persisting nothing.
+pub(crate) async fn describe_url(
+    client: &reqwest::Client,
+    token_opt: Option<&str>,
+    api_base: &str,
+    url: &str,
+    lang: Lang,
+) -> String {
+    let url = url.trim();
+    if url.is_empty() {
+        return text(lang, InfoText::CheckUrlMissing).to_string();
+    }
+    let repository_url = match RepositoryUrl::new(url.to_string()) {
+        Ok(u) => u,
+        Err(e) => return e.message(lang),
+    };
+    if let Some(package) = repository_url.package() {
+        let source = HttpReleaseSource::new(client.clone(), token_opt, api_base.to_string());
+        let latest = match source.latest_package_version(&package).await {
+            Ok(Some(release)) => release.tag_name,
+            Ok(None) => text(lang, InfoText::NoPublishedVersion).to_string(),
+            Err(e) => render(lang, InfoText::LookupError, &[("error", &e.to_string())]),
+        };
+        return render(
+            lang,
+            InfoText::CheckPackage,
+            &[
+                ("registry", package.registry.name()),
+                ("package", &package.name),
+                ("latest", &latest),
+            ],
+        );
+    }
+    let Some((owner, repo)) = repository_url.owner_and_repo() else {
+        return render(lang, CommandText::NoOwnerAndRepository, &[("url", url)]);
+    };
+
+    let latest = match fetch_latest_release_tag_with_base(
+        client, &owner, &repo, token_opt, api_base,
+    )
+    .await
+    {
+        Ok(Some(tag)) => tag,
+        Ok(None) => text(lang, InfoText::NoReleaseOrTag).to_string(),
+        Err(e) => render(lang, InfoText::LookupError, &[("error", &e.to_string())]),
+    };
+
+    render(
+        lang,
+        InfoText::CheckRepository,
+        &[
+            ("owner", &owner),
+            ("repo", &repo),
+            ("base", api_base),
+            ("latest", &latest),
+        ],
+    )
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+) -> ResponseResult<()> {
+    let client = reqwest::Client::new();
+    let token_opt = state.config.resolve_token().token();
+    let lang = chat_language(&state.db, msg.chat.id.0).await;
+    let reply = describe_url(&client, token_opt, &github_api_base(), &url, lang).await;
+    bot.send_message(msg.chat.id, reply).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn describes_valid_url() {
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!({"tag_name": "v2.0.0"}).to_string())
+            .create_async()
+            .await;
+
+        let text = describe_url(
+            &reqwest::Client::new(),
+            None,
+            &server.url(),
+            "https://github.com/owner/repo.git",
+            Lang::En,
+        )
+        .await;
+
+        assert!(text.contains("Owner: owner\nRepository: repo\n"));
+        assert!(text.contains(&format!("API base: {}", server.url())));
+        assert!(text.contains("Latest release: v2.0.0"));
+    }
+
+    #[tokio::test]
+    async fn reports_validation_errors() {
+        let client = reqwest::Client::new();
+
+        let text = describe_url(
+            &client,
+            None,
+            "http://unused",
+            "https://gitlab.com/a/b",
+            Lang::En,
+        )
+        .await;
+        assert_eq!(
+            text,
+            "Invalid GitHub repository URL: https://gitlab.com/a/b"
+        );
+
+        let text = describe_url(
+            &client,
+            None,
+            "http://unused",
+            "https://github.com/owner",
+            Lang::En,
+        )
+        .await;
+        assert!(text.contains("Could not find an owner and repository"));
+    }
+}
@@ -0,0 +1,138 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::messages::{CommandText, ScheduleText, render, text};
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+pub(crate) async fn handle_collapse_prereleases(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    url: &str,
+    minutes: &str,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let minutes: i64 = minutes
+        .trim()
+        .parse()
+        .ok()
+        .filter(|m| *m >= 0)
+        .ok_or_else(|| text(lang, ScheduleText::CollapseInvalid).to_string())?;
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+
+    let repository = SqliteRepositorySettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(&tracked.id).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::LoadSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    settings.prerelease_collapse_secs = (minutes > 0).then_some(minutes * 60);
+    settings.updated_at = chrono::Utc::now();
+    repository.save(&settings).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::SaveSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    let repository = tracked.repository_name.as_str();
+    if minutes == 0 {
+        Ok(render(
+            lang,
+            ScheduleText::CollapseOff,
+            &[("repository", repository)],
+        ))
+    } else {
+        Ok(render(
+            lang,
+            ScheduleText::CollapseOn,
+            &[
+                ("repository", repository),
+                ("minutes", &minutes.to_string()),
+            ],
+        ))
+    }
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+    minutes: String,
+) -> ResponseResult<()> {
+    let text =
+        match handle_collapse_prereleases(&state.db, &state.bot_id, msg.chat.id.0, &url, &minutes)
+            .await
+        {
+            Ok(message) => message,
+            Err(err_msg) => err_msg,
+        };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn stores_and_clears_window() {
+        let db = setup_db().await;
+        let id = match handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+            .await
+            .unwrap()
+        {
+            HandleTrackResult::Created { id, .. } => id,
+            _ => panic!("expected Created"),
+        };
+        let repository = SqliteRepositorySettingsRepository::new(db.clone());
+
+        handle_collapse_prereleases(&db, "", 1, "https://github.com/owner/repo", "60")
+            .await
+            .expect("should succeed");
+        let settings = repository.find_or_default(&id).await.unwrap();
+        assert_eq!(settings.prerelease_collapse_secs, Some(3600));
+
+        handle_collapse_prereleases(&db, "", 1, "https://github.com/owner/repo", "0")
+            .await
+            .expect("should succeed");
+        let settings = repository.find_or_default(&id).await.unwrap();
+        assert_eq!(settings.prerelease_collapse_secs, None);
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_window() {
+        let db = setup_db().await;
+        let err = handle_collapse_prereleases(&db, "", 1, "https://github.com/owner/repo", "-5")
+            .await
+            .expect_err("negative window");
+        assert!(err.contains("minutes"));
+    }
+}
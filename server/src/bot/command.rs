@@ -0,0 +1,237 @@
+use teloxide::utils::command::BotCommands;
+
+use crate::bot::track;
+use crate::utils::{TELEGRAM_MESSAGE_LIMIT, split_message};
+
+/// The commands the bot answers, with the descriptions Telegram lists for them.
+#[derive(BotCommands, Clone)]
+#[command(
+    rename_rule = "snake_case",
+    description = "These commands are supported:"
+)]
+pub enum Command {
+    #[command(
+        description = "track a repository, or a crates.io or npm package: [name] <url>",
+        parse_with = track::parse_args
+    )]
+    Track { name: String, url: String },
+    #[command(description = "show how a URL would be tracked, without tracking it: <url>")]
+    Check(String),
+    #[command(description = "list all tracked repositories: [compact|verbose]")]
+    List(String),
+    #[command(description = "find tracked repositories by name or URL: <term>")]
+    Search(String),
+    #[command(description = "stop tracking every repository matching a glob: <pattern>")]
+    UntrackMatching(String),
+    #[command(description = "stop notifying about repositories matching a glob: <pattern>")]
+    MuteMatching(String),
+    #[command(description = "notify again about muted repositories matching a glob: <pattern>")]
+    UnmuteMatching(String),
+    #[command(
+        description = "add a fallback mirror for a tracked repository: <url> <mirror_url>",
+        parse_with = "split"
+    )]
+    Mirror { url: String, mirror_url: String },
+    #[command(
+        description = "get notified once a specific tag is published: <url> <tag>",
+        parse_with = "split"
+    )]
+    WatchTag { url: String, tag: String },
+    #[command(
+        description = "collapse prerelease notifications of the same version: <url> <minutes>",
+        parse_with = "split"
+    )]
+    CollapsePrereleases { url: String, minutes: String },
+    #[command(
+        description = "announce releases only once they are old enough: <url> <age, e.g. 30m; 0 disables>",
+        parse_with = "split"
+    )]
+    MinAge { url: String, age: String },
+    #[command(
+        description = "at most one notification per window, announcing the newest release: <url> <window, e.g. 6h; 0 disables>",
+        parse_with = "split"
+    )]
+    Throttle { url: String, window: String },
+    #[command(
+        description = "let requests for a slow repository take longer: <url> <timeout, e.g. 90s; 0 uses the default>",
+        parse_with = "split"
+    )]
+    Timeout { url: String, timeout: String },
+    #[command(
+        description = "follow git tags instead of GitHub Releases: <url> <on|off>",
+        parse_with = "split"
+    )]
+    TagsOnly { url: String, value: String },
+    #[command(
+        description = "notify on any tag change, even 1.2.3 to v1.2.3: <url> <on|off>",
+        parse_with = "split"
+    )]
+    ExactTags { url: String, value: String },
+    #[command(
+        description = "skip releases whose tag matches a glob: <url> <glob, e.g. *nightly*|off>",
+        parse_with = "split"
+    )]
+    IgnoreTags { url: String, pattern: String },
+    #[command(
+        description = "follow tags matching a regex and announce the version it captures: <url> <regex, e.g. ^release-(.+)$|off>",
+        parse_with = "split"
+    )]
+    TagCapture { url: String, pattern: String },
+    #[command(
+        description = "mention someone in a repository's release notifications: <url> <@username | user_id [name] | off>"
+    )]
+    Mention(String),
+    #[command(
+        description = "toggle whether a repository notifies even while muted or snoozed: <url>"
+    )]
+    Critical(String),
+    #[command(
+        description = "give a repository a short name to use instead of its URL: <url> <alias|off>",
+        parse_with = "split"
+    )]
+    Alias { url: String, alias: String },
+    #[command(
+        description = "copy a repository's settings to another chat, which tracks it with them: <url> <chat_id>",
+        parse_with = "split"
+    )]
+    Handoff { url: String, target_chat: String },
+    #[command(
+        description = "list the releases published after a date: <url> <date, e.g. 2024-05-01 or 30d>",
+        parse_with = "split"
+    )]
+    Since { url: String, date: String },
+    #[command(
+        description = "follow one component of a monorepo by its tag prefix: <url> <prefix, e.g. pkg-a/|off>",
+        parse_with = "split"
+    )]
+    Component { url: String, prefix: String },
+    #[command(
+        description = "announce new discussions of a category instead of releases: <url> <category slug|off>",
+        parse_with = "split"
+    )]
+    Discussions { url: String, category: String },
+    #[command(
+        description = "announce every N commits on the default branch instead of releases: <url> <N|off>",
+        parse_with = "split"
+    )]
+    Milestones { url: String, every: String },
+    #[command(
+        description = "pause notifications of a repository: <url> <duration, e.g. 7d or 2h; 0 resumes>",
+        parse_with = "split"
+    )]
+    Snooze { url: String, duration: String },
+    #[command(description = "pause every notification of this chat: <duration, e.g. 8h> or off")]
+    SnoozeAll(String),
+    #[command(description = "show when a repository will be checked next: <url>")]
+    Next(String),
+    #[command(description = "show a repository's latest release: <url> [--prerelease]")]
+    Latest(String),
+    #[command(description = "explain whether a repository's latest release notifies: <url>")]
+    Why(String),
+    #[command(description = "send the last release notification of a repository again: <url>")]
+    Resend(String),
+    #[command(description = "show the stored releases of a repository: <url>")]
+    History(String),
+    #[command(description = "show how often a repository released lately: <url>")]
+    Velocity(String),
+    #[command(description = "chart a repository's releases per week over 12 weeks: <url>")]
+    Chart(String),
+    #[command(description = "choose the message format: html or markdown")]
+    Format(String),
+    #[command(description = "choose the language of the bot's messages: en, it or de")]
+    Language(String),
+    #[command(
+        description = "custom release message, e.g. {name} {tag} is out, or clear to remove it"
+    )]
+    Template(String),
+    #[command(description = "show how the release message template renders")]
+    PreviewTemplate,
+    #[command(description = "text put before every notification, or clear to remove it")]
+    Prefix(String),
+    #[command(description = "text put below every notification, or clear to remove it")]
+    Suffix(String),
+    #[command(
+        description = "include release notes in notifications: on, off or dedupe (skip repeated notes)"
+    )]
+    Notes(String),
+    #[command(
+        description = "mirror release notifications to a webhook: slack <url>, discord <url> or off"
+    )]
+    Webhook(String),
+    #[command(description = "react to successful /track commands: on or off")]
+    Reactions(String),
+    #[command(description = "link repositories and releases in /list: on or off (plain text)")]
+    ListLinks(String),
+    #[command(description = "tell this chat when a latest release is deleted on GitHub: on or off")]
+    RemovedReleases(String),
+    #[command(description = "show the previous tag in notifications, as in v1.0 → v1.1: on or off")]
+    PreviousTag(String),
+    #[command(description = "pin the latest release notification in this chat: on or off")]
+    PinReleases(String),
+    #[command(
+        description = "announce the current release of repositories tracked from now on: on or off"
+    )]
+    FirstSeen(String),
+    #[command(description = "show what this chat tracks and whether it is snoozed")]
+    Status,
+    #[command(description = "show how many notifications this chat received recently")]
+    Stats,
+    #[command(description = "show notifications held back for this chat, or clear them: [clear]")]
+    Pending(String),
+    #[command(description = "list repositories without a release in the last <days> days")]
+    Stale(String),
+    #[command(description = "check that every tracked repository still exists on GitHub")]
+    Revalidate,
+    #[command(description = "REST API token of this chat: show or rotate")]
+    Token(String),
+    #[command(description = "show the remaining GitHub API quota")]
+    Ratelimit,
+    #[command(description = "show which GitHub credentials a repository is checked with: <url>")]
+    Authinfo(String),
+    #[command(description = "(admin) list the chats subscribed to a repository: <url>")]
+    Subscribers(String),
+    #[command(
+        description = "(admin) compare the repositories two chats track: <chat_id> <chat_id>",
+        parse_with = "split"
+    )]
+    CompareChats { a: String, b: String },
+    #[command(description = "(admin) change the log level until restart: debug, info or warn")]
+    Loglevel(String),
+    #[command(description = "(admin) show the configuration in effect, secrets redacted")]
+    Config,
+    #[command(description = "(admin) show what a poll now would announce, sending nothing")]
+    Simulate,
+    #[command(description = "(admin) show how text is escaped for HTML messages: <text>")]
+    EscapeTest(String),
+    #[command(description = "show the menu: list, add, settings and mute buttons")]
+    Menu,
+    #[command(description = "show the bot's version and uptime")]
+    Version,
+    #[command(description = "display this help message")]
+    Help,
+}
+
+/// The command list, after `intro` when there is one, split into messages Telegram accepts.
+pub(super) fn help_messages(intro: Option<&str>) -> Vec<String> {
+    let help = match intro {
+        Some(intro) => format!("{} \n\n{}", intro, Command::descriptions()),
+        None => Command::descriptions().to_string(),
+    };
+    split_message(&help, TELEGRAM_MESSAGE_LIMIT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_help_message_fits_telegram_limit() {
+        for intro in [None, Some("Only commands are supported.")] {
+            let messages = help_messages(intro);
+            assert!(!messages.is_empty());
+            for message in messages {
+                assert!(message.encode_utf16().count() <= TELEGRAM_MESSAGE_LIMIT);
+            }
+        }
+    }
+}
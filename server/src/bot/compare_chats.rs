@@ -0,0 +1,214 @@
+use std::collections::BTreeMap;
+
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::chat_settings::Lang;
+use crate::messages::{InfoText, render};
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::repository::{
+    SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+};
+use crate::utils::{TELEGRAM_MESSAGE_LIMIT, split_message};
+
+/// Repositories of two chats split by who tracks them, each group sorted by URL.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct ChatComparison {
+    pub both: Vec<String>,
+    pub only_a: Vec<String>,
+    pub only_b: Vec<String>,
+}
+
+fn by_url(repos: &[TrackedRelease]) -> BTreeMap<String, String> {
+    repos
+        .iter()
+        .map(|r| {
+            (
+                r.repository_url.url(),
+                format!("{} ({})", r.repository_name, r.repository_url.url()),
+            )
+        })
+        .collect()
+}
+
+pub(crate) fn compare(a: &[TrackedRelease], b: &[TrackedRelease]) -> ChatComparison {
+    let a = by_url(a);
+    let b = by_url(b);
+    let mut comparison = ChatComparison::default();
+    for (url, label) in &a {
+        if b.contains_key(url) {
+            comparison.both.push(label.clone());
+        } else {
+            comparison.only_a.push(label.clone());
+        }
+    }
+    comparison.only_b = b
+        .into_iter()
+        .filter(|(url, _)| !a.contains_key(url))
+        .map(|(_, label)| label)
+        .collect();
+    comparison
+}
+
+fn parse_chat_id(raw: &str, lang: Lang) -> Result<i64, String> {
+    raw.trim()
+        .parse()
+        .map_err(|_| render(lang, InfoText::NotAChatId, &[("input", raw.trim())]))
+}
+
+fn section(title: &str, labels: &[String], lang: Lang) -> String {
+    if labels.is_empty() {
+        return render(lang, InfoText::SectionEmpty, &[("title", title)]);
+    }
+    let lines: Vec<String> = labels.iter().map(|l| format!("- {l}")).collect();
+    format!("{title} ({}):\n{}", labels.len(), lines.join("\n"))
+}
+
+/// Reports which repositories two chats both track and which only one of them does.
+pub(crate) async fn handle_compare_chats(
+    db: &SqlitePool,
+    bot_id: &str,
+    a: &str,
+    b: &str,
+    lang: Lang,
+) -> Result<String, String> {
+    let a = parse_chat_id(a, lang)?;
+    let b = parse_chat_id(b, lang)?;
+
+    let repository = SqliteTrackedRepositoriesRepository::new(db.clone());
+    let load = async |chat_id: i64| {
+        repository
+            .find_all_by_chat_id(bot_id, chat_id)
+            .await
+            .map_err(|e| {
+                render(
+                    lang,
+                    InfoText::ChatListFailed,
+                    &[("chat", &chat_id.to_string()), ("error", &e.to_string())],
+                )
+            })
+    };
+    let comparison = compare(&load(a).await?, &load(b).await?);
+
+    let (a, b) = (a.to_string(), b.to_string());
+    let both = render(lang, InfoText::TrackedByBoth, &[("a", &a), ("b", &b)]);
+    let only_a = render(lang, InfoText::OnlyChat, &[("chat", &a)]);
+    let only_b = render(lang, InfoText::OnlyChat, &[("chat", &b)]);
+    Ok([
+        section(&both, &comparison.both, lang),
+        section(&only_a, &comparison.only_a, lang),
+        section(&only_b, &comparison.only_b, lang),
+    ]
+    .join("\n\n"))
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    a: String,
+    b: String,
+) -> ResponseResult<()> {
+    let lang = chat_language(&state.db, msg.chat.id.0).await;
+    let text = match handle_compare_chats(&state.db, &state.bot_id, &a, &b, lang).await {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    for page in split_message(&text, TELEGRAM_MESSAGE_LIMIT) {
+        bot.send_message(msg.chat.id, page).await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::handle_track;
+    use crate::tracked_repositories::RepositoryUrl;
+    use chrono::Utc;
+    use sqlx::sqlite::SqlitePoolOptions;
+    use uuid::Uuid;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    fn tracked(chat_id: i64, name: &str) -> TrackedRelease {
+        TrackedRelease {
+            id: Uuid::now_v7(),
+            repository_name: name.to_string(),
+            repository_url: RepositoryUrl::new(format!("https://github.com/owner/{name}")).unwrap(),
+            chat_id,
+            bot_id: String::new(),
+            default_branch: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn partitions_overlapping_repositories() {
+        let a = [tracked(1, "shared"), tracked(1, "alpha")];
+        let b = [tracked(2, "beta"), tracked(2, "shared")];
+
+        assert_eq!(
+            compare(&a, &b),
+            ChatComparison {
+                both: vec!["shared (https://github.com/owner/shared)".to_string()],
+                only_a: vec!["alpha (https://github.com/owner/alpha)".to_string()],
+                only_b: vec!["beta (https://github.com/owner/beta)".to_string()],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_each_chats_repositories() {
+        let db = setup_db().await;
+        handle_track(&db, "", 1, "alpha", "https://github.com/owner/alpha")
+            .await
+            .unwrap();
+        handle_track(&db, "", 2, "beta", "https://github.com/owner/beta")
+            .await
+            .unwrap();
+
+        let text = handle_compare_chats(&db, "", "1", "2", Lang::En)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            text,
+            "Tracked by both 1 and 2 (0): none\n\n\
+             Only 1 (1):\n- alpha (https://github.com/owner/alpha)\n\n\
+             Only 2 (1):\n- beta (https://github.com/owner/beta)"
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_chat_ids() {
+        let db = setup_db().await;
+
+        let err = handle_compare_chats(&db, "", "1", "team", Lang::En)
+            .await
+            .unwrap_err();
+        assert_eq!(err, "'team' is not a chat id.");
+
+        let err = handle_compare_chats(&db, "", "1", "team", Lang::It)
+            .await
+            .unwrap_err();
+        assert_eq!(err, "'team' non è un id di chat.");
+    }
+}
@@ -0,0 +1,137 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::messages::{CommandText, SettingText, render};
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+use crate::utils::MAX_TAG_CHARS;
+
+/// Follows only the releases and tags of a tracked repository that start with `prefix`,
+/// like `pkg-a/` in a monorepo tagging `pkg-a/v1.2.0`, or every tag again with `off`.
+pub(crate) async fn handle_component(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    url: &str,
+    prefix: &str,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let prefix = prefix.trim();
+    if prefix.is_empty() {
+        return Err(render(
+            lang,
+            CommandText::UsageExample,
+            &[
+                ("usage", "/component <url> <tag prefix|off>"),
+                ("example", "pkg-a/"),
+            ],
+        ));
+    }
+    if prefix.chars().count() > MAX_TAG_CHARS {
+        return Err(render(
+            lang,
+            CommandText::PrefixTooLong,
+            &[("max", &MAX_TAG_CHARS.to_string())],
+        ));
+    }
+    let tag_prefix = (!prefix.eq_ignore_ascii_case("off")).then(|| prefix.to_string());
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+
+    let repository = SqliteRepositorySettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(&tracked.id).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::LoadSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    settings.tag_prefix = tag_prefix;
+    settings.updated_at = chrono::Utc::now();
+    repository.save(&settings).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::SaveSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    let repository = tracked.repository_name.as_str();
+    match &settings.tag_prefix {
+        Some(prefix) => Ok(render(
+            lang,
+            SettingText::ComponentSet,
+            &[("repository", repository), ("prefix", prefix)],
+        )),
+        None => Ok(render(
+            lang,
+            SettingText::ComponentCleared,
+            &[("repository", repository)],
+        )),
+    }
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+    prefix: String,
+) -> ResponseResult<()> {
+    let text = match handle_component(&state.db, &state.bot_id, msg.chat.id.0, &url, &prefix).await
+    {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn sets_and_clears_the_component_prefix() {
+        let db = setup_db().await;
+        let url = "https://github.com/owner/monorepo";
+        let HandleTrackResult::Created { id, .. } =
+            handle_track(&db, "", 1, "monorepo", url).await.unwrap()
+        else {
+            panic!("expected Created");
+        };
+        let repository = SqliteRepositorySettingsRepository::new(db.clone());
+
+        handle_component(&db, "", 1, url, "pkg-a/").await.unwrap();
+        let settings = repository.find_or_default(&id).await.unwrap();
+        assert_eq!(settings.tag_prefix.as_deref(), Some("pkg-a/"));
+
+        handle_component(&db, "", 1, url, "off").await.unwrap();
+        let settings = repository.find_or_default(&id).await.unwrap();
+        assert_eq!(settings.tag_prefix, None);
+
+        assert!(handle_component(&db, "", 1, url, " ").await.is_err());
+        assert!(handle_component(&db, "", 2, url, "pkg-a/").await.is_err());
+    }
+}
@@ -0,0 +1,13 @@
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::github::github_api_base;
+
+pub(super) async fn answer(bot: &Bot, msg: &Message, state: &BotState) -> ResponseResult<()> {
+    let text = state
+        .config
+        .redacted_summary(&state.bot_id, &github_api_base());
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
@@ -0,0 +1,149 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::messages::{CommandText, SettingText, render};
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+/// Toggles whether releases of the repository notify even while it or the chat is muted,
+/// snoozed or otherwise held back.
+pub(crate) async fn handle_critical(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    url: &str,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let url = url.trim();
+    if url.is_empty() {
+        return Err(render(
+            lang,
+            CommandText::Usage,
+            &[("usage", "/critical <url>")],
+        ));
+    }
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+
+    let repository = SqliteRepositorySettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(&tracked.id).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::LoadSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    settings.critical = !settings.critical;
+    settings.updated_at = chrono::Utc::now();
+    repository.save(&settings).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::SaveSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    let key = if settings.critical {
+        SettingText::CriticalOn
+    } else {
+        SettingText::CriticalOff
+    };
+    Ok(render(
+        lang,
+        key,
+        &[("repository", &tracked.repository_name)],
+    ))
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+) -> ResponseResult<()> {
+    let text = match handle_critical(&state.db, &state.bot_id, msg.chat.id.0, &url).await {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use crate::chat_settings::Lang;
+    use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn toggles_the_critical_flag() {
+        let db = setup_db().await;
+        let id = match handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+            .await
+            .unwrap()
+        {
+            HandleTrackResult::Created { id, .. } => id,
+            _ => panic!("expected Created"),
+        };
+        let repository = SqliteRepositorySettingsRepository::new(db.clone());
+
+        let text = handle_critical(&db, "", 1, "https://github.com/owner/repo")
+            .await
+            .expect("should succeed");
+        assert!(text.starts_with("repo is critical"));
+        assert!(repository.find_or_default(&id).await.unwrap().critical);
+
+        handle_critical(&db, "", 1, "https://github.com/owner/repo")
+            .await
+            .expect("should succeed");
+        assert!(!repository.find_or_default(&id).await.unwrap().critical);
+
+        assert!(
+            handle_critical(&db, "", 2, "https://github.com/owner/repo")
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn errors_are_written_in_the_chats_language() {
+        let db = setup_db().await;
+        let chat_settings = SqliteChatSettingsRepository::new(db.clone());
+        let mut settings = chat_settings.find_or_default(2).await.unwrap();
+        settings.language = Lang::It;
+        chat_settings.save(&settings).await.unwrap();
+
+        let err = handle_critical(&db, "", 2, "https://github.com/owner/repo")
+            .await
+            .unwrap_err();
+        assert_eq!(err, "Questa chat non segue https://github.com/owner/repo.");
+        let usage = handle_critical(&db, "", 2, " ").await.unwrap_err();
+        assert_eq!(usage, "Uso: /critical <url>");
+
+        let err = handle_critical(&db, "", 1, "https://github.com/owner/repo")
+            .await
+            .unwrap_err();
+        assert!(err.starts_with("This chat is not tracking"));
+    }
+}
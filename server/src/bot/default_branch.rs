@@ -0,0 +1,94 @@
+use sqlx::sqlite::SqlitePool;
+
+use crate::github::fetch_repository_with_base;
+use crate::tracked_repositories::RepositoryUrl;
+use crate::tracked_repositories::repository::{
+    SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+};
+
+/// Asks GitHub for the default branch of a tracked repository and stores it, returning
+/// the branch. Repositories GitHub does not know keep whatever was stored before.
+pub(crate) async fn capture_default_branch(
+    db: &SqlitePool,
+    client: &reqwest::Client,
+    token_opt: Option<&str>,
+    base: &str,
+    tracked_repository_id: &uuid::Uuid,
+    url: &RepositoryUrl,
+) -> Result<Option<String>, String> {
+    let Some((owner, repo)) = url.owner_and_repo() else {
+        return Ok(None);
+    };
+    let Some(metadata) = fetch_repository_with_base(client, &owner, &repo, token_opt, base)
+        .await
+        .map_err(|e| format!("Failed to fetch {owner}/{repo}: {e}"))?
+    else {
+        return Ok(None);
+    };
+
+    SqliteTrackedRepositoriesRepository::new(db.clone())
+        .update_default_branch(tracked_repository_id, &metadata.default_branch)
+        .await
+        .map_err(|e| format!("Failed to save the default branch: {e}"))?;
+    Ok(Some(metadata.default_branch))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use mockito::Server;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn stores_the_default_branch() {
+        let db = setup_db().await;
+        let url = "https://github.com/owner/repo";
+        let id = match handle_track(&db, "", 1, "repo", url).await.unwrap() {
+            HandleTrackResult::Created { id, .. } => id,
+            _ => panic!("expected Created"),
+        };
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/repos/owner/repo")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!({"default_branch": "develop"}).to_string())
+            .create_async()
+            .await;
+
+        let branch = capture_default_branch(
+            &db,
+            &reqwest::Client::new(),
+            None,
+            &server.url(),
+            &id,
+            &RepositoryUrl::new(url.to_string()).unwrap(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(branch.as_deref(), Some("develop"));
+        let stored = SqliteTrackedRepositoriesRepository::new(db.clone())
+            .find_by_chat_id_and_repository_url("", 1, url)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.default_branch.as_deref(), Some("develop"));
+    }
+}
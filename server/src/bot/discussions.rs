@@ -0,0 +1,137 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::messages::{self, CommandText, SourceText, render};
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+pub(crate) async fn handle_discussions(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    url: &str,
+    category: &str,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let category = category.trim();
+    if category.is_empty() {
+        return Err(messages::text(lang, SourceText::DiscussionCategoryMissing).to_string());
+    }
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+
+    let repository = SqliteRepositorySettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(&tracked.id).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::LoadSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    settings.discussion_category = if category.eq_ignore_ascii_case("off") {
+        None
+    } else {
+        Some(category.to_string())
+    };
+    // Start over so switching categories does not announce an old discussion
+    settings.last_discussion_number = None;
+    settings.updated_at = chrono::Utc::now();
+    repository.save(&settings).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::SaveSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    let repository = tracked.repository_name.as_str();
+    match settings.discussion_category {
+        Some(category) => Ok(render(
+            lang,
+            SourceText::DiscussionsOn,
+            &[("repository", repository), ("category", &category)],
+        )),
+        None => Ok(render(
+            lang,
+            SourceText::FollowsReleases,
+            &[("repository", repository)],
+        )),
+    }
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+    category: String,
+) -> ResponseResult<()> {
+    let mut text =
+        match handle_discussions(&state.db, &state.bot_id, msg.chat.id.0, &url, &category).await {
+            Ok(message) => message,
+            Err(err_msg) => err_msg,
+        };
+    if state.config.resolve_token().token().is_none()
+        && !category.trim().eq_ignore_ascii_case("off")
+    {
+        let lang = chat_language(&state.db, msg.chat.id.0).await;
+        text.push('\n');
+        text.push_str(messages::text(lang, SourceText::DiscussionsNeedToken));
+    }
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn switches_between_discussions_and_releases() {
+        let db = setup_db().await;
+        let id = match handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+            .await
+            .unwrap()
+        {
+            HandleTrackResult::Created { id, .. } => id,
+            _ => panic!("expected Created"),
+        };
+        let repository = SqliteRepositorySettingsRepository::new(db.clone());
+
+        handle_discussions(&db, "", 1, "https://github.com/owner/repo", "announcements")
+            .await
+            .expect("should succeed");
+        let settings = repository.find_or_default(&id).await.unwrap();
+        assert_eq!(
+            settings.discussion_category.as_deref(),
+            Some("announcements")
+        );
+
+        handle_discussions(&db, "", 1, "https://github.com/owner/repo", "OFF")
+            .await
+            .expect("should succeed");
+        let settings = repository.find_or_default(&id).await.unwrap();
+        assert_eq!(settings.discussion_category, None);
+    }
+}
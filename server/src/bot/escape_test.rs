@@ -0,0 +1,67 @@
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+
+use crate::utils::html_escape;
+
+/// The text as given and as escaped for HTML messages.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct EscapeTest {
+    pub raw: String,
+    pub escaped: String,
+}
+
+impl EscapeTest {
+    /// Both forms, sent as plain text so Telegram shows the entities themselves.
+    pub(crate) fn summary(&self) -> String {
+        format!("Raw: {}\nEscaped: {}", self.raw, self.escaped)
+    }
+}
+
+pub(crate) fn handle_escape_test(text: &str) -> Result<EscapeTest, String> {
+    if text.is_empty() {
+        return Err("Usage: /escape_test <text>, e.g. /escape_test <b>&\"'".to_string());
+    }
+    Ok(EscapeTest {
+        raw: text.to_string(),
+        escaped: html_escape(text).into_owned(),
+    })
+}
+
+pub(super) async fn answer(bot: &Bot, msg: &Message, text: String) -> ResponseResult<()> {
+    let test = match handle_escape_test(&text) {
+        Ok(test) => test,
+        Err(err_msg) => {
+            bot.send_message(msg.chat.id, err_msg).await?;
+            return Ok(());
+        }
+    };
+    bot.send_message(msg.chat.id, test.summary()).await?;
+    // Rendered as HTML this should read exactly like the raw text
+    bot.send_message(msg.chat.id, test.escaped)
+        .parse_mode(ParseMode::Html)
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_the_raw_and_escaped_forms() {
+        let test = handle_escape_test("<b>&\"'").expect("should succeed");
+
+        assert_eq!(test.raw, "<b>&\"'");
+        assert_eq!(test.escaped, "&lt;b&gt;&amp;&quot;&#39;");
+        assert_eq!(
+            test.summary(),
+            "Raw: <b>&\"'\nEscaped: &lt;b&gt;&amp;&quot;&#39;"
+        );
+    }
+
+    #[test]
+    fn rejects_empty_text() {
+        assert!(handle_escape_test("").is_err());
+    }
+}
@@ -0,0 +1,124 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::messages::{CommandText, SourceText, render, text};
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+/// Switches between comparing tags exactly and treating tags that name the same version,
+/// like `1.2.3` and `v1.2.3`, as one release.
+pub(crate) async fn handle_exact_tags(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    url: &str,
+    value: &str,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let exact_tags = match value.trim().to_ascii_lowercase().as_str() {
+        "on" => true,
+        "off" => false,
+        _ => return Err(text(lang, CommandText::ChooseOnOrOff).to_string()),
+    };
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+
+    let repository = SqliteRepositorySettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(&tracked.id).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::LoadSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    settings.exact_tags = exact_tags;
+    settings.updated_at = chrono::Utc::now();
+    repository.save(&settings).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::SaveSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    let key = if exact_tags {
+        SourceText::ExactTagsOn
+    } else {
+        SourceText::ExactTagsOff
+    };
+    Ok(render(
+        lang,
+        key,
+        &[("repository", &tracked.repository_name)],
+    ))
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+    value: String,
+) -> ResponseResult<()> {
+    let text = match handle_exact_tags(&state.db, &state.bot_id, msg.chat.id.0, &url, &value).await
+    {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn toggles_exact_tag_comparison() {
+        let db = setup_db().await;
+        let id = match handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+            .await
+            .unwrap()
+        {
+            HandleTrackResult::Created { id, .. } => id,
+            _ => panic!("expected Created"),
+        };
+        let repository = SqliteRepositorySettingsRepository::new(db.clone());
+
+        handle_exact_tags(&db, "", 1, "https://github.com/owner/repo", "on")
+            .await
+            .expect("should succeed");
+        assert!(repository.find_or_default(&id).await.unwrap().exact_tags);
+
+        handle_exact_tags(&db, "", 1, "https://github.com/owner/repo", "OFF")
+            .await
+            .expect("should succeed");
+        assert!(!repository.find_or_default(&id).await.unwrap().exact_tags);
+
+        let err = handle_exact_tags(&db, "", 1, "https://github.com/owner/repo", "maybe")
+            .await
+            .expect_err("invalid value");
+        assert!(err.contains("on or off"));
+    }
+}
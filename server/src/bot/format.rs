@@ -0,0 +1,67 @@
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::chat_settings::{Lang, MessageFormat};
+use crate::messages::{CommandText, Text, render, text};
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    value: String,
+) -> ResponseResult<()> {
+    let repository = SqliteChatSettingsRepository::new(state.db.clone());
+    let mut settings = match repository.find_or_default(msg.chat.id.0).await {
+        Ok(s) => s,
+        Err(e) => {
+            bot.send_message(
+                msg.chat.id,
+                render(
+                    Lang::default(),
+                    CommandText::LoadChatSettingsFailed,
+                    &[("error", &e.to_string())],
+                ),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    if value.trim().is_empty() {
+        bot.send_message(
+            msg.chat.id,
+            render(
+                settings.language,
+                Text::FormatIs,
+                &[("format", settings.message_format.as_str())],
+            ),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let Some(format) = MessageFormat::parse(&value) else {
+        bot.send_message(msg.chat.id, text(settings.language, Text::UnknownFormat))
+            .await?;
+        return Ok(());
+    };
+
+    settings.message_format = format;
+    settings.updated_at = chrono::Utc::now();
+    let text = match repository.save(&settings).await {
+        Ok(()) => render(
+            settings.language,
+            Text::FormatSet,
+            &[("format", format.as_str())],
+        ),
+        Err(e) => render(
+            settings.language,
+            CommandText::SaveChatSettingsFailed,
+            &[("error", &e.to_string())],
+        ),
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
@@ -0,0 +1,352 @@
+use std::error::Error;
+
+use chrono::Utc;
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+use uuid::Uuid;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::chat_settings::Lang;
+use crate::configuration::ChatAccess;
+use crate::messages::{ReleaseText, render, text};
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::mirrors::repository::{
+    RepositoryMirrorsRepository, SqliteRepositoryMirrorsRepository,
+};
+use crate::tracked_repositories::repository::{
+    SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+};
+use crate::tracked_repositories::settings::RepositorySettings;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+/// Whether the sender may hand settings to the chat `target`: a configured admin, the
+/// user whose private chat it is, or an administrator of the target group or channel.
+async fn administers(bot: &Bot, msg: &Message, state: &BotState, target: i64) -> bool {
+    let Some(user) = msg.from.as_ref() else {
+        return false;
+    };
+    let user_id = user.id.0 as i64;
+    if state.config.is_admin(user_id) || user_id == target {
+        return true;
+    }
+    match bot.get_chat_member(ChatId(target), user.id).await {
+        Ok(member) => member.is_privileged(),
+        Err(e) => {
+            log::info!("Could not look up {} in chat {}: {}", user_id, target, e);
+            false
+        }
+    }
+}
+
+fn parse_target(lang: Lang, target: &str) -> Result<i64, String> {
+    target
+        .trim()
+        .parse()
+        .map_err(|_| text(lang, ReleaseText::TargetChatMissing).to_string())
+}
+
+/// Takes the configuration of `from` onto `onto`, keeping what the poller recorded for
+/// `onto` unless the discussion category or commit milestone it was recorded for changes.
+fn copy_settings(from: &RepositorySettings, onto: &mut RepositorySettings) {
+    if onto.discussion_category != from.discussion_category {
+        onto.last_discussion_number = None;
+    }
+    if onto.commit_milestone != from.commit_milestone {
+        onto.last_notified_count = None;
+    }
+    onto.prerelease_collapse_secs = from.prerelease_collapse_secs;
+    onto.snoozed_until = from.snoozed_until;
+    onto.tags_only = from.tags_only;
+    onto.exact_tags = from.exact_tags;
+    onto.muted = from.muted;
+    onto.discussion_category = from.discussion_category.clone();
+    onto.min_release_age_secs = from.min_release_age_secs;
+    onto.commit_milestone = from.commit_milestone;
+    onto.tag_ignore = from.tag_ignore.clone();
+    onto.tag_prefix = from.tag_prefix.clone();
+    onto.tag_capture = from.tag_capture.clone();
+    onto.throttle_window_secs = from.throttle_window_secs;
+    onto.mention = from.mention.clone();
+    onto.request_timeout_secs = from.request_timeout_secs;
+    onto.critical = from.critical;
+}
+
+/// Copies the chat's settings and mirrors for `url` to the chat `target`, which starts
+/// tracking the repository if it does not already. The chat's own tracking is left as it is.
+pub(crate) async fn handle_handoff(
+    db: &SqlitePool,
+    bot_id: &str,
+    access: &ChatAccess,
+    chat_id: i64,
+    url: &str,
+    target: i64,
+) -> Result<TrackedRelease, String> {
+    let lang = chat_language(db, chat_id).await;
+    let source = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+    if target == chat_id {
+        return Err(render(
+            lang,
+            ReleaseText::AlreadyInThisChat,
+            &[("repository", &source.repository_name)],
+        ));
+    }
+    if !access.permits(target) {
+        let chat = target.to_string();
+        return Err(render(lang, ReleaseText::ChatNotServed, &[("chat", &chat)]));
+    }
+
+    let name = ("repository", source.repository_name.as_str());
+    let failed = |e: Box<dyn Error + Send + Sync>| {
+        render(
+            lang,
+            ReleaseText::HandoffFailed,
+            &[name, ("error", &e.to_string())],
+        )
+    };
+    let repository = SqliteTrackedRepositoriesRepository::new(db.clone());
+    let existing = repository
+        .find_by_chat_id_and_repository_url(bot_id, target, &source.repository_url.url())
+        .await
+        .map_err(failed)?;
+    let copy = match existing {
+        Some(copy) => copy,
+        None => {
+            let now = Utc::now();
+            let mut copy = TrackedRelease {
+                id: Uuid::now_v7(),
+                chat_id: target,
+                created_at: now,
+                updated_at: now,
+                ..source.clone()
+            };
+            repository.save(&mut copy).await.map_err(failed)?;
+            copy
+        }
+    };
+
+    let settings_repo = SqliteRepositorySettingsRepository::new(db.clone());
+    let settings = settings_repo
+        .find_or_default(&source.id)
+        .await
+        .map_err(failed)?;
+    let mut copied = settings_repo
+        .find_or_default(&copy.id)
+        .await
+        .map_err(failed)?;
+    copy_settings(&settings, &mut copied);
+    settings_repo.save(&copied).await.map_err(failed)?;
+
+    let mirrors_repo = SqliteRepositoryMirrorsRepository::new(db.clone());
+    let mirrors = mirrors_repo
+        .find_by_tracked_repository_id(&source.id)
+        .await
+        .map_err(failed)?;
+    for mirror in mirrors {
+        mirrors_repo
+            .append(&copy.id, &mirror.repository_url)
+            .await
+            .map_err(failed)?;
+    }
+
+    Ok(copy)
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+    target: String,
+) -> ResponseResult<()> {
+    let lang = chat_language(&state.db, msg.chat.id.0).await;
+    let target = match parse_target(lang, &target) {
+        Ok(target) => target,
+        Err(err_msg) => {
+            bot.send_message(msg.chat.id, err_msg).await?;
+            return Ok(());
+        }
+    };
+    if !administers(bot, msg, state, target).await {
+        let chat = target.to_string();
+        let refusal = render(lang, ReleaseText::NotTargetAdmin, &[("chat", &chat)]);
+        bot.send_message(msg.chat.id, refusal).await?;
+        return Ok(());
+    }
+    let access = &state.config.chat_access;
+    let tracked = match handle_handoff(
+        &state.db,
+        &state.bot_id,
+        access,
+        msg.chat.id.0,
+        &url,
+        target,
+    )
+    .await
+    {
+        Ok(tracked) => tracked,
+        Err(err_msg) => {
+            bot.send_message(msg.chat.id, err_msg).await?;
+            return Ok(());
+        }
+    };
+
+    let name = ("repository", tracked.repository_name.as_str());
+    let target = tracked.chat_id.to_string();
+    let chat = ("chat", target.as_str());
+    let target_lang = chat_language(&state.db, tracked.chat_id).await;
+    let notice = render(
+        target_lang,
+        ReleaseText::HandedOverHere,
+        &[name, ("url", &tracked.repository_url.to_string())],
+    );
+    let text = match bot.send_message(ChatId(tracked.chat_id), notice).await {
+        Ok(_) => render(lang, ReleaseText::HandedOver, &[name, chat]),
+        Err(e) => {
+            log::info!(
+                "Could not tell chat {} about a handoff: {}",
+                tracked.chat_id,
+                e
+            );
+            render(lang, ReleaseText::HandedOverUnannounced, &[name, chat])
+        }
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::handle_track;
+    use crate::tracked_repositories::RepositoryUrl;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn settings_and_mirrors_are_copied_to_a_new_tracking() {
+        let db = setup_db().await;
+        let url = "https://github.com/owner/repo";
+        let id = handle_track(&db, "", 1, "repo", url).await.unwrap().id();
+        let settings_repo = SqliteRepositorySettingsRepository::new(db.clone());
+        let mut settings = settings_repo.find_or_default(&id).await.unwrap();
+        settings.tag_ignore = Some("*nightly*".to_string());
+        settings.critical = true;
+        settings_repo.save(&settings).await.unwrap();
+        let mirror = RepositoryUrl::new("https://github.com/fork/repo".to_string()).unwrap();
+        let mirrors_repo = SqliteRepositoryMirrorsRepository::new(db.clone());
+        mirrors_repo.append(&id, &mirror).await.unwrap();
+
+        let copy = handle_handoff(&db, "", &ChatAccess::default(), 1, url, -100200)
+            .await
+            .expect("should succeed");
+
+        assert_ne!(copy.id, id);
+        assert_eq!(copy.chat_id, -100200);
+        assert_eq!(copy.repository_url.url(), url);
+        let source = find_tracked_for_chat(&db, "", 1, url).await.unwrap();
+        assert_eq!(source.id, id);
+        let copied = settings_repo.find_or_default(&copy.id).await.unwrap();
+        assert_eq!(copied.tag_ignore.as_deref(), Some("*nightly*"));
+        assert!(copied.critical);
+        let settings = settings_repo.find_or_default(&id).await.unwrap();
+        assert!(settings.critical);
+        let mirrors = mirrors_repo
+            .find_by_tracked_repository_id(&copy.id)
+            .await
+            .unwrap();
+        assert_eq!(mirrors.len(), 1);
+        assert_eq!(mirrors[0].repository_url.url(), mirror.url());
+    }
+
+    #[tokio::test]
+    async fn settings_merge_into_the_target_chats_tracking() {
+        let db = setup_db().await;
+        let url = "https://github.com/owner/repo";
+        let id = handle_track(&db, "", 1, "repo", url).await.unwrap().id();
+        let source = find_tracked_for_chat(&db, "", 1, url).await.unwrap();
+        let mut target = TrackedRelease {
+            id: Uuid::now_v7(),
+            chat_id: 2,
+            ..source
+        };
+        SqliteTrackedRepositoriesRepository::new(db.clone())
+            .save(&mut target)
+            .await
+            .unwrap();
+        let target_id = target.id;
+        let settings_repo = SqliteRepositorySettingsRepository::new(db.clone());
+        let mut settings = settings_repo.find_or_default(&id).await.unwrap();
+        settings.muted = true;
+        settings.commit_milestone = Some(100);
+        settings_repo.save(&settings).await.unwrap();
+        let mut target = settings_repo.find_or_default(&target_id).await.unwrap();
+        target.tags_only = true;
+        target.last_notified_count = Some(500);
+        target.last_prerelease_base = Some("2.0.0".to_string());
+        settings_repo.save(&target).await.unwrap();
+
+        let copy = handle_handoff(&db, "", &ChatAccess::default(), 1, url, 2)
+            .await
+            .expect("a chat that already tracks the repository takes the settings");
+
+        assert_eq!(copy.id, target_id);
+        let repository = SqliteTrackedRepositoriesRepository::new(db.clone());
+        assert_eq!(
+            repository.find_all_by_chat_id("", 2).await.unwrap().len(),
+            1
+        );
+        let target = settings_repo.find_or_default(&target_id).await.unwrap();
+        assert!(target.muted);
+        assert!(!target.tags_only);
+        assert_eq!(target.commit_milestone, Some(100));
+        // Counted for no milestone before, so the poller starts over
+        assert_eq!(target.last_notified_count, None);
+        assert_eq!(target.last_prerelease_base.as_deref(), Some("2.0.0"));
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_and_refused_targets() {
+        let db = setup_db().await;
+        let url = "https://github.com/owner/repo";
+        handle_track(&db, "", 1, "repo", url).await.unwrap();
+        let access = ChatAccess {
+            allowed_chat_ids: Vec::new(),
+            denied_chat_ids: vec![3],
+        };
+
+        for target in ["", "team"] {
+            assert!(parse_target(Lang::En, target).is_err(), "{target}");
+        }
+        for target in [1, 3] {
+            assert!(
+                handle_handoff(&db, "", &access, 1, url, target)
+                    .await
+                    .is_err(),
+                "{target}"
+            );
+        }
+        // Only a chat tracking the repository has settings to hand over
+        assert!(handle_handoff(&db, "", &access, 2, url, 4).await.is_err());
+        let repository = SqliteTrackedRepositoriesRepository::new(db.clone());
+        assert_eq!(repository.find_all().await.unwrap().len(), 1);
+    }
+}
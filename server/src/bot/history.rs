@@ -0,0 +1,178 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::messages::{CommandText, ReleaseText, render, text};
+use crate::tracked_repositories::release_history::repository::{
+    ReleaseHistoryRepository, SqliteReleaseHistoryRepository,
+};
+use crate::utils::{MAX_TAG_CHARS, TELEGRAM_MESSAGE_LIMIT, split_message, truncate_chars};
+
+/// Releases shown by `/history`.
+const SHOWN_RELEASES: usize = 5;
+/// Characters of release notes shown per release.
+const NOTES_PREVIEW_CHARS: usize = 300;
+
+/// Lists the releases the poller stored for a repository, newest first, without asking
+/// GitHub.
+pub(crate) async fn handle_history(
+    db: &SqlitePool,
+    bot_id: &str,
+    history_limit: usize,
+    chat_id: i64,
+    url: &str,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    if history_limit == 0 {
+        return Err(text(lang, CommandText::HistoryOff).to_string());
+    }
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+
+    let entries = SqliteReleaseHistoryRepository::new(db.clone())
+        .find_recent(&tracked.id, SHOWN_RELEASES)
+        .await
+        .map_err(|e| {
+            render(
+                lang,
+                CommandText::HistoryFailed,
+                &[("error", &e.to_string())],
+            )
+        })?;
+    if entries.is_empty() {
+        return Ok(render(
+            lang,
+            CommandText::NoHistoryYet,
+            &[("repository", &tracked.repository_name)],
+        ));
+    }
+
+    let mut lines = vec![render(
+        lang,
+        ReleaseText::RecentReleases,
+        &[("repository", &tracked.repository_name)],
+    )];
+    for entry in entries {
+        let mut heading = truncate_chars(&entry.tag_name, MAX_TAG_CHARS).into_owned();
+        let published = entry.published_at.unwrap_or(entry.detected_at);
+        heading.push_str(&format!(" ({})", published.format("%Y-%m-%d")));
+        if let Some(author) = &entry.author {
+            heading.push(' ');
+            heading.push_str(&render(lang, ReleaseText::ByAuthor, &[("author", author)]));
+        }
+        lines.push(String::new());
+        lines.push(heading);
+        if let Some(link) = &entry.html_url {
+            lines.push(link.clone());
+        }
+        if let Some(body) = entry
+            .body
+            .as_deref()
+            .map(str::trim)
+            .filter(|b| !b.is_empty())
+        {
+            lines.push(truncate_chars(body, NOTES_PREVIEW_CHARS).into_owned());
+        }
+    }
+
+    Ok(lines.join("\n"))
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+) -> ResponseResult<()> {
+    let text = match handle_history(
+        &state.db,
+        &state.bot_id,
+        state.config.release_history_limit,
+        msg.chat.id.0,
+        &url,
+    )
+    .await
+    {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    for piece in split_message(&text, TELEGRAM_MESSAGE_LIMIT) {
+        bot.send_message(msg.chat.id, piece).await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use crate::tracked_repositories::release_history::ReleaseHistoryEntry;
+    use chrono::Utc;
+    use sqlx::sqlite::SqlitePoolOptions;
+    use uuid::Uuid;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn lists_stored_releases() {
+        let db = setup_db().await;
+        let id = match handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+            .await
+            .unwrap()
+        {
+            HandleTrackResult::Created { id, .. } => id,
+            _ => panic!("expected Created"),
+        };
+        let url = "https://github.com/owner/repo";
+
+        let empty = handle_history(&db, "", 10, 1, url).await.unwrap();
+        assert!(empty.contains("No releases"));
+
+        SqliteReleaseHistoryRepository::new(db.clone())
+            .save_all(
+                &[ReleaseHistoryEntry {
+                    id: Uuid::now_v7(),
+                    tracked_repository_id: id,
+                    tag_name: "v1.2.0".to_string(),
+                    body: Some("Fixed a bug".to_string()),
+                    html_url: Some(format!("{url}/releases/tag/v1.2.0")),
+                    published_at: None,
+                    author: Some("octocat".to_string()),
+                    detected_at: Utc::now(),
+                }],
+                10,
+            )
+            .await
+            .unwrap();
+
+        let text = handle_history(&db, "", 10, 1, url).await.unwrap();
+        assert!(text.contains("v1.2.0"));
+        assert!(text.contains("by octocat"));
+        assert!(text.contains("Fixed a bug"));
+    }
+
+    #[tokio::test]
+    async fn explains_when_history_is_disabled() {
+        let db = setup_db().await;
+
+        let err = handle_history(&db, "", 0, 1, "https://github.com/owner/repo")
+            .await
+            .expect_err("disabled");
+        assert!(err.contains("RELEASE_HISTORY_LIMIT"));
+    }
+}
@@ -0,0 +1,75 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::chat_settings::Lang;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::messages::{CommandText, Text, render, text};
+
+/// The language the chat's messages are written in; English when its settings cannot be
+/// read.
+pub(crate) async fn chat_language(db: &SqlitePool, chat_id: i64) -> Lang {
+    SqliteChatSettingsRepository::new(db.clone())
+        .find_or_default(chat_id)
+        .await
+        .map(|settings| settings.language)
+        .unwrap_or_default()
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    value: String,
+) -> ResponseResult<()> {
+    let repository = SqliteChatSettingsRepository::new(state.db.clone());
+    let mut settings = match repository.find_or_default(msg.chat.id.0).await {
+        Ok(s) => s,
+        Err(e) => {
+            bot.send_message(
+                msg.chat.id,
+                render(
+                    Lang::default(),
+                    CommandText::LoadChatSettingsFailed,
+                    &[("error", &e.to_string())],
+                ),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    if value.trim().is_empty() {
+        let language = settings.language;
+        bot.send_message(
+            msg.chat.id,
+            render(language, Text::LanguageIs, &[("language", language.name())]),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let Some(language) = Lang::parse(&value) else {
+        bot.send_message(msg.chat.id, text(settings.language, Text::UnknownLanguage))
+            .await?;
+        return Ok(());
+    };
+
+    settings.language = language;
+    settings.updated_at = chrono::Utc::now();
+    let reply = match repository.save(&settings).await {
+        Ok(()) => render(
+            language,
+            Text::LanguageSet,
+            &[("language", language.name())],
+        ),
+        Err(e) => render(
+            language,
+            CommandText::SaveChatSettingsFailed,
+            &[("error", &e.to_string())],
+        ),
+    };
+    bot.send_message(msg.chat.id, reply).await?;
+
+    Ok(())
+}
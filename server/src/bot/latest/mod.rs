@@ -0,0 +1,175 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::chat_settings::Lang;
+use crate::github::{HttpReleaseSource, fetch_newest_release_with_base, github_api_base};
+use crate::messages::{CommandText, StatusText, render, text};
+use crate::poller::fetch_latest_from_sources;
+use crate::tracked_repositories::mirrors::repository::{
+    RepositoryMirrorsRepository, SqliteRepositoryMirrorsRepository,
+};
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+/// Flag that includes prereleases in a single `/latest` lookup.
+const PRERELEASE_FLAG: &str = "--prerelease";
+
+/// Splits `/latest` arguments into the repository URL and whether prereleases are included.
+fn parse_args(lang: Lang, args: &str) -> Result<(&str, bool), String> {
+    let mut url = None;
+    let mut prerelease = false;
+    for arg in args.split_whitespace() {
+        if arg == PRERELEASE_FLAG {
+            prerelease = true;
+        } else if arg.starts_with("--") {
+            return Err(render(
+                lang,
+                StatusText::UnknownOption,
+                &[("option", arg), ("flag", PRERELEASE_FLAG)],
+            ));
+        } else if url.replace(arg).is_some() {
+            return Err(text(lang, StatusText::SingleUrl).to_string());
+        }
+    }
+    match url {
+        Some(url) => Ok((url, prerelease)),
+        None => Err(render(
+            lang,
+            CommandText::Usage,
+            &[("usage", &format!("/latest <url> [{PRERELEASE_FLAG}]"))],
+        )),
+    }
+}
+
+/// Looks up the newest release of a tracked repository the way the poller would, or with
+/// `--prerelease` the newest release including prereleases. Stored settings are left as
+/// they are either way.
+pub(crate) async fn handle_latest(
+    db: &SqlitePool,
+    bot_id: &str,
+    client: &reqwest::Client,
+    token_opt: Option<&str>,
+    github_base_override: Option<&str>,
+    chat_id: i64,
+    args: &str,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let (url, prerelease) = parse_args(lang, args)?;
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+    let settings = SqliteRepositorySettingsRepository::new(db.clone())
+        .find_or_default(&tracked.id)
+        .await
+        .map_err(|e| {
+            render(
+                lang,
+                CommandText::LoadSettingsFailed,
+                &[("error", &e.to_string())],
+            )
+        })?;
+    let name = tracked.repository_name.as_str();
+    let lookup_failed = |e: &dyn std::fmt::Display| {
+        render(
+            lang,
+            CommandText::LookupFailed,
+            &[("repository", name), ("error", &e.to_string())],
+        )
+    };
+    let base = github_base_override
+        .map(str::to_string)
+        .unwrap_or_else(github_api_base);
+
+    if !prerelease {
+        let mut sources = vec![tracked.repository_url.clone()];
+        if let Ok(mirrors) = SqliteRepositoryMirrorsRepository::new(db.clone())
+            .find_by_tracked_repository_id(&tracked.id)
+            .await
+        {
+            sources.extend(mirrors.into_iter().map(|m| m.repository_url));
+        }
+        let release_source = HttpReleaseSource::new(client.clone(), token_opt, base);
+        let latest = fetch_latest_from_sources(&release_source, &sources, &settings).await;
+        return match latest {
+            Ok(Some(latest)) => Ok(render(
+                lang,
+                StatusText::LatestRelease,
+                &[
+                    ("name", name),
+                    ("tag", &latest.tag),
+                    ("link", &latest.url()),
+                ],
+            )),
+            Ok(None) => Ok(render(lang, StatusText::NoReleaseOrTag, &[("name", name)])),
+            Err(e) => Err(lookup_failed(&e)),
+        };
+    }
+
+    if settings.tags_only {
+        return Err(render(
+            lang,
+            StatusText::TagsOnlyNoPrereleases,
+            &[("name", name)],
+        ));
+    }
+    let Some((owner, repo)) = tracked.repository_url.owner_and_repo() else {
+        return Err(render(
+            lang,
+            CommandText::NoOwnerAndRepository,
+            &[("url", url)],
+        ));
+    };
+    match fetch_newest_release_with_base(client, &owner, &repo, token_opt, &base, true).await {
+        Ok(Some(release)) => {
+            let link = release.details.html_url.unwrap_or_else(|| {
+                format!(
+                    "https://github.com/{owner}/{repo}/releases/tag/{}",
+                    release.tag_name
+                )
+            });
+            Ok(render(
+                lang,
+                StatusText::NewestPrerelease,
+                &[("name", name), ("tag", &release.tag_name), ("link", &link)],
+            ))
+        }
+        Ok(None) => Ok(render(
+            lang,
+            StatusText::NoPublishedReleases,
+            &[("name", name)],
+        )),
+        Err(e) => Err(lookup_failed(&e)),
+    }
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    args: String,
+) -> ResponseResult<()> {
+    let client = reqwest::Client::new();
+    let token_opt = state.config.resolve_token().token();
+    let text = match handle_latest(
+        &state.db,
+        &state.bot_id,
+        &client,
+        token_opt,
+        None,
+        msg.chat.id.0,
+        &args,
+    )
+    .await
+    {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,125 @@
+use super::*;
+use crate::bot::track::{HandleTrackResult, handle_track};
+use mockito::{Server, ServerGuard};
+use sqlx::sqlite::SqlitePoolOptions;
+
+async fn setup_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("failed to create in-memory sqlite pool");
+
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("failed to run migrations");
+
+    pool
+}
+
+async fn tracked_repo(db: &SqlitePool) -> uuid::Uuid {
+    match handle_track(db, "", 1, "repo", "https://github.com/owner/repo")
+        .await
+        .unwrap()
+    {
+        HandleTrackResult::Created { id, .. } => id,
+        _ => panic!("expected Created"),
+    }
+}
+
+async fn github() -> ServerGuard {
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v2.0.0"}).to_string())
+        .create_async()
+        .await;
+    server
+        .mock("GET", "/repos/owner/repo/releases?per_page=30")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                {"tag_name": "v2.1.0-rc.1", "prerelease": true,
+                 "html_url": "https://github.com/owner/repo/releases/tag/v2.1.0-rc.1"},
+                {"tag_name": "v2.0.0", "prerelease": false}
+            ])
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    server
+}
+
+#[tokio::test]
+async fn prerelease_flag_peeks_without_changing_settings() {
+    let db = setup_db().await;
+    let id = tracked_repo(&db).await;
+    let server = github().await;
+    let client = reqwest::Client::new();
+    let latest = async |args: &str| {
+        handle_latest(&db, "", &client, None, Some(&server.url()), 1, args).await
+    };
+
+    let stable = latest("https://github.com/owner/repo").await.unwrap();
+    assert!(stable.starts_with("Latest release of repo: v2.0.0"));
+
+    let peek = latest("--prerelease https://github.com/owner/repo")
+        .await
+        .unwrap();
+    assert_eq!(
+        peek,
+        "Newest release of repo, prereleases included: v2.1.0-rc.1\n\
+         https://github.com/owner/repo/releases/tag/v2.1.0-rc.1"
+    );
+
+    // Nothing was written to the repository's settings
+    let stored: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM tracked_repository_settings WHERE tracked_repository_id = ?1",
+    )
+    .bind(id.to_string())
+    .fetch_one(&db)
+    .await
+    .unwrap();
+    assert_eq!(stored, 0);
+}
+
+#[tokio::test]
+async fn prerelease_flag_is_refused_for_tags_only_repositories() {
+    let db = setup_db().await;
+    let id = tracked_repo(&db).await;
+    let repository = SqliteRepositorySettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(&id).await.unwrap();
+    settings.tags_only = true;
+    repository.save(&settings).await.unwrap();
+
+    let err = handle_latest(
+        &db,
+        "",
+        &reqwest::Client::new(),
+        None,
+        None,
+        1,
+        "https://github.com/owner/repo --prerelease",
+    )
+    .await
+    .unwrap_err();
+    assert!(err.contains("tags only"));
+}
+
+#[test]
+fn parses_url_and_flag_in_any_order() {
+    assert_eq!(
+        parse_args(Lang::En, "https://github.com/o/r --prerelease"),
+        Ok(("https://github.com/o/r", true))
+    );
+    assert_eq!(
+        parse_args(Lang::En, "https://github.com/o/r"),
+        Ok(("https://github.com/o/r", false))
+    );
+    assert!(parse_args(Lang::En, "--drafts https://github.com/o/r").is_err());
+    assert!(parse_args(Lang::En, "").is_err());
+}
@@ -0,0 +1,89 @@
+use chrono::{DateTime, Utc};
+
+use crate::chat_settings::Lang;
+use crate::messages::{ListText, render, text};
+use crate::tracked_repositories::settings::RepositorySettings;
+
+use super::format_snoozed;
+
+/// Describes the settings that differ from the defaults, or `None` if there are none.
+pub(in crate::bot) fn describe_settings(
+    settings: &RepositorySettings,
+    now: DateTime<Utc>,
+    lang: Lang,
+) -> Option<String> {
+    let minutes = |secs: i64| (secs / 60).to_string();
+    let mut parts = Vec::new();
+    if settings.critical {
+        parts.push(text(lang, ListText::Critical).to_string());
+    }
+    if settings.muted {
+        parts.push(text(lang, ListText::Muted).to_string());
+    }
+    if let Some(category) = &settings.discussion_category {
+        parts.push(render(
+            lang,
+            ListText::Discussions,
+            &[("category", category)],
+        ));
+    }
+    if let Some(every) = settings.commit_milestone {
+        let every = every.to_string();
+        parts.push(render(lang, ListText::EveryCommits, &[("every", &every)]));
+    }
+    if settings.tags_only {
+        parts.push(text(lang, ListText::TagsOnly).to_string());
+    }
+    if settings.exact_tags {
+        parts.push(text(lang, ListText::ExactTags).to_string());
+    }
+    if let Some(prefix) = &settings.tag_prefix {
+        parts.push(render(lang, ListText::Component, &[("prefix", prefix)]));
+    }
+    if let Some(pattern) = &settings.tag_capture {
+        parts.push(render(lang, ListText::CapturedBy, &[("pattern", pattern)]));
+    }
+    if let Some(pattern) = &settings.tag_ignore {
+        parts.push(render(
+            lang,
+            ListText::IgnoringTags,
+            &[("pattern", pattern)],
+        ));
+    }
+    if let Some(secs) = settings.prerelease_collapse_secs {
+        let minutes = minutes(secs);
+        parts.push(render(
+            lang,
+            ListText::PrereleasesCollapsed,
+            &[("minutes", &minutes)],
+        ));
+    }
+    if let Some(secs) = settings.min_release_age_secs {
+        let minutes = minutes(secs);
+        parts.push(render(lang, ListText::MinAge, &[("minutes", &minutes)]));
+    }
+    if let Some(mention) = &settings.mention {
+        let user = mention.as_stored();
+        parts.push(render(lang, ListText::Mentions, &[("user", &user)]));
+    }
+    if let Some(secs) = settings.throttle_window_secs {
+        let minutes = minutes(secs);
+        parts.push(render(lang, ListText::Throttled, &[("minutes", &minutes)]));
+    }
+    if let Some(until) = settings.snoozed_until.filter(|until| *until > now) {
+        parts.push(format_snoozed(until, lang));
+    }
+    if let Some(since) = settings.inaccessible_since {
+        let date = since.format("%Y-%m-%d").to_string();
+        parts.push(render(
+            lang,
+            ListText::InaccessibleSince,
+            &[("date", &date)],
+        ));
+    }
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(", "))
+    }
+}
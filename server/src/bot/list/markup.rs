@@ -0,0 +1,52 @@
+use std::borrow::Cow;
+
+use teloxide::types::ParseMode;
+
+use crate::chat_settings::MessageFormat;
+
+/// How `/list` marks up its lines: in the chat's message format, or as plain text
+/// without links for chats that turned them off with `/list_links off`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ListMarkup {
+    Formatted(MessageFormat),
+    Plain,
+}
+
+impl ListMarkup {
+    pub(super) fn new(format: MessageFormat, links: bool) -> Self {
+        if links {
+            Self::Formatted(format)
+        } else {
+            Self::Plain
+        }
+    }
+
+    pub(super) fn escape<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        match self {
+            Self::Formatted(format) => format.escape(text),
+            Self::Plain => Cow::Borrowed(text),
+        }
+    }
+
+    /// A link to `url` labelled `label`; plain text keeps only the label.
+    pub(super) fn link(&self, url: &str, label: &str) -> String {
+        match self {
+            Self::Formatted(format) => format.link(url, label),
+            Self::Plain => label.to_string(),
+        }
+    }
+
+    /// Parse mode the list is sent with; plain text is sent without one.
+    pub(super) fn parse_mode(&self) -> Option<ParseMode> {
+        match self {
+            Self::Formatted(format) => Some(format.parse_mode()),
+            Self::Plain => None,
+        }
+    }
+}
+
+impl From<MessageFormat> for ListMarkup {
+    fn from(format: MessageFormat) -> Self {
+        Self::Formatted(format)
+    }
+}
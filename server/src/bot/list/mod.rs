@@ -0,0 +1,292 @@
+use chrono::{DateTime, Utc};
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::chat_settings::{Lang, MessageFormat};
+use crate::messages::{CommandText, ListText, TrackingText, render, text};
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::repository::{
+    SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+};
+use crate::tracked_repositories::settings::RepositorySettings;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+use crate::tracked_repositories::tracked_repositories_releases::repository::{
+    CachedRepositoryReleasesRepository, SqliteCachedRepositoryReleasesRepository,
+};
+use crate::tracked_repositories::tracked_repositories_releases::{
+    CachedRepositoryRelease, FetchStatus,
+};
+use crate::utils::{
+    MAX_NAME_CHARS, MAX_TAG_CHARS, TELEGRAM_MESSAGE_LIMIT, split_message, truncate_chars,
+};
+use urlencoding::encode;
+
+mod describe;
+pub(super) mod links;
+mod markup;
+mod mode;
+
+pub(super) use describe::describe_settings;
+use markup::ListMarkup;
+pub(crate) use mode::ListMode;
+
+fn format_latest(
+    r: &TrackedRelease,
+    latest_tag: Option<&str>,
+    status: Option<FetchStatus>,
+    format: ListMarkup,
+    lang: Lang,
+) -> String {
+    let label = text(lang, ListText::Latest);
+    let latest = match latest_tag {
+        Some(full_tag) => {
+            let tag = truncate_chars(full_tag, MAX_TAG_CHARS);
+            if let Some((owner, repo)) = r.repository_url.owner_and_repo() {
+                let release_url = format!(
+                    "https://github.com/{}/{}/releases/tag/{}",
+                    owner,
+                    repo,
+                    encode(full_tag)
+                );
+                format!(
+                    "{} {}",
+                    format.escape(label),
+                    format.link(&release_url, &tag)
+                )
+            } else if let Some(package) = r.repository_url.package() {
+                format!(
+                    "{} {}",
+                    format.escape(label),
+                    format.link(&package.version_url(full_tag), &tag)
+                )
+            } else {
+                format.escape(&format!("{label} {tag}")).into_owned()
+            }
+        }
+        None => {
+            let key = match status {
+                Some(FetchStatus::NoReleases) => ListText::NoReleasesYet,
+                Some(FetchStatus::Failed) => ListText::CheckFailed,
+                Some(FetchStatus::Ok) | None => ListText::LatestUnknown,
+            };
+            return format.escape(text(lang, key)).into_owned();
+        }
+    };
+    if status == Some(FetchStatus::Failed) {
+        format!(
+            "{} {}",
+            latest,
+            format.escape(text(lang, ListText::LastCheckFailed))
+        )
+    } else {
+        latest
+    }
+}
+
+fn format_snoozed(until: DateTime<Utc>, lang: Lang) -> String {
+    let until = until.format("%Y-%m-%d %H:%M UTC").to_string();
+    render(lang, ListText::SnoozedUntil, &[("until", &until)])
+}
+
+fn format_compact_line(r: &TrackedRelease, format: ListMarkup) -> String {
+    format!(
+        "{} {}",
+        format.escape("-"),
+        format.escape(&truncate_chars(&r.repository_name, MAX_NAME_CHARS))
+    )
+}
+
+fn format_list_line(
+    r: &TrackedRelease,
+    latest_tag: Option<&str>,
+    status: Option<FetchStatus>,
+    snoozed_until: Option<DateTime<Utc>>,
+    critical: bool,
+    format: ListMarkup,
+    lang: Lang,
+) -> String {
+    let mut line = format!(
+        "{} {} {} {}",
+        format.escape("-"),
+        format.link(
+            &r.repository_url.to_string(),
+            &truncate_chars(&r.repository_name, MAX_NAME_CHARS)
+        ),
+        format.escape("-"),
+        format_latest(r, latest_tag, status, format, lang)
+    );
+    if critical {
+        let critical = format!(" ({})", text(lang, ListText::Critical));
+        line.push_str(&format.escape(&critical));
+    }
+    if let Some(until) = snoozed_until {
+        line.push_str(&format.escape(&format!(" ({})", format_snoozed(until, lang))));
+    }
+    line
+}
+
+fn format_verbose_entry(
+    r: &TrackedRelease,
+    cached: Option<&CachedRepositoryRelease>,
+    status: Option<FetchStatus>,
+    settings: Option<&RepositorySettings>,
+    now: DateTime<Utc>,
+    format: ListMarkup,
+    lang: Lang,
+) -> String {
+    let url = r.repository_url.to_string();
+    let tag = cached.map(|c| c.tag_name.as_str());
+    let mut latest = format_latest(r, tag, status, format, lang);
+    if let Some(c) = cached {
+        let date = c.first_seen_at.format("%Y-%m-%d").to_string();
+        let seen = format!(" {}", render(lang, ListText::Seen, &[("date", &date)]));
+        latest.push_str(&format.escape(&seen));
+    }
+    let settings = settings
+        .and_then(|s| describe_settings(s, now, lang))
+        .unwrap_or_else(|| text(lang, ListText::Defaults).to_string());
+
+    let location = match &r.default_branch {
+        Some(branch) => render(
+            lang,
+            ListText::DefaultBranch,
+            &[("url", &url), ("branch", branch)],
+        ),
+        None => url.clone(),
+    };
+
+    format!(
+        "{} {}\n  {}\n  {}\n  {}",
+        format.escape("-"),
+        format.link(&url, &truncate_chars(&r.repository_name, MAX_NAME_CHARS)),
+        format.escape(&location),
+        latest,
+        format.escape(&render(
+            lang,
+            ListText::Settings,
+            &[("settings", &settings)]
+        ))
+    )
+}
+
+/// Sends `repos` under `title`, rendered as `mode` and split across as many messages
+/// as needed.
+pub(super) async fn send_repository_list(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    title: &str,
+    repos: Vec<TrackedRelease>,
+    mode: ListMode,
+) -> ResponseResult<()> {
+    let (format, lang) = SqliteChatSettingsRepository::new(state.db.clone())
+        .find_or_default(msg.chat.id.0)
+        .await
+        .map(|s| (ListMarkup::new(s.message_format, s.list_links), s.language))
+        .unwrap_or_else(|_| (MessageFormat::default().into(), Lang::default()));
+    let mut lines: Vec<String> = Vec::with_capacity(repos.len());
+    let cache_repo = SqliteCachedRepositoryReleasesRepository::new(state.db.clone());
+    let settings_repo = SqliteRepositorySettingsRepository::new(state.db.clone());
+    let now = Utc::now();
+
+    for r in repos {
+        if mode == ListMode::Compact {
+            lines.push(format_compact_line(&r, format));
+            continue;
+        }
+        let cached = cache_repo
+            .find_by_tracked_release_id(&r.id)
+            .await
+            .ok()
+            .flatten();
+        let status = cache_repo.find_fetch_status(&r.id).await.ok().flatten();
+        let settings = settings_repo
+            .find_by_tracked_repository_id(&r.id)
+            .await
+            .ok()
+            .flatten();
+        let line = if mode == ListMode::Verbose {
+            format_verbose_entry(
+                &r,
+                cached.as_ref(),
+                status,
+                settings.as_ref(),
+                now,
+                format,
+                lang,
+            )
+        } else {
+            let snoozed_until = settings
+                .as_ref()
+                .and_then(|s| s.snoozed_until)
+                .filter(|until| *until > now);
+            format_list_line(
+                &r,
+                cached.as_ref().map(|c| c.tag_name.as_str()),
+                status,
+                snoozed_until,
+                settings.is_some_and(|s| s.critical),
+                format,
+                lang,
+            )
+        };
+        lines.push(line);
+    }
+    let text = format!("{}\n{}", format.escape(title), lines.join("\n"));
+    for chunk in split_message(&text, TELEGRAM_MESSAGE_LIMIT) {
+        let request = bot.send_message(msg.chat.id, chunk);
+        match format.parse_mode() {
+            Some(mode) => request.parse_mode(mode).await?,
+            None => request.await?,
+        };
+    }
+
+    Ok(())
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    mode: String,
+) -> ResponseResult<()> {
+    let lang = chat_language(&state.db, msg.chat.id.0).await;
+    let Some(mode) = ListMode::parse(&mode) else {
+        let usage = render(
+            lang,
+            CommandText::Usage,
+            &[("usage", "/list [compact|verbose]")],
+        );
+        bot.send_message(msg.chat.id, usage).await?;
+        return Ok(());
+    };
+
+    let repository = SqliteTrackedRepositoriesRepository::new(state.db.clone());
+    match repository
+        .find_all_by_chat_id(&state.bot_id, msg.chat.id.0)
+        .await
+    {
+        Ok(repos) => {
+            if repos.is_empty() {
+                bot.send_message(msg.chat.id, text(lang, TrackingText::NoRepositories))
+                    .await?;
+            } else {
+                let title = text(lang, TrackingText::TrackedRepositories);
+                send_repository_list(bot, msg, state, title, repos, mode).await?;
+            }
+        }
+        Err(e) => {
+            let error = render(lang, TrackingText::ListFailed, &[("error", &e.to_string())]);
+            bot.send_message(msg.chat.id, error).await?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
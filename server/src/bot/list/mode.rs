@@ -0,0 +1,22 @@
+/// How much detail `/list` shows per repository.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum ListMode {
+    /// Just the repository names.
+    Compact,
+    /// Name and latest tag, both linked.
+    #[default]
+    Standard,
+    /// Name, URL, latest tag, when it was first seen and the repository's settings.
+    Verbose,
+}
+
+impl ListMode {
+    pub(crate) fn parse(input: &str) -> Option<Self> {
+        match input.trim().to_ascii_lowercase().as_str() {
+            "" => Some(Self::Standard),
+            "compact" => Some(Self::Compact),
+            "verbose" => Some(Self::Verbose),
+            _ => None,
+        }
+    }
+}
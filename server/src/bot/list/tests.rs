@@ -0,0 +1,231 @@
+use super::*;
+use crate::tracked_repositories::RepositoryUrl;
+use uuid::Uuid;
+
+fn tracked() -> TrackedRelease {
+    TrackedRelease {
+        id: Uuid::now_v7(),
+        repository_name: "my_repo".to_string(),
+        repository_url: RepositoryUrl::new("https://github.com/owner/repo".to_string()).unwrap(),
+        chat_id: 1,
+        bot_id: String::new(),
+        default_branch: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+#[test]
+fn html_line_links_repo_and_release() {
+    let line = format_list_line(
+        &tracked(),
+        Some("v1.0"),
+        None,
+        None,
+        false,
+        MessageFormat::Html.into(),
+        Lang::En,
+    );
+    assert_eq!(
+        line,
+        "- <a href=\"https://github.com/owner/repo\">my_repo</a> - latest: \
+         <a href=\"https://github.com/owner/repo/releases/tag/v1.0\">v1.0</a>"
+    );
+}
+
+#[test]
+fn markdown_line_escapes_literals() {
+    let line = format_list_line(
+        &tracked(),
+        None,
+        None,
+        None,
+        false,
+        MessageFormat::MarkdownV2.into(),
+        Lang::En,
+    );
+    assert_eq!(
+        line,
+        "\\- [my\\_repo](https://github.com/owner/repo) \\- latest: unknown"
+    );
+}
+
+#[test]
+fn marks_snoozed_repository() {
+    let until = DateTime::from_timestamp(1700000000, 0).unwrap();
+    let line = format_list_line(
+        &tracked(),
+        None,
+        None,
+        Some(until),
+        false,
+        MessageFormat::MarkdownV2.into(),
+        Lang::En,
+    );
+    assert!(line.ends_with(" \\(snoozed until 2023\\-11\\-14 22:13 UTC\\)"));
+}
+
+#[test]
+fn marks_critical_repository() {
+    let line = format_list_line(
+        &tracked(),
+        Some("v1.0"),
+        None,
+        None,
+        true,
+        MessageFormat::Html.into(),
+        Lang::En,
+    );
+    assert!(line.ends_with("</a> (critical)"));
+}
+
+#[test]
+fn renders_the_line_in_the_chat_language() {
+    let until = DateTime::from_timestamp(1700000000, 0).unwrap();
+    let line = format_list_line(
+        &tracked(),
+        Some("v1.0"),
+        Some(FetchStatus::Failed),
+        Some(until),
+        true,
+        MessageFormat::Html.into(),
+        Lang::It,
+    );
+    assert_eq!(
+        line,
+        "- <a href=\"https://github.com/owner/repo\">my_repo</a> - ultima: \
+         <a href=\"https://github.com/owner/repo/releases/tag/v1.0\">v1.0</a> \
+         (ultimo controllo fallito) (critico) (in pausa fino al 2023-11-14 22:13 UTC)"
+    );
+
+    let mut settings = RepositorySettings::default_for(Uuid::now_v7());
+    settings.tags_only = true;
+    settings.muted = true;
+    assert_eq!(
+        describe_settings(&settings, until, Lang::De).as_deref(),
+        Some("stummgeschaltet, nur Tags")
+    );
+}
+
+#[test]
+fn parses_list_modes() {
+    assert_eq!(ListMode::parse(""), Some(ListMode::Standard));
+    assert_eq!(ListMode::parse("Compact"), Some(ListMode::Compact));
+    assert_eq!(ListMode::parse(" verbose "), Some(ListMode::Verbose));
+    assert_eq!(ListMode::parse("long"), None);
+}
+
+#[test]
+fn compact_line_has_only_the_name() {
+    let line = format_compact_line(&tracked(), MessageFormat::MarkdownV2.into());
+    assert_eq!(line, "\\- my\\_repo");
+}
+
+#[test]
+fn verbose_entry_lists_url_tag_date_and_settings() {
+    let r = tracked();
+    let now = DateTime::from_timestamp(1700000000, 0).unwrap();
+    let cached = CachedRepositoryRelease {
+        tracked_repository_id: r.id,
+        tag_name: "v1.0".to_string(),
+        first_seen_at: now,
+        body_hash: None,
+        from_release: true,
+    };
+    let mut settings = RepositorySettings::default_for(r.id);
+    settings.tags_only = true;
+    settings.prerelease_collapse_secs = Some(600);
+    settings.inaccessible_since = Some(now);
+
+    let entry = format_verbose_entry(
+        &r,
+        Some(&cached),
+        Some(FetchStatus::Ok),
+        Some(&settings),
+        now,
+        MessageFormat::Html.into(),
+        Lang::En,
+    );
+    assert_eq!(
+        entry,
+        "- <a href=\"https://github.com/owner/repo\">my_repo</a>\n  \
+         https://github.com/owner/repo\n  \
+         latest: <a href=\"https://github.com/owner/repo/releases/tag/v1.0\">v1.0</a> \
+         (seen 2023-11-14)\n  \
+         settings: tags only, prereleases collapsed within 10m, inaccessible since 2023-11-14"
+    );
+
+    let entry = format_verbose_entry(
+        &r,
+        None,
+        None,
+        None,
+        now,
+        MessageFormat::Html.into(),
+        Lang::En,
+    );
+    assert!(entry.ends_with("latest: unknown\n  settings: defaults"));
+
+    let r = TrackedRelease {
+        default_branch: Some("main".to_string()),
+        ..tracked()
+    };
+    let entry = format_verbose_entry(
+        &r,
+        None,
+        None,
+        None,
+        now,
+        MessageFormat::Html.into(),
+        Lang::En,
+    );
+    assert!(entry.contains("\n  https://github.com/owner/repo (default branch: main)\n"));
+}
+
+#[test]
+fn renders_each_fetch_status() {
+    let r = tracked();
+    let line = |tag, status| {
+        format_list_line(
+            &r,
+            tag,
+            status,
+            None,
+            false,
+            MessageFormat::Html.into(),
+            Lang::En,
+        )
+    };
+
+    assert!(line(None, None).ends_with("- latest: unknown"));
+    assert!(line(None, Some(FetchStatus::NoReleases)).ends_with("- no releases yet"));
+    assert!(line(None, Some(FetchStatus::Failed)).ends_with("- check failed"));
+    assert!(line(Some("v1.0"), Some(FetchStatus::Ok)).ends_with(">v1.0</a>"));
+    assert!(
+        line(Some("v1.0"), Some(FetchStatus::Failed)).ends_with(">v1.0</a> (last check failed)")
+    );
+}
+
+#[test]
+fn plain_list_has_no_links_and_no_parse_mode() {
+    let markup = ListMarkup::new(MessageFormat::Html, false);
+    let line = format_list_line(
+        &tracked(),
+        Some("v1.0"),
+        None,
+        None,
+        false,
+        markup,
+        Lang::En,
+    );
+    assert_eq!(line, "- my_repo - latest: v1.0");
+    assert!(!line.contains("<a"));
+    assert_eq!(markup.parse_mode(), None);
+
+    let entry = format_verbose_entry(&tracked(), None, None, None, Utc::now(), markup, Lang::En);
+    assert!(!entry.contains("<a"));
+    assert_eq!(
+        ListMarkup::new(MessageFormat::Html, true).parse_mode(),
+        Some(teloxide::types::ParseMode::Html)
+    );
+}
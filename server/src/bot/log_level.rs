@@ -0,0 +1,65 @@
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::chat_settings::Lang;
+use crate::logger::{RUNTIME_LEVELS, level, parse_runtime_level, set_level};
+use crate::messages::{InfoText, render};
+
+/// Shows the active log level, or switches it until the next restart.
+pub(crate) fn handle_log_level(value: &str, lang: Lang) -> Result<String, String> {
+    if value.trim().is_empty() {
+        return Ok(render(
+            lang,
+            InfoText::LogLevelIs,
+            &[
+                ("level", &level().as_str().to_ascii_lowercase()),
+                ("levels", &RUNTIME_LEVELS.join("|")),
+            ],
+        ));
+    }
+    let level = parse_runtime_level(value).ok_or_else(|| {
+        render(
+            lang,
+            InfoText::LogLevelUnknown,
+            &[("levels", &RUNTIME_LEVELS.join(", "))],
+        )
+    })?;
+    set_level(level);
+    log::warn!("Log level changed to {} at runtime", level);
+    Ok(render(
+        lang,
+        InfoText::LogLevelSet,
+        &[("level", &level.as_str().to_ascii_lowercase())],
+    ))
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    value: String,
+) -> ResponseResult<()> {
+    let lang = chat_language(&state.db, msg.chat.id.0).await;
+    let text = match handle_log_level(&value, lang) {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_unknown_levels() {
+        let err = handle_log_level("verbose", Lang::En).expect_err("invalid level");
+        assert!(err.contains("debug, info, warn"));
+
+        let err = handle_log_level("verbose", Lang::De).expect_err("invalid level");
+        assert!(err.starts_with("Unbekanntes Level."));
+    }
+}
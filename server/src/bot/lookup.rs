@@ -0,0 +1,74 @@
+use std::error::Error;
+
+use sqlx::sqlite::SqlitePool;
+
+use crate::bot::alias::parse_alias;
+use crate::bot::language::chat_language;
+use crate::messages::{CommandText, render};
+use crate::tracked_repositories::repository::{
+    SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+};
+use crate::tracked_repositories::{RepositoryUrl, TrackedRelease};
+
+/// Resolves a repository URL or alias given in a command to the row this bot tracks for
+/// the chat, with a user-facing error in the chat's language when it is invalid or not
+/// tracked here.
+pub(crate) async fn find_tracked_for_chat(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    url: &str,
+) -> Result<TrackedRelease, String> {
+    let repository = SqliteTrackedRepositoriesRepository::new(db.clone());
+    if let Some(alias) = parse_alias(url) {
+        let found = repository
+            .find_by_chat_id_and_alias(bot_id, chat_id, &alias)
+            .await;
+        return found_or_explain(
+            db,
+            chat_id,
+            found,
+            CommandText::UnknownAlias,
+            ("alias", &alias),
+        )
+        .await;
+    }
+    let repository_url = match RepositoryUrl::new(url.trim().to_string()) {
+        Ok(u) => u,
+        Err(e) => return Err(e.message(chat_language(db, chat_id).await)),
+    };
+
+    let found = repository
+        .find_by_chat_id_and_repository_url(bot_id, chat_id, &repository_url.url())
+        .await;
+    let shown = repository_url.to_string();
+    found_or_explain(
+        db,
+        chat_id,
+        found,
+        CommandText::NotTracking,
+        ("repository", &shown),
+    )
+    .await
+}
+
+/// The row `found`, or why there is none in the chat's language: `missing` filled in with
+/// `value`, or the query error.
+async fn found_or_explain(
+    db: &SqlitePool,
+    chat_id: i64,
+    found: Result<Option<TrackedRelease>, Box<dyn Error + Send + Sync>>,
+    missing: CommandText,
+    value: (&str, &str),
+) -> Result<TrackedRelease, String> {
+    let error = match found {
+        Ok(Some(tracked)) => return Ok(tracked),
+        Ok(None) => None,
+        Err(e) => Some(e.to_string()),
+    };
+    let lang = chat_language(db, chat_id).await;
+    Err(match error {
+        Some(e) => render(lang, CommandText::QueryFailed, &[("error", &e)]),
+        None => render(lang, missing, &[value]),
+    })
+}
@@ -0,0 +1,149 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::messages::{CommandText, SettingText, render};
+use crate::tracked_repositories::settings::Mention;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+const USAGE: &str = "/mention <url> <@username | user_id [name] | off>";
+
+/// Sets who release notifications of a tracked repository mention, or clears it with
+/// `off`. `args` is the URL followed by the mention.
+pub(crate) async fn handle_mention(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    args: &str,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let Some((url, value)) = args.trim().split_once(char::is_whitespace) else {
+        return Err(render(lang, CommandText::Usage, &[("usage", USAGE)]));
+    };
+    let value = value.trim();
+    let mention = if value.eq_ignore_ascii_case("off") {
+        None
+    } else {
+        Some(Mention::parse(value)?)
+    };
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+
+    let repository = SqliteRepositorySettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(&tracked.id).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::LoadSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    settings.mention = mention;
+    settings.updated_at = chrono::Utc::now();
+    repository.save(&settings).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::SaveSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    let repository = tracked.repository_name.as_str();
+    match &settings.mention {
+        Some(Mention::Username(username)) => Ok(render(
+            lang,
+            SettingText::MentionSet,
+            &[
+                ("repository", repository),
+                ("user", &format!("@{username}")),
+            ],
+        )),
+        Some(Mention::UserId { name, .. }) => Ok(render(
+            lang,
+            SettingText::MentionSet,
+            &[("repository", repository), ("user", name)],
+        )),
+        None => Ok(render(
+            lang,
+            SettingText::MentionCleared,
+            &[("repository", repository)],
+        )),
+    }
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    args: String,
+) -> ResponseResult<()> {
+    let text = match handle_mention(&state.db, &state.bot_id, msg.chat.id.0, &args).await {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn sets_and_clears_the_mention() {
+        let db = setup_db().await;
+        let id = match handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+            .await
+            .unwrap()
+        {
+            HandleTrackResult::Created { id, .. } => id,
+            _ => panic!("expected Created"),
+        };
+        let repository = SqliteRepositorySettingsRepository::new(db.clone());
+
+        let text = handle_mention(&db, "", 1, "https://github.com/owner/repo 42 Ada")
+            .await
+            .unwrap();
+        assert_eq!(text, "Release notifications of repo now mention Ada.");
+        assert_eq!(
+            repository.find_or_default(&id).await.unwrap().mention,
+            Some(Mention::UserId {
+                id: 42,
+                name: "Ada".to_string()
+            })
+        );
+
+        handle_mention(&db, "", 1, "https://github.com/owner/repo off")
+            .await
+            .unwrap();
+        assert_eq!(repository.find_or_default(&id).await.unwrap().mention, None);
+
+        for args in [
+            "",
+            "https://github.com/owner/repo",
+            "https://github.com/owner/repo @x",
+        ] {
+            assert!(handle_mention(&db, "", 1, args).await.is_err(), "{args}");
+        }
+    }
+}
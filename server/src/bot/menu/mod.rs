@@ -0,0 +1,291 @@
+mod states;
+mod summary;
+
+use std::sync::Arc;
+
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+use teloxide::types::{ForceReply, InlineKeyboardButton, InlineKeyboardMarkup};
+use uuid::Uuid;
+
+use crate::bot::language::chat_language;
+use crate::bot::{BotState, list, track};
+use crate::chat_settings::Lang;
+use crate::messages::{CommandText, MenuText, TrackingText, render, text};
+use crate::tracked_repositories::RepositoryUrl;
+use crate::tracked_repositories::repository::{
+    SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+};
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+pub use states::MenuStates;
+use summary::settings_summary;
+
+/// Repositories offered as mute buttons; Telegram caps the buttons of one keyboard.
+const MAX_MUTE_BUTTONS: usize = 30;
+const MENU_PREFIX: &str = "menu:";
+
+/// What a button of the menu asks for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum MenuAction {
+    List,
+    Add,
+    Settings,
+    Mutes,
+    ToggleMute(Uuid),
+    Back,
+}
+
+impl MenuAction {
+    fn data(&self) -> String {
+        let action = match self {
+            MenuAction::List => "list".to_string(),
+            MenuAction::Add => "add".to_string(),
+            MenuAction::Settings => "settings".to_string(),
+            MenuAction::Mutes => "mutes".to_string(),
+            MenuAction::ToggleMute(id) => format!("mute:{id}"),
+            MenuAction::Back => "back".to_string(),
+        };
+        format!("{MENU_PREFIX}{action}")
+    }
+
+    /// The action of a button's callback data, or `None` for buttons of other features.
+    pub(crate) fn parse(data: &str) -> Option<Self> {
+        match data.strip_prefix(MENU_PREFIX)? {
+            "list" => Some(MenuAction::List),
+            "add" => Some(MenuAction::Add),
+            "settings" => Some(MenuAction::Settings),
+            "mutes" => Some(MenuAction::Mutes),
+            "back" => Some(MenuAction::Back),
+            other => Uuid::parse_str(other.strip_prefix("mute:")?)
+                .ok()
+                .map(MenuAction::ToggleMute),
+        }
+    }
+
+    fn button(&self, label: impl Into<String>) -> InlineKeyboardButton {
+        InlineKeyboardButton::callback(label, self.data())
+    }
+}
+
+fn main_keyboard(lang: Lang) -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new(vec![
+        vec![
+            MenuAction::List.button(text(lang, MenuText::List)),
+            MenuAction::Add.button(text(lang, MenuText::Add)),
+        ],
+        vec![
+            MenuAction::Settings.button(text(lang, MenuText::Settings)),
+            MenuAction::Mutes.button(text(lang, MenuText::Mutes)),
+        ],
+    ])
+}
+
+fn back_keyboard(lang: Lang) -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new(vec![vec![
+        MenuAction::Back.button(text(lang, MenuText::Back)),
+    ]])
+}
+
+/// The chat's repositories as buttons that mute or unmute them.
+pub(crate) async fn mute_keyboard(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+) -> Result<(String, InlineKeyboardMarkup), String> {
+    let lang = chat_language(db, chat_id).await;
+    let repos = SqliteTrackedRepositoriesRepository::new(db.clone())
+        .find_all_by_chat_id(bot_id, chat_id)
+        .await
+        .map_err(|e| render(lang, TrackingText::ListFailed, &[("error", &e.to_string())]))?;
+    let settings_repo = SqliteRepositorySettingsRepository::new(db.clone());
+
+    let mut rows = Vec::new();
+    for r in repos.iter().take(MAX_MUTE_BUTTONS) {
+        let muted = settings_repo
+            .find_or_default(&r.id)
+            .await
+            .map_err(|e| {
+                render(
+                    lang,
+                    CommandText::LoadSettingsFailed,
+                    &[("error", &e.to_string())],
+                )
+            })?
+            .muted;
+        let icon = if muted { "🔇" } else { "🔔" };
+        rows.push(vec![
+            MenuAction::ToggleMute(r.id).button(format!("{icon} {}", r.repository_name)),
+        ]);
+    }
+    rows.push(vec![MenuAction::Back.button(text(lang, MenuText::Back))]);
+
+    let mut view = if repos.is_empty() {
+        text(lang, TrackingText::NoRepositories).to_string()
+    } else {
+        text(lang, MenuText::TapToMute).to_string()
+    };
+    if repos.len() > MAX_MUTE_BUTTONS {
+        let more = (repos.len() - MAX_MUTE_BUTTONS).to_string();
+        view.push('\n');
+        view.push_str(&render(
+            lang,
+            MenuText::MoreThanButtons,
+            &[("count", &more)],
+        ));
+    }
+    Ok((view, InlineKeyboardMarkup::new(rows)))
+}
+
+/// Mutes or unmutes one of the chat's repositories, returning whether it is now muted.
+pub(crate) async fn handle_toggle_mute(
+    db: &SqlitePool,
+    chat_id: i64,
+    id: &Uuid,
+) -> Result<bool, String> {
+    let lang = chat_language(db, chat_id).await;
+    let tracked = SqliteTrackedRepositoriesRepository::new(db.clone())
+        .find_by_id(&id.to_string())
+        .await
+        .map_err(|e| {
+            render(
+                lang,
+                MenuText::LoadRepositoryFailed,
+                &[("error", &e.to_string())],
+            )
+        })?
+        .filter(|r| r.chat_id == chat_id)
+        .ok_or_else(|| text(lang, MenuText::NoLongerTracked).to_string())?;
+
+    let repository = SqliteRepositorySettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(&tracked.id).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::LoadSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    settings.muted = !settings.muted;
+    settings.updated_at = chrono::Utc::now();
+    repository.save(&settings).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::SaveSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    Ok(settings.muted)
+}
+
+pub(super) async fn answer(bot: &Bot, msg: &Message, state: &BotState) -> ResponseResult<()> {
+    let lang = chat_language(&state.db, msg.chat.id.0).await;
+    bot.send_message(msg.chat.id, text(lang, MenuText::Prompt))
+        .reply_markup(main_keyboard(lang))
+        .await?;
+
+    Ok(())
+}
+
+/// Whether `q` was sent by a button of the menu.
+pub(super) fn is_menu_callback(q: &CallbackQuery) -> bool {
+    q.data.as_deref().and_then(MenuAction::parse).is_some()
+}
+
+/// Handles presses of the menu's buttons.
+pub(super) async fn callback(
+    bot: Bot,
+    q: CallbackQuery,
+    state: Arc<BotState>,
+) -> ResponseResult<()> {
+    bot.answer_callback_query(q.id.clone()).await?;
+    let action = q.data.as_deref().and_then(MenuAction::parse);
+    let (Some(action), Some(message)) = (action, q.message.as_ref()) else {
+        return Ok(());
+    };
+    let chat_id = message.chat().id;
+    let lang = chat_language(&state.db, chat_id.0).await;
+
+    let (view, keyboard) = match action {
+        MenuAction::List => {
+            if let Some(message) = message.regular_message() {
+                list::answer(&bot, message, &state, String::new()).await?;
+            }
+            return Ok(());
+        }
+        MenuAction::Add => {
+            let prompt = bot
+                .send_message(chat_id, text(lang, MenuText::AddPrompt))
+                .reply_markup(
+                    ForceReply::new()
+                        .input_field_placeholder(Some("https://github.com/owner/repo".to_string())),
+                )
+                .await?;
+            state.menus.await_url(chat_id.0, prompt.id.0);
+            return Ok(());
+        }
+        MenuAction::Settings => match settings_summary(&state.db, chat_id.0).await {
+            Ok(summary) => (summary, back_keyboard(lang)),
+            Err(err_msg) => (err_msg, back_keyboard(lang)),
+        },
+        MenuAction::Mutes => match mute_keyboard(&state.db, &state.bot_id, chat_id.0).await {
+            Ok(view) => view,
+            Err(err_msg) => (err_msg, back_keyboard(lang)),
+        },
+        MenuAction::ToggleMute(id) => match handle_toggle_mute(&state.db, chat_id.0, &id).await {
+            Ok(_) => match mute_keyboard(&state.db, &state.bot_id, chat_id.0).await {
+                Ok(view) => view,
+                Err(err_msg) => (err_msg, back_keyboard(lang)),
+            },
+            Err(err_msg) => (err_msg, back_keyboard(lang)),
+        },
+        MenuAction::Back => (
+            text(lang, MenuText::Prompt).to_string(),
+            main_keyboard(lang),
+        ),
+    };
+    bot.edit_message_text(chat_id, message.id(), view)
+        .reply_markup(keyboard)
+        .await?;
+
+    Ok(())
+}
+
+/// Tracks the URL sent in reply to the Add prompt, returning whether `msg` was such a
+/// reply.
+pub(super) async fn answer_reply(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+) -> ResponseResult<bool> {
+    let (Some(replied_to), Some(reply)) = (msg.reply_to_message(), msg.text()) else {
+        return Ok(false);
+    };
+    if !state.menus.take_reply(msg.chat.id.0, replied_to.id.0) {
+        return Ok(false);
+    }
+
+    let url = reply.trim().to_string();
+    let name = match RepositoryUrl::new(url.clone()) {
+        Ok(repository_url) => track::default_name(&repository_url),
+        Err(e) => {
+            let lang = chat_language(&state.db, msg.chat.id.0).await;
+            bot.send_message(msg.chat.id, e.message(lang)).await?;
+            return Ok(true);
+        }
+    };
+    match name {
+        Some(name) => track::answer(bot, msg, state, name, url).await?,
+        None => {
+            let lang = chat_language(&state.db, msg.chat.id.0).await;
+            bot.send_message(msg.chat.id, text(lang, MenuText::UrlMissing))
+                .await?;
+        }
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A URL asked for by the Add button is only taken this long after the prompt.
+const PROMPT_TTL: Duration = Duration::from_secs(10 * 60);
+
+struct AwaitingUrl {
+    prompt_message_id: i32,
+    created_at: Instant,
+}
+
+/// Chats asked by the Add button for the URL to track, and the prompt the answer must
+/// reply to.
+#[derive(Default)]
+pub struct MenuStates(Mutex<HashMap<i64, AwaitingUrl>>);
+
+impl MenuStates {
+    pub(super) fn await_url(&self, chat_id: i64, prompt_message_id: i32) {
+        if let Ok(mut states) = self.0.lock() {
+            states.retain(|_, s| s.created_at.elapsed() < PROMPT_TTL);
+            states.insert(
+                chat_id,
+                AwaitingUrl {
+                    prompt_message_id,
+                    created_at: Instant::now(),
+                },
+            );
+        }
+    }
+
+    /// Whether a message replying to `replied_to` answers the chat's open prompt, which
+    /// is then closed.
+    pub(super) fn take_reply(&self, chat_id: i64, replied_to: i32) -> bool {
+        let Ok(mut states) = self.0.lock() else {
+            return false;
+        };
+        match states.get(&chat_id) {
+            Some(s) if s.prompt_message_id == replied_to && s.created_at.elapsed() < PROMPT_TTL => {
+                states.remove(&chat_id);
+                true
+            }
+            _ => false,
+        }
+    }
+}
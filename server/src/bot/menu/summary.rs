@@ -0,0 +1,42 @@
+use sqlx::sqlite::SqlitePool;
+
+use crate::chat_settings::Lang;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::messages::{CommandText, MenuText, render, text};
+
+/// The chat's settings as shown by the Settings button.
+pub(crate) async fn settings_summary(db: &SqlitePool, chat_id: i64) -> Result<String, String> {
+    let settings = SqliteChatSettingsRepository::new(db.clone())
+        .find_or_default(chat_id)
+        .await
+        .map_err(|e| {
+            render(
+                Lang::default(),
+                CommandText::LoadChatSettingsFailed,
+                &[("error", &e.to_string())],
+            )
+        })?;
+    let lang = settings.language;
+    let on_or_off = |value: bool| if value { "on" } else { "off" };
+    let line = |key: MenuText, value: &str| render(lang, key, &[("value", value)]);
+
+    Ok([
+        text(lang, MenuText::SettingsHeading).to_string(),
+        line(MenuText::Format, settings.message_format.as_str()),
+        line(MenuText::Language, settings.language.name()),
+        line(MenuText::Notes, settings.release_notes.as_str()),
+        line(MenuText::ListLinks, on_or_off(settings.list_links)),
+        line(MenuText::PreviousTag, on_or_off(settings.show_previous_tag)),
+        line(
+            MenuText::FirstSeen,
+            on_or_off(settings.notify_on_first_seen),
+        ),
+        line(MenuText::Pin, on_or_off(settings.pin_releases)),
+        line(
+            MenuText::RemovedReleases,
+            on_or_off(settings.notify_removed_releases),
+        ),
+        line(MenuText::Reactions, on_or_off(settings.track_reactions)),
+    ]
+    .join("\n"))
+}
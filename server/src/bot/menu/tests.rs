@@ -0,0 +1,80 @@
+use super::*;
+use crate::bot::track::{HandleTrackResult, handle_track};
+use sqlx::sqlite::SqlitePoolOptions;
+
+async fn setup_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("failed to create in-memory sqlite pool");
+
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("failed to run migrations");
+
+    pool
+}
+
+async fn track(db: &SqlitePool, chat_id: i64, name: &str) -> Uuid {
+    let url = format!("https://github.com/owner/{name}");
+    match handle_track(db, "", chat_id, name, &url).await.unwrap() {
+        HandleTrackResult::Created { id, .. } => id,
+        _ => panic!("expected Created"),
+    }
+}
+
+#[test]
+fn callback_data_routes_to_its_action() {
+    let id = Uuid::now_v7();
+    for action in [
+        MenuAction::List,
+        MenuAction::Add,
+        MenuAction::Settings,
+        MenuAction::Mutes,
+        MenuAction::ToggleMute(id),
+        MenuAction::Back,
+    ] {
+        let data = action.data();
+        assert!(data.len() <= 64, "{data}");
+        assert_eq!(MenuAction::parse(&data), Some(action));
+    }
+
+    // Buttons of other features are left to their own handlers
+    assert_eq!(MenuAction::parse(&format!("untrack:{id}")), None);
+    assert_eq!(MenuAction::parse("menu:unknown"), None);
+    assert_eq!(MenuAction::parse("menu:mute:not-a-uuid"), None);
+}
+
+#[test]
+fn only_a_reply_to_the_open_prompt_is_taken() {
+    let states = MenuStates::default();
+    states.await_url(1, 10);
+
+    assert!(!states.take_reply(2, 10));
+    assert!(!states.take_reply(1, 11));
+    assert!(states.take_reply(1, 10));
+    assert!(!states.take_reply(1, 10));
+}
+
+#[tokio::test]
+async fn toggles_mute_of_the_chats_own_repositories() {
+    let db = setup_db().await;
+    let id = track(&db, 1, "repo").await;
+
+    assert_eq!(handle_toggle_mute(&db, 1, &id).await, Ok(true));
+    let (_, keyboard) = mute_keyboard(&db, "", 1).await.unwrap();
+    assert_eq!(keyboard.inline_keyboard[0][0].text, "🔇 repo");
+
+    assert_eq!(handle_toggle_mute(&db, 1, &id).await, Ok(false));
+    assert!(handle_toggle_mute(&db, 2, &id).await.is_err());
+}
+
+#[tokio::test]
+async fn settings_summary_shows_the_chat_settings() {
+    let db = setup_db().await;
+    let summary = settings_summary(&db, 1).await.unwrap();
+    assert!(summary.contains("Format: html (/format)"));
+    assert!(summary.contains("Previous tag: on"));
+}
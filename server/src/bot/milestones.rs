@@ -0,0 +1,160 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::messages::{CommandText, SourceText, render, text};
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+pub(crate) async fn handle_milestones(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    url: &str,
+    every: &str,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let every = every.trim();
+    let every = if every.eq_ignore_ascii_case("off") {
+        None
+    } else {
+        Some(
+            every
+                .parse::<i64>()
+                .ok()
+                .filter(|n| *n > 0)
+                .ok_or_else(|| text(lang, SourceText::MilestoneInvalid).to_string())?,
+        )
+    };
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+
+    let repository = SqliteRepositorySettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(&tracked.id).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::LoadSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    settings.commit_milestone = every;
+    // Start over so the next poll sets a fresh baseline instead of announcing old milestones
+    settings.last_notified_count = None;
+    settings.updated_at = chrono::Utc::now();
+    repository.save(&settings).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::SaveSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    let branch = tracked
+        .default_branch
+        .as_deref()
+        .unwrap_or(text(lang, SourceText::DefaultBranch));
+    let repository = tracked.repository_name.as_str();
+    match every {
+        Some(every) => Ok(render(
+            lang,
+            SourceText::MilestonesOn,
+            &[
+                ("repository", repository),
+                ("every", &every.to_string()),
+                ("branch", branch),
+            ],
+        )),
+        None => Ok(render(
+            lang,
+            SourceText::FollowsReleases,
+            &[("repository", repository)],
+        )),
+    }
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+    every: String,
+) -> ResponseResult<()> {
+    let text = match handle_milestones(&state.db, &state.bot_id, msg.chat.id.0, &url, &every).await
+    {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn switches_to_milestones_and_back() {
+        let db = setup_db().await;
+        let id = match handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+            .await
+            .unwrap()
+        {
+            HandleTrackResult::Created { id, .. } => id,
+            _ => panic!("expected Created"),
+        };
+        let repository = SqliteRepositorySettingsRepository::new(db.clone());
+        let mut settings = repository.find_or_default(&id).await.unwrap();
+        settings.last_notified_count = Some(500);
+        repository.save(&settings).await.unwrap();
+
+        let text = handle_milestones(&db, "", 1, "https://github.com/owner/repo", "100")
+            .await
+            .unwrap();
+        assert_eq!(
+            text,
+            "repo now announces every 100 commits on the default branch instead of releases."
+        );
+        let settings = repository.find_or_default(&id).await.unwrap();
+        assert_eq!(settings.commit_milestone, Some(100));
+        assert_eq!(settings.last_notified_count, None);
+
+        handle_milestones(&db, "", 1, "https://github.com/owner/repo", "off")
+            .await
+            .unwrap();
+        let settings = repository.find_or_default(&id).await.unwrap();
+        assert_eq!(settings.commit_milestone, None);
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_steps() {
+        let db = setup_db().await;
+        for every in ["0", "-100", "lots", ""] {
+            assert!(
+                handle_milestones(&db, "", 1, "https://github.com/owner/repo", every)
+                    .await
+                    .is_err(),
+                "{every}"
+            );
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::messages::{CommandText, ScheduleText, render, text};
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+use crate::utils::parse_duration;
+
+/// Longest minimum age accepted, so a typo cannot hold releases back for years.
+const MAX_MIN_AGE_SECS: u64 = 30 * 24 * 60 * 60;
+
+pub(crate) async fn handle_min_age(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    url: &str,
+    age: &str,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let secs = parse_duration(age).map_err(|e| e.message(lang))?.as_secs();
+    if secs != 0 && !(60..=MAX_MIN_AGE_SECS).contains(&secs) {
+        return Err(text(lang, ScheduleText::MinAgeInvalid).to_string());
+    }
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+
+    let repository = SqliteRepositorySettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(&tracked.id).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::LoadSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    settings.min_release_age_secs = (secs > 0).then_some(secs as i64);
+    if secs == 0 {
+        settings.young_release_tag = None;
+        settings.young_release_seen_at = None;
+    }
+    settings.updated_at = chrono::Utc::now();
+    repository.save(&settings).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::SaveSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    let repository = tracked.repository_name.as_str();
+    if secs == 0 {
+        Ok(render(
+            lang,
+            ScheduleText::MinAgeOff,
+            &[("repository", repository)],
+        ))
+    } else {
+        Ok(render(
+            lang,
+            ScheduleText::MinAgeOn,
+            &[
+                ("repository", repository),
+                ("minutes", &(secs / 60).to_string()),
+            ],
+        ))
+    }
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+    age: String,
+) -> ResponseResult<()> {
+    let text = match handle_min_age(&state.db, &state.bot_id, msg.chat.id.0, &url, &age).await {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn stores_and_clears_the_minimum_age() {
+        let db = setup_db().await;
+        let id = match handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+            .await
+            .unwrap()
+        {
+            HandleTrackResult::Created { id, .. } => id,
+            _ => panic!("expected Created"),
+        };
+        let repository = SqliteRepositorySettingsRepository::new(db.clone());
+
+        let text = handle_min_age(&db, "", 1, "https://github.com/owner/repo", "2h")
+            .await
+            .expect("should succeed");
+        assert_eq!(
+            text,
+            "New releases of repo will be announced once they are 120 minutes old."
+        );
+        let settings = repository.find_or_default(&id).await.unwrap();
+        assert_eq!(settings.min_release_age_secs, Some(7200));
+
+        handle_min_age(&db, "", 1, "https://github.com/owner/repo", "0")
+            .await
+            .expect("should succeed");
+        let settings = repository.find_or_default(&id).await.unwrap();
+        assert_eq!(settings.min_release_age_secs, None);
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_ages() {
+        let db = setup_db().await;
+        for age in ["-5m", "30s", "31d", "soon"] {
+            assert!(
+                handle_min_age(&db, "", 1, "https://github.com/owner/repo", age)
+                    .await
+                    .is_err(),
+                "{age}"
+            );
+        }
+    }
+}
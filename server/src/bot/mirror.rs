@@ -0,0 +1,144 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::messages::{SourceText, render, text};
+use crate::tracked_repositories::RepositoryUrl;
+use crate::tracked_repositories::mirrors::repository::{
+    RepositoryMirrorsRepository, SqliteRepositoryMirrorsRepository,
+};
+
+pub(crate) async fn handle_mirror(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    url: &str,
+    mirror_url: &str,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let mirror_url = RepositoryUrl::new(mirror_url.to_string()).map_err(|e| e.message(lang))?;
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+    if mirror_url.url() == tracked.repository_url.url() {
+        return Err(text(lang, SourceText::MirrorOfItself).to_string());
+    }
+
+    let mirrors = SqliteRepositoryMirrorsRepository::new(db.clone());
+    match mirrors
+        .append(&tracked.id, &mirror_url)
+        .await
+        .map_err(|e| {
+            render(
+                lang,
+                SourceText::MirrorSaveFailed,
+                &[("error", &e.to_string())],
+            )
+        })? {
+        Some(m) => Ok(render(
+            lang,
+            SourceText::MirrorAdded,
+            &[
+                ("mirror", &mirror_url.to_string()),
+                ("position", &(m.position + 1).to_string()),
+                ("repository", &tracked.repository_name),
+            ],
+        )),
+        None => Ok(render(
+            lang,
+            SourceText::MirrorExists,
+            &[
+                ("mirror", &mirror_url.to_string()),
+                ("repository", &tracked.repository_name),
+            ],
+        )),
+    }
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+    mirror_url: String,
+) -> ResponseResult<()> {
+    let text = match handle_mirror(&state.db, &state.bot_id, msg.chat.id.0, &url, &mirror_url).await
+    {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::handle_track;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn handle_mirror_adds_mirror_for_tracked_repo() {
+        let db = setup_db().await;
+        handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+            .await
+            .unwrap();
+
+        let message = handle_mirror(
+            &db,
+            "",
+            1,
+            "https://github.com/owner/repo",
+            "https://github.com/mirror/repo",
+        )
+        .await
+        .expect("should succeed");
+        assert!(message.contains("mirror #1"));
+
+        let again = handle_mirror(
+            &db,
+            "",
+            1,
+            "https://github.com/owner/repo",
+            "https://github.com/mirror/repo",
+        )
+        .await
+        .expect("should succeed");
+        assert!(again.contains("already a mirror"));
+    }
+
+    #[tokio::test]
+    async fn handle_mirror_rejects_untracked_repo() {
+        let db = setup_db().await;
+        handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+            .await
+            .unwrap();
+
+        let err = handle_mirror(
+            &db,
+            "",
+            2,
+            "https://github.com/owner/repo",
+            "https://github.com/mirror/repo",
+        )
+        .await
+        .expect_err("other chat does not track it");
+        assert!(err.contains("not tracking"));
+    }
+}
@@ -0,0 +1,287 @@
+mod access;
+mod affix;
+mod alias;
+mod auth_info;
+mod bulk;
+mod chart;
+mod check;
+mod collapse_prereleases;
+mod command;
+mod compare_chats;
+mod component;
+mod config;
+mod critical;
+mod default_branch;
+mod discussions;
+mod escape_test;
+mod exact_tags;
+mod first_seen;
+mod format;
+mod handoff;
+mod history;
+mod language;
+mod latest;
+mod list;
+mod log_level;
+mod lookup;
+mod mention;
+mod menu;
+mod milestones;
+mod min_age;
+mod mirror;
+mod next;
+mod notes;
+mod pending;
+mod pin_releases;
+mod previous_tag;
+mod rate_limit;
+mod reactions;
+mod register;
+mod removed_releases;
+mod resend;
+mod revalidate;
+mod search;
+mod simulate;
+mod since;
+mod snooze;
+mod stale;
+mod startup;
+mod stats;
+mod status;
+mod subscribers;
+mod tag_capture;
+mod tag_ignore;
+mod tags_only;
+mod template;
+mod throttle;
+mod timeout;
+mod token;
+mod track;
+mod velocity;
+mod version;
+mod watch_tag;
+mod webhook;
+mod why;
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use sqlx::sqlite::SqlitePool;
+use teloxide::dispatching::{Dispatcher, UpdateFilterExt};
+use teloxide::dptree;
+use teloxide::prelude::*;
+
+use crate::configuration;
+use crate::messages::{self, InfoText};
+use crate::poller::PollSchedule;
+use access::{require_access, require_admin, require_callback_access};
+pub use command::Command;
+pub use startup::announce_startup;
+
+pub struct BotState {
+    pub db: SqlitePool,
+    /// Which bot this is; empty for the bot of `TELOXIDE_TOKEN`.
+    pub bot_id: String,
+    pub config: configuration::Configuration,
+    pub schedule: Arc<PollSchedule>,
+    pub pending_untracks: bulk::PendingUntracks,
+    /// Chats the menu's Add button asked for a URL.
+    pub menus: menu::MenuStates,
+    /// When the process started, for the uptime reported by /version.
+    pub started_at: Instant,
+}
+
+pub async fn run(bot: Bot, state: Arc<BotState>) {
+    // Register available bot commands with Telegram at startup, beside the dispatcher so a
+    // flood wait never holds back updates
+    let registering = bot.clone();
+    tokio::spawn(async move {
+        if let Err(e) = register::register_commands(&registering).await {
+            log::warn!("Failed to set Telegram bot commands: {}", e);
+        }
+    });
+
+    let messages = Update::filter_message()
+        .branch(dptree::entry().filter_command::<Command>().endpoint(answer))
+        .branch(dptree::endpoint(fallback));
+    let callbacks = Update::filter_callback_query().endpoint(callback);
+    let handler = dptree::entry().branch(messages).branch(callbacks);
+
+    let mut dispatcher = Dispatcher::builder(bot, handler)
+        .dependencies(dptree::deps![state])
+        .build();
+
+    dispatcher.dispatch().await;
+}
+
+async fn answer(bot: Bot, msg: Message, cmd: Command, state: Arc<BotState>) -> ResponseResult<()> {
+    if !require_access(&bot, &msg, &state).await? {
+        return Ok(());
+    }
+
+    match cmd {
+        Command::Track { name, url } => track::answer(&bot, &msg, &state, name, url).await?,
+        Command::Check(url) => check::answer(&bot, &msg, &state, url).await?,
+        Command::List(mode) => list::answer(&bot, &msg, &state, mode).await?,
+        Command::Search(term) => search::answer(&bot, &msg, &state, term).await?,
+        Command::UntrackMatching(glob) => bulk::answer_untrack(&bot, &msg, &state, glob).await?,
+        Command::MuteMatching(glob) => bulk::answer_mute(&bot, &msg, &state, glob, true).await?,
+        Command::UnmuteMatching(glob) => bulk::answer_mute(&bot, &msg, &state, glob, false).await?,
+        Command::Mirror { url, mirror_url } => {
+            mirror::answer(&bot, &msg, &state, url, mirror_url).await?
+        }
+        Command::WatchTag { url, tag } => watch_tag::answer(&bot, &msg, &state, url, tag).await?,
+        Command::CollapsePrereleases { url, minutes } => {
+            collapse_prereleases::answer(&bot, &msg, &state, url, minutes).await?
+        }
+        Command::MinAge { url, age } => min_age::answer(&bot, &msg, &state, url, age).await?,
+        Command::Throttle { url, window } => {
+            throttle::answer(&bot, &msg, &state, url, window).await?
+        }
+        Command::Timeout { url, timeout } => {
+            timeout::answer(&bot, &msg, &state, url, timeout).await?
+        }
+        Command::TagsOnly { url, value } => {
+            tags_only::answer(&bot, &msg, &state, url, value).await?
+        }
+        Command::ExactTags { url, value } => {
+            exact_tags::answer(&bot, &msg, &state, url, value).await?
+        }
+        Command::Mention(args) => mention::answer(&bot, &msg, &state, args).await?,
+        Command::Critical(url) => critical::answer(&bot, &msg, &state, url).await?,
+        Command::Alias { url, alias } => alias::answer(&bot, &msg, &state, url, alias).await?,
+        Command::Handoff { url, target_chat } => {
+            handoff::answer(&bot, &msg, &state, url, target_chat).await?
+        }
+        Command::Since { url, date } => since::answer(&bot, &msg, &state, url, date).await?,
+        Command::IgnoreTags { url, pattern } => {
+            tag_ignore::answer(&bot, &msg, &state, url, pattern).await?
+        }
+        Command::TagCapture { url, pattern } => {
+            tag_capture::answer(&bot, &msg, &state, url, pattern).await?
+        }
+        Command::Component { url, prefix } => {
+            component::answer(&bot, &msg, &state, url, prefix).await?
+        }
+        Command::Discussions { url, category } => {
+            discussions::answer(&bot, &msg, &state, url, category).await?
+        }
+        Command::Milestones { url, every } => {
+            milestones::answer(&bot, &msg, &state, url, every).await?
+        }
+        Command::Snooze { url, duration } => {
+            snooze::answer(&bot, &msg, &state, url, duration).await?
+        }
+        Command::SnoozeAll(duration) => snooze::answer_all(&bot, &msg, &state, duration).await?,
+        Command::Next(url) => next::answer(&bot, &msg, &state, url).await?,
+        Command::Latest(args) => latest::answer(&bot, &msg, &state, args).await?,
+        Command::Why(url) => why::answer(&bot, &msg, &state, url).await?,
+        Command::Resend(url) => resend::answer(&bot, &msg, &state, url).await?,
+        Command::History(url) => history::answer(&bot, &msg, &state, url).await?,
+        Command::Velocity(url) => velocity::answer(&bot, &msg, &state, url).await?,
+        Command::Chart(url) => chart::answer(&bot, &msg, &state, url).await?,
+        Command::Format(value) => format::answer(&bot, &msg, &state, value).await?,
+        Command::Language(value) => language::answer(&bot, &msg, &state, value).await?,
+        Command::Template(value) => template::answer(&bot, &msg, &state, value).await?,
+        Command::PreviewTemplate => template::answer_preview(&bot, &msg, &state).await?,
+        Command::Prefix(value) => {
+            affix::answer(&bot, &msg, &state, affix::Affix::Prefix, value).await?
+        }
+        Command::Suffix(value) => {
+            affix::answer(&bot, &msg, &state, affix::Affix::Suffix, value).await?
+        }
+        Command::Notes(value) => notes::answer(&bot, &msg, &state, value).await?,
+        Command::Webhook(value) => webhook::answer(&bot, &msg, &state, value).await?,
+        Command::Reactions(value) => reactions::answer(&bot, &msg, &state, value).await?,
+        Command::ListLinks(value) => list::links::answer(&bot, &msg, &state, value).await?,
+        Command::RemovedReleases(value) => {
+            removed_releases::answer(&bot, &msg, &state, value).await?
+        }
+        Command::PreviousTag(value) => previous_tag::answer(&bot, &msg, &state, value).await?,
+        Command::PinReleases(value) => pin_releases::answer(&bot, &msg, &state, value).await?,
+        Command::FirstSeen(value) => first_seen::answer(&bot, &msg, &state, value).await?,
+        Command::Status => status::answer(&bot, &msg, &state).await?,
+        Command::Stats => stats::answer(&bot, &msg, &state).await?,
+        Command::Pending(arg) => pending::answer(&bot, &msg, &state, arg).await?,
+        Command::Stale(days) => stale::answer(&bot, &msg, &state, days).await?,
+        Command::Revalidate => revalidate::answer(&bot, &msg, &state).await?,
+        Command::Token(action) => token::answer(&bot, &msg, &state, action).await?,
+        Command::Ratelimit => rate_limit::answer(&bot, &msg, &state).await?,
+        Command::Authinfo(url) => auth_info::answer(&bot, &msg, &state, url).await?,
+        Command::Subscribers(url) => {
+            if require_admin(&bot, &msg, &state).await? {
+                subscribers::answer(&bot, &msg, &state, url).await?;
+            }
+        }
+        Command::CompareChats { a, b } => {
+            if require_admin(&bot, &msg, &state).await? {
+                compare_chats::answer(&bot, &msg, &state, a, b).await?;
+            }
+        }
+        Command::Loglevel(value) => {
+            if require_admin(&bot, &msg, &state).await? {
+                log_level::answer(&bot, &msg, &state, value).await?;
+            }
+        }
+        Command::Config => {
+            if require_admin(&bot, &msg, &state).await? {
+                config::answer(&bot, &msg, &state).await?;
+            }
+        }
+        Command::Simulate => {
+            if require_admin(&bot, &msg, &state).await? {
+                simulate::answer(&bot, &msg, &state).await?;
+            }
+        }
+        Command::EscapeTest(text) => {
+            if require_admin(&bot, &msg, &state).await? {
+                escape_test::answer(&bot, &msg, text).await?;
+            }
+        }
+        Command::Menu => menu::answer(&bot, &msg, &state).await?,
+        Command::Version => version::answer(&bot, &msg, &state).await?,
+        Command::Help => send_help(&bot, msg.chat.id, None).await?,
+    };
+
+    Ok(())
+}
+
+async fn callback(bot: Bot, q: CallbackQuery, state: Arc<BotState>) -> ResponseResult<()> {
+    if !require_callback_access(&bot, &q, &state).await? {
+        return Ok(());
+    }
+
+    if menu::is_menu_callback(&q) {
+        menu::callback(bot, q, state).await
+    } else {
+        bulk::callback(bot, q, state).await
+    }
+}
+
+async fn fallback(bot: Bot, msg: Message, state: Arc<BotState>) -> ResponseResult<()> {
+    if !require_access(&bot, &msg, &state).await? {
+        return Ok(());
+    }
+
+    if menu::answer_reply(&bot, &msg, &state).await? {
+        return Ok(());
+    }
+
+    if let Some(input) = msg.text() {
+        if input.starts_with('/') {
+            send_help(&bot, msg.chat.id, None).await?;
+        } else {
+            let lang = language::chat_language(&state.db, msg.chat.id.0).await;
+            let intro = messages::text(lang, InfoText::OnlyCommands);
+            send_help(&bot, msg.chat.id, Some(intro)).await?;
+        }
+    }
+    Ok(())
+}
+
+async fn send_help(bot: &Bot, chat_id: ChatId, intro: Option<&str>) -> ResponseResult<()> {
+    for message in command::help_messages(intro) {
+        bot.send_message(chat_id, message).await?;
+    }
+    Ok(())
+}
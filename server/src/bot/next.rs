@@ -0,0 +1,133 @@
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::messages::{PollText, render};
+use crate::poller::PollSchedule;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+fn format_time(at: DateTime<Utc>) -> String {
+    at.format("%Y-%m-%d %H:%M UTC").to_string()
+}
+
+pub(crate) async fn handle_next(
+    db: &SqlitePool,
+    bot_id: &str,
+    schedule: &PollSchedule,
+    chat_id: i64,
+    url: &str,
+    now: DateTime<Utc>,
+) -> Result<String, String> {
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+
+    let lang = chat_language(db, chat_id).await;
+    let repository = ("repository", tracked.repository_name.as_str());
+
+    let mut text = match schedule.next_poll_at(&tracked.id) {
+        // A cycle that is running late checks the repository as soon as it gets to it
+        Some(next) if next <= now => render(lang, PollText::CheckingNow, &[repository]),
+        Some(next) => render(
+            lang,
+            PollText::NextCheck,
+            &[repository, ("at", &format_time(next))],
+        ),
+        None => render(lang, PollText::NotCheckedYet, &[repository]),
+    };
+
+    let snoozed_until = SqliteRepositorySettingsRepository::new(db.clone())
+        .find_by_tracked_repository_id(&tracked.id)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|s| s.snoozed_until)
+        .filter(|until| *until > now);
+    if let Some(until) = snoozed_until {
+        text.push('\n');
+        text.push_str(&render(
+            lang,
+            PollText::RepositorySnoozed,
+            &[("until", &format_time(until))],
+        ));
+    }
+
+    Ok(text)
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+) -> ResponseResult<()> {
+    let text = match handle_next(
+        &state.db,
+        &state.bot_id,
+        &state.schedule,
+        msg.chat.id.0,
+        &url,
+        Utc::now(),
+    )
+    .await
+    {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn reports_next_poll_time() {
+        let db = setup_db().await;
+        let id = match handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+            .await
+            .unwrap()
+        {
+            HandleTrackResult::Created { id, .. } => id,
+            _ => panic!("expected Created"),
+        };
+        let schedule = PollSchedule::new(600);
+        let last = DateTime::from_timestamp(1700000000, 0).unwrap();
+
+        let text = handle_next(&db, "", &schedule, 1, "https://github.com/owner/repo", last)
+            .await
+            .unwrap();
+        assert!(text.contains("has not been checked"));
+
+        schedule.record(id, last);
+        let text = handle_next(&db, "", &schedule, 1, "https://github.com/owner/repo", last)
+            .await
+            .unwrap();
+        assert_eq!(
+            text,
+            "repo will next be checked around 2023-11-14 22:23 UTC."
+        );
+    }
+}
@@ -0,0 +1,66 @@
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::chat_settings::Lang;
+use crate::chat_settings::ReleaseNotes;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::messages::{ChatText, CommandText, render, text};
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    value: String,
+) -> ResponseResult<()> {
+    let repository = SqliteChatSettingsRepository::new(state.db.clone());
+    let mut settings = match repository.find_or_default(msg.chat.id.0).await {
+        Ok(s) => s,
+        Err(e) => {
+            let text = render(
+                Lang::default(),
+                CommandText::LoadChatSettingsFailed,
+                &[("error", &e.to_string())],
+            );
+            bot.send_message(msg.chat.id, text).await?;
+            return Ok(());
+        }
+    };
+    let lang = settings.language;
+
+    if value.trim().is_empty() {
+        bot.send_message(
+            msg.chat.id,
+            render(
+                lang,
+                ChatText::NotesState,
+                &[("notes", settings.release_notes.as_str())],
+            ),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let Some(release_notes) = ReleaseNotes::parse(&value) else {
+        bot.send_message(msg.chat.id, text(lang, ChatText::NotesUnknown))
+            .await?;
+        return Ok(());
+    };
+
+    settings.release_notes = release_notes;
+    settings.updated_at = chrono::Utc::now();
+    let text = match repository.save(&settings).await {
+        Ok(()) => render(
+            lang,
+            ChatText::NotesSet,
+            &[("notes", release_notes.as_str())],
+        ),
+        Err(e) => render(
+            lang,
+            CommandText::SaveChatSettingsFailed,
+            &[("error", &e.to_string())],
+        ),
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
@@ -0,0 +1,281 @@
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::chat_settings::Lang;
+use crate::messages::{CommandText, StatusText, TrackingText, render, text};
+use crate::tracked_repositories::repository::{
+    SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+};
+use crate::tracked_repositories::settings::RepositorySettings;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+use crate::tracked_repositories::tracked_repositories_releases::CachedRepositoryRelease;
+use crate::tracked_repositories::tracked_repositories_releases::repository::{
+    CachedRepositoryReleasesRepository, SqliteCachedRepositoryReleasesRepository,
+};
+use crate::utils::{
+    MAX_NAME_CHARS, MAX_TAG_CHARS, TELEGRAM_MESSAGE_LIMIT, split_message, truncate_chars,
+};
+
+/// The releases of a repository held back from the chat, each with the reason.
+fn held_back(
+    settings: &RepositorySettings,
+    lang: Lang,
+    now: DateTime<Utc>,
+) -> Vec<(String, String)> {
+    let mut held = Vec::new();
+    if let Some(tag) = &settings.snooze_missed_tag {
+        let reason = match settings.snoozed_until.filter(|until| *until > now) {
+            Some(until) => {
+                let until = until.format("%Y-%m-%d %H:%M UTC").to_string();
+                render(lang, StatusText::HeldSnoozed, &[("until", &until)])
+            }
+            None => text(lang, StatusText::HeldSnoozeEnded).to_string(),
+        };
+        held.push((tag.clone(), reason));
+    }
+    if let (Some(tag), Some(age)) = (&settings.young_release_tag, settings.min_release_age_secs) {
+        let minutes = (age / 60).to_string();
+        held.push((
+            tag.clone(),
+            render(lang, StatusText::HeldYoung, &[("minutes", &minutes)]),
+        ));
+    }
+    held
+}
+
+/// Lists the notifications held back for the chat's repositories and why, or with
+/// `clear` drops them: a snoozed release is no longer announced when the snooze ends, and
+/// a release waiting for its minimum age is recorded as seen.
+pub(crate) async fn handle_pending(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    arg: &str,
+    now: DateTime<Utc>,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let clear = match arg.trim().to_ascii_lowercase().as_str() {
+        "" => false,
+        "clear" => true,
+        _ => {
+            return Err(render(
+                lang,
+                CommandText::Usage,
+                &[("usage", "/pending or /pending clear")],
+            ));
+        }
+    };
+
+    let tracked = SqliteTrackedRepositoriesRepository::new(db.clone())
+        .find_all_by_chat_id(bot_id, chat_id)
+        .await
+        .map_err(|e| render(lang, TrackingText::ListFailed, &[("error", &e.to_string())]))?;
+    let settings_repo = SqliteRepositorySettingsRepository::new(db.clone());
+    let cache_repo = SqliteCachedRepositoryReleasesRepository::new(db.clone());
+
+    let mut lines = Vec::new();
+    for r in &tracked {
+        let mut settings = settings_repo.find_or_default(&r.id).await.map_err(|e| {
+            render(
+                lang,
+                CommandText::LoadSettingsFailed,
+                &[("error", &e.to_string())],
+            )
+        })?;
+        let held = held_back(&settings, lang, now);
+        if held.is_empty() {
+            continue;
+        }
+        for (tag, reason) in &held {
+            lines.push(format!(
+                "- {}: {}, {reason}",
+                truncate_chars(&r.repository_name, MAX_NAME_CHARS),
+                truncate_chars(tag, MAX_TAG_CHARS)
+            ));
+        }
+        if !clear {
+            continue;
+        }
+
+        settings.snooze_missed_tag = None;
+        if let Some(tag) = settings.young_release_tag.take() {
+            settings.young_release_seen_at = None;
+            cache_repo
+                .save(&CachedRepositoryRelease {
+                    tracked_repository_id: r.id,
+                    tag_name: tag,
+                    first_seen_at: now,
+                    body_hash: None,
+                    from_release: true,
+                })
+                .await
+                .map_err(|e| {
+                    render(
+                        lang,
+                        StatusText::RecordSeenFailed,
+                        &[("error", &e.to_string())],
+                    )
+                })?;
+        }
+        settings.updated_at = now;
+        settings_repo.save(&settings).await.map_err(|e| {
+            render(
+                lang,
+                CommandText::SaveSettingsFailed,
+                &[("error", &e.to_string())],
+            )
+        })?;
+    }
+
+    if lines.is_empty() {
+        return Ok(text(lang, StatusText::NothingPending).to_string());
+    }
+    let count = lines.len().to_string();
+    let heading = match (clear, lines.len()) {
+        (true, 1) => text(lang, StatusText::DroppedOne).to_string(),
+        (true, _) => render(lang, StatusText::Dropped, &[("count", &count)]),
+        (false, 1) => text(lang, StatusText::WaitingOne).to_string(),
+        (false, _) => render(lang, StatusText::Waiting, &[("count", &count)]),
+    };
+    lines.insert(0, heading);
+    if !clear {
+        lines.push(text(lang, StatusText::ClearHint).to_string());
+    }
+    Ok(lines.join("\n"))
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    arg: String,
+) -> ResponseResult<()> {
+    let text = match handle_pending(&state.db, &state.bot_id, msg.chat.id.0, &arg, Utc::now()).await
+    {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    for page in split_message(&text, TELEGRAM_MESSAGE_LIMIT) {
+        bot.send_message(msg.chat.id, page).await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use chrono::Duration;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    async fn track(db: &SqlitePool, chat_id: i64, name: &str) -> uuid::Uuid {
+        let url = format!("https://github.com/owner/{name}");
+        match handle_track(db, "", chat_id, name, &url).await.unwrap() {
+            HandleTrackResult::Created { id, .. } => id,
+            _ => panic!("expected Created"),
+        }
+    }
+
+    async fn held_repositories(db: &SqlitePool, now: DateTime<Utc>) -> (uuid::Uuid, uuid::Uuid) {
+        let snoozed = track(db, 1, "snoozed").await;
+        let young = track(db, 1, "young").await;
+        track(db, 1, "quiet").await;
+        let elsewhere = track(db, 2, "elsewhere").await;
+
+        let repo = SqliteRepositorySettingsRepository::new(db.clone());
+        let mut settings = RepositorySettings::default_for(snoozed);
+        settings.snoozed_until = Some(now + Duration::days(1));
+        settings.snooze_missed_tag = Some("v2.0.0".to_string());
+        repo.save(&settings).await.unwrap();
+        let mut settings = RepositorySettings::default_for(young);
+        settings.min_release_age_secs = Some(3600);
+        settings.young_release_tag = Some("v0.3.0".to_string());
+        settings.young_release_seen_at = Some(now);
+        repo.save(&settings).await.unwrap();
+        let mut settings = RepositorySettings::default_for(elsewhere);
+        settings.snooze_missed_tag = Some("v9".to_string());
+        repo.save(&settings).await.unwrap();
+
+        (snoozed, young)
+    }
+
+    #[tokio::test]
+    async fn counts_waiting_notifications_and_why() {
+        let db = setup_db().await;
+        let now = Utc::now();
+        assert_eq!(
+            handle_pending(&db, "", 1, "", now).await.unwrap(),
+            "No notifications are waiting for this chat."
+        );
+        held_repositories(&db, now).await;
+
+        let until = (now + Duration::days(1)).format("%Y-%m-%d %H:%M UTC");
+        assert_eq!(
+            handle_pending(&db, "", 1, "", now).await.unwrap(),
+            format!(
+                "2 notifications are waiting for this chat:\n\
+                 - young: v0.3.0, waiting to be 60 minutes old\n\
+                 - snoozed: v2.0.0, snoozed until {until}\n\
+                 Send /pending clear to drop them."
+            )
+        );
+        assert!(handle_pending(&db, "", 1, "everything", now).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn clear_drops_waiting_notifications() {
+        let db = setup_db().await;
+        let now = Utc::now();
+        let (snoozed, young) = held_repositories(&db, now).await;
+
+        let text = handle_pending(&db, "", 1, "clear", now).await.unwrap();
+        assert!(text.starts_with("Dropped 2 waiting notifications:"));
+        assert_eq!(
+            handle_pending(&db, "", 1, "", now).await.unwrap(),
+            "No notifications are waiting for this chat."
+        );
+
+        let repo = SqliteRepositorySettingsRepository::new(db.clone());
+        let settings = repo.find_or_default(&snoozed).await.unwrap();
+        assert_eq!(settings.snooze_missed_tag, None);
+        assert!(settings.snoozed_until.is_some());
+        let settings = repo.find_or_default(&young).await.unwrap();
+        assert_eq!(settings.young_release_tag, None);
+        assert_eq!(settings.min_release_age_secs, Some(3600));
+        let cached = SqliteCachedRepositoryReleasesRepository::new(db.clone())
+            .find_by_tracked_release_id(&young)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(cached.tag_name, "v0.3.0");
+
+        // Other chats keep theirs
+        assert!(
+            handle_pending(&db, "", 2, "", now)
+                .await
+                .unwrap()
+                .starts_with("1 notification is waiting")
+        );
+    }
+}
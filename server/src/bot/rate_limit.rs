@@ -0,0 +1,75 @@
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::chat_settings::Lang;
+use crate::github::{RateLimitStatus, fetch_rate_limit};
+use crate::messages::{InfoText, render, text};
+
+pub(super) async fn answer(bot: &Bot, msg: &Message, state: &BotState) -> ResponseResult<()> {
+    let client = reqwest::Client::new();
+    let token_opt = state.config.resolve_token().token();
+
+    let lang = chat_language(&state.db, msg.chat.id.0).await;
+    let reply = match fetch_rate_limit(&client, token_opt).await {
+        Ok(status) => format_rate_limit(&status, token_opt.is_some(), lang),
+        Err(e) => render(
+            lang,
+            InfoText::RateLimitFailed,
+            &[("error", &e.to_string())],
+        ),
+    };
+    bot.send_message(msg.chat.id, reply).await?;
+
+    Ok(())
+}
+
+fn format_rate_limit(status: &RateLimitStatus, authenticated: bool, lang: Lang) -> String {
+    let reset = status.reset_at.format("%Y-%m-%d %H:%M:%S UTC").to_string();
+    let mut reply = render(
+        lang,
+        InfoText::Quota,
+        &[
+            ("remaining", &status.remaining.to_string()),
+            ("limit", &status.limit.to_string()),
+            ("reset", &reset),
+        ],
+    );
+    if !authenticated {
+        reply.push('\n');
+        reply.push_str(text(lang, InfoText::QuotaUnauthenticated));
+    }
+    reply
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+
+    fn status() -> RateLimitStatus {
+        RateLimitStatus {
+            limit: 60,
+            remaining: 12,
+            reset_at: DateTime::from_timestamp(1700000000, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn formats_authenticated_quota() {
+        let text = format_rate_limit(&status(), true, Lang::En);
+        assert!(text.contains("12/60 requests remaining"));
+        assert!(text.contains("2023-11-14 22:13:20 UTC"));
+        assert!(!text.contains("unauthenticated"));
+    }
+
+    #[test]
+    fn formats_unauthenticated_quota_distinctly() {
+        let text = format_rate_limit(&status(), false, Lang::En);
+        assert!(text.contains("unauthenticated limit"));
+
+        let text = format_rate_limit(&status(), false, Lang::It);
+        assert!(text.contains("12/60 richieste rimaste"));
+        assert!(text.contains("limite non autenticato"));
+    }
+}
@@ -0,0 +1,193 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+use teloxide::types::{MessageId, ReactionType};
+
+use crate::bot::BotState;
+use crate::chat_settings::Lang;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::messages::{ChatText, CommandText, render, text};
+
+/// Telegram only accepts emoji from a fixed list as reactions, which has no check mark.
+const TRACK_REACTION: &str = "👍";
+
+/// Shows or switches (`on`/`off`) whether successful /track commands get a reaction.
+pub(crate) async fn handle_reactions(
+    db: &SqlitePool,
+    chat_id: i64,
+    value: &str,
+) -> Result<String, String> {
+    let repository = SqliteChatSettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(chat_id).await.map_err(|e| {
+        render(
+            Lang::default(),
+            CommandText::LoadChatSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    let lang = settings.language;
+
+    let enabled = match value.trim().to_ascii_lowercase().as_str() {
+        "" => {
+            let state = if settings.track_reactions {
+                "on"
+            } else {
+                "off"
+            };
+            return Ok(render(lang, ChatText::ReactionsState, &[("state", state)]));
+        }
+        "on" => true,
+        "off" => false,
+        _ => return Err(text(lang, CommandText::ChooseOnOrOff).to_string()),
+    };
+    settings.track_reactions = enabled;
+    settings.updated_at = chrono::Utc::now();
+    repository.save(&settings).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::SaveChatSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    Ok(if enabled {
+        render(lang, ChatText::ReactionsOn, &[("reaction", TRACK_REACTION)])
+    } else {
+        text(lang, ChatText::ReactionsOff).to_string()
+    })
+}
+
+/// Reacts to a message, returning whether it worked. Chats or clients without reaction
+/// support make this fail, which is only logged.
+pub(crate) async fn react(bot: &Bot, chat_id: ChatId, message_id: MessageId) -> bool {
+    let reaction = ReactionType::Emoji {
+        emoji: TRACK_REACTION.to_string(),
+    };
+    match bot
+        .set_message_reaction(chat_id, message_id)
+        .reaction([reaction])
+        .await
+    {
+        Ok(_) => true,
+        Err(e) => {
+            log::debug!("Failed to react to message in chat {}: {}", chat_id, e);
+            false
+        }
+    }
+}
+
+/// Reacts to a successful /track command when both the operator and the chat allow it.
+pub(super) async fn confirm_track(bot: &Bot, msg: &Message, state: &BotState) {
+    if !state.config.track_reactions {
+        return;
+    }
+    let enabled = SqliteChatSettingsRepository::new(state.db.clone())
+        .find_or_default(msg.chat.id.0)
+        .await
+        .map(|s| s.track_reactions)
+        .unwrap_or(false);
+    if enabled {
+        react(bot, msg.chat.id, msg.id).await;
+    }
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    value: String,
+) -> ResponseResult<()> {
+    let text = match handle_reactions(&state.db, msg.chat.id.0, &value).await {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn toggles_track_reactions() {
+        let db = setup_db().await;
+        let repository = SqliteChatSettingsRepository::new(db.clone());
+        assert!(repository.find_or_default(1).await.unwrap().track_reactions);
+
+        handle_reactions(&db, 1, "off")
+            .await
+            .expect("should succeed");
+        assert!(!repository.find_or_default(1).await.unwrap().track_reactions);
+        assert!(handle_reactions(&db, 1, "").await.unwrap().contains("off"));
+        assert!(handle_reactions(&db, 1, "sometimes").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn failed_reaction_is_ignored() {
+        let mut tg = Server::new_async().await;
+        let token = "TESTTOKEN";
+        let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+        let m = tg
+            .mock(
+                "POST",
+                mockito::Matcher::Exact(format!("/bot{token}/SetMessageReaction")),
+            )
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "ok": false,
+                    "error_code": 400,
+                    "description": "Bad Request: REACTIONS_TOO_MANY"
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+
+        assert!(!react(&bot, ChatId(1), MessageId(7)).await);
+        m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn successful_reaction_sends_the_emoji() {
+        let mut tg = Server::new_async().await;
+        let token = "TESTTOKEN";
+        let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+        let _m = tg
+            .mock(
+                "POST",
+                mockito::Matcher::Exact(format!("/bot{token}/SetMessageReaction")),
+            )
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "message_id": 7,
+                "reaction": [{"type": "emoji", "emoji": TRACK_REACTION}]
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"ok":true,"result":true}"#)
+            .create_async()
+            .await;
+
+        assert!(react(&bot, ChatId(1), MessageId(7)).await);
+    }
+}
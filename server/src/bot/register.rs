@@ -0,0 +1,72 @@
+use teloxide::RequestError;
+use teloxide::prelude::*;
+use teloxide::utils::command::BotCommands;
+
+use crate::bot::Command;
+
+/// Attempts at registering the command list before giving up until the next start.
+const SET_COMMANDS_ATTEMPTS: usize = 4;
+
+/// Registers the command list shown in Telegram's UI. A flood wait is sat out for as long
+/// as Telegram asks and the request retried; any other error, or a flood wait on the last
+/// attempt, is returned.
+pub(super) async fn register_commands(bot: &Bot) -> Result<(), RequestError> {
+    let mut attempt = 1;
+    loop {
+        match bot.set_my_commands(Command::bot_commands()).await {
+            Ok(_) => return Ok(()),
+            Err(RequestError::RetryAfter(wait)) if attempt < SET_COMMANDS_ATTEMPTS => {
+                log::info!(
+                    "Telegram asked to wait {}s before setting the bot commands",
+                    wait.seconds()
+                );
+                tokio::time::sleep(wait.duration()).await;
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn retries_after_a_flood_wait() {
+        let mut tg = Server::new_async().await;
+        let token = "TESTTOKEN";
+        let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+        let path = format!("/bot{token}/SetMyCommands");
+
+        let m_flood = tg
+            .mock("POST", path.as_str())
+            .with_status(429)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "ok": false,
+                    "error_code": 429,
+                    "description": "Too Many Requests: retry after 1",
+                    "parameters": {"retry_after": 1}
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let m_ok = tg
+            .mock("POST", path.as_str())
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!({"ok": true, "result": true}).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        assert!(register_commands(&bot).await.is_ok());
+        m_flood.assert();
+        m_ok.assert();
+    }
+}
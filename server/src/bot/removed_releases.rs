@@ -0,0 +1,128 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::chat_settings::Lang;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::messages::{ChatText, CommandText, render, text};
+
+/// Shows or switches (`on`/`off`) whether the chat is told about deleted releases.
+pub(crate) async fn handle_removed_releases(
+    db: &SqlitePool,
+    chat_id: i64,
+    value: &str,
+) -> Result<String, String> {
+    let repository = SqliteChatSettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(chat_id).await.map_err(|e| {
+        render(
+            Lang::default(),
+            CommandText::LoadChatSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    let lang = settings.language;
+
+    let enabled = match value.trim().to_ascii_lowercase().as_str() {
+        "" => {
+            let state = if settings.notify_removed_releases {
+                "on"
+            } else {
+                "off"
+            };
+            return Ok(render(
+                lang,
+                ChatText::RemovedReleasesState,
+                &[("state", state)],
+            ));
+        }
+        "on" => true,
+        "off" => false,
+        _ => return Err(text(lang, CommandText::ChooseOnOrOff).to_string()),
+    };
+    settings.notify_removed_releases = enabled;
+    settings.updated_at = chrono::Utc::now();
+    repository.save(&settings).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::SaveChatSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    Ok(text(
+        lang,
+        if enabled {
+            ChatText::RemovedReleasesOn
+        } else {
+            ChatText::RemovedReleasesOff
+        },
+    )
+    .to_string())
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    value: String,
+) -> ResponseResult<()> {
+    let text = match handle_removed_releases(&state.db, msg.chat.id.0, &value).await {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn toggles_removed_release_notices() {
+        let db = setup_db().await;
+        let repository = SqliteChatSettingsRepository::new(db.clone());
+        assert!(
+            !repository
+                .find_or_default(1)
+                .await
+                .unwrap()
+                .notify_removed_releases
+        );
+
+        handle_removed_releases(&db, 1, "on")
+            .await
+            .expect("should succeed");
+        assert!(
+            repository
+                .find_or_default(1)
+                .await
+                .unwrap()
+                .notify_removed_releases
+        );
+        assert!(
+            handle_removed_releases(&db, 1, "")
+                .await
+                .unwrap()
+                .contains("on")
+        );
+        assert!(handle_removed_releases(&db, 1, "maybe").await.is_err());
+    }
+}
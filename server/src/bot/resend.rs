@@ -0,0 +1,211 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::chat_settings::MessageFormat;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::messages::{CommandText, ReleaseText, render};
+use crate::poller::{LatestRelease, release_headline};
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+use crate::tracked_repositories::settings::tag_capture::capture;
+use crate::tracked_repositories::tracked_repositories_releases::repository::{
+    CachedRepositoryReleasesRepository, SqliteCachedRepositoryReleasesRepository,
+};
+
+/// Renders the notification for the release cached for a tracked repository again, in
+/// the chat's format. Nothing is stored, so the poller carries on as before.
+pub(crate) async fn handle_resend(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    url: &str,
+) -> Result<(String, MessageFormat), String> {
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+    let lang = chat_language(db, chat_id).await;
+    let cached = SqliteCachedRepositoryReleasesRepository::new(db.clone())
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .map_err(|e| {
+            render(
+                lang,
+                CommandText::CachedReleaseFailed,
+                &[("error", &e.to_string())],
+            )
+        })?
+        .ok_or_else(|| {
+            render(
+                lang,
+                ReleaseText::NothingToResend,
+                &[("repository", &tracked.repository_name)],
+            )
+        })?;
+    let package = tracked.repository_url.package();
+    let Some((owner, repo)) = (match &package {
+        Some(package) => Some((package.registry.name().to_string(), package.name.clone())),
+        None => tracked.repository_url.owner_and_repo(),
+    }) else {
+        return Err(render(
+            lang,
+            CommandText::NoOwnerAndRepository,
+            &[("url", url)],
+        ));
+    };
+    let settings = SqliteRepositorySettingsRepository::new(db.clone())
+        .find_or_default(&tracked.id)
+        .await
+        .map_err(|e| {
+            render(
+                lang,
+                CommandText::LoadSettingsFailed,
+                &[("error", &e.to_string())],
+            )
+        })?;
+    let chat_settings = SqliteChatSettingsRepository::new(db.clone())
+        .find_or_default(chat_id)
+        .await
+        .map_err(|e| {
+            render(
+                lang,
+                CommandText::LoadChatSettingsFailed,
+                &[("error", &e.to_string())],
+            )
+        })?;
+
+    let captured = settings
+        .tag_capture
+        .as_deref()
+        .filter(|_| package.is_none())
+        .and_then(|pattern| capture(pattern, &cached.tag_name));
+    let latest = LatestRelease {
+        owner,
+        repo,
+        tag: cached.tag_name,
+        body: None,
+        details: Default::default(),
+        tags_only: settings.tags_only,
+        tag_prefix: settings.tag_prefix,
+        package,
+        captured,
+    };
+    Ok((
+        release_headline(&tracked, &latest, None, &chat_settings),
+        chat_settings.message_format,
+    ))
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+) -> ResponseResult<()> {
+    match handle_resend(&state.db, &state.bot_id, msg.chat.id.0, &url).await {
+        Ok((text, format)) => {
+            bot.send_message(msg.chat.id, text)
+                .parse_mode(format.parse_mode())
+                .await?;
+        }
+        Err(err_msg) => {
+            bot.send_message(msg.chat.id, err_msg).await?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use crate::tracked_repositories::tracked_repositories_releases::CachedRepositoryRelease;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    async fn tracked_repo(db: &SqlitePool) -> uuid::Uuid {
+        match handle_track(db, "", 1, "repo", "https://github.com/owner/repo")
+            .await
+            .unwrap()
+        {
+            HandleTrackResult::Created { id, .. } => id,
+            _ => panic!("expected Created"),
+        }
+    }
+
+    #[tokio::test]
+    async fn nothing_to_resend_before_a_release_is_seen() {
+        let db = setup_db().await;
+        tracked_repo(&db).await;
+
+        let err = handle_resend(&db, "", 1, "https://github.com/owner/repo")
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            "No release of repo has been seen yet, so there is nothing to resend."
+        );
+    }
+
+    #[tokio::test]
+    async fn resends_the_cached_release_without_touching_the_cache() {
+        let db = setup_db().await;
+        let id = tracked_repo(&db).await;
+        let cache_repo = SqliteCachedRepositoryReleasesRepository::new(db.clone());
+        let first_seen_at = chrono::Utc::now() - chrono::Duration::days(3);
+        cache_repo
+            .save(&CachedRepositoryRelease {
+                tracked_repository_id: id,
+                tag_name: "v1.2.0".to_string(),
+                first_seen_at,
+                body_hash: Some("hash".to_string()),
+                from_release: true,
+            })
+            .await
+            .unwrap();
+
+        let (text, format) = handle_resend(&db, "", 1, "https://github.com/owner/repo")
+            .await
+            .unwrap();
+        assert_eq!(format, MessageFormat::Html);
+        assert!(text.starts_with("New release for"));
+        assert!(text.contains("https://github.com/owner/repo/releases/tag/v1.2.0"));
+
+        let cached = cache_repo
+            .find_by_tracked_release_id(&id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(cached.tag_name, "v1.2.0");
+        assert_eq!(cached.first_seen_at, first_seen_at);
+        assert_eq!(cached.body_hash.as_deref(), Some("hash"));
+    }
+
+    #[tokio::test]
+    async fn other_chats_cannot_resend() {
+        let db = setup_db().await;
+        tracked_repo(&db).await;
+
+        assert!(
+            handle_resend(&db, "", 2, "https://github.com/owner/repo")
+                .await
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use std::time::Duration;
+
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::bulk::ask_untrack_confirmation;
+use crate::bot::language::chat_language;
+use crate::github::{fetch_repository_with_base, github_api_base};
+use crate::messages::{ReleaseText, TrackingText, render, text};
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::repository::{
+    SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+};
+use crate::utils::{TELEGRAM_MESSAGE_LIMIT, split_message};
+
+/// Pause between two repository lookups, so checking a long list does not burn through
+/// the API quota in one burst.
+const REVALIDATE_SPACING: Duration = Duration::from_secs(1);
+
+/// What GitHub says about one tracked repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RepositoryCheck {
+    Live,
+    /// GitHub redirected to a renamed or transferred repository at this URL.
+    Moved(String),
+    /// GitHub does not know the repository any more.
+    Gone,
+    Failed(String),
+}
+
+/// The outcome of checking all of a chat's repositories.
+#[derive(Debug, Default)]
+pub(crate) struct Revalidation {
+    pub report: String,
+    /// Repositories GitHub answered 404 for, offered for untracking.
+    pub gone: Vec<TrackedRelease>,
+}
+
+async fn check_repository(
+    client: &reqwest::Client,
+    token_opt: Option<&str>,
+    base: &str,
+    r: &TrackedRelease,
+) -> Result<RepositoryCheck, crate::github::GithubError> {
+    let Some((owner, repo)) = r.repository_url.owner_and_repo() else {
+        return Ok(RepositoryCheck::Failed(
+            "not a GitHub repository URL".to_string(),
+        ));
+    };
+    let Some(metadata) = fetch_repository_with_base(client, &owner, &repo, token_opt, base).await?
+    else {
+        return Ok(RepositoryCheck::Gone);
+    };
+    Ok(match metadata.full_name {
+        Some(name) if !name.eq_ignore_ascii_case(&format!("{owner}/{repo}")) => {
+            RepositoryCheck::Moved(format!("https://github.com/{name}"))
+        }
+        _ => RepositoryCheck::Live,
+    })
+}
+
+/// Looks up every repository the chat tracks, `spacing` apart, and reports the ones that
+/// were deleted or moved. A rate limit ends the run early.
+pub(crate) async fn handle_revalidate(
+    db: &SqlitePool,
+    bot_id: &str,
+    client: &reqwest::Client,
+    token_opt: Option<&str>,
+    base: &str,
+    chat_id: i64,
+    spacing: Duration,
+) -> Result<Revalidation, String> {
+    let lang = chat_language(db, chat_id).await;
+    let repos = SqliteTrackedRepositoriesRepository::new(db.clone())
+        .find_all_by_chat_id(bot_id, chat_id)
+        .await
+        .map_err(|e| render(lang, TrackingText::ListFailed, &[("error", &e.to_string())]))?;
+    if repos.is_empty() {
+        return Err(text(lang, TrackingText::NoRepositories).to_string());
+    }
+
+    let total = repos.len();
+    let mut lines = Vec::new();
+    let mut gone = Vec::new();
+    let mut checked = 0;
+    for (i, r) in repos.into_iter().enumerate() {
+        if i > 0 && !spacing.is_zero() {
+            tokio::time::sleep(spacing).await;
+        }
+        let check = match check_repository(client, token_opt, base, &r).await {
+            Ok(check) => check,
+            Err(e) if e.is_rate_limited() => {
+                lines.push(render(
+                    lang,
+                    ReleaseText::StoppedEarly,
+                    &[
+                        ("error", &e.to_string()),
+                        ("count", &(total - checked).to_string()),
+                    ],
+                ));
+                break;
+            }
+            Err(e) => RepositoryCheck::Failed(e.to_string()),
+        };
+        checked += 1;
+        match check {
+            RepositoryCheck::Live => {}
+            RepositoryCheck::Moved(url) => lines.push(render(
+                lang,
+                ReleaseText::MovedTo,
+                &[("repository", &r.repository_name), ("url", &url)],
+            )),
+            RepositoryCheck::Gone => {
+                lines.push(render(
+                    lang,
+                    ReleaseText::NoLongerExists,
+                    &[("repository", &r.repository_name)],
+                ));
+                gone.push(r);
+            }
+            RepositoryCheck::Failed(e) => lines.push(render(
+                lang,
+                ReleaseText::CouldNotBeChecked,
+                &[("repository", &r.repository_name), ("error", &e)],
+            )),
+        }
+    }
+
+    let report = if lines.is_empty() {
+        render(
+            lang,
+            ReleaseText::AllStillExist,
+            &[("count", &checked.to_string())],
+        )
+    } else {
+        let heading = render(
+            lang,
+            ReleaseText::CheckedOf,
+            &[
+                ("checked", &checked.to_string()),
+                ("total", &total.to_string()),
+            ],
+        );
+        format!("{heading}\n{}", lines.join("\n"))
+    };
+    Ok(Revalidation { report, gone })
+}
+
+pub(super) async fn answer(bot: &Bot, msg: &Message, state: &BotState) -> ResponseResult<()> {
+    let client = reqwest::Client::new();
+    let token_opt = state.config.resolve_token().token();
+    let base = github_api_base();
+    let revalidation = match handle_revalidate(
+        &state.db,
+        &state.bot_id,
+        &client,
+        token_opt,
+        &base,
+        msg.chat.id.0,
+        REVALIDATE_SPACING,
+    )
+    .await
+    {
+        Ok(revalidation) => revalidation,
+        Err(err_msg) => {
+            bot.send_message(msg.chat.id, err_msg).await?;
+            return Ok(());
+        }
+    };
+
+    for page in split_message(&revalidation.report, TELEGRAM_MESSAGE_LIMIT) {
+        bot.send_message(msg.chat.id, page).await?;
+    }
+    if !revalidation.gone.is_empty() {
+        let lang = chat_language(&state.db, msg.chat.id.0).await;
+        let intro = render(
+            lang,
+            ReleaseText::UntrackGone,
+            &[("count", &revalidation.gone.len().to_string())],
+        );
+        ask_untrack_confirmation(bot, msg, state, intro, revalidation.gone).await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
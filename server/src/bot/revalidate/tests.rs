@@ -0,0 +1,133 @@
+use super::*;
+use crate::bot::track::handle_track;
+use mockito::Server;
+use sqlx::sqlite::SqlitePoolOptions;
+
+async fn setup_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("failed to create in-memory sqlite pool");
+
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("failed to run migrations");
+
+    pool
+}
+
+#[tokio::test]
+async fn reports_gone_and_moved_repositories() {
+    let db = setup_db().await;
+    for name in ["live", "gone", "old"] {
+        handle_track(
+            &db,
+            "",
+            1,
+            name,
+            &format!("https://github.com/owner/{name}"),
+        )
+        .await
+        .unwrap();
+    }
+    let mut server = Server::new_async().await;
+    let _live = server
+        .mock("GET", "/repos/owner/live")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({"full_name": "owner/live", "default_branch": "main"}).to_string(),
+        )
+        .create_async()
+        .await;
+    let _gone = server
+        .mock("GET", "/repos/owner/gone")
+        .with_status(404)
+        .create_async()
+        .await;
+    // GitHub redirects renamed repositories and answers with the new name
+    let _old = server
+        .mock("GET", "/repos/owner/old")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({"full_name": "owner/new", "default_branch": "main"}).to_string(),
+        )
+        .create_async()
+        .await;
+
+    let revalidation = handle_revalidate(
+        &db,
+        "",
+        &reqwest::Client::new(),
+        None,
+        &server.url(),
+        1,
+        Duration::ZERO,
+    )
+    .await
+    .unwrap();
+
+    let gone: Vec<_> = revalidation
+        .gone
+        .iter()
+        .map(|r| r.repository_name.as_str())
+        .collect();
+    assert_eq!(gone, vec!["gone"]);
+    assert!(
+        revalidation
+            .report
+            .starts_with("Checked 3 of 3 repositories:")
+    );
+    assert!(
+        revalidation
+            .report
+            .contains("- old moved to https://github.com/owner/new")
+    );
+    assert!(
+        revalidation
+            .report
+            .contains("- gone no longer exists (404)")
+    );
+    assert!(!revalidation.report.contains("- live"));
+}
+
+#[tokio::test]
+async fn rate_limit_stops_the_run() {
+    let db = setup_db().await;
+    handle_track(&db, "", 1, "a", "https://github.com/owner/a")
+        .await
+        .unwrap();
+    handle_track(&db, "", 1, "b", "https://github.com/owner/b")
+        .await
+        .unwrap();
+    let mut server = Server::new_async().await;
+    let limited = server
+        .mock("GET", mockito::Matcher::Regex("^/repos/owner/".to_string()))
+        .with_status(429)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let revalidation = handle_revalidate(
+        &db,
+        "",
+        &reqwest::Client::new(),
+        None,
+        &server.url(),
+        1,
+        Duration::ZERO,
+    )
+    .await
+    .unwrap();
+
+    limited.assert_async().await;
+    assert!(revalidation.gone.is_empty());
+    assert!(
+        revalidation
+            .report
+            .contains("2 repositories were not checked")
+    );
+}
@@ -0,0 +1,49 @@
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::list::{ListMode, send_repository_list};
+use crate::messages::{TrackingText, render, text};
+use crate::tracked_repositories::repository::{
+    SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+};
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    term: String,
+) -> ResponseResult<()> {
+    let lang = chat_language(&state.db, msg.chat.id.0).await;
+    let term = term.trim();
+    if term.is_empty() {
+        bot.send_message(msg.chat.id, text(lang, TrackingText::SearchTermMissing))
+            .await?;
+        return Ok(());
+    }
+
+    let repository = SqliteTrackedRepositoriesRepository::new(state.db.clone());
+    match repository
+        .find_by_chat_id_and_name_like(&state.bot_id, msg.chat.id.0, term)
+        .await
+    {
+        Ok(repos) if repos.is_empty() => {
+            let reply = render(lang, TrackingText::NoMatches, &[("pattern", term)]);
+            bot.send_message(msg.chat.id, reply).await?;
+        }
+        Ok(repos) => {
+            let title = render(lang, TrackingText::Matching, &[("pattern", term)]);
+            send_repository_list(bot, msg, state, &title, repos, ListMode::Standard).await?;
+        }
+        Err(e) => {
+            let error = render(
+                lang,
+                TrackingText::SearchFailed,
+                &[("error", &e.to_string())],
+            );
+            bot.send_message(msg.chat.id, error).await?;
+        }
+    }
+
+    Ok(())
+}
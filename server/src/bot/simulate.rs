@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::chat_settings::Lang;
+use crate::github::{HttpReleaseSource, github_api_base};
+use crate::messages::{PollText, render, text};
+use crate::poller::{AppState, CycleReport, poll_once_with_source};
+use crate::utils::{TELEGRAM_MESSAGE_LIMIT, split_message};
+
+/// Describes what a dry-run poll cycle would announce.
+pub(crate) fn describe_report(report: &CycleReport, lang: Lang) -> String {
+    let mut reply = match report.notified.len() {
+        0 => text(lang, PollText::WouldNotifyNothing).to_string(),
+        1 => text(lang, PollText::WouldSendOne).to_string(),
+        n => render(lang, PollText::WouldSend, &[("count", &n.to_string())]),
+    };
+    for notified in &report.notified {
+        reply.push_str(&format!("\n- {notified}"));
+    }
+    if report.rate_limited > 0 {
+        let count = report.rate_limited.to_string();
+        reply.push('\n');
+        reply.push_str(&render(
+            lang,
+            PollText::RefusedForRateLimit,
+            &[("count", &count)],
+        ));
+    }
+    reply
+}
+
+pub(super) async fn answer(bot: &Bot, msg: &Message, state: &BotState) -> ResponseResult<()> {
+    let config = &state.config;
+    let poller_state = Arc::new(AppState {
+        db: state.db.clone(),
+        bot_id: state.bot_id.clone(),
+        cache_write_batch_size: config.cache_write_batch_size,
+        release_history_limit: config.release_history_limit,
+        schedule: state.schedule.clone(),
+        pause_inaccessible: config.pause_inaccessible_repositories,
+        cycle_lock: Default::default(),
+    });
+    let client = reqwest::Client::new();
+    let token_opt = config.resolve_token().token();
+    let release_source = Arc::new(HttpReleaseSource::new(
+        client.clone(),
+        token_opt,
+        github_api_base(),
+    ));
+
+    let report = poll_once_with_source(
+        poller_state,
+        bot,
+        release_source,
+        &client,
+        token_opt,
+        None,
+        true,
+    )
+    .await;
+    let lang = chat_language(&state.db, msg.chat.id.0).await;
+    for chunk in split_message(&describe_report(&report, lang), TELEGRAM_MESSAGE_LIMIT) {
+        bot.send_message(msg.chat.id, chunk).await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_what_would_be_notified() {
+        let report = CycleReport {
+            rate_limited: 2,
+            notified: vec![
+                "owner/repo v2.0.0 (chat 1)".to_string(),
+                "owner/other milestone 100 (chat 2)".to_string(),
+            ],
+            retry_after: None,
+        };
+
+        assert_eq!(
+            describe_report(&report, Lang::En),
+            "A poll now would send 2 notifications:\n\
+             - owner/repo v2.0.0 (chat 1)\n\
+             - owner/other milestone 100 (chat 2)\n\
+             GitHub refused 2 repositories for a rate limit."
+        );
+        assert_eq!(
+            describe_report(&CycleReport::default(), Lang::En),
+            "A poll now would notify nothing."
+        );
+        assert!(
+            describe_report(&report, Lang::De)
+                .starts_with("Eine Abfrage jetzt würde 2 Benachrichtigungen senden:")
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::chat_settings::Lang;
+use crate::github::{HttpReleaseSource, Release, github_api_base};
+use crate::messages::{CommandText, ReleaseText, render};
+use crate::utils::{TELEGRAM_MESSAGE_LIMIT, parse_duration, split_message};
+
+/// Pages of the releases list walked at most, so a busy repository costs a few requests.
+const MAX_PAGES: u32 = 5;
+
+/// Releases listed at most; older ones are only counted.
+const MAX_LISTED: usize = 50;
+
+/// Reads the start of a `/since` range: an ISO date such as `2024-05-01` (midnight UTC),
+/// a full RFC 3339 time, or a duration such as `30d` counted back from `now`.
+pub(crate) fn parse_since(
+    lang: Lang,
+    input: &str,
+    now: DateTime<Utc>,
+) -> Result<DateTime<Utc>, String> {
+    let input = input.trim();
+    if let Ok(date) = NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+        return Ok(date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc());
+    }
+    if let Ok(at) = DateTime::parse_from_rfc3339(input) {
+        return Ok(at.with_timezone(&Utc));
+    }
+    let ago = parse_duration(input)
+        .map_err(|_| render(lang, ReleaseText::InvalidDate, &[("input", input)]))?;
+    chrono::Duration::from_std(ago)
+        .ok()
+        .and_then(|ago| now.checked_sub_signed(ago))
+        .ok_or_else(|| render(lang, ReleaseText::DateTooFarBack, &[("input", input)]))
+}
+
+/// The releases published after `since`, in the order given. Releases without a publish
+/// date are left out.
+pub(crate) fn published_after(releases: Vec<Release>, since: DateTime<Utc>) -> Vec<Release> {
+    releases
+        .into_iter()
+        .filter(|r| r.details.published_at.is_some_and(|at| at > since))
+        .collect()
+}
+
+fn format_release(release: &Release) -> String {
+    let date = release
+        .details
+        .published_at
+        .map(|at| at.format("%Y-%m-%d").to_string())
+        .unwrap_or_default();
+    match &release.details.html_url {
+        Some(link) => format!("- {} ({date}) {link}", release.tag_name),
+        None => format!("- {} ({date})", release.tag_name),
+    }
+}
+
+/// Lists the releases of a tracked repository published after `date`, newest first.
+pub(crate) async fn handle_since(
+    db: &SqlitePool,
+    bot_id: &str,
+    release_source: &HttpReleaseSource,
+    chat_id: i64,
+    url: &str,
+    date: &str,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let since = parse_since(lang, date, Utc::now())?;
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+    let name = &tracked.repository_name;
+    let Some((owner, repo)) = tracked.repository_url.owner_and_repo() else {
+        return Err(render(
+            lang,
+            ReleaseText::NotGithubReleases,
+            &[("repository", name)],
+        ));
+    };
+    let mut found = Vec::new();
+    let mut complete = false;
+    for page in 1..=MAX_PAGES {
+        let (releases, full) = release_source
+            .release_page(&owner, &repo, page)
+            .await
+            .map_err(|e| {
+                render(
+                    lang,
+                    CommandText::LookupFailed,
+                    &[("repository", name), ("error", &e.to_string())],
+                )
+            })?;
+        // The list is newest first, so one older release means the rest are older too
+        let reached_since = releases
+            .iter()
+            .any(|r| r.details.published_at.is_some_and(|at| at <= since));
+        found.extend(published_after(releases, since));
+        if reached_since || !full {
+            complete = true;
+            break;
+        }
+    }
+
+    let since_text = since.format("%Y-%m-%d %H:%M UTC");
+    if found.is_empty() {
+        return Ok(render(
+            lang,
+            ReleaseText::NoReleasesSince,
+            &[("repository", name), ("since", &since_text.to_string())],
+        ));
+    }
+    let count = if complete {
+        found.len().to_string()
+    } else {
+        render(
+            lang,
+            ReleaseText::AtLeast,
+            &[("count", &found.len().to_string())],
+        )
+    };
+    let mut lines = vec![render(
+        lang,
+        ReleaseText::ReleasesSince,
+        &[
+            ("repository", name),
+            ("since", &since_text.to_string()),
+            ("count", &count),
+        ],
+    )];
+    lines.extend(found.iter().take(MAX_LISTED).map(format_release));
+    if found.len() > MAX_LISTED {
+        let older = (found.len() - MAX_LISTED).to_string();
+        lines.push(render(
+            lang,
+            ReleaseText::OlderNotShown,
+            &[("count", &older)],
+        ));
+    }
+    Ok(lines.join("\n"))
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+    date: String,
+) -> ResponseResult<()> {
+    let release_source = HttpReleaseSource::new(
+        reqwest::Client::new(),
+        state.config.resolve_token().token(),
+        github_api_base(),
+    );
+    let text = match handle_since(
+        &state.db,
+        &state.bot_id,
+        &release_source,
+        msg.chat.id.0,
+        &url,
+        &date,
+    )
+    .await
+    {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    for chunk in split_message(&text, TELEGRAM_MESSAGE_LIMIT) {
+        bot.send_message(msg.chat.id, chunk).await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,152 @@
+use super::*;
+use crate::bot::track::handle_track;
+use crate::github::ReleaseDetails;
+use mockito::Server;
+use sqlx::sqlite::SqlitePoolOptions;
+
+async fn setup_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("failed to create in-memory sqlite pool");
+
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("failed to run migrations");
+
+    pool
+}
+
+fn at(date: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(date)
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+fn release(tag: &str, published_at: Option<&str>) -> Release {
+    Release {
+        tag_name: tag.to_string(),
+        body: None,
+        details: ReleaseDetails {
+            html_url: None,
+            published_at: published_at.map(at),
+            author: None,
+            is_release: true,
+            from_feed: false,
+        },
+    }
+}
+
+#[test]
+fn parses_dates_and_relative_durations() {
+    let now = at("2024-06-10T12:00:00Z");
+    assert_eq!(
+        parse_since(Lang::En, "2024-05-01", now).unwrap(),
+        at("2024-05-01T00:00:00Z")
+    );
+    assert_eq!(
+        parse_since(Lang::En, "2024-05-01T08:30:00+02:00", now).unwrap(),
+        at("2024-05-01T06:30:00Z")
+    );
+    assert_eq!(
+        parse_since(Lang::En, "30d", now).unwrap(),
+        at("2024-05-11T12:00:00Z")
+    );
+    assert_eq!(
+        parse_since(Lang::En, "12h", now).unwrap(),
+        at("2024-06-10T00:00:00Z")
+    );
+    assert!(parse_since(Lang::En, "last week", now).is_err());
+    assert!(parse_since(Lang::En, "2024-13-01", now).is_err());
+}
+
+#[test]
+fn keeps_releases_published_after_the_date() {
+    let releases = vec![
+        release("v3", Some("2024-06-01T00:00:00Z")),
+        release("v2", Some("2024-05-01T00:00:00Z")),
+        release("undated", None),
+        release("v1", Some("2024-04-01T00:00:00Z")),
+    ];
+
+    let tags: Vec<String> = published_after(releases, at("2024-05-01T00:00:00Z"))
+        .into_iter()
+        .map(|r| r.tag_name)
+        .collect();
+
+    assert_eq!(tags, vec!["v3".to_string()]);
+}
+
+#[tokio::test]
+async fn stops_at_the_first_page_reaching_the_date_and_caps_the_list() {
+    let db = setup_db().await;
+    handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+        .await
+        .unwrap();
+    // Full pages of the releases list hold 100 releases
+    let newest = Utc::now();
+    let page = |page: u32| -> serde_json::Value {
+        (0..100)
+            .map(|i| {
+                let n = (page - 1) * 100 + i;
+                serde_json::json!({
+                    "tag_name": format!("v{n}"),
+                    "published_at": (newest - chrono::Duration::hours(n as i64)).to_rfc3339(),
+                    "draft": false,
+                    "prerelease": false
+                })
+            })
+            .collect()
+    };
+    let mut server = Server::new_async().await;
+    let mut pages = Vec::new();
+    for n in 1..=2 {
+        pages.push(
+            server
+                .mock("GET", "/repos/owner/repo/releases")
+                .match_query(mockito::Matcher::UrlEncoded(
+                    "page".to_string(),
+                    n.to_string(),
+                ))
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(page(n).to_string())
+                .expect(1)
+                .create_async()
+                .await,
+        );
+    }
+    let m_third = server
+        .mock("GET", "/repos/owner/repo/releases")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "page".to_string(),
+            "3".to_string(),
+        ))
+        .expect(0)
+        .create_async()
+        .await;
+
+    // Six days back reaches into the second page of hourly releases
+    let text = handle_since(
+        &db,
+        "",
+        &HttpReleaseSource::new(reqwest::Client::new(), None, server.url()),
+        1,
+        "https://github.com/owner/repo",
+        "6d",
+    )
+    .await
+    .expect("should succeed");
+
+    for m in &pages {
+        m.assert_async().await;
+    }
+    m_third.assert_async().await;
+    let lines: Vec<&str> = text.lines().collect();
+    assert!(lines[0].ends_with(": 144"), "{}", lines[0]);
+    assert!(lines[1].starts_with("- v0 "));
+    assert_eq!(lines.len(), 1 + MAX_LISTED + 1);
+    assert_eq!(lines[MAX_LISTED + 1], "…and 94 older ones not shown.");
+}
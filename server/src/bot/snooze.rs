@@ -0,0 +1,254 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::messages::{CommandText, ScheduleText, render, text};
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+use crate::utils::parse_duration;
+
+pub(crate) async fn handle_snooze(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    url: &str,
+    duration: &str,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let duration = parse_duration(duration).map_err(|e| e.message(lang))?;
+    let duration = chrono::Duration::from_std(duration)
+        .map_err(|_| text(lang, CommandText::DurationTooLong).to_string())?;
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+
+    let repository = SqliteRepositorySettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(&tracked.id).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::LoadSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    let now = chrono::Utc::now();
+
+    if duration.is_zero() {
+        settings.snoozed_until = None;
+        settings.snooze_missed_tag = None;
+    } else {
+        let until = now
+            .checked_add_signed(duration)
+            .ok_or_else(|| text(lang, CommandText::DurationTooLong).to_string())?;
+        settings.snoozed_until = Some(until);
+    }
+    settings.updated_at = now;
+    repository.save(&settings).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::SaveSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    let repository = tracked.repository_name.as_str();
+    match settings.snoozed_until {
+        Some(until) => Ok(render(
+            lang,
+            ScheduleText::SnoozedUntil,
+            &[
+                ("repository", repository),
+                ("until", &until.format("%Y-%m-%d %H:%M UTC").to_string()),
+            ],
+        )),
+        None => Ok(render(
+            lang,
+            ScheduleText::SnoozeEnded,
+            &[("repository", repository)],
+        )),
+    }
+}
+
+/// Snoozes every notification of the chat for `duration`, or resumes them with `off`
+/// or `0`. Releases keep being cached meanwhile, so nothing is announced twice.
+pub(crate) async fn handle_snooze_all(
+    db: &SqlitePool,
+    chat_id: i64,
+    duration: &str,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let duration = duration.trim();
+    let duration = if duration.eq_ignore_ascii_case("off") {
+        std::time::Duration::ZERO
+    } else {
+        parse_duration(duration).map_err(|e| e.message(lang))?
+    };
+    let duration = chrono::Duration::from_std(duration)
+        .map_err(|_| text(lang, CommandText::DurationTooLong).to_string())?;
+
+    let repository = SqliteChatSettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(chat_id).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::LoadChatSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    let now = chrono::Utc::now();
+
+    let mut missed = Vec::new();
+    if duration.is_zero() {
+        settings.snoozed_until = None;
+        missed = std::mem::take(&mut settings.snooze_missed);
+    } else {
+        let until = now
+            .checked_add_signed(duration)
+            .ok_or_else(|| text(lang, CommandText::DurationTooLong).to_string())?;
+        settings.snoozed_until = Some(until);
+    }
+    settings.updated_at = now;
+    repository.save(&settings).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::SaveChatSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    Ok(match settings.snoozed_until {
+        Some(until) => render(
+            lang,
+            ScheduleText::ChatSnoozedUntil,
+            &[("until", &until.format("%Y-%m-%d %H:%M UTC").to_string())],
+        ),
+        None if missed.is_empty() => text(lang, ScheduleText::ChatSnoozeOff).to_string(),
+        None => render(
+            lang,
+            ScheduleText::ChatSnoozeOffMissed,
+            &[("releases", &missed.join(", "))],
+        ),
+    })
+}
+
+pub(super) async fn answer_all(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    duration: String,
+) -> ResponseResult<()> {
+    let text = match handle_snooze_all(&state.db, msg.chat.id.0, &duration).await {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+    duration: String,
+) -> ResponseResult<()> {
+    let text = match handle_snooze(&state.db, &state.bot_id, msg.chat.id.0, &url, &duration).await {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn snoozes_and_unsnoozes() {
+        let db = setup_db().await;
+        let id = match handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+            .await
+            .unwrap()
+        {
+            HandleTrackResult::Created { id, .. } => id,
+            _ => panic!("expected Created"),
+        };
+        let repository = SqliteRepositorySettingsRepository::new(db.clone());
+
+        let before = chrono::Utc::now();
+        let message = handle_snooze(&db, "", 1, "https://github.com/owner/repo", "2h")
+            .await
+            .expect("should succeed");
+        assert!(message.contains("snoozed until"));
+        let until = repository
+            .find_or_default(&id)
+            .await
+            .unwrap()
+            .snoozed_until
+            .expect("snoozed");
+        assert!(until >= before + chrono::Duration::hours(2));
+
+        handle_snooze(&db, "", 1, "https://github.com/owner/repo", "0")
+            .await
+            .expect("should succeed");
+        let settings = repository.find_or_default(&id).await.unwrap();
+        assert_eq!(settings.snoozed_until, None);
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_duration() {
+        let db = setup_db().await;
+        let err = handle_snooze(&db, "", 1, "https://github.com/owner/repo", "soon")
+            .await
+            .expect_err("invalid duration");
+        assert!(err.contains("Invalid duration"));
+    }
+
+    #[tokio::test]
+    async fn snoozes_the_whole_chat() {
+        let db = setup_db().await;
+        let repository = SqliteChatSettingsRepository::new(db.clone());
+
+        let message = handle_snooze_all(&db, 1, "1d")
+            .await
+            .expect("should succeed");
+        assert!(message.starts_with("All notifications in this chat are snoozed until"));
+        let mut settings = repository.find_or_default(1).await.unwrap();
+        assert!(settings.snoozed_until.is_some());
+
+        settings.snooze_missed = vec!["a".to_string(), "b".to_string()];
+        repository.save(&settings).await.unwrap();
+        let message = handle_snooze_all(&db, 1, "off")
+            .await
+            .expect("should succeed");
+        assert_eq!(
+            message,
+            "Notifications in this chat are no longer snoozed. Released meanwhile: a, b"
+        );
+        let settings = repository.find_or_default(1).await.unwrap();
+        assert_eq!(settings.snoozed_until, None);
+        assert!(settings.snooze_missed.is_empty());
+
+        assert!(handle_snooze_all(&db, 1, "later").await.is_err());
+    }
+}
@@ -0,0 +1,175 @@
+use chrono::{DateTime, Duration, Utc};
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::messages::{CommandText, ReleaseText, render};
+use crate::tracked_repositories::tracked_repositories_releases::repository::{
+    CachedRepositoryReleasesRepository, SqliteCachedRepositoryReleasesRepository,
+};
+use crate::utils::{
+    MAX_NAME_CHARS, MAX_TAG_CHARS, TELEGRAM_MESSAGE_LIMIT, split_message, truncate_chars,
+};
+
+/// Lists the chat's repositories whose latest known release is more than `days` days old.
+pub(crate) async fn handle_stale(
+    db: &SqlitePool,
+    chat_id: i64,
+    days: &str,
+    now: DateTime<Utc>,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let days: i64 = days
+        .trim()
+        .parse()
+        .ok()
+        .filter(|d| *d > 0 && *d <= 36_500)
+        .ok_or_else(|| {
+            render(
+                lang,
+                CommandText::UsageExample,
+                &[("usage", "/stale <days>"), ("example", "/stale 180")],
+            )
+        })?;
+
+    let stale = SqliteCachedRepositoryReleasesRepository::new(db.clone())
+        .find_stale_for_chat(chat_id, now - Duration::days(days))
+        .await
+        .map_err(|e| render(lang, ReleaseText::StaleFailed, &[("error", &e.to_string())]))?;
+    if stale.is_empty() {
+        return Ok(render(
+            lang,
+            ReleaseText::NothingStale,
+            &[("days", &days.to_string())],
+        ));
+    }
+
+    let mut lines = vec![render(
+        lang,
+        ReleaseText::StaleHeading,
+        &[("days", &days.to_string())],
+    )];
+    for s in stale {
+        lines.push(render(
+            lang,
+            ReleaseText::StaleLine,
+            &[
+                (
+                    "repository",
+                    &truncate_chars(&s.repository_name, MAX_NAME_CHARS),
+                ),
+                ("tag", &truncate_chars(&s.tag_name, MAX_TAG_CHARS)),
+                ("days", &(now - s.first_seen_at).num_days().to_string()),
+            ],
+        ));
+    }
+    Ok(lines.join("\n"))
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    days: String,
+) -> ResponseResult<()> {
+    let text = match handle_stale(&state.db, msg.chat.id.0, &days, Utc::now()).await {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    for page in split_message(&text, TELEGRAM_MESSAGE_LIMIT) {
+        bot.send_message(msg.chat.id, page).await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use crate::tracked_repositories::tracked_repositories_releases::CachedRepositoryRelease;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    async fn track_with_release(
+        db: &SqlitePool,
+        chat_id: i64,
+        name: &str,
+        tag: Option<&str>,
+        first_seen_at: DateTime<Utc>,
+    ) {
+        let url = format!("https://github.com/owner/{name}");
+        let HandleTrackResult::Created { id, .. } =
+            handle_track(db, "", chat_id, name, &url).await.unwrap()
+        else {
+            panic!("expected Created");
+        };
+        if let Some(tag) = tag {
+            SqliteCachedRepositoryReleasesRepository::new(db.clone())
+                .save(&CachedRepositoryRelease {
+                    tracked_repository_id: id,
+                    tag_name: tag.to_string(),
+                    first_seen_at,
+                    body_hash: None,
+                    from_release: true,
+                })
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn lists_repositories_past_the_threshold_oldest_first() {
+        let db = setup_db().await;
+        let now = Utc::now();
+        track_with_release(&db, 1, "fresh", Some("v3.0"), now - Duration::days(5)).await;
+        track_with_release(&db, 1, "older", Some("v2.0"), now - Duration::days(200)).await;
+        track_with_release(&db, 1, "oldest", Some("v1.0"), now - Duration::days(400)).await;
+        track_with_release(&db, 1, "unreleased", None, now).await;
+        track_with_release(&db, 2, "elsewhere", Some("v0.1"), now - Duration::days(900)).await;
+
+        let text = handle_stale(&db, 1, "180", now).await.unwrap();
+        assert_eq!(
+            text,
+            "No release in more than 180 days:\n\
+             - oldest: v1.0, 400 days ago\n\
+             - older: v2.0, 200 days ago"
+        );
+    }
+
+    #[tokio::test]
+    async fn says_so_when_nothing_is_stale() {
+        let db = setup_db().await;
+        let now = Utc::now();
+        track_with_release(&db, 1, "fresh", Some("v3.0"), now - Duration::days(5)).await;
+
+        assert_eq!(
+            handle_stale(&db, 1, "30", now).await.unwrap(),
+            "No repository has gone more than 30 days without a release."
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_thresholds() {
+        let db = setup_db().await;
+        let now = Utc::now();
+        for days in ["", "0", "-3", "soon", "99999999999999999999"] {
+            assert!(handle_stale(&db, 1, days, now).await.is_err(), "{days}");
+        }
+    }
+}
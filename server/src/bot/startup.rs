@@ -0,0 +1,77 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::configuration::Configuration;
+use crate::tracked_repositories::repository::{
+    SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+};
+
+/// The message telling the operator the bot is up and what it tracks.
+pub(crate) async fn startup_message(db: &SqlitePool, interval_secs: u64) -> Result<String, String> {
+    let counts = SqliteTrackedRepositoriesRepository::new(db.clone())
+        .count_tracking()
+        .await
+        .map_err(|e| format!("Failed to count tracked repositories: {e}"))?;
+    Ok(format!(
+        "Bot started, tracking {} repos across {} chats, poll interval {}s",
+        counts.repositories, counts.chats, interval_secs
+    ))
+}
+
+/// Tells the `OPERATOR_CHAT_ID` chat, when configured, that the bot started. Failures are
+/// only logged so an unreachable operator chat never stops the bot from starting.
+pub async fn announce_startup(bot: &Bot, db: &SqlitePool, config: &Configuration) {
+    let Some(chat_id) = config.operator_chat_id else {
+        return;
+    };
+    let text = match startup_message(db, config.interval_secs).await {
+        Ok(text) => text,
+        Err(e) => {
+            log::warn!("{}", e);
+            return;
+        }
+    };
+    if let Err(e) = bot.send_message(ChatId(chat_id), text).await {
+        log::warn!("Failed to send the startup message to {}: {}", chat_id, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::handle_track;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn reports_repositories_chats_and_interval() {
+        let db = setup_db().await;
+        assert_eq!(
+            startup_message(&db, 300).await.unwrap(),
+            "Bot started, tracking 0 repos across 0 chats, poll interval 300s"
+        );
+
+        for (chat_id, name) in [(1, "alpha"), (1, "beta"), (2, "gamma")] {
+            let url = format!("https://github.com/owner/{name}");
+            handle_track(&db, "", chat_id, name, &url).await.unwrap();
+        }
+        assert_eq!(
+            startup_message(&db, 60).await.unwrap(),
+            "Bot started, tracking 3 repos across 2 chats, poll interval 60s"
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use chrono::{Duration, Utc};
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::chat_settings::Lang;
+use crate::messages::{InfoText, render, text};
+use crate::notifications::NotificationCount;
+use crate::notifications::repository::{
+    NotificationReceiptsRepository, SqliteNotificationReceiptsRepository,
+};
+
+/// Repositories listed individually before the rest is summarised.
+const MAX_LISTED_REPOSITORIES: usize = 20;
+
+fn format_stats(counts: &[NotificationCount], lang: Lang) -> String {
+    let week: i64 = counts.iter().map(|c| c.last_week).sum();
+    let month: i64 = counts.iter().map(|c| c.last_month).sum();
+    let mut reply = render(
+        lang,
+        InfoText::StatsTotals,
+        &[("week", &week.to_string()), ("month", &month.to_string())],
+    );
+    if counts.is_empty() {
+        return reply;
+    }
+
+    reply.push_str("\n\n");
+    reply.push_str(text(lang, InfoText::StatsByRepository));
+    for c in counts.iter().take(MAX_LISTED_REPOSITORIES) {
+        reply.push_str(&format!(
+            "\n- {}: {} / {}",
+            c.repository_name, c.last_week, c.last_month
+        ));
+    }
+    if counts.len() > MAX_LISTED_REPOSITORIES {
+        let more = (counts.len() - MAX_LISTED_REPOSITORIES).to_string();
+        reply.push('\n');
+        reply.push_str(&render(lang, InfoText::StatsMore, &[("count", &more)]));
+    }
+    reply
+}
+
+pub(super) async fn answer(bot: &Bot, msg: &Message, state: &BotState) -> ResponseResult<()> {
+    let now = Utc::now();
+    let lang = chat_language(&state.db, msg.chat.id.0).await;
+    let reply = match SqliteNotificationReceiptsRepository::new(state.db.clone())
+        .count_by_repository(
+            msg.chat.id.0,
+            now - Duration::days(7),
+            now - Duration::days(30),
+        )
+        .await
+    {
+        Ok(counts) => format_stats(&counts, lang),
+        Err(e) => render(lang, InfoText::StatsFailed, &[("error", &e.to_string())]),
+    };
+    bot.send_message(msg.chat.id, reply).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_totals_and_repositories() {
+        let counts = vec![
+            NotificationCount {
+                repository_name: "tokio".to_string(),
+                last_week: 2,
+                last_month: 5,
+            },
+            NotificationCount {
+                repository_name: "serde".to_string(),
+                last_week: 0,
+                last_month: 1,
+            },
+        ];
+        assert_eq!(
+            format_stats(&counts, Lang::En),
+            "Notifications sent to this chat:\nLast 7 days: 2\nLast 30 days: 6\n\n\
+             By repository (7 days / 30 days):\n- tokio: 2 / 5\n- serde: 0 / 1"
+        );
+        assert!(format_stats(&[], Lang::En).ends_with("Last 30 days: 0"));
+        assert!(format_stats(&counts, Lang::De).contains("Nach Repository (7 Tage / 30 Tage):"));
+    }
+}
@@ -0,0 +1,105 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::chat_settings::Lang;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::messages::{CommandText, StatusText, TrackingText, render, text};
+use crate::tracked_repositories::repository::{
+    SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+};
+
+/// Summarises what the chat tracks and whether its notifications are snoozed.
+pub(crate) async fn handle_status(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+) -> Result<String, String> {
+    let settings = SqliteChatSettingsRepository::new(db.clone())
+        .find_or_default(chat_id)
+        .await
+        .map_err(|e| {
+            render(
+                Lang::default(),
+                CommandText::LoadChatSettingsFailed,
+                &[("error", &e.to_string())],
+            )
+        })?;
+    let lang = settings.language;
+    let repos = SqliteTrackedRepositoriesRepository::new(db.clone())
+        .find_all_by_chat_id(bot_id, chat_id)
+        .await
+        .map_err(|e| render(lang, TrackingText::ListFailed, &[("error", &e.to_string())]))?;
+
+    let count = repos.len().to_string();
+    let mut lines = vec![render(lang, StatusText::Tracking, &[("count", &count)])];
+    match settings.snoozed_until {
+        Some(until) if until > chrono::Utc::now() => {
+            let until = until.format("%Y-%m-%d %H:%M UTC").to_string();
+            lines.push(render(lang, StatusText::AllSnoozed, &[("until", &until)]));
+            if !settings.snooze_missed.is_empty() {
+                let releases = settings.snooze_missed.join(", ");
+                lines.push(render(
+                    lang,
+                    StatusText::ReleasedMeanwhile,
+                    &[("releases", &releases)],
+                ));
+            }
+        }
+        // An expired snooze is cleared by the next poll
+        Some(_) => lines.push(text(lang, StatusText::SnoozeOver).to_string()),
+        None => lines.push(text(lang, StatusText::NotificationsOn).to_string()),
+    }
+    Ok(lines.join("\n"))
+}
+
+pub(super) async fn answer(bot: &Bot, msg: &Message, state: &BotState) -> ResponseResult<()> {
+    let text = match handle_status(&state.db, &state.bot_id, msg.chat.id.0).await {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::snooze::handle_snooze_all;
+    use crate::bot::track::handle_track;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn reports_the_chat_snooze() {
+        let db = setup_db().await;
+        handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+            .await
+            .unwrap();
+
+        let status = handle_status(&db, "", 1).await.unwrap();
+        assert_eq!(status, "Tracking 1 repositories.\nNotifications are on.");
+
+        handle_snooze_all(&db, 1, "2h").await.unwrap();
+        let status = handle_status(&db, "", 1).await.unwrap();
+        assert!(
+            status.contains("All notifications are snoozed until"),
+            "{status}"
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use teloxide::prelude::*;
+use teloxide::types::ChatId;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::chat_settings::Lang;
+use crate::messages::{InfoText, render};
+use crate::tracked_repositories::RepositoryUrl;
+use crate::tracked_repositories::repository::{
+    SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+};
+
+fn format_subscribers(url: &str, subscribers: &[(i64, Option<String>)], lang: Lang) -> String {
+    let lines: Vec<String> = subscribers
+        .iter()
+        .map(|(chat_id, title)| match title {
+            Some(t) => format!("- {} ({})", chat_id, t),
+            None => format!("- {}", chat_id),
+        })
+        .collect();
+    format!(
+        "{}\n{}",
+        render(lang, InfoText::Subscribers, &[("url", url)]),
+        lines.join("\n")
+    )
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+) -> ResponseResult<()> {
+    let lang = chat_language(&state.db, msg.chat.id.0).await;
+    let repository_url = match RepositoryUrl::new(url.trim().to_string()) {
+        Ok(u) => u,
+        Err(e) => {
+            bot.send_message(msg.chat.id, e.message(lang)).await?;
+            return Ok(());
+        }
+    };
+
+    let repository = SqliteTrackedRepositoriesRepository::new(state.db.clone());
+    // Chats of other bots sharing the database are not this bot's to resolve
+    let chat_ids = match repository
+        .find_all_by_repository_url(&repository_url.url())
+        .await
+    {
+        Ok(rows) => {
+            let mut ids: Vec<i64> = rows
+                .into_iter()
+                .filter(|r| r.bot_id == state.bot_id)
+                .map(|r| r.chat_id)
+                .collect();
+            ids.sort_unstable();
+            ids
+        }
+        Err(e) => {
+            let reply = render(
+                lang,
+                InfoText::SubscribersFailed,
+                &[("error", &e.to_string())],
+            );
+            bot.send_message(msg.chat.id, reply).await?;
+            return Ok(());
+        }
+    };
+
+    if chat_ids.is_empty() {
+        let reply = render(
+            lang,
+            InfoText::NotTracked,
+            &[("repository", &repository_url.to_string())],
+        );
+        bot.send_message(msg.chat.id, reply).await?;
+        return Ok(());
+    }
+
+    let mut subscribers = Vec::with_capacity(chat_ids.len());
+    for chat_id in chat_ids {
+        let title = match bot.get_chat(ChatId(chat_id)).await {
+            Ok(chat) => chat
+                .title()
+                .map(str::to_string)
+                .or_else(|| chat.username().map(|u| format!("@{u}"))),
+            Err(e) => {
+                log::debug!("Could not resolve chat {}: {}", chat_id, e);
+                None
+            }
+        };
+        subscribers.push((chat_id, title));
+    }
+
+    bot.send_message(
+        msg.chat.id,
+        format_subscribers(&repository_url.url(), &subscribers, lang),
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_chat_ids_with_optional_titles() {
+        let text = format_subscribers(
+            "https://github.com/owner/repo",
+            &[(1, Some("Team".to_string())), (-1002, None)],
+            Lang::En,
+        );
+        assert_eq!(
+            text,
+            "Chats subscribed to https://github.com/owner/repo:\n- 1 (Team)\n- -1002"
+        );
+    }
+}
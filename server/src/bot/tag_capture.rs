@@ -0,0 +1,154 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::messages::{CommandText, SourceText, render};
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+use crate::tracked_repositories::settings::tag_capture::compile;
+
+/// Sets the regex whose capture group names the version in a tracked repository's tags,
+/// e.g. `^release-(\d+\.\d+)$`, or clears it with `off`. The poller then follows the
+/// tags it matches and announces the captured version.
+pub(crate) async fn handle_tag_capture(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    url: &str,
+    pattern: &str,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let pattern = pattern.trim();
+    if pattern.is_empty() {
+        return Err(render(
+            lang,
+            CommandText::UsageExample,
+            &[
+                ("usage", "/tag_capture <url> <regex|off>"),
+                ("example", "^release-(\\d+\\.\\d+)$"),
+            ],
+        ));
+    }
+    let tag_capture = if pattern.eq_ignore_ascii_case("off") {
+        None
+    } else {
+        compile(pattern)?;
+        Some(pattern.to_string())
+    };
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+
+    let repository = SqliteRepositorySettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(&tracked.id).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::LoadSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    settings.tag_capture = tag_capture;
+    settings.updated_at = chrono::Utc::now();
+    repository.save(&settings).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::SaveSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    let repository = tracked.repository_name.as_str();
+    match &settings.tag_capture {
+        Some(pattern) => Ok(render(
+            lang,
+            SourceText::TagCaptureSet,
+            &[("repository", repository), ("pattern", pattern)],
+        )),
+        None => Ok(render(
+            lang,
+            SourceText::TagCaptureCleared,
+            &[("repository", repository)],
+        )),
+    }
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+    pattern: String,
+) -> ResponseResult<()> {
+    let text =
+        match handle_tag_capture(&state.db, &state.bot_id, msg.chat.id.0, &url, &pattern).await {
+            Ok(message) => message,
+            Err(err_msg) => err_msg,
+        };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::handle_track;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn sets_clears_and_rejects_capture_patterns() {
+        let db = setup_db().await;
+        let url = "https://github.com/owner/repo";
+        let id = handle_track(&db, "", 1, "repo", url).await.unwrap().id();
+        let repository = SqliteRepositorySettingsRepository::new(db.clone());
+
+        handle_tag_capture(&db, "", 1, url, r"^release-(\d+\.\d+)$")
+            .await
+            .unwrap();
+        assert_eq!(
+            repository
+                .find_or_default(&id)
+                .await
+                .unwrap()
+                .tag_capture
+                .as_deref(),
+            Some(r"^release-(\d+\.\d+)$")
+        );
+
+        // Invalid patterns leave the stored one in place
+        for pattern in ["", "release-(", r"^release-\d+$", "(a{1000}){1000}"] {
+            assert!(handle_tag_capture(&db, "", 1, url, pattern).await.is_err());
+        }
+        assert!(
+            repository
+                .find_or_default(&id)
+                .await
+                .unwrap()
+                .tag_capture
+                .is_some()
+        );
+
+        handle_tag_capture(&db, "", 1, url, "off").await.unwrap();
+        assert_eq!(
+            repository.find_or_default(&id).await.unwrap().tag_capture,
+            None
+        );
+        assert!(handle_tag_capture(&db, "", 2, url, "(.+)").await.is_err());
+    }
+}
@@ -0,0 +1,155 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::messages::{CommandText, SettingText, render, text};
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+use crate::utils::MAX_TAG_CHARS;
+
+/// Sets the glob of tags to skip for a tracked repository, e.g. `*nightly*`, or clears it
+/// with `off`. The poller then announces the newest release that does not match.
+pub(crate) async fn handle_ignore_tags(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    url: &str,
+    pattern: &str,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let pattern = pattern.trim();
+    if pattern.is_empty() {
+        return Err(render(
+            lang,
+            CommandText::UsageExample,
+            &[
+                ("usage", "/ignore_tags <url> <glob|off>"),
+                ("example", "*nightly*"),
+            ],
+        ));
+    }
+    if pattern.chars().count() > MAX_TAG_CHARS {
+        return Err(render(
+            lang,
+            CommandText::PatternTooLong,
+            &[("max", &MAX_TAG_CHARS.to_string())],
+        ));
+    }
+    if pattern.chars().all(|c| c == '*') {
+        return Err(text(lang, SettingText::IgnoreEveryTag).to_string());
+    }
+    let tag_ignore = (!pattern.eq_ignore_ascii_case("off")).then(|| pattern.to_string());
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+
+    let repository = SqliteRepositorySettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(&tracked.id).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::LoadSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    settings.tag_ignore = tag_ignore;
+    settings.updated_at = chrono::Utc::now();
+    repository.save(&settings).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::SaveSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    let repository = tracked.repository_name.as_str();
+    match &settings.tag_ignore {
+        Some(pattern) => Ok(render(
+            lang,
+            SettingText::TagsIgnored,
+            &[("repository", repository), ("pattern", pattern)],
+        )),
+        None => Ok(render(
+            lang,
+            SettingText::NoTagsIgnored,
+            &[("repository", repository)],
+        )),
+    }
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+    pattern: String,
+) -> ResponseResult<()> {
+    let text =
+        match handle_ignore_tags(&state.db, &state.bot_id, msg.chat.id.0, &url, &pattern).await {
+            Ok(message) => message,
+            Err(err_msg) => err_msg,
+        };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn sets_and_clears_the_ignore_pattern() {
+        let db = setup_db().await;
+        let id = match handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+            .await
+            .unwrap()
+        {
+            HandleTrackResult::Created { id, .. } => id,
+            _ => panic!("expected Created"),
+        };
+        let repository = SqliteRepositorySettingsRepository::new(db.clone());
+        let url = "https://github.com/owner/repo";
+
+        handle_ignore_tags(&db, "", 1, url, "*nightly*")
+            .await
+            .unwrap();
+        assert_eq!(
+            repository
+                .find_or_default(&id)
+                .await
+                .unwrap()
+                .tag_ignore
+                .as_deref(),
+            Some("*nightly*")
+        );
+
+        handle_ignore_tags(&db, "", 1, url, "OFF").await.unwrap();
+        assert_eq!(
+            repository.find_or_default(&id).await.unwrap().tag_ignore,
+            None
+        );
+
+        for pattern in ["", "*", "**"] {
+            assert!(handle_ignore_tags(&db, "", 1, url, pattern).await.is_err());
+        }
+        assert!(handle_ignore_tags(&db, "", 2, url, "*rc*").await.is_err());
+    }
+}
@@ -0,0 +1,176 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::chat_settings::Lang;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::chat_settings::{PLACEHOLDERS, ReleaseInfo, render_template};
+use crate::messages::{CommandText, DeliveryText, render, text};
+
+/// Longest template accepted, to leave room for release notes and affixes.
+const MAX_TEMPLATE_CHARS: usize = 500;
+
+/// Shows, sets or (with `clear`) removes the chat's release message template. Templates
+/// are checked against sample data before they are stored.
+pub(crate) async fn handle_template(
+    db: &SqlitePool,
+    chat_id: i64,
+    value: &str,
+) -> Result<String, String> {
+    let repository = SqliteChatSettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(chat_id).await.map_err(|e| {
+        render(
+            Lang::default(),
+            CommandText::LoadChatSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    let lang = settings.language;
+
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(match &settings.message_template {
+            Some(current) => render(
+                lang,
+                DeliveryText::TemplateCurrent,
+                &[("template", current)],
+            ),
+            None => render(
+                lang,
+                DeliveryText::TemplateNone,
+                &[("placeholders", &PLACEHOLDERS.join(", "))],
+            ),
+        });
+    }
+    if value.chars().count() > MAX_TEMPLATE_CHARS {
+        return Err(render(
+            lang,
+            DeliveryText::TemplateTooLong,
+            &[("max", &MAX_TEMPLATE_CHARS.to_string())],
+        ));
+    }
+
+    if value.eq_ignore_ascii_case("clear") {
+        settings.message_template = None;
+    } else {
+        render_template(value, &ReleaseInfo::sample(), settings.message_format)?;
+        settings.message_template = Some(value.to_string());
+    }
+    settings.updated_at = chrono::Utc::now();
+    repository.save(&settings).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::SaveChatSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    let key = match settings.message_template {
+        Some(_) => DeliveryText::TemplateSet,
+        None => DeliveryText::TemplateCleared,
+    };
+    Ok(text(lang, key).to_string())
+}
+
+/// Renders the chat's stored template with sample data.
+pub(crate) async fn handle_preview_template(
+    db: &SqlitePool,
+    chat_id: i64,
+) -> Result<String, String> {
+    let settings = SqliteChatSettingsRepository::new(db.clone())
+        .find_or_default(chat_id)
+        .await
+        .map_err(|e| {
+            render(
+                Lang::default(),
+                CommandText::LoadChatSettingsFailed,
+                &[("error", &e.to_string())],
+            )
+        })?;
+    let Some(template) = settings.message_template else {
+        return Err(text(settings.language, DeliveryText::NoTemplate).to_string());
+    };
+    render_template(&template, &ReleaseInfo::sample(), settings.message_format)
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    value: String,
+) -> ResponseResult<()> {
+    let text = match handle_template(&state.db, msg.chat.id.0, &value).await {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+pub(super) async fn answer_preview(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+) -> ResponseResult<()> {
+    match handle_preview_template(&state.db, msg.chat.id.0).await {
+        Ok(rendered) => {
+            let format = SqliteChatSettingsRepository::new(state.db.clone())
+                .find_or_default(msg.chat.id.0)
+                .await
+                .map(|s| s.message_format)
+                .unwrap_or_default();
+            bot.send_message(msg.chat.id, rendered)
+                .parse_mode(format.parse_mode())
+                .await?;
+        }
+        Err(err_msg) => {
+            bot.send_message(msg.chat.id, err_msg).await?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn stores_and_previews_template() {
+        let db = setup_db().await;
+        assert!(handle_preview_template(&db, 1).await.is_err());
+
+        handle_template(&db, 1, "{name} {tag} is out")
+            .await
+            .expect("should succeed");
+        let preview = handle_preview_template(&db, 1).await.unwrap();
+        assert_eq!(preview, "my-project v1.2.3 is out");
+    }
+
+    #[tokio::test]
+    async fn rejects_template_with_unknown_placeholder() {
+        let db = setup_db().await;
+        let err = handle_template(&db, 1, "{version} is out")
+            .await
+            .expect_err("unknown placeholder");
+        assert!(err.contains("Unknown placeholder {version}"));
+        assert!(handle_preview_template(&db, 1).await.is_err());
+    }
+}
@@ -0,0 +1,147 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::messages::{CommandText, ScheduleText, render, text};
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+use crate::utils::parse_duration;
+
+/// Longest window accepted, so a typo cannot hold releases back for years.
+const MAX_THROTTLE_SECS: u64 = 30 * 24 * 60 * 60;
+
+pub(crate) async fn handle_throttle(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    url: &str,
+    window: &str,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    let secs = parse_duration(window)
+        .map_err(|e| e.message(lang))?
+        .as_secs();
+    if secs != 0 && !(60..=MAX_THROTTLE_SECS).contains(&secs) {
+        return Err(text(lang, ScheduleText::ThrottleInvalid).to_string());
+    }
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+
+    let repository = SqliteRepositorySettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(&tracked.id).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::LoadSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    settings.throttle_window_secs = (secs > 0).then_some(secs as i64);
+    settings.updated_at = chrono::Utc::now();
+    repository.save(&settings).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::SaveSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    let repository = tracked.repository_name.as_str();
+    if secs == 0 {
+        Ok(render(
+            lang,
+            ScheduleText::ThrottleOff,
+            &[("repository", repository)],
+        ))
+    } else {
+        Ok(render(
+            lang,
+            ScheduleText::ThrottleOn,
+            &[
+                ("repository", repository),
+                ("minutes", &(secs / 60).to_string()),
+            ],
+        ))
+    }
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+    window: String,
+) -> ResponseResult<()> {
+    let text = match handle_throttle(&state.db, &state.bot_id, msg.chat.id.0, &url, &window).await {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn stores_and_clears_the_throttle_window() {
+        let db = setup_db().await;
+        let id = match handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+            .await
+            .unwrap()
+        {
+            HandleTrackResult::Created { id, .. } => id,
+            _ => panic!("expected Created"),
+        };
+        let repository = SqliteRepositorySettingsRepository::new(db.clone());
+
+        let text = handle_throttle(&db, "", 1, "https://github.com/owner/repo", "2h")
+            .await
+            .expect("should succeed");
+        assert_eq!(
+            text,
+            "repo will notify at most once every 120 minutes, announcing only the newest release."
+        );
+        let settings = repository.find_or_default(&id).await.unwrap();
+        assert_eq!(settings.throttle_window_secs, Some(7200));
+
+        handle_throttle(&db, "", 1, "https://github.com/owner/repo", "0")
+            .await
+            .expect("should succeed");
+        let settings = repository.find_or_default(&id).await.unwrap();
+        assert_eq!(settings.throttle_window_secs, None);
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_windows() {
+        let db = setup_db().await;
+        for window in ["-5m", "30s", "31d", "soon"] {
+            assert!(
+                handle_throttle(&db, "", 1, "https://github.com/owner/repo", window)
+                    .await
+                    .is_err(),
+                "{window}"
+            );
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::api_tokens::repository::{ChatApiTokensRepository, SqliteChatApiTokensRepository};
+use crate::api_tokens::{ChatApiToken, generate_token, hash_token};
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::messages::{CommandText, DeliveryText, render, text};
+
+/// How long the message revealing a new token stays in the chat.
+const TOKEN_MESSAGE_LIFETIME: Duration = Duration::from_secs(60);
+
+/// What `/token` answers with.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum TokenReply {
+    /// Plain information, kept in the chat.
+    Info(String),
+    /// Reveals a new token and is deleted after a while.
+    Secret(String),
+}
+
+/// `show` tells whether the chat has an API token; only its hash is stored, so the token
+/// itself can't be shown again. `rotate` replaces it, invalidating the previous one.
+pub(crate) async fn handle_token(
+    db: &SqlitePool,
+    chat_id: i64,
+    action: &str,
+) -> Result<TokenReply, String> {
+    let lang = chat_language(db, chat_id).await;
+    let repository = SqliteChatApiTokensRepository::new(db.clone());
+    match action.trim().to_ascii_lowercase().as_str() {
+        "" | "show" => {
+            let existing = repository.find_by_chat_id(chat_id).await.map_err(|e| {
+                render(
+                    lang,
+                    DeliveryText::TokenLoadFailed,
+                    &[("error", &e.to_string())],
+                )
+            })?;
+            Ok(TokenReply::Info(match existing {
+                Some(token) => render(
+                    lang,
+                    DeliveryText::TokenExists,
+                    &[(
+                        "created",
+                        &token.created_at.format("%Y-%m-%d %H:%M UTC").to_string(),
+                    )],
+                ),
+                None => text(lang, DeliveryText::NoToken).to_string(),
+            }))
+        }
+        "rotate" => {
+            let token = generate_token();
+            repository
+                .save(&ChatApiToken {
+                    chat_id,
+                    token_hash: hash_token(&token),
+                    created_at: Utc::now(),
+                })
+                .await
+                .map_err(|e| {
+                    render(
+                        lang,
+                        DeliveryText::TokenSaveFailed,
+                        &[("error", &e.to_string())],
+                    )
+                })?;
+            Ok(TokenReply::Secret(render(
+                lang,
+                DeliveryText::NewToken,
+                &[
+                    ("token", &token),
+                    ("seconds", &TOKEN_MESSAGE_LIFETIME.as_secs().to_string()),
+                ],
+            )))
+        }
+        _ => Err(render(
+            lang,
+            CommandText::Usage,
+            &[("usage", "/token show or /token rotate")],
+        )),
+    }
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    action: String,
+) -> ResponseResult<()> {
+    if !cfg!(feature = "rest-api") {
+        let lang = chat_language(&state.db, msg.chat.id.0).await;
+        bot.send_message(msg.chat.id, text(lang, DeliveryText::RestApiUnsupported))
+            .await?;
+        return Ok(());
+    }
+
+    match handle_token(&state.db, msg.chat.id.0, &action).await {
+        Ok(TokenReply::Info(text)) => {
+            bot.send_message(msg.chat.id, text).await?;
+        }
+        Ok(TokenReply::Secret(text)) => {
+            let sent = bot.send_message(msg.chat.id, text).await?;
+            let bot = bot.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(TOKEN_MESSAGE_LIFETIME).await;
+                if let Err(e) = bot.delete_message(sent.chat.id, sent.id).await {
+                    log::warn!("Failed to delete API token message: {}", e);
+                }
+            });
+        }
+        Err(err_msg) => {
+            bot.send_message(msg.chat.id, err_msg).await?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    fn revealed(reply: TokenReply) -> String {
+        match reply {
+            TokenReply::Secret(text) => text
+                .split_whitespace()
+                .find(|w| w.len() == 64)
+                .expect("token in reply")
+                .to_string(),
+            other => panic!("unexpected reply: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn rotate_replaces_the_previous_token() {
+        let db = setup_db().await;
+        let repository = SqliteChatApiTokensRepository::new(db.clone());
+
+        let shown = handle_token(&db, 5, "show").await.unwrap();
+        assert!(matches!(shown, TokenReply::Info(text) if text.contains("no API token")));
+
+        let first = revealed(handle_token(&db, 5, "rotate").await.unwrap());
+        let second = revealed(handle_token(&db, 5, "rotate").await.unwrap());
+        assert_ne!(first, second);
+        assert!(
+            repository
+                .find_by_hash(&hash_token(&first))
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(
+            repository
+                .find_by_hash(&hash_token(&second))
+                .await
+                .unwrap()
+                .map(|t| t.chat_id),
+            Some(5)
+        );
+
+        let shown = handle_token(&db, 5, "").await.unwrap();
+        assert!(matches!(shown, TokenReply::Info(text) if !text.contains(&second)));
+        assert!(handle_token(&db, 5, "revoke").await.is_err());
+    }
+}
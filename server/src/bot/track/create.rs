@@ -0,0 +1,62 @@
+use sqlx::sqlite::SqlitePool;
+
+use crate::chat_settings::Lang;
+use crate::db::is_unique_violation;
+use crate::messages::{TrackingText, render};
+use crate::tracked_repositories::repository::{
+    SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+};
+use crate::tracked_repositories::{RepositoryUrl, TrackedRelease};
+
+use super::{HandleTrackResult, handle_track};
+
+/// Saves a new tracking of `repo_url` for the bot's chat, which the caller found untracked.
+pub(super) async fn create(
+    db: &SqlitePool,
+    lang: Lang,
+    bot_id: &str,
+    chat_id: i64,
+    name: &str,
+    url: &str,
+    repo_url: RepositoryUrl,
+) -> Result<HandleTrackResult, String> {
+    let repository = SqliteTrackedRepositoriesRepository::new(db.clone());
+    let now = chrono::Utc::now();
+    let mut tracked = TrackedRelease {
+        id: uuid::Uuid::now_v7(),
+        repository_name: name.to_string(),
+        repository_url: repo_url,
+        chat_id,
+        bot_id: bot_id.to_string(),
+        default_branch: None,
+        created_at: now,
+        updated_at: now,
+    };
+
+    match TrackedRepositoriesRepository::save(&repository, &mut tracked).await {
+        Ok(()) => {}
+        // A concurrent /track of the URL in this chat saved its row since the caller's
+        // lookup; looking again answers like the slower command had come second
+        Err(e) if is_unique_violation(&*e) => {
+            return Box::pin(handle_track(db, bot_id, chat_id, name, url)).await;
+        }
+        Err(e) => {
+            return Err(render(
+                lang,
+                TrackingText::TrackFailed,
+                &[("error", &e.to_string())],
+            ));
+        }
+    }
+
+    Ok(HandleTrackResult::Created {
+        id: tracked.id,
+        previous_tag: None,
+        current_tag: None,
+        message: render(
+            lang,
+            TrackingText::NowTracking,
+            &[("name", name), ("url", url)],
+        ),
+    })
+}
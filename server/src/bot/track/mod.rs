@@ -0,0 +1,232 @@
+mod create;
+mod prime;
+
+use teloxide::prelude::*;
+use teloxide::utils::command::ParseError;
+
+use sqlx::sqlite::SqlitePool;
+
+use crate::bot::language::chat_language;
+use crate::bot::{BotState, reactions};
+use crate::chat_settings::Lang;
+use crate::github::github_api_base;
+use crate::messages::{CommandText, TrackingText, render, text};
+use crate::tracked_repositories::RepositoryUrl;
+use crate::tracked_repositories::repository::{
+    SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+};
+use crate::tracked_repositories::tracked_repositories_releases::repository::{
+    CachedRepositoryReleasesRepository, SqliteCachedRepositoryReleasesRepository,
+};
+use create::create;
+use prime::prime;
+
+/// Outcome of a `/track` command. Every variant names the tracked repository and the
+/// cached release tag before and after the command, so callers other than the chat reply
+/// can present it; `message` is the reply sent to Telegram.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HandleTrackResult {
+    AlreadyTracking {
+        id: uuid::Uuid,
+        previous_tag: Option<String>,
+        current_tag: Option<String>,
+        message: String,
+    },
+    /// The chat already tracked the URL under another name, which it now carries.
+    Updated {
+        id: uuid::Uuid,
+        previous_tag: Option<String>,
+        current_tag: Option<String>,
+        message: String,
+    },
+    /// A new tracking has no cached release until it is primed.
+    Created {
+        id: uuid::Uuid,
+        previous_tag: Option<String>,
+        current_tag: Option<String>,
+        message: String,
+    },
+}
+
+impl HandleTrackResult {
+    pub fn id(&self) -> uuid::Uuid {
+        match self {
+            Self::AlreadyTracking { id, .. }
+            | Self::Updated { id, .. }
+            | Self::Created { id, .. } => *id,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            Self::AlreadyTracking { message, .. }
+            | Self::Updated { message, .. }
+            | Self::Created { message, .. } => message,
+        }
+    }
+}
+
+/// The release tag cached for a tracked repository, if any.
+async fn cached_tag(
+    db: &SqlitePool,
+    lang: Lang,
+    id: &uuid::Uuid,
+) -> Result<Option<String>, String> {
+    SqliteCachedRepositoryReleasesRepository::new(db.clone())
+        .find_by_tracked_release_id(id)
+        .await
+        .map(|cached| cached.map(|c| c.tag_name))
+        .map_err(|e| {
+            render(
+                lang,
+                CommandText::CachedReleaseFailed,
+                &[("error", &e.to_string())],
+            )
+        })
+}
+
+/// Splits the arguments of `/track`: `<name> <url>`, or only `<url>`, which leaves the
+/// name empty so it is derived from the URL.
+pub(crate) fn parse_args(input: String) -> Result<(String, String), ParseError> {
+    match input.split_whitespace().collect::<Vec<_>>().as_slice() {
+        [url] => Ok((String::new(), url.to_string())),
+        [name, url] => Ok((name.to_string(), url.to_string())),
+        [] => Err(ParseError::TooFewArguments {
+            expected: 1,
+            found: 0,
+            message: "Usage: /track [name] <url>".to_string(),
+        }),
+        args => Err(ParseError::TooManyArguments {
+            expected: 2,
+            found: args.len(),
+            message: "Usage: /track [name] <url>".to_string(),
+        }),
+    }
+}
+
+/// The name a repository is tracked under when none is given: `owner/repo` for GitHub,
+/// the package name for registries.
+pub(crate) fn default_name(url: &RepositoryUrl) -> Option<String> {
+    match url.package() {
+        Some(package) => Some(package.name),
+        None => url
+            .owner_and_repo()
+            .map(|(owner, repo)| format!("{owner}/{repo}")),
+    }
+}
+
+/// Tracks `url` under `name`, or under its `default_name` when `name` is empty.
+pub(crate) async fn handle_track(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    name: &str,
+    url: &str,
+) -> Result<HandleTrackResult, String> {
+    let lang = chat_language(db, chat_id).await;
+    let repo_url = match crate::tracked_repositories::RepositoryUrl::new(url.to_string()) {
+        Ok(u) => u,
+        Err(e) => return Err(e.message(lang)),
+    };
+    let derived;
+    let name = if name.is_empty() {
+        derived = default_name(&repo_url)
+            .ok_or_else(|| text(lang, TrackingText::NameMissing).to_string())?;
+        derived.as_str()
+    } else {
+        name
+    };
+
+    let repository = SqliteTrackedRepositoriesRepository::new(db.clone());
+
+    match repository
+        .find_by_chat_id_and_repository_url(bot_id, chat_id, &repo_url.url())
+        .await
+        .map_err(|e| render(lang, CommandText::QueryFailed, &[("error", &e.to_string())]))?
+    {
+        Some(mut existing) => {
+            let tag = cached_tag(db, lang, &existing.id).await?;
+            let values = [("name", name), ("url", url)];
+            if existing.repository_name == name {
+                return Ok(HandleTrackResult::AlreadyTracking {
+                    id: existing.id,
+                    previous_tag: tag.clone(),
+                    current_tag: tag,
+                    message: render(lang, TrackingText::AlreadyTracking, &values),
+                });
+            }
+
+            existing.repository_name = name.to_string();
+            existing.updated_at = chrono::Utc::now();
+            TrackedRepositoriesRepository::save(&repository, &mut existing)
+                .await
+                .map_err(|e| {
+                    render(
+                        lang,
+                        TrackingText::UpdateFailed,
+                        &[("error", &e.to_string())],
+                    )
+                })?;
+
+            Ok(HandleTrackResult::Updated {
+                id: existing.id,
+                previous_tag: tag.clone(),
+                current_tag: tag,
+                message: render(lang, TrackingText::UpdatedTracking, &values),
+            })
+        }
+        None => create(db, lang, bot_id, chat_id, name, url, repo_url).await,
+    }
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    name: String,
+    url: String,
+) -> ResponseResult<()> {
+    log::info!("Tracking repository: {name} ({url})");
+
+    let repository_url = match crate::tracked_repositories::RepositoryUrl::new(url.clone()) {
+        Ok(u) => u,
+        Err(e) => {
+            let lang = chat_language(&state.db, msg.chat.id.0).await;
+            bot.send_message(msg.chat.id, e.message(lang)).await?;
+            return Ok(());
+        }
+    };
+
+    let result = match handle_track(&state.db, &state.bot_id, msg.chat.id.0, &name, &url).await {
+        Ok(result) => result,
+        Err(err_msg) => {
+            bot.send_message(msg.chat.id, err_msg).await?;
+            return Ok(());
+        }
+    };
+    bot.send_message(msg.chat.id, result.message()).await?;
+    let id = result.id();
+    match result {
+        HandleTrackResult::AlreadyTracking { .. } => {}
+        HandleTrackResult::Updated { .. } => {
+            reactions::confirm_track(bot, msg, state).await;
+        }
+        HandleTrackResult::Created { .. } => {
+            reactions::confirm_track(bot, msg, state).await;
+            prime(
+                &state.db,
+                state.config.resolve_token().token(),
+                &github_api_base(),
+                msg.chat.id.0,
+                id,
+                &repository_url,
+            )
+            .await;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
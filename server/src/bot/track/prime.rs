@@ -0,0 +1,160 @@
+use sqlx::sqlite::SqlitePool;
+
+use crate::bot::default_branch::capture_default_branch;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::github::HttpReleaseSource;
+use crate::poller::fetch_latest_from_sources;
+use crate::tracked_repositories::RepositoryUrl;
+use crate::tracked_repositories::mirrors::repository::{
+    RepositoryMirrorsRepository, SqliteRepositoryMirrorsRepository,
+};
+use crate::tracked_repositories::settings::RepositorySettings;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+use crate::tracked_repositories::tracked_repositories_releases::CachedRepositoryRelease;
+use crate::tracked_repositories::tracked_repositories_releases::repository::{
+    CachedRepositoryReleasesRepository, SqliteCachedRepositoryReleasesRepository,
+};
+
+/// Caches the newest release of a freshly tracked repository, so only later releases
+/// notify, and records its default branch. The release is found the way the poller finds
+/// it, so the repository's tag filters and mirrors apply. Chats that want to hear about
+/// the current release get nothing cached, so the poller announces it on first sight.
+pub(super) async fn prime(
+    db: &SqlitePool,
+    token_opt: Option<&str>,
+    base: &str,
+    chat_id: i64,
+    id: uuid::Uuid,
+    repository_url: &RepositoryUrl,
+) {
+    let client = reqwest::Client::new();
+    let seed = !SqliteChatSettingsRepository::new(db.clone())
+        .find_or_default(chat_id)
+        .await
+        .is_ok_and(|s| s.notify_on_first_seen);
+    if seed {
+        let settings = SqliteRepositorySettingsRepository::new(db.clone())
+            .find_or_default(&id)
+            .await
+            .unwrap_or_else(|_| RepositorySettings::default_for(id));
+        let mut sources = vec![repository_url.clone()];
+        if let Ok(mirrors) = SqliteRepositoryMirrorsRepository::new(db.clone())
+            .find_by_tracked_repository_id(&id)
+            .await
+        {
+            sources.extend(mirrors.into_iter().map(|m| m.repository_url));
+        }
+        let source = HttpReleaseSource::new(client.clone(), token_opt, base.to_string());
+        // A tag read from the releases feed may be a prerelease; the poller caches the
+        // release once the API answers
+        if let Ok(Some(latest)) = fetch_latest_from_sources(&source, &sources, &settings).await
+            && !latest.details.from_feed
+        {
+            let cached = CachedRepositoryRelease {
+                tracked_repository_id: id,
+                tag_name: latest.tag.clone(),
+                first_seen_at: chrono::Utc::now(),
+                body_hash: None,
+                from_release: latest.is_release(),
+            };
+            let _ = SqliteCachedRepositoryReleasesRepository::new(db.clone())
+                .save(&cached)
+                .await;
+        }
+    }
+    if repository_url.package().is_some() {
+        return;
+    }
+    if let Err(e) = capture_default_branch(db, &client, token_opt, base, &id, repository_url).await
+    {
+        log::warn!("{e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::handle_track;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+        pool
+    }
+
+    async fn cached(db: &SqlitePool, id: uuid::Uuid) -> Option<CachedRepositoryRelease> {
+        SqliteCachedRepositoryReleasesRepository::new(db.clone())
+            .find_by_tracked_release_id(&id)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn prime_caches_the_latest_release_as_a_release() {
+        let db = setup_db().await;
+        let url = "https://github.com/owner/repo";
+        let created = handle_track(&db, "", 1, "repo", url).await.unwrap();
+        let mut gh = mockito::Server::new_async().await;
+        let _release = gh
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!({ "tag_name": "v1.2.3" }).to_string())
+            .create_async()
+            .await;
+
+        let repository_url = RepositoryUrl::new(url.to_string()).unwrap();
+        prime(&db, None, &gh.url(), 1, created.id(), &repository_url).await;
+
+        let cached = cached(&db, created.id()).await.expect("seeded");
+        assert_eq!(cached.tag_name, "v1.2.3");
+        assert!(cached.from_release);
+    }
+
+    #[tokio::test]
+    async fn prime_follows_the_tags_the_poller_follows() {
+        let db = setup_db().await;
+        let url = "https://github.com/owner/repo";
+        let created = handle_track(&db, "", 1, "repo", url).await.unwrap();
+        let mut settings = RepositorySettings::default_for(created.id());
+        settings.tags_only = true;
+        SqliteRepositorySettingsRepository::new(db.clone())
+            .save(&settings)
+            .await
+            .unwrap();
+        let mut gh = mockito::Server::new_async().await;
+        let release = gh
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .expect(0)
+            .create_async()
+            .await;
+        let _tags = gh
+            .mock(
+                "GET",
+                mockito::Matcher::Regex("^/repos/owner/repo/tags".to_string()),
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!([{ "name": "v1.3.0-nightly" }]).to_string())
+            .create_async()
+            .await;
+
+        let repository_url = RepositoryUrl::new(url.to_string()).unwrap();
+        prime(&db, None, &gh.url(), 1, created.id(), &repository_url).await;
+
+        let cached = cached(&db, created.id()).await.expect("seeded");
+        assert_eq!(cached.tag_name, "v1.3.0-nightly");
+        assert!(!cached.from_release);
+        release.assert_async().await;
+    }
+}
@@ -0,0 +1,278 @@
+use super::*;
+use crate::chat_settings::Lang;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::tracked_repositories::tracked_repositories_releases::CachedRepositoryRelease;
+use sqlx::sqlite::SqlitePoolOptions;
+
+async fn setup_db() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("failed to create in-memory sqlite pool");
+
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("failed to run migrations");
+
+    pool
+}
+
+async fn cache_tag(db: &SqlitePool, id: uuid::Uuid, tag: &str) {
+    SqliteCachedRepositoryReleasesRepository::new(db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: id,
+            tag_name: tag.to_string(),
+            first_seen_at: chrono::Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn handle_track_creates_new_when_not_exists() {
+    let db = setup_db().await;
+    let res = handle_track(
+        &db,
+        "",
+        100,
+        "repo-one",
+        "https://github.com/owner/repo-one",
+    )
+    .await
+    .expect("should succeed");
+
+    match res {
+        HandleTrackResult::Created {
+            id,
+            previous_tag,
+            current_tag,
+            message,
+        } => {
+            assert_eq!(
+                message,
+                "Now tracking repo-one (https://github.com/owner/repo-one)."
+            );
+            assert_eq!(previous_tag, None);
+            assert_eq!(current_tag, None);
+            let row = SqliteTrackedRepositoriesRepository::new(db.clone())
+                .find_by_chat_id_and_repository_url("", 100, "https://github.com/owner/repo-one")
+                .await
+                .unwrap()
+                .expect("tracked row");
+            assert_eq!(row.id, id);
+        }
+        _ => panic!("expected Created"),
+    }
+}
+
+#[tokio::test]
+async fn handle_track_reports_already_tracking_in_same_chat() {
+    let db = setup_db().await;
+
+    // First, create
+    let created = handle_track(&db, "", 42, "repo-two", "https://github.com/owner/repo-two")
+        .await
+        .expect("create should succeed");
+    cache_tag(&db, created.id(), "v2.0.0").await;
+
+    // Second, same chat and same url -> already tracking
+    let res = handle_track(&db, "", 42, "repo-two", "https://github.com/owner/repo-two")
+        .await
+        .expect("should succeed");
+
+    match res {
+        HandleTrackResult::AlreadyTracking {
+            id,
+            previous_tag,
+            current_tag,
+            message,
+        } => {
+            assert!(message.contains("already tracking"));
+            assert_eq!(id, created.id());
+            assert_eq!(previous_tag.as_deref(), Some("v2.0.0"));
+            assert_eq!(current_tag.as_deref(), Some("v2.0.0"));
+        }
+        _ => panic!("expected AlreadyTracking"),
+    }
+}
+
+#[tokio::test]
+async fn handle_track_renames_within_the_same_chat() {
+    let db = setup_db().await;
+    let url = "https://github.com/owner/repo-three";
+    let created = handle_track(&db, "", 1, "repo-three", url)
+        .await
+        .expect("create should succeed");
+    cache_tag(&db, created.id(), "v3.1.0").await;
+
+    let res = handle_track(&db, "", 1, "three", url)
+        .await
+        .expect("should succeed");
+
+    match res {
+        HandleTrackResult::Updated {
+            id,
+            previous_tag,
+            current_tag,
+            message,
+        } => {
+            assert!(message.contains("Updated tracking"));
+            assert_eq!(id, created.id());
+            assert_eq!(previous_tag.as_deref(), Some("v3.1.0"));
+            assert_eq!(current_tag.as_deref(), Some("v3.1.0"));
+        }
+        _ => panic!("expected Updated"),
+    }
+    let row = SqliteTrackedRepositoriesRepository::new(db.clone())
+        .find_by_chat_id_and_repository_url("", 1, url)
+        .await
+        .unwrap()
+        .expect("tracked row");
+    assert_eq!(row.repository_name, "three");
+}
+
+#[tokio::test]
+async fn handle_track_in_another_chat_leaves_the_first_alone() {
+    let db = setup_db().await;
+    let url = "https://github.com/owner/repo-four";
+    let first = handle_track(&db, "", 1, "repo-four", url)
+        .await
+        .expect("create should succeed");
+
+    let second = handle_track(&db, "", 2, "four", url)
+        .await
+        .expect("should succeed");
+
+    assert!(matches!(second, HandleTrackResult::Created { .. }));
+    assert_ne!(second.id(), first.id());
+    let row = SqliteTrackedRepositoriesRepository::new(db.clone())
+        .find_by_chat_id_and_repository_url("", 1, url)
+        .await
+        .unwrap()
+        .expect("first chat's row");
+    assert_eq!(
+        (row.id, row.repository_name.as_str()),
+        (first.id(), "repo-four")
+    );
+}
+
+#[tokio::test]
+async fn handle_track_through_another_bot_leaves_the_first_alone() {
+    let db = setup_db().await;
+    let url = "https://github.com/owner/shared";
+    let prod = handle_track(&db, "", 1, "shared", url)
+        .await
+        .expect("create should succeed");
+
+    let staging = handle_track(&db, "staging", 2, "shared", url)
+        .await
+        .expect("should succeed");
+
+    assert!(matches!(staging, HandleTrackResult::Created { .. }));
+    assert_ne!(staging.id(), prod.id());
+    let row = SqliteTrackedRepositoriesRepository::new(db.clone())
+        .find_by_chat_id_and_repository_url("", 1, url)
+        .await
+        .unwrap()
+        .expect("prod row");
+    assert_eq!((row.id, row.chat_id), (prod.id(), 1));
+}
+
+#[test]
+fn track_takes_a_url_with_or_without_a_name() {
+    use crate::bot::Command;
+    use teloxide::utils::command::BotCommands;
+
+    let url = "https://github.com/owner/repo";
+    assert_eq!(
+        parse_args(url.to_string()).unwrap(),
+        (String::new(), url.to_string())
+    );
+    assert_eq!(
+        parse_args(format!("mine {url}")).unwrap(),
+        ("mine".to_string(), url.to_string())
+    );
+    assert!(parse_args(String::new()).is_err());
+    assert!(parse_args(format!("my repo {url}")).is_err());
+    assert!(matches!(
+        Command::parse(&format!("/track {url}"), "bot"),
+        Ok(Command::Track { name, .. }) if name.is_empty()
+    ));
+}
+
+#[test]
+fn default_names_come_from_the_url() {
+    let name = |url: &str| default_name(&RepositoryUrl::new(url.to_string()).unwrap());
+    assert_eq!(
+        name("https://github.com/tokio-rs/tokio").as_deref(),
+        Some("tokio-rs/tokio")
+    );
+    assert_eq!(
+        name("https://crates.io/crates/serde").as_deref(),
+        Some("serde")
+    );
+}
+
+#[tokio::test]
+async fn handle_track_without_a_name_uses_owner_and_repo() {
+    let db = setup_db().await;
+    let res = handle_track(&db, "", 5, "", "https://github.com/owner/unnamed")
+        .await
+        .expect("should succeed");
+
+    assert_eq!(
+        res.message(),
+        "Now tracking owner/unnamed (https://github.com/owner/unnamed)."
+    );
+    let row = SqliteTrackedRepositoriesRepository::new(db.clone())
+        .find_by_chat_id_and_repository_url("", 5, "https://github.com/owner/unnamed")
+        .await
+        .unwrap()
+        .expect("tracked row");
+    assert_eq!(row.repository_name, "owner/unnamed");
+}
+
+#[tokio::test]
+async fn concurrent_tracks_of_the_same_url_keep_one_row() {
+    let db = setup_db().await;
+    let url = "https://github.com/owner/raced";
+
+    let (a, b) = tokio::join!(
+        handle_track(&db, "", 7, "raced", url),
+        handle_track(&db, "", 7, "raced", url)
+    );
+    let mut results = [a.expect("first"), b.expect("second")];
+    results.sort_by_key(|r| matches!(r, HandleTrackResult::AlreadyTracking { .. }));
+    assert!(matches!(results[0], HandleTrackResult::Created { .. }));
+    assert!(matches!(
+        results[1],
+        HandleTrackResult::AlreadyTracking { .. }
+    ));
+
+    let rows = SqliteTrackedRepositoriesRepository::new(db.clone())
+        .find_all_by_chat_id("", 7)
+        .await
+        .unwrap();
+    assert_eq!(rows.len(), 1);
+}
+
+#[tokio::test]
+async fn invalid_urls_are_explained_in_the_chats_language() {
+    let db = setup_db().await;
+    let chat_settings = SqliteChatSettingsRepository::new(db.clone());
+    let mut settings = chat_settings.find_or_default(3).await.unwrap();
+    settings.language = Lang::It;
+    chat_settings.save(&settings).await.unwrap();
+
+    let err = handle_track(&db, "", 3, "", "https://gitlab.com/owner/repo")
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        "URL di repository GitHub non valido: https://gitlab.com/owner/repo"
+    );
+}
@@ -0,0 +1,218 @@
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::chat_settings::Lang;
+use crate::messages::{CommandText, ReleaseText, render, text};
+use crate::tracked_repositories::release_history::repository::{
+    ReleaseHistoryRepository, SqliteReleaseHistoryRepository,
+};
+
+fn releases(n: i64, lang: Lang) -> String {
+    if n == 1 {
+        text(lang, ReleaseText::OneRelease).to_string()
+    } else {
+        render(lang, ReleaseText::Releases, &[("count", &n.to_string())])
+    }
+}
+
+/// Reports how often a repository released in the last 30 and 90 days, from the release
+/// history the poller recorded.
+pub(crate) async fn handle_velocity(
+    db: &SqlitePool,
+    bot_id: &str,
+    history_limit: usize,
+    chat_id: i64,
+    url: &str,
+    now: DateTime<Utc>,
+) -> Result<String, String> {
+    let lang = chat_language(db, chat_id).await;
+    if history_limit == 0 {
+        return Err(text(lang, CommandText::HistoryOff).to_string());
+    }
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+
+    let velocity = SqliteReleaseHistoryRepository::new(db.clone())
+        .velocity(&tracked.id, now)
+        .await
+        .map_err(|e| {
+            render(
+                lang,
+                CommandText::HistoryFailed,
+                &[("error", &e.to_string())],
+            )
+        })?;
+    let Some(since) = velocity.first_release_at else {
+        return Ok(render(
+            lang,
+            CommandText::NoHistoryYet,
+            &[("repository", &tracked.repository_name)],
+        ));
+    };
+
+    let average = match velocity.average_days_between() {
+        Some(days) => render(
+            lang,
+            ReleaseText::AverageDays,
+            &[("days", &format!("{days:.1}"))],
+        ),
+        None => text(lang, ReleaseText::NoAverage).to_string(),
+    };
+    Ok([
+        render(
+            lang,
+            ReleaseText::VelocityHeading,
+            &[("repository", &tracked.repository_name)],
+        ),
+        render(
+            lang,
+            ReleaseText::Last30Days,
+            &[("releases", &releases(velocity.last_30_days, lang))],
+        ),
+        render(
+            lang,
+            ReleaseText::Last90Days,
+            &[("releases", &releases(velocity.last_90_days, lang))],
+        ),
+        average,
+        render(
+            lang,
+            ReleaseText::BasedOn,
+            &[
+                ("releases", &releases(velocity.recorded, lang)),
+                ("since", &since.format("%Y-%m-%d").to_string()),
+            ],
+        ),
+    ]
+    .join("\n"))
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+) -> ResponseResult<()> {
+    let text = match handle_velocity(
+        &state.db,
+        &state.bot_id,
+        state.config.release_history_limit,
+        msg.chat.id.0,
+        &url,
+        Utc::now(),
+    )
+    .await
+    {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use crate::tracked_repositories::release_history::ReleaseHistoryEntry;
+    use chrono::Duration;
+    use sqlx::sqlite::SqlitePoolOptions;
+    use uuid::Uuid;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    async fn record(db: &SqlitePool, id: Uuid, tags: &[(&str, DateTime<Utc>)]) {
+        let entries: Vec<_> = tags
+            .iter()
+            .map(|(tag, published_at)| ReleaseHistoryEntry {
+                id: Uuid::now_v7(),
+                tracked_repository_id: id,
+                tag_name: tag.to_string(),
+                body: None,
+                html_url: None,
+                published_at: Some(*published_at),
+                author: None,
+                detected_at: Utc::now(),
+            })
+            .collect();
+        SqliteReleaseHistoryRepository::new(db.clone())
+            .save_all(&entries, 10)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn reports_releases_per_window_and_average_gap() {
+        let db = setup_db().await;
+        let HandleTrackResult::Created { id, .. } =
+            handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+                .await
+                .unwrap()
+        else {
+            panic!("expected Created");
+        };
+        let url = "https://github.com/owner/repo";
+        let now = Utc::now();
+
+        assert_eq!(
+            handle_velocity(&db, "", 10, 1, url, now).await.unwrap(),
+            "No releases of repo have been recorded yet."
+        );
+
+        record(&db, id, &[("v1.0.0", now - Duration::days(5))]).await;
+        let single = handle_velocity(&db, "", 10, 1, url, now).await.unwrap();
+        assert!(single.contains("- last 30 days: 1 release\n"));
+        assert!(single.contains("not enough releases recorded for an average yet"));
+
+        record(
+            &db,
+            id,
+            &[
+                ("v0.9.0", now - Duration::days(45)),
+                ("v0.8.0", now - Duration::days(125)),
+            ],
+        )
+        .await;
+        let since = (now - Duration::days(125)).format("%Y-%m-%d");
+        assert_eq!(
+            handle_velocity(&db, "", 10, 1, url, now).await.unwrap(),
+            format!(
+                "Release velocity of repo:\n\
+                 - last 30 days: 1 release\n\
+                 - last 90 days: 2 releases\n\
+                 - 60.0 days between releases on average\n\
+                 Based on 3 releases recorded since {since}."
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn needs_release_history() {
+        let db = setup_db().await;
+        handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+            .await
+            .unwrap();
+
+        let err = handle_velocity(&db, "", 0, 1, "https://github.com/owner/repo", Utc::now())
+            .await
+            .unwrap_err();
+        assert!(err.contains("RELEASE_HISTORY_LIMIT"));
+    }
+}
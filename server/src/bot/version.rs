@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::chat_settings::Lang;
+use crate::messages::{InfoText, render};
+
+/// Commit hash baked in at build time through the `GIT_COMMIT` environment variable.
+const GIT_COMMIT: Option<&str> = option_env!("GIT_COMMIT");
+
+fn format_uptime(uptime: Duration) -> String {
+    let secs = uptime.as_secs();
+    let (days, hours, minutes) = (secs / 86_400, secs % 86_400 / 3_600, secs % 3_600 / 60);
+    if days > 0 {
+        format!("{days}d {hours}h {minutes}m")
+    } else if hours > 0 {
+        format!("{hours}h {minutes}m")
+    } else {
+        format!("{minutes}m {}s", secs % 60)
+    }
+}
+
+fn format_version(commit: Option<&str>, uptime: Duration, lang: Lang) -> String {
+    let mut text = format!("github-release-bot {}", env!("CARGO_PKG_VERSION"));
+    if let Some(commit) = commit.filter(|c| !c.is_empty()) {
+        text.push_str(&format!(" ({commit})"));
+    }
+    text.push('\n');
+    text.push_str(&render(
+        lang,
+        InfoText::Uptime,
+        &[("uptime", &format_uptime(uptime))],
+    ));
+    text
+}
+
+pub(super) async fn answer(bot: &Bot, msg: &Message, state: &BotState) -> ResponseResult<()> {
+    let lang = chat_language(&state.db, msg.chat.id.0).await;
+    let text = format_version(GIT_COMMIT, state.started_at.elapsed(), lang);
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_version_commit_and_uptime() {
+        let text = format_version(Some("abc1234"), Duration::from_secs(93_784), Lang::En);
+        assert_eq!(
+            text,
+            format!(
+                "github-release-bot {} (abc1234)\nUptime: 1d 2h 3m",
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+    }
+
+    #[test]
+    fn omits_missing_commit() {
+        let text = format_version(None, std::time::Instant::now().elapsed(), Lang::En);
+        assert!(text.starts_with(&format!(
+            "github-release-bot {}\n",
+            env!("CARGO_PKG_VERSION")
+        )));
+        assert!(text.contains("Uptime: 0m "));
+    }
+}
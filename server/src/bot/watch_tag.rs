@@ -0,0 +1,156 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::github::tag_exists;
+use crate::messages::{ReleaseText, render, text};
+use crate::tag_watches::TagWatch;
+use crate::tag_watches::repository::{SqliteTagWatchesRepository, TagWatchesRepository};
+use crate::tracked_repositories::RepositoryUrl;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HandleWatchTagResult {
+    AlreadyWatching { message: String },
+    Created { id: uuid::Uuid, message: String },
+}
+
+pub(crate) async fn handle_watch_tag(
+    db: &SqlitePool,
+    bot_id: &str,
+    chat_id: i64,
+    url: &str,
+    tag: &str,
+) -> Result<HandleWatchTagResult, String> {
+    let lang = chat_language(db, chat_id).await;
+    let tag = tag.trim();
+    if tag.is_empty() {
+        return Err(text(lang, ReleaseText::TagMissing).to_string());
+    }
+    let repository_url = RepositoryUrl::new(url.to_string()).map_err(|e| e.message(lang))?;
+
+    let watch = TagWatch {
+        id: uuid::Uuid::now_v7(),
+        chat_id,
+        bot_id: bot_id.to_string(),
+        repository_url,
+        tag_name: tag.to_string(),
+        created_at: chrono::Utc::now(),
+    };
+
+    let repository = SqliteTagWatchesRepository::new(db.clone());
+    let created = repository.save(&watch).await.map_err(|e| {
+        render(
+            lang,
+            ReleaseText::WatchSaveFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    let values = [("url", url), ("tag", tag)];
+    if created {
+        Ok(HandleWatchTagResult::Created {
+            id: watch.id,
+            message: render(lang, ReleaseText::WatchingTag, &values),
+        })
+    } else {
+        Ok(HandleWatchTagResult::AlreadyWatching {
+            message: render(lang, ReleaseText::AlreadyWatchingTag, &values),
+        })
+    }
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+    tag: String,
+) -> ResponseResult<()> {
+    log::info!("Watching repository {url} for tag {tag}");
+
+    let repository_url = match RepositoryUrl::new(url.clone()) {
+        Ok(u) => u,
+        Err(e) => {
+            let lang = chat_language(&state.db, msg.chat.id.0).await;
+            bot.send_message(msg.chat.id, e.message(lang)).await?;
+            return Ok(());
+        }
+    };
+
+    if let Some((owner, repo)) = repository_url.owner_and_repo() {
+        let client = reqwest::Client::new();
+        let token_opt = state.config.resolve_token().token();
+        if let Ok(true) = tag_exists(&client, &owner, &repo, &tag, token_opt).await {
+            let lang = chat_language(&state.db, msg.chat.id.0).await;
+            let published = render(
+                lang,
+                ReleaseText::TagAlreadyPublished,
+                &[("tag", &tag), ("url", &url)],
+            );
+            bot.send_message(msg.chat.id, published).await?;
+            return Ok(());
+        }
+    }
+
+    let text = match handle_watch_tag(&state.db, &state.bot_id, msg.chat.id.0, &url, &tag).await {
+        Ok(HandleWatchTagResult::AlreadyWatching { message }) => message,
+        Ok(HandleWatchTagResult::Created { message, .. }) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn handle_watch_tag_creates_then_reports_existing() {
+        let db = setup_db().await;
+
+        let res = handle_watch_tag(&db, "", 1, "https://github.com/owner/repo", "v2.0.0")
+            .await
+            .expect("should succeed");
+        assert!(matches!(res, HandleWatchTagResult::Created { .. }));
+
+        let res = handle_watch_tag(&db, "", 1, "https://github.com/owner/repo", "v2.0.0")
+            .await
+            .expect("should succeed");
+        assert!(matches!(res, HandleWatchTagResult::AlreadyWatching { .. }));
+    }
+
+    #[tokio::test]
+    async fn handle_watch_tag_rejects_invalid_input() {
+        let db = setup_db().await;
+
+        assert!(
+            handle_watch_tag(&db, "", 1, "https://example.com/owner/repo", "v1")
+                .await
+                .is_err()
+        );
+        assert!(
+            handle_watch_tag(&db, "", 1, "https://github.com/owner/repo", " ")
+                .await
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::chat_settings::{ChatWebhook, Lang, WebhookKind};
+use crate::messages::{CommandText, DeliveryText, render, text};
+
+/// Shows, sets (`slack <url>` or `discord <url>`) or with `off` removes the webhook the
+/// chat's release notifications are mirrored to. The URL is a secret and never echoed.
+pub(crate) async fn handle_webhook(
+    db: &SqlitePool,
+    chat_id: i64,
+    value: &str,
+) -> Result<String, String> {
+    let repository = SqliteChatSettingsRepository::new(db.clone());
+    let mut settings = repository.find_or_default(chat_id).await.map_err(|e| {
+        render(
+            Lang::default(),
+            CommandText::LoadChatSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    let lang = settings.language;
+
+    let mut parts = value.split_whitespace();
+    let (kind, url) = match (parts.next(), parts.next(), parts.next()) {
+        (None, _, _) => {
+            return Ok(match &settings.webhook {
+                Some(webhook) => render(
+                    lang,
+                    DeliveryText::WebhookCurrent,
+                    &[("kind", webhook.kind.as_str())],
+                ),
+                None => text(lang, DeliveryText::WebhookNone).to_string(),
+            });
+        }
+        (Some(off), None, _) if off.eq_ignore_ascii_case("off") => {
+            settings.webhook = None;
+            settings.updated_at = chrono::Utc::now();
+            repository.save(&settings).await.map_err(|e| {
+                render(
+                    lang,
+                    CommandText::SaveChatSettingsFailed,
+                    &[("error", &e.to_string())],
+                )
+            })?;
+            return Ok(text(lang, DeliveryText::WebhookOff).to_string());
+        }
+        (Some(kind), Some(url), None) => (kind, url),
+        _ => {
+            return Err(render(
+                lang,
+                CommandText::Usage,
+                &[("usage", "/webhook slack|discord <url>, or /webhook off")],
+            ));
+        }
+    };
+
+    let kind = WebhookKind::parse(kind)
+        .ok_or_else(|| render(lang, DeliveryText::WebhookUnknownKind, &[("kind", kind)]))?;
+    if !url.starts_with("https://") || reqwest::Url::parse(url).is_err() {
+        return Err(text(lang, DeliveryText::WebhookNotHttps).to_string());
+    }
+
+    settings.webhook = Some(ChatWebhook {
+        kind,
+        url: url.to_string(),
+    });
+    settings.updated_at = chrono::Utc::now();
+    repository.save(&settings).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::SaveChatSettingsFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+
+    Ok(render(
+        lang,
+        DeliveryText::WebhookSet,
+        &[("kind", kind.as_str())],
+    ))
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    value: String,
+) -> ResponseResult<()> {
+    let text = if cfg!(feature = "webhooks") {
+        match handle_webhook(&state.db, msg.chat.id.0, &value).await {
+            Ok(message) => message,
+            Err(err_msg) => err_msg,
+        }
+    } else {
+        let lang = chat_language(&state.db, msg.chat.id.0).await;
+        text(lang, DeliveryText::WebhooksUnsupported).to_string()
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn sets_shows_and_removes_webhook() {
+        let db = setup_db().await;
+        let url = "https://hooks.slack.com/services/T0/B0/secret";
+
+        handle_webhook(&db, 1, &format!("slack {url}"))
+            .await
+            .expect("should succeed");
+        let settings = SqliteChatSettingsRepository::new(db.clone())
+            .find_or_default(1)
+            .await
+            .unwrap();
+        assert_eq!(
+            settings.webhook,
+            Some(ChatWebhook {
+                kind: WebhookKind::Slack,
+                url: url.to_string(),
+            })
+        );
+
+        let shown = handle_webhook(&db, 1, "").await.unwrap();
+        assert!(shown.contains("slack"));
+        assert!(!shown.contains("secret"));
+
+        handle_webhook(&db, 1, "off").await.unwrap();
+        let settings = SqliteChatSettingsRepository::new(db.clone())
+            .find_or_default(1)
+            .await
+            .unwrap();
+        assert_eq!(settings.webhook, None);
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_kind_and_plain_http() {
+        let db = setup_db().await;
+        assert!(
+            handle_webhook(&db, 1, "teams https://example.com/hook")
+                .await
+                .is_err()
+        );
+        assert!(
+            handle_webhook(&db, 1, "discord http://example.com/hook")
+                .await
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,275 @@
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqlitePool;
+use teloxide::prelude::*;
+
+use crate::bot::BotState;
+use crate::bot::language::chat_language;
+use crate::bot::list::describe_settings;
+use crate::bot::lookup::find_tracked_for_chat;
+use crate::chat_settings::Lang;
+use crate::github::{HttpReleaseSource, ReleaseSource, github_api_base};
+use crate::messages::{CommandText, ListText, PollText, render, text};
+use crate::poller::{Decision, decide, fetch_latest_from_sources};
+use crate::tracked_repositories::mirrors::repository::{
+    RepositoryMirrorsRepository, SqliteRepositoryMirrorsRepository,
+};
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+use crate::tracked_repositories::tracked_repositories_releases::repository::{
+    CachedRepositoryReleasesRepository, SqliteCachedRepositoryReleasesRepository,
+};
+
+fn format_time(at: DateTime<Utc>) -> String {
+    at.format("%Y-%m-%d %H:%M UTC").to_string()
+}
+
+fn explain(decision: &Decision, tag: &str, lang: Lang) -> String {
+    let (key, until) = match decision {
+        Decision::Remember => (PollText::Remember, None),
+        Decision::Unchanged => (PollText::Unchanged, None),
+        Decision::Muted => (PollText::Muted, None),
+        Decision::Snoozed { until } => (PollText::Snoozed, Some(*until)),
+        Decision::TooYoung { until } => (PollText::TooYoung, Some(*until)),
+        Decision::Throttled { until } => (PollText::Throttled, Some(*until)),
+        Decision::CollapsedPrerelease => (PollText::CollapsedPrerelease, None),
+        Decision::Notify => (PollText::Notify, None),
+    };
+    let until = until.map(format_time).unwrap_or_default();
+    render(lang, key, &[("tag", tag), ("until", &until)])
+}
+
+/// Explains what the poller knows about a tracked repository and what it would do with
+/// the release GitHub reports right now.
+pub(crate) async fn handle_why(
+    db: &SqlitePool,
+    bot_id: &str,
+    release_source: &dyn ReleaseSource,
+    chat_id: i64,
+    url: &str,
+    now: DateTime<Utc>,
+) -> Result<String, String> {
+    let tracked = find_tracked_for_chat(db, bot_id, chat_id, url).await?;
+    let lang = chat_language(db, chat_id).await;
+    let cache_repo = SqliteCachedRepositoryReleasesRepository::new(db.clone());
+    let cached = cache_repo
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .map_err(|e| {
+            render(
+                lang,
+                CommandText::CachedReleaseFailed,
+                &[("error", &e.to_string())],
+            )
+        })?;
+    let last_check = cache_repo.find_last_check(&tracked.id).await.map_err(|e| {
+        render(
+            lang,
+            CommandText::LastCheckFailed,
+            &[("error", &e.to_string())],
+        )
+    })?;
+    let settings = SqliteRepositorySettingsRepository::new(db.clone())
+        .find_or_default(&tracked.id)
+        .await
+        .map_err(|e| {
+            render(
+                lang,
+                CommandText::LoadSettingsFailed,
+                &[("error", &e.to_string())],
+            )
+        })?;
+
+    let mut lines = vec![format!(
+        "{} ({})",
+        tracked.repository_name, tracked.repository_url
+    )];
+    lines.push(match &cached {
+        Some(c) => render(
+            lang,
+            PollText::CachedRelease,
+            &[
+                ("tag", &c.tag_name),
+                ("seen", &format_time(c.first_seen_at)),
+            ],
+        ),
+        None => text(lang, PollText::NoCachedRelease).to_string(),
+    });
+    lines.push(match last_check {
+        Some((status, at)) => render(
+            lang,
+            PollText::LastCheck,
+            &[("status", status.as_str()), ("at", &format_time(at))],
+        ),
+        None => text(lang, PollText::NoLastCheck).to_string(),
+    });
+    let described = describe_settings(&settings, now, lang)
+        .unwrap_or_else(|| text(lang, ListText::Defaults).to_string());
+    lines.push(render(
+        lang,
+        PollText::Settings,
+        &[("settings", &described)],
+    ));
+    let explain_line =
+        |explanation: &str| render(lang, PollText::Decision, &[("explanation", explanation)]);
+
+    if let Some(category) = &settings.discussion_category {
+        let explanation = render(lang, PollText::DecideDiscussions, &[("category", category)]);
+        lines.push(explain_line(&explanation));
+        return Ok(lines.join("\n"));
+    }
+    if let Some(every) = settings.commit_milestone {
+        let every = every.to_string();
+        let explanation = render(lang, PollText::DecideMilestones, &[("every", &every)]);
+        lines.push(explain_line(&explanation));
+        return Ok(lines.join("\n"));
+    }
+
+    let mut sources = vec![tracked.repository_url.clone()];
+    if let Ok(mirrors) = SqliteRepositoryMirrorsRepository::new(db.clone())
+        .find_by_tracked_repository_id(&tracked.id)
+        .await
+    {
+        sources.extend(mirrors.into_iter().map(|m| m.repository_url));
+    }
+    let latest = fetch_latest_from_sources(release_source, &sources, &settings).await;
+    match latest {
+        Ok(Some(latest)) => {
+            lines.push(render(lang, PollText::GithubNow, &[("tag", &latest.tag)]));
+            let decision = decide(
+                &settings,
+                cached.as_ref().map(|c| c.tag_name.as_str()),
+                &latest.tag,
+                latest.details.published_at,
+                now,
+            );
+            lines.push(explain_line(&explain(&decision, &latest.tag, lang)));
+        }
+        Ok(None) => {
+            lines.push(text(lang, PollText::GithubNothing).to_string());
+            lines.push(explain_line(text(lang, PollText::DecideNothingYet)));
+        }
+        Err(e) => {
+            let error = e.to_string();
+            lines.push(render(lang, PollText::GithubFailed, &[("error", &error)]));
+            lines.push(explain_line(text(lang, PollText::DecideAfterLookup)));
+        }
+    }
+    Ok(lines.join("\n"))
+}
+
+pub(super) async fn answer(
+    bot: &Bot,
+    msg: &Message,
+    state: &BotState,
+    url: String,
+) -> ResponseResult<()> {
+    let release_source = HttpReleaseSource::new(
+        reqwest::Client::new(),
+        state.config.resolve_token().token(),
+        github_api_base(),
+    );
+    let text = match handle_why(
+        &state.db,
+        &state.bot_id,
+        &release_source,
+        msg.chat.id.0,
+        &url,
+        Utc::now(),
+    )
+    .await
+    {
+        Ok(message) => message,
+        Err(err_msg) => err_msg,
+    };
+    bot.send_message(msg.chat.id, text).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::track::{HandleTrackResult, handle_track};
+    use crate::tracked_repositories::tracked_repositories_releases::CachedRepositoryRelease;
+    use mockito::Server;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_db() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn explains_pending_notification() {
+        let db = setup_db().await;
+        let id = match handle_track(&db, "", 1, "repo", "https://github.com/owner/repo")
+            .await
+            .unwrap()
+        {
+            HandleTrackResult::Created { id, .. } => id,
+            _ => panic!("expected Created"),
+        };
+        SqliteCachedRepositoryReleasesRepository::new(db.clone())
+            .save(&CachedRepositoryRelease {
+                tracked_repository_id: id,
+                tag_name: "v1.0.0".to_string(),
+                first_seen_at: Utc::now(),
+                body_hash: None,
+                from_release: true,
+            })
+            .await
+            .unwrap();
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!({"tag_name": "v1.1.0"}).to_string())
+            .create_async()
+            .await;
+
+        let text = handle_why(
+            &db,
+            "",
+            &HttpReleaseSource::new(reqwest::Client::new(), None, server.url()),
+            1,
+            "https://github.com/owner/repo",
+            Utc::now(),
+        )
+        .await
+        .expect("should succeed");
+
+        assert!(text.contains("Cached release: v1.0.0"));
+        assert!(text.contains("Last check: not checked yet"));
+        assert!(text.contains("Settings: defaults"));
+        assert!(text.contains("GitHub now: v1.1.0"));
+        assert!(text.contains("Decision: v1.1.0 is new"));
+    }
+
+    #[tokio::test]
+    async fn untracked_repository_is_an_error() {
+        let db = setup_db().await;
+        let err = handle_why(
+            &db,
+            "",
+            &HttpReleaseSource::new(reqwest::Client::new(), None, github_api_base()),
+            1,
+            "https://github.com/owner/other",
+            Utc::now(),
+        )
+        .await
+        .expect_err("not tracked");
+        assert!(err.contains("not tracking"));
+    }
+}
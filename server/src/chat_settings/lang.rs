@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+/// Language the bot writes a chat's messages in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Lang {
+    #[default]
+    En,
+    It,
+    De,
+}
+
+impl Lang {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "en" | "english" => Some(Self::En),
+            "it" | "italian" | "italiano" => Some(Self::It),
+            "de" | "german" | "deutsch" => Some(Self::De),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::It => "it",
+            Self::De => "de",
+        }
+    }
+
+    /// The language's name in that language.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::En => "English",
+            Self::It => "italiano",
+            Self::De => "Deutsch",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_accepts_codes_and_names() {
+        assert_eq!(Lang::parse(" IT "), Some(Lang::It));
+        assert_eq!(Lang::parse("Deutsch"), Some(Lang::De));
+        assert_eq!(Lang::parse(Lang::En.as_str()), Some(Lang::En));
+        assert_eq!(Lang::parse("fr"), None);
+    }
+}
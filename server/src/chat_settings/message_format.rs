@@ -0,0 +1,99 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+use teloxide::types::ParseMode;
+
+use crate::utils::{html_escape, markdown_v2_escape, markdown_v2_escape_url};
+
+/// Markup used for messages sent to a chat.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MessageFormat {
+    #[default]
+    Html,
+    MarkdownV2,
+}
+
+impl MessageFormat {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "html" => Some(Self::Html),
+            "markdown" | "markdown_v2" | "markdownv2" => Some(Self::MarkdownV2),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Html => "html",
+            Self::MarkdownV2 => "markdown_v2",
+        }
+    }
+
+    pub fn parse_mode(&self) -> ParseMode {
+        match self {
+            Self::Html => ParseMode::Html,
+            Self::MarkdownV2 => ParseMode::MarkdownV2,
+        }
+    }
+
+    pub fn escape<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        match self {
+            Self::Html => html_escape(text),
+            Self::MarkdownV2 => markdown_v2_escape(text),
+        }
+    }
+
+    /// A link whose label is escaped plain text.
+    pub fn link(&self, url: &str, label: &str) -> String {
+        self.link_markup(url, &self.escape(label))
+    }
+
+    /// A link whose label is already formatted markup.
+    pub fn link_markup(&self, url: &str, label_markup: &str) -> String {
+        match self {
+            Self::Html => format!("<a href=\"{}\">{}</a>", html_escape(url), label_markup),
+            Self::MarkdownV2 => format!("[{}]({})", label_markup, markdown_v2_escape_url(url)),
+        }
+    }
+
+    pub fn bold(&self, text: &str) -> String {
+        match self {
+            Self::Html => format!("<b>{}</b>", html_escape(text)),
+            Self::MarkdownV2 => format!("*{}*", markdown_v2_escape(text)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_accepts_aliases() {
+        assert_eq!(MessageFormat::parse("HTML"), Some(MessageFormat::Html));
+        assert_eq!(
+            MessageFormat::parse("markdown"),
+            Some(MessageFormat::MarkdownV2)
+        );
+        assert_eq!(
+            MessageFormat::parse(MessageFormat::MarkdownV2.as_str()),
+            Some(MessageFormat::MarkdownV2)
+        );
+        assert_eq!(MessageFormat::parse("rtf"), None);
+    }
+
+    #[test]
+    fn markdown_link_escapes_label_and_url() {
+        let link = MessageFormat::MarkdownV2.link("https://x.io/a_(b)", "my_repo.rs");
+        assert_eq!(link, "[my\\_repo\\.rs](https://x.io/a_(b\\))");
+    }
+
+    #[test]
+    fn html_link_escapes_label_and_url() {
+        let link = MessageFormat::Html.link("https://x.io/?a=1&b=2", "<repo>");
+        assert_eq!(
+            link,
+            "<a href=\"https://x.io/?a=1&amp;b=2\">&lt;repo&gt;</a>"
+        );
+    }
+}
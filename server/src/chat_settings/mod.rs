@@ -0,0 +1,141 @@
+mod lang;
+mod message_format;
+mod release_notes;
+pub mod repository;
+mod template;
+mod webhook;
+
+pub use lang::Lang;
+pub use message_format::MessageFormat;
+pub use release_notes::ReleaseNotes;
+pub use template::{PLACEHOLDERS, ReleaseInfo, render_template};
+pub use webhook::{ChatWebhook, WebhookKind};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{FromRow, Row};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatSettings {
+    pub chat_id: i64,
+    pub message_format: MessageFormat,
+    pub release_notes: ReleaseNotes,
+    /// Plain text put in front of every notification.
+    pub message_prefix: Option<String>,
+    /// Plain text put below every notification.
+    pub message_suffix: Option<String>,
+    /// Replaces the default release headline; see `render_template`.
+    pub message_template: Option<String>,
+    /// Slack or Discord webhook release notifications are mirrored to.
+    pub webhook: Option<ChatWebhook>,
+    /// React to successful `/track` commands, unless `TRACK_REACTIONS` is off.
+    pub track_reactions: bool,
+    /// Language of the text the bot writes to the chat.
+    pub language: Lang,
+    /// Render `/list` with links in the chat's message format; off sends plain text.
+    pub list_links: bool,
+    /// Notifications for the whole chat are held back until then.
+    pub snoozed_until: Option<DateTime<Utc>>,
+    /// Repositories that released while the chat was snoozed, in order of release.
+    pub snooze_missed: Vec<String>,
+    /// Tell the chat when the release it last heard about is deleted on GitHub.
+    pub notify_removed_releases: bool,
+    /// Name the tag a release follows in its notification, when there is one.
+    pub show_previous_tag: bool,
+    /// Pin the latest release notification, unpinning the one pinned before it.
+    pub pin_releases: bool,
+    /// Announce the current release of a newly tracked repository instead of only
+    /// remembering it.
+    pub notify_on_first_seen: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ChatSettings {
+    /// Settings used for chats that never changed anything.
+    pub fn default_for(chat_id: i64) -> Self {
+        Self {
+            chat_id,
+            message_format: MessageFormat::default(),
+            release_notes: ReleaseNotes::default(),
+            message_prefix: None,
+            message_suffix: None,
+            message_template: None,
+            webhook: None,
+            track_reactions: true,
+            language: Lang::default(),
+            list_links: true,
+            snoozed_until: None,
+            snooze_missed: Vec::new(),
+            notify_removed_releases: false,
+            show_previous_tag: true,
+            pin_releases: false,
+            notify_on_first_seen: false,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+impl FromRow<'_, SqliteRow> for ChatSettings {
+    fn from_row(row: &SqliteRow) -> Result<Self, sqlx::Error> {
+        let chat_id: i64 = row.try_get("chat_id")?;
+        let message_format_str: String = row.try_get("message_format")?;
+        let message_format = MessageFormat::parse(&message_format_str).ok_or_else(|| {
+            sqlx::Error::Decode(format!("unknown message format {message_format_str}").into())
+        })?;
+        let release_notes_str: String = row.try_get("release_notes")?;
+        let release_notes = ReleaseNotes::parse(&release_notes_str).ok_or_else(|| {
+            sqlx::Error::Decode(format!("unknown release notes mode {release_notes_str}").into())
+        })?;
+        let message_prefix: Option<String> = row.try_get("message_prefix")?;
+        let message_suffix: Option<String> = row.try_get("message_suffix")?;
+        let message_template: Option<String> = row.try_get("message_template")?;
+        let webhook_kind: Option<String> = row.try_get("webhook_kind")?;
+        let webhook_url: Option<String> = row.try_get("webhook_url")?;
+        let webhook = match (webhook_kind, webhook_url) {
+            (Some(kind), Some(url)) => Some(ChatWebhook {
+                kind: WebhookKind::parse(&kind).ok_or_else(|| {
+                    sqlx::Error::Decode(format!("unknown webhook kind {kind}").into())
+                })?,
+                url,
+            }),
+            _ => None,
+        };
+        let track_reactions: bool = row.try_get("track_reactions")?;
+        let language_str: String = row.try_get("language")?;
+        let language = Lang::parse(&language_str).ok_or_else(|| {
+            sqlx::Error::Decode(format!("unknown language {language_str}").into())
+        })?;
+        let list_links: bool = row.try_get("list_links")?;
+        let snoozed_until: Option<DateTime<Utc>> = row.try_get("snoozed_until")?;
+        let snooze_missed: Option<String> = row.try_get("snooze_missed")?;
+        let snooze_missed = snooze_missed
+            .map(|names| names.lines().map(str::to_string).collect())
+            .unwrap_or_default();
+        let notify_removed_releases: bool = row.try_get("notify_removed_releases")?;
+        let show_previous_tag: bool = row.try_get("show_previous_tag")?;
+        let pin_releases: bool = row.try_get("pin_releases")?;
+        let notify_on_first_seen: bool = row.try_get("notify_on_first_seen")?;
+        let updated_at: DateTime<Utc> = row.try_get("updated_at")?;
+
+        Ok(Self {
+            chat_id,
+            message_format,
+            release_notes,
+            message_prefix,
+            message_suffix,
+            message_template,
+            webhook,
+            track_reactions,
+            language,
+            list_links,
+            snoozed_until,
+            snooze_missed,
+            notify_removed_releases,
+            show_previous_tag,
+            pin_releases,
+            notify_on_first_seen,
+            updated_at,
+        })
+    }
+}
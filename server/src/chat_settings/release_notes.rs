@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+/// Whether release notes are appended to notifications sent to a chat.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReleaseNotes {
+    #[default]
+    Off,
+    On,
+    /// Like `On`, but notes identical to the previous release's are left out.
+    Dedupe,
+}
+
+impl ReleaseNotes {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "off" => Some(Self::Off),
+            "on" => Some(Self::On),
+            "dedupe" => Some(Self::Dedupe),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::On => "on",
+            Self::Dedupe => "dedupe",
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use crate::chat_settings::ChatSettings;
+use async_trait::async_trait;
+use sqlx::{self, sqlite::SqlitePool};
+use std::error::Error;
+
+#[async_trait]
+pub trait ChatSettingsRepository: Send + Sync {
+    async fn save(&self, settings: &ChatSettings) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn find_by_chat_id(
+        &self,
+        chat_id: i64,
+    ) -> Result<Option<ChatSettings>, Box<dyn Error + Send + Sync>>;
+    /// Updates only the chat's snooze, leaving settings changed meanwhile untouched.
+    async fn save_snooze(
+        &self,
+        settings: &ChatSettings,
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    /// Returns the stored settings for the chat, or the defaults if none were saved.
+    async fn find_or_default(
+        &self,
+        chat_id: i64,
+    ) -> Result<ChatSettings, Box<dyn Error + Send + Sync>> {
+        Ok(self
+            .find_by_chat_id(chat_id)
+            .await?
+            .unwrap_or_else(|| ChatSettings::default_for(chat_id)))
+    }
+}
+
+/// Repository names are stored one per line; an empty list is stored as NULL.
+fn stored_snooze_missed(settings: &ChatSettings) -> Option<String> {
+    (!settings.snooze_missed.is_empty()).then(|| settings.snooze_missed.join("\n"))
+}
+
+pub struct SqliteChatSettingsRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteChatSettingsRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ChatSettingsRepository for SqliteChatSettingsRepository {
+    async fn save(&self, settings: &ChatSettings) -> Result<(), Box<dyn Error + Send + Sync>> {
+        sqlx::query(
+            r#"
+            INSERT INTO chat_settings (
+                chat_id, message_format, release_notes, message_prefix, message_suffix,
+                message_template, webhook_kind, webhook_url, track_reactions, updated_at,
+                language, list_links, snoozed_until, snooze_missed, notify_removed_releases,
+                show_previous_tag, pin_releases, notify_on_first_seen
+            )
+            VALUES (
+                ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18
+            )
+            ON CONFLICT(chat_id) DO UPDATE SET
+                message_format = excluded.message_format,
+                release_notes = excluded.release_notes,
+                message_prefix = excluded.message_prefix,
+                message_suffix = excluded.message_suffix,
+                message_template = excluded.message_template,
+                webhook_kind = excluded.webhook_kind,
+                webhook_url = excluded.webhook_url,
+                track_reactions = excluded.track_reactions,
+                updated_at = excluded.updated_at,
+                language = excluded.language,
+                list_links = excluded.list_links,
+                snoozed_until = excluded.snoozed_until,
+                snooze_missed = excluded.snooze_missed,
+                notify_removed_releases = excluded.notify_removed_releases,
+                show_previous_tag = excluded.show_previous_tag,
+                pin_releases = excluded.pin_releases,
+                notify_on_first_seen = excluded.notify_on_first_seen
+            "#,
+        )
+        .bind(settings.chat_id)
+        .bind(settings.message_format.as_str())
+        .bind(settings.release_notes.as_str())
+        .bind(&settings.message_prefix)
+        .bind(&settings.message_suffix)
+        .bind(&settings.message_template)
+        .bind(settings.webhook.as_ref().map(|w| w.kind.as_str()))
+        .bind(settings.webhook.as_ref().map(|w| w.url.as_str()))
+        .bind(settings.track_reactions)
+        .bind(settings.updated_at)
+        .bind(settings.language.as_str())
+        .bind(settings.list_links)
+        .bind(settings.snoozed_until)
+        .bind(stored_snooze_missed(settings))
+        .bind(settings.notify_removed_releases)
+        .bind(settings.show_previous_tag)
+        .bind(settings.pin_releases)
+        .bind(settings.notify_on_first_seen)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn save_snooze(
+        &self,
+        settings: &ChatSettings,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        sqlx::query(
+            r#"
+            UPDATE chat_settings
+            SET snoozed_until = ?2, snooze_missed = ?3
+            WHERE chat_id = ?1
+            "#,
+        )
+        .bind(settings.chat_id)
+        .bind(settings.snoozed_until)
+        .bind(stored_snooze_missed(settings))
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_chat_id(
+        &self,
+        chat_id: i64,
+    ) -> Result<Option<ChatSettings>, Box<dyn Error + Send + Sync>> {
+        let rec = sqlx::query_as::<_, ChatSettings>(
+            r#"
+            SELECT chat_id, message_format, release_notes, message_prefix, message_suffix,
+                message_template, webhook_kind, webhook_url, track_reactions, updated_at,
+                language, list_links, snoozed_until, snooze_missed, notify_removed_releases,
+                show_previous_tag, pin_releases, notify_on_first_seen
+            FROM chat_settings
+            WHERE chat_id = ?1
+            "#,
+        )
+        .bind(chat_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(rec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chat_settings::{ChatWebhook, Lang, MessageFormat, ReleaseNotes, WebhookKind};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_repo() -> SqliteChatSettingsRepository {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        SqliteChatSettingsRepository::new(pool)
+    }
+
+    #[tokio::test]
+    async fn missing_settings_fall_back_to_defaults() {
+        let repo = setup_repo().await;
+
+        assert!(repo.find_by_chat_id(5).await.unwrap().is_none());
+        let settings = repo.find_or_default(5).await.unwrap();
+        assert_eq!(settings.chat_id, 5);
+        assert_eq!(settings.message_format, MessageFormat::Html);
+        assert_eq!(settings.language, Lang::En);
+    }
+
+    #[tokio::test]
+    async fn save_and_update_roundtrip() {
+        let repo = setup_repo().await;
+        let mut settings = ChatSettings::default_for(9);
+        settings.message_format = MessageFormat::MarkdownV2;
+        settings.release_notes = ReleaseNotes::Dedupe;
+        settings.message_prefix = Some("[Releases]".to_string());
+        settings.message_template = Some("{name} {tag}".to_string());
+        settings.webhook = Some(ChatWebhook {
+            kind: WebhookKind::Discord,
+            url: "https://discord.com/api/webhooks/1/abc".to_string(),
+        });
+        settings.track_reactions = false;
+        settings.language = Lang::It;
+        settings.list_links = false;
+        settings.snoozed_until = Some(settings.updated_at);
+        settings.snooze_missed = vec!["a".to_string(), "b".to_string()];
+        repo.save(&settings).await.unwrap();
+
+        let fetched = repo.find_or_default(9).await.unwrap();
+        assert_eq!(fetched.message_format, MessageFormat::MarkdownV2);
+        assert_eq!(fetched.release_notes, ReleaseNotes::Dedupe);
+        assert_eq!(fetched.message_prefix.as_deref(), Some("[Releases]"));
+        assert_eq!(fetched.message_suffix, None);
+        assert_eq!(fetched.message_template.as_deref(), Some("{name} {tag}"));
+        assert_eq!(fetched.webhook, settings.webhook);
+        assert!(!fetched.track_reactions);
+        assert_eq!(fetched.language, Lang::It);
+        assert!(!fetched.list_links);
+        assert_eq!(fetched.snoozed_until, settings.snoozed_until);
+        assert_eq!(fetched.snooze_missed, vec!["a", "b"]);
+
+        settings.message_format = MessageFormat::Html;
+        repo.save(&settings).await.unwrap();
+        let fetched = repo.find_or_default(9).await.unwrap();
+        assert_eq!(fetched.message_format, MessageFormat::Html);
+    }
+
+    #[tokio::test]
+    async fn save_snooze_keeps_other_settings() {
+        let repo = setup_repo().await;
+        let mut settings = ChatSettings::default_for(3);
+        repo.save(&settings).await.unwrap();
+
+        let mut changed = settings.clone();
+        changed.message_format = MessageFormat::MarkdownV2;
+        repo.save(&changed).await.unwrap();
+
+        settings.snooze_missed = vec!["repo".to_string()];
+        repo.save_snooze(&settings).await.unwrap();
+        let fetched = repo.find_or_default(3).await.unwrap();
+        assert_eq!(fetched.message_format, MessageFormat::MarkdownV2);
+        assert_eq!(fetched.snooze_missed, vec!["repo"]);
+
+        settings.snooze_missed.clear();
+        repo.save_snooze(&settings).await.unwrap();
+        assert!(
+            repo.find_or_default(3)
+                .await
+                .unwrap()
+                .snooze_missed
+                .is_empty()
+        );
+    }
+}
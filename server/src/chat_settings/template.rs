@@ -0,0 +1,120 @@
+use crate::chat_settings::MessageFormat;
+
+/// Placeholders a message template may use.
+pub const PLACEHOLDERS: [&str; 4] = ["{name}", "{repository}", "{tag}", "{url}"];
+
+/// The values a message template is rendered with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseInfo {
+    /// The name the chat tracks the repository under.
+    pub name: String,
+    /// `owner/repo`.
+    pub repository: String,
+    pub tag: String,
+    /// Where the release can be viewed.
+    pub url: String,
+}
+
+impl ReleaseInfo {
+    /// Made-up values used to preview templates.
+    pub fn sample() -> Self {
+        Self {
+            name: "my-project".to_string(),
+            repository: "octocat/my-project".to_string(),
+            tag: "v1.2.3".to_string(),
+            url: "https://github.com/octocat/my-project/releases/tag/v1.2.3".to_string(),
+        }
+    }
+
+    fn value(&self, placeholder: &str) -> Option<&str> {
+        match placeholder {
+            "name" => Some(&self.name),
+            "repository" => Some(&self.repository),
+            "tag" => Some(&self.tag),
+            "url" => Some(&self.url),
+            _ => None,
+        }
+    }
+}
+
+/// Renders `template` with `info`, escaping both the template text and the values for
+/// `format`. `{{` and `}}` stand for literal braces.
+pub fn render_template(
+    template: &str,
+    info: &ReleaseInfo,
+    format: MessageFormat,
+) -> Result<String, String> {
+    let mut rendered = String::new();
+    let mut literal = String::new();
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err("The template has a { without a closing }.".to_string()),
+                    }
+                }
+                let value = info.value(name.trim()).ok_or_else(|| {
+                    format!(
+                        "Unknown placeholder {{{name}}}. Available: {}.",
+                        PLACEHOLDERS.join(", ")
+                    )
+                })?;
+                rendered.push_str(&format.escape(&std::mem::take(&mut literal)));
+                rendered.push_str(&format.escape(value));
+            }
+            c => literal.push(c),
+        }
+    }
+    rendered.push_str(&format.escape(&literal));
+
+    Ok(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_placeholders_and_escapes() {
+        let rendered = render_template(
+            "{{new}} {name} <{tag}>",
+            &ReleaseInfo::sample(),
+            MessageFormat::Html,
+        )
+        .unwrap();
+        assert_eq!(rendered, "{new} my-project &lt;v1.2.3&gt;");
+    }
+
+    #[test]
+    fn rejects_unknown_placeholder() {
+        let err = render_template(
+            "{version} is out",
+            &ReleaseInfo::sample(),
+            MessageFormat::Html,
+        )
+        .expect_err("unknown placeholder");
+        assert!(err.starts_with("Unknown placeholder {version}."));
+        assert!(err.contains("{tag}"));
+    }
+
+    #[test]
+    fn rejects_unclosed_placeholder() {
+        let err = render_template("{tag is out", &ReleaseInfo::sample(), MessageFormat::Html)
+            .expect_err("unclosed placeholder");
+        assert!(err.contains("without a closing"));
+    }
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+/// The kind of incoming webhook a chat mirrors its release notifications to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WebhookKind {
+    Slack,
+    Discord,
+}
+
+impl WebhookKind {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "slack" => Some(Self::Slack),
+            "discord" => Some(Self::Discord),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Slack => "slack",
+            Self::Discord => "discord",
+        }
+    }
+}
+
+/// Where a chat's release notifications are mirrored to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatWebhook {
+    pub kind: WebhookKind,
+    pub url: String,
+}
@@ -0,0 +1,54 @@
+/// Which chats may talk to the bot. A denied chat is always refused; when an allowlist is
+/// set only the chats on it are served, otherwise every chat is.
+#[derive(Clone, Debug, Default)]
+pub struct ChatAccess {
+    pub allowed_chat_ids: Vec<i64>,
+    pub denied_chat_ids: Vec<i64>,
+}
+
+impl ChatAccess {
+    pub fn permits(&self, chat_id: i64) -> bool {
+        if self.denied_chat_ids.contains(&chat_id) {
+            return false;
+        }
+        self.allowed_chat_ids.is_empty() || self.allowed_chat_ids.contains(&chat_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open_to_all_when_unset() {
+        assert!(ChatAccess::default().permits(42));
+    }
+
+    #[test]
+    fn allowlist_restricts_chats() {
+        let access = ChatAccess {
+            allowed_chat_ids: vec![1, -100],
+            denied_chat_ids: Vec::new(),
+        };
+        assert!(access.permits(1));
+        assert!(access.permits(-100));
+        assert!(!access.permits(2));
+    }
+
+    #[test]
+    fn denylist_wins() {
+        let access = ChatAccess {
+            allowed_chat_ids: vec![1],
+            denied_chat_ids: vec![1, 3],
+        };
+        assert!(!access.permits(1));
+        assert!(!access.permits(3));
+
+        let access = ChatAccess {
+            allowed_chat_ids: Vec::new(),
+            denied_chat_ids: vec![3],
+        };
+        assert!(access.permits(2));
+        assert!(!access.permits(3));
+    }
+}
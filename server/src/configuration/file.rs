@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// Configuration read from the TOML file named by `CONFIG_FILE`. Keys are the lowercase
+/// names of the matching environment variables, which take precedence over the file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) struct FileConfig {
+    database_path: Option<String>,
+    teloxide_token: Option<String>,
+    extra_teloxide_tokens: Option<Vec<String>>,
+    poll_interval_secs: Option<u64>,
+    cache_write_batch_size: Option<u64>,
+    github_token: Option<String>,
+    admin_chat_ids: Option<Vec<i64>>,
+    operator_chat_id: Option<i64>,
+    allowed_chat_ids: Option<Vec<i64>>,
+    denied_chat_ids: Option<Vec<i64>>,
+    api_token: Option<String>,
+    github_webhook_secret: Option<String>,
+    http_bind_addr: Option<String>,
+    track_reactions: Option<bool>,
+    github_request_log: Option<bool>,
+    github_request_timeout_secs: Option<u64>,
+    release_history_limit: Option<u64>,
+    sqlite_busy_retries: Option<u32>,
+    pause_inaccessible_repositories: Option<bool>,
+    retention_days: Option<u64>,
+    compact_database: Option<bool>,
+    migration_backup: Option<bool>,
+}
+
+fn join_ids(ids: Vec<i64>) -> String {
+    ids.iter().map(i64::to_string).collect::<Vec<_>>().join(",")
+}
+
+impl FileConfig {
+    pub(super) fn parse(contents: &str) -> Result<Self, String> {
+        toml::from_str(contents).map_err(|e| format!("Invalid configuration file: {e}"))
+    }
+
+    pub(super) fn load(path: &str) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read configuration file '{path}': {e}"))?;
+        Self::parse(&contents)
+    }
+
+    /// The file's values keyed by environment variable name, in the same textual form the
+    /// environment would carry them.
+    pub(super) fn into_values(self) -> HashMap<&'static str, String> {
+        let entries = [
+            ("DATABASE_PATH", self.database_path),
+            ("TELOXIDE_TOKEN", self.teloxide_token),
+            (
+                "EXTRA_TELOXIDE_TOKENS",
+                self.extra_teloxide_tokens.map(|t| t.join(",")),
+            ),
+            (
+                "POLL_INTERVAL_SECS",
+                self.poll_interval_secs.map(|n| n.to_string()),
+            ),
+            (
+                "CACHE_WRITE_BATCH_SIZE",
+                self.cache_write_batch_size.map(|n| n.to_string()),
+            ),
+            ("GITHUB_TOKEN", self.github_token),
+            ("ADMIN_CHAT_IDS", self.admin_chat_ids.map(join_ids)),
+            (
+                "OPERATOR_CHAT_ID",
+                self.operator_chat_id.map(|id| id.to_string()),
+            ),
+            ("ALLOWED_CHAT_IDS", self.allowed_chat_ids.map(join_ids)),
+            ("DENIED_CHAT_IDS", self.denied_chat_ids.map(join_ids)),
+            ("API_TOKEN", self.api_token),
+            ("GITHUB_WEBHOOK_SECRET", self.github_webhook_secret),
+            ("HTTP_BIND_ADDR", self.http_bind_addr),
+            (
+                "TRACK_REACTIONS",
+                self.track_reactions.map(|b| b.to_string()),
+            ),
+            (
+                "GITHUB_REQUEST_LOG",
+                self.github_request_log.map(|b| b.to_string()),
+            ),
+            (
+                "GITHUB_REQUEST_TIMEOUT_SECS",
+                self.github_request_timeout_secs.map(|n| n.to_string()),
+            ),
+            (
+                "RELEASE_HISTORY_LIMIT",
+                self.release_history_limit.map(|n| n.to_string()),
+            ),
+            (
+                "SQLITE_BUSY_RETRIES",
+                self.sqlite_busy_retries.map(|n| n.to_string()),
+            ),
+            (
+                "PAUSE_INACCESSIBLE_REPOSITORIES",
+                self.pause_inaccessible_repositories.map(|b| b.to_string()),
+            ),
+            ("RETENTION_DAYS", self.retention_days.map(|n| n.to_string())),
+            (
+                "COMPACT_DATABASE",
+                self.compact_database.map(|b| b.to_string()),
+            ),
+            (
+                "MIGRATION_BACKUP",
+                self.migration_backup.map(|b| b.to_string()),
+            ),
+        ];
+        entries
+            .into_iter()
+            .filter_map(|(key, value)| value.map(|v| (key, v)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_file_values_to_environment_names() {
+        let values = FileConfig::parse(
+            r#"
+            database_path = "bot.db"
+            poll_interval_secs = 120
+            admin_chat_ids = [1, -200]
+            "#,
+        )
+        .expect("valid file")
+        .into_values();
+
+        assert_eq!(values["DATABASE_PATH"], "bot.db");
+        assert_eq!(values["POLL_INTERVAL_SECS"], "120");
+        assert_eq!(values["ADMIN_CHAT_IDS"], "1,-200");
+        assert!(!values.contains_key("GITHUB_TOKEN"));
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        let err = FileConfig::parse("poll_interval = 5").expect_err("unknown key");
+        assert!(err.contains("Invalid configuration file"));
+    }
+}
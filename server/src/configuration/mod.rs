@@ -0,0 +1,337 @@
+mod chat_access;
+mod file;
+mod summary;
+mod token_source;
+
+use std::collections::HashMap;
+
+pub use chat_access::ChatAccess;
+use file::FileConfig;
+pub use token_source::TokenSource;
+
+/// Looks up a raw configuration value by its environment variable name.
+type Lookup<'a> = dyn Fn(&str) -> Option<String> + 'a;
+
+/// Layers `env` over the values of the configuration file, so the environment wins.
+fn layered<'a>(
+    env: impl Fn(&str) -> Option<String> + 'a,
+    file: &'a HashMap<&'static str, String>,
+) -> impl Fn(&str) -> Option<String> + 'a {
+    move |key| env(key).or_else(|| file.get(key).cloned())
+}
+
+/// The numeric bot id a Telegram bot token starts with, as in `123456:ABC-DEF`.
+fn bot_id_for_token(token: &str) -> &str {
+    token.split(':').next().unwrap_or(token)
+}
+
+#[derive(Clone)]
+pub struct Configuration {
+    pub database_path: String,
+    pub teloxide_token: String,
+    /// Tokens of further bots served by this process, each with its own chats.
+    pub extra_teloxide_tokens: Vec<String>,
+    pub interval_secs: u64,
+    /// Release cache updates of a poll cycle are committed in transactions of this many rows.
+    pub cache_write_batch_size: usize,
+    pub github_token: Option<String>,
+    /// Telegram user ids allowed to use admin commands. A user's id is also the id of
+    /// their private chat with the bot, hence the name.
+    pub admin_chat_ids: Vec<i64>,
+    /// Chat told by the primary bot that the bot started, with what it tracks.
+    pub operator_chat_id: Option<i64>,
+    pub chat_access: ChatAccess,
+    pub api_token: Option<String>,
+    /// Secret GitHub signs release webhooks with; unset disables `/github/webhook`.
+    pub github_webhook_secret: Option<String>,
+    pub http_bind_addr: String,
+    /// React to successful `/track` commands; chats can still turn it off for themselves.
+    pub track_reactions: bool,
+    /// Log every GitHub request at debug level, with the token redacted.
+    pub github_request_log: bool,
+    /// How long a request to GitHub or a package registry may take; repositories can
+    /// override it with /timeout.
+    pub github_request_timeout_secs: u64,
+    /// Releases kept per repository in the release history; 0 keeps none.
+    pub release_history_limit: usize,
+    /// How often a database write that found SQLite busy is retried.
+    pub sqlite_busy_retries: u32,
+    /// Stop polling repositories GitHub no longer answers for, until they come back.
+    pub pause_inaccessible_repositories: bool,
+    /// Notification receipts and release history older than this many days are pruned
+    /// daily; 0 keeps them.
+    pub retention_days: u64,
+    /// Run VACUUM daily so the database file shrinks after pruning.
+    pub compact_database: bool,
+    /// Snapshot the database before migrating it, and restore the snapshot and migrate
+    /// once more when a migration fails.
+    pub migration_backup: bool,
+}
+
+impl Configuration {
+    fn resolve_secret_value(key: &str, value: String) -> Result<String, String> {
+        const SECRET_PREFIX: &str = "secret:";
+        if let Some(rest) = value.strip_prefix(SECRET_PREFIX) {
+            log::debug!("Resolving secret value for {}", key);
+            let path = rest.trim();
+            if path.is_empty() {
+                return Err(format!(
+                    "{} is using 'secret:' prefix but no file path was provided",
+                    key
+                ));
+            }
+            let content = std::fs::read_to_string(path).map_err(|e| {
+                format!(
+                    "Failed to read secret file for {} from '{}': {}",
+                    key, path, e
+                )
+            })?;
+            let content = content.trim_end_matches(&['\n', '\r'][..]).to_string();
+            Ok(content)
+        } else {
+            Ok(value)
+        }
+    }
+
+    fn resolve_env_or_panic(lookup: &Lookup, key: &str) -> String {
+        let raw = lookup(key).unwrap_or_else(|| panic!("{} environment variable is required", key));
+        Self::resolve_secret_value(key, raw).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    fn parse_chat_id_list(key: &str, value: &str) -> Result<Vec<i64>, String> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                s.parse::<i64>().map_err(|e| {
+                    format!("{} must be a comma-separated list of chat ids: {}", key, e)
+                })
+            })
+            .collect()
+    }
+
+    fn chat_id_list_from_env(lookup: &Lookup, key: &str) -> Vec<i64> {
+        match lookup(key) {
+            Some(raw) => {
+                let resolved =
+                    Self::resolve_secret_value(key, raw).unwrap_or_else(|e| panic!("{}", e));
+                Self::parse_chat_id_list(key, &resolved).unwrap_or_else(|e| panic!("{}", e))
+            }
+            None => Vec::new(),
+        }
+    }
+
+    fn parse_flag(key: &str, value: &str) -> Result<bool, String> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "on" | "true" | "1" | "yes" => Ok(true),
+            "off" | "false" | "0" | "no" => Ok(false),
+            other => Err(format!("{} must be on or off, got '{}'", key, other)),
+        }
+    }
+
+    fn flag_from_env(lookup: &Lookup, key: &str, default: bool) -> bool {
+        match lookup(key) {
+            Some(raw) => Self::parse_flag(key, &raw).unwrap_or_else(|e| panic!("{}", e)),
+            None => default,
+        }
+    }
+
+    pub fn is_admin(&self, user_id: i64) -> bool {
+        self.admin_chat_ids.contains(&user_id)
+    }
+
+    /// The id and token of every bot to run. The bot of `TELOXIDE_TOKEN` has the empty id,
+    /// so rows stored before other bots existed stay with it; every other bot is identified
+    /// by the numeric id its token starts with.
+    pub fn bots(&self) -> Vec<(String, String)> {
+        let mut bots = vec![(String::new(), self.teloxide_token.clone())];
+        for token in &self.extra_teloxide_tokens {
+            bots.push((bot_id_for_token(token).to_string(), token.clone()));
+        }
+        bots
+    }
+
+    fn extra_tokens_from_env(lookup: &Lookup, primary: &str) -> Result<Vec<String>, String> {
+        let Some(raw) = lookup("EXTRA_TELOXIDE_TOKENS") else {
+            return Ok(Vec::new());
+        };
+        let resolved = Self::resolve_secret_value("EXTRA_TELOXIDE_TOKENS", raw)?;
+        let tokens: Vec<String> = resolved
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+            .collect();
+        let mut ids = Vec::new();
+        for token in &tokens {
+            let id = bot_id_for_token(token);
+            if token == primary || id == bot_id_for_token(primary) || ids.contains(&id) {
+                return Err(format!(
+                    "EXTRA_TELOXIDE_TOKENS lists bot {id} more than once, or the bot of TELOXIDE_TOKEN"
+                ));
+            }
+            ids.push(id);
+        }
+        Ok(tokens)
+    }
+
+    /// Reads the configuration from the environment, falling back to the TOML file named
+    /// by `CONFIG_FILE` for variables that are not set.
+    pub fn from_file_and_env() -> Self {
+        let config_file = std::env::var("CONFIG_FILE").ok();
+        let file = match config_file.as_deref().map(str::trim) {
+            Some(path) if !path.is_empty() => FileConfig::load(path)
+                .unwrap_or_else(|e| panic!("{}", e))
+                .into_values(),
+            _ => HashMap::new(),
+        };
+        Self::from_lookup(&layered(|key| std::env::var(key).ok(), &file))
+    }
+
+    fn from_lookup(lookup: &Lookup) -> Self {
+        let database_path = Self::resolve_env_or_panic(lookup, "DATABASE_PATH");
+        let teloxide_token = Self::resolve_env_or_panic(lookup, "TELOXIDE_TOKEN");
+        let extra_teloxide_tokens = Self::extra_tokens_from_env(lookup, &teloxide_token)
+            .unwrap_or_else(|e| panic!("{}", e));
+
+        let interval_secs = match lookup("POLL_INTERVAL_SECS") {
+            Some(raw) => {
+                let resolved = Self::resolve_secret_value("POLL_INTERVAL_SECS", raw)
+                    .unwrap_or_else(|e| panic!("{}", e));
+                crate::utils::parse_duration(&resolved)
+                    .unwrap_or_else(|e| panic!("POLL_INTERVAL_SECS: {}", e))
+                    .as_secs()
+            }
+            None => 60,
+        };
+
+        let cache_write_batch_size = match lookup("CACHE_WRITE_BATCH_SIZE") {
+            Some(raw) => raw
+                .trim()
+                .parse::<usize>()
+                .ok()
+                .filter(|n| *n > 0)
+                .unwrap_or_else(|| panic!("CACHE_WRITE_BATCH_SIZE must be a positive integer")),
+            None => 100,
+        };
+
+        let github_token = match lookup("GITHUB_TOKEN") {
+            Some(raw) => {
+                let resolved = Self::resolve_secret_value("GITHUB_TOKEN", raw)
+                    .unwrap_or_else(|e| panic!("{}", e));
+                Some(resolved)
+            }
+            None => None,
+        };
+
+        let admin_chat_ids = Self::chat_id_list_from_env(lookup, "ADMIN_CHAT_IDS");
+
+        let operator_chat_id = match lookup("OPERATOR_CHAT_ID") {
+            Some(raw) => {
+                let resolved = Self::resolve_secret_value("OPERATOR_CHAT_ID", raw)
+                    .unwrap_or_else(|e| panic!("{}", e));
+                let trimmed = resolved.trim();
+                (!trimmed.is_empty()).then(|| {
+                    trimmed
+                        .parse::<i64>()
+                        .unwrap_or_else(|e| panic!("OPERATOR_CHAT_ID must be a chat id: {}", e))
+                })
+            }
+            None => None,
+        };
+
+        let chat_access = ChatAccess {
+            allowed_chat_ids: Self::chat_id_list_from_env(lookup, "ALLOWED_CHAT_IDS"),
+            denied_chat_ids: Self::chat_id_list_from_env(lookup, "DENIED_CHAT_IDS"),
+        };
+
+        let api_token = match lookup("API_TOKEN") {
+            Some(raw) => {
+                let resolved = Self::resolve_secret_value("API_TOKEN", raw)
+                    .unwrap_or_else(|e| panic!("{}", e));
+                Some(resolved).filter(|t| !t.is_empty())
+            }
+            None => None,
+        };
+
+        let github_webhook_secret = match lookup("GITHUB_WEBHOOK_SECRET") {
+            Some(raw) => {
+                let resolved = Self::resolve_secret_value("GITHUB_WEBHOOK_SECRET", raw)
+                    .unwrap_or_else(|e| panic!("{}", e));
+                Some(resolved).filter(|t| !t.is_empty())
+            }
+            None => None,
+        };
+
+        let http_bind_addr = lookup("HTTP_BIND_ADDR").unwrap_or_else(|| "0.0.0.0:8080".to_string());
+        let track_reactions = Self::flag_from_env(lookup, "TRACK_REACTIONS", true);
+        let github_request_log = Self::flag_from_env(lookup, "GITHUB_REQUEST_LOG", false);
+        let github_request_timeout_secs = match lookup("GITHUB_REQUEST_TIMEOUT_SECS") {
+            Some(raw) => crate::utils::parse_duration(&raw)
+                .ok()
+                .map(|d| d.as_secs())
+                .filter(|secs| *secs > 0)
+                .unwrap_or_else(|| {
+                    panic!("GITHUB_REQUEST_TIMEOUT_SECS must be a positive duration, e.g. 30s")
+                }),
+            None => 30,
+        };
+        let pause_inaccessible_repositories =
+            Self::flag_from_env(lookup, "PAUSE_INACCESSIBLE_REPOSITORIES", true);
+
+        let release_history_limit = match lookup("RELEASE_HISTORY_LIMIT") {
+            Some(raw) => raw.trim().parse::<usize>().unwrap_or_else(|e| {
+                panic!(
+                    "RELEASE_HISTORY_LIMIT must be a non-negative integer: {}",
+                    e
+                )
+            }),
+            None => 0,
+        };
+
+        let sqlite_busy_retries = match lookup("SQLITE_BUSY_RETRIES") {
+            Some(raw) => raw.trim().parse::<u32>().unwrap_or_else(|e| {
+                panic!("SQLITE_BUSY_RETRIES must be a non-negative integer: {}", e)
+            }),
+            None => 3,
+        };
+
+        let retention_days = match lookup("RETENTION_DAYS") {
+            Some(raw) => raw
+                .trim()
+                .parse::<u64>()
+                .unwrap_or_else(|e| panic!("RETENTION_DAYS must be a non-negative integer: {}", e)),
+            None => 0,
+        };
+        let compact_database = Self::flag_from_env(lookup, "COMPACT_DATABASE", false);
+        let migration_backup = Self::flag_from_env(lookup, "MIGRATION_BACKUP", false);
+
+        Self {
+            database_path,
+            teloxide_token,
+            extra_teloxide_tokens,
+            interval_secs,
+            cache_write_batch_size,
+            github_token,
+            admin_chat_ids,
+            operator_chat_id,
+            chat_access,
+            api_token,
+            github_webhook_secret,
+            http_bind_addr,
+            track_reactions,
+            github_request_log,
+            github_request_timeout_secs,
+            release_history_limit,
+            sqlite_busy_retries,
+            pause_inaccessible_repositories,
+            retention_days,
+            compact_database,
+            migration_backup,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,69 @@
+use super::Configuration;
+
+fn set_or_unset<T>(value: &Option<T>) -> &'static str {
+    if value.is_some() { "set" } else { "unset" }
+}
+
+fn on_or_off(flag: bool) -> &'static str {
+    if flag { "on" } else { "off" }
+}
+
+impl Configuration {
+    /// The settings this process runs with, one `key: value` per line. Tokens and secrets
+    /// only show whether they are set, never their value.
+    pub fn redacted_summary(&self, bot_id: &str, github_api_base: &str) -> String {
+        let bot = if bot_id.is_empty() {
+            "primary (TELOXIDE_TOKEN)".to_string()
+        } else {
+            format!("{bot_id} (EXTRA_TELOXIDE_TOKENS)")
+        };
+        let lines = [
+            format!("database_path: {}", self.database_path),
+            format!("interval_secs: {}", self.interval_secs),
+            format!("github_token: {}", set_or_unset(&self.github_token)),
+            format!("github_api_base: {github_api_base}"),
+            format!("bot: {bot}, {} bot(s) in total", self.bots().len()),
+            "bot_mode: long polling".to_string(),
+            format!("cache_write_batch_size: {}", self.cache_write_batch_size),
+            format!("release_history_limit: {}", self.release_history_limit),
+            format!("sqlite_busy_retries: {}", self.sqlite_busy_retries),
+            format!("admin_chat_ids: {}", self.admin_chat_ids.len()),
+            format!(
+                "operator_chat_id: {}",
+                self.operator_chat_id
+                    .map_or_else(|| "unset".to_string(), |id| id.to_string())
+            ),
+            format!(
+                "allowed_chat_ids: {}",
+                match self.chat_access.allowed_chat_ids.len() {
+                    0 => "all chats".to_string(),
+                    n => n.to_string(),
+                }
+            ),
+            format!(
+                "denied_chat_ids: {}",
+                self.chat_access.denied_chat_ids.len()
+            ),
+            format!("api_token: {}", set_or_unset(&self.api_token)),
+            format!(
+                "github_webhook_secret: {}",
+                set_or_unset(&self.github_webhook_secret)
+            ),
+            format!("http_bind_addr: {}", self.http_bind_addr),
+            format!("track_reactions: {}", on_or_off(self.track_reactions)),
+            format!("github_request_log: {}", on_or_off(self.github_request_log)),
+            format!(
+                "github_request_timeout_secs: {}",
+                self.github_request_timeout_secs
+            ),
+            format!(
+                "pause_inaccessible_repositories: {}",
+                on_or_off(self.pause_inaccessible_repositories)
+            ),
+            format!("retention_days: {}", self.retention_days),
+            format!("compact_database: {}", on_or_off(self.compact_database)),
+            format!("migration_backup: {}", on_or_off(self.migration_backup)),
+        ];
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,259 @@
+use super::*;
+use std::fs;
+use uuid::Uuid;
+
+fn write_temp_file_with_contents(contents: &str) -> String {
+    let mut path = std::env::temp_dir();
+    path.push(format!("github_release_bot_test_{}", Uuid::new_v4()));
+    fs::write(&path, contents).expect("failed to write temp file");
+    path.to_string_lossy().into_owned()
+}
+
+fn save_env_var(key: &str) -> Option<String> {
+    std::env::var(key).ok()
+}
+
+fn restore_env_var(key: &str, previous: Option<String>) {
+    match previous {
+        Some(value) => unsafe { std::env::set_var(key, value) },
+        None => unsafe { std::env::remove_var(key) },
+    }
+}
+
+#[test]
+fn from_file_and_env_reads_secret_files_successfully() {
+    let prev_db = save_env_var("DATABASE_PATH");
+    let prev_token = save_env_var("TELOXIDE_TOKEN");
+    let prev_interval = save_env_var("POLL_INTERVAL_SECS");
+    let prev_gh = save_env_var("GITHUB_TOKEN");
+
+    let token_file = write_temp_file_with_contents("my-telegram-token\n");
+    let interval_file = write_temp_file_with_contents("90\n");
+    let gh_file = write_temp_file_with_contents("gh-secret-token");
+
+    unsafe {
+        std::env::set_var("DATABASE_PATH", "db-path.db");
+        std::env::set_var("TELOXIDE_TOKEN", format!("secret:{}", token_file));
+        std::env::set_var("POLL_INTERVAL_SECS", format!("secret:{}", interval_file));
+        std::env::set_var("GITHUB_TOKEN", format!("secret:{}", gh_file));
+    }
+
+    let cfg = Configuration::from_file_and_env();
+
+    assert_eq!(cfg.database_path, "db-path.db");
+    assert_eq!(cfg.teloxide_token, "my-telegram-token");
+    assert_eq!(cfg.interval_secs, 90);
+    assert_eq!(cfg.github_token.as_deref(), Some("gh-secret-token"));
+
+    let _ = fs::remove_file(&token_file);
+    let _ = fs::remove_file(&interval_file);
+    let _ = fs::remove_file(&gh_file);
+
+    restore_env_var("DATABASE_PATH", prev_db);
+    restore_env_var("TELOXIDE_TOKEN", prev_token);
+    restore_env_var("POLL_INTERVAL_SECS", prev_interval);
+    restore_env_var("GITHUB_TOKEN", prev_gh);
+}
+
+#[test]
+fn parse_chat_id_list_accepts_spaces_and_negative_ids() {
+    let ids = Configuration::parse_chat_id_list("ADMIN_CHAT_IDS", " 12, -100345 ,,7")
+        .expect("valid list");
+    assert_eq!(ids, vec![12, -100345, 7]);
+}
+
+#[test]
+fn parse_chat_id_list_rejects_garbage() {
+    let err = Configuration::parse_chat_id_list("ADMIN_CHAT_IDS", "12,abc")
+        .expect_err("expected error for non-numeric id");
+    assert!(err.contains("ADMIN_CHAT_IDS"));
+}
+
+#[test]
+fn parse_flag_accepts_on_off_words() {
+    assert!(Configuration::parse_flag("TRACK_REACTIONS", " On ").unwrap());
+    assert!(Configuration::parse_flag("TRACK_REACTIONS", "true").unwrap());
+    assert!(!Configuration::parse_flag("TRACK_REACTIONS", "off").unwrap());
+    assert!(!Configuration::parse_flag("TRACK_REACTIONS", "0").unwrap());
+    assert!(Configuration::parse_flag("TRACK_REACTIONS", "maybe").is_err());
+}
+
+#[test]
+fn resolve_secret_value_requires_non_empty_path() {
+    let err = Configuration::resolve_secret_value("SOME_KEY", "secret:".to_string())
+        .expect_err("expected error for empty secret path");
+    assert!(err.contains("no file path"));
+}
+
+#[test]
+fn environment_overrides_file_values() {
+    let file = FileConfig::parse(
+        r#"
+        database_path = "file.db"
+        teloxide_token = "file-token"
+        poll_interval_secs = 600
+        "#,
+    )
+    .unwrap()
+    .into_values();
+    let env: HashMap<&str, String> = HashMap::from([("POLL_INTERVAL_SECS", "30".to_string())]);
+
+    let cfg = Configuration::from_lookup(&layered(|key| env.get(key).cloned(), &file));
+
+    assert_eq!(cfg.database_path, "file.db");
+    assert_eq!(cfg.teloxide_token, "file-token");
+    assert_eq!(cfg.interval_secs, 30);
+    assert!(cfg.track_reactions);
+    assert!(!cfg.github_request_log);
+    assert_eq!(cfg.github_request_timeout_secs, 30);
+    assert_eq!(cfg.release_history_limit, 0);
+    assert_eq!(cfg.sqlite_busy_retries, 3);
+    assert!(cfg.pause_inaccessible_repositories);
+    assert_eq!(cfg.retention_days, 0);
+    assert!(!cfg.compact_database);
+    assert!(!cfg.migration_backup);
+    assert_eq!(cfg.github_webhook_secret, None);
+    assert_eq!(cfg.operator_chat_id, None);
+}
+
+#[test]
+fn file_values_resolve_secret_prefix() {
+    let token_file = write_temp_file_with_contents("file-secret-token\n");
+    let file = FileConfig::parse(&format!(
+        "database_path = \"file.db\"\nteloxide_token = \"secret:{}\"\n",
+        token_file
+    ))
+    .unwrap()
+    .into_values();
+
+    let cfg = Configuration::from_lookup(&layered(|_| None, &file));
+
+    assert_eq!(cfg.teloxide_token, "file-secret-token");
+    let _ = fs::remove_file(&token_file);
+}
+
+#[test]
+fn extra_bots_are_identified_by_their_token() {
+    let env: HashMap<&str, String> = HashMap::from([
+        ("DATABASE_PATH", "bot.db".to_string()),
+        ("TELOXIDE_TOKEN", "111:primary".to_string()),
+        ("EXTRA_TELOXIDE_TOKENS", "222:staging, 333:test".to_string()),
+    ]);
+
+    let cfg = Configuration::from_lookup(&|key: &str| env.get(key).cloned());
+
+    assert_eq!(
+        cfg.bots(),
+        vec![
+            (String::new(), "111:primary".to_string()),
+            ("222".to_string(), "222:staging".to_string()),
+            ("333".to_string(), "333:test".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn extra_bots_must_differ_from_the_primary_bot() {
+    let env: HashMap<&str, String> =
+        HashMap::from([("EXTRA_TELOXIDE_TOKENS", "111:again".to_string())]);
+
+    let err =
+        Configuration::extra_tokens_from_env(&|key: &str| env.get(key).cloned(), "111:primary")
+            .unwrap_err();
+    assert!(err.contains("bot 111"));
+}
+
+#[test]
+fn poll_interval_accepts_a_unit() {
+    let env: HashMap<&str, String> = HashMap::from([
+        ("DATABASE_PATH", "bot.db".to_string()),
+        ("TELOXIDE_TOKEN", "111:primary".to_string()),
+        ("POLL_INTERVAL_SECS", "5m".to_string()),
+    ]);
+
+    let cfg = Configuration::from_lookup(&|key: &str| env.get(key).cloned());
+
+    assert_eq!(cfg.interval_secs, 300);
+}
+
+#[test]
+fn summary_redacts_tokens_and_secrets() {
+    let env: HashMap<&str, String> = HashMap::from([
+        ("DATABASE_PATH", "bot.db".to_string()),
+        ("TELOXIDE_TOKEN", "111:telegram-secret".to_string()),
+        ("EXTRA_TELOXIDE_TOKENS", "222:extra-secret".to_string()),
+        ("GITHUB_TOKEN", "ghp_supersecret".to_string()),
+        ("API_TOKEN", "api-secret".to_string()),
+        ("GITHUB_WEBHOOK_SECRET", "hook-secret".to_string()),
+        ("POLL_INTERVAL_SECS", "5m".to_string()),
+    ]);
+    let cfg = Configuration::from_lookup(&|key: &str| env.get(key).cloned());
+
+    let summary = cfg.redacted_summary("", "https://api.github.com");
+
+    assert!(summary.contains("github_token: set"));
+    assert!(summary.contains("api_token: set"));
+    assert!(summary.contains("github_webhook_secret: set"));
+    assert!(summary.contains("database_path: bot.db"));
+    assert!(summary.contains("interval_secs: 300"));
+    assert!(summary.contains("github_api_base: https://api.github.com"));
+    assert!(summary.contains("bot: primary (TELOXIDE_TOKEN), 2 bot(s) in total"));
+    for secret in [
+        "ghp_supersecret",
+        "telegram-secret",
+        "extra-secret",
+        "api-secret",
+        "hook-secret",
+    ] {
+        assert!(!summary.contains(secret), "{secret} leaked");
+    }
+
+    let unset: HashMap<&str, String> = HashMap::from([
+        ("DATABASE_PATH", "bot.db".to_string()),
+        ("TELOXIDE_TOKEN", "111:telegram-secret".to_string()),
+    ]);
+    let cfg = Configuration::from_lookup(&|key: &str| unset.get(key).cloned());
+    assert!(
+        cfg.redacted_summary("", "https://api.github.com")
+            .contains("github_token: unset")
+    );
+}
+
+#[test]
+fn operator_chat_id_is_optional() {
+    let env: HashMap<&str, String> = HashMap::from([
+        ("DATABASE_PATH", "bot.db".to_string()),
+        ("TELOXIDE_TOKEN", "token".to_string()),
+        ("OPERATOR_CHAT_ID", " -100123 ".to_string()),
+    ]);
+    let cfg = Configuration::from_lookup(&|key: &str| env.get(key).cloned());
+    assert_eq!(cfg.operator_chat_id, Some(-100123));
+
+    let env: HashMap<&str, String> = HashMap::from([
+        ("DATABASE_PATH", "bot.db".to_string()),
+        ("TELOXIDE_TOKEN", "token".to_string()),
+        ("OPERATOR_CHAT_ID", "".to_string()),
+    ]);
+    let cfg = Configuration::from_lookup(&|key: &str| env.get(key).cloned());
+    assert_eq!(cfg.operator_chat_id, None);
+}
+
+#[test]
+fn resolve_token_prefers_the_configured_token() {
+    let resolve = |token: Option<&str>| {
+        let mut env: HashMap<&str, String> = HashMap::from([
+            ("DATABASE_PATH", "bot.db".to_string()),
+            ("TELOXIDE_TOKEN", "111:primary".to_string()),
+        ]);
+        if let Some(token) = token {
+            env.insert("GITHUB_TOKEN", token.to_string());
+        }
+        let cfg = Configuration::from_lookup(&|key: &str| env.get(key).cloned());
+        cfg.resolve_token().token().map(str::to_string)
+    };
+
+    assert_eq!(resolve(Some(" ghp_token\n")).as_deref(), Some("ghp_token"));
+    // A blank token would be refused by GitHub, so requests go out anonymously instead
+    assert_eq!(resolve(Some("  ")), None);
+    assert_eq!(resolve(None), None);
+}
@@ -0,0 +1,31 @@
+use super::Configuration;
+
+/// Where the credentials for GitHub requests come from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenSource<'a> {
+    /// `GITHUB_TOKEN`, shared by every chat of every bot.
+    Global(&'a str),
+    /// No token: requests are anonymous and share GitHub's unauthenticated limit.
+    Anonymous,
+}
+
+impl<'a> TokenSource<'a> {
+    /// The token to send, if any.
+    pub fn token(self) -> Option<&'a str> {
+        match self {
+            TokenSource::Global(token) => Some(token),
+            TokenSource::Anonymous => None,
+        }
+    }
+}
+
+impl Configuration {
+    /// The credentials GitHub requests are sent with. A blank `GITHUB_TOKEN` counts as
+    /// unset, as GitHub would refuse it rather than treat the request as anonymous.
+    pub fn resolve_token(&self) -> TokenSource<'_> {
+        match self.github_token.as_deref().map(str::trim) {
+            Some(token) if !token.is_empty() => TokenSource::Global(token),
+            _ => TokenSource::Anonymous,
+        }
+    }
+}
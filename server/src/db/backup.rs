@@ -0,0 +1,51 @@
+use std::path::Path;
+
+use sqlx::sqlite::SqlitePool;
+
+use super::migrations;
+
+/// Where the snapshot taken before migrating `database_path` is kept.
+pub(crate) fn backup_path(database_path: &str) -> String {
+    format!("{database_path}.bak")
+}
+
+/// Copies the database to `backup` before migrations change it, returning whether it
+/// did. A database left behind by a failed migration is not copied, so the snapshot stays
+/// the last good one.
+pub(crate) async fn snapshot(
+    pool: &SqlitePool,
+    backup: &str,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    if !migrations::is_cleanly_behind(pool).await? {
+        return Ok(false);
+    }
+    // VACUUM INTO refuses to overwrite, and a crash halfway must not cost the old snapshot
+    let partial = format!("{backup}.partial");
+    if Path::new(&partial).exists() {
+        std::fs::remove_file(&partial)?;
+    }
+    sqlx::query("VACUUM INTO ?1")
+        .bind(&partial)
+        .execute(pool)
+        .await?;
+    std::fs::rename(&partial, backup)?;
+    log::info!(
+        "Saved a snapshot of the database to {} before migrating",
+        backup
+    );
+    Ok(true)
+}
+
+/// Puts the snapshot at `backup` in place of the database at `database_path`, whose
+/// connections must all be closed.
+pub(crate) fn restore(backup: &str, database_path: &str) -> std::io::Result<()> {
+    // Journal files of the failed run would otherwise be replayed onto the snapshot
+    for suffix in ["-wal", "-shm", "-journal"] {
+        let journal = format!("{database_path}{suffix}");
+        if Path::new(&journal).exists() {
+            std::fs::remove_file(&journal)?;
+        }
+    }
+    std::fs::copy(backup, database_path)?;
+    Ok(())
+}
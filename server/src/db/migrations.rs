@@ -0,0 +1,282 @@
+use sqlx::Row;
+use sqlx::migrate::{MigrateError, Migrator};
+use sqlx::sqlite::SqlitePool;
+
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+/// Differences between the migrations embedded in this build and those recorded in the
+/// database, computed before anything is applied.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct MigrationDiff {
+    /// Embedded migrations not yet applied.
+    pub pending: Vec<i64>,
+    /// Migrations applied to the database that this build does not know about.
+    pub unknown: Vec<i64>,
+    /// Applied migrations whose contents changed since they were applied.
+    pub modified: Vec<i64>,
+    /// Migrations recorded as partially applied.
+    pub failed: Vec<i64>,
+}
+
+impl MigrationDiff {
+    fn has_drift(&self) -> bool {
+        !self.unknown.is_empty() || !self.modified.is_empty() || !self.failed.is_empty()
+    }
+}
+
+pub(crate) async fn diff(
+    pool: &SqlitePool,
+    migrator: &Migrator,
+) -> Result<MigrationDiff, sqlx::Error> {
+    let table_exists: Option<String> = sqlx::query_scalar(
+        "SELECT name FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations'",
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    let applied = if table_exists.is_some() {
+        sqlx::query("SELECT version, checksum, success FROM _sqlx_migrations ORDER BY version")
+            .fetch_all(pool)
+            .await?
+    } else {
+        Vec::new()
+    };
+
+    let mut diff = MigrationDiff::default();
+    for row in &applied {
+        let version: i64 = row.try_get("version")?;
+        let checksum: Vec<u8> = row.try_get("checksum")?;
+        let success: bool = row.try_get("success")?;
+
+        match migrator.iter().find(|m| m.version == version) {
+            None => diff.unknown.push(version),
+            Some(m) if *m.checksum != *checksum => diff.modified.push(version),
+            Some(_) if !success => diff.failed.push(version),
+            Some(_) => {}
+        }
+    }
+
+    for m in migrator.iter() {
+        let mut versions = applied.iter().map(|r| r.get::<i64, _>("version"));
+        if !versions.any(|v| v == m.version) {
+            diff.pending.push(m.version);
+        }
+    }
+
+    Ok(diff)
+}
+
+fn describe(migrator: &Migrator, version: i64) -> String {
+    match migrator.iter().find(|m| m.version == version) {
+        Some(m) => format!("{} ({})", version, m.description),
+        None => version.to_string(),
+    }
+}
+
+pub(crate) fn describe_error(migrator: &Migrator, err: MigrateError) -> String {
+    match err {
+        MigrateError::ExecuteMigration(e, version) => {
+            format!("Migration {} failed: {}", describe(migrator, version), e)
+        }
+        MigrateError::VersionMissing(version) => format!(
+            "Migration {} is applied to the database but not included in this build; \
+             the database was created by a newer or different version of the bot",
+            version
+        ),
+        MigrateError::VersionMismatch(version) => format!(
+            "Migration {} was modified after it was applied to the database",
+            describe(migrator, version)
+        ),
+        MigrateError::Dirty(version) => format!(
+            "Migration {} was only partially applied by a previous run",
+            describe(migrator, version)
+        ),
+        other => format!("Failed to run migrations: {}", other),
+    }
+}
+
+/// A failed migration run, described by `describe_error`.
+#[derive(Debug)]
+pub(crate) struct MigrationFailure {
+    message: String,
+    /// Whether a migration failed to apply, which a snapshot taken before it can undo.
+    /// A database recorded by another build is not, as restoring it would lose data.
+    pub restorable: bool,
+}
+
+impl std::fmt::Display for MigrationFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for MigrationFailure {}
+
+pub(crate) async fn run_with(
+    pool: &SqlitePool,
+    migrator: &Migrator,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let diff = diff(pool, migrator).await?;
+    for version in &diff.pending {
+        log::info!("Pending migration {}", describe(migrator, *version));
+    }
+    if diff.has_drift() {
+        log::warn!(
+            "Database schema drift detected: unknown={:?} modified={:?} failed={:?}",
+            diff.unknown,
+            diff.modified,
+            diff.failed
+        );
+    }
+
+    migrator.run(pool).await.map_err(|e| {
+        let restorable = matches!(
+            e,
+            MigrateError::ExecuteMigration(..) | MigrateError::Dirty(_)
+        );
+        MigrationFailure {
+            message: describe_error(migrator, e),
+            restorable,
+        }
+        .into()
+    })
+}
+
+pub(crate) async fn run(pool: &SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+    run_with(pool, &MIGRATOR).await
+}
+
+/// Whether migrations are about to change a database that every earlier migration left
+/// intact, which is when a snapshot of it is worth keeping.
+pub(crate) async fn is_cleanly_behind(pool: &SqlitePool) -> Result<bool, sqlx::Error> {
+    let diff = diff(pool, &MIGRATOR).await?;
+    Ok(!diff.pending.is_empty() && !diff.has_drift())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_pool() -> SqlitePool {
+        SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool")
+    }
+
+    #[tokio::test]
+    async fn fresh_database_has_everything_pending() {
+        let pool = setup_pool().await;
+
+        let diff = diff(&pool, &MIGRATOR).await.unwrap();
+
+        assert_eq!(diff.pending.len(), MIGRATOR.iter().count());
+        assert!(!diff.has_drift());
+    }
+
+    #[tokio::test]
+    async fn migrated_database_has_no_diff() {
+        let pool = setup_pool().await;
+        run(&pool).await.expect("migrations should run");
+
+        let diff = diff(&pool, &MIGRATOR).await.unwrap();
+
+        assert_eq!(diff, MigrationDiff::default());
+    }
+
+    #[tokio::test]
+    async fn unknown_and_modified_migrations_are_reported() {
+        let pool = setup_pool().await;
+        run(&pool).await.unwrap();
+
+        sqlx::query("UPDATE _sqlx_migrations SET checksum = X'00' WHERE version = 1")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time) \
+             VALUES (9999, 'from the future', 1, X'00', 0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let diff = diff(&pool, &MIGRATOR).await.unwrap();
+
+        assert_eq!(diff.modified, vec![1]);
+        assert_eq!(diff.unknown, vec![9999]);
+        assert!(diff.pending.is_empty());
+    }
+
+    #[tokio::test]
+    async fn failure_names_the_offending_migration() {
+        let pool = setup_pool().await;
+        run(&pool).await.unwrap();
+
+        sqlx::query(
+            "INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time) \
+             VALUES (9999, 'from the future', 1, X'00', 0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let err = run(&pool).await.expect_err("unknown migration should fail");
+        assert!(err.to_string().contains("Migration 9999"));
+    }
+
+    #[tokio::test]
+    async fn scoping_by_bot_keeps_the_rows_of_each_repository() {
+        let earlier = std::env::temp_dir().join(format!("migrations-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir(&earlier).unwrap();
+        for entry in std::fs::read_dir("./migrations").unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_name().unwrap().to_string_lossy().into_owned();
+            if name.as_str() < "0042" {
+                std::fs::copy(&path, earlier.join(&name)).unwrap();
+            }
+        }
+        let pool = setup_pool().await;
+        run_with(&pool, &Migrator::new(earlier.as_path()).await.unwrap())
+            .await
+            .unwrap();
+        std::fs::remove_dir_all(&earlier).unwrap();
+        let now = chrono::Utc::now();
+        sqlx::query(
+            "INSERT INTO tracked_repositories (id, repository_name, repository_url, chat_id, created_at, updated_at) \
+             VALUES ('kept', 'kept', 'https://github.com/owner/kept', 1, ?1, ?1)",
+        )
+        .bind(now)
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO tracked_repository_releases (tracked_repository_id, tag_name, first_seen_at) \
+             VALUES ('kept', 'v1', ?1)",
+        )
+        .bind(now)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        run(&pool).await.expect("migrations should run");
+
+        let tag: String = sqlx::query_scalar(
+            "SELECT tag_name FROM tracked_repository_releases WHERE tracked_repository_id = 'kept'",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(tag, "v1");
+        sqlx::query(
+            "INSERT INTO tracked_repositories (id, repository_name, repository_url, chat_id, bot_id, created_at, updated_at) \
+             VALUES ('other', 'kept', 'https://github.com/owner/kept', 1, 'staging', ?1, ?1)",
+        )
+        .bind(now)
+        .execute(&pool)
+        .await
+        .expect("another bot may track the same URL");
+    }
+}
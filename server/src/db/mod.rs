@@ -0,0 +1,191 @@
+mod backup;
+mod migrations;
+mod retry;
+mod schema;
+
+pub(crate) use retry::retry_on_busy;
+
+use crate::configuration;
+use sqlx::sqlite::SqlitePool;
+use std::fs::File;
+use std::path::Path;
+
+/// Whether a repository error is a write SQLite rejected for breaking a UNIQUE constraint.
+pub(crate) fn is_unique_violation(err: &(dyn std::error::Error + Send + Sync + 'static)) -> bool {
+    err.downcast_ref::<sqlx::Error>()
+        .and_then(|e| e.as_database_error())
+        .is_some_and(|e| e.is_unique_violation())
+}
+
+pub async fn initialize_db(
+    config: configuration::Configuration,
+) -> Result<SqlitePool, Box<dyn std::error::Error>> {
+    log::debug!("Initializing database with path {}", config.database_path);
+    retry::set_busy_retries(config.sqlite_busy_retries);
+    open(&config.database_path, config.migration_backup).await
+}
+
+/// Opens and migrates the database at `database_path`. With `migration_backup`, it is
+/// first copied to a `.bak` snapshot, which replaces it when a migration fails to apply
+/// before migrating once more.
+async fn open(
+    database_path: &str,
+    migration_backup: bool,
+) -> Result<SqlitePool, Box<dyn std::error::Error>> {
+    let db_url = format!("sqlite://{}", database_path);
+
+    // Check db file exists, create it if it doesn't
+    if !Path::new(database_path).exists() {
+        log::debug!("Database file does not exist, creating it");
+        File::create(database_path)?;
+    }
+
+    let mut pool = SqlitePool::connect(&db_url).await?;
+    let backup = backup::backup_path(database_path);
+    // Only a snapshot taken now is known to hold this database just before migrating
+    let snapshot_taken = migration_backup
+        && backup::snapshot(&pool, &backup).await.unwrap_or_else(|e| {
+            log::warn!("Failed to snapshot the database before migrating: {}", e);
+            false
+        });
+
+    log::debug!("Running migrations");
+    if let Err(e) = migrations::run(&pool).await {
+        log::error!("{}", e);
+        let restorable = e
+            .downcast_ref::<migrations::MigrationFailure>()
+            .is_some_and(|f| f.restorable);
+        if !snapshot_taken || !restorable {
+            return Err(e);
+        }
+        log::warn!(
+            "Restoring the database from {} and running the migrations once more",
+            backup
+        );
+        pool.close().await;
+        backup::restore(&backup, database_path)?;
+        pool = SqlitePool::connect(&db_url).await?;
+        migrations::run(&pool).await?;
+    }
+    log::debug!("Migrations run successfully");
+    schema::validate(&pool).await?;
+
+    log::debug!("Database initialized");
+
+    Ok(pool)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    /// A database migrated from a snapshot holding a `marker` row, with a row added after
+    /// migrating, then left with its newest migration marked as interrupted.
+    async fn interrupted_database() -> String {
+        let path = std::env::temp_dir()
+            .join(format!("migrate-{}.db", Uuid::new_v4()))
+            .to_string_lossy()
+            .into_owned();
+        File::create(&path).unwrap();
+        let pool = SqlitePool::connect(&format!("sqlite://{path}"))
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE marker (x INTEGER)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO marker (x) VALUES (1)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        pool.close().await;
+
+        let pool = open(&path, true).await.expect("first start migrates");
+        assert!(Path::new(&backup::backup_path(&path)).exists());
+        sqlx::query("INSERT INTO marker (x) VALUES (2)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "UPDATE _sqlx_migrations SET success = 0 \
+             WHERE version = (SELECT MAX(version) FROM _sqlx_migrations)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        pool.close().await;
+        path
+    }
+
+    fn remove(path: &str) {
+        for file in [path.to_string(), backup::backup_path(path)] {
+            let _ = std::fs::remove_file(file);
+        }
+    }
+
+    async fn markers(path: &str) -> Vec<i64> {
+        let pool = SqlitePool::connect(&format!("sqlite://{path}"))
+            .await
+            .unwrap();
+        let markers = sqlx::query_scalar("SELECT x FROM marker")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        pool.close().await;
+        markers
+    }
+
+    #[tokio::test]
+    async fn migration_left_failed_by_an_earlier_start_is_not_restored_over() {
+        let path = interrupted_database().await;
+
+        let err = open(&path, true)
+            .await
+            .expect_err("the migration stays failed");
+
+        assert!(err.to_string().contains("partially applied"));
+        assert_eq!(markers(&path).await, [1, 2]);
+        remove(&path);
+    }
+
+    #[tokio::test]
+    async fn database_from_another_build_is_not_restored_over() {
+        let path = interrupted_database().await;
+        let pool = SqlitePool::connect(&format!("sqlite://{path}"))
+            .await
+            .unwrap();
+        sqlx::query("UPDATE _sqlx_migrations SET success = 1")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time) \
+             VALUES (9999, 'from the future', 1, X'00', 0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        pool.close().await;
+
+        let err = open(&path, true)
+            .await
+            .expect_err("the newer database is left alone");
+
+        assert!(err.to_string().contains("not included in this build"));
+        assert_eq!(markers(&path).await, [1, 2]);
+        remove(&path);
+    }
+
+    #[tokio::test]
+    async fn failed_migration_is_reported_without_migration_backup() {
+        let path = interrupted_database().await;
+
+        let err = open(&path, false)
+            .await
+            .expect_err("the migration stays failed");
+
+        assert!(err.to_string().contains("partially applied"));
+        remove(&path);
+    }
+}
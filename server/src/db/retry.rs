@@ -0,0 +1,133 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+static BUSY_RETRIES: AtomicU32 = AtomicU32::new(3);
+
+/// Pause before the first retry; each further retry waits one step longer.
+const RETRY_STEP: Duration = Duration::from_millis(50);
+
+/// Sets how often a write that found the database busy is retried (`SQLITE_BUSY_RETRIES`).
+pub fn set_busy_retries(retries: u32) {
+    BUSY_RETRIES.store(retries, Ordering::Relaxed);
+}
+
+/// Whether `err` is SQLite reporting the database as busy or locked by another writer.
+fn is_busy(err: &sqlx::Error) -> bool {
+    let Some(code) = err.as_database_error().and_then(|e| e.code()) else {
+        return false;
+    };
+    // Extended result codes keep the primary code in their low byte
+    let primary = code.parse::<i32>().map(|c| c & 0xff);
+    matches!(primary, Ok(5 | 6))
+}
+
+/// Runs the write `op`, running it again while SQLite reports the database as busy,
+/// up to the configured number of retries.
+pub(crate) async fn retry_on_busy<T, F, Fut>(mut op: F) -> Result<T, sqlx::Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, sqlx::Error>>,
+{
+    let retries = BUSY_RETRIES.load(Ordering::Relaxed);
+    let mut attempt = 0;
+    loop {
+        match op().await {
+            Err(e) if attempt < retries && is_busy(&e) => {
+                attempt += 1;
+                log::debug!("Database busy, retrying write ({attempt}/{retries})");
+                tokio::time::sleep(RETRY_STEP * attempt).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracked_repositories::repository::{
+        SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+    };
+    use crate::tracked_repositories::{RepositoryUrl, TrackedRelease};
+    use chrono::Utc;
+    use sqlx::sqlite::{SqliteConnectOptions, SqlitePool, SqlitePoolOptions};
+    use uuid::Uuid;
+
+    /// A pool on `path` that reports a locked database right away instead of waiting.
+    async fn impatient_pool(path: &std::path::Path) -> SqlitePool {
+        let options = SqliteConnectOptions::new()
+            .filename(path)
+            .create_if_missing(true)
+            .busy_timeout(Duration::ZERO);
+        SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(options)
+            .await
+            .expect("failed to open sqlite database")
+    }
+
+    /// Sets the retry count for one test and puts the previous count back when dropped,
+    /// so other tests keep seeing the default.
+    struct BusyRetries(u32);
+
+    impl BusyRetries {
+        fn set(retries: u32) -> Self {
+            Self(BUSY_RETRIES.swap(retries, Ordering::Relaxed))
+        }
+    }
+
+    impl Drop for BusyRetries {
+        fn drop(&mut self) {
+            set_busy_retries(self.0);
+        }
+    }
+
+    fn tracked(name: &str) -> TrackedRelease {
+        TrackedRelease {
+            id: Uuid::now_v7(),
+            repository_name: name.to_string(),
+            repository_url: RepositoryUrl::new(format!("https://github.com/owner/{name}")).unwrap(),
+            chat_id: 1,
+            bot_id: String::new(),
+            default_branch: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn racing_saves_both_succeed() {
+        let _retries = BusyRetries::set(10);
+        let path = std::env::temp_dir().join(format!("busy-{}.db", Uuid::new_v4()));
+        let first = impatient_pool(&path).await;
+        let second = impatient_pool(&path).await;
+        sqlx::migrate!("./migrations").run(&first).await.unwrap();
+
+        // Hold the write lock on the first connection while the second one saves
+        let mut lock = first.acquire().await.unwrap();
+        sqlx::query("BEGIN IMMEDIATE")
+            .execute(&mut *lock)
+            .await
+            .unwrap();
+        let racing = tokio::spawn(async move {
+            let mut tr = tracked("second");
+            SqliteTrackedRepositoriesRepository::new(second)
+                .save(&mut tr)
+                .await
+                .map_err(|e| e.to_string())
+        });
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        sqlx::query("COMMIT").execute(&mut *lock).await.unwrap();
+        drop(lock);
+
+        let mut tr = tracked("first");
+        let repository = SqliteTrackedRepositoriesRepository::new(first.clone());
+        repository.save(&mut tr).await.unwrap();
+        racing.await.unwrap().unwrap();
+
+        assert_eq!(repository.find_all().await.unwrap().len(), 2);
+        first.close().await;
+        let _ = std::fs::remove_file(&path);
+    }
+}
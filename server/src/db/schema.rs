@@ -0,0 +1,99 @@
+use sqlx::sqlite::SqlitePool;
+
+/// Tables the bot reads and writes, with the columns it cannot work without. The
+/// migrations create them with `IF NOT EXISTS`, so a database from an incompatible fork
+/// or an old version can come through them with tables of the same name but a different
+/// shape.
+const EXPECTED: &[(&str, &[&str])] = &[
+    (
+        "tracked_repositories",
+        &[
+            "id",
+            "repository_name",
+            "repository_url",
+            "chat_id",
+            "bot_id",
+        ],
+    ),
+    (
+        "tracked_repository_releases",
+        &["tracked_repository_id", "tag_name", "first_seen_at"],
+    ),
+    ("tracked_repository_settings", &["tracked_repository_id"]),
+    ("tracked_repository_mirrors", &["tracked_repository_id"]),
+    ("chat_settings", &["chat_id", "message_format"]),
+    ("tag_watches", &["chat_id"]),
+    ("notifications", &["chat_id"]),
+];
+
+/// Checks that every expected table and key column exists, describing everything that is
+/// missing at once.
+pub(crate) async fn validate(pool: &SqlitePool) -> Result<(), String> {
+    let mut missing = Vec::new();
+    for (table, columns) in EXPECTED {
+        let existing: Vec<String> = sqlx::query_scalar("SELECT name FROM pragma_table_info(?1)")
+            .bind(table)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| format!("Failed to read the schema of {table}: {e}"))?;
+        if existing.is_empty() {
+            missing.push(format!("table {table}"));
+            continue;
+        }
+        for column in *columns {
+            if !existing.iter().any(|c| c == column) {
+                missing.push(format!("column {table}.{column}"));
+            }
+        }
+    }
+
+    if missing.is_empty() {
+        return Ok(());
+    }
+    Err(format!(
+        "The database does not match the schema this bot expects (missing {}); it was \
+         probably created by an incompatible fork or an old version of the bot",
+        missing.join(", ")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_pool() -> SqlitePool {
+        SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool")
+    }
+
+    #[tokio::test]
+    async fn migrated_database_is_valid() {
+        let pool = setup_pool().await;
+        crate::db::migrations::run(&pool).await.unwrap();
+
+        assert_eq!(validate(&pool).await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn incompatible_schema_names_what_is_missing() {
+        let pool = setup_pool().await;
+        sqlx::query(
+            "CREATE TABLE tracked_repositories (id TEXT PRIMARY KEY, repository_name TEXT, \
+             repository_url TEXT, bot_id TEXT)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let err = validate(&pool)
+            .await
+            .expect_err("schema should be rejected");
+        assert!(err.contains("column tracked_repositories.chat_id"), "{err}");
+        assert!(err.contains("table chat_settings"), "{err}");
+        assert!(err.contains("incompatible fork"), "{err}");
+    }
+}
@@ -0,0 +1,210 @@
+use crate::github::GithubError;
+use crate::github::request::get;
+
+/// Prefix of the `<id>` GitHub gives each entry of a releases feed; the repository id
+/// and the raw tag name follow it.
+const ENTRY_ID_PREFIX: &str = "tag:github.com,2008:Repository/";
+
+pub(crate) fn github_web_base() -> String {
+    std::env::var("GITHUB_WEB_BASE").unwrap_or_else(|_| "https://github.com".to_string())
+}
+
+/// Reads the tag of the newest release from a `releases.atom` document. GitHub lists
+/// entries newest first, so only the first `<entry>` is looked at. The tag comes from the
+/// entry's `<id>`, or from its `releases/tag/` link when the id has an unexpected shape.
+pub(crate) fn parse_latest_atom_tag(xml: &str) -> Option<String> {
+    let start = xml.find("<entry")?;
+    let entry = &xml[start..];
+    let entry = &entry[..entry.find("</entry>").unwrap_or(entry.len())];
+
+    let from_id = element_text(entry, "id").and_then(|id| {
+        let rest = id.strip_prefix(ENTRY_ID_PREFIX)?;
+        let (repository_id, tag) = rest.split_once('/')?;
+        repository_id
+            .chars()
+            .all(|c| c.is_ascii_digit())
+            .then(|| tag.to_string())
+    });
+    let tag = from_id.or_else(|| {
+        let href = link_href(entry)?;
+        let (_, tag) = href.split_once("/releases/tag/")?;
+        Some(tag.to_string())
+    })?;
+
+    let tag = unescape(tag.trim());
+    (!tag.is_empty()).then_some(tag)
+}
+
+/// The text between `<name>` and `</name>` for the first such element in `xml`.
+fn element_text<'a>(xml: &'a str, name: &str) -> Option<&'a str> {
+    let open = format!("<{name}>");
+    let close = format!("</{name}>");
+    let start = xml.find(&open)? + open.len();
+    let len = xml[start..].find(&close)?;
+    Some(&xml[start..start + len])
+}
+
+/// The `href` of the first `<link>` element in `xml`.
+fn link_href(xml: &str) -> Option<&str> {
+    let start = xml.find("<link")?;
+    let tag = &xml[start..start + xml[start..].find('>')?];
+    let value = &tag[tag.find("href=")? + "href=".len()..];
+    let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let value = &value[1..];
+    Some(&value[..value.find(quote)?])
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Reads the newest release tag from the public `releases.atom` feed at `web_base`.
+/// The feed is served by the website rather than the API, so it keeps working while the
+/// API rate limit is exhausted. It carries no token and only sees public repositories.
+pub(crate) async fn fetch_latest_atom_tag_with_base(
+    client: &reqwest::Client,
+    owner: &str,
+    repo: &str,
+    web_base: &str,
+) -> Result<Option<String>, GithubError> {
+    let url = format!("{web_base}/{owner}/{repo}/releases.atom");
+    let resp = get(client, &url, None).await?;
+    if !resp.status().is_success() {
+        return Err(GithubError::Status {
+            status: resp.status().as_u16(),
+        });
+    }
+    let body = resp.text().await?;
+    Ok(parse_latest_atom_tag(&body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::{HttpReleaseSource, ReleaseSource};
+    use mockito::Server;
+
+    const SAMPLE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom" xmlns:media="http://search.yahoo.com/mrss/" xml:lang="en-US">
+  <id>tag:github.com,2008:https://github.com/owner/repo/releases</id>
+  <link type="text/html" rel="alternate" href="https://github.com/owner/repo/releases"/>
+  <link type="application/atom+xml" rel="self" href="https://github.com/owner/repo/releases.atom"/>
+  <title>Release notes from repo</title>
+  <updated>2024-05-02T10:00:00Z</updated>
+  <entry>
+    <id>tag:github.com,2008:Repository/123456/v2.1.0</id>
+    <updated>2024-05-02T10:00:00Z</updated>
+    <link rel="alternate" type="text/html" href="https://github.com/owner/repo/releases/tag/v2.1.0"/>
+    <title>v2.1.0</title>
+    <content type="html">&lt;p&gt;Fixes &amp;amp; features&lt;/p&gt;</content>
+    <author><name>octocat</name></author>
+  </entry>
+  <entry>
+    <id>tag:github.com,2008:Repository/123456/v2.0.0</id>
+    <updated>2024-04-01T10:00:00Z</updated>
+    <link rel="alternate" type="text/html" href="https://github.com/owner/repo/releases/tag/v2.0.0"/>
+    <title>v2.0.0</title>
+  </entry>
+</feed>"#;
+
+    #[test]
+    fn parses_the_newest_entry_of_a_releases_feed() {
+        assert_eq!(parse_latest_atom_tag(SAMPLE), Some("v2.1.0".to_string()));
+    }
+
+    #[test]
+    fn keeps_slashes_and_unescapes_entities_in_the_tag() {
+        let xml = SAMPLE.replace(
+            "Repository/123456/v2.1.0",
+            "Repository/123456/cli/v1&amp;rc",
+        );
+        assert_eq!(parse_latest_atom_tag(&xml), Some("cli/v1&rc".to_string()));
+    }
+
+    #[test]
+    fn falls_back_to_the_entry_link_when_the_id_is_unexpected() {
+        let xml = SAMPLE.replace("tag:github.com,2008:Repository/123456/v2.1.0", "urn:x");
+        assert_eq!(parse_latest_atom_tag(&xml), Some("v2.1.0".to_string()));
+    }
+
+    #[test]
+    fn a_feed_without_entries_has_no_tag() {
+        let xml = "<feed><id>tag:github.com,2008:https://github.com/o/r/releases</id></feed>";
+        assert_eq!(parse_latest_atom_tag(xml), None);
+    }
+
+    #[tokio::test]
+    async fn fetches_the_feed_from_the_web_base() {
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/owner/repo/releases.atom")
+            .with_status(200)
+            .with_header("content-type", "application/atom+xml")
+            .with_body(SAMPLE)
+            .create_async()
+            .await;
+
+        let tag = fetch_latest_atom_tag_with_base(
+            &reqwest::Client::new(),
+            "owner",
+            "repo",
+            &server.url(),
+        )
+        .await
+        .expect("ok");
+        assert_eq!(tag, Some("v2.1.0".to_string()));
+    }
+
+    #[tokio::test]
+    async fn rate_limited_source_marks_the_release_read_from_the_feed() {
+        let mut server = Server::new_async().await;
+        let _api = server
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .with_status(403)
+            .create_async()
+            .await;
+        let _feed = server
+            .mock("GET", "/owner/repo/releases.atom")
+            .with_status(200)
+            .with_body(SAMPLE)
+            .create_async()
+            .await;
+
+        let source = HttpReleaseSource::new(reqwest::Client::new(), None, server.url())
+            .with_web_base(server.url());
+        let release = source
+            .latest_release("owner", "repo")
+            .await
+            .expect("ok")
+            .expect("release");
+        assert_eq!(release.tag_name, "v2.1.0");
+        assert_eq!(release.body, None);
+        assert!(release.details.from_feed);
+    }
+
+    #[tokio::test]
+    async fn other_api_errors_do_not_read_the_feed() {
+        let mut server = Server::new_async().await;
+        let _api = server
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .with_status(500)
+            .create_async()
+            .await;
+        let feed = server
+            .mock("GET", "/owner/repo/releases.atom")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let source = HttpReleaseSource::new(reqwest::Client::new(), None, server.url())
+            .with_web_base(server.url());
+        let result = source.latest_release("owner", "repo").await;
+        assert!(matches!(result, Err(GithubError::Status { status: 500 })));
+        feed.assert_async().await;
+    }
+}
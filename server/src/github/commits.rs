@@ -0,0 +1,123 @@
+use reqwest::StatusCode;
+use serde::de::IgnoredAny;
+use urlencoding::encode;
+
+use crate::github::GithubError;
+use crate::github::request::{get, json, link_url};
+
+/// The `page` parameter of a pagination URL.
+fn page_number(url: &str) -> Option<u64> {
+    reqwest::Url::parse(url)
+        .ok()?
+        .query_pairs()
+        .find(|(key, _)| key == "page")?
+        .1
+        .parse()
+        .ok()
+}
+
+/// Counts the commits on `branch`, or on the default branch when it is `None`. One commit
+/// is listed per page, so the page number of the `rel="last"` link is the count. An
+/// empty repository has no commits; a missing one gives `None`.
+pub(crate) async fn fetch_commit_count_with_base(
+    client: &reqwest::Client,
+    owner: &str,
+    repo: &str,
+    branch: Option<&str>,
+    token: Option<&str>,
+    base: &str,
+) -> Result<Option<u64>, GithubError> {
+    let mut url = format!("{}/repos/{}/{}/commits?per_page=1", base, owner, repo);
+    if let Some(branch) = branch {
+        url.push_str(&format!("&sha={}", encode(branch)));
+    }
+    let resp = get(client, &url, token).await?;
+    match resp.status() {
+        s if s.is_success() => {
+            if let Some(count) = link_url(&resp, "last").as_deref().and_then(page_number) {
+                return Ok(Some(count));
+            }
+            let commits: Vec<IgnoredAny> = json(resp).await?;
+            Ok(Some(commits.len() as u64))
+        }
+        StatusCode::CONFLICT => Ok(Some(0)),
+        StatusCode::NOT_FOUND => Ok(None),
+        s => Err(GithubError::Status { status: s.as_u16() }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn counts_commits_from_the_last_page_link() {
+        let mut server = Server::new_async().await;
+        let last = format!(
+            "<{}/repositories/1/commits?per_page=1&sha=main&page=1234>; rel=\"last\"",
+            server.url()
+        );
+        let link = format!(
+            "<{}/repositories/1/commits?per_page=1&sha=main&page=2>; rel=\"next\", {last}",
+            server.url()
+        );
+        let _m = server
+            .mock("GET", "/repos/owner/repo/commits")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("per_page".into(), "1".into()),
+                Matcher::UrlEncoded("sha".into(), "main".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("link", &link)
+            .with_body(r#"[{"sha": "abc"}]"#)
+            .create_async()
+            .await;
+
+        let client = reqwest::Client::new();
+        let count = fetch_commit_count_with_base(
+            &client,
+            "owner",
+            "repo",
+            Some("main"),
+            None,
+            &server.url(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(count, Some(1234));
+    }
+
+    #[tokio::test]
+    async fn single_page_and_empty_repositories() {
+        let mut server = Server::new_async().await;
+        let _one = server
+            .mock("GET", "/repos/owner/one/commits?per_page=1")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"sha": "abc"}]"#)
+            .create_async()
+            .await;
+        let _empty = server
+            .mock("GET", "/repos/owner/empty/commits?per_page=1")
+            .with_status(409)
+            .create_async()
+            .await;
+        let _missing = server
+            .mock("GET", "/repos/owner/missing/commits?per_page=1")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let client = reqwest::Client::new();
+        let count = async |repo: &str| {
+            fetch_commit_count_with_base(&client, "owner", repo, None, None, &server.url())
+                .await
+                .unwrap()
+        };
+        assert_eq!(count("one").await, Some(1));
+        assert_eq!(count("empty").await, Some(0));
+        assert_eq!(count("missing").await, None);
+    }
+}
@@ -0,0 +1,235 @@
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::json;
+
+use crate::github::GithubError;
+use crate::github::request::{json, post_json};
+
+const CATEGORIES_QUERY: &str = "query($owner: String!, $name: String!) { \
+    repository(owner: $owner, name: $name) { \
+        discussionCategories(first: 100) { nodes { id name slug } } } }";
+
+const LATEST_DISCUSSION_QUERY: &str = "query($owner: String!, $name: String!, $category: ID!) { \
+    repository(owner: $owner, name: $name) { \
+        discussions(first: 1, categoryId: $category, \
+            orderBy: { field: CREATED_AT, direction: DESC }) { nodes { number title url } } } }";
+
+#[derive(Deserialize)]
+struct GraphqlResponse<T> {
+    data: Option<RepositoryData<T>>,
+    #[serde(default)]
+    errors: Vec<GraphqlError>,
+}
+
+#[derive(Deserialize)]
+struct GraphqlError {
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct RepositoryData<T> {
+    repository: Option<T>,
+}
+
+#[derive(Deserialize)]
+struct Nodes<T> {
+    nodes: Vec<T>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CategoriesRepository {
+    discussion_categories: Nodes<Category>,
+}
+
+#[derive(Deserialize)]
+struct Category {
+    id: String,
+    name: String,
+    slug: String,
+}
+
+#[derive(Deserialize)]
+struct DiscussionsRepository {
+    discussions: Nodes<Discussion>,
+}
+
+/// A GitHub Discussion, as announced to chats following a discussion category.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Discussion {
+    pub number: i64,
+    pub title: String,
+    pub url: String,
+}
+
+/// Runs a query against `repository(owner, name)`. A repository that does not exist or
+/// is not visible yields `None`.
+async fn query_repository<T: DeserializeOwned>(
+    client: &reqwest::Client,
+    base: &str,
+    token: Option<&str>,
+    query: &str,
+    variables: serde_json::Value,
+) -> Result<Option<T>, GithubError> {
+    let url = format!("{}/graphql", base);
+    let body = json!({ "query": query, "variables": variables });
+    let resp = post_json(client, &url, token, &body).await?;
+    if !resp.status().is_success() {
+        return Err(GithubError::Status {
+            status: resp.status().as_u16(),
+        });
+    }
+
+    let response: GraphqlResponse<T> = json(resp).await?;
+    match response.data {
+        Some(data) => Ok(data.repository),
+        None => {
+            let messages: Vec<String> = response.errors.into_iter().map(|e| e.message).collect();
+            Err(GithubError::Graphql(messages.join("; ")))
+        }
+    }
+}
+
+/// Returns the newest discussion of the category named `category` (by name or slug,
+/// ignoring case). Discussions are only reachable through GraphQL, which needs a token.
+pub(crate) async fn fetch_latest_discussion_with_base(
+    client: &reqwest::Client,
+    owner: &str,
+    repo: &str,
+    category: &str,
+    token: Option<&str>,
+    base: &str,
+) -> Result<Option<Discussion>, GithubError> {
+    let variables = json!({ "owner": owner, "name": repo });
+    let Some(categories) =
+        query_repository::<CategoriesRepository>(client, base, token, CATEGORIES_QUERY, variables)
+            .await?
+    else {
+        return Ok(None);
+    };
+    let Some(category_id) = categories
+        .discussion_categories
+        .nodes
+        .into_iter()
+        .find(|c| c.name.eq_ignore_ascii_case(category) || c.slug.eq_ignore_ascii_case(category))
+        .map(|c| c.id)
+    else {
+        log::warn!("{owner}/{repo} has no discussion category named {category}");
+        return Ok(None);
+    };
+
+    let variables = json!({ "owner": owner, "name": repo, "category": category_id });
+    let discussions = query_repository::<DiscussionsRepository>(
+        client,
+        base,
+        token,
+        LATEST_DISCUSSION_QUERY,
+        variables,
+    )
+    .await?;
+    Ok(discussions.and_then(|d| d.discussions.nodes.into_iter().next()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+
+    async fn mock_query(server: &mut Server, query_fragment: &str, body: serde_json::Value) {
+        server
+            .mock("POST", "/graphql")
+            .match_body(Matcher::Regex(query_fragment.to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body.to_string())
+            .create_async()
+            .await;
+    }
+
+    #[tokio::test]
+    async fn finds_latest_discussion_in_category() {
+        let mut server = Server::new_async().await;
+        mock_query(
+            &mut server,
+            "discussionCategories",
+            json!({"data": {"repository": {"discussionCategories": {"nodes": [
+                {"id": "C1", "name": "General", "slug": "general"},
+                {"id": "C2", "name": "Announcements", "slug": "announcements"}
+            ]}}}}),
+        )
+        .await;
+        mock_query(
+            &mut server,
+            r#""category":"C2""#,
+            json!({"data": {"repository": {"discussions": {"nodes": [
+                {"number": 7, "title": "v2 is out", "url": "https://github.com/o/r/discussions/7"}
+            ]}}}}),
+        )
+        .await;
+
+        let discussion = fetch_latest_discussion_with_base(
+            &reqwest::Client::new(),
+            "o",
+            "r",
+            "announcements",
+            Some("token"),
+            &server.url(),
+        )
+        .await
+        .expect("ok");
+
+        assert_eq!(
+            discussion,
+            Some(Discussion {
+                number: 7,
+                title: "v2 is out".to_string(),
+                url: "https://github.com/o/r/discussions/7".to_string(),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_category_yields_none() {
+        let mut server = Server::new_async().await;
+        mock_query(
+            &mut server,
+            "discussionCategories",
+            json!({"data": {"repository": {"discussionCategories": {"nodes": []}}}}),
+        )
+        .await;
+
+        let discussion = fetch_latest_discussion_with_base(
+            &reqwest::Client::new(),
+            "o",
+            "r",
+            "Announcements",
+            Some("token"),
+            &server.url(),
+        )
+        .await
+        .expect("ok");
+        assert_eq!(discussion, None);
+    }
+
+    #[tokio::test]
+    async fn errors_without_data_surface() {
+        let mut server = Server::new_async().await;
+        mock_query(
+            &mut server,
+            "discussionCategories",
+            json!({"errors": [{"message": "Bad credentials"}]}),
+        )
+        .await;
+
+        let res = fetch_latest_discussion_with_base(
+            &reqwest::Client::new(),
+            "o",
+            "r",
+            "Announcements",
+            None,
+            &server.url(),
+        )
+        .await;
+        assert!(matches!(res, Err(GithubError::Graphql(m)) if m == "Bad credentials"));
+    }
+}
@@ -0,0 +1,76 @@
+use std::fmt;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub enum GithubError {
+    /// The request could not be sent or the response could not be read.
+    Request(reqwest::Error),
+    /// GitHub answered with a non-success status.
+    Status { status: u16 },
+    /// The configured token was rejected and the repository is not reachable without it.
+    TokenRejected { status: u16 },
+    /// GitHub's secondary rate limit was hit; no request should be made for `retry_after`.
+    SecondaryRateLimited { retry_after: Duration },
+    /// A successful response carried a body that is not the expected JSON.
+    Decode(serde_json::Error),
+    /// The GraphQL API answered with errors and no data.
+    Graphql(String),
+    /// The source cannot answer this kind of request at all.
+    Unsupported,
+}
+
+impl fmt::Display for GithubError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GithubError::Request(e) => write!(f, "GitHub request failed: {e}"),
+            GithubError::Status { status } => {
+                write!(f, "GitHub API returned non-success status {status}")
+            }
+            GithubError::TokenRejected { status } => write!(
+                f,
+                "GitHub token was rejected and the repository is not publicly accessible (status {status})"
+            ),
+            GithubError::SecondaryRateLimited { retry_after } => write!(
+                f,
+                "GitHub secondary rate limit hit, retry after {}s",
+                retry_after.as_secs()
+            ),
+            GithubError::Decode(e) => write!(f, "GitHub response could not be decoded: {e}"),
+            GithubError::Graphql(message) => write!(f, "GitHub GraphQL query failed: {message}"),
+            GithubError::Unsupported => write!(f, "The release source does not support this"),
+        }
+    }
+}
+
+impl GithubError {
+    /// The API host could not be reached at all: the connection was refused or the name
+    /// did not resolve. Any other request to the same base would fail the same way.
+    pub fn is_unreachable(&self) -> bool {
+        matches!(self, GithubError::Request(e) if e.is_connect())
+    }
+
+    /// GitHub refused the request because a primary (403 or 429) or secondary rate limit
+    /// is exhausted.
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(
+            self,
+            GithubError::SecondaryRateLimited { .. } | GithubError::Status { status: 403 | 429 }
+        )
+    }
+}
+
+impl std::error::Error for GithubError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GithubError::Request(e) => Some(e),
+            GithubError::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for GithubError {
+    fn from(e: reqwest::Error) -> Self {
+        GithubError::Request(e)
+    }
+}
@@ -0,0 +1,90 @@
+use serde::Deserialize;
+
+use crate::github::Release;
+use crate::github::releases::ReleaseResponse;
+
+#[derive(Deserialize, Debug)]
+struct EventRelease {
+    #[serde(flatten)]
+    release: ReleaseResponse,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    prerelease: bool,
+}
+
+#[derive(Deserialize, Debug)]
+struct EventRepository {
+    full_name: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct ReleaseEvent {
+    action: String,
+    release: EventRelease,
+    repository: EventRepository,
+}
+
+/// A release GitHub announced through a webhook, for the repository `owner/repo`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PushedRelease {
+    pub full_name: String,
+    pub release: Release,
+}
+
+/// Reads the payload of a `release` webhook event. Only newly published stable releases
+/// are returned, matching what `/releases/latest` would report; drafts, prereleases and
+/// other actions give `None`.
+pub(crate) fn parse_release_event(body: &[u8]) -> Result<Option<PushedRelease>, serde_json::Error> {
+    let event: ReleaseEvent = serde_json::from_slice(body)?;
+    if event.action != "published" || event.release.draft || event.release.prerelease {
+        return Ok(None);
+    }
+    Ok(Some(PushedRelease {
+        full_name: event.repository.full_name,
+        release: event.release.release.into(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(action: &str, prerelease: bool) -> Vec<u8> {
+        serde_json::json!({
+            "action": action,
+            "release": {
+                "tag_name": "v1.2.0",
+                "body": "notes",
+                "html_url": "https://github.com/owner/repo/releases/tag/v1.2.0",
+                "author": {"login": "octocat"},
+                "draft": false,
+                "prerelease": prerelease
+            },
+            "repository": {"full_name": "owner/repo"}
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    #[test]
+    fn reads_published_releases() {
+        let pushed = parse_release_event(&event("published", false))
+            .unwrap()
+            .unwrap();
+        assert_eq!(pushed.full_name, "owner/repo");
+        assert_eq!(pushed.release.tag_name, "v1.2.0");
+        assert_eq!(pushed.release.body.as_deref(), Some("notes"));
+        assert_eq!(pushed.release.details.author.as_deref(), Some("octocat"));
+    }
+
+    #[test]
+    fn ignores_other_actions_and_prereleases() {
+        assert_eq!(parse_release_event(&event("edited", false)).unwrap(), None);
+        assert_eq!(
+            parse_release_event(&event("published", true)).unwrap(),
+            None
+        );
+        assert!(parse_release_event(b"{}").is_err());
+    }
+}
@@ -0,0 +1,36 @@
+mod atom;
+mod commits;
+mod discussions;
+mod error;
+#[cfg(feature = "rest-api")]
+mod events;
+mod rate_limit;
+mod release_list;
+mod releases;
+mod repository;
+mod request;
+mod source;
+mod tags;
+
+pub(crate) use atom::{fetch_latest_atom_tag_with_base, github_web_base};
+pub(crate) use commits::fetch_commit_count_with_base;
+pub(crate) use discussions::{Discussion, fetch_latest_discussion_with_base};
+pub use error::GithubError;
+#[cfg(feature = "rest-api")]
+pub(crate) use events::parse_release_event;
+pub use rate_limit::{RateLimitStatus, fetch_rate_limit};
+pub(crate) use release_list::{
+    fetch_newest_release_with_base, fetch_release_page_with_base, fetch_releases_with_base,
+};
+pub use releases::{Release, ReleaseDetails};
+pub(crate) use releases::{fetch_latest_release_tag_with_base, fetch_latest_release_with_base};
+pub(crate) use repository::fetch_repository_with_base;
+pub(crate) use request::{request_timeout, with_request_timeout};
+pub use request::{set_request_logging, set_request_timeout};
+pub use source::{HttpReleaseSource, ReleaseSource};
+pub use tags::tag_exists;
+pub(crate) use tags::{fetch_latest_tag_with_base, fetch_tags_with_base, tag_exists_with_base};
+
+pub(crate) fn github_api_base() -> String {
+    std::env::var("GITHUB_API_BASE").unwrap_or_else(|_| "https://api.github.com".to_string())
+}
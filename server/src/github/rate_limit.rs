@@ -0,0 +1,92 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::github::request::{get, json};
+use crate::github::{GithubError, github_api_base};
+
+#[derive(Deserialize)]
+struct RateLimitResponse {
+    resources: RateLimitResources,
+}
+
+#[derive(Deserialize)]
+struct RateLimitResources {
+    core: RateLimitCore,
+}
+
+#[derive(Deserialize)]
+struct RateLimitCore {
+    limit: u64,
+    remaining: u64,
+    reset: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    pub limit: u64,
+    pub remaining: u64,
+    pub reset_at: DateTime<Utc>,
+}
+
+pub(crate) async fn fetch_rate_limit_with_base(
+    client: &reqwest::Client,
+    token: Option<&str>,
+    base: &str,
+) -> Result<RateLimitStatus, GithubError> {
+    let url = format!("{}/rate_limit", base);
+    let resp = get(client, &url, token).await?;
+
+    if !resp.status().is_success() {
+        return Err(GithubError::Status {
+            status: resp.status().as_u16(),
+        });
+    }
+
+    let body: RateLimitResponse = json(resp).await?;
+    let core = body.resources.core;
+    Ok(RateLimitStatus {
+        limit: core.limit,
+        remaining: core.remaining,
+        reset_at: DateTime::from_timestamp(core.reset, 0).unwrap_or_else(Utc::now),
+    })
+}
+
+pub async fn fetch_rate_limit(
+    client: &reqwest::Client,
+    token: Option<&str>,
+) -> Result<RateLimitStatus, GithubError> {
+    let base = github_api_base();
+    fetch_rate_limit_with_base(client, token, &base).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn parses_core_rate_limit() {
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/rate_limit")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "resources": {"core": {"limit": 5000, "remaining": 4321, "reset": 1700000000, "used": 679}},
+                    "rate": {"limit": 5000, "remaining": 4321, "reset": 1700000000, "used": 679}
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let status = fetch_rate_limit_with_base(&reqwest::Client::new(), None, &server.url())
+            .await
+            .expect("ok");
+
+        assert_eq!(status.limit, 5000);
+        assert_eq!(status.remaining, 4321);
+        assert_eq!(status.reset_at.timestamp(), 1700000000);
+    }
+}
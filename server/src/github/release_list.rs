@@ -0,0 +1,142 @@
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+use crate::github::GithubError;
+use crate::github::releases::{Release, ReleaseResponse};
+use crate::github::request::{get, json};
+
+/// Releases looked at when searching the newest one, most recent first.
+const RELEASES_PER_PAGE: u32 = 30;
+
+/// Releases per page when walking further back through the releases list.
+const RELEASES_PER_FULL_PAGE: u32 = 100;
+
+#[derive(Deserialize, Debug)]
+struct ListedRelease {
+    #[serde(flatten)]
+    release: ReleaseResponse,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    prerelease: bool,
+}
+
+/// The most recent published release of the repository, looked up in the releases list
+/// rather than `releases/latest`, which never reports prereleases. Drafts are skipped.
+pub(crate) async fn fetch_newest_release_with_base(
+    client: &reqwest::Client,
+    owner: &str,
+    repo: &str,
+    token: Option<&str>,
+    base: &str,
+    include_prereleases: bool,
+) -> Result<Option<Release>, GithubError> {
+    Ok(
+        fetch_releases_with_base(client, owner, repo, token, base, include_prereleases)
+            .await?
+            .into_iter()
+            .next(),
+    )
+}
+
+/// The repository's recent published releases, newest first. Drafts are skipped, and so
+/// are prereleases unless `include_prereleases` is set.
+pub(crate) async fn fetch_releases_with_base(
+    client: &reqwest::Client,
+    owner: &str,
+    repo: &str,
+    token: Option<&str>,
+    base: &str,
+    include_prereleases: bool,
+) -> Result<Vec<Release>, GithubError> {
+    let url = format!(
+        "{}/repos/{}/{}/releases?per_page={}",
+        base, owner, repo, RELEASES_PER_PAGE
+    );
+    Ok(fetch_listed(client, &url, token)
+        .await?
+        .into_iter()
+        .filter(|r| !r.draft && (include_prereleases || !r.prerelease))
+        .map(|r| r.release.into())
+        .collect())
+}
+
+/// Page `page` (counted from 1) of the repository's releases, `RELEASES_PER_FULL_PAGE`
+/// at a time and newest first, without drafts and prereleases. Also tells whether the
+/// page was full, so that there may be another one.
+pub(crate) async fn fetch_release_page_with_base(
+    client: &reqwest::Client,
+    owner: &str,
+    repo: &str,
+    token: Option<&str>,
+    base: &str,
+    page: u32,
+) -> Result<(Vec<Release>, bool), GithubError> {
+    let url = format!(
+        "{}/repos/{}/{}/releases?per_page={}&page={}",
+        base, owner, repo, RELEASES_PER_FULL_PAGE, page
+    );
+    let listed = fetch_listed(client, &url, token).await?;
+    let full = listed.len() >= RELEASES_PER_FULL_PAGE as usize;
+    let releases = listed
+        .into_iter()
+        .filter(|r| !r.draft && !r.prerelease)
+        .map(|r| r.release.into())
+        .collect();
+    Ok((releases, full))
+}
+
+async fn fetch_listed(
+    client: &reqwest::Client,
+    url: &str,
+    token: Option<&str>,
+) -> Result<Vec<ListedRelease>, GithubError> {
+    let resp = get(client, url, token).await?;
+    match resp.status() {
+        s if s.is_success() => json(resp).await,
+        StatusCode::NOT_FOUND => Ok(Vec::new()),
+        s => Err(GithubError::Status { status: s.as_u16() }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+
+    async fn newest(include_prereleases: bool) -> Option<String> {
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/repos/owner/repo/releases?per_page=30")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!([
+                    {"tag_name": "v3.0.0", "draft": true, "prerelease": false},
+                    {"tag_name": "v2.1.0-rc.1", "draft": false, "prerelease": true},
+                    {"tag_name": "v2.0.0", "draft": false, "prerelease": false}
+                ])
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        fetch_newest_release_with_base(
+            &reqwest::Client::new(),
+            "owner",
+            "repo",
+            None,
+            &server.url(),
+            include_prereleases,
+        )
+        .await
+        .expect("ok")
+        .map(|r| r.tag_name)
+    }
+
+    #[tokio::test]
+    async fn skips_drafts_and_optionally_prereleases() {
+        assert_eq!(newest(true).await.as_deref(), Some("v2.1.0-rc.1"));
+        assert_eq!(newest(false).await.as_deref(), Some("v2.0.0"));
+    }
+}
@@ -0,0 +1,416 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::github::GithubError;
+use crate::github::request::{get, json};
+use crate::github::tags::fetch_latest_tag_with_base;
+
+#[derive(Deserialize, Debug)]
+struct AuthorResponse {
+    login: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub(super) struct ReleaseResponse {
+    tag_name: String,
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    html_url: Option<String>,
+    #[serde(default)]
+    published_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    author: Option<AuthorResponse>,
+}
+
+/// What GitHub reports about a release beyond its tag and notes. Tags have none of it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReleaseDetails {
+    pub html_url: Option<String>,
+    pub published_at: Option<DateTime<Utc>>,
+    /// Login of the account that published the release.
+    pub author: Option<String>,
+    /// Whether GitHub listed it as a release; tags found through the tags fallback and
+    /// registry versions are not.
+    pub is_release: bool,
+    /// Read from the `releases.atom` feed while the API was rate limited. The feed's
+    /// newest entry may be a prerelease the API would skip, so tags shaped like one are
+    /// not announced from it.
+    pub from_feed: bool,
+}
+
+/// The newest release of a repository. Tags found through the tags fallback have no body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Release {
+    pub tag_name: String,
+    pub body: Option<String>,
+    pub details: ReleaseDetails,
+}
+
+impl From<ReleaseResponse> for Release {
+    fn from(release: ReleaseResponse) -> Self {
+        Self {
+            tag_name: release.tag_name,
+            body: release.body.filter(|b| !b.trim().is_empty()),
+            details: ReleaseDetails {
+                html_url: release.html_url,
+                published_at: release.published_at,
+                author: release.author.map(|a| a.login),
+                is_release: true,
+                from_feed: false,
+            },
+        }
+    }
+}
+
+pub(crate) async fn fetch_latest_release_with_base(
+    client: &reqwest::Client,
+    owner: &str,
+    repo: &str,
+    token: Option<&str>,
+    base: &str,
+) -> Result<Option<Release>, GithubError> {
+    let release_url = format!("{}/repos/{}/{}/releases/latest", base, owner, repo);
+
+    let resp = get(client, &release_url, token).await?;
+
+    if resp.status().is_success() {
+        let release: ReleaseResponse = json(resp).await?;
+        log::debug!("Latest release for {owner}/{repo} is {release:?}");
+
+        if release.tag_name.is_empty() {
+            log::debug!("Latest release for {owner}/{repo} is empty");
+            return Ok(None);
+        }
+
+        return Ok(Some(release.into()));
+    } else if resp.status().as_u16() == 404 {
+        // Fallback: try tags
+        let tag = match fetch_latest_tag_with_base(client, owner, repo, token, base).await {
+            Err(GithubError::Status { .. }) => None,
+            other => other?,
+        };
+        return Ok(tag.map(|tag_name| Release {
+            tag_name,
+            body: None,
+            details: ReleaseDetails::default(),
+        }));
+    }
+
+    let status = resp.status();
+    let body = resp.text().await.unwrap_or_default();
+    log::warn!(
+        "GitHub releases request failed for {owner}/{repo}: status={} body={}",
+        status,
+        body
+    );
+    Err(GithubError::Status {
+        status: status.as_u16(),
+    })
+}
+
+pub(crate) async fn fetch_latest_release_tag_with_base(
+    client: &reqwest::Client,
+    owner: &str,
+    repo: &str,
+    token: Option<&str>,
+    base: &str,
+) -> Result<Option<String>, GithubError> {
+    let release = fetch_latest_release_with_base(client, owner, repo, token, base).await?;
+    Ok(release.map(|r| r.tag_name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+
+    fn client() -> reqwest::Client {
+        reqwest::Client::new()
+    }
+
+    #[tokio::test]
+    async fn latest_release_success() {
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!({"tag_name":"v1.2.3"}).to_string())
+            .create_async()
+            .await;
+
+        let tag =
+            fetch_latest_release_tag_with_base(&client(), "owner", "repo", None, &server.url())
+                .await
+                .expect("ok");
+
+        assert_eq!(tag, Some("v1.2.3".to_string()));
+    }
+
+    #[tokio::test]
+    async fn latest_release_includes_body() {
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "tag_name": "v1.2.3",
+                    "body": "Fixes",
+                    "html_url": "https://github.com/owner/repo/releases/tag/v1.2.3",
+                    "published_at": "2024-05-01T12:00:00Z",
+                    "author": {"login": "octocat"}
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let release =
+            fetch_latest_release_with_base(&client(), "owner", "repo", None, &server.url())
+                .await
+                .expect("ok")
+                .expect("release");
+
+        assert_eq!(release.tag_name, "v1.2.3");
+        assert_eq!(release.body.as_deref(), Some("Fixes"));
+        assert_eq!(release.details.author.as_deref(), Some("octocat"));
+        assert_eq!(
+            release.details.published_at.map(|t| t.to_rfc3339()),
+            Some("2024-05-01T12:00:00+00:00".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn latest_release_empty_tag_returns_none() {
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!({"tag_name":""}).to_string())
+            .create_async()
+            .await;
+
+        let tag =
+            fetch_latest_release_tag_with_base(&client(), "owner", "repo", None, &server.url())
+                .await
+                .expect("ok");
+
+        assert_eq!(tag, None);
+    }
+
+    #[tokio::test]
+    async fn fallback_to_tags_on_404_success() {
+        let mut server = Server::new_async().await;
+        let _m1 = server
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let _m2 = server
+            .mock("GET", Matcher::Exact("/repos/owner/repo/tags".to_string()))
+            .match_query(Matcher::UrlEncoded("per_page".into(), "1".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!([{ "name": "v0.9.0" }]).to_string())
+            .create_async()
+            .await;
+
+        let tag =
+            fetch_latest_release_tag_with_base(&client(), "owner", "repo", None, &server.url())
+                .await
+                .expect("ok");
+
+        assert_eq!(tag, Some("v0.9.0".to_string()));
+    }
+
+    #[tokio::test]
+    async fn fallback_to_tags_empty_returns_none() {
+        let mut server = Server::new_async().await;
+        let _m1 = server
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let _m2 = server
+            .mock("GET", Matcher::Exact("/repos/owner/repo/tags".to_string()))
+            .match_query(Matcher::UrlEncoded("per_page".into(), "1".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!([]).to_string())
+            .create_async()
+            .await;
+
+        let tag =
+            fetch_latest_release_tag_with_base(&client(), "owner", "repo", None, &server.url())
+                .await
+                .expect("ok");
+
+        assert_eq!(tag, None);
+    }
+
+    #[tokio::test]
+    async fn non_success_errors() {
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .with_status(500)
+            .with_body("err")
+            .create_async()
+            .await;
+
+        let res =
+            fetch_latest_release_tag_with_base(&client(), "owner", "repo", None, &server.url())
+                .await;
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn html_success_body_is_a_decode_error() {
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .with_status(200)
+            .with_header("content-type", "text/html")
+            .with_body("<html><body>Bad gateway</body></html>")
+            .create_async()
+            .await;
+
+        let res =
+            fetch_latest_release_tag_with_base(&client(), "owner", "repo", None, &server.url())
+                .await;
+        assert!(matches!(res, Err(GithubError::Decode(_))));
+    }
+
+    #[tokio::test]
+    async fn secondary_rate_limit_surfaces_retry_after() {
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .with_status(403)
+            .with_header("retry-after", "30")
+            .with_body(r#"{"message":"You have exceeded a secondary rate limit"}"#)
+            .create_async()
+            .await;
+
+        let err =
+            fetch_latest_release_tag_with_base(&client(), "owner", "repo", None, &server.url())
+                .await
+                .expect_err("rate limited");
+        match err {
+            GithubError::SecondaryRateLimited { retry_after } => {
+                assert_eq!(retry_after, std::time::Duration::from_secs(30));
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn rejected_token_retries_unauthenticated() {
+        let mut server = Server::new_async().await;
+        let _m1 = server
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .match_header("authorization", "Bearer expired-token")
+            .with_status(401)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let _m2 = server
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .match_header("authorization", Matcher::Missing)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!({"tag_name":"v2.0.0"}).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let tag = fetch_latest_release_tag_with_base(
+            &client(),
+            "owner",
+            "repo",
+            Some("expired-token"),
+            &server.url(),
+        )
+        .await
+        .expect("ok");
+
+        assert_eq!(tag, Some("v2.0.0".to_string()));
+    }
+
+    #[tokio::test]
+    async fn rejected_token_still_falls_back_to_tags() {
+        let mut server = Server::new_async().await;
+        let _rejected = server
+            .mock(
+                "GET",
+                mockito::Matcher::Regex("^/repos/owner/repo/".to_string()),
+            )
+            .match_query(Matcher::Any)
+            .match_header("authorization", "Bearer expired-token")
+            .with_status(401)
+            .expect(2)
+            .create_async()
+            .await;
+        let _no_release = server
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .match_header("authorization", Matcher::Missing)
+            .with_status(404)
+            .expect(1)
+            .create_async()
+            .await;
+        let _tags = server
+            .mock("GET", "/repos/owner/repo/tags")
+            .match_query(Matcher::Any)
+            .match_header("authorization", Matcher::Missing)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!([{"name": "v0.3.0"}]).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let tag = fetch_latest_release_tag_with_base(
+            &client(),
+            "owner",
+            "repo",
+            Some("expired-token"),
+            &server.url(),
+        )
+        .await
+        .expect("ok");
+
+        assert_eq!(tag, Some("v0.3.0".to_string()));
+    }
+
+    #[tokio::test]
+    async fn token_rejected_anonymously_too_surfaces_error() {
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/repos/owner/private/releases/latest")
+            .with_status(401)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let res = fetch_latest_release_tag_with_base(
+            &client(),
+            "owner",
+            "private",
+            Some("expired-token"),
+            &server.url(),
+        )
+        .await;
+
+        assert!(matches!(
+            res,
+            Err(GithubError::TokenRejected { status: 401 })
+        ));
+    }
+}
@@ -0,0 +1,80 @@
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+use crate::github::GithubError;
+use crate::github::request::{get, json};
+
+#[derive(Deserialize)]
+struct RepositoryResponse {
+    default_branch: String,
+    #[serde(default)]
+    full_name: Option<String>,
+}
+
+/// What GitHub reports about a repository itself, as opposed to its releases.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepositoryMetadata {
+    pub default_branch: String,
+    /// `owner/name` as GitHub knows it now. Requests for a renamed or transferred
+    /// repository are redirected, so this differs from the name asked for.
+    pub full_name: Option<String>,
+}
+
+/// Fetches the repository's metadata, or `None` if GitHub does not know the repository.
+pub(crate) async fn fetch_repository_with_base(
+    client: &reqwest::Client,
+    owner: &str,
+    repo: &str,
+    token: Option<&str>,
+    base: &str,
+) -> Result<Option<RepositoryMetadata>, GithubError> {
+    let url = format!("{}/repos/{}/{}", base, owner, repo);
+    let resp = get(client, &url, token).await?;
+    match resp.status() {
+        s if s.is_success() => {
+            let repository: RepositoryResponse = json(resp).await?;
+            Ok(Some(RepositoryMetadata {
+                default_branch: repository.default_branch,
+                full_name: repository.full_name,
+            }))
+        }
+        StatusCode::NOT_FOUND => Ok(None),
+        s => Err(GithubError::Status { status: s.as_u16() }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn reads_default_branch() {
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/repos/owner/repo")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({"full_name": "owner/repo", "default_branch": "trunk"})
+                    .to_string(),
+            )
+            .create_async()
+            .await;
+        let _missing = server
+            .mock("GET", "/repos/owner/gone")
+            .with_status(404)
+            .create_async()
+            .await;
+        let client = reqwest::Client::new();
+
+        let found = fetch_repository_with_base(&client, "owner", "repo", None, &server.url())
+            .await
+            .unwrap();
+        assert_eq!(found.map(|m| m.default_branch).as_deref(), Some("trunk"));
+        let missing = fetch_repository_with_base(&client, "owner", "gone", None, &server.url())
+            .await
+            .unwrap();
+        assert_eq!(missing, None);
+    }
+}
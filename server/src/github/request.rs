@@ -0,0 +1,275 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+
+use crate::github::GithubError;
+
+static TOKEN_REJECTED_WARNED: AtomicBool = AtomicBool::new(false);
+static REQUEST_LOG: AtomicBool = AtomicBool::new(false);
+static REQUEST_TIMEOUT_MILLIS: AtomicU64 = AtomicU64::new(30_000);
+
+tokio::task_local! {
+    /// Timeout for the requests of the repository being polled, when it has its own.
+    static REPOSITORY_TIMEOUT: Duration;
+}
+
+/// Rate limit headers included in request logs.
+const RATE_LIMIT_HEADERS: [&str; 4] = [
+    "x-ratelimit-limit",
+    "x-ratelimit-remaining",
+    "x-ratelimit-used",
+    "x-ratelimit-reset",
+];
+
+/// Turns debug logging of every GitHub request on or off (`GITHUB_REQUEST_LOG`).
+pub fn set_request_logging(enabled: bool) {
+    REQUEST_LOG.store(enabled, Ordering::Relaxed);
+}
+
+/// Sets how long a request may take before it fails (`GITHUB_REQUEST_TIMEOUT_SECS`).
+pub fn set_request_timeout(timeout: Duration) {
+    REQUEST_TIMEOUT_MILLIS.store(timeout.as_millis() as u64, Ordering::Relaxed);
+}
+
+/// The timeout for a request sent now: the one set by [`with_request_timeout`] around
+/// the current call, or the global default.
+pub(crate) fn request_timeout() -> Duration {
+    REPOSITORY_TIMEOUT
+        .try_with(|t| *t)
+        .unwrap_or_else(|_| Duration::from_millis(REQUEST_TIMEOUT_MILLIS.load(Ordering::Relaxed)))
+}
+
+/// Runs `fut` with every request it sends limited to `timeout`, or to the global default
+/// when `timeout` is `None`.
+pub(crate) async fn with_request_timeout<F: Future>(
+    timeout: Option<Duration>,
+    fut: F,
+) -> F::Output {
+    match timeout {
+        Some(timeout) => REPOSITORY_TIMEOUT.scope(timeout, fut).await,
+        None => fut.await,
+    }
+}
+
+/// Method, path and authentication of `request`. The host is left out and the
+/// Authorization header is never included, only whether one was sent.
+fn describe_request(request: &reqwest::Request) -> String {
+    let url = request.url();
+    let path = match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    };
+    let auth = if request
+        .headers()
+        .contains_key(reqwest::header::AUTHORIZATION)
+    {
+        "authorization=[redacted]"
+    } else {
+        "anonymous"
+    };
+    format!("{} {} {}", request.method(), path, auth)
+}
+
+/// Status and rate limit headers of `resp`.
+fn describe_response(resp: &reqwest::Response) -> String {
+    let mut line = resp.status().as_u16().to_string();
+    for name in RATE_LIMIT_HEADERS {
+        if let Some(value) = resp.headers().get(name).and_then(|v| v.to_str().ok()) {
+            line.push_str(&format!(" {name}={value}"));
+        }
+    }
+    line
+}
+
+/// Sends `req`, logging it at debug level when request logging is on.
+async fn send(
+    client: &reqwest::Client,
+    req: reqwest::RequestBuilder,
+) -> Result<reqwest::Response, GithubError> {
+    let request = req.build()?;
+    let described = REQUEST_LOG
+        .load(Ordering::Relaxed)
+        .then(|| describe_request(&request));
+    let resp = client.execute(request).await?;
+    if let Some(request) = described {
+        log::debug!("GitHub request {} -> {}", request, describe_response(&resp));
+    }
+    Ok(resp)
+}
+
+fn build(client: &reqwest::Client, url: &str, token: Option<&str>) -> reqwest::RequestBuilder {
+    let mut req = client
+        .get(url)
+        .timeout(request_timeout())
+        .header("User-Agent", "github-release-bot/0.1")
+        .header("Accept", "application/vnd.github+json")
+        .header("X-GitHub-Api-Version", "2022-11-28");
+    if let Some(t) = token {
+        req = req.bearer_auth(t);
+    }
+    req
+}
+
+/// Returns the `Retry-After` delay GitHub sends with secondary rate limit responses.
+fn secondary_rate_limit(resp: &reqwest::Response) -> Option<Duration> {
+    if !matches!(
+        resp.status(),
+        StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS
+    ) {
+        return None;
+    }
+    let secs = resp
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs(secs))
+}
+
+/// The URL of the `rel` entry of a `Link` header, as GitHub sends when paginating.
+pub(crate) fn link_url(resp: &reqwest::Response, rel: &str) -> Option<String> {
+    let link = resp.headers().get(reqwest::header::LINK)?.to_str().ok()?;
+    let wanted = format!("rel=\"{rel}\"");
+    link.split(',').find_map(|entry| {
+        let (url, params) = entry.split_once(';')?;
+        params.split(';').any(|p| p.trim() == wanted).then(|| {
+            url.trim()
+                .trim_start_matches('<')
+                .trim_end_matches('>')
+                .to_string()
+        })
+    })
+}
+
+/// Sends a GET request to the GitHub API.
+///
+/// If the configured token is rejected with a 401 the request is retried without it, so
+/// public repositories keep working with an expired token. The retry's response is
+/// returned as it is, so a 404 still reads as a missing resource; only a retry that is
+/// unauthorized as well surfaces as `GithubError::TokenRejected`. Secondary rate limit
+/// responses surface as `GithubError::SecondaryRateLimited`.
+pub(crate) async fn get(
+    client: &reqwest::Client,
+    url: &str,
+    token: Option<&str>,
+) -> Result<reqwest::Response, GithubError> {
+    let resp = send(client, build(client, url, token)).await?;
+    if let Some(retry_after) = secondary_rate_limit(&resp) {
+        return Err(GithubError::SecondaryRateLimited { retry_after });
+    }
+
+    if token.is_none() || resp.status() != StatusCode::UNAUTHORIZED {
+        return Ok(resp);
+    }
+
+    if !TOKEN_REJECTED_WARNED.swap(true, Ordering::Relaxed) {
+        log::warn!(
+            "GITHUB_TOKEN was rejected by GitHub (401). Falling back to unauthenticated requests; \
+             private repositories will not be reachable until the token is replaced."
+        );
+    }
+
+    let resp = send(client, build(client, url, None)).await?;
+    if let Some(retry_after) = secondary_rate_limit(&resp) {
+        return Err(GithubError::SecondaryRateLimited { retry_after });
+    }
+    if resp.status() == StatusCode::UNAUTHORIZED {
+        return Err(GithubError::TokenRejected {
+            status: resp.status().as_u16(),
+        });
+    }
+    Ok(resp)
+}
+
+/// Sends a JSON POST request to the GitHub API, such as a GraphQL query.
+pub(crate) async fn post_json(
+    client: &reqwest::Client,
+    url: &str,
+    token: Option<&str>,
+    body: &serde_json::Value,
+) -> Result<reqwest::Response, GithubError> {
+    let mut req = client
+        .post(url)
+        .timeout(request_timeout())
+        .header("User-Agent", "github-release-bot/0.1")
+        .header("Accept", "application/vnd.github+json")
+        .json(body);
+    if let Some(t) = token {
+        req = req.bearer_auth(t);
+    }
+    let resp = send(client, req).await?;
+    if let Some(retry_after) = secondary_rate_limit(&resp) {
+        return Err(GithubError::SecondaryRateLimited { retry_after });
+    }
+    Ok(resp)
+}
+
+/// Longest part of an undecodable body that is logged.
+const BODY_SNIPPET_CHARS: usize = 200;
+
+/// Reads `resp` as JSON. Bodies that do not decode, such as an HTML page from a proxy or a
+/// truncated response, become `GithubError::Decode` and are logged with a snippet.
+pub(crate) async fn json<T: DeserializeOwned>(resp: reqwest::Response) -> Result<T, GithubError> {
+    let url = resp.url().to_string();
+    let body = resp.text().await?;
+    serde_json::from_str(&body).map_err(|e| {
+        let snippet: String = body.chars().take(BODY_SNIPPET_CHARS).collect();
+        log::warn!("Undecodable GitHub response from {url}: {e}; body starts with {snippet:?}");
+        GithubError::Decode(e)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+
+    #[test]
+    fn request_description_never_contains_the_token() {
+        let client = reqwest::Client::new();
+        let request = build(
+            &client,
+            "https://api.github.com/repos/owner/repo/tags?per_page=1",
+            Some("ghp_supersecret"),
+        )
+        .build()
+        .unwrap();
+
+        let line = describe_request(&request);
+
+        assert_eq!(
+            line,
+            "GET /repos/owner/repo/tags?per_page=1 authorization=[redacted]"
+        );
+        assert!(!line.contains("ghp_supersecret"));
+    }
+
+    #[tokio::test]
+    async fn response_description_includes_rate_limit_headers() {
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .with_status(404)
+            .with_header("x-ratelimit-limit", "5000")
+            .with_header("x-ratelimit-remaining", "4999")
+            .create_async()
+            .await;
+        let client = reqwest::Client::new();
+        let url = format!("{}/repos/owner/repo/releases/latest", server.url());
+
+        let resp = send(&client, build(&client, &url, Some("ghp_supersecret")))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            describe_response(&resp),
+            "404 x-ratelimit-limit=5000 x-ratelimit-remaining=4999"
+        );
+    }
+}
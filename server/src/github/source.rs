@@ -0,0 +1,166 @@
+use async_trait::async_trait;
+
+use crate::packages::{
+    Package, Registry, crates_io_api_base, fetch_latest_version_with_base, npm_registry_base,
+};
+
+use crate::github::{
+    GithubError, Release, ReleaseDetails, fetch_latest_atom_tag_with_base,
+    fetch_latest_release_with_base, fetch_latest_tag_with_base, fetch_release_page_with_base,
+    fetch_releases_with_base, fetch_tags_with_base, github_web_base,
+};
+
+/// Where the poller learns about the newest release or tag of a repository. The HTTP
+/// implementation talks to the GitHub API; tests can answer from memory instead.
+#[async_trait]
+pub trait ReleaseSource: Send + Sync {
+    async fn latest_release(&self, owner: &str, repo: &str)
+    -> Result<Option<Release>, GithubError>;
+    async fn latest_tag(&self, owner: &str, repo: &str) -> Result<Option<String>, GithubError>;
+
+    /// Recent stable releases, newest first, for looking past the latest one. Sources
+    /// that only know the latest release return just that.
+    async fn recent_releases(&self, owner: &str, repo: &str) -> Result<Vec<Release>, GithubError> {
+        Ok(self
+            .latest_release(owner, repo)
+            .await?
+            .into_iter()
+            .collect())
+    }
+
+    /// Every recent stable release, newest first, so a release missing from it can be
+    /// told apart from one that was never listed. Sources that only know the latest
+    /// release answer `GithubError::Unsupported`.
+    async fn listed_releases(
+        &self,
+        _owner: &str,
+        _repo: &str,
+    ) -> Result<Vec<Release>, GithubError> {
+        Err(GithubError::Unsupported)
+    }
+
+    /// Recent tags, newest first, from at most `pages` pages; by default only the latest one.
+    async fn recent_tags(
+        &self,
+        owner: &str,
+        repo: &str,
+        _pages: usize,
+    ) -> Result<Vec<String>, GithubError> {
+        Ok(self.latest_tag(owner, repo).await?.into_iter().collect())
+    }
+
+    /// The newest published version of a registry package; sources that only know GitHub
+    /// find none.
+    async fn latest_package_version(
+        &self,
+        _package: &Package,
+    ) -> Result<Option<Release>, GithubError> {
+        Ok(None)
+    }
+}
+
+/// Reads releases and tags from the GitHub REST API at `base`. While the API is rate
+/// limited, the latest release is read from the public Atom feed at `web_base` instead,
+/// marked as read from the feed.
+pub struct HttpReleaseSource {
+    client: reqwest::Client,
+    token: Option<String>,
+    base: String,
+    web_base: String,
+}
+
+impl HttpReleaseSource {
+    pub fn new(client: reqwest::Client, token: Option<&str>, base: String) -> Self {
+        Self {
+            client,
+            token: token.map(str::to_string),
+            base,
+            web_base: github_web_base(),
+        }
+    }
+
+    /// One page of the repository's releases, newest first, and whether the page was full.
+    pub(crate) async fn release_page(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: u32,
+    ) -> Result<(Vec<Release>, bool), GithubError> {
+        let token = self.token.as_deref();
+        fetch_release_page_with_base(&self.client, owner, repo, token, &self.base, page).await
+    }
+
+    /// Reads the Atom feed fallback from `web_base` instead of `https://github.com`.
+    #[cfg(test)]
+    pub(crate) fn with_web_base(mut self, web_base: String) -> Self {
+        self.web_base = web_base;
+        self
+    }
+}
+
+#[async_trait]
+impl ReleaseSource for HttpReleaseSource {
+    async fn latest_release(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Option<Release>, GithubError> {
+        let token = self.token.as_deref();
+        match fetch_latest_release_with_base(&self.client, owner, repo, token, &self.base).await {
+            Err(e) if e.is_rate_limited() => {
+                log::warn!("{e}; reading {owner}/{repo} from its releases.atom feed instead");
+                let tag =
+                    fetch_latest_atom_tag_with_base(&self.client, owner, repo, &self.web_base)
+                        .await
+                        .map_err(|feed_error| {
+                            log::warn!("Atom feed for {owner}/{repo} failed: {feed_error}");
+                            e
+                        })?;
+                Ok(tag.map(|tag_name| Release {
+                    tag_name,
+                    body: None,
+                    details: ReleaseDetails {
+                        from_feed: true,
+                        ..ReleaseDetails::default()
+                    },
+                }))
+            }
+            other => other,
+        }
+    }
+
+    async fn latest_tag(&self, owner: &str, repo: &str) -> Result<Option<String>, GithubError> {
+        fetch_latest_tag_with_base(&self.client, owner, repo, self.token.as_deref(), &self.base)
+            .await
+    }
+
+    async fn recent_releases(&self, owner: &str, repo: &str) -> Result<Vec<Release>, GithubError> {
+        let token = self.token.as_deref();
+        fetch_releases_with_base(&self.client, owner, repo, token, &self.base, false).await
+    }
+
+    async fn listed_releases(&self, owner: &str, repo: &str) -> Result<Vec<Release>, GithubError> {
+        self.recent_releases(owner, repo).await
+    }
+
+    async fn recent_tags(
+        &self,
+        owner: &str,
+        repo: &str,
+        pages: usize,
+    ) -> Result<Vec<String>, GithubError> {
+        let token = self.token.as_deref();
+        fetch_tags_with_base(&self.client, owner, repo, token, &self.base, pages).await
+    }
+
+    async fn latest_package_version(
+        &self,
+        package: &Package,
+    ) -> Result<Option<Release>, GithubError> {
+        let base = match package.registry {
+            Registry::CratesIo => crates_io_api_base(),
+            Registry::Npm => npm_registry_base(),
+        };
+        fetch_latest_version_with_base(&self.client, package, &base).await
+    }
+}
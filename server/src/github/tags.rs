@@ -0,0 +1,284 @@
+use reqwest::StatusCode;
+use serde::Deserialize;
+use urlencoding::encode;
+
+use crate::github::request::{get, json, link_url};
+use crate::github::{GithubError, github_api_base};
+
+#[derive(Deserialize)]
+struct TagResponse {
+    name: String,
+}
+
+/// Returns the newest git tag of the repository, regardless of any GitHub Release.
+pub(crate) async fn fetch_latest_tag_with_base(
+    client: &reqwest::Client,
+    owner: &str,
+    repo: &str,
+    token: Option<&str>,
+    base: &str,
+) -> Result<Option<String>, GithubError> {
+    let tags_url = format!("{}/repos/{}/{}/tags?per_page=1", base, owner, repo);
+    let resp = get(client, &tags_url, token).await?;
+    match resp.status() {
+        s if s.is_success() => {
+            let tags: Vec<TagResponse> = json(resp).await?;
+            Ok(tags.into_iter().next().map(|t| t.name))
+        }
+        StatusCode::NOT_FOUND => Ok(None),
+        s => Err(GithubError::Status { status: s.as_u16() }),
+    }
+}
+
+/// Tags requested per page when listing tags.
+const TAGS_PER_PAGE: u32 = 100;
+
+/// Lists the repository's tags newest first, following `Link: rel="next"` headers for at
+/// most `max_pages` pages, for tag filters that need to look past the newest tag.
+pub(crate) async fn fetch_tags_with_base(
+    client: &reqwest::Client,
+    owner: &str,
+    repo: &str,
+    token: Option<&str>,
+    base: &str,
+    max_pages: usize,
+) -> Result<Vec<String>, GithubError> {
+    let mut url = Some(format!(
+        "{}/repos/{}/{}/tags?per_page={}",
+        base, owner, repo, TAGS_PER_PAGE
+    ));
+    let mut names = Vec::new();
+    for _ in 0..max_pages {
+        let Some(page_url) = url.take() else {
+            break;
+        };
+        let resp = get(client, &page_url, token).await?;
+        match resp.status() {
+            s if s.is_success() => {
+                url = link_url(&resp, "next");
+                let tags: Vec<TagResponse> = json(resp).await?;
+                names.extend(tags.into_iter().map(|t| t.name));
+            }
+            StatusCode::NOT_FOUND => break,
+            s => return Err(GithubError::Status { status: s.as_u16() }),
+        }
+    }
+    Ok(names)
+}
+
+/// Checks whether `tag` has been published on the repository, either as a release or
+/// as a plain git tag.
+pub(crate) async fn tag_exists_with_base(
+    client: &reqwest::Client,
+    owner: &str,
+    repo: &str,
+    tag: &str,
+    token: Option<&str>,
+    base: &str,
+) -> Result<bool, GithubError> {
+    let release_url = format!(
+        "{}/repos/{}/{}/releases/tags/{}",
+        base,
+        owner,
+        repo,
+        encode(tag)
+    );
+    let resp = get(client, &release_url, token).await?;
+    if resp.status().is_success() {
+        return Ok(true);
+    }
+    if resp.status() != StatusCode::NOT_FOUND {
+        return Err(GithubError::Status {
+            status: resp.status().as_u16(),
+        });
+    }
+
+    // No release for the tag: it may still exist as a plain tag
+    let ref_url = format!("{}/repos/{}/{}/git/ref/tags/{}", base, owner, repo, tag);
+    let resp = get(client, &ref_url, token).await?;
+    match resp.status() {
+        s if s.is_success() => Ok(true),
+        StatusCode::NOT_FOUND => Ok(false),
+        s => Err(GithubError::Status { status: s.as_u16() }),
+    }
+}
+
+pub async fn tag_exists(
+    client: &reqwest::Client,
+    owner: &str,
+    repo: &str,
+    tag: &str,
+    token: Option<&str>,
+) -> Result<bool, GithubError> {
+    let base = github_api_base();
+    tag_exists_with_base(client, owner, repo, tag, token, &base).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+
+    #[tokio::test]
+    async fn latest_tag_skips_releases() {
+        let mut server = Server::new_async().await;
+        let m_releases = server
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .expect(0)
+            .create_async()
+            .await;
+        let _m_tags = server
+            .mock("GET", Matcher::Exact("/repos/owner/repo/tags".to_string()))
+            .match_query(Matcher::UrlEncoded("per_page".into(), "1".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!([{ "name": "v3.1.0" }]).to_string())
+            .create_async()
+            .await;
+
+        let tag = fetch_latest_tag_with_base(
+            &reqwest::Client::new(),
+            "owner",
+            "repo",
+            None,
+            &server.url(),
+        )
+        .await
+        .expect("ok");
+        assert_eq!(tag.as_deref(), Some("v3.1.0"));
+        m_releases.assert();
+    }
+
+    #[tokio::test]
+    async fn tags_follow_link_header_up_to_the_page_cap() {
+        let mut server = Server::new_async().await;
+        let page = |n: u32| {
+            format!(
+                "{}/repos/owner/repo/tags?per_page=100&page={}",
+                server.url(),
+                n
+            )
+        };
+        let (page_2, page_3) = (page(2), page(3));
+        let _m1 = server
+            .mock("GET", "/repos/owner/repo/tags")
+            .match_query(Matcher::Exact("per_page=100".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header(
+                "link",
+                &format!("<{page_2}>; rel=\"next\", <{page_3}>; rel=\"last\""),
+            )
+            .with_body(serde_json::json!([{ "name": "v3.0.0" }, { "name": "v2.0.0" }]).to_string())
+            .create_async()
+            .await;
+        let _m2 = server
+            .mock("GET", "/repos/owner/repo/tags")
+            .match_query(Matcher::Exact("per_page=100&page=2".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("link", &format!("<{page_3}>; rel=\"next\""))
+            .with_body(serde_json::json!([{ "name": "v1.0.0" }]).to_string())
+            .create_async()
+            .await;
+        let m3 = server
+            .mock("GET", "/repos/owner/repo/tags")
+            .match_query(Matcher::Exact("per_page=100&page=3".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!([{ "name": "v0.1.0" }]).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+        let client = reqwest::Client::new();
+
+        let two_pages = fetch_tags_with_base(&client, "owner", "repo", None, &server.url(), 2)
+            .await
+            .expect("ok");
+        assert_eq!(two_pages, ["v3.0.0", "v2.0.0", "v1.0.0"]);
+
+        let all = fetch_tags_with_base(&client, "owner", "repo", None, &server.url(), 10)
+            .await
+            .expect("ok");
+        assert_eq!(all, ["v3.0.0", "v2.0.0", "v1.0.0", "v0.1.0"]);
+        m3.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn release_for_tag_exists() {
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/repos/owner/repo/releases/tags/v2.0.0")
+            .with_status(200)
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let exists = tag_exists_with_base(
+            &reqwest::Client::new(),
+            "owner",
+            "repo",
+            "v2.0.0",
+            None,
+            &server.url(),
+        )
+        .await
+        .expect("ok");
+        assert!(exists);
+    }
+
+    #[tokio::test]
+    async fn plain_tag_exists_without_release() {
+        let mut server = Server::new_async().await;
+        let _m1 = server
+            .mock("GET", "/repos/owner/repo/releases/tags/v2.0.0")
+            .with_status(404)
+            .create_async()
+            .await;
+        let _m2 = server
+            .mock("GET", "/repos/owner/repo/git/ref/tags/v2.0.0")
+            .with_status(200)
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let exists = tag_exists_with_base(
+            &reqwest::Client::new(),
+            "owner",
+            "repo",
+            "v2.0.0",
+            None,
+            &server.url(),
+        )
+        .await
+        .expect("ok");
+        assert!(exists);
+    }
+
+    #[tokio::test]
+    async fn missing_tag_does_not_exist() {
+        let mut server = Server::new_async().await;
+        let _m1 = server
+            .mock("GET", "/repos/owner/repo/releases/tags/v2.0.0")
+            .with_status(404)
+            .create_async()
+            .await;
+        let _m2 = server
+            .mock("GET", "/repos/owner/repo/git/ref/tags/v2.0.0")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let exists = tag_exists_with_base(
+            &reqwest::Client::new(),
+            "owner",
+            "repo",
+            "v2.0.0",
+            None,
+            &server.url(),
+        )
+        .await
+        .expect("ok");
+        assert!(!exists);
+    }
+}
@@ -0,0 +1,161 @@
+use axum::extract::{Path, Request, State};
+use axum::http::{StatusCode, header};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Extension, Json, Router};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use sqlx::sqlite::SqlitePool;
+
+use crate::api_tokens::hash_token;
+use crate::api_tokens::repository::{ChatApiTokensRepository, SqliteChatApiTokensRepository};
+use crate::tracked_repositories::repository::{
+    SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+};
+use crate::tracked_repositories::tracked_repositories_releases::repository::{
+    CachedRepositoryReleasesRepository, SqliteCachedRepositoryReleasesRepository,
+};
+
+#[derive(Clone)]
+pub(crate) struct ApiState {
+    pub db: SqlitePool,
+    pub token: String,
+}
+
+pub(crate) fn router(state: ApiState) -> Router {
+    Router::new()
+        .route("/api/chats/{chat_id}/repos", get(chat_repos))
+        .route("/api/repos/{id}/latest", get(repo_latest))
+        .route_layer(middleware::from_fn_with_state(state.clone(), require_token))
+        .with_state(state)
+}
+
+/// Who a request is made by: the operator's `API_TOKEN` reaches every chat, a chat's own
+/// token (see `/token`) only that chat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Caller {
+    Operator,
+    Chat(i64),
+}
+
+impl Caller {
+    fn can_access(self, chat_id: i64) -> bool {
+        match self {
+            Self::Operator => true,
+            Self::Chat(own) => own == chat_id,
+        }
+    }
+}
+
+/// Whether `token` is the operator token. Both are hashed and every byte of the digests
+/// compared, so the time taken says nothing about how much of a guess was right.
+fn is_operator_token(token: &str, operator: &str) -> bool {
+    let (given, expected) = (Sha256::digest(token), Sha256::digest(operator));
+    given
+        .iter()
+        .zip(expected.iter())
+        .fold(0, |diff, (a, b)| diff | (a ^ b))
+        == 0
+}
+
+async fn require_token(State(state): State<ApiState>, mut req: Request, next: Next) -> Response {
+    let Some(token) = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+    else {
+        return error(StatusCode::UNAUTHORIZED, "missing or invalid bearer token");
+    };
+
+    let caller = if is_operator_token(token, &state.token) {
+        Caller::Operator
+    } else {
+        // Looked up on every request so a rotated token stops working at once
+        let tokens = SqliteChatApiTokensRepository::new(state.db.clone());
+        match tokens.find_by_hash(&hash_token(token)).await {
+            Ok(Some(chat_token)) => Caller::Chat(chat_token.chat_id),
+            Ok(None) => return error(StatusCode::UNAUTHORIZED, "missing or invalid bearer token"),
+            Err(e) => {
+                log::warn!("API failed to look up a chat token: {}", e);
+                return error(StatusCode::INTERNAL_SERVER_ERROR, "failed to check token");
+            }
+        }
+    };
+    req.extensions_mut().insert(caller);
+    next.run(req).await
+}
+
+#[derive(Serialize)]
+struct ErrorBody<'a> {
+    error: &'a str,
+}
+
+fn error(status: StatusCode, message: &str) -> Response {
+    (status, Json(ErrorBody { error: message })).into_response()
+}
+
+async fn chat_repos(
+    State(state): State<ApiState>,
+    Extension(caller): Extension<Caller>,
+    Path(chat_id): Path<i64>,
+) -> Response {
+    if !caller.can_access(chat_id) {
+        return error(
+            StatusCode::FORBIDDEN,
+            "token does not grant access to this chat",
+        );
+    }
+    let repository = SqliteTrackedRepositoriesRepository::new(state.db.clone());
+    match repository.find_all_in_chat(chat_id).await {
+        Ok(repos) => Json(repos).into_response(),
+        Err(e) => {
+            log::warn!(
+                "API failed to list repositories for chat {}: {}",
+                chat_id,
+                e
+            );
+            error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "failed to list repositories",
+            )
+        }
+    }
+}
+
+async fn repo_latest(
+    State(state): State<ApiState>,
+    Extension(caller): Extension<Caller>,
+    Path(id): Path<uuid::Uuid>,
+) -> Response {
+    let repository = SqliteTrackedRepositoriesRepository::new(state.db.clone());
+    match repository.find_by_id(&id.to_string()).await {
+        Ok(Some(tracked)) if caller.can_access(tracked.chat_id) => {}
+        Ok(Some(_)) => return error(StatusCode::NOT_FOUND, "repository not found"),
+        Ok(None) => return error(StatusCode::NOT_FOUND, "repository not found"),
+        Err(e) => {
+            log::warn!("API failed to load repository {}: {}", id, e);
+            return error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "failed to load repository",
+            );
+        }
+    }
+
+    let cache_repo = SqliteCachedRepositoryReleasesRepository::new(state.db.clone());
+    match cache_repo.find_by_tracked_release_id(&id).await {
+        Ok(Some(cached)) => Json(cached).into_response(),
+        Ok(None) => error(StatusCode::NOT_FOUND, "no release seen yet"),
+        Err(e) => {
+            log::warn!("API failed to load latest release for {}: {}", id, e);
+            error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "failed to load latest release",
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,169 @@
+use super::*;
+use crate::api_tokens::ChatApiToken;
+use crate::tracked_repositories::tracked_repositories_releases::CachedRepositoryRelease;
+use crate::tracked_repositories::{RepositoryUrl, TrackedRelease};
+use axum::body::{Body, to_bytes};
+use axum::http::Request;
+use chrono::Utc;
+use sqlx::sqlite::SqlitePoolOptions;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+async fn setup_state() -> ApiState {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("failed to create in-memory sqlite pool");
+
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("failed to run migrations");
+
+    ApiState {
+        db: pool,
+        token: "api-secret".to_string(),
+    }
+}
+
+async fn insert_tracked(state: &ApiState, chat_id: i64) -> TrackedRelease {
+    let mut tracked = TrackedRelease {
+        id: Uuid::now_v7(),
+        repository_name: "repo".to_string(),
+        repository_url: RepositoryUrl::new("https://github.com/owner/repo".to_string()).unwrap(),
+        chat_id,
+        bot_id: String::new(),
+        default_branch: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    };
+    SqliteTrackedRepositoriesRepository::new(state.db.clone())
+        .save(&mut tracked)
+        .await
+        .unwrap();
+    tracked
+}
+
+async fn get_json(
+    state: &ApiState,
+    uri: &str,
+    token: Option<&str>,
+) -> (StatusCode, serde_json::Value) {
+    let mut req = Request::builder().uri(uri);
+    if let Some(t) = token {
+        req = req.header(header::AUTHORIZATION, format!("Bearer {t}"));
+    }
+    let resp = router(state.clone())
+        .oneshot(req.body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    let status = resp.status();
+    let bytes = to_bytes(resp.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+#[test]
+fn operator_token_must_match_exactly() {
+    assert!(is_operator_token("api-secret", "api-secret"));
+    assert!(!is_operator_token("api-secre", "api-secret"));
+    assert!(!is_operator_token("api-secret2", "api-secret"));
+    assert!(!is_operator_token("", "api-secret"));
+}
+
+#[tokio::test]
+async fn rejects_missing_and_wrong_tokens() {
+    let state = setup_state().await;
+
+    let (status, _) = get_json(&state, "/api/chats/1/repos", None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _) = get_json(&state, "/api/chats/1/repos", Some("nope")).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn lists_chat_repositories() {
+    let state = setup_state().await;
+    let tracked = insert_tracked(&state, 42).await;
+
+    let (status, body) = get_json(&state, "/api/chats/42/repos", Some("api-secret")).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body[0]["id"], tracked.id.to_string());
+    assert_eq!(
+        body[0]["repository_url"]["url"],
+        "https://github.com/owner/repo"
+    );
+}
+
+#[tokio::test]
+async fn chat_token_reaches_only_its_chat_until_rotated() {
+    let state = setup_state().await;
+    let tracked = insert_tracked(&state, 42).await;
+    let tokens = SqliteChatApiTokensRepository::new(state.db.clone());
+    let save = |token: &str| ChatApiToken {
+        chat_id: 42,
+        token_hash: hash_token(token),
+        created_at: Utc::now(),
+    };
+    tokens.save(&save("first")).await.unwrap();
+
+    let (status, _) = get_json(&state, "/api/chats/42/repos", Some("first")).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = get_json(&state, "/api/chats/7/repos", Some("first")).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let mut other = TrackedRelease {
+        id: Uuid::now_v7(),
+        repository_name: "other".to_string(),
+        repository_url: RepositoryUrl::new("https://github.com/owner/other".to_string()).unwrap(),
+        chat_id: 7,
+        bot_id: String::new(),
+        default_branch: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    };
+    SqliteTrackedRepositoriesRepository::new(state.db.clone())
+        .save(&mut other)
+        .await
+        .unwrap();
+    let uri = format!("/api/repos/{}/latest", other.id);
+    let (status, _) = get_json(&state, &uri, Some("first")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    tokens.save(&save("second")).await.unwrap();
+    let (status, _) = get_json(&state, "/api/chats/42/repos", Some("first")).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (status, body) = get_json(&state, "/api/chats/42/repos", Some("second")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body[0]["id"], tracked.id.to_string());
+}
+
+#[tokio::test]
+async fn latest_release_and_not_found_cases() {
+    let state = setup_state().await;
+    let tracked = insert_tracked(&state, 42).await;
+    let uri = format!("/api/repos/{}/latest", tracked.id);
+
+    let (status, _) = get_json(&state, &uri, Some("api-secret")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v3.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+
+    let (status, body) = get_json(&state, &uri, Some("api-secret")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["tag_name"], "v3.0.0");
+
+    let missing = format!("/api/repos/{}/latest", Uuid::now_v7());
+    let (status, _) = get_json(&state, &missing, Some("api-secret")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::Router;
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::post;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use teloxide::Bot;
+
+use crate::github::parse_release_event;
+use crate::poller::{AppState, process_pushed_release};
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::repository::{
+    SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+};
+
+/// A bot together with the poller state of its repositories.
+pub(crate) type BotPollers = HashMap<String, (Bot, Arc<AppState>)>;
+
+#[derive(Clone)]
+pub(crate) struct WebhookState {
+    pub db: sqlx::sqlite::SqlitePool,
+    pub secret: String,
+    pub bots: Arc<BotPollers>,
+}
+
+pub(crate) fn router(state: WebhookState) -> Router {
+    Router::new()
+        .route("/github/webhook", post(receive))
+        .with_state(state)
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Checks an `X-Hub-Signature-256` header, `sha256=` followed by the hex HMAC-SHA256 of
+/// the body keyed with the webhook secret. The comparison takes constant time.
+fn verify_signature(secret: &[u8], body: &[u8], header: Option<&str>) -> bool {
+    let Some(expected) = header
+        .and_then(|h| h.strip_prefix("sha256="))
+        .and_then(decode_hex)
+    else {
+        return false;
+    };
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret) else {
+        return false;
+    };
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+/// The rows tracking the repository GitHub names `owner/repo` in a webhook, however
+/// their URLs were written when tracked.
+async fn tracked_for(
+    db: &sqlx::sqlite::SqlitePool,
+    full_name: &str,
+) -> Result<Vec<TrackedRelease>, Box<dyn std::error::Error + Send + Sync>> {
+    let Some((owner, repo)) = full_name.split_once('/') else {
+        return Ok(Vec::new());
+    };
+    SqliteTrackedRepositoriesRepository::new(db.clone())
+        .find_all_by_github_repository(owner, repo)
+        .await
+}
+
+async fn receive(State(state): State<WebhookState>, headers: HeaderMap, body: Bytes) -> StatusCode {
+    let signature = headers
+        .get("x-hub-signature-256")
+        .and_then(|v| v.to_str().ok());
+    if !verify_signature(state.secret.as_bytes(), &body, signature) {
+        return StatusCode::UNAUTHORIZED;
+    }
+
+    match headers.get("x-github-event").and_then(|v| v.to_str().ok()) {
+        Some("release") => {}
+        Some("ping") => return StatusCode::OK,
+        _ => return StatusCode::ACCEPTED,
+    }
+    let pushed = match parse_release_event(&body) {
+        Ok(Some(pushed)) => pushed,
+        Ok(None) => return StatusCode::ACCEPTED,
+        Err(e) => {
+            log::warn!("Invalid GitHub release webhook: {}", e);
+            return StatusCode::BAD_REQUEST;
+        }
+    };
+
+    let url = format!("https://github.com/{}", pushed.full_name);
+    let tracked = match tracked_for(&state.db, &pushed.full_name).await {
+        Ok(tracked) if tracked.is_empty() => {
+            log::debug!("Release webhook for untracked repository {}", url);
+            return StatusCode::ACCEPTED;
+        }
+        Ok(tracked) => tracked,
+        Err(e) => {
+            log::warn!("Failed to look up {} for a release webhook: {}", url, e);
+            return StatusCode::INTERNAL_SERVER_ERROR;
+        }
+    };
+
+    // Every bot tracking the repository announces the release to its own chat
+    let mut announced = false;
+    for tracked in &tracked {
+        let Some((bot, poller_state)) = state.bots.get(&tracked.bot_id) else {
+            log::warn!("No bot '{}' to announce {} with", tracked.bot_id, url);
+            continue;
+        };
+        announced |=
+            process_pushed_release(poller_state, bot, tracked, pushed.release.clone()).await;
+    }
+
+    if announced {
+        StatusCode::OK
+    } else {
+        StatusCode::ACCEPTED
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracked_repositories::RepositoryUrl;
+    use axum::body::Body;
+    use axum::http::Request;
+    use sqlx::sqlite::SqlitePoolOptions;
+    use tower::ServiceExt;
+
+    const SECRET: &str = "It's a Secret to Everybody";
+    const BODY: &str = "Hello, World!";
+    // The example from GitHub's webhook documentation
+    const SIGNATURE: &str =
+        "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+
+    #[test]
+    fn accepts_githubs_example_signature() {
+        assert!(verify_signature(
+            SECRET.as_bytes(),
+            BODY.as_bytes(),
+            Some(SIGNATURE)
+        ));
+    }
+
+    #[test]
+    fn rejects_wrong_or_malformed_signatures() {
+        let body = BODY.as_bytes();
+        assert!(!verify_signature(b"other secret", body, Some(SIGNATURE)));
+        assert!(!verify_signature(
+            SECRET.as_bytes(),
+            b"Hello, World?",
+            Some(SIGNATURE)
+        ));
+        assert!(!verify_signature(SECRET.as_bytes(), body, None));
+        assert!(!verify_signature(
+            SECRET.as_bytes(),
+            body,
+            Some(SIGNATURE.trim_start_matches("sha256="))
+        ));
+        assert!(!verify_signature(
+            SECRET.as_bytes(),
+            body,
+            Some("sha256=zz")
+        ));
+        assert!(!verify_signature(
+            SECRET.as_bytes(),
+            body,
+            Some("sha256=75710")
+        ));
+        assert!(!verify_signature(SECRET.as_bytes(), body, Some("sha256=")));
+    }
+
+    async fn state() -> WebhookState {
+        let db = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+        WebhookState {
+            db,
+            secret: SECRET.to_string(),
+            bots: Arc::new(HashMap::new()),
+        }
+    }
+
+    async fn post_event(event: &str, signature: Option<&str>) -> StatusCode {
+        let mut req = Request::builder()
+            .method("POST")
+            .uri("/github/webhook")
+            .header("x-github-event", event);
+        if let Some(signature) = signature {
+            req = req.header("x-hub-signature-256", signature);
+        }
+        router(state().await)
+            .oneshot(req.body(Body::from(BODY)).unwrap())
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn unsigned_requests_are_rejected() {
+        assert_eq!(post_event("ping", None).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            post_event("ping", Some("sha256=00")).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(post_event("ping", Some(SIGNATURE)).await, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn finds_repositories_tracked_under_another_spelling() {
+        let db = state().await.db;
+        sqlx::migrate!("./migrations").run(&db).await.unwrap();
+        let now = chrono::Utc::now();
+        let mut tracked = TrackedRelease {
+            id: uuid::Uuid::now_v7(),
+            repository_name: "Repo".to_string(),
+            repository_url: RepositoryUrl::new("https://github.com/Owner/Repo.git".to_string())
+                .unwrap(),
+            chat_id: 1,
+            bot_id: String::new(),
+            default_branch: None,
+            created_at: now,
+            updated_at: now,
+        };
+        SqliteTrackedRepositoriesRepository::new(db.clone())
+            .save(&mut tracked)
+            .await
+            .unwrap();
+
+        let tracked = tracked_for(&db, "owner/repo").await.unwrap();
+
+        assert_eq!(tracked.len(), 1);
+        assert_eq!(tracked[0].chat_id, 1);
+        assert!(tracked_for(&db, "owner/other").await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,58 @@
+mod api;
+mod github_webhook;
+
+use std::sync::Arc;
+
+use axum::Router;
+use sqlx::sqlite::SqlitePool;
+
+use crate::configuration::Configuration;
+
+pub(crate) use github_webhook::BotPollers;
+
+/// Starts the HTTP server in the background when an API token or a GitHub webhook secret
+/// is configured.
+pub async fn spawn(db: SqlitePool, config: Configuration, bots: BotPollers) {
+    let mut app = Router::new();
+    match config.api_token.clone() {
+        Some(token) => {
+            app = app.merge(api::router(api::ApiState {
+                db: db.clone(),
+                token,
+            }));
+        }
+        None => log::info!("API_TOKEN not set, REST API disabled"),
+    }
+    match config.github_webhook_secret.clone() {
+        Some(secret) => {
+            app = app.merge(github_webhook::router(github_webhook::WebhookState {
+                db,
+                secret,
+                bots: Arc::new(bots),
+            }));
+        }
+        None => log::info!("GITHUB_WEBHOOK_SECRET not set, GitHub webhook disabled"),
+    }
+    if config.api_token.is_none() && config.github_webhook_secret.is_none() {
+        return;
+    }
+
+    let listener = match tokio::net::TcpListener::bind(&config.http_bind_addr).await {
+        Ok(l) => l,
+        Err(e) => {
+            log::error!(
+                "Failed to bind HTTP server to {}: {}",
+                config.http_bind_addr,
+                e
+            );
+            return;
+        }
+    };
+
+    log::info!("HTTP server listening on {}", config.http_bind_addr);
+    tokio::spawn(async move {
+        if let Err(e) = axum::serve(listener, app).await {
+            log::error!("HTTP server stopped: {}", e);
+        }
+    });
+}
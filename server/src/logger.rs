@@ -1,40 +1,107 @@
 use chrono::Local;
 use env_logger::Builder;
-use log::LevelFilter;
+use log::{LevelFilter, Log, Metadata, Record};
 use std::io::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-fn logger(log_level_filter: LevelFilter) {
-    Builder::new()
-        .format(|buf, record| {
-            writeln!(
-                buf,
-                "{} [{}] - {}",
-                Local::now().format("%Y-%m-%dT%H:%M:%S"),
-                record.level(),
-                record.args()
-            )
-        })
-        .filter(Some("github_release_bot"), log_level_filter)
+// The builders let every level of this crate through and `ReloadableLogger` holds it to
+// `CRATE_LEVEL`, so /loglevel can change the level at runtime. Dependencies stay at info.
+
+const CRATE: &str = "github_release_bot";
+
+static CRATE_LEVEL: AtomicUsize = AtomicUsize::new(LevelFilter::Info as usize);
+
+struct ReloadableLogger {
+    inner: env_logger::Logger,
+}
+
+impl Log for ReloadableLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        let target = metadata.target();
+        let own = target == CRATE
+            || target
+                .strip_prefix(CRATE)
+                .is_some_and(|rest| rest.starts_with("::"));
+        if own {
+            metadata.level() <= level()
+        } else {
+            self.inner.enabled(metadata)
+        }
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+fn install(mut builder: Builder, log_level_filter: LevelFilter) {
+    let inner = builder
+        .filter(Some(CRATE), LevelFilter::Trace)
         .filter(None, LevelFilter::Info)
-        .init();
+        .build();
+    log::set_boxed_logger(Box::new(ReloadableLogger { inner })).expect("logger initialized twice");
+    set_level(log_level_filter);
+}
+
+fn logger(log_level_filter: LevelFilter) {
+    let mut builder = Builder::new();
+    builder.format(|buf, record| {
+        writeln!(
+            buf,
+            "{} [{}] - {}",
+            Local::now().format("%Y-%m-%dT%H:%M:%S"),
+            record.level(),
+            record.args()
+        )
+    });
+    install(builder, log_level_filter);
 }
 
 fn debug_logger(log_level_filter: LevelFilter) {
-    Builder::new()
-        .format(|buf, record| {
-            writeln!(
-                buf,
-                "{} [{}] - {}:{} - {}",
-                Local::now().format("%Y-%m-%dT%H:%M:%S"),
-                record.level(),
-                record.file().unwrap(),
-                record.line().unwrap(),
-                record.args()
-            )
-        })
-        .filter(Some("github_release_bot"), log_level_filter)
-        .filter(None, LevelFilter::Info)
-        .init();
+    let mut builder = Builder::new();
+    builder.format(|buf, record| {
+        writeln!(
+            buf,
+            "{} [{}] - {}:{} - {}",
+            Local::now().format("%Y-%m-%dT%H:%M:%S"),
+            record.level(),
+            record.file().unwrap(),
+            record.line().unwrap(),
+            record.args()
+        )
+    });
+    install(builder, log_level_filter);
+}
+
+/// Levels `/loglevel` can switch to.
+pub const RUNTIME_LEVELS: [&str; 3] = ["debug", "info", "warn"];
+
+pub fn parse_runtime_level(value: &str) -> Option<LevelFilter> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "debug" => Some(LevelFilter::Debug),
+        "info" => Some(LevelFilter::Info),
+        "warn" => Some(LevelFilter::Warn),
+        _ => None,
+    }
+}
+
+/// The level this crate logs at; dependencies always log at info.
+pub fn level() -> LevelFilter {
+    LevelFilter::iter()
+        .nth(CRATE_LEVEL.load(Ordering::Relaxed))
+        .unwrap_or(LevelFilter::Info)
+}
+
+/// Changes this crate's log level until the process restarts.
+pub fn set_level(level: LevelFilter) {
+    CRATE_LEVEL.store(level as usize, Ordering::Relaxed);
+    log::set_max_level(level.max(LevelFilter::Info));
 }
 
 pub fn init_from_environment() {
@@ -56,3 +123,39 @@ pub fn init_from_environment() {
         log::info!("Logger initialized");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_runtime_levels() {
+        assert_eq!(parse_runtime_level("DEBUG"), Some(LevelFilter::Debug));
+        assert_eq!(parse_runtime_level(" info "), Some(LevelFilter::Info));
+        assert_eq!(parse_runtime_level("warn"), Some(LevelFilter::Warn));
+        assert_eq!(parse_runtime_level("trace"), None);
+    }
+
+    #[test]
+    fn runtime_level_leaves_dependencies_at_info() {
+        let logger = ReloadableLogger {
+            inner: Builder::new()
+                .filter(Some(CRATE), LevelFilter::Trace)
+                .filter(None, LevelFilter::Info)
+                .build(),
+        };
+        let enabled = |target: &str, level: log::Level| {
+            logger.enabled(&Metadata::builder().target(target).level(level).build())
+        };
+
+        set_level(LevelFilter::Debug);
+        assert!(enabled("github_release_bot::poller", log::Level::Debug));
+        assert!(!enabled("hyper::client", log::Level::Debug));
+
+        set_level(LevelFilter::Warn);
+        assert!(!enabled("github_release_bot", log::Level::Info));
+        assert!(enabled("hyper::client", log::Level::Info));
+
+        set_level(LevelFilter::Info);
+    }
+}
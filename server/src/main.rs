@@ -1,14 +1,26 @@
 use std::sync::Arc;
+use std::time::Duration;
 use teloxide::prelude::*;
 
+mod api_tokens;
 mod bot;
+mod chat_settings;
 mod configuration;
 mod db;
 mod github;
+#[cfg(feature = "rest-api")]
+mod http;
 mod logger;
+mod maintenance;
+mod messages;
+mod notifications;
+mod packages;
 mod poller;
+mod tag_watches;
 mod tracked_repositories;
 mod utils;
+#[cfg(feature = "webhooks")]
+mod webhooks;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -27,23 +39,69 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     log::info!("Starting github release bot...");
 
     log::debug!("Loading configuration");
-    let config = configuration::Configuration::from_env();
+    let config = configuration::Configuration::from_file_and_env();
+    github::set_request_logging(config.github_request_log);
+    github::set_request_timeout(Duration::from_secs(config.github_request_timeout_secs));
 
     log::debug!("Initializing database");
     let pool = db::initialize_db(config.clone()).await?;
 
-    let bot = Bot::new(config.teloxide_token.clone());
+    let schedule = Arc::new(poller::PollSchedule::new(config.interval_secs));
 
-    let bot_state = Arc::new(bot::BotState {
-        db: pool.clone(),
-        config: config.clone(),
-    });
+    // Every bot gets its own dispatcher and poller over the shared database
+    let mut dispatchers = Vec::new();
+    #[cfg(feature = "rest-api")]
+    let mut pollers = http::BotPollers::new();
+    for (bot_id, token) in config.bots() {
+        let bot = Bot::new(token);
 
-    let polling_state = Arc::new(poller::AppState { db: pool.clone() });
-    let polling_bot = bot.clone();
-    poller::spawn(polling_state, polling_bot, config.clone()).await;
+        let bot_state = Arc::new(bot::BotState {
+            db: pool.clone(),
+            bot_id: bot_id.clone(),
+            config: config.clone(),
+            schedule: schedule.clone(),
+            pending_untracks: Default::default(),
+            menus: Default::default(),
+            started_at: std::time::Instant::now(),
+        });
 
-    bot::run(bot, bot_state).await;
+        let polling_state = Arc::new(poller::AppState {
+            db: pool.clone(),
+            bot_id: bot_id.clone(),
+            cache_write_batch_size: config.cache_write_batch_size,
+            release_history_limit: config.release_history_limit,
+            schedule: schedule.clone(),
+            pause_inaccessible: config.pause_inaccessible_repositories,
+            cycle_lock: Default::default(),
+        });
+        // The primary bot tells the operator that the deployment came up
+        if bot_id.is_empty() {
+            bot::announce_startup(&bot, &pool, &config).await;
+        }
+        #[cfg(feature = "rest-api")]
+        pollers.insert(bot_id, (bot.clone(), polling_state.clone()));
+        poller::spawn(polling_state, bot.clone(), config.clone()).await;
+
+        dispatchers.push(tokio::spawn(bot::run(bot, bot_state)));
+    }
+
+    maintenance::spawn(pool.clone(), config.clone()).await;
+
+    #[cfg(feature = "rest-api")]
+    http::spawn(pool.clone(), config.clone(), pollers).await;
+    #[cfg(not(feature = "rest-api"))]
+    if config.api_token.is_some() || config.github_webhook_secret.is_some() {
+        log::warn!(
+            "API_TOKEN or GITHUB_WEBHOOK_SECRET is set but this build does not include the rest-api feature; not listening on {}",
+            config.http_bind_addr
+        );
+    }
+
+    for dispatcher in dispatchers {
+        if let Err(e) = dispatcher.await {
+            log::error!("Bot dispatcher stopped: {}", e);
+        }
+    }
 
     Ok(())
 }
@@ -0,0 +1,62 @@
+mod orphans;
+mod retention;
+
+use chrono::{NaiveDate, Timelike, Utc};
+use sqlx::sqlite::SqlitePool;
+use tokio::time::{Duration, interval};
+
+use crate::configuration::Configuration;
+
+const MAINTENANCE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Hour (UTC) in which the daily pruning and compaction run, when few releases go out.
+const DAILY_MAINTENANCE_HOUR_UTC: u32 = 4;
+
+pub async fn spawn(db: SqlitePool, config: Configuration) {
+    tokio::spawn(async move {
+        run(db, config).await;
+    });
+}
+
+async fn run(db: SqlitePool, config: Configuration) {
+    log::info!("Starting maintenance task");
+
+    let mut ticker = interval(MAINTENANCE_INTERVAL);
+    let mut last_daily: Option<NaiveDate> = None;
+    loop {
+        ticker.tick().await;
+
+        match orphans::cleanup_orphans(&db).await {
+            Ok(0) => log::debug!("Maintenance found no orphaned rows"),
+            Ok(n) => log::info!("Maintenance removed {} orphaned rows", n),
+            Err(e) => log::warn!("Maintenance failed to clean up orphaned rows: {}", e),
+        }
+
+        let now = Utc::now();
+        if now.hour() == DAILY_MAINTENANCE_HOUR_UTC && last_daily != Some(now.date_naive()) {
+            last_daily = Some(now.date_naive());
+            run_daily(&db, &config).await;
+        }
+    }
+}
+
+/// Prunes rows past `RETENTION_DAYS` and, with `COMPACT_DATABASE`, rewrites the database
+/// file to give the space they took back.
+async fn run_daily(db: &SqlitePool, config: &Configuration) {
+    if config.retention_days > 0 {
+        let cutoff = Utc::now() - chrono::Duration::days(config.retention_days as i64);
+        match retention::prune_before(db, cutoff).await {
+            Ok(0) => log::debug!("Maintenance found no rows past the retention"),
+            Ok(n) => log::info!("Maintenance pruned {} rows past the retention", n),
+            Err(e) => log::warn!("Maintenance failed to prune old rows: {}", e),
+        }
+    }
+
+    if config.compact_database {
+        // VACUUM holds the database lock while it runs; busy writes retry meanwhile
+        match sqlx::query("VACUUM").execute(db).await {
+            Ok(_) => log::info!("Maintenance compacted the database"),
+            Err(e) => log::warn!("Maintenance failed to compact the database: {}", e),
+        }
+    }
+}
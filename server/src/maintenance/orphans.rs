@@ -0,0 +1,82 @@
+use sqlx::sqlite::SqlitePool;
+
+/// Deletes rows that point at a tracked repository that no longer exists, returning
+/// how many were removed.
+pub(crate) async fn cleanup_orphans(db: &SqlitePool) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM tracked_repository_releases
+        WHERE tracked_repository_id NOT IN (SELECT id FROM tracked_repositories)
+        "#,
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        // Allow seeding rows the way older code paths could leave them behind
+        sqlx::query("PRAGMA foreign_keys = OFF")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        pool
+    }
+
+    #[tokio::test]
+    async fn removes_only_orphaned_cache_rows() {
+        let pool = setup_pool().await;
+        let now = chrono::Utc::now();
+
+        sqlx::query(
+            "INSERT INTO tracked_repositories (id, repository_name, repository_url, chat_id, created_at, updated_at) \
+             VALUES ('live', 'live', 'https://github.com/owner/live', 1, ?1, ?1)",
+        )
+        .bind(now)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        for id in ["live", "gone-1", "gone-2"] {
+            sqlx::query(
+                "INSERT INTO tracked_repository_releases (tracked_repository_id, tag_name, first_seen_at) \
+                 VALUES (?1, 'v1', ?2)",
+            )
+            .bind(id)
+            .bind(now)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        let removed = cleanup_orphans(&pool).await.unwrap();
+        assert_eq!(removed, 2);
+
+        let remaining: Vec<String> =
+            sqlx::query_scalar("SELECT tracked_repository_id FROM tracked_repository_releases")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(remaining, vec!["live".to_string()]);
+
+        assert_eq!(cleanup_orphans(&pool).await.unwrap(), 0);
+    }
+}
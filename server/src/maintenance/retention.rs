@@ -0,0 +1,134 @@
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqlitePool;
+
+/// Rows deleted per statement, so the poller's writes can get in between batches.
+const PRUNE_BATCH_SIZE: i64 = 500;
+
+/// Deletes notification receipts sent and release history detected before `cutoff`,
+/// returning how many rows were removed. Pinned notifications are kept, since the next
+/// pinned release still has to unpin them.
+pub(crate) async fn prune_before(
+    db: &SqlitePool,
+    cutoff: DateTime<Utc>,
+) -> Result<u64, sqlx::Error> {
+    let statements = [
+        r#"
+        DELETE FROM notifications
+        WHERE rowid IN (
+            SELECT rowid FROM notifications WHERE sent_at < ?1 AND pinned = 0 LIMIT ?2
+        )
+        "#,
+        r#"
+        DELETE FROM release_history
+        WHERE rowid IN (
+            SELECT rowid FROM release_history WHERE detected_at < ?1 LIMIT ?2
+        )
+        "#,
+    ];
+
+    let mut removed = 0;
+    for statement in statements {
+        loop {
+            let result = sqlx::query(statement)
+                .bind(cutoff)
+                .bind(PRUNE_BATCH_SIZE)
+                .execute(db)
+                .await?;
+            removed += result.rows_affected();
+            if result.rows_affected() < PRUNE_BATCH_SIZE as u64 {
+                break;
+            }
+        }
+    }
+
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    async fn insert_receipt(pool: &SqlitePool, id: &str, sent_at: DateTime<Utc>, pinned: bool) {
+        sqlx::query(
+            "INSERT INTO notifications (id, tracked_repository_id, chat_id, tag_name, telegram_message_id, sent_at, pinned) \
+             VALUES (?1, 'repo', 1, 'v1', 10, ?2, ?3)",
+        )
+        .bind(id)
+        .bind(sent_at)
+        .bind(pinned)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn insert_history(pool: &SqlitePool, id: &str, detected_at: DateTime<Utc>) {
+        sqlx::query(
+            "INSERT INTO release_history (id, tracked_repository_id, tag_name, detected_at) \
+             VALUES (?1, 'repo', 'v1', ?2)",
+        )
+        .bind(id)
+        .bind(detected_at)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn prunes_rows_older_than_the_cutoff_except_pins() {
+        let pool = setup_pool().await;
+        let now = Utc::now();
+        sqlx::query(
+            "INSERT INTO tracked_repositories (id, repository_name, repository_url, chat_id, created_at, updated_at) \
+             VALUES ('repo', 'repo', 'https://github.com/owner/repo', 1, ?1, ?1)",
+        )
+        .bind(now)
+        .execute(&pool)
+        .await
+        .unwrap();
+        let old = now - Duration::days(100);
+        insert_receipt(&pool, "old", old, false).await;
+        insert_receipt(&pool, "old-pinned", old, true).await;
+        insert_receipt(&pool, "recent", now, false).await;
+        insert_history(&pool, "old", old).await;
+        insert_history(&pool, "recent", now).await;
+
+        let removed = prune_before(&pool, now - Duration::days(30)).await.unwrap();
+
+        assert_eq!(removed, 2);
+        let receipts: Vec<String> = sqlx::query_scalar("SELECT id FROM notifications ORDER BY id")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(
+            receipts,
+            vec!["old-pinned".to_string(), "recent".to_string()]
+        );
+        let history: Vec<String> = sqlx::query_scalar("SELECT id FROM release_history")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(history, vec!["recent".to_string()]);
+
+        assert_eq!(
+            prune_before(&pool, now - Duration::days(30)).await.unwrap(),
+            0
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use crate::chat_settings::Lang;
+
+use super::Key;
+
+/// What the commands switching how the chat is notified answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatText {
+    /// Placeholder: `{state}`, the `on` or `off` the command takes.
+    RemovedReleasesState,
+    RemovedReleasesOn,
+    RemovedReleasesOff,
+    /// Placeholder: `{state}`, the `on` or `off` the command takes.
+    PreviousTagState,
+    PreviousTagOn,
+    PreviousTagOff,
+    /// Placeholder: `{state}`, the `on` or `off` the command takes.
+    PinState,
+    PinOn,
+    PinOff,
+    /// Placeholder: `{state}`, the `on` or `off` the command takes.
+    FirstSeenState,
+    FirstSeenOn,
+    FirstSeenOff,
+    /// Placeholder: `{state}`, the `on` or `off` the command takes.
+    ReactionsState,
+    /// Placeholder: `{reaction}`.
+    ReactionsOn,
+    ReactionsOff,
+    /// Placeholder: `{state}`, the `on` or `off` the command takes.
+    ListLinksState,
+    ListLinksOn,
+    ListLinksOff,
+    /// Placeholder: `{notes}`.
+    NotesState,
+    NotesUnknown,
+    /// Placeholder: `{notes}`.
+    NotesSet,
+}
+
+impl Key for ChatText {
+    fn text(self, lang: Lang) -> &'static str {
+        match (self, lang) {
+            (Self::RemovedReleasesState, Lang::En) => {
+                "Notices about removed releases are {state}. Change it with /removed_releases on or off."
+            }
+            (Self::RemovedReleasesState, Lang::It) => {
+                "Gli avvisi sulle release rimosse sono {state}. Cambialo con /removed_releases on oppure off."
+            }
+            (Self::RemovedReleasesState, Lang::De) => {
+                "Hinweise auf entfernte Releases sind {state}. Ändere das mit /removed_releases on oder off."
+            }
+
+            (Self::RemovedReleasesOn, Lang::En) => {
+                "You will be told when the latest release of a repository is deleted on GitHub."
+            }
+            (Self::RemovedReleasesOn, Lang::It) => {
+                "Ti avviserò quando l'ultima release di un repository viene eliminata su GitHub."
+            }
+            (Self::RemovedReleasesOn, Lang::De) => {
+                "Du wirst benachrichtigt, wenn das neueste Release eines Repositorys auf GitHub gelöscht wird."
+            }
+
+            (Self::RemovedReleasesOff, Lang::En) => "Deleted releases are no longer announced.",
+            (Self::RemovedReleasesOff, Lang::It) => {
+                "Le release eliminate non vengono più annunciate."
+            }
+            (Self::RemovedReleasesOff, Lang::De) => {
+                "Gelöschte Releases werden nicht mehr angekündigt."
+            }
+
+            (Self::PreviousTagState, Lang::En) => {
+                "Showing the previous tag is {state}. Change it with /previous_tag on or off."
+            }
+            (Self::PreviousTagState, Lang::It) => {
+                "Mostrare il tag precedente è {state}. Cambialo con /previous_tag on oppure off."
+            }
+            (Self::PreviousTagState, Lang::De) => {
+                "Das Anzeigen des vorherigen Tags ist {state}. Ändere das mit /previous_tag on oder off."
+            }
+
+            (Self::PreviousTagOn, Lang::En) => {
+                "Notifications now show the previous tag, as in v1.0.0 → v1.1.0."
+            }
+            (Self::PreviousTagOn, Lang::It) => {
+                "Le notifiche ora mostrano il tag precedente, come in v1.0.0 → v1.1.0."
+            }
+            (Self::PreviousTagOn, Lang::De) => {
+                "Benachrichtigungen zeigen jetzt das vorherige Tag, etwa v1.0.0 → v1.1.0."
+            }
+
+            (Self::PreviousTagOff, Lang::En) => "Notifications now show only the new tag.",
+            (Self::PreviousTagOff, Lang::It) => "Le notifiche ora mostrano solo il nuovo tag.",
+            (Self::PreviousTagOff, Lang::De) => "Benachrichtigungen zeigen jetzt nur das neue Tag.",
+
+            (Self::PinState, Lang::En) => {
+                "Pinning the latest release is {state}. Change it with /pin_releases on or off."
+            }
+            (Self::PinState, Lang::It) => {
+                "Fissare l'ultima release è {state}. Cambialo con /pin_releases on oppure off."
+            }
+            (Self::PinState, Lang::De) => {
+                "Das Anheften des neuesten Releases ist {state}. Ändere das mit /pin_releases on oder off."
+            }
+
+            (Self::PinOn, Lang::En) => {
+                "The latest release notification will be pinned. Make sure the bot may pin messages here."
+            }
+            (Self::PinOn, Lang::It) => {
+                "La notifica dell'ultima release sarà fissata. Assicurati che il bot possa fissare messaggi qui."
+            }
+            (Self::PinOn, Lang::De) => {
+                "Die Benachrichtigung zum neuesten Release wird angeheftet. Stelle sicher, dass der Bot hier Nachrichten anheften darf."
+            }
+
+            (Self::PinOff, Lang::En) => "Release notifications are no longer pinned.",
+            (Self::PinOff, Lang::It) => "Le notifiche delle release non vengono più fissate.",
+            (Self::PinOff, Lang::De) => "Release-Benachrichtigungen werden nicht mehr angeheftet.",
+
+            (Self::FirstSeenState, Lang::En) => {
+                "Announcing the current release of newly tracked repositories is {state}. Change it with /first_seen on or off."
+            }
+            (Self::FirstSeenState, Lang::It) => {
+                "Annunciare la release attuale dei repository appena seguiti è {state}. Cambialo con /first_seen on oppure off."
+            }
+            (Self::FirstSeenState, Lang::De) => {
+                "Das Ankündigen des aktuellen Releases neu verfolgter Repositories ist {state}. Ändere das mit /first_seen on oder off."
+            }
+
+            (Self::FirstSeenOn, Lang::En) => {
+                "Repositories tracked from now on announce their current release."
+            }
+            (Self::FirstSeenOn, Lang::It) => {
+                "I repository seguiti da ora in poi annunciano la loro release attuale."
+            }
+            (Self::FirstSeenOn, Lang::De) => {
+                "Ab jetzt verfolgte Repositories kündigen ihr aktuelles Release an."
+            }
+
+            (Self::FirstSeenOff, Lang::En) => {
+                "Repositories tracked from now on only announce releases published later."
+            }
+            (Self::FirstSeenOff, Lang::It) => {
+                "I repository seguiti da ora in poi annunciano solo le release pubblicate dopo."
+            }
+            (Self::FirstSeenOff, Lang::De) => {
+                "Ab jetzt verfolgte Repositories kündigen nur später veröffentlichte Releases an."
+            }
+
+            (Self::ReactionsState, Lang::En) => {
+                "Reactions to /track are {state}. Change it with /reactions on or off."
+            }
+            (Self::ReactionsState, Lang::It) => {
+                "Le reazioni a /track sono {state}. Cambialo con /reactions on oppure off."
+            }
+            (Self::ReactionsState, Lang::De) => {
+                "Reaktionen auf /track sind {state}. Ändere das mit /reactions on oder off."
+            }
+
+            (Self::ReactionsOn, Lang::En) => {
+                "Successful /track commands now get a {reaction} reaction."
+            }
+            (Self::ReactionsOn, Lang::It) => {
+                "I comandi /track riusciti ora ricevono una reazione {reaction}."
+            }
+            (Self::ReactionsOn, Lang::De) => {
+                "Erfolgreiche /track-Befehle bekommen jetzt eine {reaction}-Reaktion."
+            }
+
+            (Self::ReactionsOff, Lang::En) => {
+                "Successful /track commands no longer get a reaction."
+            }
+            (Self::ReactionsOff, Lang::It) => {
+                "I comandi /track riusciti non ricevono più una reazione."
+            }
+            (Self::ReactionsOff, Lang::De) => {
+                "Erfolgreiche /track-Befehle bekommen keine Reaktion mehr."
+            }
+
+            (Self::ListLinksState, Lang::En) => {
+                "Links in /list are {state}. Change it with /list_links on or off."
+            }
+            (Self::ListLinksState, Lang::It) => {
+                "I link in /list sono {state}. Cambialo con /list_links on oppure off."
+            }
+            (Self::ListLinksState, Lang::De) => {
+                "Links in /list sind {state}. Ändere das mit /list_links on oder off."
+            }
+
+            (Self::ListLinksOn, Lang::En) => "/list now links repositories and releases.",
+            (Self::ListLinksOn, Lang::It) => "/list ora collega repository e release.",
+            (Self::ListLinksOn, Lang::De) => "/list verlinkt jetzt Repositories und Releases.",
+
+            (Self::ListLinksOff, Lang::En) => "/list is now sent as plain text without links.",
+            (Self::ListLinksOff, Lang::It) => {
+                "/list ora viene inviato come testo semplice senza link."
+            }
+            (Self::ListLinksOff, Lang::De) => {
+                "/list wird jetzt als reiner Text ohne Links gesendet."
+            }
+
+            (Self::NotesState, Lang::En) => {
+                "Release notes in this chat are {notes}. Change it with /notes on, off or dedupe."
+            }
+            (Self::NotesState, Lang::It) => {
+                "Le note di rilascio in questa chat sono {notes}. Cambialo con /notes on, off oppure dedupe."
+            }
+            (Self::NotesState, Lang::De) => {
+                "Release Notes in diesem Chat sind {notes}. Ändere das mit /notes on, off oder dedupe."
+            }
+
+            (Self::NotesUnknown, Lang::En) => "Unknown option. Use on, off or dedupe.",
+            (Self::NotesUnknown, Lang::It) => "Opzione sconosciuta. Usa on, off oppure dedupe.",
+            (Self::NotesUnknown, Lang::De) => "Unbekannte Option. Nutze on, off oder dedupe.",
+
+            (Self::NotesSet, Lang::En) => "Release notes in this chat are now {notes}.",
+            (Self::NotesSet, Lang::It) => "Le note di rilascio in questa chat ora sono {notes}.",
+            (Self::NotesSet, Lang::De) => "Release Notes in diesem Chat sind jetzt {notes}.",
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use crate::chat_settings::Lang;
+
+use super::Key;
+
+/// What commands answer whatever they do: usage, access and lookup errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandText {
+    /// Placeholder: `{usage}`.
+    Usage,
+    /// Placeholders: `{usage}`, `{example}`.
+    UsageExample,
+    NotAuthorized,
+    AdminsOnly,
+    /// Placeholder: `{error}`.
+    QueryFailed,
+    /// Placeholder: `{alias}`.
+    UnknownAlias,
+    /// Placeholder: `{repository}`.
+    NotTracking,
+    /// Placeholder: `{error}`.
+    LoadSettingsFailed,
+    /// Placeholder: `{error}`.
+    SaveSettingsFailed,
+    /// Placeholder: `{error}`.
+    LoadChatSettingsFailed,
+    /// Placeholder: `{error}`.
+    SaveChatSettingsFailed,
+    ChooseOnOrOff,
+    DurationTooLong,
+    /// Placeholder: `{input}`.
+    InvalidDuration,
+    /// Placeholder: `{input}`.
+    DurationOverflow,
+    /// Placeholder: `{max}`.
+    PatternTooLong,
+    /// Placeholder: `{max}`.
+    PrefixTooLong,
+    /// Placeholders: `{repository}`, `{error}`.
+    LookupFailed,
+    /// Placeholder: `{url}`.
+    NoOwnerAndRepository,
+    HistoryOff,
+    /// Placeholder: `{error}`.
+    HistoryFailed,
+    /// Placeholder: `{repository}`.
+    NoHistoryYet,
+    /// Placeholder: `{error}`.
+    LastCheckFailed,
+    /// Placeholder: `{error}`.
+    CachedReleaseFailed,
+}
+
+impl Key for CommandText {
+    fn text(self, lang: Lang) -> &'static str {
+        match (self, lang) {
+            (Self::Usage, Lang::En) => "Usage: {usage}",
+            (Self::Usage, Lang::It) => "Uso: {usage}",
+            (Self::Usage, Lang::De) => "Verwendung: {usage}",
+
+            (Self::UsageExample, Lang::En) => "Usage: {usage}, e.g. {example}",
+            (Self::UsageExample, Lang::It) => "Uso: {usage}, ad es. {example}",
+            (Self::UsageExample, Lang::De) => "Verwendung: {usage}, z. B. {example}",
+
+            (Self::NotAuthorized, Lang::En) => {
+                "Sorry, this chat is not authorized to use this bot."
+            }
+            (Self::NotAuthorized, Lang::It) => {
+                "Spiacente, questa chat non è autorizzata a usare questo bot."
+            }
+            (Self::NotAuthorized, Lang::De) => {
+                "Dieser Chat darf diesen Bot leider nicht verwenden."
+            }
+
+            (Self::AdminsOnly, Lang::En) => "This command is restricted to bot administrators.",
+            (Self::AdminsOnly, Lang::It) => {
+                "Questo comando è riservato agli amministratori del bot."
+            }
+            (Self::AdminsOnly, Lang::De) => {
+                "Dieser Befehl ist den Administratoren des Bots vorbehalten."
+            }
+
+            (Self::QueryFailed, Lang::En) => "Failed to query repository: {error}",
+            (Self::QueryFailed, Lang::It) => "Impossibile cercare il repository: {error}",
+            (Self::QueryFailed, Lang::De) => {
+                "Das Repository konnte nicht abgefragt werden: {error}"
+            }
+
+            (Self::UnknownAlias, Lang::En) => {
+                "No repository of this chat is called {alias}; give its URL or set an alias with /alias."
+            }
+            (Self::UnknownAlias, Lang::It) => {
+                "Nessun repository di questa chat si chiama {alias}; indica il suo URL o imposta un alias con /alias."
+            }
+            (Self::UnknownAlias, Lang::De) => {
+                "Kein Repository dieses Chats heißt {alias}; gib seine URL an oder setze einen Alias mit /alias."
+            }
+
+            (Self::NotTracking, Lang::En) => "This chat is not tracking {repository}.",
+            (Self::NotTracking, Lang::It) => "Questa chat non segue {repository}.",
+            (Self::NotTracking, Lang::De) => "Dieser Chat verfolgt {repository} nicht.",
+
+            (Self::LoadSettingsFailed, Lang::En) => "Failed to load repository settings: {error}",
+            (Self::LoadSettingsFailed, Lang::It) => {
+                "Impossibile caricare le impostazioni del repository: {error}"
+            }
+            (Self::LoadSettingsFailed, Lang::De) => {
+                "Die Einstellungen des Repositorys konnten nicht geladen werden: {error}"
+            }
+
+            (Self::SaveSettingsFailed, Lang::En) => "Failed to save repository settings: {error}",
+            (Self::SaveSettingsFailed, Lang::It) => {
+                "Impossibile salvare le impostazioni del repository: {error}"
+            }
+            (Self::SaveSettingsFailed, Lang::De) => {
+                "Die Einstellungen des Repositorys konnten nicht gespeichert werden: {error}"
+            }
+
+            (Self::LoadChatSettingsFailed, Lang::En) => "Failed to load chat settings: {error}",
+            (Self::LoadChatSettingsFailed, Lang::It) => {
+                "Impossibile caricare le impostazioni della chat: {error}"
+            }
+            (Self::LoadChatSettingsFailed, Lang::De) => {
+                "Die Einstellungen des Chats konnten nicht geladen werden: {error}"
+            }
+
+            (Self::SaveChatSettingsFailed, Lang::En) => "Failed to save chat settings: {error}",
+            (Self::SaveChatSettingsFailed, Lang::It) => {
+                "Impossibile salvare le impostazioni della chat: {error}"
+            }
+            (Self::SaveChatSettingsFailed, Lang::De) => {
+                "Die Einstellungen des Chats konnten nicht gespeichert werden: {error}"
+            }
+
+            (Self::ChooseOnOrOff, Lang::En) => "Please choose on or off.",
+            (Self::ChooseOnOrOff, Lang::It) => "Scegli on oppure off.",
+            (Self::ChooseOnOrOff, Lang::De) => "Bitte wähle on oder off.",
+
+            (Self::DurationTooLong, Lang::En) => "That duration is too long.",
+            (Self::DurationTooLong, Lang::It) => "Questa durata è troppo lunga.",
+            (Self::DurationTooLong, Lang::De) => "Diese Dauer ist zu lang.",
+
+            (Self::InvalidDuration, Lang::En) => {
+                "Invalid duration '{input}'. Use e.g. 30m, 2h or 7d."
+            }
+            (Self::InvalidDuration, Lang::It) => {
+                "Durata '{input}' non valida. Usa ad esempio 30m, 2h o 7d."
+            }
+            (Self::InvalidDuration, Lang::De) => {
+                "Ungültige Dauer '{input}'. Verwende z. B. 30m, 2h oder 7d."
+            }
+
+            (Self::DurationOverflow, Lang::En) => "Duration '{input}' is too long.",
+            (Self::DurationOverflow, Lang::It) => "La durata '{input}' è troppo lunga.",
+            (Self::DurationOverflow, Lang::De) => "Die Dauer '{input}' ist zu lang.",
+
+            (Self::PatternTooLong, Lang::En) => "Please keep the pattern under {max} characters.",
+            (Self::PatternTooLong, Lang::It) => "Il pattern deve restare sotto i {max} caratteri.",
+            (Self::PatternTooLong, Lang::De) => "Das Muster muss unter {max} Zeichen bleiben.",
+
+            (Self::PrefixTooLong, Lang::En) => "Please keep the prefix under {max} characters.",
+            (Self::PrefixTooLong, Lang::It) => "Il prefisso deve restare sotto i {max} caratteri.",
+            (Self::PrefixTooLong, Lang::De) => "Das Präfix muss unter {max} Zeichen bleiben.",
+
+            (Self::LookupFailed, Lang::En) => "Failed to look up {repository}: {error}",
+            (Self::LookupFailed, Lang::It) => "Impossibile consultare {repository}: {error}",
+            (Self::LookupFailed, Lang::De) => "{repository} konnte nicht abgefragt werden: {error}",
+
+            (Self::NoOwnerAndRepository, Lang::En) => {
+                "Could not find an owner and repository in {url}."
+            }
+            (Self::NoOwnerAndRepository, Lang::It) => {
+                "Impossibile trovare proprietario e repository in {url}."
+            }
+            (Self::NoOwnerAndRepository, Lang::De) => {
+                "In {url} wurden kein Eigentümer und kein Repository gefunden."
+            }
+
+            (Self::HistoryOff, Lang::En) => {
+                "Release history is turned off; set RELEASE_HISTORY_LIMIT to keep it."
+            }
+            (Self::HistoryOff, Lang::It) => {
+                "Lo storico delle release è disattivato; imposta RELEASE_HISTORY_LIMIT per conservarlo."
+            }
+            (Self::HistoryOff, Lang::De) => {
+                "Der Release-Verlauf ist ausgeschaltet; setze RELEASE_HISTORY_LIMIT, um ihn zu behalten."
+            }
+
+            (Self::HistoryFailed, Lang::En) => "Failed to load release history: {error}",
+            (Self::HistoryFailed, Lang::It) => {
+                "Impossibile caricare lo storico delle release: {error}"
+            }
+            (Self::HistoryFailed, Lang::De) => {
+                "Der Release-Verlauf konnte nicht geladen werden: {error}"
+            }
+
+            (Self::NoHistoryYet, Lang::En) => "No releases of {repository} have been recorded yet.",
+            (Self::NoHistoryYet, Lang::It) => {
+                "Nessuna release di {repository} è stata ancora registrata."
+            }
+            (Self::NoHistoryYet, Lang::De) => {
+                "Von {repository} wurden noch keine Releases aufgezeichnet."
+            }
+
+            (Self::LastCheckFailed, Lang::En) => "Failed to load the last check: {error}",
+            (Self::LastCheckFailed, Lang::It) => "Impossibile caricare l'ultimo controllo: {error}",
+            (Self::LastCheckFailed, Lang::De) => {
+                "Die letzte Abfrage konnte nicht geladen werden: {error}"
+            }
+
+            (Self::CachedReleaseFailed, Lang::En) => "Failed to load the cached release: {error}",
+            (Self::CachedReleaseFailed, Lang::It) => {
+                "Impossibile caricare la release in cache: {error}"
+            }
+            (Self::CachedReleaseFailed, Lang::De) => {
+                "Das zwischengespeicherte Release konnte nicht geladen werden: {error}"
+            }
+        }
+    }
+}
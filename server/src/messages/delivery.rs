@@ -0,0 +1,277 @@
+use crate::chat_settings::Lang;
+
+use super::Key;
+
+/// What the commands shaping notifications and where they are sent answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryText {
+    /// Placeholder: `{template}`.
+    TemplateCurrent,
+    /// Placeholder: `{placeholders}`.
+    TemplateNone,
+    /// Placeholder: `{max}`.
+    TemplateTooLong,
+    TemplateSet,
+    TemplateCleared,
+    NoTemplate,
+    /// Placeholder: `{text}`.
+    PrefixCurrent,
+    /// Placeholder: `{text}`.
+    SuffixCurrent,
+    NoPrefix,
+    NoSuffix,
+    /// Placeholder: `{max}`.
+    PrefixTooLong,
+    /// Placeholder: `{max}`.
+    SuffixTooLong,
+    /// Placeholder: `{text}`.
+    PrefixSet,
+    /// Placeholder: `{text}`.
+    SuffixSet,
+    PrefixRemoved,
+    SuffixRemoved,
+    /// Placeholder: `{kind}`.
+    WebhookCurrent,
+    WebhookNone,
+    WebhookOff,
+    /// Placeholder: `{kind}`.
+    WebhookUnknownKind,
+    WebhookNotHttps,
+    /// Placeholder: `{kind}`.
+    WebhookSet,
+    WebhooksUnsupported,
+    /// Placeholder: `{error}`.
+    TokenLoadFailed,
+    /// Placeholder: `{created}`.
+    TokenExists,
+    NoToken,
+    /// Placeholder: `{error}`.
+    TokenSaveFailed,
+    /// Placeholders: `{token}`, `{seconds}`.
+    NewToken,
+    RestApiUnsupported,
+}
+
+impl Key for DeliveryText {
+    fn text(self, lang: Lang) -> &'static str {
+        match (self, lang) {
+            (Self::TemplateCurrent, Lang::En) => {
+                "Release notifications use the template \"{template}\"."
+            }
+            (Self::TemplateCurrent, Lang::It) => {
+                "Le notifiche delle release usano il modello \"{template}\"."
+            }
+            (Self::TemplateCurrent, Lang::De) => {
+                "Release-Benachrichtigungen nutzen die Vorlage \"{template}\"."
+            }
+
+            (Self::TemplateNone, Lang::En) => {
+                "Release notifications use the default text. Set a template with /template <text> using {placeholders}."
+            }
+            (Self::TemplateNone, Lang::It) => {
+                "Le notifiche delle release usano il testo predefinito. Imposta un modello con /template <testo> usando {placeholders}."
+            }
+            (Self::TemplateNone, Lang::De) => {
+                "Release-Benachrichtigungen nutzen den Standardtext. Lege eine Vorlage mit /template <text> fest und nutze {placeholders}."
+            }
+
+            (Self::TemplateTooLong, Lang::En) => {
+                "The template can be at most {max} characters long."
+            }
+            (Self::TemplateTooLong, Lang::It) => {
+                "Il modello può essere lungo al massimo {max} caratteri."
+            }
+            (Self::TemplateTooLong, Lang::De) => {
+                "Die Vorlage darf höchstens {max} Zeichen lang sein."
+            }
+
+            (Self::TemplateSet, Lang::En) => {
+                "Release notifications will use the new template. Try /preview_template."
+            }
+            (Self::TemplateSet, Lang::It) => {
+                "Le notifiche delle release useranno il nuovo modello. Prova /preview_template."
+            }
+            (Self::TemplateSet, Lang::De) => {
+                "Release-Benachrichtigungen nutzen die neue Vorlage. Probiere /preview_template."
+            }
+
+            (Self::TemplateCleared, Lang::En) => {
+                "Release notifications use the default text again."
+            }
+            (Self::TemplateCleared, Lang::It) => {
+                "Le notifiche delle release usano di nuovo il testo predefinito."
+            }
+            (Self::TemplateCleared, Lang::De) => {
+                "Release-Benachrichtigungen nutzen wieder den Standardtext."
+            }
+
+            (Self::NoTemplate, Lang::En) => {
+                "This chat has no template. Set one with /template <text>."
+            }
+            (Self::NoTemplate, Lang::It) => {
+                "Questa chat non ha un modello. Impostalo con /template <testo>."
+            }
+            (Self::NoTemplate, Lang::De) => {
+                "Dieser Chat hat keine Vorlage. Lege eine mit /template <text> fest."
+            }
+
+            (Self::PrefixCurrent, Lang::En) => "Notifications use the prefix \"{text}\".",
+            (Self::PrefixCurrent, Lang::It) => "Le notifiche usano il prefisso \"{text}\".",
+            (Self::PrefixCurrent, Lang::De) => "Benachrichtigungen nutzen das Präfix \"{text}\".",
+
+            (Self::SuffixCurrent, Lang::En) => "Notifications use the suffix \"{text}\".",
+            (Self::SuffixCurrent, Lang::It) => "Le notifiche usano il suffisso \"{text}\".",
+            (Self::SuffixCurrent, Lang::De) => "Benachrichtigungen nutzen das Suffix \"{text}\".",
+
+            (Self::NoPrefix, Lang::En) => {
+                "Notifications have no prefix. Set one with /prefix <text>."
+            }
+            (Self::NoPrefix, Lang::It) => {
+                "Le notifiche non hanno un prefisso. Impostalo con /prefix <testo>."
+            }
+            (Self::NoPrefix, Lang::De) => {
+                "Benachrichtigungen haben kein Präfix. Lege eins mit /prefix <text> fest."
+            }
+
+            (Self::NoSuffix, Lang::En) => {
+                "Notifications have no suffix. Set one with /suffix <text>."
+            }
+            (Self::NoSuffix, Lang::It) => {
+                "Le notifiche non hanno un suffisso. Impostalo con /suffix <testo>."
+            }
+            (Self::NoSuffix, Lang::De) => {
+                "Benachrichtigungen haben kein Suffix. Lege eins mit /suffix <text> fest."
+            }
+
+            (Self::PrefixTooLong, Lang::En) => "The prefix can be at most {max} characters long.",
+            (Self::PrefixTooLong, Lang::It) => {
+                "Il prefisso può essere lungo al massimo {max} caratteri."
+            }
+            (Self::PrefixTooLong, Lang::De) => "Das Präfix darf höchstens {max} Zeichen lang sein.",
+
+            (Self::SuffixTooLong, Lang::En) => "The suffix can be at most {max} characters long.",
+            (Self::SuffixTooLong, Lang::It) => {
+                "Il suffisso può essere lungo al massimo {max} caratteri."
+            }
+            (Self::SuffixTooLong, Lang::De) => "Das Suffix darf höchstens {max} Zeichen lang sein.",
+
+            (Self::PrefixSet, Lang::En) => "Notifications will now use the prefix \"{text}\".",
+            (Self::PrefixSet, Lang::It) => "Le notifiche ora useranno il prefisso \"{text}\".",
+            (Self::PrefixSet, Lang::De) => "Benachrichtigungen nutzen jetzt das Präfix \"{text}\".",
+
+            (Self::SuffixSet, Lang::En) => "Notifications will now use the suffix \"{text}\".",
+            (Self::SuffixSet, Lang::It) => "Le notifiche ora useranno il suffisso \"{text}\".",
+            (Self::SuffixSet, Lang::De) => "Benachrichtigungen nutzen jetzt das Suffix \"{text}\".",
+
+            (Self::PrefixRemoved, Lang::En) => "Removed the notification prefix.",
+            (Self::PrefixRemoved, Lang::It) => "Rimosso il prefisso delle notifiche.",
+            (Self::PrefixRemoved, Lang::De) => "Das Präfix der Benachrichtigungen wurde entfernt.",
+
+            (Self::SuffixRemoved, Lang::En) => "Removed the notification suffix.",
+            (Self::SuffixRemoved, Lang::It) => "Rimosso il suffisso delle notifiche.",
+            (Self::SuffixRemoved, Lang::De) => "Das Suffix der Benachrichtigungen wurde entfernt.",
+
+            (Self::WebhookCurrent, Lang::En) => {
+                "Release notifications are mirrored to a {kind} webhook."
+            }
+            (Self::WebhookCurrent, Lang::It) => {
+                "Le notifiche delle release sono replicate su un webhook {kind}."
+            }
+            (Self::WebhookCurrent, Lang::De) => {
+                "Release-Benachrichtigungen werden an einen {kind}-Webhook gespiegelt."
+            }
+
+            (Self::WebhookNone, Lang::En) => {
+                "Release notifications are not mirrored anywhere. Use /webhook slack <url> or /webhook discord <url>."
+            }
+            (Self::WebhookNone, Lang::It) => {
+                "Le notifiche delle release non sono replicate da nessuna parte. Usa /webhook slack <url> oppure /webhook discord <url>."
+            }
+            (Self::WebhookNone, Lang::De) => {
+                "Release-Benachrichtigungen werden nirgends gespiegelt. Nutze /webhook slack <url> oder /webhook discord <url>."
+            }
+
+            (Self::WebhookOff, Lang::En) => "Release notifications are no longer mirrored.",
+            (Self::WebhookOff, Lang::It) => "Le notifiche delle release non sono più replicate.",
+            (Self::WebhookOff, Lang::De) => {
+                "Release-Benachrichtigungen werden nicht mehr gespiegelt."
+            }
+
+            (Self::WebhookUnknownKind, Lang::En) => {
+                "Unknown webhook type \"{kind}\". Use slack or discord."
+            }
+            (Self::WebhookUnknownKind, Lang::It) => {
+                "Tipo di webhook sconosciuto \"{kind}\". Usa slack oppure discord."
+            }
+            (Self::WebhookUnknownKind, Lang::De) => {
+                "Unbekannter Webhook-Typ \"{kind}\". Nutze slack oder discord."
+            }
+
+            (Self::WebhookNotHttps, Lang::En) => "The webhook URL must be an https:// URL.",
+            (Self::WebhookNotHttps, Lang::It) => "L'URL del webhook deve essere un URL https://.",
+            (Self::WebhookNotHttps, Lang::De) => "Die Webhook-URL muss eine https://-URL sein.",
+
+            (Self::WebhookSet, Lang::En) => {
+                "Release notifications will also be sent to the {kind} webhook."
+            }
+            (Self::WebhookSet, Lang::It) => {
+                "Le notifiche delle release saranno inviate anche al webhook {kind}."
+            }
+            (Self::WebhookSet, Lang::De) => {
+                "Release-Benachrichtigungen werden auch an den {kind}-Webhook gesendet."
+            }
+
+            (Self::WebhooksUnsupported, Lang::En) => "This build does not include webhook support.",
+            (Self::WebhooksUnsupported, Lang::It) => {
+                "Questa build non include il supporto ai webhook."
+            }
+            (Self::WebhooksUnsupported, Lang::De) => {
+                "Dieser Build enthält keine Webhook-Unterstützung."
+            }
+
+            (Self::TokenLoadFailed, Lang::En) => "Failed to load the API token: {error}",
+            (Self::TokenLoadFailed, Lang::It) => "Impossibile caricare il token API: {error}",
+            (Self::TokenLoadFailed, Lang::De) => "API-Token konnte nicht geladen werden: {error}",
+
+            (Self::TokenExists, Lang::En) => {
+                "This chat has an API token, created {created}. Tokens are stored hashed and can't be shown again; use /token rotate to get a new one."
+            }
+            (Self::TokenExists, Lang::It) => {
+                "Questa chat ha un token API, creato il {created}. I token sono salvati come hash e non possono essere mostrati di nuovo; usa /token rotate per averne uno nuovo."
+            }
+            (Self::TokenExists, Lang::De) => {
+                "Dieser Chat hat ein API-Token, erstellt am {created}. Tokens werden gehasht gespeichert und können nicht erneut angezeigt werden; nutze /token rotate für ein neues."
+            }
+
+            (Self::NoToken, Lang::En) => {
+                "This chat has no API token yet. Create one with /token rotate."
+            }
+            (Self::NoToken, Lang::It) => {
+                "Questa chat non ha ancora un token API. Creane uno con /token rotate."
+            }
+            (Self::NoToken, Lang::De) => {
+                "Dieser Chat hat noch kein API-Token. Erstelle eins mit /token rotate."
+            }
+
+            (Self::TokenSaveFailed, Lang::En) => "Failed to save the API token: {error}",
+            (Self::TokenSaveFailed, Lang::It) => "Impossibile salvare il token API: {error}",
+            (Self::TokenSaveFailed, Lang::De) => {
+                "API-Token konnte nicht gespeichert werden: {error}"
+            }
+
+            (Self::NewToken, Lang::En) => {
+                "New API token for this chat (the previous one no longer works): {token}\nSend it as Authorization: Bearer <token>. This message is deleted in {seconds} seconds."
+            }
+            (Self::NewToken, Lang::It) => {
+                "Nuovo token API per questa chat (il precedente non funziona più): {token}\nInvialo come Authorization: Bearer <token>. Questo messaggio sarà eliminato tra {seconds} secondi."
+            }
+            (Self::NewToken, Lang::De) => {
+                "Neues API-Token für diesen Chat (das vorherige funktioniert nicht mehr): {token}\nSende es als Authorization: Bearer <token>. Diese Nachricht wird in {seconds} Sekunden gelöscht."
+            }
+
+            (Self::RestApiUnsupported, Lang::En) => "This build does not include the REST API.",
+            (Self::RestApiUnsupported, Lang::It) => "Questa build non include la REST API.",
+            (Self::RestApiUnsupported, Lang::De) => "Dieser Build enthält die REST-API nicht.",
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use crate::chat_settings::Lang;
+
+use super::Key;
+
+/// What the commands reporting on the bot, its GitHub access and the chats it serves answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InfoText {
+    OnlyCommands,
+    CheckUrlMissing,
+    NoPublishedVersion,
+    NoReleaseOrTag,
+    /// Placeholder: `{error}`.
+    LookupError,
+    /// Placeholders: `{registry}`, `{package}`, `{latest}`.
+    CheckPackage,
+    /// Placeholders: `{owner}`, `{repo}`, `{base}`, `{latest}`.
+    CheckRepository,
+    /// Placeholder: `{error}`.
+    RateLimitFailed,
+    /// Placeholders: `{remaining}`, `{limit}`, `{reset}`.
+    Quota,
+    QuotaUnauthenticated,
+    /// Placeholders: `{week}`, `{month}`.
+    StatsTotals,
+    StatsByRepository,
+    /// Placeholder: `{count}`.
+    StatsMore,
+    /// Placeholder: `{error}`.
+    StatsFailed,
+    /// Placeholders: `{registry}`, `{package}`.
+    AuthPackage,
+    /// Placeholder: `{repository}`.
+    AuthGlobal,
+    /// Placeholder: `{repository}`.
+    AuthAnonymous,
+    /// Placeholder: `{input}`.
+    NotAChatId,
+    /// Placeholders: `{chat}`, `{error}`.
+    ChatListFailed,
+    /// Placeholders: `{a}`, `{b}`.
+    TrackedByBoth,
+    /// Placeholder: `{chat}`.
+    OnlyChat,
+    /// Placeholder: `{title}`.
+    SectionEmpty,
+    /// Placeholders: `{level}`, `{levels}`.
+    LogLevelIs,
+    /// Placeholder: `{levels}`.
+    LogLevelUnknown,
+    /// Placeholder: `{level}`.
+    LogLevelSet,
+    /// Placeholder: `{uptime}`.
+    Uptime,
+    /// Placeholder: `{error}`.
+    SubscribersFailed,
+    /// Placeholder: `{repository}`.
+    NotTracked,
+    /// Placeholder: `{url}`.
+    Subscribers,
+}
+
+impl Key for InfoText {
+    fn text(self, lang: Lang) -> &'static str {
+        match (self, lang) {
+            (Self::OnlyCommands, Lang::En) => "Sorry, I only work with commands.",
+            (Self::OnlyCommands, Lang::It) => "Spiacente, funziono solo con i comandi.",
+            (Self::OnlyCommands, Lang::De) => "Ich verstehe leider nur Befehle.",
+
+            (Self::CheckUrlMissing, Lang::En) => "Please provide a URL: /check <url>",
+            (Self::CheckUrlMissing, Lang::It) => "Indica un URL: /check <url>",
+            (Self::CheckUrlMissing, Lang::De) => "Bitte gib eine URL an: /check <url>",
+
+            (Self::NoPublishedVersion, Lang::En) => "no published version found",
+            (Self::NoPublishedVersion, Lang::It) => "nessuna versione pubblicata trovata",
+            (Self::NoPublishedVersion, Lang::De) => "keine veröffentlichte Version gefunden",
+
+            (Self::NoReleaseOrTag, Lang::En) => "no release or tag found",
+            (Self::NoReleaseOrTag, Lang::It) => "nessuna release o tag trovato",
+            (Self::NoReleaseOrTag, Lang::De) => "kein Release oder Tag gefunden",
+
+            (Self::LookupError, Lang::En) => "lookup failed: {error}",
+            (Self::LookupError, Lang::It) => "ricerca fallita: {error}",
+            (Self::LookupError, Lang::De) => "Abfrage fehlgeschlagen: {error}",
+
+            (Self::CheckPackage, Lang::En) => {
+                "Registry: {registry}\nPackage: {package}\nLatest version: {latest}\nNothing was tracked."
+            }
+            (Self::CheckPackage, Lang::It) => {
+                "Registro: {registry}\nPacchetto: {package}\nUltima versione: {latest}\nNon è stato seguito nulla."
+            }
+            (Self::CheckPackage, Lang::De) => {
+                "Registry: {registry}\nPaket: {package}\nNeueste Version: {latest}\nEs wurde nichts verfolgt."
+            }
+
+            (Self::CheckRepository, Lang::En) => {
+                "Owner: {owner}\nRepository: {repo}\nAPI base: {base}\nLatest release: {latest}\nNothing was tracked."
+            }
+            (Self::CheckRepository, Lang::It) => {
+                "Proprietario: {owner}\nRepository: {repo}\nBase API: {base}\nUltima release: {latest}\nNon è stato seguito nulla."
+            }
+            (Self::CheckRepository, Lang::De) => {
+                "Eigentümer: {owner}\nRepository: {repo}\nAPI-Basis: {base}\nNeuestes Release: {latest}\nEs wurde nichts verfolgt."
+            }
+
+            (Self::RateLimitFailed, Lang::En) => "Failed to fetch GitHub rate limit: {error}",
+            (Self::RateLimitFailed, Lang::It) => {
+                "Impossibile leggere il limite di richieste di GitHub: {error}"
+            }
+            (Self::RateLimitFailed, Lang::De) => {
+                "Das GitHub-Anfragelimit konnte nicht abgerufen werden: {error}"
+            }
+
+            (Self::Quota, Lang::En) => {
+                "GitHub API quota: {remaining}/{limit} requests remaining, resets at {reset}."
+            }
+            (Self::Quota, Lang::It) => {
+                "Quota dell'API di GitHub: {remaining}/{limit} richieste rimaste, si azzera alle {reset}."
+            }
+            (Self::Quota, Lang::De) => {
+                "GitHub-API-Kontingent: {remaining}/{limit} Anfragen übrig, zurückgesetzt um {reset}."
+            }
+
+            (Self::QuotaUnauthenticated, Lang::En) => {
+                "No GitHub token is configured, so the low unauthenticated limit applies. \
+                 Set GITHUB_TOKEN to raise it."
+            }
+            (Self::QuotaUnauthenticated, Lang::It) => {
+                "Nessun token GitHub è configurato, quindi vale il basso limite non autenticato. \
+                 Imposta GITHUB_TOKEN per alzarlo."
+            }
+            (Self::QuotaUnauthenticated, Lang::De) => {
+                "Es ist kein GitHub-Token eingerichtet, daher gilt das niedrige Limit ohne \
+                 Anmeldung. Setze GITHUB_TOKEN, um es anzuheben."
+            }
+
+            (Self::StatsTotals, Lang::En) => {
+                "Notifications sent to this chat:\nLast 7 days: {week}\nLast 30 days: {month}"
+            }
+            (Self::StatsTotals, Lang::It) => {
+                "Notifiche inviate a questa chat:\nUltimi 7 giorni: {week}\nUltimi 30 giorni: {month}"
+            }
+            (Self::StatsTotals, Lang::De) => {
+                "An diesen Chat gesendete Benachrichtigungen:\nLetzte 7 Tage: {week}\nLetzte 30 Tage: {month}"
+            }
+
+            (Self::StatsByRepository, Lang::En) => "By repository (7 days / 30 days):",
+            (Self::StatsByRepository, Lang::It) => "Per repository (7 giorni / 30 giorni):",
+            (Self::StatsByRepository, Lang::De) => "Nach Repository (7 Tage / 30 Tage):",
+
+            (Self::StatsMore, Lang::En) => "…and {count} more",
+            (Self::StatsMore, Lang::It) => "…e altri {count}",
+            (Self::StatsMore, Lang::De) => "…und {count} weitere",
+
+            (Self::StatsFailed, Lang::En) => "Failed to load notification stats: {error}",
+            (Self::StatsFailed, Lang::It) => {
+                "Impossibile caricare le statistiche delle notifiche: {error}"
+            }
+            (Self::StatsFailed, Lang::De) => {
+                "Die Benachrichtigungsstatistik konnte nicht geladen werden: {error}"
+            }
+
+            (Self::AuthPackage, Lang::En) => {
+                "{registry} is asked about {package} without credentials; no token is used."
+            }
+            (Self::AuthPackage, Lang::It) => {
+                "{registry} viene interrogato su {package} senza credenziali; non si usa alcun token."
+            }
+            (Self::AuthPackage, Lang::De) => {
+                "{registry} wird ohne Zugangsdaten nach {package} gefragt; es wird kein Token verwendet."
+            }
+
+            (Self::AuthGlobal, Lang::En) => {
+                "Requests for {repository} use the bot's GitHub token (GITHUB_TOKEN), shared by all chats."
+            }
+            (Self::AuthGlobal, Lang::It) => {
+                "Le richieste per {repository} usano il token GitHub del bot (GITHUB_TOKEN), condiviso da tutte le chat."
+            }
+            (Self::AuthGlobal, Lang::De) => {
+                "Anfragen für {repository} nutzen das GitHub-Token des Bots (GITHUB_TOKEN), das alle Chats teilen."
+            }
+
+            (Self::AuthAnonymous, Lang::En) => {
+                "Requests for {repository} are sent without a token, so GitHub's unauthenticated \
+                 limit of 60 requests per hour applies. Set GITHUB_TOKEN to raise it."
+            }
+            (Self::AuthAnonymous, Lang::It) => {
+                "Le richieste per {repository} sono inviate senza token, quindi vale il limite non \
+                 autenticato di GitHub di 60 richieste all'ora. Imposta GITHUB_TOKEN per alzarlo."
+            }
+            (Self::AuthAnonymous, Lang::De) => {
+                "Anfragen für {repository} werden ohne Token gesendet, daher gilt GitHubs Limit \
+                 ohne Anmeldung von 60 Anfragen pro Stunde. Setze GITHUB_TOKEN, um es anzuheben."
+            }
+
+            (Self::NotAChatId, Lang::En) => "'{input}' is not a chat id.",
+            (Self::NotAChatId, Lang::It) => "'{input}' non è un id di chat.",
+            (Self::NotAChatId, Lang::De) => "'{input}' ist keine Chat-ID.",
+
+            (Self::ChatListFailed, Lang::En) => "Failed to list repositories of {chat}: {error}",
+            (Self::ChatListFailed, Lang::It) => {
+                "Impossibile elencare i repository di {chat}: {error}"
+            }
+            (Self::ChatListFailed, Lang::De) => {
+                "Die Repositories von {chat} konnten nicht aufgelistet werden: {error}"
+            }
+
+            (Self::TrackedByBoth, Lang::En) => "Tracked by both {a} and {b}",
+            (Self::TrackedByBoth, Lang::It) => "Seguiti sia da {a} che da {b}",
+            (Self::TrackedByBoth, Lang::De) => "Von {a} und {b} verfolgt",
+
+            (Self::OnlyChat, Lang::En) => "Only {chat}",
+            (Self::OnlyChat, Lang::It) => "Solo {chat}",
+            (Self::OnlyChat, Lang::De) => "Nur {chat}",
+
+            (Self::SectionEmpty, Lang::En) => "{title} (0): none",
+            (Self::SectionEmpty, Lang::It) => "{title} (0): nessuno",
+            (Self::SectionEmpty, Lang::De) => "{title} (0): keine",
+
+            (Self::LogLevelIs, Lang::En) => {
+                "The log level is {level}. Change it with /loglevel {levels}."
+            }
+            (Self::LogLevelIs, Lang::It) => {
+                "Il livello di log è {level}. Cambialo con /loglevel {levels}."
+            }
+            (Self::LogLevelIs, Lang::De) => {
+                "Das Log-Level ist {level}. Ändern mit /loglevel {levels}."
+            }
+
+            (Self::LogLevelUnknown, Lang::En) => "Unknown level. Use one of {levels}.",
+            (Self::LogLevelUnknown, Lang::It) => "Livello sconosciuto. Usa uno tra {levels}.",
+            (Self::LogLevelUnknown, Lang::De) => "Unbekanntes Level. Verwende eines von {levels}.",
+
+            (Self::LogLevelSet, Lang::En) => "The log level is now {level} until the bot restarts.",
+            (Self::LogLevelSet, Lang::It) => {
+                "Il livello di log è ora {level} fino al riavvio del bot."
+            }
+            (Self::LogLevelSet, Lang::De) => {
+                "Das Log-Level ist jetzt {level}, bis der Bot neu startet."
+            }
+
+            (Self::Uptime, Lang::En) => "Uptime: {uptime}",
+            (Self::Uptime, Lang::It) => "Attivo da: {uptime}",
+            (Self::Uptime, Lang::De) => "Laufzeit: {uptime}",
+
+            (Self::SubscribersFailed, Lang::En) => "Failed to list subscribers: {error}",
+            (Self::SubscribersFailed, Lang::It) => "Impossibile elencare gli iscritti: {error}",
+            (Self::SubscribersFailed, Lang::De) => {
+                "Die Abonnenten konnten nicht aufgelistet werden: {error}"
+            }
+
+            (Self::NotTracked, Lang::En) => "{repository} is not tracked.",
+            (Self::NotTracked, Lang::It) => "{repository} non è seguito.",
+            (Self::NotTracked, Lang::De) => "{repository} wird nicht verfolgt.",
+
+            (Self::Subscribers, Lang::En) => "Chats subscribed to {url}:",
+            (Self::Subscribers, Lang::It) => "Chat iscritte a {url}:",
+            (Self::Subscribers, Lang::De) => "Chats, die {url} abonniert haben:",
+        }
+    }
+}
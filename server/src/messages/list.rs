@@ -0,0 +1,150 @@
+use crate::chat_settings::Lang;
+
+use super::Key;
+
+/// What the lines of /list and the summary of a repository's settings say.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListText {
+    Latest,
+    LatestUnknown,
+    NoReleasesYet,
+    CheckFailed,
+    LastCheckFailed,
+    Critical,
+    /// Placeholder: `{until}`.
+    SnoozedUntil,
+    /// Placeholder: `{date}`.
+    Seen,
+    /// Placeholders: `{url}`, `{branch}`.
+    DefaultBranch,
+    /// Placeholder: `{settings}`.
+    Settings,
+    Defaults,
+    Muted,
+    /// Placeholder: `{category}`.
+    Discussions,
+    /// Placeholder: `{every}`.
+    EveryCommits,
+    TagsOnly,
+    ExactTags,
+    /// Placeholder: `{prefix}`.
+    Component,
+    /// Placeholder: `{pattern}`.
+    CapturedBy,
+    /// Placeholder: `{pattern}`.
+    IgnoringTags,
+    /// Placeholder: `{minutes}`.
+    PrereleasesCollapsed,
+    /// Placeholder: `{minutes}`.
+    MinAge,
+    /// Placeholder: `{user}`.
+    Mentions,
+    /// Placeholder: `{minutes}`.
+    Throttled,
+    /// Placeholder: `{date}`.
+    InaccessibleSince,
+}
+
+impl Key for ListText {
+    fn text(self, lang: Lang) -> &'static str {
+        match (self, lang) {
+            (Self::Latest, Lang::En) => "latest:",
+            (Self::Latest, Lang::It) => "ultima:",
+            (Self::Latest, Lang::De) => "neueste:",
+
+            (Self::LatestUnknown, Lang::En) => "latest: unknown",
+            (Self::LatestUnknown, Lang::It) => "ultima: sconosciuta",
+            (Self::LatestUnknown, Lang::De) => "neueste: unbekannt",
+
+            (Self::NoReleasesYet, Lang::En) => "no releases yet",
+            (Self::NoReleasesYet, Lang::It) => "ancora nessuna release",
+            (Self::NoReleasesYet, Lang::De) => "noch keine Releases",
+
+            (Self::CheckFailed, Lang::En) => "check failed",
+            (Self::CheckFailed, Lang::It) => "controllo fallito",
+            (Self::CheckFailed, Lang::De) => "Abfrage fehlgeschlagen",
+
+            (Self::LastCheckFailed, Lang::En) => "(last check failed)",
+            (Self::LastCheckFailed, Lang::It) => "(ultimo controllo fallito)",
+            (Self::LastCheckFailed, Lang::De) => "(letzte Abfrage fehlgeschlagen)",
+
+            (Self::Critical, Lang::En) => "critical",
+            (Self::Critical, Lang::It) => "critico",
+            (Self::Critical, Lang::De) => "kritisch",
+
+            (Self::SnoozedUntil, Lang::En) => "snoozed until {until}",
+            (Self::SnoozedUntil, Lang::It) => "in pausa fino al {until}",
+            (Self::SnoozedUntil, Lang::De) => "pausiert bis {until}",
+
+            (Self::Seen, Lang::En) => "(seen {date})",
+            (Self::Seen, Lang::It) => "(vista il {date})",
+            (Self::Seen, Lang::De) => "(gesehen am {date})",
+
+            (Self::DefaultBranch, Lang::En) => "{url} (default branch: {branch})",
+            (Self::DefaultBranch, Lang::It) => "{url} (branch predefinito: {branch})",
+            (Self::DefaultBranch, Lang::De) => "{url} (Standard-Branch: {branch})",
+
+            (Self::Settings, Lang::En) => "settings: {settings}",
+            (Self::Settings, Lang::It) => "impostazioni: {settings}",
+            (Self::Settings, Lang::De) => "Einstellungen: {settings}",
+
+            (Self::Defaults, Lang::En) => "defaults",
+            (Self::Defaults, Lang::It) => "predefinite",
+            (Self::Defaults, Lang::De) => "Standard",
+
+            (Self::Muted, Lang::En) => "muted",
+            (Self::Muted, Lang::It) => "silenziato",
+            (Self::Muted, Lang::De) => "stummgeschaltet",
+
+            (Self::Discussions, Lang::En) => "discussions in {category}",
+            (Self::Discussions, Lang::It) => "discussioni in {category}",
+            (Self::Discussions, Lang::De) => "Diskussionen in {category}",
+
+            (Self::EveryCommits, Lang::En) => "every {every} commits",
+            (Self::EveryCommits, Lang::It) => "ogni {every} commit",
+            (Self::EveryCommits, Lang::De) => "alle {every} Commits",
+
+            (Self::TagsOnly, Lang::En) => "tags only",
+            (Self::TagsOnly, Lang::It) => "solo tag",
+            (Self::TagsOnly, Lang::De) => "nur Tags",
+
+            (Self::ExactTags, Lang::En) => "exact tags",
+            (Self::ExactTags, Lang::It) => "tag esatti",
+            (Self::ExactTags, Lang::De) => "exakte Tags",
+
+            (Self::Component, Lang::En) => "component {prefix}",
+            (Self::Component, Lang::It) => "componente {prefix}",
+            (Self::Component, Lang::De) => "Komponente {prefix}",
+
+            (Self::CapturedBy, Lang::En) => "versions captured by {pattern}",
+            (Self::CapturedBy, Lang::It) => "versioni estratte da {pattern}",
+            (Self::CapturedBy, Lang::De) => "Versionen erfasst durch {pattern}",
+
+            (Self::IgnoringTags, Lang::En) => "ignoring tags like {pattern}",
+            (Self::IgnoringTags, Lang::It) => "ignora i tag come {pattern}",
+            (Self::IgnoringTags, Lang::De) => "ignoriert Tags wie {pattern}",
+
+            (Self::PrereleasesCollapsed, Lang::En) => "prereleases collapsed within {minutes}m",
+            (Self::PrereleasesCollapsed, Lang::It) => "prerelease raggruppate entro {minutes}m",
+            (Self::PrereleasesCollapsed, Lang::De) => {
+                "Prereleases innerhalb von {minutes}m zusammengefasst"
+            }
+
+            (Self::MinAge, Lang::En) => "releases announced once {minutes}m old",
+            (Self::MinAge, Lang::It) => "release annunciate dopo {minutes}m",
+            (Self::MinAge, Lang::De) => "Releases ab {minutes}m Alter angekündigt",
+
+            (Self::Mentions, Lang::En) => "mentions {user}",
+            (Self::Mentions, Lang::It) => "menziona {user}",
+            (Self::Mentions, Lang::De) => "erwähnt {user}",
+
+            (Self::Throttled, Lang::En) => "at most one notification per {minutes}m",
+            (Self::Throttled, Lang::It) => "al massimo una notifica ogni {minutes}m",
+            (Self::Throttled, Lang::De) => "höchstens eine Benachrichtigung pro {minutes}m",
+
+            (Self::InaccessibleSince, Lang::En) => "inaccessible since {date}",
+            (Self::InaccessibleSince, Lang::It) => "inaccessibile dal {date}",
+            (Self::InaccessibleSince, Lang::De) => "nicht erreichbar seit {date}",
+        }
+    }
+}
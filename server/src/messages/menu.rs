@@ -0,0 +1,147 @@
+use crate::chat_settings::Lang;
+
+use super::Key;
+
+/// What the buttons of /menu and the views they open say.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuText {
+    Prompt,
+    List,
+    Add,
+    Settings,
+    Mutes,
+    Back,
+    TapToMute,
+    /// Placeholder: `{count}`.
+    MoreThanButtons,
+    /// Placeholder: `{error}`.
+    LoadRepositoryFailed,
+    NoLongerTracked,
+    SettingsHeading,
+    /// Placeholder: `{value}`, the current setting.
+    Format,
+    /// Placeholder: `{value}`, the current setting.
+    Language,
+    /// Placeholder: `{value}`, the current setting.
+    Notes,
+    /// Placeholder: `{value}`, the current setting.
+    ListLinks,
+    /// Placeholder: `{value}`, the current setting.
+    PreviousTag,
+    /// Placeholder: `{value}`, the current setting.
+    FirstSeen,
+    /// Placeholder: `{value}`, the current setting.
+    Pin,
+    /// Placeholder: `{value}`, the current setting.
+    RemovedReleases,
+    /// Placeholder: `{value}`, the current setting.
+    Reactions,
+    AddPrompt,
+    UrlMissing,
+}
+
+impl Key for MenuText {
+    fn text(self, lang: Lang) -> &'static str {
+        match (self, lang) {
+            (Self::Prompt, Lang::En) => "What would you like to do?",
+            (Self::Prompt, Lang::It) => "Cosa vuoi fare?",
+            (Self::Prompt, Lang::De) => "Was möchtest du tun?",
+
+            (Self::List, Lang::En) => "List",
+            (Self::List, Lang::It) => "Elenco",
+            (Self::List, Lang::De) => "Liste",
+
+            (Self::Add, Lang::En) => "Add",
+            (Self::Add, Lang::It) => "Aggiungi",
+            (Self::Add, Lang::De) => "Hinzufügen",
+
+            (Self::Settings, Lang::En) => "Settings",
+            (Self::Settings, Lang::It) => "Impostazioni",
+            (Self::Settings, Lang::De) => "Einstellungen",
+
+            (Self::Mutes, Lang::En) => "Mute/Unmute",
+            (Self::Mutes, Lang::It) => "Silenzia/Riattiva",
+            (Self::Mutes, Lang::De) => "Stumm/Laut",
+
+            (Self::Back, Lang::En) => "Back",
+            (Self::Back, Lang::It) => "Indietro",
+            (Self::Back, Lang::De) => "Zurück",
+
+            (Self::TapToMute, Lang::En) => "Tap a repository to mute or unmute it.",
+            (Self::TapToMute, Lang::It) => "Tocca un repository per silenziarlo o riattivarlo.",
+            (Self::TapToMute, Lang::De) => {
+                "Tippe auf ein Repository, um es stumm oder laut zu schalten."
+            }
+
+            (Self::MoreThanButtons, Lang::En) => "…and {count} more; use /mute_matching for those.",
+            (Self::MoreThanButtons, Lang::It) => "…e altri {count}; usa /mute_matching per quelli.",
+            (Self::MoreThanButtons, Lang::De) => {
+                "…und {count} weitere; nutze dafür /mute_matching."
+            }
+
+            (Self::LoadRepositoryFailed, Lang::En) => "Failed to load repository: {error}",
+            (Self::LoadRepositoryFailed, Lang::It) => "Impossibile caricare il repository: {error}",
+            (Self::LoadRepositoryFailed, Lang::De) => {
+                "Repository konnte nicht geladen werden: {error}"
+            }
+
+            (Self::NoLongerTracked, Lang::En) => "This repository is no longer tracked here.",
+            (Self::NoLongerTracked, Lang::It) => "Questo repository non è più seguito qui.",
+            (Self::NoLongerTracked, Lang::De) => "Dieses Repository wird hier nicht mehr verfolgt.",
+
+            (Self::SettingsHeading, Lang::En) => "Settings of this chat:",
+            (Self::SettingsHeading, Lang::It) => "Impostazioni di questa chat:",
+            (Self::SettingsHeading, Lang::De) => "Einstellungen dieses Chats:",
+
+            (Self::Format, Lang::En) => "Format: {value} (/format)",
+            (Self::Format, Lang::It) => "Formato: {value} (/format)",
+            (Self::Format, Lang::De) => "Format: {value} (/format)",
+
+            (Self::Language, Lang::En) => "Language: {value} (/language)",
+            (Self::Language, Lang::It) => "Lingua: {value} (/language)",
+            (Self::Language, Lang::De) => "Sprache: {value} (/language)",
+
+            (Self::Notes, Lang::En) => "Release notes: {value} (/notes)",
+            (Self::Notes, Lang::It) => "Note di rilascio: {value} (/notes)",
+            (Self::Notes, Lang::De) => "Release Notes: {value} (/notes)",
+
+            (Self::ListLinks, Lang::En) => "Links in /list: {value} (/list_links)",
+            (Self::ListLinks, Lang::It) => "Link in /list: {value} (/list_links)",
+            (Self::ListLinks, Lang::De) => "Links in /list: {value} (/list_links)",
+
+            (Self::PreviousTag, Lang::En) => "Previous tag: {value} (/previous_tag)",
+            (Self::PreviousTag, Lang::It) => "Tag precedente: {value} (/previous_tag)",
+            (Self::PreviousTag, Lang::De) => "Vorheriges Tag: {value} (/previous_tag)",
+
+            (Self::FirstSeen, Lang::En) => "Announce on first sight: {value} (/first_seen)",
+            (Self::FirstSeen, Lang::It) => "Annuncia al primo avvistamento: {value} (/first_seen)",
+            (Self::FirstSeen, Lang::De) => "Beim ersten Sehen ankündigen: {value} (/first_seen)",
+
+            (Self::Pin, Lang::En) => "Pin latest release: {value} (/pin_releases)",
+            (Self::Pin, Lang::It) => "Fissa l'ultima release: {value} (/pin_releases)",
+            (Self::Pin, Lang::De) => "Neuestes Release anheften: {value} (/pin_releases)",
+
+            (Self::RemovedReleases, Lang::En) => "Removed releases: {value} (/removed_releases)",
+            (Self::RemovedReleases, Lang::It) => "Release rimosse: {value} (/removed_releases)",
+            (Self::RemovedReleases, Lang::De) => "Entfernte Releases: {value} (/removed_releases)",
+
+            (Self::Reactions, Lang::En) => "Reactions: {value} (/reactions)",
+            (Self::Reactions, Lang::It) => "Reazioni: {value} (/reactions)",
+            (Self::Reactions, Lang::De) => "Reaktionen: {value} (/reactions)",
+
+            (Self::AddPrompt, Lang::En) => {
+                "Reply with the URL of the repository or package to track."
+            }
+            (Self::AddPrompt, Lang::It) => {
+                "Rispondi con l'URL del repository o del pacchetto da seguire."
+            }
+            (Self::AddPrompt, Lang::De) => {
+                "Antworte mit der URL des Repositorys oder Pakets, das verfolgt werden soll."
+            }
+
+            (Self::UrlMissing, Lang::En) => "Please send a repository or package URL.",
+            (Self::UrlMissing, Lang::It) => "Invia l'URL di un repository o di un pacchetto.",
+            (Self::UrlMissing, Lang::De) => "Bitte sende die URL eines Repositorys oder Pakets.",
+        }
+    }
+}
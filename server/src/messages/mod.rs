@@ -0,0 +1,89 @@
+//! Text the bot writes to chats, in every supported language. Strings are plain text;
+//! callers escape them for the chat's message format after filling in placeholders.
+
+mod chat;
+mod commands;
+mod delivery;
+mod info;
+mod list;
+mod menu;
+mod poll;
+mod releases;
+mod schedule;
+mod settings;
+mod source;
+mod status;
+mod text;
+mod tracking;
+
+pub use chat::ChatText;
+pub use commands::CommandText;
+pub use delivery::DeliveryText;
+pub use info::InfoText;
+pub use list::ListText;
+pub use menu::MenuText;
+pub use poll::PollText;
+pub use releases::ReleaseText;
+pub use schedule::ScheduleText;
+pub use settings::SettingText;
+pub use source::SourceText;
+pub use status::StatusText;
+pub use text::Text;
+pub use tracking::TrackingText;
+
+use crate::chat_settings::Lang;
+
+/// A piece of text the bot writes, with a translation for every [`Lang`].
+pub trait Key: Copy {
+    fn text(self, lang: Lang) -> &'static str;
+}
+
+/// The text for `key` in `lang`.
+pub fn text(lang: Lang, key: impl Key) -> &'static str {
+    key.text(lang)
+}
+
+/// The text for `key` in `lang` with each `{placeholder}` replaced by its value.
+pub fn render(lang: Lang, key: impl Key, values: &[(&str, &str)]) -> String {
+    values
+        .iter()
+        .fold(text(lang, key).to_string(), |out, (name, value)| {
+            out.replace(&format!("{{{name}}}"), value)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chat_settings::MessageFormat;
+
+    #[test]
+    fn renders_keys_in_the_selected_language() {
+        assert_eq!(text(Lang::En, Text::NewRelease), "New release for");
+        assert_eq!(text(Lang::It, Text::NewRelease), "Nuova release per");
+        assert_eq!(
+            render(
+                Lang::De,
+                Text::LanguageSet,
+                &[("language", Lang::De.name())]
+            ),
+            "Nachrichten in diesem Chat sind ab jetzt auf Deutsch."
+        );
+        assert_eq!(
+            render(Lang::It, Text::FormatSet, &[("format", "html")]),
+            "Da ora i messaggi in questa chat useranno html."
+        );
+    }
+
+    #[test]
+    fn localized_text_is_escaped_like_any_other() {
+        assert_eq!(
+            MessageFormat::MarkdownV2.escape(text(Lang::De, Text::WhileSnoozed)),
+            "\\(während der Pause\\)"
+        );
+        assert_eq!(
+            MessageFormat::Html.escape(&render(Lang::En, Text::FormatSet, &[("format", "<b>")])),
+            "Messages in this chat will now use &lt;b&gt;."
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use crate::chat_settings::Lang;
+
+use super::Key;
+
+/// What the commands explaining when and why the poller notifies answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PollText {
+    WouldNotifyNothing,
+    WouldSendOne,
+    /// Placeholder: `{count}`.
+    WouldSend,
+    /// Placeholder: `{count}`.
+    RefusedForRateLimit,
+    /// Placeholder: `{repository}`.
+    CheckingNow,
+    /// Placeholders: `{repository}`, `{at}`.
+    NextCheck,
+    /// Placeholder: `{repository}`.
+    NotCheckedYet,
+    /// Placeholder: `{until}`.
+    RepositorySnoozed,
+    /// Placeholders: `{tag}`, `{seen}`.
+    CachedRelease,
+    NoCachedRelease,
+    /// Placeholders: `{status}`, `{at}`.
+    LastCheck,
+    NoLastCheck,
+    /// Placeholder: `{settings}`.
+    Settings,
+    /// Placeholder: `{tag}`.
+    GithubNow,
+    GithubNothing,
+    /// Placeholder: `{error}`.
+    GithubFailed,
+    /// Placeholder: `{explanation}`.
+    Decision,
+    /// Placeholder: `{category}`.
+    DecideDiscussions,
+    /// Placeholder: `{every}`.
+    DecideMilestones,
+    DecideNothingYet,
+    DecideAfterLookup,
+    /// Placeholder: `{tag}`.
+    Remember,
+    /// Placeholder: `{tag}`.
+    Unchanged,
+    Muted,
+    /// Placeholders: `{tag}`, `{until}`.
+    Snoozed,
+    /// Placeholders: `{tag}`, `{until}`.
+    TooYoung,
+    /// Placeholders: `{tag}`, `{until}`.
+    Throttled,
+    /// Placeholder: `{tag}`.
+    CollapsedPrerelease,
+    /// Placeholder: `{tag}`.
+    Notify,
+}
+
+impl Key for PollText {
+    fn text(self, lang: Lang) -> &'static str {
+        match (self, lang) {
+            (Self::WouldNotifyNothing, Lang::En) => "A poll now would notify nothing.",
+            (Self::WouldNotifyNothing, Lang::It) => "Un controllo ora non notificherebbe nulla.",
+            (Self::WouldNotifyNothing, Lang::De) => "Eine Abfrage jetzt würde nichts melden.",
+
+            (Self::WouldSendOne, Lang::En) => "A poll now would send 1 notification:",
+            (Self::WouldSendOne, Lang::It) => "Un controllo ora invierebbe 1 notifica:",
+            (Self::WouldSendOne, Lang::De) => "Eine Abfrage jetzt würde 1 Benachrichtigung senden:",
+
+            (Self::WouldSend, Lang::En) => "A poll now would send {count} notifications:",
+            (Self::WouldSend, Lang::It) => "Un controllo ora invierebbe {count} notifiche:",
+            (Self::WouldSend, Lang::De) => {
+                "Eine Abfrage jetzt würde {count} Benachrichtigungen senden:"
+            }
+
+            (Self::RefusedForRateLimit, Lang::En) => {
+                "GitHub refused {count} repositories for a rate limit."
+            }
+            (Self::RefusedForRateLimit, Lang::It) => {
+                "GitHub ha rifiutato {count} repository per un limite di richieste."
+            }
+            (Self::RefusedForRateLimit, Lang::De) => {
+                "GitHub hat {count} Repositories wegen eines Anfragelimits abgelehnt."
+            }
+
+            (Self::CheckingNow, Lang::En) => "{repository} is being checked right now.",
+            (Self::CheckingNow, Lang::It) => "{repository} è in fase di controllo proprio ora.",
+            (Self::CheckingNow, Lang::De) => "{repository} wird gerade abgefragt.",
+
+            (Self::NextCheck, Lang::En) => "{repository} will next be checked around {at}.",
+            (Self::NextCheck, Lang::It) => "{repository} sarà controllato di nuovo verso le {at}.",
+            (Self::NextCheck, Lang::De) => "{repository} wird als Nächstes gegen {at} abgefragt.",
+
+            (Self::NotCheckedYet, Lang::En) => {
+                "{repository} has not been checked since the bot started; it will be in the current poll cycle."
+            }
+            (Self::NotCheckedYet, Lang::It) => {
+                "{repository} non è stato controllato dall'avvio del bot; lo sarà nel ciclo di controllo in corso."
+            }
+            (Self::NotCheckedYet, Lang::De) => {
+                "{repository} wurde seit dem Start des Bots nicht abgefragt; das geschieht im laufenden Abfragezyklus."
+            }
+
+            (Self::RepositorySnoozed, Lang::En) => "Notifications are snoozed until {until}.",
+            (Self::RepositorySnoozed, Lang::It) => "Le notifiche sono in pausa fino al {until}.",
+            (Self::RepositorySnoozed, Lang::De) => "Benachrichtigungen sind bis {until} pausiert.",
+
+            (Self::CachedRelease, Lang::En) => "Cached release: {tag} (first seen {seen})",
+            (Self::CachedRelease, Lang::It) => {
+                "Release in cache: {tag} (vista per la prima volta {seen})"
+            }
+            (Self::CachedRelease, Lang::De) => {
+                "Zwischengespeichertes Release: {tag} (zuerst gesehen {seen})"
+            }
+
+            (Self::NoCachedRelease, Lang::En) => "Cached release: none yet",
+            (Self::NoCachedRelease, Lang::It) => "Release in cache: ancora nessuna",
+            (Self::NoCachedRelease, Lang::De) => "Zwischengespeichertes Release: noch keines",
+
+            (Self::LastCheck, Lang::En) => "Last check: {status} at {at}",
+            (Self::LastCheck, Lang::It) => "Ultimo controllo: {status} il {at}",
+            (Self::LastCheck, Lang::De) => "Letzte Abfrage: {status} am {at}",
+
+            (Self::NoLastCheck, Lang::En) => "Last check: not checked yet",
+            (Self::NoLastCheck, Lang::It) => "Ultimo controllo: non ancora controllato",
+            (Self::NoLastCheck, Lang::De) => "Letzte Abfrage: noch nicht abgefragt",
+
+            (Self::Settings, Lang::En) => "Settings: {settings}",
+            (Self::Settings, Lang::It) => "Impostazioni: {settings}",
+            (Self::Settings, Lang::De) => "Einstellungen: {settings}",
+
+            (Self::GithubNow, Lang::En) => "GitHub now: {tag}",
+            (Self::GithubNow, Lang::It) => "GitHub ora: {tag}",
+            (Self::GithubNow, Lang::De) => "GitHub jetzt: {tag}",
+
+            (Self::GithubNothing, Lang::En) => "GitHub now: no release or tag found",
+            (Self::GithubNothing, Lang::It) => "GitHub ora: nessuna release o tag trovato",
+            (Self::GithubNothing, Lang::De) => "GitHub jetzt: kein Release oder Tag gefunden",
+
+            (Self::GithubFailed, Lang::En) => "GitHub now: lookup failed ({error})",
+            (Self::GithubFailed, Lang::It) => "GitHub ora: ricerca fallita ({error})",
+            (Self::GithubFailed, Lang::De) => "GitHub jetzt: Abfrage fehlgeschlagen ({error})",
+
+            (Self::Decision, Lang::En) => "Decision: {explanation}",
+            (Self::Decision, Lang::It) => "Decisione: {explanation}",
+            (Self::Decision, Lang::De) => "Entscheidung: {explanation}",
+
+            (Self::DecideDiscussions, Lang::En) => {
+                "new discussions in {category} are announced instead of releases."
+            }
+            (Self::DecideDiscussions, Lang::It) => {
+                "vengono annunciate le nuove discussioni in {category} invece delle release."
+            }
+            (Self::DecideDiscussions, Lang::De) => {
+                "statt Releases werden neue Diskussionen in {category} angekündigt."
+            }
+
+            (Self::DecideMilestones, Lang::En) => {
+                "every {every} commits on the default branch are announced instead of releases."
+            }
+            (Self::DecideMilestones, Lang::It) => {
+                "vengono annunciati ogni {every} commit sul branch predefinito invece delle release."
+            }
+            (Self::DecideMilestones, Lang::De) => {
+                "statt Releases werden alle {every} Commits auf dem Standard-Branch angekündigt."
+            }
+
+            (Self::DecideNothingYet, Lang::En) => "there is nothing to announce yet.",
+            (Self::DecideNothingYet, Lang::It) => "non c'è ancora nulla da annunciare.",
+            (Self::DecideNothingYet, Lang::De) => "es gibt noch nichts anzukündigen.",
+
+            (Self::DecideAfterLookup, Lang::En) => "nothing is sent until the lookup succeeds.",
+            (Self::DecideAfterLookup, Lang::It) => {
+                "non viene inviato nulla finché la ricerca non riesce."
+            }
+            (Self::DecideAfterLookup, Lang::De) => {
+                "es wird nichts gesendet, bis die Abfrage gelingt."
+            }
+
+            (Self::Remember, Lang::En) => {
+                "{tag} is the first release seen, so it is remembered without a notification."
+            }
+            (Self::Remember, Lang::It) => {
+                "{tag} è la prima release vista, quindi viene memorizzata senza notifica."
+            }
+            (Self::Remember, Lang::De) => {
+                "{tag} ist das erste gesehene Release und wird ohne Benachrichtigung gemerkt."
+            }
+
+            (Self::Unchanged, Lang::En) => "{tag} was already announced; nothing new to send.",
+            (Self::Unchanged, Lang::It) => {
+                "{tag} è già stata annunciata; niente di nuovo da inviare."
+            }
+            (Self::Unchanged, Lang::De) => {
+                "{tag} wurde bereits angekündigt; es gibt nichts Neues zu senden."
+            }
+
+            (Self::Muted, Lang::En) => "The repository is muted, so no notification is sent.",
+            (Self::Muted, Lang::It) => {
+                "Il repository è silenziato, quindi non viene inviata alcuna notifica."
+            }
+            (Self::Muted, Lang::De) => {
+                "Das Repository ist stummgeschaltet, daher wird keine Benachrichtigung gesendet."
+            }
+
+            (Self::Snoozed, Lang::En) => {
+                "Notifications are snoozed until {until}; {tag} will be announced then if it is new."
+            }
+            (Self::Snoozed, Lang::It) => {
+                "Le notifiche sono in pausa fino al {until}; {tag} sarà annunciata allora se è nuova."
+            }
+            (Self::Snoozed, Lang::De) => {
+                "Benachrichtigungen sind bis {until} pausiert; {tag} wird dann angekündigt, wenn es neu ist."
+            }
+
+            (Self::TooYoung, Lang::En) => {
+                "{tag} is newer than the repository's minimum release age; it is announced from {until} on."
+            }
+            (Self::TooYoung, Lang::It) => {
+                "{tag} è più recente dell'età minima delle release del repository; viene annunciata dal {until}."
+            }
+            (Self::TooYoung, Lang::De) => {
+                "{tag} ist jünger als das Mindestalter für Releases des Repositorys; es wird ab {until} angekündigt."
+            }
+
+            (Self::Throttled, Lang::En) => {
+                "{tag} is held by the repository's throttle; the newest release is announced from {until} on."
+            }
+            (Self::Throttled, Lang::It) => {
+                "{tag} è trattenuta dal limite del repository; la release più recente viene annunciata dal {until}."
+            }
+            (Self::Throttled, Lang::De) => {
+                "{tag} wird von der Drosselung des Repositorys zurückgehalten; das neueste Release wird ab {until} angekündigt."
+            }
+
+            (Self::CollapsedPrerelease, Lang::En) => {
+                "{tag} is a prerelease of a version announced recently, so it is collapsed."
+            }
+            (Self::CollapsedPrerelease, Lang::It) => {
+                "{tag} è una prerelease di una versione annunciata di recente, quindi viene raggruppata."
+            }
+            (Self::CollapsedPrerelease, Lang::De) => {
+                "{tag} ist ein Prerelease einer kürzlich angekündigten Version und wird daher zusammengefasst."
+            }
+
+            (Self::Notify, Lang::En) => "{tag} is new: the next check notifies this chat.",
+            (Self::Notify, Lang::It) => {
+                "{tag} è nuova: il prossimo controllo notificherà questa chat."
+            }
+            (Self::Notify, Lang::De) => {
+                "{tag} ist neu: die nächste Abfrage benachrichtigt diesen Chat."
+            }
+        }
+    }
+}
@@ -0,0 +1,392 @@
+use crate::chat_settings::Lang;
+
+use super::Key;
+
+/// What the commands looking up, resending, watching or handing over releases answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReleaseText {
+    /// Placeholder: `{input}`.
+    InvalidDate,
+    /// Placeholder: `{input}`.
+    DateTooFarBack,
+    /// Placeholder: `{repository}`.
+    NotGithubReleases,
+    /// Placeholders: `{repository}`, `{since}`.
+    NoReleasesSince,
+    /// Placeholder: `{repository}`.
+    NothingToResend,
+    TargetChatMissing,
+    /// Placeholder: `{repository}`.
+    AlreadyInThisChat,
+    /// Placeholder: `{chat}`.
+    ChatNotServed,
+    /// Placeholder: `{chat}`.
+    NotTargetAdmin,
+    /// Placeholders: `{repository}`, `{error}`.
+    HandoffFailed,
+    /// Placeholders: `{repository}`, `{url}`.
+    HandedOverHere,
+    /// Placeholders: `{repository}`, `{chat}`.
+    HandedOver,
+    /// Placeholders: `{repository}`, `{chat}`.
+    HandedOverUnannounced,
+    TagMissing,
+    /// Placeholder: `{error}`.
+    WatchSaveFailed,
+    /// Placeholders: `{url}`, `{tag}`.
+    WatchingTag,
+    /// Placeholders: `{url}`, `{tag}`.
+    AlreadyWatchingTag,
+    /// Placeholders: `{tag}`, `{url}`.
+    TagAlreadyPublished,
+    /// Placeholder: `{error}`.
+    StaleFailed,
+    /// Placeholder: `{days}`.
+    NothingStale,
+    /// Placeholders: `{repository}`, `{weeks}`.
+    NoChartReleases,
+    /// Placeholders: `{repository}`, `{weeks}`, `{chart}`, `{total}`, `{busiest}`.
+    Chart,
+    /// Placeholder: `{count}`.
+    AllStillExist,
+    /// Placeholder: `{count}`.
+    UntrackGone,
+    /// Placeholders: `{checked}`, `{total}`.
+    CheckedOf,
+    /// Placeholders: `{error}`, `{count}`.
+    StoppedEarly,
+    /// Placeholders: `{repository}`, `{url}`.
+    MovedTo,
+    /// Placeholder: `{repository}`.
+    NoLongerExists,
+    /// Placeholders: `{repository}`, `{error}`.
+    CouldNotBeChecked,
+    /// Placeholders: `{repository}`, `{since}`, `{count}`.
+    ReleasesSince,
+    /// Placeholder: `{count}`.
+    AtLeast,
+    /// Placeholder: `{count}`.
+    OlderNotShown,
+    /// Placeholder: `{days}`.
+    StaleHeading,
+    /// Placeholders: `{repository}`, `{tag}`, `{days}`.
+    StaleLine,
+    /// Placeholder: `{repository}`.
+    RecentReleases,
+    /// Placeholder: `{author}`.
+    ByAuthor,
+    /// Placeholder: `{repository}`.
+    VelocityHeading,
+    /// Placeholder: `{releases}`.
+    Last30Days,
+    /// Placeholder: `{releases}`.
+    Last90Days,
+    /// Placeholder: `{days}`.
+    AverageDays,
+    NoAverage,
+    /// Placeholders: `{releases}`, `{since}`.
+    BasedOn,
+    OneRelease,
+    /// Placeholder: `{count}`.
+    Releases,
+}
+
+impl Key for ReleaseText {
+    fn text(self, lang: Lang) -> &'static str {
+        match (self, lang) {
+            (Self::InvalidDate, Lang::En) => "Invalid date '{input}'. Use e.g. 2024-05-01 or 30d.",
+            (Self::InvalidDate, Lang::It) => {
+                "Data non valida '{input}'. Usa ad esempio 2024-05-01 oppure 30d."
+            }
+            (Self::InvalidDate, Lang::De) => {
+                "Ungültiges Datum '{input}'. Nutze z. B. 2024-05-01 oder 30d."
+            }
+
+            (Self::DateTooFarBack, Lang::En) => "Date '{input}' is too far back.",
+            (Self::DateTooFarBack, Lang::It) => "La data '{input}' è troppo lontana nel passato.",
+            (Self::DateTooFarBack, Lang::De) => "Das Datum '{input}' liegt zu weit zurück.",
+
+            (Self::NotGithubReleases, Lang::En) => {
+                "{repository} is not a GitHub repository with releases."
+            }
+            (Self::NotGithubReleases, Lang::It) => {
+                "{repository} non è un repository GitHub con release."
+            }
+            (Self::NotGithubReleases, Lang::De) => {
+                "{repository} ist kein GitHub-Repository mit Releases."
+            }
+
+            (Self::NoReleasesSince, Lang::En) => "No releases of {repository} since {since}.",
+            (Self::NoReleasesSince, Lang::It) => "Nessuna release di {repository} dal {since}.",
+            (Self::NoReleasesSince, Lang::De) => "Keine Releases von {repository} seit {since}.",
+
+            (Self::NothingToResend, Lang::En) => {
+                "No release of {repository} has been seen yet, so there is nothing to resend."
+            }
+            (Self::NothingToResend, Lang::It) => {
+                "Non è ancora stata vista nessuna release di {repository}, quindi non c'è niente da reinviare."
+            }
+            (Self::NothingToResend, Lang::De) => {
+                "Von {repository} wurde noch kein Release gesehen, daher gibt es nichts erneut zu senden."
+            }
+
+            (Self::TargetChatMissing, Lang::En) => {
+                "Please give the id of the target chat, e.g. -1001234567890."
+            }
+            (Self::TargetChatMissing, Lang::It) => {
+                "Indica l'id della chat di destinazione, ad esempio -1001234567890."
+            }
+            (Self::TargetChatMissing, Lang::De) => {
+                "Bitte gib die ID des Ziel-Chats an, z. B. -1001234567890."
+            }
+
+            (Self::AlreadyInThisChat, Lang::En) => "This chat already tracks {repository}.",
+            (Self::AlreadyInThisChat, Lang::It) => "Questa chat segue già {repository}.",
+            (Self::AlreadyInThisChat, Lang::De) => "Dieser Chat verfolgt {repository} bereits.",
+
+            (Self::ChatNotServed, Lang::En) => "This bot does not serve chat {chat}.",
+            (Self::ChatNotServed, Lang::It) => "Questo bot non serve la chat {chat}.",
+            (Self::ChatNotServed, Lang::De) => "Dieser Bot bedient den Chat {chat} nicht.",
+
+            (Self::NotTargetAdmin, Lang::En) => {
+                "Only an administrator of chat {chat} can hand settings over to it."
+            }
+            (Self::NotTargetAdmin, Lang::It) => {
+                "Solo un amministratore della chat {chat} può trasferirle delle impostazioni."
+            }
+            (Self::NotTargetAdmin, Lang::De) => {
+                "Nur ein Administrator von Chat {chat} kann Einstellungen an ihn übergeben."
+            }
+
+            (Self::HandoffFailed, Lang::En) => "Failed to hand over {repository}: {error}",
+            (Self::HandoffFailed, Lang::It) => "Impossibile trasferire {repository}: {error}",
+            (Self::HandoffFailed, Lang::De) => {
+                "{repository} konnte nicht übergeben werden: {error}"
+            }
+
+            (Self::HandedOverHere, Lang::En) => {
+                "The settings of {repository} ({url}) were handed over to this chat, which now tracks it with them."
+            }
+            (Self::HandedOverHere, Lang::It) => {
+                "Le impostazioni di {repository} ({url}) sono state trasferite a questa chat, che ora lo segue con esse."
+            }
+            (Self::HandedOverHere, Lang::De) => {
+                "Die Einstellungen von {repository} ({url}) wurden an diesen Chat übergeben, der es jetzt damit verfolgt."
+            }
+
+            (Self::HandedOver, Lang::En) => {
+                "Chat {chat} now tracks {repository} with this chat's settings."
+            }
+            (Self::HandedOver, Lang::It) => {
+                "La chat {chat} ora segue {repository} con le impostazioni di questa chat."
+            }
+            (Self::HandedOver, Lang::De) => {
+                "Chat {chat} verfolgt {repository} jetzt mit den Einstellungen dieses Chats."
+            }
+
+            (Self::HandedOverUnannounced, Lang::En) => {
+                "Chat {chat} now tracks {repository} with this chat's settings, but the bot could not post there. Make sure it is a member of that chat."
+            }
+            (Self::HandedOverUnannounced, Lang::It) => {
+                "La chat {chat} ora segue {repository} con le impostazioni di questa chat, ma il bot non è riuscito a scriverci. Assicurati che ne sia membro."
+            }
+            (Self::HandedOverUnannounced, Lang::De) => {
+                "Chat {chat} verfolgt {repository} jetzt mit den Einstellungen dieses Chats, aber der Bot konnte dort nicht schreiben. Stelle sicher, dass er Mitglied dieses Chats ist."
+            }
+
+            (Self::TagMissing, Lang::En) => "Please provide the tag to watch for.",
+            (Self::TagMissing, Lang::It) => "Indica il tag da attendere.",
+            (Self::TagMissing, Lang::De) => "Bitte gib das Tag an, auf das gewartet werden soll.",
+
+            (Self::WatchSaveFailed, Lang::En) => "Failed to save tag watch: {error}",
+            (Self::WatchSaveFailed, Lang::It) => "Impossibile salvare l'attesa del tag: {error}",
+            (Self::WatchSaveFailed, Lang::De) => {
+                "Tag-Beobachtung konnte nicht gespeichert werden: {error}"
+            }
+
+            (Self::WatchingTag, Lang::En) => {
+                "Watching {url} for tag {tag}. I'll let you know once it's out."
+            }
+            (Self::WatchingTag, Lang::It) => {
+                "Attendo il tag {tag} su {url}. Ti avviserò appena esce."
+            }
+            (Self::WatchingTag, Lang::De) => {
+                "Ich warte auf Tag {tag} in {url} und melde mich, sobald es da ist."
+            }
+
+            (Self::AlreadyWatchingTag, Lang::En) => {
+                "This chat is already watching {url} for tag {tag}."
+            }
+            (Self::AlreadyWatchingTag, Lang::It) => {
+                "Questa chat attende già il tag {tag} su {url}."
+            }
+            (Self::AlreadyWatchingTag, Lang::De) => {
+                "Dieser Chat wartet bereits auf Tag {tag} in {url}."
+            }
+
+            (Self::TagAlreadyPublished, Lang::En) => {
+                "Tag {tag} has already been published on {url}."
+            }
+            (Self::TagAlreadyPublished, Lang::It) => {
+                "Il tag {tag} è già stato pubblicato su {url}."
+            }
+            (Self::TagAlreadyPublished, Lang::De) => {
+                "Tag {tag} wurde bereits in {url} veröffentlicht."
+            }
+
+            (Self::StaleFailed, Lang::En) => "Failed to load cached releases: {error}",
+            (Self::StaleFailed, Lang::It) => "Impossibile caricare le release in cache: {error}",
+            (Self::StaleFailed, Lang::De) => {
+                "Zwischengespeicherte Releases konnten nicht geladen werden: {error}"
+            }
+
+            (Self::NothingStale, Lang::En) => {
+                "No repository has gone more than {days} days without a release."
+            }
+            (Self::NothingStale, Lang::It) => {
+                "Nessun repository è rimasto più di {days} giorni senza una release."
+            }
+            (Self::NothingStale, Lang::De) => {
+                "Kein Repository war länger als {days} Tage ohne Release."
+            }
+
+            (Self::NoChartReleases, Lang::En) => {
+                "No releases of {repository} were recorded in the last {weeks} weeks."
+            }
+            (Self::NoChartReleases, Lang::It) => {
+                "Nessuna release di {repository} registrata nelle ultime {weeks} settimane."
+            }
+            (Self::NoChartReleases, Lang::De) => {
+                "In den letzten {weeks} Wochen wurden keine Releases von {repository} aufgezeichnet."
+            }
+
+            (Self::Chart, Lang::En) => {
+                "Releases of {repository} per week, last {weeks} weeks:\n{chart}\n{total} in total, at most {busiest} in a week. The newest week is on the right."
+            }
+            (Self::Chart, Lang::It) => {
+                "Release di {repository} per settimana, ultime {weeks} settimane:\n{chart}\n{total} in totale, al massimo {busiest} in una settimana. La settimana più recente è a destra."
+            }
+            (Self::Chart, Lang::De) => {
+                "Releases von {repository} pro Woche, letzte {weeks} Wochen:\n{chart}\n{total} insgesamt, höchstens {busiest} in einer Woche. Die neueste Woche steht rechts."
+            }
+
+            (Self::AllStillExist, Lang::En) => "All {count} tracked repositories still exist.",
+            (Self::AllStillExist, Lang::It) => {
+                "Tutti i {count} repository seguiti esistono ancora."
+            }
+            (Self::AllStillExist, Lang::De) => {
+                "Alle {count} verfolgten Repositories existieren noch."
+            }
+
+            (Self::UntrackGone, Lang::En) => {
+                "Stop tracking the {count} repositories that no longer exist?"
+            }
+            (Self::UntrackGone, Lang::It) => {
+                "Smettere di seguire i {count} repository che non esistono più?"
+            }
+            (Self::UntrackGone, Lang::De) => {
+                "Die {count} Repositories, die nicht mehr existieren, nicht mehr verfolgen?"
+            }
+
+            (Self::CheckedOf, Lang::En) => "Checked {checked} of {total} repositories:",
+            (Self::CheckedOf, Lang::It) => "Controllati {checked} di {total} repository:",
+            (Self::CheckedOf, Lang::De) => "{checked} von {total} Repositories geprüft:",
+
+            (Self::StoppedEarly, Lang::En) => {
+                "Stopped early: {error}. {count} repositories were not checked."
+            }
+            (Self::StoppedEarly, Lang::It) => {
+                "Interrotto in anticipo: {error}. {count} repository non sono stati controllati."
+            }
+            (Self::StoppedEarly, Lang::De) => {
+                "Vorzeitig beendet: {error}. {count} Repositories wurden nicht geprüft."
+            }
+
+            (Self::MovedTo, Lang::En) => {
+                "- {repository} moved to {url}; /untrack it and /track the new URL"
+            }
+            (Self::MovedTo, Lang::It) => {
+                "- {repository} è stato spostato su {url}; usa /untrack e poi /track con il nuovo URL"
+            }
+            (Self::MovedTo, Lang::De) => {
+                "- {repository} ist nach {url} umgezogen; /untrack und dann /track mit der neuen URL"
+            }
+
+            (Self::NoLongerExists, Lang::En) => "- {repository} no longer exists (404)",
+            (Self::NoLongerExists, Lang::It) => "- {repository} non esiste più (404)",
+            (Self::NoLongerExists, Lang::De) => "- {repository} existiert nicht mehr (404)",
+
+            (Self::CouldNotBeChecked, Lang::En) => "- {repository} could not be checked: {error}",
+            (Self::CouldNotBeChecked, Lang::It) => {
+                "- {repository} non è stato possibile controllarlo: {error}"
+            }
+            (Self::CouldNotBeChecked, Lang::De) => {
+                "- {repository} konnte nicht geprüft werden: {error}"
+            }
+
+            (Self::ReleasesSince, Lang::En) => "Releases of {repository} since {since}: {count}",
+            (Self::ReleasesSince, Lang::It) => "Release di {repository} dal {since}: {count}",
+            (Self::ReleasesSince, Lang::De) => "Releases von {repository} seit {since}: {count}",
+
+            (Self::AtLeast, Lang::En) => "at least {count}",
+            (Self::AtLeast, Lang::It) => "almeno {count}",
+            (Self::AtLeast, Lang::De) => "mindestens {count}",
+
+            (Self::OlderNotShown, Lang::En) => "…and {count} older ones not shown.",
+            (Self::OlderNotShown, Lang::It) => "…e altre {count} più vecchie non mostrate.",
+            (Self::OlderNotShown, Lang::De) => "…und {count} ältere, nicht angezeigt.",
+
+            (Self::StaleHeading, Lang::En) => "No release in more than {days} days:",
+            (Self::StaleHeading, Lang::It) => "Nessuna release da più di {days} giorni:",
+            (Self::StaleHeading, Lang::De) => "Kein Release seit mehr als {days} Tagen:",
+
+            (Self::StaleLine, Lang::En) => "- {repository}: {tag}, {days} days ago",
+            (Self::StaleLine, Lang::It) => "- {repository}: {tag}, {days} giorni fa",
+            (Self::StaleLine, Lang::De) => "- {repository}: {tag}, vor {days} Tagen",
+
+            (Self::RecentReleases, Lang::En) => "Recent releases of {repository}:",
+            (Self::RecentReleases, Lang::It) => "Release recenti di {repository}:",
+            (Self::RecentReleases, Lang::De) => "Neueste Releases von {repository}:",
+
+            (Self::ByAuthor, Lang::En) => "by {author}",
+            (Self::ByAuthor, Lang::It) => "di {author}",
+            (Self::ByAuthor, Lang::De) => "von {author}",
+
+            (Self::VelocityHeading, Lang::En) => "Release velocity of {repository}:",
+            (Self::VelocityHeading, Lang::It) => "Frequenza delle release di {repository}:",
+            (Self::VelocityHeading, Lang::De) => "Release-Frequenz von {repository}:",
+
+            (Self::Last30Days, Lang::En) => "- last 30 days: {releases}",
+            (Self::Last30Days, Lang::It) => "- ultimi 30 giorni: {releases}",
+            (Self::Last30Days, Lang::De) => "- letzte 30 Tage: {releases}",
+
+            (Self::Last90Days, Lang::En) => "- last 90 days: {releases}",
+            (Self::Last90Days, Lang::It) => "- ultimi 90 giorni: {releases}",
+            (Self::Last90Days, Lang::De) => "- letzte 90 Tage: {releases}",
+
+            (Self::AverageDays, Lang::En) => "- {days} days between releases on average",
+            (Self::AverageDays, Lang::It) => "- in media {days} giorni tra una release e l'altra",
+            (Self::AverageDays, Lang::De) => "- durchschnittlich {days} Tage zwischen Releases",
+
+            (Self::NoAverage, Lang::En) => "- not enough releases recorded for an average yet",
+            (Self::NoAverage, Lang::It) => {
+                "- non ci sono ancora abbastanza release registrate per una media"
+            }
+            (Self::NoAverage, Lang::De) => {
+                "- noch nicht genug aufgezeichnete Releases für einen Durchschnitt"
+            }
+
+            (Self::BasedOn, Lang::En) => "Based on {releases} recorded since {since}.",
+            (Self::BasedOn, Lang::It) => "In base a {releases} registrate dal {since}.",
+            (Self::BasedOn, Lang::De) => "Basierend auf {releases}, aufgezeichnet seit {since}.",
+
+            (Self::OneRelease, Lang::En) => "1 release",
+            (Self::OneRelease, Lang::It) => "1 release",
+            (Self::OneRelease, Lang::De) => "1 Release",
+
+            (Self::Releases, Lang::En) => "{count} releases",
+            (Self::Releases, Lang::It) => "{count} release",
+            (Self::Releases, Lang::De) => "{count} Releases",
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use crate::chat_settings::Lang;
+
+use super::Key;
+
+/// What the commands holding back or timing notifications answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleText {
+    /// Placeholders: `{repository}`, `{until}`.
+    SnoozedUntil,
+    /// Placeholder: `{repository}`.
+    SnoozeEnded,
+    /// Placeholder: `{until}`.
+    ChatSnoozedUntil,
+    ChatSnoozeOff,
+    /// Placeholder: `{releases}`.
+    ChatSnoozeOffMissed,
+    MinAgeInvalid,
+    /// Placeholder: `{repository}`.
+    MinAgeOff,
+    /// Placeholders: `{repository}`, `{minutes}`.
+    MinAgeOn,
+    ThrottleInvalid,
+    /// Placeholder: `{repository}`.
+    ThrottleOff,
+    /// Placeholders: `{repository}`, `{minutes}`.
+    ThrottleOn,
+    TimeoutInvalid,
+    /// Placeholder: `{repository}`.
+    TimeoutDefault,
+    /// Placeholders: `{repository}`, `{seconds}`.
+    TimeoutSet,
+    CollapseInvalid,
+    /// Placeholder: `{repository}`.
+    CollapseOff,
+    /// Placeholders: `{repository}`, `{minutes}`.
+    CollapseOn,
+}
+
+impl Key for ScheduleText {
+    fn text(self, lang: Lang) -> &'static str {
+        match (self, lang) {
+            (Self::SnoozedUntil, Lang::En) => {
+                "Notifications for {repository} are snoozed until {until}."
+            }
+            (Self::SnoozedUntil, Lang::It) => {
+                "Le notifiche di {repository} sono in pausa fino al {until}."
+            }
+            (Self::SnoozedUntil, Lang::De) => {
+                "Benachrichtigungen für {repository} sind bis {until} pausiert."
+            }
+
+            (Self::SnoozeEnded, Lang::En) => {
+                "Notifications for {repository} are no longer snoozed."
+            }
+            (Self::SnoozeEnded, Lang::It) => "Le notifiche di {repository} non sono più in pausa.",
+            (Self::SnoozeEnded, Lang::De) => {
+                "Benachrichtigungen für {repository} sind nicht mehr pausiert."
+            }
+
+            (Self::ChatSnoozedUntil, Lang::En) => {
+                "All notifications in this chat are snoozed until {until}."
+            }
+            (Self::ChatSnoozedUntil, Lang::It) => {
+                "Tutte le notifiche di questa chat sono in pausa fino al {until}."
+            }
+            (Self::ChatSnoozedUntil, Lang::De) => {
+                "Alle Benachrichtigungen in diesem Chat sind bis {until} pausiert."
+            }
+
+            (Self::ChatSnoozeOff, Lang::En) => "Notifications in this chat are no longer snoozed.",
+            (Self::ChatSnoozeOff, Lang::It) => "Le notifiche di questa chat non sono più in pausa.",
+            (Self::ChatSnoozeOff, Lang::De) => {
+                "Benachrichtigungen in diesem Chat sind nicht mehr pausiert."
+            }
+
+            (Self::ChatSnoozeOffMissed, Lang::En) => {
+                "Notifications in this chat are no longer snoozed. Released meanwhile: {releases}"
+            }
+            (Self::ChatSnoozeOffMissed, Lang::It) => {
+                "Le notifiche di questa chat non sono più in pausa. Uscite nel frattempo: {releases}"
+            }
+            (Self::ChatSnoozeOffMissed, Lang::De) => {
+                "Benachrichtigungen in diesem Chat sind nicht mehr pausiert. Inzwischen erschienen: {releases}"
+            }
+
+            (Self::MinAgeInvalid, Lang::En) => {
+                "The minimum age must be between 1m and 30d, or 0 to disable."
+            }
+            (Self::MinAgeInvalid, Lang::It) => {
+                "L'età minima deve essere tra 1m e 30d, oppure 0 per disattivarla."
+            }
+            (Self::MinAgeInvalid, Lang::De) => {
+                "Das Mindestalter muss zwischen 1m und 30d liegen, oder 0 zum Ausschalten."
+            }
+
+            (Self::MinAgeOff, Lang::En) => {
+                "New releases of {repository} will be announced as soon as they are found."
+            }
+            (Self::MinAgeOff, Lang::It) => {
+                "Le nuove release di {repository} saranno annunciate appena trovate."
+            }
+            (Self::MinAgeOff, Lang::De) => {
+                "Neue Releases von {repository} werden angekündigt, sobald sie gefunden werden."
+            }
+
+            (Self::MinAgeOn, Lang::En) => {
+                "New releases of {repository} will be announced once they are {minutes} minutes old."
+            }
+            (Self::MinAgeOn, Lang::It) => {
+                "Le nuove release di {repository} saranno annunciate quando avranno {minutes} minuti."
+            }
+            (Self::MinAgeOn, Lang::De) => {
+                "Neue Releases von {repository} werden angekündigt, sobald sie {minutes} Minuten alt sind."
+            }
+
+            (Self::ThrottleInvalid, Lang::En) => {
+                "The throttle window must be between 1m and 30d, or 0 to disable."
+            }
+            (Self::ThrottleInvalid, Lang::It) => {
+                "L'intervallo di limitazione deve essere tra 1m e 30d, oppure 0 per disattivarlo."
+            }
+            (Self::ThrottleInvalid, Lang::De) => {
+                "Das Drosselungsfenster muss zwischen 1m und 30d liegen, oder 0 zum Ausschalten."
+            }
+
+            (Self::ThrottleOff, Lang::En) => "{repository} is no longer throttled.",
+            (Self::ThrottleOff, Lang::It) => "{repository} non è più limitato.",
+            (Self::ThrottleOff, Lang::De) => "{repository} wird nicht mehr gedrosselt.",
+
+            (Self::ThrottleOn, Lang::En) => {
+                "{repository} will notify at most once every {minutes} minutes, announcing only the newest release."
+            }
+            (Self::ThrottleOn, Lang::It) => {
+                "{repository} notificherà al massimo una volta ogni {minutes} minuti, annunciando solo la release più recente."
+            }
+            (Self::ThrottleOn, Lang::De) => {
+                "{repository} meldet sich höchstens alle {minutes} Minuten und kündigt nur das neueste Release an."
+            }
+
+            (Self::TimeoutInvalid, Lang::En) => {
+                "The timeout must be between 1s and 10m, or 0 for the default."
+            }
+            (Self::TimeoutInvalid, Lang::It) => {
+                "Il timeout deve essere tra 1s e 10m, oppure 0 per quello predefinito."
+            }
+            (Self::TimeoutInvalid, Lang::De) => {
+                "Das Timeout muss zwischen 1s und 10m liegen, oder 0 für den Standard."
+            }
+
+            (Self::TimeoutDefault, Lang::En) => {
+                "Requests for {repository} use the default timeout again."
+            }
+            (Self::TimeoutDefault, Lang::It) => {
+                "Le richieste per {repository} usano di nuovo il timeout predefinito."
+            }
+            (Self::TimeoutDefault, Lang::De) => {
+                "Anfragen für {repository} nutzen wieder das Standard-Timeout."
+            }
+
+            (Self::TimeoutSet, Lang::En) => {
+                "Requests for {repository} may now take up to {seconds} seconds."
+            }
+            (Self::TimeoutSet, Lang::It) => {
+                "Le richieste per {repository} ora possono durare fino a {seconds} secondi."
+            }
+            (Self::TimeoutSet, Lang::De) => {
+                "Anfragen für {repository} dürfen jetzt bis zu {seconds} Sekunden dauern."
+            }
+
+            (Self::CollapseInvalid, Lang::En) => {
+                "Please provide the window in minutes, or 0 to disable."
+            }
+            (Self::CollapseInvalid, Lang::It) => {
+                "Indica l'intervallo in minuti, oppure 0 per disattivarlo."
+            }
+            (Self::CollapseInvalid, Lang::De) => {
+                "Bitte gib das Fenster in Minuten an, oder 0 zum Ausschalten."
+            }
+
+            (Self::CollapseOff, Lang::En) => "Every prerelease of {repository} will be notified.",
+            (Self::CollapseOff, Lang::It) => "Ogni prerelease di {repository} sarà notificata.",
+            (Self::CollapseOff, Lang::De) => "Jedes Prerelease von {repository} wird gemeldet.",
+
+            (Self::CollapseOn, Lang::En) => {
+                "Prereleases of the same version of {repository} will notify at most once every {minutes} minutes."
+            }
+            (Self::CollapseOn, Lang::It) => {
+                "Le prerelease della stessa versione di {repository} saranno notificate al massimo una volta ogni {minutes} minuti."
+            }
+            (Self::CollapseOn, Lang::De) => {
+                "Prereleases derselben Version von {repository} melden sich höchstens alle {minutes} Minuten."
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use crate::chat_settings::Lang;
+
+use super::Key;
+
+/// What the commands changing how one repository is followed answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingText {
+    IgnoreEveryTag,
+    /// Placeholders: `{repository}`, `{pattern}`.
+    TagsIgnored,
+    /// Placeholder: `{repository}`.
+    NoTagsIgnored,
+    /// Placeholder: `{repository}`.
+    CriticalOn,
+    /// Placeholder: `{repository}`.
+    CriticalOff,
+    /// Placeholders: `{repository}`, `{user}`.
+    MentionSet,
+    /// Placeholder: `{repository}`.
+    MentionCleared,
+    /// Placeholder: `{max}`.
+    AliasInvalid,
+    /// Placeholder: `{alias}`.
+    AliasTaken,
+    /// Placeholder: `{error}`.
+    AliasSaveFailed,
+    /// Placeholders: `{repository}`, `{alias}`.
+    AliasSet,
+    /// Placeholder: `{repository}`.
+    AliasCleared,
+    /// Placeholders: `{repository}`, `{prefix}`.
+    ComponentSet,
+    /// Placeholder: `{repository}`.
+    ComponentCleared,
+}
+
+impl Key for SettingText {
+    fn text(self, lang: Lang) -> &'static str {
+        match (self, lang) {
+            (Self::IgnoreEveryTag, Lang::En) => "That pattern would ignore every tag.",
+            (Self::IgnoreEveryTag, Lang::It) => "Questo pattern ignorerebbe ogni tag.",
+            (Self::IgnoreEveryTag, Lang::De) => "Dieses Muster würde jeden Tag ignorieren.",
+
+            (Self::TagsIgnored, Lang::En) => {
+                "Tags of {repository} matching {pattern} are ignored; the newest other release is announced instead."
+            }
+            (Self::TagsIgnored, Lang::It) => {
+                "I tag di {repository} che corrispondono a {pattern} sono ignorati; viene annunciata invece la release più recente tra le altre."
+            }
+            (Self::TagsIgnored, Lang::De) => {
+                "Tags von {repository}, die zu {pattern} passen, werden ignoriert; stattdessen wird das neueste andere Release angekündigt."
+            }
+
+            (Self::NoTagsIgnored, Lang::En) => "No tags of {repository} are ignored anymore.",
+            (Self::NoTagsIgnored, Lang::It) => "Nessun tag di {repository} viene più ignorato.",
+            (Self::NoTagsIgnored, Lang::De) => {
+                "Es werden keine Tags von {repository} mehr ignoriert."
+            }
+
+            (Self::CriticalOn, Lang::En) => {
+                "{repository} is critical: its releases notify right away, even while muted or snoozed."
+            }
+            (Self::CriticalOn, Lang::It) => {
+                "{repository} è critico: le sue release vengono notificate subito, anche se silenziato o in pausa."
+            }
+            (Self::CriticalOn, Lang::De) => {
+                "{repository} ist kritisch: Seine Releases melden sich sofort, auch stummgeschaltet oder pausiert."
+            }
+
+            (Self::CriticalOff, Lang::En) => "{repository} is no longer critical.",
+            (Self::CriticalOff, Lang::It) => "{repository} non è più critico.",
+            (Self::CriticalOff, Lang::De) => "{repository} ist nicht mehr kritisch.",
+
+            (Self::MentionSet, Lang::En) => {
+                "Release notifications of {repository} now mention {user}."
+            }
+            (Self::MentionSet, Lang::It) => {
+                "Le notifiche delle release di {repository} ora menzionano {user}."
+            }
+            (Self::MentionSet, Lang::De) => {
+                "Release-Benachrichtigungen von {repository} erwähnen jetzt {user}."
+            }
+
+            (Self::MentionCleared, Lang::En) => {
+                "Release notifications of {repository} no longer mention anyone."
+            }
+            (Self::MentionCleared, Lang::It) => {
+                "Le notifiche delle release di {repository} non menzionano più nessuno."
+            }
+            (Self::MentionCleared, Lang::De) => {
+                "Release-Benachrichtigungen von {repository} erwähnen niemanden mehr."
+            }
+
+            (Self::AliasInvalid, Lang::En) => {
+                "Aliases are up to {max} letters, digits, -, _ or ., starting with a letter or digit."
+            }
+            (Self::AliasInvalid, Lang::It) => {
+                "Gli alias hanno al massimo {max} lettere, cifre, -, _ o ., e iniziano con una lettera o una cifra."
+            }
+            (Self::AliasInvalid, Lang::De) => {
+                "Aliase bestehen aus höchstens {max} Buchstaben, Ziffern, -, _ oder . und beginnen mit einem Buchstaben oder einer Ziffer."
+            }
+
+            (Self::AliasTaken, Lang::En) => {
+                "Another repository of this chat is already called {alias}."
+            }
+            (Self::AliasTaken, Lang::It) => {
+                "Un altro repository di questa chat si chiama già {alias}."
+            }
+            (Self::AliasTaken, Lang::De) => {
+                "Ein anderes Repository dieses Chats heißt bereits {alias}."
+            }
+
+            (Self::AliasSaveFailed, Lang::En) => "Failed to save the alias: {error}",
+            (Self::AliasSaveFailed, Lang::It) => "Impossibile salvare l'alias: {error}",
+            (Self::AliasSaveFailed, Lang::De) => {
+                "Der Alias konnte nicht gespeichert werden: {error}"
+            }
+
+            (Self::AliasSet, Lang::En) => {
+                "{repository} can now be given as {alias} wherever a URL is expected."
+            }
+            (Self::AliasSet, Lang::It) => {
+                "Ora {repository} si può indicare come {alias} ovunque serva un URL."
+            }
+            (Self::AliasSet, Lang::De) => {
+                "{repository} kann jetzt überall, wo eine URL erwartet wird, als {alias} angegeben werden."
+            }
+
+            (Self::AliasCleared, Lang::En) => "{repository} has no alias anymore.",
+            (Self::AliasCleared, Lang::It) => "{repository} non ha più un alias.",
+            (Self::AliasCleared, Lang::De) => "{repository} hat keinen Alias mehr.",
+
+            (Self::ComponentSet, Lang::En) => {
+                "Only tags of {repository} starting with {prefix} are followed now, shown without the prefix."
+            }
+            (Self::ComponentSet, Lang::It) => {
+                "Ora si seguono solo i tag di {repository} che iniziano con {prefix}, mostrati senza il prefisso."
+            }
+            (Self::ComponentSet, Lang::De) => {
+                "Es werden jetzt nur Tags von {repository} verfolgt, die mit {prefix} beginnen, angezeigt ohne das Präfix."
+            }
+
+            (Self::ComponentCleared, Lang::En) => "Every tag of {repository} is followed again.",
+            (Self::ComponentCleared, Lang::It) => {
+                "Ogni tag di {repository} viene di nuovo seguito."
+            }
+            (Self::ComponentCleared, Lang::De) => {
+                "Jeder Tag von {repository} wird wieder verfolgt."
+            }
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use crate::chat_settings::Lang;
+
+use super::Key;
+
+/// What the commands choosing where the releases of a repository come from answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceText {
+    DiscussionCategoryMissing,
+    /// Placeholders: `{repository}`, `{category}`.
+    DiscussionsOn,
+    DiscussionsNeedToken,
+    /// Placeholder: `{repository}`.
+    FollowsReleases,
+    /// Placeholder: `{repository}`.
+    ExactTagsOn,
+    /// Placeholder: `{repository}`.
+    ExactTagsOff,
+    /// Placeholder: `{repository}`.
+    TagsOnlyOn,
+    /// Placeholders: `{repository}`, `{pattern}`.
+    TagCaptureSet,
+    /// Placeholder: `{repository}`.
+    TagCaptureCleared,
+    MilestoneInvalid,
+    /// Placeholders: `{repository}`, `{every}`, `{branch}`.
+    MilestonesOn,
+    DefaultBranch,
+    MirrorOfItself,
+    /// Placeholder: `{error}`.
+    MirrorSaveFailed,
+    /// Placeholders: `{mirror}`, `{position}`, `{repository}`.
+    MirrorAdded,
+    /// Placeholders: `{mirror}`, `{repository}`.
+    MirrorExists,
+}
+
+impl Key for SourceText {
+    fn text(self, lang: Lang) -> &'static str {
+        match (self, lang) {
+            (Self::DiscussionCategoryMissing, Lang::En) => {
+                "Please give a discussion category, or off."
+            }
+            (Self::DiscussionCategoryMissing, Lang::It) => {
+                "Indica una categoria di discussioni, oppure off."
+            }
+            (Self::DiscussionCategoryMissing, Lang::De) => {
+                "Bitte gib eine Diskussionskategorie an, oder off."
+            }
+
+            (Self::DiscussionsOn, Lang::En) => {
+                "{repository} now announces new discussions in the {category} category instead of releases."
+            }
+            (Self::DiscussionsOn, Lang::It) => {
+                "{repository} ora annuncia le nuove discussioni della categoria {category} invece delle release."
+            }
+            (Self::DiscussionsOn, Lang::De) => {
+                "{repository} kündigt jetzt neue Diskussionen der Kategorie {category} statt Releases an."
+            }
+
+            (Self::DiscussionsNeedToken, Lang::En) => {
+                "Note: GitHub only serves discussions to authenticated requests, so GITHUB_TOKEN must be configured."
+            }
+            (Self::DiscussionsNeedToken, Lang::It) => {
+                "Nota: GitHub fornisce le discussioni solo a richieste autenticate, quindi va configurato GITHUB_TOKEN."
+            }
+            (Self::DiscussionsNeedToken, Lang::De) => {
+                "Hinweis: GitHub liefert Diskussionen nur an authentifizierte Anfragen, daher muss GITHUB_TOKEN gesetzt sein."
+            }
+
+            (Self::FollowsReleases, Lang::En) => "{repository} now follows its GitHub Releases.",
+            (Self::FollowsReleases, Lang::It) => "{repository} ora segue le sue GitHub Release.",
+            (Self::FollowsReleases, Lang::De) => "{repository} folgt jetzt seinen GitHub-Releases.",
+
+            (Self::ExactTagsOn, Lang::En) => {
+                "Any new tag of {repository} notifies, even one that only adds or drops a leading v."
+            }
+            (Self::ExactTagsOn, Lang::It) => {
+                "Ogni nuovo tag di {repository} viene notificato, anche se aggiunge o toglie solo una v iniziale."
+            }
+            (Self::ExactTagsOn, Lang::De) => {
+                "Jeder neue Tag von {repository} meldet sich, auch wenn er nur ein führendes v hinzufügt oder weglässt."
+            }
+
+            (Self::ExactTagsOff, Lang::En) => {
+                "Tags of {repository} naming the same version, like 1.2.3 and v1.2.3, no longer notify twice."
+            }
+            (Self::ExactTagsOff, Lang::It) => {
+                "I tag di {repository} che indicano la stessa versione, come 1.2.3 e v1.2.3, non vengono più notificati due volte."
+            }
+            (Self::ExactTagsOff, Lang::De) => {
+                "Tags von {repository} mit derselben Version, wie 1.2.3 und v1.2.3, melden sich nicht mehr doppelt."
+            }
+
+            (Self::TagsOnlyOn, Lang::En) => {
+                "{repository} now follows its newest git tag and ignores GitHub Releases."
+            }
+            (Self::TagsOnlyOn, Lang::It) => {
+                "{repository} ora segue il suo tag git più recente e ignora le GitHub Release."
+            }
+            (Self::TagsOnlyOn, Lang::De) => {
+                "{repository} folgt jetzt seinem neuesten Git-Tag und ignoriert GitHub-Releases."
+            }
+
+            (Self::TagCaptureSet, Lang::En) => {
+                "Only tags of {repository} matching {pattern} are followed, and the version they capture is announced."
+            }
+            (Self::TagCaptureSet, Lang::It) => {
+                "Si seguono solo i tag di {repository} che corrispondono a {pattern}, e si annuncia la versione che catturano."
+            }
+            (Self::TagCaptureSet, Lang::De) => {
+                "Es werden nur Tags von {repository} verfolgt, die zu {pattern} passen, und die erfasste Version wird angekündigt."
+            }
+
+            (Self::TagCaptureCleared, Lang::En) => {
+                "Tags of {repository} are followed and announced as they are again."
+            }
+            (Self::TagCaptureCleared, Lang::It) => {
+                "I tag di {repository} vengono di nuovo seguiti e annunciati così come sono."
+            }
+            (Self::TagCaptureCleared, Lang::De) => {
+                "Tags von {repository} werden wieder so verfolgt und angekündigt, wie sie sind."
+            }
+
+            (Self::MilestoneInvalid, Lang::En) => {
+                "Please give a number of commits, e.g. 100, or off."
+            }
+            (Self::MilestoneInvalid, Lang::It) => {
+                "Indica un numero di commit, ad es. 100, oppure off."
+            }
+            (Self::MilestoneInvalid, Lang::De) => {
+                "Bitte gib eine Anzahl Commits an, z. B. 100, oder off."
+            }
+
+            (Self::MilestonesOn, Lang::En) => {
+                "{repository} now announces every {every} commits on {branch} instead of releases."
+            }
+            (Self::MilestonesOn, Lang::It) => {
+                "{repository} ora annuncia ogni {every} commit su {branch} invece delle release."
+            }
+            (Self::MilestonesOn, Lang::De) => {
+                "{repository} kündigt jetzt alle {every} Commits auf {branch} statt Releases an."
+            }
+
+            (Self::DefaultBranch, Lang::En) => "the default branch",
+            (Self::DefaultBranch, Lang::It) => "il branch predefinito",
+            (Self::DefaultBranch, Lang::De) => "dem Standard-Branch",
+
+            (Self::MirrorOfItself, Lang::En) => "A repository cannot be its own mirror.",
+            (Self::MirrorOfItself, Lang::It) => {
+                "Un repository non può essere il mirror di se stesso."
+            }
+            (Self::MirrorOfItself, Lang::De) => {
+                "Ein Repository kann nicht sein eigener Mirror sein."
+            }
+
+            (Self::MirrorSaveFailed, Lang::En) => "Failed to save mirror: {error}",
+            (Self::MirrorSaveFailed, Lang::It) => "Impossibile salvare il mirror: {error}",
+            (Self::MirrorSaveFailed, Lang::De) => {
+                "Der Mirror konnte nicht gespeichert werden: {error}"
+            }
+
+            (Self::MirrorAdded, Lang::En) => {
+                "Added {mirror} as mirror #{position} of {repository}."
+            }
+            (Self::MirrorAdded, Lang::It) => {
+                "Aggiunto {mirror} come mirror n. {position} di {repository}."
+            }
+            (Self::MirrorAdded, Lang::De) => {
+                "{mirror} wurde als Mirror Nr. {position} von {repository} hinzugefügt."
+            }
+
+            (Self::MirrorExists, Lang::En) => "{mirror} is already a mirror of {repository}.",
+            (Self::MirrorExists, Lang::It) => "{mirror} è già un mirror di {repository}.",
+            (Self::MirrorExists, Lang::De) => "{mirror} ist bereits ein Mirror von {repository}.",
+        }
+    }
+}
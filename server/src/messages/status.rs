@@ -0,0 +1,172 @@
+use crate::chat_settings::Lang;
+
+use super::Key;
+
+/// What the commands reporting on releases and held back notifications answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusText {
+    NothingPending,
+    DroppedOne,
+    /// Placeholder: `{count}`.
+    Dropped,
+    WaitingOne,
+    /// Placeholder: `{count}`.
+    Waiting,
+    ClearHint,
+    /// Placeholder: `{until}`.
+    HeldSnoozed,
+    HeldSnoozeEnded,
+    /// Placeholder: `{minutes}`.
+    HeldYoung,
+    /// Placeholder: `{error}`.
+    RecordSeenFailed,
+    /// Placeholders: `{option}`, `{flag}`.
+    UnknownOption,
+    SingleUrl,
+    /// Placeholders: `{name}`, `{tag}`, `{link}`.
+    LatestRelease,
+    /// Placeholder: `{name}`.
+    NoReleaseOrTag,
+    /// Placeholder: `{name}`.
+    TagsOnlyNoPrereleases,
+    /// Placeholders: `{name}`, `{tag}`, `{link}`.
+    NewestPrerelease,
+    /// Placeholder: `{name}`.
+    NoPublishedReleases,
+    /// Placeholder: `{count}`.
+    Tracking,
+    /// Placeholder: `{until}`.
+    AllSnoozed,
+    /// Placeholder: `{releases}`.
+    ReleasedMeanwhile,
+    SnoozeOver,
+    NotificationsOn,
+}
+
+impl Key for StatusText {
+    fn text(self, lang: Lang) -> &'static str {
+        match (self, lang) {
+            (Self::NothingPending, Lang::En) => "No notifications are waiting for this chat.",
+            (Self::NothingPending, Lang::It) => "Nessuna notifica è in attesa per questa chat.",
+            (Self::NothingPending, Lang::De) => "Für diesen Chat warten keine Benachrichtigungen.",
+
+            (Self::DroppedOne, Lang::En) => "Dropped 1 waiting notification:",
+            (Self::DroppedOne, Lang::It) => "Scartata 1 notifica in attesa:",
+            (Self::DroppedOne, Lang::De) => "1 wartende Benachrichtigung verworfen:",
+
+            (Self::Dropped, Lang::En) => "Dropped {count} waiting notifications:",
+            (Self::Dropped, Lang::It) => "Scartate {count} notifiche in attesa:",
+            (Self::Dropped, Lang::De) => "{count} wartende Benachrichtigungen verworfen:",
+
+            (Self::WaitingOne, Lang::En) => "1 notification is waiting for this chat:",
+            (Self::WaitingOne, Lang::It) => "1 notifica è in attesa per questa chat:",
+            (Self::WaitingOne, Lang::De) => "1 Benachrichtigung wartet auf diesen Chat:",
+
+            (Self::Waiting, Lang::En) => "{count} notifications are waiting for this chat:",
+            (Self::Waiting, Lang::It) => "{count} notifiche sono in attesa per questa chat:",
+            (Self::Waiting, Lang::De) => "{count} Benachrichtigungen warten auf diesen Chat:",
+
+            (Self::ClearHint, Lang::En) => "Send /pending clear to drop them.",
+            (Self::ClearHint, Lang::It) => "Invia /pending clear per scartarle.",
+            (Self::ClearHint, Lang::De) => "Sende /pending clear, um sie zu verwerfen.",
+
+            (Self::HeldSnoozed, Lang::En) => "snoozed until {until}",
+            (Self::HeldSnoozed, Lang::It) => "in pausa fino al {until}",
+            (Self::HeldSnoozed, Lang::De) => "pausiert bis {until}",
+
+            (Self::HeldSnoozeEnded, Lang::En) => "snooze ended, announced on the next poll",
+            (Self::HeldSnoozeEnded, Lang::It) => "pausa finita, annunciata al prossimo controllo",
+            (Self::HeldSnoozeEnded, Lang::De) => {
+                "Pause beendet, wird bei der nächsten Abfrage angekündigt"
+            }
+
+            (Self::HeldYoung, Lang::En) => "waiting to be {minutes} minutes old",
+            (Self::HeldYoung, Lang::It) => "in attesa di avere {minutes} minuti",
+            (Self::HeldYoung, Lang::De) => "wartet, bis es {minutes} Minuten alt ist",
+
+            (Self::RecordSeenFailed, Lang::En) => "Failed to record the release as seen: {error}",
+            (Self::RecordSeenFailed, Lang::It) => {
+                "Impossibile segnare la release come vista: {error}"
+            }
+            (Self::RecordSeenFailed, Lang::De) => {
+                "Release konnte nicht als gesehen markiert werden: {error}"
+            }
+
+            (Self::UnknownOption, Lang::En) => "Unknown option {option}; only {flag} is supported.",
+            (Self::UnknownOption, Lang::It) => {
+                "Opzione sconosciuta {option}; è supportata solo {flag}."
+            }
+            (Self::UnknownOption, Lang::De) => {
+                "Unbekannte Option {option}; nur {flag} wird unterstützt."
+            }
+
+            (Self::SingleUrl, Lang::En) => "Please give a single repository URL.",
+            (Self::SingleUrl, Lang::It) => "Indica un solo URL di repository.",
+            (Self::SingleUrl, Lang::De) => "Bitte gib nur eine Repository-URL an.",
+
+            (Self::LatestRelease, Lang::En) => "Latest release of {name}: {tag}\n{link}",
+            (Self::LatestRelease, Lang::It) => "Ultima release di {name}: {tag}\n{link}",
+            (Self::LatestRelease, Lang::De) => "Neuestes Release von {name}: {tag}\n{link}",
+
+            (Self::NoReleaseOrTag, Lang::En) => "No release or tag of {name} found.",
+            (Self::NoReleaseOrTag, Lang::It) => "Nessuna release o tag trovato per {name}.",
+            (Self::NoReleaseOrTag, Lang::De) => "Kein Release oder Tag von {name} gefunden.",
+
+            (Self::TagsOnlyNoPrereleases, Lang::En) => {
+                "{name} is tracked by its tags only, which have no prereleases."
+            }
+            (Self::TagsOnlyNoPrereleases, Lang::It) => {
+                "{name} è seguito solo tramite i tag, che non hanno prerelease."
+            }
+            (Self::TagsOnlyNoPrereleases, Lang::De) => {
+                "{name} wird nur über Tags verfolgt, die keine Prereleases haben."
+            }
+
+            (Self::NewestPrerelease, Lang::En) => {
+                "Newest release of {name}, prereleases included: {tag}\n{link}"
+            }
+            (Self::NewestPrerelease, Lang::It) => {
+                "Release più recente di {name}, prerelease incluse: {tag}\n{link}"
+            }
+            (Self::NewestPrerelease, Lang::De) => {
+                "Neuestes Release von {name}, Prereleases eingeschlossen: {tag}\n{link}"
+            }
+
+            (Self::NoPublishedReleases, Lang::En) => "{name} has no published releases.",
+            (Self::NoPublishedReleases, Lang::It) => "{name} non ha release pubblicate.",
+            (Self::NoPublishedReleases, Lang::De) => "{name} hat keine veröffentlichten Releases.",
+
+            (Self::Tracking, Lang::En) => "Tracking {count} repositories.",
+            (Self::Tracking, Lang::It) => "{count} repository seguiti.",
+            (Self::Tracking, Lang::De) => "{count} Repositories werden verfolgt.",
+
+            (Self::AllSnoozed, Lang::En) => {
+                "All notifications are snoozed until {until}; resume them with /snooze_all off."
+            }
+            (Self::AllSnoozed, Lang::It) => {
+                "Tutte le notifiche sono in pausa fino al {until}; riattivale con /snooze_all off."
+            }
+            (Self::AllSnoozed, Lang::De) => {
+                "Alle Benachrichtigungen sind bis {until} pausiert; setze sie mit /snooze_all off fort."
+            }
+
+            (Self::ReleasedMeanwhile, Lang::En) => "Released meanwhile: {releases}",
+            (Self::ReleasedMeanwhile, Lang::It) => "Uscite nel frattempo: {releases}",
+            (Self::ReleasedMeanwhile, Lang::De) => "Inzwischen erschienen: {releases}",
+
+            (Self::SnoozeOver, Lang::En) => {
+                "The snooze has ended; notifications resume with the next poll."
+            }
+            (Self::SnoozeOver, Lang::It) => {
+                "La pausa è finita; le notifiche riprendono al prossimo controllo."
+            }
+            (Self::SnoozeOver, Lang::De) => {
+                "Die Pause ist beendet; Benachrichtigungen folgen ab der nächsten Abfrage."
+            }
+
+            (Self::NotificationsOn, Lang::En) => "Notifications are on.",
+            (Self::NotificationsOn, Lang::It) => "Le notifiche sono attive.",
+            (Self::NotificationsOn, Lang::De) => "Benachrichtigungen sind an.",
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use crate::chat_settings::Lang;
+
+use super::Key;
+
+/// What notifications and the chat's language and format settings say.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Text {
+    NewRelease,
+    NewTag,
+    WhileSnoozed,
+    ChatSnoozeEnded,
+    /// Placeholder: `{language}`.
+    LanguageIs,
+    /// Placeholder: `{language}`.
+    LanguageSet,
+    UnknownLanguage,
+    /// Placeholder: `{format}`.
+    FormatIs,
+    /// Placeholder: `{format}`.
+    FormatSet,
+    UnknownFormat,
+    /// Placeholders: `{tag}`, `{latest}`; `{repository}` is left for the caller's link.
+    ReleaseRemoved,
+    TagWatchOut,
+    TagWatchOn,
+    NewAnnouncement,
+    MilestoneReached,
+    /// Placeholders: `{count}`, `{branch}`.
+    MilestoneCommits,
+    TheDefaultBranch,
+    /// `{repository}` is left for the caller's link.
+    NoLongerAccessible,
+    InaccessiblePaused,
+    InaccessibleWatched,
+    /// `{repository}` is left for the caller's link.
+    AccessibleAgain,
+}
+
+impl Key for Text {
+    fn text(self, lang: Lang) -> &'static str {
+        match (self, lang) {
+            (Self::NewRelease, Lang::En) => "New release for",
+            (Self::NewRelease, Lang::It) => "Nuova release per",
+            (Self::NewRelease, Lang::De) => "Neues Release für",
+
+            (Self::NewTag, Lang::En) => "New tag for",
+            (Self::NewTag, Lang::It) => "Nuovo tag per",
+            (Self::NewTag, Lang::De) => "Neuer Tag für",
+
+            (Self::WhileSnoozed, Lang::En) => "(while snoozed)",
+            (Self::WhileSnoozed, Lang::It) => "(durante la pausa)",
+            (Self::WhileSnoozed, Lang::De) => "(während der Pause)",
+
+            (Self::ChatSnoozeEnded, Lang::En) => {
+                "Notifications are back on. Released while this chat was snoozed:"
+            }
+            (Self::ChatSnoozeEnded, Lang::It) => {
+                "Le notifiche sono di nuovo attive. Uscite durante la pausa della chat:"
+            }
+            (Self::ChatSnoozeEnded, Lang::De) => {
+                "Benachrichtigungen sind wieder aktiv. Während der Pause des Chats erschienen:"
+            }
+
+            (Self::LanguageIs, Lang::En) => {
+                "Messages in this chat are in {language}. Change it with /language en, it or de."
+            }
+            (Self::LanguageIs, Lang::It) => {
+                "I messaggi in questa chat sono in {language}. Cambiala con /language en, it o de."
+            }
+            (Self::LanguageIs, Lang::De) => {
+                "Nachrichten in diesem Chat sind auf {language}. Ändern mit /language en, it oder de."
+            }
+
+            (Self::LanguageSet, Lang::En) => "Messages in this chat will now be in {language}.",
+            (Self::LanguageSet, Lang::It) => {
+                "Da ora i messaggi in questa chat saranno in {language}."
+            }
+            (Self::LanguageSet, Lang::De) => {
+                "Nachrichten in diesem Chat sind ab jetzt auf {language}."
+            }
+
+            (Self::UnknownLanguage, Lang::En) => "Unknown language. Use en, it or de.",
+            (Self::UnknownLanguage, Lang::It) => "Lingua sconosciuta. Usa en, it o de.",
+            (Self::UnknownLanguage, Lang::De) => "Unbekannte Sprache. Verwende en, it oder de.",
+
+            (Self::FormatIs, Lang::En) => {
+                "Messages in this chat use {format}. Change it with /format html or /format markdown."
+            }
+            (Self::FormatIs, Lang::It) => {
+                "I messaggi in questa chat usano {format}. Cambialo con /format html o /format markdown."
+            }
+            (Self::FormatIs, Lang::De) => {
+                "Nachrichten in diesem Chat nutzen {format}. Ändern mit /format html oder /format markdown."
+            }
+
+            (Self::FormatSet, Lang::En) => "Messages in this chat will now use {format}.",
+            (Self::FormatSet, Lang::It) => "Da ora i messaggi in questa chat useranno {format}.",
+            (Self::FormatSet, Lang::De) => "Nachrichten in diesem Chat nutzen ab jetzt {format}.",
+
+            (Self::UnknownFormat, Lang::En) => "Unknown format. Use html or markdown.",
+            (Self::UnknownFormat, Lang::It) => "Formato sconosciuto. Usa html o markdown.",
+            (Self::UnknownFormat, Lang::De) => "Unbekanntes Format. Verwende html oder markdown.",
+
+            (Self::ReleaseRemoved, Lang::En) => {
+                "Release {tag} of {repository} was removed; the latest release is now {latest}."
+            }
+            (Self::ReleaseRemoved, Lang::It) => {
+                "La release {tag} di {repository} è stata rimossa; ora l'ultima release è {latest}."
+            }
+            (Self::ReleaseRemoved, Lang::De) => {
+                "Release {tag} von {repository} wurde entfernt; das neueste Release ist jetzt {latest}."
+            }
+
+            (Self::TagWatchOut, Lang::En) => "The tag you were watching is out:",
+            (Self::TagWatchOut, Lang::It) => "Il tag che stavi aspettando è uscito:",
+            (Self::TagWatchOut, Lang::De) => "Der Tag, auf den du gewartet hast, ist da:",
+
+            (Self::TagWatchOn, Lang::En) => "on",
+            (Self::TagWatchOn, Lang::It) => "su",
+            (Self::TagWatchOn, Lang::De) => "in",
+
+            (Self::NewAnnouncement, Lang::En) => "New announcement for",
+            (Self::NewAnnouncement, Lang::It) => "Nuovo annuncio per",
+            (Self::NewAnnouncement, Lang::De) => "Neue Ankündigung für",
+
+            (Self::MilestoneReached, Lang::En) => "reached",
+            (Self::MilestoneReached, Lang::It) => "ha raggiunto",
+            (Self::MilestoneReached, Lang::De) => "hat erreicht:",
+
+            (Self::MilestoneCommits, Lang::En) => "{count} commits on {branch}",
+            (Self::MilestoneCommits, Lang::It) => "{count} commit su {branch}",
+            (Self::MilestoneCommits, Lang::De) => "{count} Commits auf {branch}",
+
+            (Self::TheDefaultBranch, Lang::En) => "the default branch",
+            (Self::TheDefaultBranch, Lang::It) => "il branch predefinito",
+            (Self::TheDefaultBranch, Lang::De) => "dem Standard-Branch",
+
+            (Self::NoLongerAccessible, Lang::En) => {
+                "{repository} is no longer accessible: GitHub answers 404 for it, so it was deleted or made private."
+            }
+            (Self::NoLongerAccessible, Lang::It) => {
+                "{repository} non è più accessibile: GitHub risponde 404, quindi è stato eliminato o reso privato."
+            }
+            (Self::NoLongerAccessible, Lang::De) => {
+                "{repository} ist nicht mehr erreichbar: GitHub antwortet mit 404, es wurde also gelöscht oder privat gemacht."
+            }
+
+            (Self::InaccessiblePaused, Lang::En) => {
+                "It is not checked for releases until it is reachable again; /untrack it if it is gone for good."
+            }
+            (Self::InaccessiblePaused, Lang::It) => {
+                "Non viene controllato finché non è di nuovo raggiungibile; usa /untrack se non tornerà più."
+            }
+            (Self::InaccessiblePaused, Lang::De) => {
+                "Es wird nicht abgefragt, bis es wieder erreichbar ist; entferne es mit /untrack, wenn es endgültig weg ist."
+            }
+
+            (Self::InaccessibleWatched, Lang::En) => {
+                "You will be told if it becomes reachable again; /untrack it if it is gone for good."
+            }
+            (Self::InaccessibleWatched, Lang::It) => {
+                "Riceverai un avviso se torna raggiungibile; usa /untrack se non tornerà più."
+            }
+            (Self::InaccessibleWatched, Lang::De) => {
+                "Du erfährst es, wenn es wieder erreichbar ist; entferne es mit /untrack, wenn es endgültig weg ist."
+            }
+
+            (Self::AccessibleAgain, Lang::En) => {
+                "{repository} is accessible again; its releases are announced as before."
+            }
+            (Self::AccessibleAgain, Lang::It) => {
+                "{repository} è di nuovo accessibile; le sue release vengono annunciate come prima."
+            }
+            (Self::AccessibleAgain, Lang::De) => {
+                "{repository} ist wieder erreichbar; seine Releases werden wie zuvor angekündigt."
+            }
+        }
+    }
+}
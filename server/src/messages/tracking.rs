@@ -0,0 +1,201 @@
+use crate::chat_settings::Lang;
+
+use super::Key;
+
+/// What the commands adding, listing and removing tracked repositories answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrackingText {
+    NameMissing,
+    /// Placeholder: `{url}`.
+    InvalidUrl,
+    /// Placeholder: `{owner}`.
+    InvalidOwner,
+    /// Placeholder: `{repository}`.
+    InvalidRepositoryName,
+    /// Placeholders: `{name}`, `{url}`.
+    AlreadyTracking,
+    /// Placeholders: `{name}`, `{url}`.
+    UpdatedTracking,
+    /// Placeholders: `{name}`, `{url}`.
+    NowTracking,
+    /// Placeholder: `{error}`.
+    TrackFailed,
+    /// Placeholder: `{error}`.
+    UpdateFailed,
+    NoRepositories,
+    TrackedRepositories,
+    /// Placeholder: `{error}`.
+    ListFailed,
+    SearchTermMissing,
+    /// Placeholder: `{error}`.
+    SearchFailed,
+    /// Placeholder: `{pattern}`.
+    NoMatches,
+    /// Placeholder: `{pattern}`.
+    Matching,
+    /// Placeholder: `{command}`.
+    PatternMissing,
+    /// Placeholder: `{count}`.
+    Muted,
+    /// Placeholder: `{count}`.
+    Unmuted,
+    /// Placeholder: `{count}`.
+    UntrackIntro,
+    /// Placeholder: `{count}`.
+    AndMore,
+    /// Placeholder: `{count}`.
+    ConfirmUntrack,
+    Cancel,
+    ConfirmationExpired,
+    UntrackCancelled,
+    /// Placeholder: `{error}`.
+    UntrackFailed,
+    /// Placeholder: `{count}`.
+    Untracked,
+}
+
+impl Key for TrackingText {
+    fn text(self, lang: Lang) -> &'static str {
+        match (self, lang) {
+            (Self::NameMissing, Lang::En) => "Please provide a name for the repository.",
+            (Self::NameMissing, Lang::It) => "Indica un nome per il repository.",
+            (Self::NameMissing, Lang::De) => "Bitte gib einen Namen für das Repository an.",
+
+            (Self::InvalidUrl, Lang::En) => "Invalid GitHub repository URL: {url}",
+            (Self::InvalidUrl, Lang::It) => "URL di repository GitHub non valido: {url}",
+            (Self::InvalidUrl, Lang::De) => "Ungültige GitHub-Repository-URL: {url}",
+
+            (Self::InvalidOwner, Lang::En) => "'{owner}' is not a valid GitHub owner name.",
+            (Self::InvalidOwner, Lang::It) => {
+                "'{owner}' non è un nome di proprietario GitHub valido."
+            }
+            (Self::InvalidOwner, Lang::De) => "'{owner}' ist kein gültiger GitHub-Eigentümername.",
+
+            (Self::InvalidRepositoryName, Lang::En) => {
+                "'{repository}' is not a valid GitHub repository name."
+            }
+            (Self::InvalidRepositoryName, Lang::It) => {
+                "'{repository}' non è un nome di repository GitHub valido."
+            }
+            (Self::InvalidRepositoryName, Lang::De) => {
+                "'{repository}' ist kein gültiger GitHub-Repository-Name."
+            }
+
+            (Self::AlreadyTracking, Lang::En) => "This chat is already tracking {name} ({url}).",
+            (Self::AlreadyTracking, Lang::It) => "Questa chat segue già {name} ({url}).",
+            (Self::AlreadyTracking, Lang::De) => "Dieser Chat verfolgt {name} ({url}) bereits.",
+
+            (Self::UpdatedTracking, Lang::En) => "Updated tracking for {name} ({url}).",
+            (Self::UpdatedTracking, Lang::It) => "Aggiornato il monitoraggio di {name} ({url}).",
+            (Self::UpdatedTracking, Lang::De) => "Verfolgung von {name} ({url}) aktualisiert.",
+
+            (Self::NowTracking, Lang::En) => "Now tracking {name} ({url}).",
+            (Self::NowTracking, Lang::It) => "Ora seguo {name} ({url}).",
+            (Self::NowTracking, Lang::De) => "{name} ({url}) wird jetzt verfolgt.",
+
+            (Self::TrackFailed, Lang::En) => "Failed to track repository: {error}",
+            (Self::TrackFailed, Lang::It) => "Impossibile seguire il repository: {error}",
+            (Self::TrackFailed, Lang::De) => "Repository konnte nicht verfolgt werden: {error}",
+
+            (Self::UpdateFailed, Lang::En) => "Failed to update tracked repository: {error}",
+            (Self::UpdateFailed, Lang::It) => {
+                "Impossibile aggiornare il repository seguito: {error}"
+            }
+            (Self::UpdateFailed, Lang::De) => {
+                "Verfolgtes Repository konnte nicht aktualisiert werden: {error}"
+            }
+
+            (Self::NoRepositories, Lang::En) => "No repositories tracked yet.",
+            (Self::NoRepositories, Lang::It) => "Nessun repository seguito per ora.",
+            (Self::NoRepositories, Lang::De) => "Noch keine Repositories verfolgt.",
+
+            (Self::TrackedRepositories, Lang::En) => "Tracked repositories:",
+            (Self::TrackedRepositories, Lang::It) => "Repository seguiti:",
+            (Self::TrackedRepositories, Lang::De) => "Verfolgte Repositories:",
+
+            (Self::ListFailed, Lang::En) => "Failed to list repositories: {error}",
+            (Self::ListFailed, Lang::It) => "Impossibile elencare i repository: {error}",
+            (Self::ListFailed, Lang::De) => {
+                "Repositories konnten nicht aufgelistet werden: {error}"
+            }
+
+            (Self::SearchTermMissing, Lang::En) => "Please provide a search term: /search <term>",
+            (Self::SearchTermMissing, Lang::It) => {
+                "Indica un termine di ricerca: /search <termine>"
+            }
+            (Self::SearchTermMissing, Lang::De) => {
+                "Bitte gib einen Suchbegriff an: /search <begriff>"
+            }
+
+            (Self::SearchFailed, Lang::En) => "Failed to search repositories: {error}",
+            (Self::SearchFailed, Lang::It) => "Impossibile cercare i repository: {error}",
+            (Self::SearchFailed, Lang::De) => {
+                "Repositories konnten nicht durchsucht werden: {error}"
+            }
+
+            (Self::NoMatches, Lang::En) => "No tracked repositories match \"{pattern}\".",
+            (Self::NoMatches, Lang::It) => "Nessun repository seguito corrisponde a \"{pattern}\".",
+            (Self::NoMatches, Lang::De) => "Kein verfolgtes Repository passt zu \"{pattern}\".",
+
+            (Self::Matching, Lang::En) => "Tracked repositories matching \"{pattern}\":",
+            (Self::Matching, Lang::It) => "Repository seguiti che corrispondono a \"{pattern}\":",
+            (Self::Matching, Lang::De) => "Verfolgte Repositories, die zu \"{pattern}\" passen:",
+
+            (Self::PatternMissing, Lang::En) => "Please provide a pattern: /{command} <glob>",
+            (Self::PatternMissing, Lang::It) => "Indica un pattern: /{command} <glob>",
+            (Self::PatternMissing, Lang::De) => "Bitte gib ein Muster an: /{command} <glob>",
+
+            (Self::Muted, Lang::En) => "Muted {count} repositories.",
+            (Self::Muted, Lang::It) => "Silenziati {count} repository.",
+            (Self::Muted, Lang::De) => "{count} Repositories stummgeschaltet.",
+
+            (Self::Unmuted, Lang::En) => "Unmuted {count} repositories.",
+            (Self::Unmuted, Lang::It) => "Riattivati {count} repository.",
+            (Self::Unmuted, Lang::De) => "Stummschaltung für {count} Repositories aufgehoben.",
+
+            (Self::UntrackIntro, Lang::En) => "This stops tracking {count} repositories:",
+            (Self::UntrackIntro, Lang::It) => "Così smetterai di seguire {count} repository:",
+            (Self::UntrackIntro, Lang::De) => {
+                "Damit werden {count} Repositories nicht mehr verfolgt:"
+            }
+
+            (Self::AndMore, Lang::En) => "…and {count} more",
+            (Self::AndMore, Lang::It) => "…e altri {count}",
+            (Self::AndMore, Lang::De) => "…und {count} weitere",
+
+            (Self::ConfirmUntrack, Lang::En) => "Yes, remove {count} repos",
+            (Self::ConfirmUntrack, Lang::It) => "Sì, rimuovi {count} repository",
+            (Self::ConfirmUntrack, Lang::De) => "Ja, {count} Repositories entfernen",
+
+            (Self::Cancel, Lang::En) => "Cancel",
+            (Self::Cancel, Lang::It) => "Annulla",
+            (Self::Cancel, Lang::De) => "Abbrechen",
+
+            (Self::ConfirmationExpired, Lang::En) => {
+                "This confirmation has expired, please run the command again."
+            }
+            (Self::ConfirmationExpired, Lang::It) => {
+                "Questa conferma è scaduta, esegui di nuovo il comando."
+            }
+            (Self::ConfirmationExpired, Lang::De) => {
+                "Diese Bestätigung ist abgelaufen, bitte führe den Befehl erneut aus."
+            }
+
+            (Self::UntrackCancelled, Lang::En) => "Cancelled, nothing was untracked.",
+            (Self::UntrackCancelled, Lang::It) => "Annullato, nessun repository è stato rimosso.",
+            (Self::UntrackCancelled, Lang::De) => "Abgebrochen, es wurde nichts entfernt.",
+
+            (Self::UntrackFailed, Lang::En) => "Failed to untrack repositories: {error}",
+            (Self::UntrackFailed, Lang::It) => {
+                "Impossibile smettere di seguire i repository: {error}"
+            }
+            (Self::UntrackFailed, Lang::De) => {
+                "Repositories konnten nicht entfernt werden: {error}"
+            }
+
+            (Self::Untracked, Lang::En) => "Stopped tracking {count} repositories.",
+            (Self::Untracked, Lang::It) => "Non seguo più {count} repository.",
+            (Self::Untracked, Lang::De) => "{count} Repositories werden nicht mehr verfolgt.",
+        }
+    }
+}
@@ -0,0 +1,60 @@
+pub mod repository;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{FromRow, Row};
+use uuid::Uuid;
+
+/// Records that a release notification reached a chat in a given Telegram message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationReceipt {
+    pub id: Uuid,
+    pub tracked_repository_id: Uuid,
+    pub chat_id: i64,
+    pub tag_name: String,
+    pub telegram_message_id: i32,
+    pub sent_at: DateTime<Utc>,
+}
+
+impl FromRow<'_, SqliteRow> for NotificationReceipt {
+    fn from_row(row: &SqliteRow) -> Result<Self, sqlx::Error> {
+        let id_str: String = row.try_get("id")?;
+        let id = Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let tracked_repository_id_str: String = row.try_get("tracked_repository_id")?;
+        let tracked_repository_id = Uuid::parse_str(&tracked_repository_id_str)
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+
+        let chat_id: i64 = row.try_get("chat_id")?;
+        let tag_name: String = row.try_get("tag_name")?;
+        let telegram_message_id: i32 = row.try_get("telegram_message_id")?;
+        let sent_at: DateTime<Utc> = row.try_get("sent_at")?;
+
+        Ok(Self {
+            id,
+            tracked_repository_id,
+            chat_id,
+            tag_name,
+            telegram_message_id,
+            sent_at,
+        })
+    }
+}
+
+/// How many notifications about one repository a chat received recently.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationCount {
+    pub repository_name: String,
+    pub last_week: i64,
+    pub last_month: i64,
+}
+
+impl FromRow<'_, SqliteRow> for NotificationCount {
+    fn from_row(row: &SqliteRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            repository_name: row.try_get("repository_name")?,
+            last_week: row.try_get("last_week")?,
+            last_month: row.try_get("last_month")?,
+        })
+    }
+}
@@ -0,0 +1,286 @@
+use crate::notifications::{NotificationCount, NotificationReceipt};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{self, sqlite::SqlitePool};
+use std::error::Error;
+
+#[async_trait]
+pub trait NotificationReceiptsRepository: Send + Sync {
+    async fn save(&self, receipt: &NotificationReceipt)
+    -> Result<(), Box<dyn Error + Send + Sync>>;
+    /// The newest receipt for the release whose message announced nothing else, so it
+    /// can be edited without touching other releases.
+    async fn find_editable(
+        &self,
+        tracked_repository_id: &uuid::Uuid,
+        tag_name: &str,
+    ) -> Result<Option<NotificationReceipt>, Box<dyn Error + Send + Sync>>;
+    /// Notifications sent to the chat since `week_start` and `month_start`, per repository,
+    /// busiest first. Repositories without notifications since `month_start` are left out.
+    async fn count_by_repository(
+        &self,
+        chat_id: i64,
+        week_start: DateTime<Utc>,
+        month_start: DateTime<Utc>,
+    ) -> Result<Vec<NotificationCount>, Box<dyn Error + Send + Sync>>;
+    /// Telegram messages of the chat currently pinned as its latest release.
+    async fn find_pinned(&self, chat_id: i64) -> Result<Vec<i32>, Box<dyn Error + Send + Sync>>;
+    /// Records whether the release notification in `telegram_message_id` is pinned.
+    async fn set_pinned(
+        &self,
+        chat_id: i64,
+        telegram_message_id: i32,
+        pinned: bool,
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
+
+pub struct SqliteNotificationReceiptsRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteNotificationReceiptsRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NotificationReceiptsRepository for SqliteNotificationReceiptsRepository {
+    async fn save(
+        &self,
+        receipt: &NotificationReceipt,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        sqlx::query(
+            r#"
+            INSERT INTO notifications (
+                id, tracked_repository_id, chat_id, tag_name, telegram_message_id, sent_at
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            "#,
+        )
+        .bind(receipt.id.to_string())
+        .bind(receipt.tracked_repository_id.to_string())
+        .bind(receipt.chat_id)
+        .bind(&receipt.tag_name)
+        .bind(receipt.telegram_message_id)
+        .bind(receipt.sent_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_editable(
+        &self,
+        tracked_repository_id: &uuid::Uuid,
+        tag_name: &str,
+    ) -> Result<Option<NotificationReceipt>, Box<dyn Error + Send + Sync>> {
+        let receipt = sqlx::query_as::<_, NotificationReceipt>(
+            r#"
+            SELECT n.id, n.tracked_repository_id, n.chat_id, n.tag_name, n.telegram_message_id,
+                n.sent_at
+            FROM notifications n
+            WHERE n.tracked_repository_id = ?1
+                AND n.tag_name = ?2
+                AND (
+                    SELECT COUNT(*) FROM notifications o
+                    WHERE o.chat_id = n.chat_id
+                        AND o.telegram_message_id = n.telegram_message_id
+                ) = 1
+            ORDER BY n.sent_at DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(tracked_repository_id.to_string())
+        .bind(tag_name)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(receipt)
+    }
+
+    async fn count_by_repository(
+        &self,
+        chat_id: i64,
+        week_start: DateTime<Utc>,
+        month_start: DateTime<Utc>,
+    ) -> Result<Vec<NotificationCount>, Box<dyn Error + Send + Sync>> {
+        let counts = sqlx::query_as::<_, NotificationCount>(
+            r#"
+            SELECT t.repository_name,
+                SUM(CASE WHEN n.sent_at >= ?2 THEN 1 ELSE 0 END) AS last_week,
+                COUNT(*) AS last_month
+            FROM notifications n
+            JOIN tracked_repositories t ON t.id = n.tracked_repository_id
+            WHERE n.chat_id = ?1 AND n.sent_at >= ?3
+            GROUP BY n.tracked_repository_id
+            ORDER BY last_month DESC, t.repository_name
+            "#,
+        )
+        .bind(chat_id)
+        .bind(week_start)
+        .bind(month_start)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(counts)
+    }
+
+    async fn find_pinned(&self, chat_id: i64) -> Result<Vec<i32>, Box<dyn Error + Send + Sync>> {
+        let message_ids = sqlx::query_scalar::<_, i32>(
+            r#"
+            SELECT DISTINCT telegram_message_id
+            FROM notifications
+            WHERE chat_id = ?1 AND pinned = 1
+            ORDER BY telegram_message_id
+            "#,
+        )
+        .bind(chat_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(message_ids)
+    }
+
+    async fn set_pinned(
+        &self,
+        chat_id: i64,
+        telegram_message_id: i32,
+        pinned: bool,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        sqlx::query(
+            r#"
+            UPDATE notifications
+            SET pinned = ?3
+            WHERE chat_id = ?1 AND telegram_message_id = ?2
+            "#,
+        )
+        .bind(chat_id)
+        .bind(telegram_message_id)
+        .bind(pinned)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notifications::NotificationReceipt;
+    use crate::tracked_repositories::repository::{
+        SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+    };
+    use crate::tracked_repositories::{RepositoryUrl, TrackedRelease};
+    use chrono::Duration;
+    use sqlx::sqlite::SqlitePoolOptions;
+    use uuid::Uuid;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    async fn insert_tracked(pool: &SqlitePool, url: &str) -> TrackedRelease {
+        let mut tracked = TrackedRelease {
+            id: Uuid::now_v7(),
+            repository_name: url.to_string(),
+            repository_url: RepositoryUrl::new(url.to_string()).unwrap(),
+            chat_id: 1,
+            bot_id: String::new(),
+            default_branch: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        SqliteTrackedRepositoriesRepository::new(pool.clone())
+            .save(&mut tracked)
+            .await
+            .unwrap();
+        tracked
+    }
+
+    fn receipt(tracked: &TrackedRelease, tag: &str, message_id: i32) -> NotificationReceipt {
+        NotificationReceipt {
+            id: Uuid::now_v7(),
+            tracked_repository_id: tracked.id,
+            chat_id: 1,
+            tag_name: tag.to_string(),
+            telegram_message_id: message_id,
+            sent_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn shared_messages_are_not_editable() {
+        let pool = setup_pool().await;
+        let repo = SqliteNotificationReceiptsRepository::new(pool.clone());
+        let alpha = insert_tracked(&pool, "https://github.com/owner/alpha").await;
+        let beta = insert_tracked(&pool, "https://github.com/owner/beta").await;
+
+        repo.save(&receipt(&alpha, "v1", 10)).await.unwrap();
+        repo.save(&receipt(&beta, "v2", 10)).await.unwrap();
+        assert!(repo.find_editable(&alpha.id, "v1").await.unwrap().is_none());
+
+        repo.save(&receipt(&alpha, "v3", 11)).await.unwrap();
+        let editable = repo.find_editable(&alpha.id, "v3").await.unwrap().unwrap();
+        assert_eq!(editable.telegram_message_id, 11);
+    }
+
+    #[tokio::test]
+    async fn counts_notifications_per_window() {
+        let pool = setup_pool().await;
+        let repo = SqliteNotificationReceiptsRepository::new(pool.clone());
+        let alpha = insert_tracked(&pool, "https://github.com/owner/alpha").await;
+        let beta = insert_tracked(&pool, "https://github.com/owner/beta").await;
+        let now = Utc::now();
+
+        for (tracked, days_ago, message_id) in [
+            (&alpha, 1, 1),
+            (&alpha, 10, 2),
+            (&alpha, 20, 3),
+            (&alpha, 45, 4),
+            (&beta, 3, 5),
+        ] {
+            let mut r = receipt(tracked, "v1", message_id);
+            r.sent_at = now - Duration::days(days_ago);
+            repo.save(&r).await.unwrap();
+        }
+
+        let counts = repo
+            .count_by_repository(1, now - Duration::days(7), now - Duration::days(30))
+            .await
+            .unwrap();
+        assert_eq!(
+            counts,
+            vec![
+                NotificationCount {
+                    repository_name: "https://github.com/owner/alpha".to_string(),
+                    last_week: 1,
+                    last_month: 3,
+                },
+                NotificationCount {
+                    repository_name: "https://github.com/owner/beta".to_string(),
+                    last_week: 1,
+                    last_month: 1,
+                },
+            ]
+        );
+        assert!(
+            repo.count_by_repository(2, now - Duration::days(7), now - Duration::days(30))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
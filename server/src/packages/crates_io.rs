@@ -0,0 +1,127 @@
+use serde::Deserialize;
+
+use crate::github::{GithubError, Release, ReleaseDetails};
+
+use super::{Package, get_json};
+
+#[derive(Deserialize)]
+struct CrateResponse {
+    #[serde(rename = "crate")]
+    krate: CrateData,
+}
+
+#[derive(Deserialize)]
+struct CrateData {
+    #[serde(default)]
+    max_stable_version: Option<String>,
+    max_version: String,
+}
+
+/// The newest stable version of a crate, or the newest prerelease when it has no stable
+/// version yet.
+pub(super) async fn fetch_latest_version(
+    client: &reqwest::Client,
+    package: &Package,
+    base: &str,
+) -> Result<Option<Release>, GithubError> {
+    let url = format!("{}/crates/{}", base, package.name);
+    let Some(response) = get_json::<CrateResponse>(client, &url).await? else {
+        return Ok(None);
+    };
+    let version = response
+        .krate
+        .max_stable_version
+        .unwrap_or(response.krate.max_version);
+    if version.is_empty() || version == "0.0.0" {
+        return Ok(None);
+    }
+    Ok(Some(Release {
+        details: ReleaseDetails {
+            html_url: Some(package.version_url(&version)),
+            ..ReleaseDetails::default()
+        },
+        tag_name: version,
+        body: None,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packages::Registry;
+    use mockito::Server;
+
+    fn package() -> Package {
+        Package {
+            registry: Registry::CratesIo,
+            name: "serde".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn reads_the_newest_stable_version() {
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/crates/serde")
+            .match_header("user-agent", mockito::Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "crate": {
+                        "name": "serde",
+                        "max_version": "2.0.0-alpha.1",
+                        "max_stable_version": "1.0.210"
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let release = fetch_latest_version(&reqwest::Client::new(), &package(), &server.url())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(release.tag_name, "1.0.210");
+        assert_eq!(
+            release.details.html_url.as_deref(),
+            Some("https://crates.io/crates/serde/1.0.210")
+        );
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_prereleases_and_reports_missing_crates() {
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/crates/serde")
+            .with_status(200)
+            .with_body(
+                serde_json::json!({"crate": {"max_version": "0.1.0-rc.1", "max_stable_version": null}})
+                    .to_string(),
+            )
+            .create_async()
+            .await;
+        let _missing = server
+            .mock("GET", "/crates/nope")
+            .with_status(404)
+            .create_async()
+            .await;
+        let client = reqwest::Client::new();
+
+        let release = fetch_latest_version(&client, &package(), &server.url())
+            .await
+            .unwrap();
+        assert_eq!(release.map(|r| r.tag_name).as_deref(), Some("0.1.0-rc.1"));
+        let missing = Package {
+            registry: Registry::CratesIo,
+            name: "nope".to_string(),
+        };
+        assert_eq!(
+            fetch_latest_version(&client, &missing, &server.url())
+                .await
+                .unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,191 @@
+//! Package registries followed instead of GitHub Releases: crates.io and npm.
+
+mod crates_io;
+mod npm;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use urlencoding::encode;
+
+use crate::github::{GithubError, Release, request_timeout};
+
+const CRATES_IO_PREFIX: &str = "https://crates.io/crates/";
+const NPM_PREFIXES: &[&str] = &[
+    "https://www.npmjs.com/package/",
+    "https://npmjs.com/package/",
+];
+
+/// Longest crate name crates.io accepts.
+const MAX_CRATE_CHARS: usize = 64;
+/// Longest package name, scope included, npm accepts.
+const MAX_NPM_CHARS: usize = 214;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Registry {
+    CratesIo,
+    Npm,
+}
+
+impl Registry {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::CratesIo => "crates.io",
+            Self::Npm => "npm",
+        }
+    }
+}
+
+/// A package published to a registry, tracked through its registry page URL.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Package {
+    pub registry: Registry,
+    pub name: String,
+}
+
+fn is_valid_crate(name: &str) -> bool {
+    name.len() <= MAX_CRATE_CHARS
+        && name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn is_valid_npm_part(part: &str) -> bool {
+    !part.is_empty()
+        && !part.starts_with(['.', '_'])
+        && part.chars().all(|c| {
+            c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '.' | '_' | '~')
+        })
+}
+
+fn is_valid_npm(name: &str) -> bool {
+    if name.len() > MAX_NPM_CHARS {
+        return false;
+    }
+    match name.strip_prefix('@') {
+        Some(scoped) => scoped
+            .split_once('/')
+            .is_some_and(|(scope, name)| is_valid_npm_part(scope) && is_valid_npm_part(name)),
+        None => is_valid_npm_part(name),
+    }
+}
+
+impl Package {
+    /// Recognizes `https://crates.io/crates/<name>` and
+    /// `https://www.npmjs.com/package/<name>`, scoped npm names included.
+    pub fn from_url(url: &str) -> Option<Self> {
+        let url = url.trim_end_matches('/');
+        if let Some(name) = url.strip_prefix(CRATES_IO_PREFIX) {
+            return is_valid_crate(name).then(|| Self {
+                registry: Registry::CratesIo,
+                name: name.to_string(),
+            });
+        }
+        let name = NPM_PREFIXES
+            .iter()
+            .find_map(|prefix| url.strip_prefix(prefix))?;
+        is_valid_npm(name).then(|| Self {
+            registry: Registry::Npm,
+            name: name.to_string(),
+        })
+    }
+
+    /// The registry page of one published version.
+    pub fn version_url(&self, version: &str) -> String {
+        match self.registry {
+            Registry::CratesIo => format!("{CRATES_IO_PREFIX}{}/{}", self.name, encode(version)),
+            Registry::Npm => format!("{}{}/v/{}", NPM_PREFIXES[0], self.name, encode(version)),
+        }
+    }
+}
+
+pub(crate) fn crates_io_api_base() -> String {
+    std::env::var("CRATES_IO_API_BASE").unwrap_or_else(|_| "https://crates.io/api/v1".to_string())
+}
+
+pub(crate) fn npm_registry_base() -> String {
+    std::env::var("NPM_REGISTRY_BASE").unwrap_or_else(|_| "https://registry.npmjs.org".to_string())
+}
+
+/// Reads a registry's JSON answer, or `None` when the package does not exist. Registries
+/// ask for a descriptive user agent; crates.io refuses requests without one.
+async fn get_json<T: DeserializeOwned>(
+    client: &reqwest::Client,
+    url: &str,
+) -> Result<Option<T>, GithubError> {
+    let resp = client
+        .get(url)
+        .timeout(request_timeout())
+        .header("User-Agent", "github-release-bot/0.1")
+        .header("Accept", "application/json")
+        .send()
+        .await?;
+    match resp.status() {
+        s if s.is_success() => {
+            let body = resp.text().await?;
+            serde_json::from_str(&body)
+                .map(Some)
+                .map_err(GithubError::Decode)
+        }
+        reqwest::StatusCode::NOT_FOUND => Ok(None),
+        s => Err(GithubError::Status { status: s.as_u16() }),
+    }
+}
+
+/// The newest published version of `package`, asking the registry at `base`.
+pub(crate) async fn fetch_latest_version_with_base(
+    client: &reqwest::Client,
+    package: &Package,
+    base: &str,
+) -> Result<Option<Release>, GithubError> {
+    match package.registry {
+        Registry::CratesIo => crates_io::fetch_latest_version(client, package, base).await,
+        Registry::Npm => npm::fetch_latest_version(client, package, base).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_registry_urls() {
+        assert_eq!(
+            Package::from_url("https://crates.io/crates/serde_json/"),
+            Some(Package {
+                registry: Registry::CratesIo,
+                name: "serde_json".to_string()
+            })
+        );
+        assert_eq!(
+            Package::from_url("https://www.npmjs.com/package/@types/node"),
+            Some(Package {
+                registry: Registry::Npm,
+                name: "@types/node".to_string()
+            })
+        );
+        for url in [
+            "https://crates.io/crates/",
+            "https://crates.io/crates/9lives",
+            "https://www.npmjs.com/package/Upper",
+            "https://www.npmjs.com/package/@scope",
+            "https://github.com/owner/repo",
+        ] {
+            assert_eq!(Package::from_url(url), None, "{url}");
+        }
+    }
+
+    #[test]
+    fn links_to_the_version_page() {
+        let npm = Package::from_url("https://npmjs.com/package/@scope/pkg").unwrap();
+        assert_eq!(
+            npm.version_url("1.0.0"),
+            "https://www.npmjs.com/package/@scope/pkg/v/1.0.0"
+        );
+        let krate = Package::from_url("https://crates.io/crates/tokio").unwrap();
+        assert_eq!(
+            krate.version_url("1.40.0"),
+            "https://crates.io/crates/tokio/1.40.0"
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use urlencoding::encode;
+
+use crate::github::{GithubError, Release, ReleaseDetails};
+
+use super::{Package, get_json};
+
+#[derive(Deserialize)]
+struct PackageResponse {
+    #[serde(rename = "dist-tags", default)]
+    dist_tags: HashMap<String, String>,
+    /// Publication time of every version, keyed by version.
+    #[serde(default)]
+    time: HashMap<String, serde_json::Value>,
+}
+
+/// The version the package's `latest` dist-tag points at.
+pub(super) async fn fetch_latest_version(
+    client: &reqwest::Client,
+    package: &Package,
+    base: &str,
+) -> Result<Option<Release>, GithubError> {
+    // Scoped names keep their `@` but need the slash encoded
+    let url = format!("{}/{}", base, encode(&package.name).replace("%40", "@"));
+    let Some(response) = get_json::<PackageResponse>(client, &url).await? else {
+        return Ok(None);
+    };
+    let Some(version) = response.dist_tags.get("latest").cloned() else {
+        return Ok(None);
+    };
+    let published_at = response
+        .time
+        .get(&version)
+        .and_then(|t| t.as_str())
+        .and_then(|t| t.parse::<DateTime<Utc>>().ok());
+    Ok(Some(Release {
+        details: ReleaseDetails {
+            html_url: Some(package.version_url(&version)),
+            published_at,
+            author: None,
+            is_release: false,
+            from_feed: false,
+        },
+        tag_name: version,
+        body: None,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packages::Registry;
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn reads_the_latest_dist_tag() {
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/@scope%2Fpkg")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "name": "@scope/pkg",
+                    "dist-tags": {"latest": "3.1.0", "next": "4.0.0-beta.2"},
+                    "time": {
+                        "modified": "2024-06-01T00:00:00.000Z",
+                        "3.1.0": "2024-05-20T12:00:00.000Z"
+                    }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let package = Package {
+            registry: Registry::Npm,
+            name: "@scope/pkg".to_string(),
+        };
+
+        let release = fetch_latest_version(&reqwest::Client::new(), &package, &server.url())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(release.tag_name, "3.1.0");
+        assert_eq!(
+            release.details.published_at,
+            Some("2024-05-20T12:00:00Z".parse().unwrap())
+        );
+        assert_eq!(
+            release.details.html_url.as_deref(),
+            Some("https://www.npmjs.com/package/@scope/pkg/v/3.1.0")
+        );
+    }
+
+    #[tokio::test]
+    async fn package_without_releases_has_no_version() {
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("GET", "/left-pad")
+            .with_status(200)
+            .with_body(serde_json::json!({"name": "left-pad", "dist-tags": {}}).to_string())
+            .create_async()
+            .await;
+        let package = Package {
+            registry: Registry::Npm,
+            name: "left-pad".to_string(),
+        };
+
+        let release = fetch_latest_version(&reqwest::Client::new(), &package, &server.url())
+            .await
+            .unwrap();
+        assert_eq!(release, None);
+    }
+}
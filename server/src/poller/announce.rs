@@ -0,0 +1,75 @@
+#[cfg(feature = "webhooks")]
+use super::notification::release_info;
+use crate::messages::{Text, text as text_in};
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::settings::RepositorySettings;
+use crate::tracked_repositories::settings::tag_capture::capture;
+use crate::tracked_repositories::tracked_repositories_releases::CachedRepositoryRelease;
+
+use super::cycle::PollCycle;
+use super::fetch::LatestRelease;
+use super::notification::{release_headline, with_mention};
+use super::pending::Announced;
+use super::release_notes::{format_release_notes, notes_to_include};
+
+impl PollCycle<'_> {
+    /// Queues the announcement of `latest` for `r`'s chat, with the notes the chat wants
+    /// and, after a snooze, a note that it was missed meanwhile.
+    pub(super) async fn queue_release(
+        &mut self,
+        r: &TrackedRelease,
+        repo_settings: &mut RepositorySettings,
+        latest: &LatestRelease,
+        previous: Option<&CachedRepositoryRelease>,
+        catch_up: bool,
+    ) {
+        let latest_tag = latest.tag.as_str();
+        log::debug!(
+            "Queueing notification for {}/{} to {}",
+            latest.owner,
+            latest.repo,
+            r.chat_id
+        );
+        if repo_settings.throttle_window_secs.is_some() {
+            repo_settings.last_notified_at = Some(self.now);
+            self.save_settings(repo_settings).await;
+        }
+        let chat_settings = self.settings.get(r.chat_id).await;
+        let format = chat_settings.message_format;
+        let previous_tag = previous
+            .map(|c| c.tag_name.as_str())
+            .filter(|tag| *tag != latest_tag);
+        // The previous release is shown by its captured version too
+        let previous_captured = repo_settings
+            .tag_capture
+            .as_deref()
+            .zip(previous_tag)
+            .and_then(|(pattern, tag)| capture(pattern, tag));
+        let previous_tag = previous_captured.as_deref().or(previous_tag);
+        let mut text = release_headline(r, latest, previous_tag, chat_settings);
+        if catch_up {
+            let note = text_in(chat_settings.language, Text::WhileSnoozed);
+            text = format!("{} {}", format.escape(note), text);
+        }
+        text = with_mention(text, repo_settings.mention.as_ref(), format);
+        let previous_hash = previous.and_then(|c| c.body_hash.as_deref());
+        if let Some(notes) = notes_to_include(
+            chat_settings.release_notes,
+            latest.body.as_deref(),
+            previous_hash,
+        ) {
+            text = format!("{}\n{}", text, format_release_notes(notes, format));
+        }
+        let announced = Announced {
+            tracked_repository_id: r.id,
+            tag_name: latest_tag.to_string(),
+        };
+        #[cfg(feature = "webhooks")]
+        if let Some(webhook) = &chat_settings.webhook {
+            self.webhook_deliveries
+                .push((webhook.clone(), release_info(r, latest)));
+        }
+        self.pending.push_release(chat_settings, text, announced);
+        self.record_notified(r, latest_tag);
+    }
+}
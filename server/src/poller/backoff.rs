@@ -0,0 +1,134 @@
+use std::collections::VecDeque;
+
+use chrono::{DateTime, Duration, Utc};
+
+/// Rate limit hits within this window count towards backing off.
+const WINDOW: Duration = Duration::hours(1);
+/// Hits within the window that lengthen the poll interval.
+const THRESHOLD: usize = 5;
+/// The interval grows to at most this many times the configured one.
+const MAX_FACTOR: u64 = 8;
+
+/// How the effective poll interval changed after a cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BackoffChange {
+    /// Rate limits keep being hit, so cycles now run this many times less often.
+    Lengthened { factor: u64 },
+    /// No rate limit was hit for a whole window; the configured interval applies again.
+    Restored,
+}
+
+/// Lengthens the poll interval while GitHub keeps rate limiting the poller, for example
+/// when more repositories are tracked than the unauthenticated budget covers.
+#[derive(Debug)]
+pub(crate) struct RateLimitBackoff {
+    hits: VecDeque<DateTime<Utc>>,
+    last_hit: Option<DateTime<Utc>>,
+    factor: u64,
+}
+
+impl Default for RateLimitBackoff {
+    fn default() -> Self {
+        Self {
+            hits: VecDeque::new(),
+            last_hit: None,
+            factor: 1,
+        }
+    }
+}
+
+impl RateLimitBackoff {
+    /// Records the rate limit hits of a cycle that ended at `now`. The interval doubles
+    /// whenever a cycle with hits brings the last hour to `THRESHOLD` or more.
+    pub(crate) fn record_cycle(
+        &mut self,
+        hits: usize,
+        now: DateTime<Utc>,
+    ) -> Option<BackoffChange> {
+        while self.hits.front().is_some_and(|at| now - *at >= WINDOW) {
+            self.hits.pop_front();
+        }
+        self.hits.extend(std::iter::repeat_n(now, hits));
+        if hits > 0 {
+            self.last_hit = Some(now);
+        }
+
+        if hits > 0 && self.hits.len() >= THRESHOLD && self.factor < MAX_FACTOR {
+            self.factor *= 2;
+            // Count afresh, so the next doubling needs new hits at the longer interval
+            self.hits.clear();
+            return Some(BackoffChange::Lengthened {
+                factor: self.factor,
+            });
+        }
+        if self.factor > 1 && self.last_hit.is_some_and(|at| now - at >= WINDOW) {
+            self.factor = 1;
+            return Some(BackoffChange::Restored);
+        }
+        None
+    }
+
+    /// The seconds to wait before the next cycle, given the configured interval.
+    pub(crate) fn interval_secs(&self, configured_secs: u64) -> u64 {
+        configured_secs.saturating_mul(self.factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_rate_limits_lengthen_the_interval() {
+        let mut backoff = RateLimitBackoff::default();
+        let start = Utc::now();
+        assert_eq!(backoff.interval_secs(60), 60);
+
+        assert_eq!(backoff.record_cycle(3, start), None);
+        assert_eq!(
+            backoff.record_cycle(2, start + Duration::minutes(1)),
+            Some(BackoffChange::Lengthened { factor: 2 })
+        );
+        assert_eq!(backoff.interval_secs(60), 120);
+
+        let mut at = start + Duration::minutes(3);
+        for factor in [4, 8] {
+            assert_eq!(
+                backoff.record_cycle(THRESHOLD, at),
+                Some(BackoffChange::Lengthened { factor })
+            );
+            at += Duration::minutes(10);
+        }
+        assert_eq!(backoff.record_cycle(THRESHOLD, at), None);
+        assert_eq!(backoff.interval_secs(60), 480);
+    }
+
+    #[test]
+    fn hits_spread_over_more_than_an_hour_do_not_back_off() {
+        let mut backoff = RateLimitBackoff::default();
+        let start = Utc::now();
+        for i in 0..10 {
+            assert_eq!(
+                backoff.record_cycle(1, start + Duration::minutes(15 * i)),
+                None
+            );
+        }
+        assert_eq!(backoff.interval_secs(60), 60);
+    }
+
+    #[test]
+    fn interval_is_restored_once_rate_limits_subside() {
+        let mut backoff = RateLimitBackoff::default();
+        let start = Utc::now();
+        backoff.record_cycle(THRESHOLD, start);
+        backoff.record_cycle(1, start + Duration::minutes(5));
+
+        assert_eq!(backoff.record_cycle(0, start + Duration::minutes(10)), None);
+        assert_eq!(backoff.record_cycle(0, start + Duration::minutes(30)), None);
+        assert_eq!(
+            backoff.record_cycle(0, start + Duration::minutes(66)),
+            Some(BackoffChange::Restored)
+        );
+        assert_eq!(backoff.interval_secs(60), 60);
+    }
+}
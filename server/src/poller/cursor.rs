@@ -0,0 +1,177 @@
+use sqlx::sqlite::SqlitePool;
+use uuid::Uuid;
+
+use crate::db::retry_on_busy;
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::tracked_repositories_releases::CachedRepositoryRelease;
+use crate::tracked_repositories::tracked_repositories_releases::repository::upsert_all;
+
+use super::cycle::PollCycle;
+
+/// The repository the poller of `bot_id` finished last in an unfinished cycle.
+pub(super) async fn load_cursor(
+    db: &SqlitePool,
+    bot_id: &str,
+) -> Result<Option<Uuid>, sqlx::Error> {
+    let id: Option<String> =
+        sqlx::query_scalar("SELECT tracked_repository_id FROM poll_cursors WHERE bot_id = ?1")
+            .bind(bot_id)
+            .fetch_optional(db)
+            .await?;
+
+    Ok(id.and_then(|id| Uuid::parse_str(&id).ok()))
+}
+
+/// Where the next cycle of a bot starts, stored with the release cache it goes by.
+pub(super) enum Cursor {
+    /// From the top, after a cycle that checked every repository.
+    Top,
+    /// Right after the given repository.
+    After(Uuid),
+    /// Wherever the stored cursor already says.
+    Keep,
+}
+
+async fn save_cursor(
+    db: impl sqlx::SqliteExecutor<'_>,
+    bot_id: &str,
+    tracked_repository_id: &Uuid,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO poll_cursors (bot_id, tracked_repository_id, updated_at)
+        VALUES (?1, ?2, ?3)
+        ON CONFLICT(bot_id) DO UPDATE SET
+            tracked_repository_id = excluded.tracked_repository_id,
+            updated_at = excluded.updated_at
+        "#,
+    )
+    .bind(bot_id)
+    .bind(tracked_repository_id.to_string())
+    .bind(chrono::Utc::now())
+    .execute(db)
+    .await?;
+
+    Ok(())
+}
+
+/// Forgets the cursor once a cycle got through every repository.
+async fn clear_cursor(db: impl sqlx::SqliteExecutor<'_>, bot_id: &str) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM poll_cursors WHERE bot_id = ?1")
+        .bind(bot_id)
+        .execute(db)
+        .await?;
+
+    Ok(())
+}
+
+/// Saves `cached` and `cursor` in one transaction, so a cycle never resumes past a
+/// repository whose release it did not store.
+pub(super) async fn save_with_cursor(
+    db: &SqlitePool,
+    bot_id: &str,
+    cached: &[CachedRepositoryRelease],
+    cursor: &Cursor,
+) -> Result<(), sqlx::Error> {
+    retry_on_busy(|| async {
+        let mut tx = db.begin().await?;
+        upsert_all(&mut tx, cached).await?;
+        match cursor {
+            Cursor::Top => clear_cursor(&mut *tx, bot_id).await?,
+            Cursor::After(id) => save_cursor(&mut *tx, bot_id, id).await?,
+            Cursor::Keep => {}
+        }
+        tx.commit().await
+    })
+    .await
+}
+
+impl PollCycle<'_> {
+    /// Counts `r` as checked and, every `batch_size` repositories, commits the release
+    /// cache gathered so far with a cursor right after `r`, so a crash or restart in the
+    /// middle of the cycle resumes there.
+    pub(super) async fn checkpoint(
+        &mut self,
+        db: &SqlitePool,
+        bot_id: &str,
+        r: &TrackedRelease,
+        batch_size: usize,
+    ) {
+        self.unflushed += 1;
+        if self.unflushed >= batch_size.max(1) {
+            self.flush_cache(db, bot_id, batch_size, Cursor::After(r.id))
+                .await;
+        }
+    }
+
+    /// Where the next cycle starts once this one is done: from the top after a cycle that
+    /// checked every repository, otherwise right after the last repository checked.
+    pub(super) fn final_cursor(&self) -> Cursor {
+        match (self.went_through, self.last_processed) {
+            (true, _) => Cursor::Top,
+            (false, Some(id)) => Cursor::After(id),
+            (false, None) => Cursor::Keep,
+        }
+    }
+}
+
+/// Reorders `repos` to start right after the one `cursor` names, wrapping around so every
+/// repository is still checked once. Without a cursor, or when its repository was
+/// untracked since, the order is left as it is.
+pub(super) fn resume_after(
+    mut repos: Vec<TrackedRelease>,
+    cursor: Option<Uuid>,
+) -> Vec<TrackedRelease> {
+    if let Some(position) = cursor.and_then(|id| repos.iter().position(|r| r.id == id)) {
+        repos.rotate_left(position + 1);
+    }
+    repos
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracked_repositories::RepositoryUrl;
+    use chrono::Utc;
+
+    fn tracked(name: &str) -> TrackedRelease {
+        TrackedRelease {
+            id: Uuid::new_v4(),
+            repository_name: name.to_string(),
+            repository_url: RepositoryUrl::new(format!("https://github.com/owner/{name}")).unwrap(),
+            chat_id: 1,
+            bot_id: String::new(),
+            default_branch: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn names(repos: &[TrackedRelease]) -> Vec<&str> {
+        repos.iter().map(|r| r.repository_name.as_str()).collect()
+    }
+
+    #[test]
+    fn resumes_after_the_cursor_and_wraps_around() {
+        let repos: Vec<_> = ["a", "b", "c", "d"].into_iter().map(tracked).collect();
+        let b = repos[1].id;
+        let d = repos[3].id;
+
+        assert_eq!(
+            names(&resume_after(repos.clone(), Some(b))),
+            ["c", "d", "a", "b"]
+        );
+        assert_eq!(
+            names(&resume_after(repos.clone(), Some(d))),
+            ["a", "b", "c", "d"]
+        );
+        assert_eq!(
+            names(&resume_after(repos.clone(), None)),
+            ["a", "b", "c", "d"]
+        );
+        assert_eq!(
+            names(&resume_after(repos, Some(Uuid::new_v4()))),
+            ["a", "b", "c", "d"]
+        );
+    }
+}
@@ -0,0 +1,297 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[cfg(feature = "webhooks")]
+use crate::chat_settings::{ChatWebhook, ReleaseInfo};
+use crate::github::{GithubError, ReleaseSource, with_request_timeout};
+use crate::notifications::repository::SqliteNotificationReceiptsRepository;
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::mirrors::repository::SqliteRepositoryMirrorsRepository;
+use crate::tracked_repositories::release_history::ReleaseHistoryEntry;
+use crate::tracked_repositories::settings::RepositorySettings;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+use crate::tracked_repositories::settings::tag_capture::capture;
+use crate::tracked_repositories::tracked_repositories_releases::repository::{
+    CachedRepositoryReleasesRepository, SqliteCachedRepositoryReleasesRepository,
+};
+use crate::tracked_repositories::tracked_repositories_releases::{
+    CachedRepositoryRelease, FetchStatus,
+};
+
+use super::cursor::{Cursor, save_with_cursor};
+use super::holds::{Announce, Hold};
+use super::pending::PendingNotifications;
+use super::release_notes::body_hash;
+use super::settings_cache::ChatSettingsCache;
+use super::versions::same_version;
+
+/// Everything shared by the repositories checked during a single poll cycle.
+pub(crate) struct PollCycle<'a> {
+    pub release_source: Arc<dyn ReleaseSource>,
+    pub client: &'a reqwest::Client,
+    pub token_opt: Option<&'a str>,
+    pub github_base_override: Option<&'a str>,
+    pub settings: ChatSettingsCache,
+    pub pending: PendingNotifications,
+    cache_updates: Vec<CachedRepositoryRelease>,
+    fetch_statuses: Vec<(Uuid, FetchStatus)>,
+    pub(super) cache_repo: SqliteCachedRepositoryReleasesRepository,
+    pub(super) mirrors_repo: SqliteRepositoryMirrorsRepository,
+    repo_settings_repo: SqliteRepositorySettingsRepository,
+    pub(super) receipts_repo: SqliteNotificationReceiptsRepository,
+    pub(super) now: DateTime<Utc>,
+    /// Releases kept per repository in the release history; 0 records nothing.
+    pub history_limit: usize,
+    pub(super) history: Vec<ReleaseHistoryEntry>,
+    /// Skip repositories marked inaccessible until GitHub knows them again.
+    pub pause_inaccessible: bool,
+    /// Set when the GitHub API could not be reached, so the rest of the cycle is skipped.
+    pub unreachable: Option<GithubError>,
+    /// Set when GitHub's secondary rate limit asked to wait, so the rest of the cycle is
+    /// left to the next one rather than waited out here.
+    pub secondary_retry_after: Option<Duration>,
+    /// The last repository checked, which the next cycle resumes after if this one stops
+    /// early.
+    pub(super) last_processed: Option<Uuid>,
+    /// Set once every repository was checked, so the next cycle starts from the top.
+    pub(super) went_through: bool,
+    /// Repositories checked since the release cache was last written.
+    pub(super) unflushed: usize,
+    /// Repositories whose fetch GitHub refused for a rate limit.
+    pub rate_limited: usize,
+    /// Compute what the cycle would announce without storing or sending anything.
+    pub dry_run: bool,
+    /// The releases, discussions, milestones and fired tag watches queued for
+    /// announcement, as `name what (chat id)`.
+    pub notified: Vec<String>,
+    /// Release announcements to mirror to chat webhooks once the cycle is done.
+    #[cfg(feature = "webhooks")]
+    pub(super) webhook_deliveries: Vec<(ChatWebhook, ReleaseInfo)>,
+}
+
+impl<'a> PollCycle<'a> {
+    pub(crate) fn new(
+        db: sqlx::sqlite::SqlitePool,
+        release_source: Arc<dyn ReleaseSource>,
+        client: &'a reqwest::Client,
+        token_opt: Option<&'a str>,
+        github_base_override: Option<&'a str>,
+    ) -> Self {
+        Self {
+            release_source,
+            client,
+            token_opt,
+            github_base_override,
+            settings: ChatSettingsCache::new(db.clone()),
+            pending: PendingNotifications::default(),
+            cache_updates: Vec::new(),
+            fetch_statuses: Vec::new(),
+            cache_repo: SqliteCachedRepositoryReleasesRepository::new(db.clone()),
+            mirrors_repo: SqliteRepositoryMirrorsRepository::new(db.clone()),
+            repo_settings_repo: SqliteRepositorySettingsRepository::new(db.clone()),
+            receipts_repo: SqliteNotificationReceiptsRepository::new(db),
+            now: Utc::now(),
+            history_limit: 0,
+            history: Vec::new(),
+            pause_inaccessible: true,
+            unreachable: None,
+            secondary_retry_after: None,
+            last_processed: None,
+            went_through: false,
+            unflushed: 0,
+            rate_limited: 0,
+            dry_run: false,
+            notified: Vec::new(),
+            #[cfg(feature = "webhooks")]
+            webhook_deliveries: Vec::new(),
+        }
+    }
+
+    /// Whether GitHub cut the cycle short, leaving the remaining repositories to the next.
+    pub(crate) fn stopped_early(&self) -> bool {
+        self.unreachable.is_some() || self.secondary_retry_after.is_some()
+    }
+
+    /// Fetches the newest release of `r`, updates the cache and queues a notification
+    /// when the release is new and nothing holds it back.
+    pub(crate) async fn process(&mut self, r: &TrackedRelease) {
+        let repo_settings = match self.repo_settings_repo.find_or_default(&r.id).await {
+            Ok(s) => s,
+            Err(e) => {
+                log::warn!("Failed to load settings for {}: {}", r.repository_url, e);
+                RepositorySettings::default_for(r.id)
+            }
+        };
+
+        let timeout = repo_settings
+            .request_timeout_secs
+            .map(|secs| Duration::from_secs(secs as u64));
+        with_request_timeout(timeout, self.process_with(r, repo_settings)).await;
+    }
+
+    /// Everything `process` does once the settings are loaded, with the requests limited
+    /// to the repository's own timeout.
+    async fn process_with(&mut self, r: &TrackedRelease, mut repo_settings: RepositorySettings) {
+        if repo_settings.inaccessible_since.is_some()
+            && self.pause_inaccessible
+            && !self.resume_if_accessible(r, &mut repo_settings).await
+        {
+            return;
+        }
+
+        if let Some(category) = repo_settings.discussion_category.clone() {
+            self.process_discussions(r, repo_settings, &category).await;
+            return;
+        }
+        if let Some(every) = repo_settings.commit_milestone {
+            self.process_milestones(r, repo_settings, every).await;
+            return;
+        }
+
+        let Some(latest) = self.fetch_latest(r, &mut repo_settings).await else {
+            return;
+        };
+        let latest_tag = latest.tag.as_str();
+        let mut should_notify = false;
+        let previous = match self.cache_repo.find_by_tracked_release_id(&r.id).await {
+            Ok(Some(cached)) => {
+                // The cache still takes the exact tag, even when it names the same version
+                should_notify = match repo_settings.tag_capture.as_deref() {
+                    Some(pattern) => {
+                        capture(pattern, &cached.tag_name) != capture(pattern, latest_tag)
+                    }
+                    None => {
+                        cached.tag_name != latest_tag
+                            && (repo_settings.exact_tags
+                                || !same_version(&cached.tag_name, latest_tag))
+                    }
+                };
+                Some(cached)
+            }
+            // First sight of the repository: its release is only remembered, unless the
+            // chat asked to hear about it
+            Ok(None) => {
+                should_notify = self.settings.get(r.chat_id).await.notify_on_first_seen;
+                None
+            }
+            Err(_) => None,
+        };
+
+        if should_notify {
+            match self
+                .hold_new_release(r, &mut repo_settings, &latest, previous.as_ref())
+                .await
+            {
+                Hold::None => {}
+                Hold::Older => should_notify = false,
+                Hold::Held => return,
+            }
+        }
+
+        let new_hash = latest.body.as_deref().map(body_hash);
+        let unchanged = previous.as_ref().is_some_and(|c| {
+            c.tag_name == latest_tag
+                && c.body_hash == new_hash
+                && c.from_release == latest.is_release()
+        });
+        if !unchanged {
+            let cached = CachedRepositoryRelease {
+                tracked_repository_id: r.id,
+                tag_name: latest_tag.to_string(),
+                first_seen_at: self.now,
+                body_hash: new_hash.clone(),
+                from_release: latest.is_release(),
+            };
+            self.cache_updates.push(cached);
+        }
+        self.record_history(r, &latest, previous.as_ref());
+
+        if repo_settings.muted && !repo_settings.critical {
+            log::debug!("Not notifying about muted {}", r.repository_url);
+            return;
+        }
+
+        // Same tag with different notes: the release was edited on GitHub. A tag found
+        // through the tags fallback has no notes, so a release published for it later only
+        // refreshes the cached hash
+        if let Some(cached) = &previous
+            && cached.tag_name == latest_tag
+            && let Some(old_hash) = cached.body_hash.as_deref()
+            && new_hash.as_deref().is_some_and(|h| h != old_hash)
+        {
+            self.queue_edit(r, &latest, old_hash, repo_settings.mention.as_ref())
+                .await;
+        }
+
+        let announce = self
+            .announce_cached_release(r, &mut repo_settings, latest_tag, should_notify)
+            .await;
+        if let Announce::Yes { catch_up } = announce {
+            self.queue_release(r, &mut repo_settings, &latest, previous.as_ref(), catch_up)
+                .await;
+        }
+    }
+
+    /// Writes the cache updates and fetch statuses collected so far, `batch_size` rows
+    /// per transaction. The last cache batch carries `cursor`.
+    pub(crate) async fn flush_cache(
+        &mut self,
+        db: &sqlx::sqlite::SqlitePool,
+        bot_id: &str,
+        batch_size: usize,
+        cursor: Cursor,
+    ) {
+        self.unflushed = 0;
+        let updates = std::mem::take(&mut self.cache_updates);
+        let mut batches: Vec<_> = updates.chunks(batch_size.max(1)).collect();
+        let last = batches.pop().unwrap_or_default();
+        for batch in batches {
+            if let Err(e) = self.cache_repo.save_all(batch).await {
+                log::warn!("Failed to save {} release cache rows: {}", batch.len(), e);
+            }
+        }
+        if let Err(e) = save_with_cursor(db, bot_id, last, &cursor).await {
+            log::warn!(
+                "Failed to save {} release cache rows with the poll cursor: {}",
+                last.len(),
+                e
+            );
+        }
+
+        let statuses = std::mem::take(&mut self.fetch_statuses);
+        for batch in statuses.chunks(batch_size.max(1)) {
+            if let Err(e) = self.cache_repo.save_fetch_statuses(batch, self.now).await {
+                log::warn!("Failed to save {} fetch statuses: {}", batch.len(), e);
+            }
+        }
+    }
+
+    pub(super) fn record_fetch_status(&mut self, tracked_repository_id: Uuid, status: FetchStatus) {
+        self.fetch_statuses.push((tracked_repository_id, status));
+    }
+
+    pub(super) fn record_notified(&mut self, r: &TrackedRelease, what: &str) {
+        self.notified.push(format!(
+            "{} {} (chat {})",
+            r.repository_name, what, r.chat_id
+        ));
+    }
+
+    pub(super) async fn save_settings(&self, settings: &RepositorySettings) {
+        if self.dry_run {
+            return;
+        }
+        if let Err(e) = self.repo_settings_repo.save(settings).await {
+            log::warn!(
+                "Failed to save settings for {}: {}",
+                settings.tracked_repository_id,
+                e
+            );
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use chrono::{DateTime, Utc};
+
+use crate::tracked_repositories::settings::RepositorySettings;
+
+use super::min_age::{AgeOutcome, apply_min_age};
+use super::prerelease::collapse_prerelease;
+use super::snooze::{SnoozeOutcome, apply_snooze};
+use super::throttle::throttled_until;
+use super::versions::same_version;
+
+/// What a poll finding `latest_tag` would do, following the same steps as
+/// `PollCycle::process` without changing anything.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Decision {
+    /// Nothing was cached yet: the tag is remembered without notifying.
+    Remember,
+    /// The tag was already announced, or names the same version as the cached one.
+    Unchanged,
+    Muted,
+    Snoozed {
+        until: DateTime<Utc>,
+    },
+    /// The release is younger than the repository's minimum age.
+    TooYoung {
+        until: DateTime<Utc>,
+    },
+    /// The repository notified within its throttle window; the newest release is
+    /// announced once the window ends.
+    Throttled {
+        until: DateTime<Utc>,
+    },
+    /// A prerelease of a version announced within the collapsing window.
+    CollapsedPrerelease,
+    Notify,
+}
+
+pub(crate) fn decide(
+    settings: &RepositorySettings,
+    cached_tag: Option<&str>,
+    latest_tag: &str,
+    published_at: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> Decision {
+    let Some(cached_tag) = cached_tag else {
+        return Decision::Remember;
+    };
+    let is_new =
+        cached_tag != latest_tag && (settings.exact_tags || !same_version(cached_tag, latest_tag));
+    if settings.muted {
+        return Decision::Muted;
+    }
+
+    let mut settings = settings.clone();
+    if is_new
+        && let AgeOutcome::TooYoung { until, .. } =
+            apply_min_age(&mut settings, latest_tag, published_at, now)
+    {
+        return Decision::TooYoung { until };
+    }
+    if is_new && let Some(until) = throttled_until(&settings, now) {
+        return Decision::Throttled { until };
+    }
+    match apply_snooze(&mut settings, latest_tag, is_new, now) {
+        SnoozeOutcome::Suppressed { .. } => {
+            return Decision::Snoozed {
+                until: settings.snoozed_until.unwrap_or(now),
+            };
+        }
+        SnoozeOutcome::Resumed { catch_up: true } if !is_new => return Decision::Notify,
+        _ => {}
+    }
+    if !is_new {
+        return Decision::Unchanged;
+    }
+    if settings.prerelease_collapse_secs.is_some()
+        && !collapse_prerelease(&mut settings, latest_tag, now)
+    {
+        return Decision::CollapsedPrerelease;
+    }
+    Decision::Notify
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    #[test]
+    fn follows_the_poller_steps() {
+        let now = Utc::now();
+        let mut settings = RepositorySettings::default_for(Uuid::now_v7());
+
+        assert_eq!(decide(&settings, None, "v1", None, now), Decision::Remember);
+        assert_eq!(
+            decide(&settings, Some("1.0"), "v1.0", None, now),
+            Decision::Unchanged
+        );
+        assert_eq!(
+            decide(&settings, Some("v1"), "v2", None, now),
+            Decision::Notify
+        );
+
+        settings.snoozed_until = Some(now + Duration::hours(1));
+        assert_eq!(
+            decide(&settings, Some("v1"), "v2", None, now),
+            Decision::Snoozed {
+                until: now + Duration::hours(1)
+            }
+        );
+
+        settings.snoozed_until = None;
+        settings.prerelease_collapse_secs = Some(3600);
+        settings.last_prerelease_base = Some("v2.0.0".to_string());
+        settings.last_prerelease_notified_at = Some(now);
+        assert_eq!(
+            decide(&settings, Some("v2.0.0-rc.1"), "v2.0.0-rc.2", None, now),
+            Decision::CollapsedPrerelease
+        );
+
+        settings.prerelease_collapse_secs = None;
+        settings.min_release_age_secs = Some(600);
+        assert_eq!(
+            decide(&settings, Some("v1"), "v2", None, now),
+            Decision::TooYoung {
+                until: now + Duration::minutes(10)
+            }
+        );
+        assert_eq!(
+            decide(
+                &settings,
+                Some("v1"),
+                "v2",
+                Some(now - Duration::hours(1)),
+                now
+            ),
+            Decision::Notify
+        );
+
+        settings.min_release_age_secs = None;
+        settings.throttle_window_secs = Some(3600);
+        settings.last_notified_at = Some(now - Duration::minutes(15));
+        assert_eq!(
+            decide(&settings, Some("v1"), "v2", None, now),
+            Decision::Throttled {
+                until: now + Duration::minutes(45)
+            }
+        );
+
+        settings.muted = true;
+        assert_eq!(
+            decide(&settings, Some("v1"), "v2", None, now),
+            Decision::Muted
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use crate::chat_settings::{Lang, MessageFormat};
+use crate::github::{Discussion, fetch_latest_discussion_with_base, github_api_base};
+use crate::messages::{Text, text};
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::settings::RepositorySettings;
+use crate::tracked_repositories::tracked_repositories_releases::FetchStatus;
+use crate::utils::{MAX_NAME_CHARS, truncate_chars};
+
+use super::cycle::PollCycle;
+
+pub(crate) fn format_discussion_message(
+    tracked: &TrackedRelease,
+    discussion: &Discussion,
+    format: MessageFormat,
+    lang: Lang,
+) -> String {
+    format!(
+        "{} {}{} {}",
+        format.escape(text(lang, Text::NewAnnouncement)),
+        format.link(
+            &tracked.repository_url.to_string(),
+            &truncate_chars(&tracked.repository_name, MAX_NAME_CHARS)
+        ),
+        format.escape(":"),
+        format.link_markup(&discussion.url, &format.bold(&discussion.title)),
+    )
+}
+
+impl PollCycle<'_> {
+    /// Checks a repository followed through a discussion category instead of its releases.
+    /// The first discussion seen is only remembered, like the first release of a repository.
+    pub(super) async fn process_discussions(
+        &mut self,
+        r: &TrackedRelease,
+        mut repo_settings: RepositorySettings,
+        category: &str,
+    ) {
+        let Some((owner, repo)) = r.repository_url.owner_and_repo() else {
+            return;
+        };
+        let base = self
+            .github_base_override
+            .map(str::to_string)
+            .unwrap_or_else(github_api_base);
+        let latest = fetch_latest_discussion_with_base(
+            self.client,
+            &owner,
+            &repo,
+            category,
+            self.token_opt,
+            &base,
+        )
+        .await;
+
+        let discussion = match latest {
+            Ok(Some(discussion)) => {
+                self.record_fetch_status(r.id, FetchStatus::Ok);
+                discussion
+            }
+            Ok(None) => {
+                self.record_fetch_status(r.id, FetchStatus::NoReleases);
+                log::info!("No discussion in {} for {}", category, r.repository_url);
+                return;
+            }
+            Err(e) => {
+                self.record_fetch_status(r.id, FetchStatus::Failed);
+                log::warn!(
+                    "Poller failed to fetch discussions for {}: {}",
+                    r.repository_url,
+                    e
+                );
+                return;
+            }
+        };
+
+        let previous = repo_settings.last_discussion_number;
+        if previous == Some(discussion.number) {
+            return;
+        }
+        repo_settings.last_discussion_number = Some(discussion.number);
+        repo_settings.updated_at = self.now;
+        self.save_settings(&repo_settings).await;
+
+        if previous.is_none() || repo_settings.muted {
+            return;
+        }
+        log::debug!(
+            "Queueing discussion #{} of {} to {}",
+            discussion.number,
+            r.repository_url,
+            r.chat_id
+        );
+        let chat_settings = self.settings.get(r.chat_id).await;
+        let message = format_discussion_message(
+            r,
+            &discussion,
+            chat_settings.message_format,
+            chat_settings.language,
+        );
+        self.pending.push(chat_settings, message);
+        self.record_notified(r, &format!("discussion #{}", discussion.number));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracked_repositories::RepositoryUrl;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    #[test]
+    fn formats_discussion_announcement() {
+        let tracked = TrackedRelease {
+            id: Uuid::now_v7(),
+            repository_name: "repo".to_string(),
+            repository_url: RepositoryUrl::new("https://github.com/owner/repo".to_string())
+                .unwrap(),
+            chat_id: 1,
+            bot_id: String::new(),
+            default_branch: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        let discussion = Discussion {
+            number: 3,
+            title: "v2 <beta>".to_string(),
+            url: "https://github.com/owner/repo/discussions/3".to_string(),
+        };
+
+        assert_eq!(
+            format_discussion_message(&tracked, &discussion, MessageFormat::Html, Lang::En),
+            "New announcement for <a href=\"https://github.com/owner/repo\">repo</a>: \
+             <a href=\"https://github.com/owner/repo/discussions/3\"><b>v2 &lt;beta&gt;</b></a>"
+        );
+        assert!(
+            format_discussion_message(&tracked, &discussion, MessageFormat::Html, Lang::It)
+                .starts_with("Nuovo annuncio per ")
+        );
+    }
+}
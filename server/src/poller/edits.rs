@@ -0,0 +1,58 @@
+use crate::notifications::repository::NotificationReceiptsRepository;
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::settings::Mention;
+
+use super::cycle::PollCycle;
+use super::fetch::LatestRelease;
+use super::notification::{release_headline, with_mention};
+use super::pending::Announced;
+use super::release_notes::{format_release_notes, notes_to_include};
+
+impl PollCycle<'_> {
+    /// Rewrites the delivered notification of a release whose notes changed on GitHub.
+    /// Nothing is edited for chats that do not show release notes.
+    pub(super) async fn queue_edit(
+        &mut self,
+        r: &TrackedRelease,
+        latest: &LatestRelease,
+        old_hash: &str,
+        mention: Option<&Mention>,
+    ) {
+        let receipt = match self.receipts_repo.find_editable(&r.id, &latest.tag).await {
+            Ok(Some(receipt)) => receipt,
+            Ok(None) => return,
+            Err(e) => {
+                log::warn!("Failed to load receipts for {}: {}", r.repository_url, e);
+                return;
+            }
+        };
+        let chat_settings = self.settings.get(r.chat_id).await;
+        let format = chat_settings.message_format;
+        let Some(notes) = notes_to_include(
+            chat_settings.release_notes,
+            latest.body.as_deref(),
+            Some(old_hash),
+        ) else {
+            return;
+        };
+
+        log::debug!(
+            "Release {} of {} was edited, updating message {}",
+            latest.tag,
+            r.repository_url,
+            receipt.telegram_message_id
+        );
+        let headline = with_mention(
+            release_headline(r, latest, None, chat_settings),
+            mention,
+            format,
+        );
+        let text = format!("{}\n{}", headline, format_release_notes(notes, format));
+        let announced = Announced {
+            tracked_repository_id: r.id,
+            tag_name: latest.tag.clone(),
+        };
+        self.pending
+            .push_edit(chat_settings, receipt.telegram_message_id, text, announced);
+    }
+}
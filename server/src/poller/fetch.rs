@@ -0,0 +1,257 @@
+use urlencoding::encode;
+
+use crate::github::{GithubError, Release, ReleaseDetails, ReleaseSource};
+use crate::packages::Package;
+use crate::tracked_repositories::RepositoryUrl;
+use crate::tracked_repositories::settings::RepositorySettings;
+use crate::tracked_repositories::settings::tag_capture::capture;
+use crate::utils::{MAX_TAG_CHARS, glob_matches};
+
+use super::versions::{newest_captured, newest_tag};
+
+/// The newest release found for a tracked repository and where it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LatestRelease {
+    pub owner: String,
+    pub repo: String,
+    pub tag: String,
+    pub body: Option<String>,
+    pub details: ReleaseDetails,
+    /// Found through the tags of a repository tracked in tags-only mode.
+    pub tags_only: bool,
+    /// Prefix of the monorepo component followed, left out when the tag is shown.
+    pub tag_prefix: Option<String>,
+    /// The registry package the version was published as, for crates.io and npm URLs.
+    pub package: Option<Package>,
+    /// The version the repository's tag capture found in the tag, shown instead of it.
+    pub captured: Option<String>,
+}
+
+impl LatestRelease {
+    /// Whether the tag is a published GitHub release, which removal detection can look
+    /// for among the repository's releases later.
+    pub(crate) fn is_release(&self) -> bool {
+        self.details.is_release && !self.tags_only && self.package.is_none()
+    }
+
+    /// The tag as shown in notifications: the captured version, or the tag without the
+    /// component prefix.
+    pub(crate) fn display_tag(&self) -> &str {
+        if let Some(captured) = &self.captured {
+            return captured;
+        }
+        self.tag_prefix
+            .as_deref()
+            .and_then(|prefix| self.tag.strip_prefix(prefix))
+            .filter(|rest| !rest.is_empty())
+            .unwrap_or(&self.tag)
+    }
+
+    /// Where the notification links to: the release page, the tag's tree in tags-only mode,
+    /// or the version's page on its package registry.
+    /// Tags too long to show in full link to the repository's release or tag listing instead.
+    pub(crate) fn url(&self) -> String {
+        if let Some(package) = &self.package {
+            return package.version_url(&self.tag);
+        }
+        if self.tag.chars().count() > MAX_TAG_CHARS {
+            let listing = if self.tags_only { "tags" } else { "releases" };
+            return format!(
+                "https://github.com/{}/{}/{}",
+                self.owner, self.repo, listing
+            );
+        }
+        let page = if self.tags_only {
+            "tree"
+        } else {
+            "releases/tag"
+        };
+        format!(
+            "https://github.com/{}/{}/{}/{}",
+            self.owner,
+            self.repo,
+            page,
+            encode(&self.tag)
+        )
+    }
+}
+
+/// Pages of tags searched when only some tags are followed. A monorepo component's tags
+/// can sit well behind the newest tags of the repository.
+const FILTERED_TAG_PAGES: usize = 5;
+
+/// Newest release of `owner/repo` whose tag starts with the settings' `tag_prefix`,
+/// matches `tag_capture` and does not match `tag_ignore`; without any of them only the
+/// latest release or tag is asked for.
+async fn fetch_newest_followed(
+    release_source: &dyn ReleaseSource,
+    owner: &str,
+    repo: &str,
+    settings: &RepositorySettings,
+) -> Result<Option<Release>, GithubError> {
+    let tag_release = |tag_name| Release {
+        tag_name,
+        body: None,
+        details: ReleaseDetails::default(),
+    };
+    let ignore = settings.tag_ignore.as_deref();
+    let prefix = settings.tag_prefix.as_deref();
+    let pattern = settings.tag_capture.as_deref();
+    if ignore.is_none() && prefix.is_none() && pattern.is_none() {
+        return if settings.tags_only {
+            Ok(release_source
+                .latest_tag(owner, repo)
+                .await?
+                .map(tag_release))
+        } else {
+            release_source.latest_release(owner, repo).await
+        };
+    }
+
+    let followed = |tag: &str| {
+        prefix.is_none_or(|p| tag.starts_with(p))
+            && pattern.is_none_or(|p| capture(p, tag).is_some())
+            && !ignore.is_some_and(|pattern| glob_matches(pattern, tag))
+    };
+    if settings.tags_only {
+        let pages = if prefix.is_some() || pattern.is_some() {
+            FILTERED_TAG_PAGES
+        } else {
+            1
+        };
+        let tags: Vec<String> = release_source
+            .recent_tags(owner, repo, pages)
+            .await?
+            .into_iter()
+            .filter(|tag| followed(tag))
+            .collect();
+        let newest = match pattern {
+            Some(pattern) => newest_captured(&tags, pattern),
+            None => newest_tag(&tags, prefix.unwrap_or_default()),
+        };
+        Ok(newest.cloned().map(tag_release))
+    } else {
+        Ok(release_source
+            .recent_releases(owner, repo)
+            .await?
+            .into_iter()
+            .find(|r| followed(&r.tag_name)))
+    }
+}
+
+/// Tries each source in order and returns the first release found, or the first tag
+/// when the repository follows tags, keeping to the tags `settings` follow. Errors only
+/// surface when no source yields a release.
+pub(crate) async fn fetch_latest_from_sources(
+    release_source: &dyn ReleaseSource,
+    sources: &[RepositoryUrl],
+    settings: &RepositorySettings,
+) -> Result<Option<LatestRelease>, GithubError> {
+    let mut last_error = None;
+
+    for source in sources {
+        let package = source.package();
+        let (owner, repo, latest) = match &package {
+            // Tag filters only apply to GitHub; a registry's version is always followed
+            Some(package) => (
+                package.registry.name().to_string(),
+                package.name.clone(),
+                release_source.latest_package_version(package).await,
+            ),
+            None => {
+                let Some((owner, repo)) = source.owner_and_repo() else {
+                    continue;
+                };
+                let latest = fetch_newest_followed(release_source, &owner, &repo, settings).await;
+                (owner, repo, latest)
+            }
+        };
+
+        match latest {
+            Ok(Some(release)) => {
+                let captured = settings
+                    .tag_capture
+                    .as_deref()
+                    .filter(|_| package.is_none())
+                    .and_then(|pattern| capture(pattern, &release.tag_name));
+                return Ok(Some(LatestRelease {
+                    owner,
+                    repo,
+                    tag: release.tag_name,
+                    body: release.body,
+                    details: release.details,
+                    tags_only: settings.tags_only && package.is_none(),
+                    tag_prefix: settings.tag_prefix.clone().filter(|_| package.is_none()),
+                    package,
+                    captured,
+                }));
+            }
+            Ok(None) => {
+                log::debug!("No release found at {}", source);
+            }
+            // Mirrors are served by the same API, so trying them would only prolong the limit
+            // or fail the same way
+            Err(e @ GithubError::SecondaryRateLimited { .. }) => return Err(e),
+            Err(e) if e.is_unreachable() => return Err(e),
+            Err(e) => {
+                log::debug!("Failed to fetch latest release from {}: {}", source, e);
+                last_error = Some(e);
+            }
+        }
+    }
+
+    match last_error {
+        Some(e) => Err(e),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn links_to_release_or_tag_tree() {
+        let mut latest = LatestRelease {
+            owner: "owner".to_string(),
+            repo: "repo".to_string(),
+            tag: "v1.0.0".to_string(),
+            body: None,
+            details: ReleaseDetails::default(),
+            tags_only: false,
+            tag_prefix: None,
+            package: None,
+            captured: None,
+        };
+        assert_eq!(
+            latest.url(),
+            "https://github.com/owner/repo/releases/tag/v1.0.0"
+        );
+
+        latest.tags_only = true;
+        assert_eq!(latest.url(), "https://github.com/owner/repo/tree/v1.0.0");
+    }
+
+    #[test]
+    fn display_tag_leaves_out_the_component_prefix() {
+        let mut latest = LatestRelease {
+            owner: "owner".to_string(),
+            repo: "monorepo".to_string(),
+            tag: "pkg-a/v1.2.0".to_string(),
+            body: None,
+            details: ReleaseDetails::default(),
+            tags_only: true,
+            tag_prefix: Some("pkg-a/".to_string()),
+            package: None,
+            captured: None,
+        };
+        assert_eq!(latest.display_tag(), "v1.2.0");
+        assert_eq!(
+            latest.url(),
+            "https://github.com/owner/monorepo/tree/pkg-a%2Fv1.2.0"
+        );
+
+        latest.tag_prefix = None;
+        assert_eq!(latest.display_tag(), "pkg-a/v1.2.0");
+    }
+}
@@ -0,0 +1,59 @@
+use uuid::Uuid;
+
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::release_history::ReleaseHistoryEntry;
+use crate::tracked_repositories::release_history::repository::{
+    ReleaseHistoryRepository, SqliteReleaseHistoryRepository,
+};
+use crate::tracked_repositories::tracked_repositories_releases::CachedRepositoryRelease;
+
+use super::cycle::PollCycle;
+use super::fetch::LatestRelease;
+
+impl PollCycle<'_> {
+    /// Remembers the details of `latest` when it is a release the cache has not seen
+    /// before and the release history is enabled.
+    pub(super) fn record_history(
+        &mut self,
+        r: &TrackedRelease,
+        latest: &LatestRelease,
+        previous: Option<&CachedRepositoryRelease>,
+    ) {
+        if self.history_limit == 0 || previous.is_some_and(|c| c.tag_name == latest.tag) {
+            return;
+        }
+        self.history.push(ReleaseHistoryEntry {
+            id: Uuid::now_v7(),
+            tracked_repository_id: r.id,
+            tag_name: latest.tag.clone(),
+            body: latest.body.clone(),
+            html_url: Some(
+                latest
+                    .details
+                    .html_url
+                    .clone()
+                    .unwrap_or_else(|| latest.url()),
+            ),
+            published_at: latest.details.published_at,
+            author: latest.details.author.clone(),
+            detected_at: self.now,
+        });
+    }
+
+    /// Writes the release history collected during the cycle and prunes every repository
+    /// involved to the configured number of entries.
+    pub(super) async fn flush_history(&mut self, db: &sqlx::sqlite::SqlitePool) {
+        let entries = std::mem::take(&mut self.history);
+        if entries.is_empty() {
+            return;
+        }
+        let repo = SqliteReleaseHistoryRepository::new(db.clone());
+        if let Err(e) = repo.save_all(&entries, self.history_limit).await {
+            log::warn!(
+                "Failed to save {} release history rows: {}",
+                entries.len(),
+                e
+            );
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::settings::RepositorySettings;
+use crate::tracked_repositories::tracked_repositories_releases::CachedRepositoryRelease;
+
+use super::cycle::PollCycle;
+use super::fetch::LatestRelease;
+use super::prerelease::collapse_prerelease;
+use super::snooze::{SnoozeOutcome, apply_snooze};
+
+/// What keeps a new release from being announced before it is cached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Hold {
+    /// Nothing: the release is cached and announced.
+    None,
+    /// The release is cached but not announced, as it is older than the cached one.
+    Older,
+    /// The release is neither cached nor announced, so a later poll finds it new again.
+    Held,
+}
+
+/// Whether a release that made it past the cache is announced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Announce {
+    No,
+    /// Announce it; `catch_up` when it is only announced because a snooze just ended.
+    Yes {
+        catch_up: bool,
+    },
+}
+
+impl PollCycle<'_> {
+    /// Decides whether a new release of `r` is held back before it is cached: because the
+    /// cached release was removed and the latest one is older, because it is too young,
+    /// or because the repository's throttle window is open.
+    pub(super) async fn hold_new_release(
+        &mut self,
+        r: &TrackedRelease,
+        repo_settings: &mut RepositorySettings,
+        latest: &LatestRelease,
+        previous: Option<&CachedRepositoryRelease>,
+    ) -> Hold {
+        // Critical repositories notify whatever would mute or hold them back
+        let critical = repo_settings.critical;
+        if repo_settings.muted && !critical {
+            return Hold::None;
+        }
+        if let Some(cached) = previous
+            && self.notice_removed_release(r, latest, cached).await
+        {
+            log::debug!(
+                "{} of {} is an older release, not announcing it",
+                latest.tag,
+                r.repository_url
+            );
+            return Hold::Older;
+        }
+        if critical {
+            return Hold::None;
+        }
+        if self.hold_if_too_young(r, repo_settings, latest).await
+            || self.hold_if_throttled(r, repo_settings, latest)
+        {
+            return Hold::Held;
+        }
+        Hold::None
+    }
+
+    /// Applies the chat's and the repository's snooze and the prerelease collapse window
+    /// to a cached release of `r`, which is new when `should_notify`.
+    pub(super) async fn announce_cached_release(
+        &mut self,
+        r: &TrackedRelease,
+        repo_settings: &mut RepositorySettings,
+        latest_tag: &str,
+        mut should_notify: bool,
+    ) -> Announce {
+        let critical = repo_settings.critical;
+        if !critical && self.hold_if_chat_snoozed(r, should_notify).await {
+            return Announce::No;
+        }
+
+        let mut catch_up = false;
+        let snooze = if critical {
+            SnoozeOutcome::Inactive
+        } else {
+            apply_snooze(repo_settings, latest_tag, should_notify, self.now)
+        };
+        match snooze {
+            SnoozeOutcome::Inactive => {}
+            SnoozeOutcome::Suppressed { changed } => {
+                log::debug!("Suppressing {} while snoozed", r.repository_url);
+                if changed {
+                    self.save_settings(repo_settings).await;
+                }
+                return Announce::No;
+            }
+            SnoozeOutcome::Resumed { catch_up: missed } => {
+                self.save_settings(repo_settings).await;
+                catch_up = missed && !should_notify;
+            }
+        }
+
+        if should_notify && !critical && repo_settings.prerelease_collapse_secs.is_some() {
+            should_notify = collapse_prerelease(repo_settings, latest_tag, self.now);
+            if should_notify {
+                self.save_settings(repo_settings).await;
+            } else {
+                log::debug!(
+                    "Collapsing prerelease {} for {}",
+                    latest_tag,
+                    r.repository_url
+                );
+            }
+        }
+
+        if should_notify || catch_up {
+            Announce::Yes { catch_up }
+        } else {
+            Announce::No
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::chat_settings::MessageFormat;
+use crate::github::{GithubError, fetch_repository_with_base, github_api_base};
+use crate::messages::{Text, text};
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::settings::RepositorySettings;
+use crate::tracked_repositories::tracked_repositories_releases::repository::CachedRepositoryReleasesRepository;
+
+use super::cycle::PollCycle;
+use super::fetch::LatestRelease;
+
+impl PollCycle<'_> {
+    /// Asks GitHub about the repository itself: `Some(false)` when it answers 404, which
+    /// it does both for deleted repositories and for private ones the token cannot see.
+    /// `None` when the answer says nothing, or for package registry URLs.
+    async fn repository_exists(&self, r: &TrackedRelease) -> Option<bool> {
+        let (owner, repo) = r.repository_url.owner_and_repo()?;
+        let base = self
+            .github_base_override
+            .map(str::to_string)
+            .unwrap_or_else(github_api_base);
+        match fetch_repository_with_base(self.client, &owner, &repo, self.token_opt, &base).await {
+            Ok(metadata) => Some(metadata.is_some()),
+            Err(e) => {
+                log::debug!("Could not look up {}: {}", r.repository_url, e);
+                None
+            }
+        }
+    }
+
+    /// Notices a repository turning inaccessible, or coming back, from the outcome of
+    /// fetching its newest release. A 404, or finding no release where one was cached
+    /// before, is confirmed against the repository endpoint before the chat is told.
+    pub(super) async fn track_accessibility(
+        &mut self,
+        r: &TrackedRelease,
+        settings: &mut RepositorySettings,
+        latest: &Result<Option<LatestRelease>, GithubError>,
+    ) {
+        match latest {
+            Ok(Some(_)) if settings.inaccessible_since.is_some() => {
+                self.mark_accessible(r, settings).await;
+            }
+            Ok(None) | Err(GithubError::Status { status: 404 })
+                if settings.inaccessible_since.is_none() =>
+            {
+                if latest.is_ok() {
+                    let had_releases = matches!(
+                        self.cache_repo.find_by_tracked_release_id(&r.id).await,
+                        Ok(Some(_))
+                    );
+                    if !had_releases {
+                        return;
+                    }
+                }
+                if self.repository_exists(r).await == Some(false) {
+                    self.mark_inaccessible(r, settings).await;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Checks whether a paused repository is reachable again, returning whether the poll
+    /// goes on.
+    pub(super) async fn resume_if_accessible(
+        &mut self,
+        r: &TrackedRelease,
+        settings: &mut RepositorySettings,
+    ) -> bool {
+        if self.repository_exists(r).await != Some(true) {
+            log::debug!("Skipping {} while it is inaccessible", r.repository_url);
+            return false;
+        }
+        self.mark_accessible(r, settings).await;
+        true
+    }
+
+    async fn mark_inaccessible(&mut self, r: &TrackedRelease, settings: &mut RepositorySettings) {
+        log::info!("{} is no longer accessible", r.repository_url);
+        settings.inaccessible_since = Some(self.now);
+        self.save_settings(settings).await;
+
+        let chat_settings = self.settings.get(r.chat_id).await;
+        let format = chat_settings.message_format;
+        let lang = chat_settings.language;
+        let next = if self.pause_inaccessible {
+            Text::InaccessiblePaused
+        } else {
+            Text::InaccessibleWatched
+        };
+        let message = format!(
+            "{} {}",
+            text(lang, Text::NoLongerAccessible),
+            text(lang, next)
+        );
+        let text = link_repository(r, format, &message);
+        self.pending.push(chat_settings, text);
+    }
+
+    async fn mark_accessible(&mut self, r: &TrackedRelease, settings: &mut RepositorySettings) {
+        log::info!("{} is accessible again", r.repository_url);
+        settings.inaccessible_since = None;
+        self.save_settings(settings).await;
+
+        let chat_settings = self.settings.get(r.chat_id).await;
+        let format = chat_settings.message_format;
+        let message = text(chat_settings.language, Text::AccessibleAgain);
+        let text = link_repository(r, format, message);
+        self.pending.push(chat_settings, text);
+    }
+}
+
+/// Escapes `message`, turning its `{repository}` placeholder into a link to the repository.
+fn link_repository(r: &TrackedRelease, format: MessageFormat, message: &str) -> String {
+    let (before, after) = message.split_once("{repository}").unwrap_or((message, ""));
+    format!(
+        "{}{}{}",
+        format.escape(before),
+        format.link(&r.repository_url.to_string(), &r.repository_name),
+        format.escape(after)
+    )
+}
@@ -0,0 +1,77 @@
+use crate::github::GithubError;
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::mirrors::repository::RepositoryMirrorsRepository;
+use crate::tracked_repositories::settings::RepositorySettings;
+use crate::tracked_repositories::tracked_repositories_releases::FetchStatus;
+
+use super::cycle::PollCycle;
+use super::fetch::{LatestRelease, fetch_latest_from_sources};
+use super::prerelease::prerelease_base;
+
+impl PollCycle<'_> {
+    /// Fetches the newest release of `r` from the repository and its mirrors, recording
+    /// how the fetch went. Returns `None` when there is nothing to announce from, after
+    /// noting errors that cut the cycle short.
+    pub(super) async fn fetch_latest(
+        &mut self,
+        r: &TrackedRelease,
+        repo_settings: &mut RepositorySettings,
+    ) -> Option<LatestRelease> {
+        let mut sources = vec![r.repository_url.clone()];
+        match self.mirrors_repo.find_by_tracked_repository_id(&r.id).await {
+            Ok(mirrors) => sources.extend(mirrors.into_iter().map(|m| m.repository_url)),
+            Err(e) => log::warn!("Failed to load mirrors for {}: {}", r.repository_url, e),
+        }
+
+        let latest =
+            fetch_latest_from_sources(self.release_source.as_ref(), &sources, repo_settings).await;
+        let status = match &latest {
+            Ok(Some(_)) => FetchStatus::Ok,
+            Ok(None) => FetchStatus::NoReleases,
+            Err(_) => FetchStatus::Failed,
+        };
+        self.record_fetch_status(r.id, status);
+        if matches!(&latest, Err(e) if e.is_rate_limited()) {
+            self.rate_limited += 1;
+        }
+        self.track_accessibility(r, repo_settings, &latest).await;
+        let latest = match latest {
+            Ok(Some(latest)) => latest,
+            Ok(None) => {
+                log::info!("No new release for {}", r.repository_url);
+                return None;
+            }
+            Err(GithubError::SecondaryRateLimited { retry_after }) => {
+                self.secondary_retry_after = Some(retry_after);
+                return None;
+            }
+            Err(e) if e.is_unreachable() => {
+                self.unreachable = Some(e);
+                return None;
+            }
+            Err(e) => {
+                log::warn!(
+                    "Poller failed to fetch latest release for {}: {}",
+                    r.repository_url,
+                    e
+                );
+                return None;
+            }
+        };
+
+        // The feed lists whatever was published last, prereleases included, and does not
+        // tell them apart, so a tag shaped like a prerelease waits for the API to answer
+        if latest.details.from_feed {
+            self.rate_limited += 1;
+            if prerelease_base(&latest.tag).is_some() {
+                log::info!(
+                    "{} lists {} in its releases feed, announcing once the API answers again",
+                    r.repository_url,
+                    latest.tag
+                );
+                return None;
+            }
+        }
+        Some(latest)
+    }
+}
@@ -0,0 +1,184 @@
+use crate::chat_settings::{Lang, MessageFormat};
+use crate::github::{fetch_commit_count_with_base, github_api_base};
+use crate::messages::{Text, render, text};
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::settings::RepositorySettings;
+use crate::tracked_repositories::tracked_repositories_releases::FetchStatus;
+use crate::utils::{MAX_NAME_CHARS, truncate_chars};
+
+use super::cycle::PollCycle;
+
+/// The milestone reached with `count` commits and whether it is new. The first count seen
+/// only sets the baseline; jumping past several milestones at once announces the highest.
+/// The stored milestone never goes down, so history rewrites do not repeat announcements.
+pub(crate) fn crossed_milestone(
+    last_notified: Option<i64>,
+    count: i64,
+    every: i64,
+) -> (i64, Option<i64>) {
+    let reached = count / every.max(1) * every.max(1);
+    match last_notified {
+        None => (reached, None),
+        Some(last) if reached > last => (reached, Some(reached)),
+        Some(last) => (last, None),
+    }
+}
+
+pub(crate) fn format_milestone_message(
+    tracked: &TrackedRelease,
+    milestone: i64,
+    format: MessageFormat,
+    lang: Lang,
+) -> String {
+    let url = tracked.repository_url.to_string();
+    let branch = tracked
+        .default_branch
+        .as_deref()
+        .unwrap_or(text(lang, Text::TheDefaultBranch));
+    let commits = render(
+        lang,
+        Text::MilestoneCommits,
+        &[("count", &milestone.to_string()), ("branch", branch)],
+    );
+    format!(
+        "{} {} {}",
+        format.link(
+            &url,
+            &truncate_chars(&tracked.repository_name, MAX_NAME_CHARS)
+        ),
+        format.escape(text(lang, Text::MilestoneReached)),
+        format.link_markup(&format!("{url}/commits"), &format.bold(&commits)),
+    )
+}
+
+impl PollCycle<'_> {
+    /// Checks a repository followed through the commit count of its default branch.
+    pub(super) async fn process_milestones(
+        &mut self,
+        r: &TrackedRelease,
+        mut repo_settings: RepositorySettings,
+        every: i64,
+    ) {
+        let Some((owner, repo)) = r.repository_url.owner_and_repo() else {
+            return;
+        };
+        let base = self
+            .github_base_override
+            .map(str::to_string)
+            .unwrap_or_else(github_api_base);
+        let count = match fetch_commit_count_with_base(
+            self.client,
+            &owner,
+            &repo,
+            r.default_branch.as_deref(),
+            self.token_opt,
+            &base,
+        )
+        .await
+        {
+            Ok(Some(count)) => {
+                self.record_fetch_status(r.id, FetchStatus::Ok);
+                i64::try_from(count).unwrap_or(i64::MAX)
+            }
+            Ok(None) => {
+                self.record_fetch_status(r.id, FetchStatus::NoReleases);
+                log::info!("No commits found for {}", r.repository_url);
+                return;
+            }
+            Err(e) => {
+                self.record_fetch_status(r.id, FetchStatus::Failed);
+                log::warn!(
+                    "Poller failed to count commits of {}: {}",
+                    r.repository_url,
+                    e
+                );
+                return;
+            }
+        };
+
+        let (last, announce) = crossed_milestone(repo_settings.last_notified_count, count, every);
+        if repo_settings.last_notified_count != Some(last) {
+            repo_settings.last_notified_count = Some(last);
+            repo_settings.updated_at = self.now;
+            self.save_settings(&repo_settings).await;
+        }
+        let Some(milestone) = announce else {
+            return;
+        };
+        if repo_settings.muted {
+            return;
+        }
+        log::debug!(
+            "Queueing milestone {} of {} to {}",
+            milestone,
+            r.repository_url,
+            r.chat_id
+        );
+        let chat_settings = self.settings.get(r.chat_id).await;
+        let message = format_milestone_message(
+            r,
+            milestone,
+            chat_settings.message_format,
+            chat_settings.language,
+        );
+        self.pending.push(chat_settings, message);
+        self.record_notified(r, &format!("milestone {milestone}"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracked_repositories::RepositoryUrl;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    #[test]
+    fn first_count_only_sets_the_baseline() {
+        assert_eq!(crossed_milestone(None, 1234, 100), (1200, None));
+        assert_eq!(crossed_milestone(None, 99, 100), (0, None));
+    }
+
+    #[test]
+    fn announces_when_a_multiple_is_crossed() {
+        assert_eq!(crossed_milestone(Some(1200), 1299, 100), (1200, None));
+        assert_eq!(crossed_milestone(Some(1200), 1300, 100), (1300, Some(1300)));
+        assert_eq!(crossed_milestone(Some(1300), 1301, 100), (1300, None));
+    }
+
+    #[test]
+    fn several_milestones_at_once_announce_the_highest() {
+        assert_eq!(crossed_milestone(Some(100), 455, 100), (400, Some(400)));
+    }
+
+    #[test]
+    fn shrinking_history_does_not_lower_the_milestone() {
+        assert_eq!(crossed_milestone(Some(1300), 1250, 100), (1300, None));
+        assert_eq!(crossed_milestone(Some(1300), 1310, 100), (1300, None));
+    }
+
+    #[test]
+    fn formats_milestone_announcement() {
+        let tracked = TrackedRelease {
+            id: Uuid::now_v7(),
+            repository_name: "repo".to_string(),
+            repository_url: RepositoryUrl::new("https://github.com/owner/repo".to_string())
+                .unwrap(),
+            chat_id: 1,
+            bot_id: String::new(),
+            default_branch: Some("main".to_string()),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        assert_eq!(
+            format_milestone_message(&tracked, 1300, MessageFormat::Html, Lang::En),
+            "<a href=\"https://github.com/owner/repo\">repo</a> reached \
+             <a href=\"https://github.com/owner/repo/commits\"><b>1300 commits on main</b></a>"
+        );
+        assert!(
+            format_milestone_message(&tracked, 1300, MessageFormat::Html, Lang::De)
+                .contains("<b>1300 Commits auf main</b>")
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::settings::RepositorySettings;
+
+use super::cycle::PollCycle;
+use super::fetch::LatestRelease;
+
+/// What a repository's minimum release age means for a new release found in this poll.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum AgeOutcome {
+    /// The release may be announced; `changed` tells whether `settings` must be saved.
+    OldEnough { changed: bool },
+    /// The release is held back until `until`; `changed` tells whether `settings` must be
+    /// saved.
+    TooYoung { until: DateTime<Utc>, changed: bool },
+}
+
+/// Checks a new release against the minimum age stored in `settings`. The age counts from
+/// GitHub's `published_at`, or for tags from when the poller first saw `tag`, which is
+/// remembered in `settings` while the release is held back.
+pub(crate) fn apply_min_age(
+    settings: &mut RepositorySettings,
+    tag: &str,
+    published_at: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> AgeOutcome {
+    let Some(min_age) = settings.min_release_age_secs else {
+        return AgeOutcome::OldEnough { changed: false };
+    };
+
+    let held = settings.young_release_tag.as_deref() == Some(tag);
+    let seen_at = settings
+        .young_release_seen_at
+        .filter(|_| held)
+        .unwrap_or(now);
+    let until = published_at.unwrap_or(seen_at) + Duration::seconds(min_age);
+    if until > now {
+        if held {
+            return AgeOutcome::TooYoung {
+                until,
+                changed: false,
+            };
+        }
+        settings.young_release_tag = Some(tag.to_string());
+        settings.young_release_seen_at = Some(now);
+        return AgeOutcome::TooYoung {
+            until,
+            changed: true,
+        };
+    }
+
+    settings.young_release_seen_at = None;
+    AgeOutcome::OldEnough {
+        changed: settings.young_release_tag.take().is_some(),
+    }
+}
+
+impl PollCycle<'_> {
+    /// Holds back a new release of `r` that is younger than the repository's minimum age,
+    /// returning whether it was held.
+    pub(super) async fn hold_if_too_young(
+        &self,
+        r: &TrackedRelease,
+        settings: &mut RepositorySettings,
+        latest: &LatestRelease,
+    ) -> bool {
+        match apply_min_age(settings, &latest.tag, latest.details.published_at, self.now) {
+            AgeOutcome::TooYoung { until, changed } => {
+                log::debug!(
+                    "Holding back {} of {} until {}",
+                    latest.tag,
+                    r.repository_url,
+                    until
+                );
+                if changed {
+                    self.save_settings(settings).await;
+                }
+                true
+            }
+            AgeOutcome::OldEnough { changed } => {
+                if changed {
+                    self.save_settings(settings).await;
+                }
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn settings(min_age_secs: Option<i64>) -> RepositorySettings {
+        let mut s = RepositorySettings::default_for(Uuid::now_v7());
+        s.min_release_age_secs = min_age_secs;
+        s
+    }
+
+    #[test]
+    fn without_a_minimum_every_release_is_old_enough() {
+        let mut s = settings(None);
+        assert_eq!(
+            apply_min_age(&mut s, "v1", None, Utc::now()),
+            AgeOutcome::OldEnough { changed: false }
+        );
+    }
+
+    #[test]
+    fn published_at_decides_the_age() {
+        let now = Utc::now();
+        let mut s = settings(Some(600));
+
+        assert_eq!(
+            apply_min_age(&mut s, "v1", Some(now - Duration::minutes(3)), now),
+            AgeOutcome::TooYoung {
+                until: now + Duration::minutes(7),
+                changed: true
+            }
+        );
+        assert_eq!(
+            apply_min_age(&mut s, "v1", Some(now - Duration::minutes(3)), now),
+            AgeOutcome::TooYoung {
+                until: now + Duration::minutes(7),
+                changed: false
+            }
+        );
+        assert_eq!(
+            apply_min_age(&mut s, "v1", Some(now - Duration::minutes(10)), now),
+            AgeOutcome::OldEnough { changed: true }
+        );
+        assert_eq!(s.young_release_tag, None);
+        assert_eq!(s.young_release_seen_at, None);
+    }
+
+    #[test]
+    fn tags_age_from_when_they_were_first_seen() {
+        let start = Utc::now();
+        let mut s = settings(Some(600));
+
+        assert!(matches!(
+            apply_min_age(&mut s, "v1", None, start),
+            AgeOutcome::TooYoung { changed: true, .. }
+        ));
+        assert!(matches!(
+            apply_min_age(&mut s, "v1", None, start + Duration::minutes(5)),
+            AgeOutcome::TooYoung { changed: false, .. }
+        ));
+        // A different tag starts its own clock
+        assert!(matches!(
+            apply_min_age(&mut s, "v2", None, start + Duration::minutes(9)),
+            AgeOutcome::TooYoung { changed: true, .. }
+        ));
+        assert_eq!(
+            apply_min_age(&mut s, "v2", None, start + Duration::minutes(19)),
+            AgeOutcome::OldEnough { changed: true }
+        );
+    }
+}
@@ -0,0 +1,306 @@
+mod announce;
+mod backoff;
+mod cursor;
+mod cycle;
+mod decision;
+mod discussions;
+mod edits;
+mod fetch;
+mod history;
+mod holds;
+mod inaccessible;
+mod latest;
+mod milestones;
+mod min_age;
+mod notification;
+mod pending;
+mod pins;
+mod prerelease;
+#[cfg(feature = "rest-api")]
+mod pushed;
+mod release_notes;
+mod removed;
+mod schedule;
+mod settings_cache;
+mod snooze;
+mod tag_watches;
+mod throttle;
+mod versions;
+#[cfg(feature = "webhooks")]
+mod webhooks;
+
+use std::sync::Arc;
+use teloxide::prelude::*;
+use tokio::time::{Duration, sleep};
+
+use crate::configuration::Configuration;
+use crate::github::{HttpReleaseSource, ReleaseSource, github_api_base};
+use crate::notifications::repository::SqliteNotificationReceiptsRepository;
+use crate::tracked_repositories::repository::SqliteTrackedRepositoriesRepository;
+use crate::tracked_repositories::repository::TrackedRepositoriesRepository;
+use backoff::{BackoffChange, RateLimitBackoff};
+use cursor::{load_cursor, resume_after};
+use cycle::PollCycle;
+use tag_watches::remove_fired_watches;
+
+pub(crate) use decision::{Decision, decide};
+pub(crate) use fetch::{LatestRelease, fetch_latest_from_sources};
+pub(crate) use notification::release_headline;
+#[cfg(feature = "rest-api")]
+pub(crate) use pushed::process_pushed_release;
+pub use schedule::PollSchedule;
+
+/// What a poll cycle announced, or would announce in a dry run.
+#[derive(Debug, Default)]
+pub(crate) struct CycleReport {
+    /// Repositories whose fetch GitHub refused for a rate limit.
+    pub rate_limited: usize,
+    /// The releases, discussions, milestones and tag watches queued for announcement.
+    pub notified: Vec<String>,
+    /// How long GitHub's secondary rate limit asked to wait, when it cut the cycle short.
+    pub retry_after: Option<Duration>,
+}
+
+impl CycleReport {
+    /// How long to wait before the next cycle: the poll interval, or longer when GitHub
+    /// asked to wait longer.
+    pub(crate) fn next_delay(&self, interval_secs: u64) -> Duration {
+        let interval = Duration::from_secs(interval_secs);
+        self.retry_after.map_or(interval, |r| r.max(interval))
+    }
+}
+
+pub struct AppState {
+    pub db: sqlx::sqlite::SqlitePool,
+    /// The bot whose repositories this poller checks; empty for the bot of `TELOXIDE_TOKEN`.
+    pub bot_id: String,
+    pub cache_write_batch_size: usize,
+    pub release_history_limit: usize,
+    pub schedule: Arc<PollSchedule>,
+    /// Skip repositories that became inaccessible instead of polling them every cycle.
+    pub pause_inaccessible: bool,
+    /// Held while a poll cycle or a pushed release is processed. A cycle announces what it
+    /// found only when it finishes, so a release pushed meanwhile is left to the poller.
+    pub cycle_lock: tokio::sync::Mutex<()>,
+}
+
+pub async fn spawn(state: Arc<AppState>, bot: Bot, config: Configuration) {
+    tokio::spawn(async move {
+        run(state, bot, config).await;
+    });
+}
+
+async fn run(state: Arc<AppState>, bot: Bot, config: Configuration) {
+    log::info!("Starting release poller");
+
+    let client = reqwest::Client::new();
+    let token_opt = config.resolve_token().token();
+    let release_source: Arc<dyn ReleaseSource> = Arc::new(HttpReleaseSource::new(
+        client.clone(),
+        token_opt,
+        github_api_base(),
+    ));
+
+    let mut backoff = RateLimitBackoff::default();
+    loop {
+        let report = poll_once_with_source(
+            state.clone(),
+            &bot,
+            release_source.clone(),
+            &client,
+            token_opt,
+            None,
+            false,
+        )
+        .await;
+
+        if let Some(change) = backoff.record_cycle(report.rate_limited, chrono::Utc::now()) {
+            let interval = backoff.interval_secs(config.interval_secs);
+            report_backoff(&bot, &config, change, interval).await;
+        }
+        sleep(report.next_delay(backoff.interval_secs(config.interval_secs))).await;
+    }
+}
+
+/// Logs a change of the effective poll interval and tells the operator chat about it.
+async fn report_backoff(bot: &Bot, config: &Configuration, change: BackoffChange, interval: u64) {
+    let text = match change {
+        BackoffChange::Lengthened { factor } => {
+            log::warn!(
+                "GitHub keeps rate limiting the poller, polling every {}s ({}x the configured interval)",
+                interval,
+                factor
+            );
+            format!(
+                "GitHub keeps rate limiting the poller, so it now polls every {interval}s. \
+                 Set GITHUB_TOKEN or track fewer repositories to avoid this."
+            )
+        }
+        BackoffChange::Restored => {
+            log::info!("Rate limits subsided, polling every {}s again", interval);
+            format!("Rate limits subsided, the poller polls every {interval}s again.")
+        }
+    };
+    let Some(chat_id) = config.operator_chat_id else {
+        return;
+    };
+    if let Err(e) = bot.send_message(ChatId(chat_id), text).await {
+        log::warn!("Failed to tell {} about the poll interval: {}", chat_id, e);
+    }
+}
+
+/// Runs a poll cycle that reads releases straight from the GitHub API at the given base.
+#[cfg(test)]
+pub(crate) async fn poll_once(
+    state: Arc<AppState>,
+    bot: &Bot,
+    client: &reqwest::Client,
+    token_opt: Option<&str>,
+    github_base_override: Option<&str>,
+    dry_run: bool,
+) -> CycleReport {
+    let base = github_base_override
+        .map(str::to_string)
+        .unwrap_or_else(github_api_base);
+    let release_source = Arc::new(HttpReleaseSource::new(client.clone(), token_opt, base));
+    poll_once_with_source(
+        state,
+        bot,
+        release_source,
+        client,
+        token_opt,
+        github_base_override,
+        dry_run,
+    )
+    .await
+}
+
+/// Runs a poll cycle that reads releases and tags from `release_source`. Discussions, tag
+/// watches and webhooks still go through `client`. A dry run fetches and decides as usual
+/// but stores and sends nothing, so it only reports what the cycle would announce.
+pub(crate) async fn poll_once_with_source(
+    state: Arc<AppState>,
+    bot: &Bot,
+    release_source: Arc<dyn ReleaseSource>,
+    client: &reqwest::Client,
+    token_opt: Option<&str>,
+    github_base_override: Option<&str>,
+    dry_run: bool,
+) -> CycleReport {
+    // A dry run stores and sends nothing, so it cannot race a pushed release
+    let _cycle_guard = if dry_run {
+        None
+    } else {
+        Some(state.cycle_lock.lock().await)
+    };
+    if dry_run {
+        log::info!("Simulating a poll for new releases");
+    } else {
+        log::info!("Polling for new releases");
+    }
+    let repos_repo = SqliteTrackedRepositoriesRepository::new(state.db.clone());
+    let mut cycle = PollCycle::new(
+        state.db.clone(),
+        release_source,
+        client,
+        token_opt,
+        github_base_override,
+    );
+    cycle.history_limit = state.release_history_limit;
+    cycle.pause_inaccessible = state.pause_inaccessible;
+    cycle.dry_run = dry_run;
+    cycle.settings.dry_run = dry_run;
+
+    match repos_repo.find_all_for_bot(&state.bot_id).await {
+        Ok(repos) => {
+            // A cycle GitHub cut short resumes where it stopped, so repeated rate limits
+            // cannot starve the repositories at the end of the list
+            let cursor = load_cursor(&state.db, &state.bot_id)
+                .await
+                .unwrap_or_else(|e| {
+                    log::warn!("Failed to load the poll cursor: {}", e);
+                    None
+                });
+            for r in resume_after(repos, cursor) {
+                cycle.process(&r).await;
+                if !dry_run {
+                    state.schedule.record(r.id, chrono::Utc::now());
+                }
+                if let Some(e) = &cycle.unreachable {
+                    let base = github_base_override
+                        .map(str::to_string)
+                        .unwrap_or_else(github_api_base);
+                    log::warn!(
+                        "GitHub API unreachable at {}, skipping the rest of this poll cycle: {}",
+                        base,
+                        e
+                    );
+                    break;
+                }
+                // Waiting out the secondary rate limit here would hold the cycle lock for
+                // as long as GitHub asks; the next cycle resumes from this repository
+                if let Some(retry_after) = cycle.secondary_retry_after {
+                    log::warn!(
+                        "GitHub secondary rate limit hit while polling {}, leaving the rest \
+                         of this poll cycle to the next one (retry after {}s)",
+                        r.repository_url,
+                        retry_after.as_secs()
+                    );
+                    break;
+                }
+                cycle.last_processed = Some(r.id);
+                if !dry_run {
+                    cycle
+                        .checkpoint(&state.db, &state.bot_id, &r, state.cache_write_batch_size)
+                        .await;
+                }
+            }
+            cycle.went_through = !cycle.stopped_early();
+        }
+        Err(e) => {
+            log::warn!("Poller failed to list repositories: {}", e);
+        }
+    }
+
+    if !cycle.stopped_early() {
+        cycle.check_tag_watches(&state).await;
+    }
+
+    let report = CycleReport {
+        rate_limited: cycle.rate_limited,
+        notified: std::mem::take(&mut cycle.notified),
+        retry_after: cycle.secondary_retry_after,
+    };
+    if !dry_run {
+        finish_cycle(cycle, &state, bot).await;
+    }
+    report
+}
+
+/// Stores what a cycle learned, then sends its notifications.
+async fn finish_cycle(mut cycle: PollCycle<'_>, state: &AppState, bot: &Bot) {
+    // Commit the cache before sending so a crash in between can miss, but never repeat,
+    // a notification
+    let cursor = cycle.final_cursor();
+    cycle
+        .flush_cache(
+            &state.db,
+            &state.bot_id,
+            state.cache_write_batch_size,
+            cursor,
+        )
+        .await;
+    cycle.flush_history(&state.db).await;
+
+    let receipts = SqliteNotificationReceiptsRepository::new(state.db.clone());
+    let fired_watches = std::mem::take(&mut cycle.pending)
+        .send(bot, &receipts)
+        .await;
+    remove_fired_watches(state, &fired_watches).await;
+
+    #[cfg(feature = "webhooks")]
+    cycle.deliver_webhooks().await;
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,314 @@
+use crate::chat_settings::{ChatSettings, Lang, MessageFormat, ReleaseInfo, render_template};
+use crate::messages::{Text, text};
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::settings::Mention;
+use crate::utils::{MAX_NAME_CHARS, MAX_TAG_CHARS, truncate_chars};
+
+use super::fetch::LatestRelease;
+
+/// The default release headline. With `previous_tag` the headline shows which tag the
+/// release follows, as in `v1.0.0 → v1.1.0`.
+pub(crate) fn format_release_message(
+    tracked: &TrackedRelease,
+    latest: &LatestRelease,
+    previous_tag: Option<&str>,
+    format: MessageFormat,
+    lang: Lang,
+) -> String {
+    let url_string = tracked.repository_url.to_string();
+    let headline = if latest.tags_only {
+        text(lang, Text::NewTag)
+    } else {
+        text(lang, Text::NewRelease)
+    };
+    let previous = previous_tag
+        .map(|tag| {
+            let tag = latest
+                .tag_prefix
+                .as_deref()
+                .and_then(|prefix| tag.strip_prefix(prefix))
+                .filter(|rest| !rest.is_empty())
+                .unwrap_or(tag);
+            format
+                .escape(&format!("{} → ", truncate_chars(tag, MAX_TAG_CHARS)))
+                .into_owned()
+        })
+        .unwrap_or_default();
+    format!(
+        "{} {}{} {}{}",
+        format.escape(headline),
+        format.link(
+            &url_string,
+            &truncate_chars(&tracked.repository_name, MAX_NAME_CHARS)
+        ),
+        format.escape(":"),
+        previous,
+        format.link_markup(
+            &latest.url(),
+            &format.bold(&truncate_chars(latest.display_tag(), MAX_TAG_CHARS))
+        ),
+    )
+}
+
+/// The values a template or webhook describes `latest` with.
+pub(crate) fn release_info(tracked: &TrackedRelease, latest: &LatestRelease) -> ReleaseInfo {
+    ReleaseInfo {
+        name: truncate_chars(&tracked.repository_name, MAX_NAME_CHARS).into_owned(),
+        repository: format!("{}/{}", latest.owner, latest.repo),
+        tag: truncate_chars(latest.display_tag(), MAX_TAG_CHARS).into_owned(),
+        url: latest.url(),
+    }
+}
+
+/// The chat's template rendered for `latest`, or the default headline when the chat has
+/// no template or it no longer renders. The default headline names `previous_tag`, the
+/// tag the chat was last told about, unless the chat turned that off.
+pub(crate) fn release_headline(
+    tracked: &TrackedRelease,
+    latest: &LatestRelease,
+    previous_tag: Option<&str>,
+    settings: &ChatSettings,
+) -> String {
+    let (format, lang) = (settings.message_format, settings.language);
+    let previous_tag = previous_tag.filter(|_| settings.show_previous_tag);
+    let Some(template) = settings.message_template.as_deref() else {
+        return format_release_message(tracked, latest, previous_tag, format, lang);
+    };
+    render_template(template, &release_info(tracked, latest), format).unwrap_or_else(|e| {
+        log::warn!(
+            "Template of chat {} failed to render: {}",
+            settings.chat_id,
+            e
+        );
+        format_release_message(tracked, latest, previous_tag, format, lang)
+    })
+}
+
+/// Puts the repository's mention, if any, in front of a notification's text. The chat's
+/// prefix still comes first, as it wraps the whole message.
+pub(crate) fn with_mention(
+    text: String,
+    mention: Option<&Mention>,
+    format: MessageFormat,
+) -> String {
+    match mention {
+        Some(mention) => format!("{} {}", mention.render(format), text),
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracked_repositories::RepositoryUrl;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn tracked(name: &str) -> TrackedRelease {
+        TrackedRelease {
+            id: Uuid::now_v7(),
+            repository_name: name.to_string(),
+            repository_url: RepositoryUrl::new("https://github.com/owner/repo".to_string())
+                .unwrap(),
+            chat_id: 1,
+            bot_id: String::new(),
+            default_branch: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn latest(tag: &str, tags_only: bool) -> LatestRelease {
+        LatestRelease {
+            owner: "owner".to_string(),
+            repo: "repo".to_string(),
+            tag: tag.to_string(),
+            body: None,
+            details: Default::default(),
+            tags_only,
+            tag_prefix: None,
+            package: None,
+            captured: None,
+        }
+    }
+
+    #[test]
+    fn formats_html_release_message() {
+        let text = format_release_message(
+            &tracked("a<b>"),
+            &latest("v1.0.0", false),
+            None,
+            MessageFormat::Html,
+            Lang::En,
+        );
+        assert_eq!(
+            text,
+            "New release for <a href=\"https://github.com/owner/repo\">a&lt;b&gt;</a>: \
+             <a href=\"https://github.com/owner/repo/releases/tag/v1.0.0\"><b>v1.0.0</b></a>"
+        );
+    }
+
+    #[test]
+    fn names_the_previous_tag_when_given() {
+        let text = format_release_message(
+            &tracked("repo"),
+            &latest("v1.1.0", false),
+            Some("v1.0.0"),
+            MessageFormat::MarkdownV2,
+            Lang::En,
+        );
+        assert_eq!(
+            text,
+            "New release for [repo](https://github.com/owner/repo): v1\\.0\\.0 → \
+             [*v1\\.1\\.0*](https://github.com/owner/repo/releases/tag/v1.1.0)"
+        );
+
+        let mut component = latest("pkg-a/v2.0.0", true);
+        component.tag_prefix = Some("pkg-a/".to_string());
+        let text = format_release_message(
+            &tracked("repo"),
+            &component,
+            Some("pkg-a/v1.0.0"),
+            MessageFormat::Html,
+            Lang::En,
+        );
+        assert!(text.contains(": v1.0.0 → <a href="));
+    }
+
+    #[test]
+    fn headline_leaves_out_the_previous_tag_when_turned_off() {
+        let mut settings = ChatSettings::default_for(1);
+        let with_previous = release_headline(
+            &tracked("repo"),
+            &latest("v1.1", false),
+            Some("v1.0"),
+            &settings,
+        );
+        assert!(with_previous.contains(": v1.0 → <a href="));
+
+        settings.show_previous_tag = false;
+        assert_eq!(
+            release_headline(
+                &tracked("repo"),
+                &latest("v1.1", false),
+                Some("v1.0"),
+                &settings
+            ),
+            release_headline(&tracked("repo"), &latest("v1.1", false), None, &settings)
+        );
+    }
+
+    #[test]
+    fn mention_goes_in_front_of_the_headline() {
+        let mention = Mention::parse("42 A&B").unwrap();
+        let text = with_mention(
+            format_release_message(
+                &tracked("repo"),
+                &latest("v1.0.0", false),
+                None,
+                MessageFormat::Html,
+                Lang::En,
+            ),
+            Some(&mention),
+            MessageFormat::Html,
+        );
+        assert!(text.starts_with("<a href=\"tg://user?id=42\">A&amp;B</a> New release for "));
+        assert_eq!(
+            with_mention("text".to_string(), None, MessageFormat::Html),
+            "text"
+        );
+    }
+
+    #[test]
+    fn formats_markdown_release_message() {
+        let text = format_release_message(
+            &tracked("my_repo"),
+            &latest("v1.0.0-rc.1", false),
+            None,
+            MessageFormat::MarkdownV2,
+            Lang::En,
+        );
+        assert_eq!(
+            text,
+            "New release for [my\\_repo](https://github.com/owner/repo): \
+             [*v1\\.0\\.0\\-rc\\.1*](https://github.com/owner/repo/releases/tag/v1.0.0-rc.1)"
+        );
+    }
+
+    #[test]
+    fn formats_release_message_in_the_chat_language() {
+        let text = format_release_message(
+            &tracked("my_repo"),
+            &latest("v1.0.0", false),
+            None,
+            MessageFormat::MarkdownV2,
+            Lang::It,
+        );
+        assert!(text.starts_with("Nuova release per [my\\_repo]"));
+
+        let mut settings = ChatSettings::default_for(1);
+        settings.language = Lang::De;
+        let text = release_headline(&tracked("repo"), &latest("v2.0.0", true), None, &settings);
+        assert!(text.starts_with("Neuer Tag für <a href="));
+    }
+
+    #[test]
+    fn formats_tag_message_with_tree_link() {
+        let text = format_release_message(
+            &tracked("repo"),
+            &latest("v2.0.0", true),
+            None,
+            MessageFormat::Html,
+            Lang::En,
+        );
+        assert_eq!(
+            text,
+            "New tag for <a href=\"https://github.com/owner/repo\">repo</a>: \
+             <a href=\"https://github.com/owner/repo/tree/v2.0.0\"><b>v2.0.0</b></a>"
+        );
+    }
+
+    #[test]
+    fn truncates_huge_tag_and_name() {
+        let tag = "v".repeat(10 * 1024);
+        let name = "<".repeat(1000);
+        for format in [MessageFormat::Html, MessageFormat::MarkdownV2] {
+            let text = format_release_message(
+                &tracked(&name),
+                &latest(&tag, false),
+                None,
+                format,
+                Lang::En,
+            );
+            assert!(text.chars().count() < crate::utils::TELEGRAM_MESSAGE_LIMIT);
+            assert!(text.contains(&format!("{}…", "v".repeat(MAX_TAG_CHARS - 1))));
+            assert!(!text.contains(&tag));
+            assert!(text.contains("https://github.com/owner/repo/releases"));
+        }
+
+        let html = format_release_message(
+            &tracked(&name),
+            &latest("v1", false),
+            None,
+            MessageFormat::Html,
+            Lang::En,
+        );
+        assert!(html.contains(&format!("{}…</a>", "&lt;".repeat(MAX_NAME_CHARS - 1))));
+    }
+
+    #[test]
+    fn headline_uses_chat_template() {
+        let mut settings = ChatSettings::default_for(1);
+        settings.message_template = Some("{repository} {tag}: {url}".to_string());
+        assert_eq!(
+            release_headline(&tracked("repo"), &latest("v1.0", false), None, &settings),
+            "owner/repo v1.0: https://github.com/owner/repo/releases/tag/v1.0"
+        );
+
+        settings.message_template = Some("{unknown}".to_string());
+        assert!(
+            release_headline(&tracked("repo"), &latest("v1.0", false), None, &settings)
+                .starts_with("New release for")
+        );
+    }
+}
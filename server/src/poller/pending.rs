@@ -0,0 +1,325 @@
+use std::collections::BTreeMap;
+
+use teloxide::prelude::*;
+use teloxide::types::{ChatId, MessageId};
+use teloxide::{ApiError, RequestError};
+use uuid::Uuid;
+
+use crate::chat_settings::ChatSettings;
+use crate::notifications::NotificationReceipt;
+use crate::notifications::repository::NotificationReceiptsRepository;
+use crate::utils::{TELEGRAM_MESSAGE_LIMIT, split_message};
+
+use super::pins::pin_latest_release;
+
+/// The release a queued notification announces, recorded once it is delivered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Announced {
+    pub tracked_repository_id: Uuid,
+    pub tag_name: String,
+}
+
+struct Entry {
+    text: String,
+    announces: Option<Announced>,
+    /// The tag watch the notification fires, removed once it is delivered.
+    fires_watch: Option<Uuid>,
+}
+
+/// A Telegram message ready to send, the releases it announces and the tag watches it
+/// fires.
+#[derive(Debug)]
+struct OutgoingMessage {
+    text: String,
+    announces: Vec<Announced>,
+    fires_watches: Vec<Uuid>,
+}
+
+/// Packs the notifications for one chat into messages, wrapping each in the chat's
+/// prefix and suffix while keeping every message within `limit` characters.
+fn render_messages(
+    settings: &ChatSettings,
+    entries: &[Entry],
+    limit: usize,
+) -> Vec<OutgoingMessage> {
+    let format = settings.message_format;
+    let prefix = settings
+        .message_prefix
+        .as_deref()
+        .map(|p| format!("{} ", format.escape(p)))
+        .unwrap_or_default();
+    let suffix = settings
+        .message_suffix
+        .as_deref()
+        .map(|s| format!("\n{}", format.escape(s)))
+        .unwrap_or_default();
+    let available = limit
+        .saturating_sub(prefix.chars().count() + suffix.chars().count())
+        .max(1);
+
+    let mut messages: Vec<OutgoingMessage> = Vec::new();
+    let mut current = OutgoingMessage {
+        text: String::new(),
+        announces: Vec::new(),
+        fires_watches: Vec::new(),
+    };
+    let mut current_len = 0;
+    for entry in entries {
+        for piece in split_message(&entry.text, available) {
+            let piece_len = piece.chars().count();
+            let separator = usize::from(!current.text.is_empty());
+            if current_len + separator + piece_len > available && !current.text.is_empty() {
+                messages.push(std::mem::replace(
+                    &mut current,
+                    OutgoingMessage {
+                        text: String::new(),
+                        announces: Vec::new(),
+                        fires_watches: Vec::new(),
+                    },
+                ));
+                current_len = 0;
+            }
+            if !current.text.is_empty() {
+                current.text.push('\n');
+                current_len += 1;
+            }
+            current.text.push_str(&piece);
+            current_len += piece_len;
+            if let Some(announced) = &entry.announces
+                && !current.announces.contains(announced)
+            {
+                current.announces.push(announced.clone());
+            }
+            if let Some(watch) = entry.fires_watch
+                && !current.fires_watches.contains(&watch)
+            {
+                current.fires_watches.push(watch);
+            }
+        }
+    }
+    if !current.text.is_empty() {
+        messages.push(current);
+    }
+
+    for message in &mut messages {
+        message.text = format!("{prefix}{}{suffix}", message.text);
+    }
+    messages
+}
+
+/// A delivered notification to rewrite in place.
+struct PendingEdit {
+    settings: ChatSettings,
+    telegram_message_id: i32,
+    entry: Entry,
+}
+
+/// Notifications collected during a poll cycle, grouped by destination chat so that
+/// simultaneous releases reach each chat in as few messages as possible.
+#[derive(Default)]
+pub(crate) struct PendingNotifications {
+    by_chat: BTreeMap<i64, (ChatSettings, Vec<Entry>)>,
+    edits: Vec<PendingEdit>,
+}
+
+impl PendingNotifications {
+    fn entries(&mut self, settings: &ChatSettings) -> &mut Vec<Entry> {
+        &mut self
+            .by_chat
+            .entry(settings.chat_id)
+            .or_insert_with(|| (settings.clone(), Vec::new()))
+            .1
+    }
+
+    pub(crate) fn push(&mut self, settings: &ChatSettings, text: String) {
+        self.entries(settings).push(Entry {
+            text,
+            announces: None,
+            fires_watch: None,
+        });
+    }
+
+    /// Queues the notification of a fired tag watch, which is removed once delivered.
+    pub(crate) fn push_tag_watch(&mut self, settings: &ChatSettings, text: String, watch: Uuid) {
+        self.entries(settings).push(Entry {
+            text,
+            announces: None,
+            fires_watch: Some(watch),
+        });
+    }
+
+    /// Queues a notification whose delivery is recorded as a receipt for the release.
+    pub(crate) fn push_release(
+        &mut self,
+        settings: &ChatSettings,
+        text: String,
+        announced: Announced,
+    ) {
+        self.entries(settings).push(Entry {
+            text,
+            announces: Some(announced),
+            fires_watch: None,
+        });
+    }
+
+    /// Queues a new text for an already delivered notification. If the message can no
+    /// longer be edited the text is sent as a new notification instead.
+    pub(crate) fn push_edit(
+        &mut self,
+        settings: &ChatSettings,
+        telegram_message_id: i32,
+        text: String,
+        announced: Announced,
+    ) {
+        self.edits.push(PendingEdit {
+            settings: settings.clone(),
+            telegram_message_id,
+            entry: Entry {
+                text,
+                announces: Some(announced),
+                fires_watch: None,
+            },
+        });
+    }
+
+    /// Applies the queued edits, moving those that failed to the regular notifications.
+    async fn apply_edits(&mut self, bot: &Bot) {
+        for edit in std::mem::take(&mut self.edits) {
+            let chat_id = edit.settings.chat_id;
+            let mut messages = render_messages(
+                &edit.settings,
+                std::slice::from_ref(&edit.entry),
+                TELEGRAM_MESSAGE_LIMIT,
+            );
+            if messages.len() == 1 {
+                let result = bot
+                    .edit_message_text(
+                        ChatId(chat_id),
+                        MessageId(edit.telegram_message_id),
+                        messages.remove(0).text,
+                    )
+                    .parse_mode(edit.settings.message_format.parse_mode())
+                    .await;
+                match result {
+                    Ok(_) | Err(RequestError::Api(ApiError::MessageNotModified)) => continue,
+                    Err(e) => log::info!(
+                        "Could not edit notification {} in {}, sending a new one: {}",
+                        edit.telegram_message_id,
+                        chat_id,
+                        e
+                    ),
+                }
+            }
+            self.entries(&edit.settings).push(edit.entry);
+        }
+    }
+
+    /// Sends every queued notification, recording a receipt for each release delivered.
+    /// Returns the tag watches whose notification was delivered.
+    pub(crate) async fn send(
+        mut self,
+        bot: &Bot,
+        receipts: &impl NotificationReceiptsRepository,
+    ) -> Vec<Uuid> {
+        self.apply_edits(bot).await;
+
+        let mut fired_watches = Vec::new();
+        for (chat_id, (settings, entries)) in self.by_chat {
+            let mut latest_release_message = None;
+            for message in render_messages(&settings, &entries, TELEGRAM_MESSAGE_LIMIT) {
+                let sent = match bot
+                    .send_message(ChatId(chat_id), message.text)
+                    .parse_mode(settings.message_format.parse_mode())
+                    .await
+                {
+                    Ok(sent) => sent,
+                    Err(e) => {
+                        log::warn!("Failed to send release notification to {}: {}", chat_id, e);
+                        continue;
+                    }
+                };
+
+                if !message.announces.is_empty() {
+                    latest_release_message = Some(sent.id.0);
+                }
+                fired_watches.extend(message.fires_watches);
+                for announced in message.announces {
+                    let receipt = NotificationReceipt {
+                        id: Uuid::now_v7(),
+                        tracked_repository_id: announced.tracked_repository_id,
+                        chat_id,
+                        tag_name: announced.tag_name,
+                        telegram_message_id: sent.id.0,
+                        sent_at: chrono::Utc::now(),
+                    };
+                    if let Err(e) = receipts.save(&receipt).await {
+                        log::warn!("Failed to record notification receipt: {}", e);
+                    }
+                }
+            }
+            if settings.pin_releases
+                && let Some(message_id) = latest_release_message
+            {
+                pin_latest_release(bot, receipts, chat_id, message_id).await;
+            }
+        }
+        fired_watches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(text: String, tag: Option<&str>) -> Entry {
+        Entry {
+            text,
+            announces: tag.map(|t| Announced {
+                tracked_repository_id: Uuid::nil(),
+                tag_name: t.to_string(),
+            }),
+            fires_watch: None,
+        }
+    }
+
+    #[test]
+    fn prefix_and_suffix_wrap_each_message_escaped() {
+        let mut settings = ChatSettings::default_for(1);
+        settings.message_prefix = Some("[Releases] <ops>".to_string());
+        settings.message_suffix = Some("— team & co".to_string());
+        let entries = vec![
+            entry("a".repeat(10), None),
+            entry("b".repeat(10), None),
+            entry("c".repeat(10), None),
+        ];
+
+        let messages = render_messages(&settings, &entries, 60);
+
+        assert_eq!(messages.len(), 2);
+        for message in &messages {
+            assert!(message.text.starts_with("[Releases] &lt;ops&gt; "));
+            assert!(message.text.ends_with("\n— team &amp; co"));
+            assert!(message.text.chars().count() <= 60);
+        }
+    }
+
+    #[test]
+    fn messages_remember_the_releases_they_carry() {
+        let settings = ChatSettings::default_for(1);
+        let entries = vec![
+            entry("a".repeat(10), Some("v1")),
+            entry("b".repeat(10), None),
+            entry("c".repeat(10), Some("v2")),
+        ];
+
+        let messages = render_messages(&settings, &entries, 21);
+
+        assert_eq!(messages.len(), 2);
+        assert_eq!(
+            messages[0].text,
+            format!("{}\n{}", "a".repeat(10), "b".repeat(10))
+        );
+        assert_eq!(messages[0].announces[0].tag_name, "v1");
+        assert_eq!(messages[1].announces[0].tag_name, "v2");
+    }
+}
@@ -0,0 +1,61 @@
+use teloxide::prelude::*;
+use teloxide::types::{ChatId, MessageId};
+
+use crate::notifications::repository::NotificationReceiptsRepository;
+
+/// Pins the release notification `telegram_message_id` as the chat's latest release and
+/// unpins the ones pinned before it. The new message is pinned first, so the chat is never
+/// left without a pin; when the bot may not pin messages nothing else changes.
+pub(super) async fn pin_latest_release(
+    bot: &Bot,
+    receipts: &impl NotificationReceiptsRepository,
+    chat_id: i64,
+    telegram_message_id: i32,
+) {
+    let previous = match receipts.find_pinned(chat_id).await {
+        Ok(previous) => previous,
+        Err(e) => {
+            log::warn!("Failed to load pinned notifications of {}: {}", chat_id, e);
+            return;
+        }
+    };
+
+    if let Err(e) = bot
+        .pin_chat_message(ChatId(chat_id), MessageId(telegram_message_id))
+        .disable_notification(true)
+        .await
+    {
+        log::info!(
+            "Could not pin notification {} in {}, the bot may lack the permission: {}",
+            telegram_message_id,
+            chat_id,
+            e
+        );
+        return;
+    }
+    if let Err(e) = receipts
+        .set_pinned(chat_id, telegram_message_id, true)
+        .await
+    {
+        log::warn!("Failed to record pinned notification: {}", e);
+    }
+
+    for message_id in previous.into_iter().filter(|id| *id != telegram_message_id) {
+        // A message that was unpinned or deleted by hand is forgotten all the same
+        if let Err(e) = bot
+            .unpin_chat_message(ChatId(chat_id))
+            .message_id(MessageId(message_id))
+            .await
+        {
+            log::info!(
+                "Could not unpin notification {} in {}: {}",
+                message_id,
+                chat_id,
+                e
+            );
+        }
+        if let Err(e) = receipts.set_pinned(chat_id, message_id, false).await {
+            log::warn!("Failed to record unpinned notification: {}", e);
+        }
+    }
+}
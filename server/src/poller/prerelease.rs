@@ -0,0 +1,104 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::tracked_repositories::settings::RepositorySettings;
+
+/// Returns the version a prerelease tag leads up to: `v1.0.0-rc.1` -> `v1.0.0`.
+pub(crate) fn prerelease_base(tag: &str) -> Option<&str> {
+    let (base, suffix) = tag.split_once('-')?;
+    if suffix.is_empty() || !base.chars().any(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(base)
+}
+
+/// Decides whether a newly detected tag should notify given the repository's prerelease
+/// collapsing window, recording the notification in `settings` when it does.
+///
+/// Returns `true` when the tag should notify.
+pub(crate) fn collapse_prerelease(
+    settings: &mut RepositorySettings,
+    tag: &str,
+    now: DateTime<Utc>,
+) -> bool {
+    let Some(window) = settings.prerelease_collapse_secs.filter(|w| *w > 0) else {
+        return true;
+    };
+    let Some(base) = prerelease_base(tag) else {
+        return true;
+    };
+
+    let same_base = settings.last_prerelease_base.as_deref() == Some(base);
+    let within_window = settings
+        .last_prerelease_notified_at
+        .is_some_and(|at| now - at < Duration::seconds(window));
+    if same_base && within_window {
+        return false;
+    }
+
+    settings.last_prerelease_base = Some(base.to_string());
+    settings.last_prerelease_notified_at = Some(now);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn settings(window: Option<i64>) -> RepositorySettings {
+        let mut s = RepositorySettings::default_for(Uuid::now_v7());
+        s.prerelease_collapse_secs = window;
+        s
+    }
+
+    #[test]
+    fn prerelease_base_extracts_version() {
+        assert_eq!(prerelease_base("v1.0.0-rc.1"), Some("v1.0.0"));
+        assert_eq!(prerelease_base("2.1-beta"), Some("2.1"));
+        assert_eq!(prerelease_base("v1.0.0"), None);
+        assert_eq!(prerelease_base("release-candidate"), None);
+        assert_eq!(prerelease_base("v1-"), None);
+    }
+
+    #[test]
+    fn disabled_collapse_always_notifies() {
+        let mut s = settings(None);
+        let now = Utc::now();
+        assert!(collapse_prerelease(&mut s, "v1.0.0-rc.1", now));
+        assert!(collapse_prerelease(&mut s, "v1.0.0-rc.2", now));
+    }
+
+    #[test]
+    fn rc_bumps_within_window_collapse() {
+        let mut s = settings(Some(3600));
+        let t0 = Utc::now();
+
+        assert!(collapse_prerelease(&mut s, "v1.0.0-rc.1", t0));
+        assert!(!collapse_prerelease(
+            &mut s,
+            "v1.0.0-rc.2",
+            t0 + Duration::minutes(10)
+        ));
+        assert!(!collapse_prerelease(
+            &mut s,
+            "v1.0.0-rc.3",
+            t0 + Duration::minutes(50)
+        ));
+        assert!(collapse_prerelease(
+            &mut s,
+            "v1.0.0-rc.4",
+            t0 + Duration::minutes(61)
+        ));
+    }
+
+    #[test]
+    fn new_base_or_stable_release_is_not_collapsed() {
+        let mut s = settings(Some(3600));
+        let t0 = Utc::now();
+
+        assert!(collapse_prerelease(&mut s, "v1.0.0-rc.1", t0));
+        assert!(collapse_prerelease(&mut s, "v1.0.0", t0));
+        assert!(collapse_prerelease(&mut s, "v1.1.0-rc.1", t0));
+        assert_eq!(s.last_prerelease_base.as_deref(), Some("v1.1.0"));
+    }
+}
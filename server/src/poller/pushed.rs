@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use teloxide::prelude::*;
+
+use crate::github::{GithubError, Release, ReleaseSource};
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+use crate::tracked_repositories::settings::tag_capture::capture;
+use crate::utils::glob_matches;
+
+use super::cycle::PollCycle;
+use super::{AppState, finish_cycle};
+
+/// Answers with a release GitHub pushed to us instead of asking the API.
+struct PushedReleaseSource {
+    owner: String,
+    repo: String,
+    release: Release,
+}
+
+#[async_trait]
+impl ReleaseSource for PushedReleaseSource {
+    async fn latest_release(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Option<Release>, GithubError> {
+        let matches =
+            owner.eq_ignore_ascii_case(&self.owner) && repo.eq_ignore_ascii_case(&self.repo);
+        Ok(matches.then(|| self.release.clone()))
+    }
+
+    async fn latest_tag(&self, _owner: &str, _repo: &str) -> Result<Option<String>, GithubError> {
+        Ok(None)
+    }
+}
+
+/// Runs a release announced by a GitHub webhook through the same checks, cache and
+/// notifications as a poll of `tracked`, so the next poll finds it already seen.
+/// Repositories followed by their tags, discussions or commit milestones, releases whose
+/// tag is ignored, outside the followed component or not matched by the tag capture, and
+/// releases pushed while a poll cycle runs are left to the poller, which is reported by
+/// returning `false`.
+pub(crate) async fn process_pushed_release(
+    state: &AppState,
+    bot: &Bot,
+    tracked: &TrackedRelease,
+    release: Release,
+) -> bool {
+    let Some((owner, repo)) = tracked.repository_url.owner_and_repo() else {
+        return false;
+    };
+    match SqliteRepositorySettingsRepository::new(state.db.clone())
+        .find_or_default(&tracked.id)
+        .await
+    {
+        Ok(settings)
+            if settings.tags_only
+                || settings.discussion_category.is_some()
+                || settings.commit_milestone.is_some()
+                || settings
+                    .tag_ignore
+                    .as_deref()
+                    .is_some_and(|pattern| glob_matches(pattern, &release.tag_name))
+                || settings
+                    .tag_prefix
+                    .as_deref()
+                    .is_some_and(|prefix| !release.tag_name.starts_with(prefix))
+                || settings
+                    .tag_capture
+                    .as_deref()
+                    .is_some_and(|pattern| capture(pattern, &release.tag_name).is_none()) =>
+        {
+            return false;
+        }
+        Ok(_) => {}
+        Err(e) => {
+            log::warn!(
+                "Failed to load settings for {}: {}",
+                tracked.repository_url,
+                e
+            );
+            return false;
+        }
+    }
+
+    // The running cycle may have read the cache before this release and announce it too
+    let Ok(_cycle_guard) = state.cycle_lock.try_lock() else {
+        log::debug!(
+            "A poll cycle is running, leaving {} of {} to it",
+            release.tag_name,
+            tracked.repository_url
+        );
+        return false;
+    };
+    let release_source = Arc::new(PushedReleaseSource {
+        owner,
+        repo,
+        release,
+    });
+    let client = reqwest::Client::new();
+    let mut cycle = PollCycle::new(state.db.clone(), release_source, &client, None, None);
+    cycle.history_limit = state.release_history_limit;
+    cycle.pause_inaccessible = state.pause_inaccessible;
+    cycle.process(tracked).await;
+    finish_cycle(cycle, state, bot).await;
+    true
+}
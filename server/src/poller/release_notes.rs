@@ -0,0 +1,117 @@
+use sha2::{Digest, Sha256};
+
+use crate::chat_settings::{MessageFormat, ReleaseNotes};
+
+/// Release notes longer than this are cut off in notifications.
+const MAX_NOTES_CHARS: usize = 1000;
+
+/// Hex encoded SHA-256 of a release body.
+pub(crate) fn body_hash(body: &str) -> String {
+    Sha256::digest(body.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Returns the notes to include for a release, if any, given the chat's mode and the
+/// body hash stored for the previously seen release.
+pub(crate) fn notes_to_include<'a>(
+    mode: ReleaseNotes,
+    body: Option<&'a str>,
+    previous_hash: Option<&str>,
+) -> Option<&'a str> {
+    let body = body?;
+    match mode {
+        ReleaseNotes::Off => None,
+        ReleaseNotes::On => Some(body),
+        ReleaseNotes::Dedupe if previous_hash == Some(body_hash(body).as_str()) => None,
+        ReleaseNotes::Dedupe => Some(body),
+    }
+}
+
+/// Escaped release notes, truncated to keep notifications readable. The limit applies to
+/// the escaped text, so notes full of entities take no more room than plain ones.
+pub(crate) fn format_release_notes(body: &str, format: MessageFormat) -> String {
+    let body = body.trim();
+    let escaped = format.escape(body);
+    if escaped.chars().count() <= MAX_NOTES_CHARS {
+        return escaped.into_owned();
+    }
+    // Cut the body before escaping it, so the cut never falls inside an entity; one
+    // character is left for the ellipsis
+    let mut buf = [0u8; 4];
+    let mut len = 0;
+    let mut end = 0;
+    for (i, c) in body.char_indices() {
+        len += format.escape(c.encode_utf8(&mut buf)).chars().count();
+        if len >= MAX_NOTES_CHARS {
+            break;
+        }
+        end = i + c.len_utf8();
+    }
+    format!("{}…", format.escape(&body[..end]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::split_message;
+
+    #[test]
+    fn dedupe_skips_identical_body_only() {
+        let previous = body_hash("Same boilerplate");
+
+        assert_eq!(
+            notes_to_include(
+                ReleaseNotes::Dedupe,
+                Some("Same boilerplate"),
+                Some(&previous)
+            ),
+            None
+        );
+        assert_eq!(
+            notes_to_include(ReleaseNotes::Dedupe, Some("Changed"), Some(&previous)),
+            Some("Changed")
+        );
+        assert_eq!(
+            notes_to_include(ReleaseNotes::On, Some("Same boilerplate"), Some(&previous)),
+            Some("Same boilerplate")
+        );
+        assert_eq!(
+            notes_to_include(ReleaseNotes::Off, Some("Changed"), None),
+            None
+        );
+    }
+
+    #[test]
+    fn long_notes_are_truncated_and_escaped() {
+        let notes = format_release_notes(&"x".repeat(MAX_NOTES_CHARS + 5), MessageFormat::Html);
+        assert!(notes.ends_with('…'));
+        assert_eq!(notes.chars().count(), MAX_NOTES_CHARS);
+    }
+
+    #[test]
+    fn entity_heavy_notes_are_truncated_after_escaping() {
+        let body = "a & b <c> ".repeat(MAX_NOTES_CHARS);
+        for format in [MessageFormat::Html, MessageFormat::MarkdownV2] {
+            let notes = format_release_notes(&body, format);
+            assert!(notes.ends_with('…'));
+            assert!(notes.chars().count() <= MAX_NOTES_CHARS);
+        }
+
+        // Neither the cut nor a split of the message falls inside an entity
+        let html = format_release_notes(&body, MessageFormat::Html);
+        assert!(whole_entities(&html));
+        for piece in split_message(&html, 7) {
+            assert!(whole_entities(&piece), "{piece}");
+        }
+    }
+
+    fn whole_entities(html: &str) -> bool {
+        html.match_indices('&').all(|(i, _)| {
+            ["&amp;", "&lt;", "&gt;"]
+                .iter()
+                .any(|entity| html[i..].starts_with(entity))
+        })
+    }
+}
@@ -0,0 +1,157 @@
+use chrono::{DateTime, Utc};
+
+use crate::github::{GithubError, Release};
+use crate::messages::{Text, render};
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::tracked_repositories_releases::CachedRepositoryRelease;
+use crate::utils::{MAX_NAME_CHARS, MAX_TAG_CHARS, truncate_chars};
+
+use super::cycle::PollCycle;
+use super::fetch::LatestRelease;
+
+/// What became of the cached release once the latest release changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Removal {
+    /// The cached release is still listed: the latest release is simply newer.
+    Kept,
+    /// The cached release is gone. `reverted` when the latest release is one that was
+    /// published before the cached one was seen, so it is not new either.
+    Removed { reverted: bool },
+}
+
+/// Looks for `cached_tag` among the repository's recent `releases`. The latest release
+/// counts as reverted unless GitHub says it was published after `cached_seen_at`.
+pub(crate) fn detect_removal(
+    cached_tag: &str,
+    cached_seen_at: DateTime<Utc>,
+    releases: &[Release],
+    latest_published_at: Option<DateTime<Utc>>,
+) -> Removal {
+    if releases.iter().any(|r| r.tag_name == cached_tag) {
+        return Removal::Kept;
+    }
+    Removal::Removed {
+        reverted: latest_published_at.is_none_or(|published| published <= cached_seen_at),
+    }
+}
+
+impl PollCycle<'_> {
+    /// For chats that asked to hear about deleted releases, checks whether the cached
+    /// release of `r` is still on GitHub now that the latest release changed, and queues a
+    /// notice when it is not. Returns whether the latest release is an older one that
+    /// surfaced again, which is not announced as new.
+    pub(super) async fn notice_removed_release(
+        &mut self,
+        r: &TrackedRelease,
+        latest: &LatestRelease,
+        cached: &CachedRepositoryRelease,
+    ) -> bool {
+        // Tags and registry versions are not listed as releases, so missing from the
+        // releases says nothing about them
+        if latest.tags_only || latest.package.is_some() || !cached.from_release {
+            return false;
+        }
+        if !self.settings.get(r.chat_id).await.notify_removed_releases {
+            return false;
+        }
+        let releases = match self
+            .release_source
+            .listed_releases(&latest.owner, &latest.repo)
+            .await
+        {
+            Ok(releases) => releases,
+            Err(GithubError::Unsupported) => return false,
+            Err(e) => {
+                log::debug!("Could not list releases of {}: {}", r.repository_url, e);
+                return false;
+            }
+        };
+        let Removal::Removed { reverted } = detect_removal(
+            &cached.tag_name,
+            cached.first_seen_at,
+            &releases,
+            latest.details.published_at,
+        ) else {
+            return false;
+        };
+
+        log::info!(
+            "Release {} of {} was removed",
+            cached.tag_name,
+            r.repository_url
+        );
+        let chat_settings = self.settings.get(r.chat_id).await;
+        let format = chat_settings.message_format;
+        let message = render(
+            chat_settings.language,
+            Text::ReleaseRemoved,
+            &[
+                ("tag", &truncate_chars(&cached.tag_name, MAX_TAG_CHARS)),
+                (
+                    "latest",
+                    &truncate_chars(latest.display_tag(), MAX_TAG_CHARS),
+                ),
+            ],
+        );
+        let (before, after) = message
+            .split_once("{repository}")
+            .unwrap_or((message.as_str(), ""));
+        let text = format!(
+            "{}{}{}",
+            format.escape(before),
+            format.link(
+                &r.repository_url.to_string(),
+                &truncate_chars(&r.repository_name, MAX_NAME_CHARS)
+            ),
+            format.escape(after)
+        );
+        self.pending.push(chat_settings, text);
+        reverted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::ReleaseDetails;
+    use chrono::Duration;
+
+    fn release(tag: &str) -> Release {
+        Release {
+            tag_name: tag.to_string(),
+            body: None,
+            details: ReleaseDetails::default(),
+        }
+    }
+
+    #[test]
+    fn listed_release_was_kept() {
+        let seen = Utc::now();
+        let releases = [release("v1.1.0"), release("v1.0.0")];
+        assert_eq!(
+            detect_removal("v1.0.0", seen, &releases, Some(seen)),
+            Removal::Kept
+        );
+    }
+
+    #[test]
+    fn missing_release_was_removed() {
+        let seen = Utc::now();
+        let releases = [release("v1.0.0")];
+
+        // The previous release became the latest again
+        assert_eq!(
+            detect_removal("v1.1.0", seen, &releases, Some(seen - Duration::days(3))),
+            Removal::Removed { reverted: true }
+        );
+        assert_eq!(
+            detect_removal("v1.1.0", seen, &releases, None),
+            Removal::Removed { reverted: true }
+        );
+        // Replaced by a release published since
+        assert_eq!(
+            detect_removal("v1.1.0", seen, &releases, Some(seen + Duration::hours(1))),
+            Removal::Removed { reverted: false }
+        );
+    }
+}
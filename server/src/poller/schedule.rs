@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+/// When each tracked repository was last polled, shared with the bot so it can tell
+/// users when a repository will be checked next.
+pub struct PollSchedule {
+    interval: Duration,
+    last_polled: Mutex<HashMap<Uuid, DateTime<Utc>>>,
+}
+
+impl PollSchedule {
+    pub fn new(interval_secs: u64) -> Self {
+        Self {
+            interval: Duration::seconds(i64::try_from(interval_secs).unwrap_or(i64::MAX)),
+            last_polled: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn record(&self, tracked_repository_id: Uuid, at: DateTime<Utc>) {
+        if let Ok(mut last_polled) = self.last_polled.lock() {
+            last_polled.insert(tracked_repository_id, at);
+        }
+    }
+
+    pub fn last_polled_at(&self, tracked_repository_id: &Uuid) -> Option<DateTime<Utc>> {
+        self.last_polled
+            .lock()
+            .ok()
+            .and_then(|last_polled| last_polled.get(tracked_repository_id).copied())
+    }
+
+    /// The next poll of the repository, or `None` if it has not been polled since startup.
+    pub fn next_poll_at(&self, tracked_repository_id: &Uuid) -> Option<DateTime<Utc>> {
+        self.last_polled_at(tracked_repository_id)
+            .map(|last| last + self.interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_poll_is_one_interval_after_the_last() {
+        let schedule = PollSchedule::new(300);
+        let id = Uuid::now_v7();
+        assert_eq!(schedule.next_poll_at(&id), None);
+
+        let last = DateTime::from_timestamp(1700000000, 0).unwrap();
+        schedule.record(id, last);
+        assert_eq!(
+            schedule.next_poll_at(&id),
+            DateTime::from_timestamp(1700000300, 0)
+        );
+
+        schedule.record(id, last + Duration::seconds(300));
+        assert_eq!(
+            schedule.next_poll_at(&id),
+            DateTime::from_timestamp(1700000600, 0)
+        );
+    }
+}
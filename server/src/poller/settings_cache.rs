@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use crate::chat_settings::ChatSettings;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+
+/// Loads each chat's settings at most once per poll cycle.
+pub(crate) struct ChatSettingsCache {
+    repository: SqliteChatSettingsRepository,
+    cache: HashMap<i64, ChatSettings>,
+    /// Keep changed snoozes for the cycle only, without storing them.
+    pub dry_run: bool,
+}
+
+impl ChatSettingsCache {
+    pub(crate) fn new(db: sqlx::sqlite::SqlitePool) -> Self {
+        Self {
+            repository: SqliteChatSettingsRepository::new(db),
+            cache: HashMap::new(),
+            dry_run: false,
+        }
+    }
+
+    pub(crate) async fn get(&mut self, chat_id: i64) -> &ChatSettings {
+        if !self.cache.contains_key(&chat_id) {
+            let settings = match self.repository.find_or_default(chat_id).await {
+                Ok(s) => s,
+                Err(e) => {
+                    log::warn!("Failed to load settings for chat {}: {}", chat_id, e);
+                    ChatSettings::default_for(chat_id)
+                }
+            };
+            self.cache.insert(chat_id, settings);
+        }
+        &self.cache[&chat_id]
+    }
+
+    /// Stores the chat's changed snooze, in the database and for the rest of the cycle.
+    /// A dry run keeps it for the cycle only.
+    pub(crate) async fn save_snooze(&mut self, settings: ChatSettings) {
+        if !self.dry_run
+            && let Err(e) = self.repository.save_snooze(&settings).await
+        {
+            log::warn!(
+                "Failed to save the snooze of chat {}: {}",
+                settings.chat_id,
+                e
+            );
+        }
+        self.cache.insert(settings.chat_id, settings);
+    }
+}
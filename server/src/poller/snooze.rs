@@ -0,0 +1,212 @@
+use chrono::{DateTime, Utc};
+
+use crate::chat_settings::ChatSettings;
+use crate::messages::{Text, text};
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::settings::RepositorySettings;
+
+use super::cycle::PollCycle;
+
+/// What a repository's snooze means for the tag seen in this poll.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum SnoozeOutcome {
+    /// The repository is not snoozed.
+    Inactive,
+    /// The repository is still snoozed; `changed` tells whether `settings` must be saved.
+    Suppressed { changed: bool },
+    /// The snooze just ended and was cleared from `settings`; `catch_up` is set when a
+    /// release was held back in the meantime.
+    Resumed { catch_up: bool },
+}
+
+/// Applies the snooze stored in `settings` to a poll that found `tag`, remembering held
+/// back releases while snoozed and clearing the snooze once it has expired.
+pub(crate) fn apply_snooze(
+    settings: &mut RepositorySettings,
+    tag: &str,
+    is_new: bool,
+    now: DateTime<Utc>,
+) -> SnoozeOutcome {
+    let Some(until) = settings.snoozed_until else {
+        return SnoozeOutcome::Inactive;
+    };
+
+    if until > now {
+        if !is_new {
+            return SnoozeOutcome::Suppressed { changed: false };
+        }
+        settings.snooze_missed_tag = Some(tag.to_string());
+        return SnoozeOutcome::Suppressed { changed: true };
+    }
+
+    settings.snoozed_until = None;
+    let missed = settings.snooze_missed_tag.take();
+    SnoozeOutcome::Resumed {
+        catch_up: missed.is_some(),
+    }
+}
+
+/// What a chat-wide snooze means for a repository of the chat polled now.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum ChatSnoozeOutcome {
+    /// The chat is not snoozed.
+    Inactive,
+    /// The chat is still snoozed; `changed` tells whether `settings` must be saved.
+    Suppressed { changed: bool },
+    /// The snooze just ended and was cleared from `settings`; `missed` names the
+    /// repositories that released in the meantime.
+    Resumed { missed: Vec<String> },
+}
+
+/// Applies the chat's snooze to a poll of `repository_name`, noting the repository when
+/// `is_new` is set while snoozed and clearing the snooze once it has expired.
+pub(crate) fn apply_chat_snooze(
+    settings: &mut ChatSettings,
+    repository_name: &str,
+    is_new: bool,
+    now: DateTime<Utc>,
+) -> ChatSnoozeOutcome {
+    let Some(until) = settings.snoozed_until else {
+        return ChatSnoozeOutcome::Inactive;
+    };
+
+    if until > now {
+        if !is_new || settings.snooze_missed.iter().any(|n| n == repository_name) {
+            return ChatSnoozeOutcome::Suppressed { changed: false };
+        }
+        settings.snooze_missed.push(repository_name.to_string());
+        return ChatSnoozeOutcome::Suppressed { changed: true };
+    }
+
+    settings.snoozed_until = None;
+    ChatSnoozeOutcome::Resumed {
+        missed: std::mem::take(&mut settings.snooze_missed),
+    }
+}
+
+impl PollCycle<'_> {
+    /// Applies the snooze of `r`'s chat, returning whether the poll must stop here. Once
+    /// the snooze has expired the chat gets a summary of the repositories it missed.
+    pub(super) async fn hold_if_chat_snoozed(&mut self, r: &TrackedRelease, is_new: bool) -> bool {
+        let chat_settings = self.settings.get(r.chat_id).await;
+        if chat_settings.snoozed_until.is_none() {
+            return false;
+        }
+        let mut chat_settings = chat_settings.clone();
+        match apply_chat_snooze(&mut chat_settings, &r.repository_name, is_new, self.now) {
+            ChatSnoozeOutcome::Inactive => false,
+            ChatSnoozeOutcome::Suppressed { changed } => {
+                log::debug!("Suppressing {} while its chat is snoozed", r.repository_url);
+                if changed {
+                    self.settings.save_snooze(chat_settings).await;
+                }
+                true
+            }
+            ChatSnoozeOutcome::Resumed { missed } => {
+                if !missed.is_empty() {
+                    let format = chat_settings.message_format;
+                    let summary = format!(
+                        "{}\n{}",
+                        format.escape(text(chat_settings.language, Text::ChatSnoozeEnded)),
+                        format.escape(&missed.join(", "))
+                    );
+                    self.pending.push(&chat_settings, summary);
+                }
+                self.settings.save_snooze(chat_settings).await;
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    fn snoozed(until: DateTime<Utc>) -> RepositorySettings {
+        let mut s = RepositorySettings::default_for(Uuid::now_v7());
+        s.snoozed_until = Some(until);
+        s
+    }
+
+    #[test]
+    fn unsnoozed_repository_is_inactive() {
+        let mut s = RepositorySettings::default_for(Uuid::now_v7());
+        assert_eq!(
+            apply_snooze(&mut s, "v1", true, Utc::now()),
+            SnoozeOutcome::Inactive
+        );
+    }
+
+    #[test]
+    fn records_missed_release_while_snoozed() {
+        let now = Utc::now();
+        let mut s = snoozed(now + Duration::hours(1));
+
+        assert_eq!(
+            apply_snooze(&mut s, "v1", false, now),
+            SnoozeOutcome::Suppressed { changed: false }
+        );
+        assert_eq!(
+            apply_snooze(&mut s, "v2", true, now),
+            SnoozeOutcome::Suppressed { changed: true }
+        );
+        assert_eq!(s.snooze_missed_tag.as_deref(), Some("v2"));
+    }
+
+    #[test]
+    fn resumes_after_expiry() {
+        let now = Utc::now();
+        let mut s = snoozed(now - Duration::minutes(1));
+        s.snooze_missed_tag = Some("v2".to_string());
+
+        assert_eq!(
+            apply_snooze(&mut s, "v2", false, now),
+            SnoozeOutcome::Resumed { catch_up: true }
+        );
+        assert_eq!(s.snoozed_until, None);
+        assert_eq!(s.snooze_missed_tag, None);
+
+        let mut s = snoozed(now - Duration::minutes(1));
+        assert_eq!(
+            apply_snooze(&mut s, "v2", false, now),
+            SnoozeOutcome::Resumed { catch_up: false }
+        );
+    }
+
+    #[test]
+    fn chat_snooze_lists_each_missed_repository_once() {
+        let now = Utc::now();
+        let mut s = ChatSettings::default_for(1);
+        assert_eq!(
+            apply_chat_snooze(&mut s, "a", true, now),
+            ChatSnoozeOutcome::Inactive
+        );
+
+        s.snoozed_until = Some(now + Duration::hours(1));
+        assert_eq!(
+            apply_chat_snooze(&mut s, "a", true, now),
+            ChatSnoozeOutcome::Suppressed { changed: true }
+        );
+        assert_eq!(
+            apply_chat_snooze(&mut s, "a", true, now),
+            ChatSnoozeOutcome::Suppressed { changed: false }
+        );
+        assert_eq!(
+            apply_chat_snooze(&mut s, "b", false, now),
+            ChatSnoozeOutcome::Suppressed { changed: false }
+        );
+
+        let later = now + Duration::hours(2);
+        assert_eq!(
+            apply_chat_snooze(&mut s, "b", false, later),
+            ChatSnoozeOutcome::Resumed {
+                missed: vec!["a".to_string()]
+            }
+        );
+        assert_eq!(s.snoozed_until, None);
+        assert!(s.snooze_missed.is_empty());
+    }
+}
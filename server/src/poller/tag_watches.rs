@@ -0,0 +1,95 @@
+use crate::chat_settings::{Lang, MessageFormat};
+use crate::github::{tag_exists, tag_exists_with_base};
+use crate::messages::{Text, text};
+use crate::poller::AppState;
+use crate::tag_watches::TagWatch;
+use crate::tag_watches::repository::{SqliteTagWatchesRepository, TagWatchesRepository};
+use crate::utils::{MAX_TAG_CHARS, truncate_chars};
+
+use super::cycle::PollCycle;
+
+pub(crate) fn format_tag_watch_message(
+    watch: &TagWatch,
+    format: MessageFormat,
+    lang: Lang,
+) -> String {
+    let url_string = watch.repository_url.to_string();
+    format!(
+        "{} {} {} {}",
+        format.escape(text(lang, Text::TagWatchOut)),
+        format.bold(&truncate_chars(&watch.tag_name, MAX_TAG_CHARS)),
+        format.escape(text(lang, Text::TagWatchOn)),
+        format.link(&url_string, &url_string),
+    )
+}
+
+impl PollCycle<'_> {
+    /// Checks every pending tag watch of the bot, queueing a notification once its tag
+    /// has been published. The watch is only removed when that notification is delivered.
+    pub(super) async fn check_tag_watches(&mut self, state: &AppState) {
+        let watches_repo = SqliteTagWatchesRepository::new(state.db.clone());
+        let watches = match watches_repo.find_all_for_bot(&state.bot_id).await {
+            Ok(w) => w,
+            Err(e) => {
+                log::warn!("Poller failed to list tag watches: {}", e);
+                return;
+            }
+        };
+
+        for watch in watches {
+            let Some((owner, repo)) = watch.repository_url.owner_and_repo() else {
+                continue;
+            };
+            let (client, token_opt) = (self.client, self.token_opt);
+            let exists = if let Some(base) = self.github_base_override {
+                tag_exists_with_base(client, &owner, &repo, &watch.tag_name, token_opt, base).await
+            } else {
+                tag_exists(client, &owner, &repo, &watch.tag_name, token_opt).await
+            };
+
+            match exists {
+                Ok(true) => {
+                    log::debug!(
+                        "Watched tag {} appeared on {}/{}",
+                        watch.tag_name,
+                        owner,
+                        repo
+                    );
+                    let chat_settings = self.settings.get(watch.chat_id).await;
+                    let message = format_tag_watch_message(
+                        &watch,
+                        chat_settings.message_format,
+                        chat_settings.language,
+                    );
+                    self.pending
+                        .push_tag_watch(chat_settings, message, watch.id);
+                    self.notified.push(format!(
+                        "{} watched tag {} (chat {})",
+                        watch.repository_url,
+                        truncate_chars(&watch.tag_name, MAX_TAG_CHARS),
+                        watch.chat_id
+                    ));
+                }
+                Ok(false) => {}
+                Err(e) => {
+                    log::warn!(
+                        "Poller failed to check tag {} for {}: {}",
+                        watch.tag_name,
+                        watch.repository_url,
+                        e
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Removes the tag watches whose notification was delivered.
+pub(super) async fn remove_fired_watches(state: &AppState, fired: &[uuid::Uuid]) {
+    let watches_repo = SqliteTagWatchesRepository::new(state.db.clone());
+    for id in fired {
+        if let Err(e) = watches_repo.delete(id).await {
+            log::warn!("Failed to remove fired tag watch {}: {}", id, e);
+        }
+    }
+}
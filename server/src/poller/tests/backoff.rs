@@ -0,0 +1,263 @@
+use super::*;
+use crate::github::{GithubError, Release, ReleaseDetails, ReleaseSource};
+use crate::poller::backoff::{BackoffChange, RateLimitBackoff};
+use async_trait::async_trait;
+
+/// Refuses every request as if the rate limit were exhausted.
+struct RateLimitedSource;
+
+#[async_trait]
+impl ReleaseSource for RateLimitedSource {
+    async fn latest_release(
+        &self,
+        _owner: &str,
+        _repo: &str,
+    ) -> Result<Option<Release>, GithubError> {
+        Err(GithubError::Status { status: 429 })
+    }
+
+    async fn latest_tag(&self, _owner: &str, _repo: &str) -> Result<Option<String>, GithubError> {
+        Err(GithubError::Status { status: 429 })
+    }
+}
+
+#[tokio::test]
+async fn repeated_rate_limited_cycles_lengthen_the_interval() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let tg = Server::new_async().await;
+    let bot = Bot::new("TESTTOKEN").set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    for name in ["one", "two", "three"] {
+        let url = format!("https://github.com/owner/{name}");
+        insert_tracked(&state, name, &url, 1).await;
+    }
+
+    let mut backoff = RateLimitBackoff::default();
+    let mut changes = Vec::new();
+    let start = Utc::now();
+    for minute in 0..2 {
+        let rate_limited = poll_once_with_source(
+            state.clone(),
+            &bot,
+            Arc::new(RateLimitedSource),
+            &client,
+            None,
+            Some("http://127.0.0.1:9"),
+            false,
+        )
+        .await
+        .rate_limited;
+        assert_eq!(rate_limited, 3);
+        changes
+            .extend(backoff.record_cycle(rate_limited, start + chrono::Duration::minutes(minute)));
+    }
+
+    assert_eq!(changes, [BackoffChange::Lengthened { factor: 2 }]);
+    assert_eq!(backoff.interval_secs(60), 120);
+}
+
+/// Answers as the HTTP source does while rate limited: from the releases feed.
+struct FeedSource;
+
+#[async_trait]
+impl ReleaseSource for FeedSource {
+    async fn latest_release(
+        &self,
+        _owner: &str,
+        _repo: &str,
+    ) -> Result<Option<Release>, GithubError> {
+        Ok(Some(Release {
+            tag_name: "v2.0.0-rc.1".to_string(),
+            body: None,
+            details: ReleaseDetails {
+                from_feed: true,
+                ..ReleaseDetails::default()
+            },
+        }))
+    }
+
+    async fn latest_tag(&self, _owner: &str, _repo: &str) -> Result<Option<String>, GithubError> {
+        Err(GithubError::Status { status: 429 })
+    }
+}
+
+#[tokio::test]
+async fn prereleases_read_from_the_feed_wait_for_the_api() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut tg = Server::new_async().await;
+    let bot = Bot::new("TESTTOKEN").set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 1).await;
+    let cache = SqliteCachedRepositoryReleasesRepository::new(state.db.clone());
+    cache
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    let m_tg = tg
+        .mock("POST", mockito::Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let outcome = poll_once_with_source(
+        state.clone(),
+        &bot,
+        Arc::new(FeedSource),
+        &client,
+        None,
+        Some("http://127.0.0.1:9"),
+        false,
+    )
+    .await;
+
+    assert_eq!(outcome.rate_limited, 1);
+    assert!(outcome.notified.is_empty());
+    let cached = cache
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(cached.tag_name, "v1.0.0");
+    m_tg.assert_async().await;
+}
+
+#[tokio::test]
+async fn stable_releases_read_from_the_feed_are_announced() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let bot = Bot::new("TESTTOKEN").set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 1).await;
+    let cache = SqliteCachedRepositoryReleasesRepository::new(state.db.clone());
+    cache
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    let _api = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(403)
+        .create_async()
+        .await;
+    let _feed = gh
+        .mock("GET", "/owner/repo/releases.atom")
+        .with_status(200)
+        .with_header("content-type", "application/atom+xml")
+        .with_body(
+            r#"<feed xmlns="http://www.w3.org/2005/Atom">
+  <entry>
+    <id>tag:github.com,2008:Repository/1/v1.1.0</id>
+    <link rel="alternate" type="text/html" href="https://github.com/owner/repo/releases/tag/v1.1.0"/>
+  </entry>
+</feed>"#,
+        )
+        .create_async()
+        .await;
+    let m_tg = tg
+        .mock("POST", "/botTESTTOKEN/SendMessage")
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let source = HttpReleaseSource::new(client.clone(), None, gh.url()).with_web_base(gh.url());
+    let report = poll_once_with_source(
+        state.clone(),
+        &bot,
+        Arc::new(source),
+        &client,
+        None,
+        Some("http://127.0.0.1:9"),
+        false,
+    )
+    .await;
+
+    assert_eq!(report.rate_limited, 1);
+    assert_eq!(report.notified, ["repo v1.1.0 (chat 1)"]);
+    let cached = cache
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(cached.tag_name, "v1.1.0");
+    m_tg.assert_async().await;
+}
+
+/// Asks for an hour's pause on every request, counting them.
+#[derive(Default)]
+struct SecondaryRateLimitedSource {
+    requests: std::sync::atomic::AtomicUsize,
+}
+
+#[async_trait]
+impl ReleaseSource for SecondaryRateLimitedSource {
+    async fn latest_release(
+        &self,
+        _owner: &str,
+        _repo: &str,
+    ) -> Result<Option<Release>, GithubError> {
+        self.requests
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        Err(GithubError::SecondaryRateLimited {
+            retry_after: std::time::Duration::from_secs(3600),
+        })
+    }
+
+    async fn latest_tag(&self, _owner: &str, _repo: &str) -> Result<Option<String>, GithubError> {
+        Err(GithubError::Status { status: 429 })
+    }
+}
+
+#[tokio::test]
+async fn secondary_rate_limit_leaves_the_rest_of_the_cycle_to_the_next() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let tg = Server::new_async().await;
+    let bot = Bot::new("TESTTOKEN").set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    for name in ["one", "two", "three"] {
+        let url = format!("https://github.com/owner/{name}");
+        insert_tracked(&state, name, &url, 1).await;
+    }
+
+    let source = Arc::new(SecondaryRateLimitedSource::default());
+    let cycle = poll_once_with_source(
+        state.clone(),
+        &bot,
+        source.clone(),
+        &client,
+        None,
+        Some("http://127.0.0.1:9"),
+        false,
+    );
+    let report = tokio::time::timeout(std::time::Duration::from_secs(10), cycle)
+        .await
+        .expect("the cycle does not wait out the rate limit");
+
+    assert_eq!(report.rate_limited, 1);
+    assert_eq!(source.requests.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+    // The next cycle waits out the hour GitHub asked for, not just the poll interval
+    assert_eq!(report.next_delay(60), Duration::from_secs(3600));
+    assert_eq!(report.next_delay(7200), Duration::from_secs(7200));
+}
+
+#[test]
+fn a_cycle_without_retry_after_waits_the_interval() {
+    assert_eq!(
+        CycleReport::default().next_delay(60),
+        Duration::from_secs(60)
+    );
+}
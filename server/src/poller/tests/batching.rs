@@ -0,0 +1,43 @@
+use super::*;
+
+#[tokio::test]
+async fn batched_cache_writes_persist_every_updated_tag() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+
+    let mut gh = Server::new_async().await;
+    let tg = Server::new_async().await;
+    let bot = Bot::new("TESTTOKEN").set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    // Three repositories with a batch size of two spread the writes over two transactions
+    let mut tracked = Vec::new();
+    let mut mocks = Vec::new();
+    for name in ["alpha", "beta", "gamma"] {
+        tracked.push(
+            insert_tracked(&state, name, &format!("https://github.com/owner/{name}"), 1).await,
+        );
+        mocks.push(
+            gh.mock(
+                "GET",
+                format!("/repos/owner/{name}/releases/latest").as_str(),
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!({"tag_name": format!("{name}-v1")}).to_string())
+            .create_async()
+            .await,
+        );
+    }
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+
+    let cache_repo = SqliteCachedRepositoryReleasesRepository::new(state.db.clone());
+    for (t, name) in tracked.iter().zip(["alpha", "beta", "gamma"]) {
+        let cached = cache_repo
+            .find_by_tracked_release_id(&t.id)
+            .await
+            .unwrap()
+            .expect("cached row");
+        assert_eq!(cached.tag_name, format!("{name}-v1"));
+    }
+}
@@ -0,0 +1,111 @@
+use super::*;
+
+/// A cached `v1` release of `url`, tracked in `chat_id` through the bot of `state`.
+async fn tracked_for_bot(state: &Arc<AppState>, url: &str, chat_id: i64) -> TrackedRelease {
+    let tracked = insert_tracked(state, "repo", url, chat_id).await;
+    SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    tracked
+}
+
+/// A second bot, `222`, sharing the database of `primary`.
+fn staging_bot(primary: &Arc<AppState>) -> Arc<AppState> {
+    Arc::new(AppState {
+        db: primary.db.clone(),
+        bot_id: "222".to_string(),
+        cache_write_batch_size: 2,
+        release_history_limit: 0,
+        schedule: Arc::new(PollSchedule::new(60)),
+        pause_inaccessible: true,
+        cycle_lock: Default::default(),
+    })
+}
+
+#[tokio::test]
+async fn bots_only_notify_about_their_own_repositories() {
+    let primary = setup_state().await;
+    let staging = staging_bot(&primary);
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+
+    tracked_for_bot(&primary, "https://github.com/owner/prod", 5).await;
+    tracked_for_bot(&staging, "https://github.com/owner/staging", 6).await;
+    for repo in ["prod", "staging"] {
+        gh.mock(
+            "GET",
+            format!("/repos/owner/{repo}/releases/latest").as_str(),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v2"}).to_string())
+        .create_async()
+        .await;
+    }
+
+    let mut outcomes = Vec::new();
+    for (state, own, other) in [(&primary, "prod", "staging"), (&staging, "staging", "prod")] {
+        let mut tg = Server::new_async().await;
+        let token = "TESTTOKEN";
+        let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+        let mut mock_for = async |repo: &str, hits: usize| {
+            tg.mock(
+                "POST",
+                mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+            )
+            .match_body(mockito::Matcher::Regex(format!("owner/{repo}")))
+            .with_status(200)
+            .with_body("invalid-json")
+            .expect(hits)
+            .create_async()
+            .await
+        };
+        let m_own = mock_for(own, 1).await;
+        let m_other = mock_for(other, 0).await;
+
+        poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+        outcomes.push((m_own.matched_async().await, m_other.matched_async().await));
+    }
+
+    // Each bot announced its own release and never the other bot's
+    assert_eq!(outcomes, [(true, true), (true, true)]);
+}
+
+#[tokio::test]
+async fn untracking_through_one_bot_leaves_the_other_bots_row_alone() {
+    let primary = setup_state().await;
+    let staging = staging_bot(&primary);
+    let url = "https://github.com/owner/repo";
+    let kept = tracked_for_bot(&primary, url, 5).await;
+    tracked_for_bot(&staging, url, 5).await;
+
+    let repository = SqliteTrackedRepositoriesRepository::new(primary.db.clone());
+    let untracked = repository
+        .find_by_chat_id_and_repository_url(&staging.bot_id, 5, url)
+        .await
+        .unwrap()
+        .expect("the staging bot tracks the repository");
+    assert_ne!(untracked.id, kept.id);
+    repository.delete_all(&[untracked.id]).await.unwrap();
+
+    let remaining = repository
+        .find_all_by_chat_id(&primary.bot_id, 5)
+        .await
+        .unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].id, kept.id);
+    assert!(
+        repository
+            .find_all_by_chat_id(&staging.bot_id, 5)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+}
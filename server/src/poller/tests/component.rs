@@ -0,0 +1,80 @@
+use super::*;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+#[tokio::test]
+async fn follows_the_newest_tag_of_one_component() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "monorepo", "https://github.com/owner/monorepo", 5).await;
+    SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "pkg-a/v1.9.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    let settings_repo = SqliteRepositorySettingsRepository::new(state.db.clone());
+    let mut settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    settings.tags_only = true;
+    settings.tag_prefix = Some("pkg-a/".to_string());
+    settings_repo.save(&settings).await.unwrap();
+
+    // The component's newest tag is only on the second page, behind other components
+    let page_2 = format!("{}/repos/owner/monorepo/tags?per_page=100&page=2", gh.url());
+    let _m_page_1 = gh
+        .mock("GET", "/repos/owner/monorepo/tags")
+        .match_query(mockito::Matcher::Exact("per_page=100".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("link", &format!("<{page_2}>; rel=\"next\""))
+        .with_body(
+            serde_json::json!([{ "name": "pkg-b/v0.3.0" }, { "name": "pkg-a/v1.9.0" }]).to_string(),
+        )
+        .create_async()
+        .await;
+    let _m_page_2 = gh
+        .mock("GET", "/repos/owner/monorepo/tags")
+        .match_query(mockito::Matcher::Exact("per_page=100&page=2".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([{ "name": "pkg-a/v1.10.0" }, { "name": "pkg-b/v0.2.0" }])
+                .to_string(),
+        )
+        .create_async()
+        .await;
+    let m_tg = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::Regex("<b>v1.10.0</b>".to_string()),
+            mockito::Matcher::Regex("owner/monorepo/tree/pkg-a%2Fv1.10.0".to_string()),
+        ]))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_tg.assert();
+
+    let cached = SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(cached.tag_name, "pkg-a/v1.10.0");
+}
@@ -0,0 +1,96 @@
+use super::*;
+use crate::chat_settings::ChatSettings;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::tracked_repositories::settings::RepositorySettings;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+const TOKEN: &str = "TESTTOKEN";
+
+/// Tracks a critical repository whose cached release is `v1.0.0`, with `suppress` applied
+/// to its settings, and returns the mock announcing `v1.1.0` to the chat.
+async fn poll_critical_release(
+    state: &Arc<AppState>,
+    tg: &mut mockito::ServerGuard,
+    suppress: impl FnOnce(&mut RepositorySettings),
+) -> mockito::Mock {
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let bot = Bot::new(TOKEN).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(state, "repo", "https://github.com/owner/repo", 42).await;
+    SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    let settings_repo = SqliteRepositorySettingsRepository::new(state.db.clone());
+    let mut settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    settings.critical = true;
+    suppress(&mut settings);
+    settings_repo.save(&settings).await.unwrap();
+
+    let _m_gh = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v1.1.0"}).to_string())
+        .create_async()
+        .await;
+    let m_notify = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{TOKEN}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex("v1.1.0".to_string()))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_notify
+}
+
+#[tokio::test]
+async fn critical_repository_notifies_while_muted() {
+    let state = setup_state().await;
+    let mut tg = Server::new_async().await;
+
+    let m_notify = poll_critical_release(&state, &mut tg, |s| s.muted = true).await;
+
+    m_notify.assert_async().await;
+}
+
+#[tokio::test]
+async fn critical_repository_notifies_while_snoozed() {
+    let state = setup_state().await;
+    let until = Utc::now() + chrono::Duration::hours(1);
+    let mut chat_settings = ChatSettings::default_for(42);
+    chat_settings.snoozed_until = Some(until);
+    SqliteChatSettingsRepository::new(state.db.clone())
+        .save(&chat_settings)
+        .await
+        .unwrap();
+
+    let mut tg = Server::new_async().await;
+    let m_notify = poll_critical_release(&state, &mut tg, |s| {
+        s.snoozed_until = Some(until);
+        s.min_release_age_secs = Some(3600);
+    })
+    .await;
+
+    m_notify.assert_async().await;
+    let chat_settings = SqliteChatSettingsRepository::new(state.db.clone())
+        .find_or_default(42)
+        .await
+        .unwrap();
+    assert!(chat_settings.snooze_missed.is_empty());
+}
@@ -0,0 +1,174 @@
+use super::*;
+use crate::github::{GithubError, Release, ReleaseDetails, ReleaseSource};
+use crate::poller::cursor::load_cursor;
+use crate::tracked_repositories::tracked_repositories_releases::FetchStatus;
+
+/// Knows `v1.0.0` of every repository but `one`, whose fetch never finishes.
+struct HangsOnOne {
+    reached: Arc<tokio::sync::Notify>,
+}
+
+#[async_trait::async_trait]
+impl ReleaseSource for HangsOnOne {
+    async fn latest_release(
+        &self,
+        _owner: &str,
+        repo: &str,
+    ) -> Result<Option<Release>, GithubError> {
+        if repo == "one" {
+            self.reached.notify_one();
+            std::future::pending::<()>().await;
+        }
+        Ok(Some(Release {
+            tag_name: "v1.0.0".to_string(),
+            body: None,
+            details: ReleaseDetails::default(),
+        }))
+    }
+
+    async fn latest_tag(&self, _owner: &str, _repo: &str) -> Result<Option<String>, GithubError> {
+        Ok(None)
+    }
+}
+
+#[tokio::test]
+async fn restart_mid_cycle_resumes_after_the_last_processed_repository() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let tg = Server::new_async().await;
+    let bot = Bot::new("TESTTOKEN").set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    // Polled newest first: four, three, two, one
+    let one = insert_tracked(&state, "one", "https://github.com/owner/one", 1).await;
+    let two = insert_tracked(&state, "two", "https://github.com/owner/two", 1).await;
+    let three = insert_tracked(&state, "three", "https://github.com/owner/three", 1).await;
+    let four = insert_tracked(&state, "four", "https://github.com/owner/four", 1).await;
+
+    // The process dies while fetching `one`, after `four`, `three` and `two` were checked
+    let reached = Arc::new(tokio::sync::Notify::new());
+    let cycle = tokio::spawn({
+        let (state, bot, client) = (state.clone(), bot.clone(), client.clone());
+        let source = Arc::new(HangsOnOne {
+            reached: reached.clone(),
+        });
+        async move {
+            poll_once_with_source(state, &bot, source, &client, None, None, false).await;
+        }
+    });
+    reached.notified().await;
+    cycle.abort();
+    assert!(cycle.await.unwrap_err().is_cancelled());
+
+    // With a batch size of two only `four` and `three` were committed, with their cursor
+    assert_eq!(
+        load_cursor(&state.db, &state.bot_id).await.unwrap(),
+        Some(three.id)
+    );
+    let cache = SqliteCachedRepositoryReleasesRepository::new(state.db.clone());
+    for tracked in [&four, &three] {
+        assert!(
+            cache
+                .find_by_tracked_release_id(&tracked.id)
+                .await
+                .unwrap()
+                .is_some(),
+            "{}",
+            tracked.repository_name
+        );
+    }
+
+    let _m = gh
+        .mock(
+            "GET",
+            mockito::Matcher::Regex("^/repos/owner/[a-z]+/releases/latest$".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"tag_name": "v1.0.0"}"#)
+        .create_async()
+        .await;
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+
+    for tracked in [&one, &two, &three, &four] {
+        assert_eq!(
+            cache.find_fetch_status(&tracked.id).await.unwrap(),
+            Some(FetchStatus::Ok),
+            "{}",
+            tracked.repository_name
+        );
+    }
+    let polled_at = |id| state.schedule.last_polled_at(id).unwrap();
+    assert!(polled_at(&two.id) < polled_at(&one.id));
+    assert!(polled_at(&one.id) < polled_at(&four.id));
+    assert!(polled_at(&four.id) < polled_at(&three.id));
+
+    // A finished cycle starts the next one from the top again
+    assert_eq!(load_cursor(&state.db, &state.bot_id).await.unwrap(), None);
+}
+
+/// Knows `v1.0.0` of every repository but `one`, for which it asks to back off.
+struct BackOffOnOne;
+
+#[async_trait::async_trait]
+impl ReleaseSource for BackOffOnOne {
+    async fn latest_release(
+        &self,
+        _owner: &str,
+        repo: &str,
+    ) -> Result<Option<Release>, GithubError> {
+        if repo == "one" {
+            return Err(GithubError::SecondaryRateLimited {
+                retry_after: std::time::Duration::from_secs(60),
+            });
+        }
+        Ok(Some(Release {
+            tag_name: "v1.0.0".to_string(),
+            body: None,
+            details: ReleaseDetails::default(),
+        }))
+    }
+
+    async fn latest_tag(&self, _owner: &str, _repo: &str) -> Result<Option<String>, GithubError> {
+        Ok(None)
+    }
+}
+
+#[tokio::test]
+async fn a_cycle_cut_short_stores_its_cursor_with_the_cache() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let tg = Server::new_async().await;
+    let bot = Bot::new("TESTTOKEN").set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    // Polled newest first: three, two, one
+    insert_tracked(&state, "one", "https://github.com/owner/one", 1).await;
+    let two = insert_tracked(&state, "two", "https://github.com/owner/two", 1).await;
+    let three = insert_tracked(&state, "three", "https://github.com/owner/three", 1).await;
+
+    poll_once_with_source(
+        state.clone(),
+        &bot,
+        Arc::new(BackOffOnOne),
+        &client,
+        None,
+        Some("http://127.0.0.1:9"),
+        false,
+    )
+    .await;
+
+    let cache = SqliteCachedRepositoryReleasesRepository::new(state.db.clone());
+    for tracked in [&three, &two] {
+        assert!(
+            cache
+                .find_by_tracked_release_id(&tracked.id)
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+    assert_eq!(
+        load_cursor(&state.db, &state.bot_id).await.unwrap(),
+        Some(two.id)
+    );
+}
@@ -0,0 +1,83 @@
+use super::*;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+async fn mock_discussion(gh: &mut Server, number: i64) -> mockito::Mock {
+    gh.mock("POST", "/graphql")
+        .match_body(mockito::Matcher::Regex("categoryId".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({"data": {"repository": {"discussions": {"nodes": [{
+                "number": number,
+                "title": format!("Announcement {number}"),
+                "url": format!("https://github.com/owner/repo/discussions/{number}")
+            }]}}}})
+            .to_string(),
+        )
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn announces_new_discussions_after_the_first() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 9).await;
+    let settings_repo = SqliteRepositorySettingsRepository::new(state.db.clone());
+    let mut settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    settings.discussion_category = Some("Announcements".to_string());
+    settings_repo.save(&settings).await.unwrap();
+
+    let m_releases = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .expect(0)
+        .create_async()
+        .await;
+    let _m_categories = gh
+        .mock("POST", "/graphql")
+        .match_body(mockito::Matcher::Regex("discussionCategories".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({"data": {"repository": {"discussionCategories": {"nodes": [
+                {"id": "C1", "name": "Announcements", "slug": "announcements"}
+            ]}}}})
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let m_first = mock_discussion(&mut gh, 1).await;
+
+    let m_tg = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex("discussions/2".to_string()))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    // First sighting is only remembered
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_first.remove_async().await;
+
+    let _m_second = mock_discussion(&mut gh, 2).await;
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+
+    m_tg.assert();
+    m_releases.assert();
+    let settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    assert_eq!(settings.last_discussion_number, Some(2));
+}
@@ -0,0 +1,66 @@
+use super::*;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+#[tokio::test]
+async fn dry_run_reports_without_storing_or_sending() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "owner/repo", "https://github.com/owner/repo", 7).await;
+    let cache_repo = SqliteCachedRepositoryReleasesRepository::new(state.db.clone());
+    cache_repo
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    // A throttled repository stores when it was last announced
+    let settings_repo = SqliteRepositorySettingsRepository::new(state.db.clone());
+    let mut settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    settings.throttle_window_secs = Some(3600);
+    settings_repo.save(&settings).await.unwrap();
+
+    let _m_gh = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v2.0.0"}).to_string())
+        .create_async()
+        .await;
+    let m_tg = tg
+        .mock("POST", mockito::Matcher::Any)
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let report = poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), true).await;
+
+    assert_eq!(report.notified, ["owner/repo v2.0.0 (chat 7)"]);
+    m_tg.assert_async().await;
+    let cached = cache_repo
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .expect("cached row");
+    assert_eq!(cached.tag_name, "v1.0.0");
+    let settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    assert_eq!(settings.last_notified_at, None);
+    let receipts: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM notifications")
+        .fetch_one(&state.db)
+        .await
+        .unwrap();
+    assert_eq!(receipts, 0);
+    assert_eq!(state.schedule.last_polled_at(&tracked.id), None);
+}
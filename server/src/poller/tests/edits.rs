@@ -0,0 +1,144 @@
+use super::*;
+use crate::chat_settings::ReleaseNotes;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::notifications::NotificationReceipt;
+use crate::notifications::repository::{
+    NotificationReceiptsRepository, SqliteNotificationReceiptsRepository,
+};
+
+/// Tracks a repository whose v1.0.0 notification went out as message 77 with old notes.
+async fn setup_delivered_release(state: &Arc<AppState>) -> TrackedRelease {
+    let tracked = insert_tracked(state, "repo", "https://github.com/owner/repo", 12).await;
+    SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: Some(crate::poller::release_notes::body_hash("Old notes")),
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    SqliteNotificationReceiptsRepository::new(state.db.clone())
+        .save(&NotificationReceipt {
+            id: Uuid::now_v7(),
+            tracked_repository_id: tracked.id,
+            chat_id: 12,
+            tag_name: "v1.0.0".to_string(),
+            telegram_message_id: 77,
+            sent_at: Utc::now(),
+        })
+        .await
+        .unwrap();
+    let chat_settings_repo = SqliteChatSettingsRepository::new(state.db.clone());
+    let mut chat_settings = chat_settings_repo.find_or_default(12).await.unwrap();
+    chat_settings.release_notes = ReleaseNotes::On;
+    chat_settings_repo.save(&chat_settings).await.unwrap();
+    tracked
+}
+
+async fn mock_edited_release(gh: &mut mockito::ServerGuard) -> mockito::Mock {
+    gh.mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v1.0.0", "body": "New notes"}).to_string())
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn edited_release_updates_previous_message() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    setup_delivered_release(&state).await;
+    let _m_gh = mock_edited_release(&mut gh).await;
+
+    let m_edit = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/EditMessageText")),
+        )
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::Regex("\"message_id\":77".to_string()),
+            mockito::Matcher::Regex("New notes".to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "ok": true,
+                "result": {
+                    "message_id": 77,
+                    "date": 1700000000,
+                    "chat": {"id": 12, "type": "private", "first_name": "Test"},
+                    "text": "New release"
+                }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let m_send = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .expect(0)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_edit.assert();
+    m_send.assert();
+}
+
+#[tokio::test]
+async fn deleted_message_falls_back_to_new_notification() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    setup_delivered_release(&state).await;
+    let _m_gh = mock_edited_release(&mut gh).await;
+
+    let _m_edit = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/EditMessageText")),
+        )
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "ok": false,
+                "error_code": 400,
+                "description": "Bad Request: message to edit not found"
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let m_send = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex("New notes".to_string()))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_send.assert();
+}
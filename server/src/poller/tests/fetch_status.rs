@@ -0,0 +1,79 @@
+use super::*;
+use crate::tracked_repositories::tracked_repositories_releases::FetchStatus;
+
+#[tokio::test]
+async fn records_the_outcome_of_each_fetch() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+
+    let mut gh = Server::new_async().await;
+    let tg = Server::new_async().await;
+    let bot = Bot::new("TESTTOKEN").set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let empty = insert_tracked(&state, "empty", "https://github.com/owner/empty", 1).await;
+    let broken = insert_tracked(&state, "broken", "https://github.com/owner/broken", 1).await;
+
+    let _m_empty = gh
+        .mock("GET", "/repos/owner/empty/releases/latest")
+        .with_status(404)
+        .create_async()
+        .await;
+    let _m_empty_tags = gh
+        .mock(
+            "GET",
+            mockito::Matcher::Regex("^/repos/owner/empty/tags".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("[]")
+        .create_async()
+        .await;
+    let _m_broken = gh
+        .mock("GET", "/repos/owner/broken/releases/latest")
+        .with_status(500)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+
+    let cache = SqliteCachedRepositoryReleasesRepository::new(state.db.clone());
+    assert_eq!(
+        cache.find_fetch_status(&empty.id).await.unwrap(),
+        Some(FetchStatus::NoReleases)
+    );
+    assert_eq!(
+        cache.find_fetch_status(&broken.id).await.unwrap(),
+        Some(FetchStatus::Failed)
+    );
+}
+
+#[tokio::test]
+async fn stops_the_cycle_when_github_is_unreachable() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+
+    let tg = Server::new_async().await;
+    let bot = Bot::new("TESTTOKEN").set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let mut tracked = Vec::new();
+    for name in ["one", "two", "three"] {
+        let url = format!("https://github.com/owner/{name}");
+        tracked.push(insert_tracked(&state, name, &url, 1).await);
+    }
+
+    // Nothing listens on a port the OS handed out and took back
+    let closed = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", closed.local_addr().unwrap());
+    drop(closed);
+
+    poll_once(state.clone(), &bot, &client, None, Some(&base), false).await;
+
+    // Only the first repository was tried before the cycle gave up
+    let cache = SqliteCachedRepositoryReleasesRepository::new(state.db.clone());
+    let mut statuses = Vec::new();
+    for r in &tracked {
+        statuses.push(cache.find_fetch_status(&r.id).await.unwrap());
+    }
+    statuses.sort_by_key(Option::is_none);
+    assert_eq!(statuses, [Some(FetchStatus::Failed), None, None]);
+}
@@ -0,0 +1,61 @@
+use super::*;
+use crate::chat_settings::ChatSettings;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+
+const TOKEN: &str = "TESTTOKEN";
+
+/// Polls a repository the bot has never seen, in a chat with `notify_on_first_seen` set
+/// as given, and checks how many notifications were sent.
+async fn poll_first_sight(notify_on_first_seen: bool, expected_notifications: usize) {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let bot = Bot::new(TOKEN).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 42).await;
+    let mut chat_settings = ChatSettings::default_for(42);
+    chat_settings.notify_on_first_seen = notify_on_first_seen;
+    SqliteChatSettingsRepository::new(state.db.clone())
+        .save(&chat_settings)
+        .await
+        .unwrap();
+
+    let _m_gh = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v1.0.0"}).to_string())
+        .create_async()
+        .await;
+    let m_notify = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{TOKEN}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex("v1.0.0".to_string()))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(expected_notifications)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+
+    m_notify.assert_async().await;
+    let cached = SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .expect("cached row");
+    assert_eq!(cached.tag_name, "v1.0.0");
+}
+
+#[tokio::test]
+async fn first_sight_is_silent_by_default() {
+    poll_first_sight(false, 0).await;
+}
+
+#[tokio::test]
+async fn first_sight_notifies_when_the_chat_asks_for_it() {
+    poll_first_sight(true, 1).await;
+}
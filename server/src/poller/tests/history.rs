@@ -0,0 +1,86 @@
+use super::*;
+use crate::tracked_repositories::release_history::repository::{
+    ReleaseHistoryRepository, SqliteReleaseHistoryRepository,
+};
+
+#[tokio::test]
+async fn records_new_releases_and_prunes_to_the_limit() {
+    let db = setup_state().await.db.clone();
+    let state = Arc::new(AppState {
+        db: db.clone(),
+        bot_id: String::new(),
+        cache_write_batch_size: 2,
+        release_history_limit: 2,
+        schedule: Arc::new(PollSchedule::new(60)),
+        pause_inaccessible: true,
+        cycle_lock: Default::default(),
+    });
+    let client = reqwest::Client::new();
+
+    let mut gh = Server::new_async().await;
+    let tg = Server::new_async().await;
+    let bot = Bot::new("TESTTOKEN").set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 7).await;
+
+    for tag in ["v1", "v1", "v2", "v3"] {
+        let _m_gh = gh
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "tag_name": tag,
+                    "body": format!("Notes for {tag}"),
+                    "html_url": format!("https://github.com/owner/repo/releases/tag/{tag}"),
+                    "published_at": "2024-05-01T12:00:00Z",
+                    "author": {"login": "octocat"}
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    }
+
+    let entries = SqliteReleaseHistoryRepository::new(db)
+        .find_recent(&tracked.id, 10)
+        .await
+        .unwrap();
+    let tags: Vec<_> = entries.iter().map(|e| e.tag_name.as_str()).collect();
+    assert_eq!(tags, ["v3", "v2"]);
+    assert_eq!(entries[0].author.as_deref(), Some("octocat"));
+    assert_eq!(entries[0].body.as_deref(), Some("Notes for v3"));
+    assert_eq!(
+        entries[0].html_url.as_deref(),
+        Some("https://github.com/owner/repo/releases/tag/v3")
+    );
+    assert!(entries[0].published_at.is_some());
+}
+
+#[tokio::test]
+async fn records_nothing_when_disabled() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+
+    let mut gh = Server::new_async().await;
+    let tg = Server::new_async().await;
+    let bot = Bot::new("TESTTOKEN").set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 8).await;
+    let _m_gh = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v1"}).to_string())
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+
+    let entries = SqliteReleaseHistoryRepository::new(state.db.clone())
+        .find_recent(&tracked.id, 10)
+        .await
+        .unwrap();
+    assert!(entries.is_empty());
+}
@@ -0,0 +1,229 @@
+use super::*;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+async fn insert_with_cached_release(state: &Arc<AppState>) -> TrackedRelease {
+    let tracked = insert_tracked(state, "repo", "https://github.com/owner/repo", 42).await;
+    SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    tracked
+}
+
+#[tokio::test]
+async fn repository_answering_404_is_paused_until_it_returns() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    let tracked = insert_with_cached_release(&state).await;
+    let settings_repo = SqliteRepositorySettingsRepository::new(state.db.clone());
+
+    // The repository went private: its releases, tags and the repository itself are 404
+    let releases = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(404)
+        .expect(1)
+        .create_async()
+        .await;
+    let tags = gh
+        .mock("GET", "/repos/owner/repo/tags")
+        .match_query(mockito::Matcher::Any)
+        .with_status(404)
+        .create_async()
+        .await;
+    let missing = gh
+        .mock("GET", "/repos/owner/repo")
+        .with_status(404)
+        .expect(2)
+        .create_async()
+        .await;
+    let m_gone = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex("no longer accessible".to_string()))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    let settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    assert!(settings.inaccessible_since.is_some());
+
+    // While paused only the repository endpoint is asked, and the chat hears nothing more
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    releases.assert_async().await;
+    missing.assert_async().await;
+    m_gone.assert_async().await;
+    for mock in [releases, tags, missing, m_gone] {
+        mock.remove_async().await;
+    }
+
+    let _found = gh
+        .mock("GET", "/repos/owner/repo")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({"full_name": "owner/repo", "default_branch": "main"}).to_string(),
+        )
+        .create_async()
+        .await;
+    let _release = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v1.1.0"}).to_string())
+        .create_async()
+        .await;
+    let m_back = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::Regex("accessible again".to_string()),
+            mockito::Matcher::Regex("v1.1.0".to_string()),
+        ]))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_back.assert_async().await;
+    let settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    assert_eq!(settings.inaccessible_since, None);
+}
+
+#[tokio::test]
+async fn inaccessible_repository_keeps_being_polled_when_pausing_is_off() {
+    let state = setup_state().await;
+    let state = Arc::new(AppState {
+        db: state.db.clone(),
+        bot_id: String::new(),
+        cache_write_batch_size: 2,
+        release_history_limit: 0,
+        schedule: Arc::new(PollSchedule::new(60)),
+        pause_inaccessible: false,
+        cycle_lock: Default::default(),
+    });
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    insert_with_cached_release(&state).await;
+
+    let releases = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(404)
+        .expect(2)
+        .create_async()
+        .await;
+    let _tags = gh
+        .mock("GET", "/repos/owner/repo/tags")
+        .match_query(mockito::Matcher::Any)
+        .with_status(404)
+        .create_async()
+        .await;
+    let missing = gh
+        .mock("GET", "/repos/owner/repo")
+        .with_status(404)
+        .expect(1)
+        .create_async()
+        .await;
+    let m_gone = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex(
+            "You will be told if it becomes reachable again".to_string(),
+        ))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    releases.assert_async().await;
+    missing.assert_async().await;
+    m_gone.assert_async().await;
+}
+
+#[tokio::test]
+async fn forbidden_or_release_less_repositories_are_not_reported() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    let tracked = insert_with_cached_release(&state).await;
+    // A repository that never had a release is not looked up at all
+    let fresh = insert_tracked(&state, "fresh", "https://github.com/owner/fresh", 42).await;
+
+    for repo in ["repo", "fresh"] {
+        gh.mock(
+            "GET",
+            format!("/repos/owner/{repo}/releases/latest").as_str(),
+        )
+        .with_status(404)
+        .create_async()
+        .await;
+        gh.mock("GET", format!("/repos/owner/{repo}/tags").as_str())
+            .match_query(mockito::Matcher::Any)
+            .with_status(404)
+            .create_async()
+            .await;
+    }
+    // A 403 may be a rate limit, which says nothing about the repository
+    let forbidden = gh
+        .mock("GET", "/repos/owner/repo")
+        .with_status(403)
+        .expect(1)
+        .create_async()
+        .await;
+    let fresh_lookup = gh
+        .mock("GET", "/repos/owner/fresh")
+        .expect(0)
+        .create_async()
+        .await;
+    let m_silent = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(0)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    forbidden.assert_async().await;
+    fresh_lookup.assert_async().await;
+    m_silent.assert_async().await;
+    let settings_repo = SqliteRepositorySettingsRepository::new(state.db.clone());
+    for id in [tracked.id, fresh.id] {
+        let settings = settings_repo.find_or_default(&id).await.unwrap();
+        assert_eq!(settings.inaccessible_since, None);
+    }
+}
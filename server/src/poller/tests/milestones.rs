@@ -0,0 +1,66 @@
+use super::*;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+async fn mock_commit_count(gh: &mut Server, count: u64) -> mockito::Mock {
+    let link = format!(
+        "<{}/repositories/1/commits?per_page=1&page={count}>; rel=\"last\"",
+        gh.url()
+    );
+    gh.mock("GET", "/repos/owner/repo/commits?per_page=1")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("link", &link)
+        .with_body(r#"[{"sha": "abc"}]"#)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn announces_crossed_commit_milestones_after_the_baseline() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 5).await;
+    let settings_repo = SqliteRepositorySettingsRepository::new(state.db.clone());
+    let mut settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    settings.commit_milestone = Some(100);
+    settings_repo.save(&settings).await.unwrap();
+
+    let m_releases = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .expect(0)
+        .create_async()
+        .await;
+    let m_tg = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex("1300 commits".to_string()))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let m_count = mock_commit_count(&mut gh, 1234).await;
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_count.remove_async().await;
+    let m_count = mock_commit_count(&mut gh, 1299).await;
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_count.remove_async().await;
+    mock_commit_count(&mut gh, 1302).await;
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+
+    m_tg.assert();
+    m_releases.assert();
+    let settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    assert_eq!(settings.last_notified_count, Some(1300));
+}
@@ -0,0 +1,92 @@
+use super::*;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+#[tokio::test]
+async fn too_young_release_is_announced_once_it_has_aged() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 5).await;
+    let cache_repo = SqliteCachedRepositoryReleasesRepository::new(state.db.clone());
+    cache_repo
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    let settings_repo = SqliteRepositorySettingsRepository::new(state.db.clone());
+    let mut settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    settings.min_release_age_secs = Some(600);
+    settings_repo.save(&settings).await.unwrap();
+
+    // No published_at, so the age counts from when the poller first saw the release
+    let _m_gh = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v1.1.0"}).to_string())
+        .create_async()
+        .await;
+    let m_tg = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(0)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_tg.assert();
+    let cached = cache_repo
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(cached.tag_name, "v1.0.0");
+
+    // Ten minutes pass
+    let mut settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    assert_eq!(settings.young_release_tag.as_deref(), Some("v1.1.0"));
+    settings.young_release_seen_at = Some(Utc::now() - chrono::Duration::minutes(11));
+    settings_repo.save(&settings).await.unwrap();
+
+    m_tg.remove_async().await;
+    let m_tg = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex(
+            "owner/repo/releases/tag/v1.1.0".to_string(),
+        ))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_tg.assert();
+    let settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    assert_eq!(settings.young_release_tag, None);
+    let cached = cache_repo
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(cached.tag_name, "v1.1.0");
+}
@@ -0,0 +1,436 @@
+mod backoff;
+mod batching;
+mod bots;
+mod component;
+mod critical;
+mod cursor;
+mod discussions;
+mod dry_run;
+mod edits;
+mod fetch_status;
+mod first_seen;
+mod history;
+mod inaccessible;
+mod milestones;
+mod min_age;
+mod pins;
+#[cfg(feature = "rest-api")]
+mod pushed;
+mod receipts;
+mod release_notes;
+mod release_source;
+mod removed;
+mod snooze;
+mod tag_capture;
+mod tag_ignore;
+mod tags_only;
+mod throttle;
+mod timeout;
+mod versions;
+#[cfg(feature = "webhooks")]
+mod webhooks;
+
+use super::*;
+use crate::tracked_repositories::tracked_repositories_releases::CachedRepositoryRelease;
+use crate::tracked_repositories::tracked_repositories_releases::repository::{
+    CachedRepositoryReleasesRepository, SqliteCachedRepositoryReleasesRepository,
+};
+use crate::tracked_repositories::{RepositoryUrl, TrackedRelease};
+use chrono::Utc;
+use mockito::Server;
+use sqlx::sqlite::SqlitePoolOptions;
+use uuid::Uuid;
+
+async fn setup_state() -> Arc<AppState> {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("failed to create in-memory sqlite pool");
+
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("failed to run migrations");
+
+    Arc::new(AppState {
+        db: pool,
+        bot_id: String::new(),
+        cache_write_batch_size: 2,
+        release_history_limit: 0,
+        schedule: Arc::new(PollSchedule::new(60)),
+        pause_inaccessible: true,
+        cycle_lock: Default::default(),
+    })
+}
+
+async fn insert_tracked(
+    state: &Arc<AppState>,
+    name: &str,
+    url: &str,
+    chat_id: i64,
+) -> TrackedRelease {
+    let repo = SqliteTrackedRepositoriesRepository::new(state.db.clone());
+    let mut tr = TrackedRelease {
+        id: Uuid::new_v4(),
+        repository_name: name.to_string(),
+        repository_url: RepositoryUrl::new(url.to_string()).unwrap(),
+        chat_id,
+        bot_id: state.bot_id.clone(),
+        default_branch: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    };
+    repo.save(&mut tr).await.unwrap();
+    tr
+}
+
+#[tokio::test]
+async fn poller_behaviour_caches_and_notifies_as_expected() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+
+    // Dedicated mock servers
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+
+    // Configure bot to hit mock Telegram
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    // Track repository
+    let tracked = insert_tracked(&state, "owner/repo", "https://github.com/owner/repo", 123).await;
+
+    // 1) First time seeing tag -> cache saved, no notify
+    let _m_gh1 = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v1.0.0"}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let _m_tg0 = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(0)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+
+    let cache_repo = SqliteCachedRepositoryReleasesRepository::new(state.db.clone());
+    let cached = cache_repo
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .expect("cached row");
+    assert_eq!(cached.tag_name, "v1.0.0");
+
+    // 2) Same tag again -> no notify, cache unchanged
+    let _m_gh2 = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v1.0.0"}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let _m_tg1 = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let first_seen_at_before = cached.first_seen_at;
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    let cached_again = cache_repo
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(cached_again.tag_name, "v1.0.0");
+    assert_eq!(cached_again.first_seen_at, first_seen_at_before);
+
+    // 3) New tag -> notify once and cache updates
+    let _m_gh3 = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v1.1.0"}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let m_tg2 = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .with_status(200)
+        // We can return invalid JSON; the poller ignores send errors
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_tg2.assert();
+
+    let cached_new = cache_repo
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(cached_new.tag_name, "v1.1.0");
+    assert!(cached_new.first_seen_at > first_seen_at_before);
+}
+
+#[tokio::test]
+async fn simultaneous_releases_for_one_chat_are_grouped() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let cache_repo = SqliteCachedRepositoryReleasesRepository::new(state.db.clone());
+    let mut mocks = Vec::new();
+    for name in ["alpha", "beta", "gamma"] {
+        let tracked = insert_tracked(
+            &state,
+            name,
+            &format!("https://github.com/owner/{name}"),
+            555,
+        )
+        .await;
+        cache_repo
+            .save(&CachedRepositoryRelease {
+                tracked_repository_id: tracked.id,
+                tag_name: "v1.0.0".to_string(),
+                first_seen_at: Utc::now(),
+                body_hash: None,
+                from_release: true,
+            })
+            .await
+            .unwrap();
+
+        mocks.push(
+            gh.mock(
+                "GET",
+                format!("/repos/owner/{name}/releases/latest").as_str(),
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!({"tag_name": "v2.0.0"}).to_string())
+            .create_async()
+            .await,
+        );
+    }
+
+    let m_tg = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::Regex("owner/alpha/releases/tag/v2.0.0".to_string()),
+            mockito::Matcher::Regex("owner/beta/releases/tag/v2.0.0".to_string()),
+            mockito::Matcher::Regex("owner/gamma/releases/tag/v2.0.0".to_string()),
+        ]))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_tg.assert();
+}
+
+/// A tag watch for `v2.0.0` of owner/repo in chat 77, with GitHub reporting the tag out.
+async fn fired_tag_watch(
+    state: &Arc<AppState>,
+    gh: &mut mockito::ServerGuard,
+) -> crate::tag_watches::repository::SqliteTagWatchesRepository {
+    use crate::tag_watches::TagWatch;
+    use crate::tag_watches::repository::{SqliteTagWatchesRepository, TagWatchesRepository};
+
+    let watches_repo = SqliteTagWatchesRepository::new(state.db.clone());
+    watches_repo
+        .save(&TagWatch {
+            id: Uuid::now_v7(),
+            chat_id: 77,
+            bot_id: String::new(),
+            repository_url: RepositoryUrl::new("https://github.com/owner/repo".to_string())
+                .unwrap(),
+            tag_name: "v2.0.0".to_string(),
+            created_at: Utc::now(),
+        })
+        .await
+        .unwrap();
+    gh.mock("GET", "/repos/owner/repo/releases/tags/v2.0.0")
+        .with_status(200)
+        .with_body("{}")
+        .create_async()
+        .await;
+    watches_repo
+}
+
+#[tokio::test]
+async fn fired_tag_watch_notifies_and_is_removed() {
+    use crate::tag_watches::repository::TagWatchesRepository;
+
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    let watches_repo = fired_tag_watch(&state, &mut gh).await;
+
+    let m_tg = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex("v2.0.0".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "ok": true,
+                "result": {
+                    "message_id": 7,
+                    "date": 1700000000,
+                    "chat": {"id": 77, "type": "private", "first_name": "Test"},
+                    "text": "The tag you were watching is out"
+                }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let report = poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_tg.assert();
+
+    assert_eq!(
+        report.notified,
+        ["https://github.com/owner/repo watched tag v2.0.0 (chat 77)"]
+    );
+    assert!(watches_repo.find_all().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn tag_watch_is_kept_until_its_notification_is_delivered() {
+    use crate::tag_watches::repository::TagWatchesRepository;
+
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    let watches_repo = fired_tag_watch(&state, &mut gh).await;
+
+    let m_tg = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"ok": false, "error_code": 400, "description": "Bad Request: chat not found"}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_tg.assert();
+
+    assert_eq!(watches_repo.find_all().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn mirror_is_used_when_primary_fails() {
+    use crate::tracked_repositories::mirrors::repository::{
+        RepositoryMirrorsRepository, SqliteRepositoryMirrorsRepository,
+    };
+
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "Primary", "https://github.com/owner/primary", 9).await;
+    SqliteRepositoryMirrorsRepository::new(state.db.clone())
+        .append(
+            &tracked.id,
+            &RepositoryUrl::new("https://github.com/mirror/copy".to_string()).unwrap(),
+        )
+        .await
+        .unwrap();
+    SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+
+    let _m_primary = gh
+        .mock("GET", "/repos/owner/primary/releases/latest")
+        .with_status(500)
+        .create_async()
+        .await;
+    let _m_mirror = gh
+        .mock("GET", "/repos/mirror/copy/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v1.1.0"}).to_string())
+        .create_async()
+        .await;
+
+    let m_tg = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::Regex("Primary".to_string()),
+            mockito::Matcher::Regex("mirror/copy/releases/tag/v1.1.0".to_string()),
+        ]))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_tg.assert();
+}
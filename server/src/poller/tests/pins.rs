@@ -0,0 +1,168 @@
+use super::*;
+use crate::chat_settings::ChatSettings;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::notifications::NotificationReceipt;
+use crate::notifications::repository::{
+    NotificationReceiptsRepository, SqliteNotificationReceiptsRepository,
+};
+
+const TOKEN: &str = "TESTTOKEN";
+
+/// A chat that pins its releases, whose notification of `v1.0.0` in message 100 is pinned.
+async fn setup_pinned_chat(state: &Arc<AppState>) -> TrackedRelease {
+    let tracked = insert_tracked(state, "repo", "https://github.com/owner/repo", 321).await;
+    SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    let mut settings = ChatSettings::default_for(321);
+    settings.pin_releases = true;
+    SqliteChatSettingsRepository::new(state.db.clone())
+        .save(&settings)
+        .await
+        .unwrap();
+    let receipts = SqliteNotificationReceiptsRepository::new(state.db.clone());
+    receipts
+        .save(&NotificationReceipt {
+            id: Uuid::now_v7(),
+            tracked_repository_id: tracked.id,
+            chat_id: 321,
+            tag_name: "v1.0.0".to_string(),
+            telegram_message_id: 100,
+            sent_at: Utc::now(),
+        })
+        .await
+        .unwrap();
+    receipts.set_pinned(321, 100, true).await.unwrap();
+    tracked
+}
+
+async fn mock_new_release(gh: &mut mockito::ServerGuard) -> mockito::Mock {
+    gh.mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v1.1.0"}).to_string())
+        .create_async()
+        .await
+}
+
+async fn mock_sent_message(tg: &mut mockito::ServerGuard) -> mockito::Mock {
+    tg.mock(
+        "POST",
+        mockito::Matcher::Exact(format!("/bot{TOKEN}/SendMessage")),
+    )
+    .with_status(200)
+    .with_header("content-type", "application/json")
+    .with_body(
+        serde_json::json!({
+            "ok": true,
+            "result": {
+                "message_id": 4242,
+                "date": 1700000000,
+                "chat": {"id": 321, "type": "channel", "title": "Releases"},
+                "text": "New release"
+            }
+        })
+        .to_string(),
+    )
+    .expect(1)
+    .create_async()
+    .await
+}
+
+fn receipts(state: &Arc<AppState>) -> SqliteNotificationReceiptsRepository {
+    SqliteNotificationReceiptsRepository::new(state.db.clone())
+}
+
+#[tokio::test]
+async fn new_release_is_pinned_and_the_previous_pin_removed() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let bot = Bot::new(TOKEN).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    setup_pinned_chat(&state).await;
+    let _m_gh = mock_new_release(&mut gh).await;
+    let m_send = mock_sent_message(&mut tg).await;
+    let m_pin = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{TOKEN}/PinChatMessage")),
+        )
+        .match_body(mockito::Matcher::PartialJson(
+            serde_json::json!({"chat_id": 321, "message_id": 4242}),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"ok": true, "result": true}"#)
+        .expect(1)
+        .create_async()
+        .await;
+    let m_unpin = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{TOKEN}/UnpinChatMessage")),
+        )
+        .match_body(mockito::Matcher::PartialJson(
+            serde_json::json!({"chat_id": 321, "message_id": 100}),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"ok": true, "result": true}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+
+    m_send.assert_async().await;
+    m_pin.assert_async().await;
+    m_unpin.assert_async().await;
+    assert_eq!(receipts(&state).find_pinned(321).await.unwrap(), vec![4242]);
+}
+
+#[tokio::test]
+async fn previous_pin_stays_when_the_bot_may_not_pin() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let bot = Bot::new(TOKEN).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    setup_pinned_chat(&state).await;
+    let _m_gh = mock_new_release(&mut gh).await;
+    let m_send = mock_sent_message(&mut tg).await;
+    let m_pin = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{TOKEN}/PinChatMessage")),
+        )
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"ok": false, "error_code": 400, "description": "Bad Request: not enough rights to manage pinned messages in the chat"}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let m_unpin = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{TOKEN}/UnpinChatMessage")),
+        )
+        .expect(0)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+
+    m_send.assert_async().await;
+    m_pin.assert_async().await;
+    m_unpin.assert_async().await;
+    assert_eq!(receipts(&state).find_pinned(321).await.unwrap(), vec![100]);
+}
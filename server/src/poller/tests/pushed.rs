@@ -0,0 +1,160 @@
+use super::*;
+use crate::chat_settings::ChatSettings;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::github::{Release, ReleaseDetails};
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+fn release(tag: &str) -> Release {
+    Release {
+        tag_name: tag.to_string(),
+        body: None,
+        details: ReleaseDetails::default(),
+    }
+}
+
+#[tokio::test]
+async fn pushed_release_notifies_once_and_the_poll_finds_it_seen() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 5).await;
+    SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+
+    let _m_gh = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v1.1.0"}).to_string())
+        .create_async()
+        .await;
+    let m_tg = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex(
+            "owner/repo/releases/tag/v1.1.0".to_string(),
+        ))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    assert!(process_pushed_release(&state, &bot, &tracked, release("v1.1.0")).await);
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+
+    m_tg.assert();
+}
+
+#[tokio::test]
+async fn tags_only_repositories_are_left_to_the_poller() {
+    let state = setup_state().await;
+    let tg = Server::new_async().await;
+    let bot = Bot::new("TESTTOKEN").set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 5).await;
+    let settings_repo = SqliteRepositorySettingsRepository::new(state.db.clone());
+    let mut settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    settings.tags_only = true;
+    settings_repo.save(&settings).await.unwrap();
+
+    assert!(!process_pushed_release(&state, &bot, &tracked, release("v1.1.0")).await);
+    assert!(
+        SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+            .find_by_tracked_release_id(&tracked.id)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn pushed_releases_do_not_report_the_cached_one_as_removed() {
+    let state = setup_state().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 42).await;
+    SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.1.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    let mut chat_settings = ChatSettings::default_for(42);
+    chat_settings.notify_removed_releases = true;
+    SqliteChatSettingsRepository::new(state.db.clone())
+        .save(&chat_settings)
+        .await
+        .unwrap();
+
+    let m_removed = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex("was removed".to_string()))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(0)
+        .create_async()
+        .await;
+    let m_release = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex(
+            "owner/repo/releases/tag/v1.2.0".to_string(),
+        ))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    assert!(process_pushed_release(&state, &bot, &tracked, release("v1.2.0")).await);
+
+    m_removed.assert_async().await;
+    m_release.assert_async().await;
+}
+
+#[tokio::test]
+async fn releases_pushed_during_a_poll_cycle_are_left_to_it() {
+    let state = setup_state().await;
+    let tg = Server::new_async().await;
+    let bot = Bot::new("TESTTOKEN").set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 5).await;
+
+    let cycle = state.cycle_lock.lock().await;
+    assert!(!process_pushed_release(&state, &bot, &tracked, release("v1.1.0")).await);
+    drop(cycle);
+
+    assert!(
+        SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+            .find_by_tracked_release_id(&tracked.id)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
@@ -0,0 +1,70 @@
+use super::*;
+use crate::notifications::NotificationReceipt;
+
+#[tokio::test]
+async fn delivered_notification_records_message_id() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 321).await;
+    SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+
+    let _m_gh = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v1.1.0"}).to_string())
+        .create_async()
+        .await;
+    let _m_tg = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "ok": true,
+                "result": {
+                    "message_id": 4242,
+                    "date": 1700000000,
+                    "chat": {"id": 321, "type": "private", "first_name": "Test"},
+                    "text": "New release"
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+
+    let receipts = sqlx::query_as::<_, NotificationReceipt>(
+        "SELECT id, tracked_repository_id, chat_id, tag_name, telegram_message_id, sent_at \
+         FROM notifications",
+    )
+    .fetch_all(&state.db)
+    .await
+    .unwrap();
+    assert_eq!(receipts.len(), 1);
+    assert_eq!(receipts[0].tracked_repository_id, tracked.id);
+    assert_eq!(receipts[0].chat_id, 321);
+    assert_eq!(receipts[0].tag_name, "v1.1.0");
+    assert_eq!(receipts[0].telegram_message_id, 4242);
+}
@@ -0,0 +1,69 @@
+use super::*;
+use crate::chat_settings::ReleaseNotes;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+
+#[tokio::test]
+async fn identical_release_bodies_are_sent_once() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 8).await;
+    SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    let chat_settings_repo = SqliteChatSettingsRepository::new(state.db.clone());
+    let mut chat_settings = chat_settings_repo.find_or_default(8).await.unwrap();
+    chat_settings.release_notes = ReleaseNotes::Dedupe;
+    chat_settings_repo.save(&chat_settings).await.unwrap();
+
+    let releases = [
+        ("v1.0.1", "Boilerplate notes", true),
+        ("v1.0.2", "Boilerplate notes", false),
+        ("v1.0.3", "Fresh notes", true),
+    ];
+    for (tag, body, expect_body) in releases {
+        let m_gh = gh
+            .mock("GET", "/repos/owner/repo/releases/latest")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!({"tag_name": tag, "body": body}).to_string())
+            .create_async()
+            .await;
+
+        // Without notes the text ends right after the release link
+        let text_matcher = if expect_body {
+            format!("{tag}</b></a>\\\\n{body}")
+        } else {
+            format!("{tag}</b></a>\"")
+        };
+        let m_tg = tg
+            .mock(
+                "POST",
+                mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+            )
+            .match_body(mockito::Matcher::Regex(text_matcher))
+            .with_status(200)
+            .with_body("invalid-json")
+            .expect(1)
+            .create_async()
+            .await;
+
+        poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+        m_tg.assert();
+        m_tg.remove_async().await;
+        m_gh.remove_async().await;
+    }
+}
@@ -0,0 +1,275 @@
+use super::*;
+use crate::github::{GithubError, Release, ReleaseDetails, ReleaseSource};
+use crate::packages::Package;
+use async_trait::async_trait;
+use std::sync::Mutex;
+
+/// Answers from memory and remembers which repositories were asked about.
+#[derive(Default)]
+struct FakeSource {
+    requests: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl ReleaseSource for FakeSource {
+    async fn latest_release(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Option<Release>, GithubError> {
+        self.requests
+            .lock()
+            .unwrap()
+            .push(format!("{owner}/{repo}"));
+        Ok(match repo {
+            "repo" => Some(Release {
+                tag_name: "v2.0.0".to_string(),
+                body: None,
+                details: ReleaseDetails::default(),
+            }),
+            _ => None,
+        })
+    }
+
+    async fn latest_tag(&self, _owner: &str, _repo: &str) -> Result<Option<String>, GithubError> {
+        Ok(None)
+    }
+
+    async fn latest_package_version(
+        &self,
+        package: &Package,
+    ) -> Result<Option<Release>, GithubError> {
+        self.requests
+            .lock()
+            .unwrap()
+            .push(format!("{}:{}", package.registry.name(), package.name));
+        Ok(Some(Release {
+            tag_name: "1.0.1".to_string(),
+            body: None,
+            details: ReleaseDetails::default(),
+        }))
+    }
+}
+
+#[tokio::test]
+async fn poller_reads_releases_from_the_given_source() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 5).await;
+    insert_tracked(&state, "quiet", "https://github.com/owner/quiet", 5).await;
+    SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+
+    let m_tg = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex(
+            "owner/repo/releases/tag/v2.0.0".to_string(),
+        ))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let source = Arc::new(FakeSource::default());
+    // Nothing listens here, so any request that bypassed the source would fail the cycle
+    poll_once_with_source(
+        state.clone(),
+        &bot,
+        source.clone(),
+        &client,
+        None,
+        Some("http://127.0.0.1:9"),
+        false,
+    )
+    .await;
+
+    m_tg.assert();
+    let mut requests = source.requests.lock().unwrap().clone();
+    requests.sort();
+    assert_eq!(requests, vec!["owner/quiet", "owner/repo"]);
+    let cached = SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(cached.tag_name, "v2.0.0");
+}
+
+#[tokio::test]
+async fn repositories_tracked_before_the_naming_rules_are_still_polled() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let tg = Server::new_async().await;
+    let bot = Bot::new("TESTTOKEN").set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    // `/track` refuses this owner now, but a row saved before it did is kept
+    let url = "https://github.com/old--owner-/quiet";
+    assert!(RepositoryUrl::new(url.to_string()).is_err());
+    let mut tracked = TrackedRelease {
+        id: Uuid::new_v4(),
+        repository_name: "quiet".to_string(),
+        repository_url: RepositoryUrl::from_trusted(url.to_string()),
+        chat_id: 5,
+        bot_id: state.bot_id.clone(),
+        default_branch: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    };
+    SqliteTrackedRepositoriesRepository::new(state.db.clone())
+        .save(&mut tracked)
+        .await
+        .unwrap();
+
+    let source = Arc::new(FakeSource::default());
+    poll_once_with_source(
+        state.clone(),
+        &bot,
+        source.clone(),
+        &client,
+        None,
+        Some("http://127.0.0.1:9"),
+        false,
+    )
+    .await;
+
+    assert_eq!(*source.requests.lock().unwrap(), vec!["old--owner-/quiet"]);
+}
+
+#[tokio::test]
+async fn release_published_for_a_notified_tag_does_not_notify_again() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 5).await;
+    let cache_repo = SqliteCachedRepositoryReleasesRepository::new(state.db.clone());
+    cache_repo
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.1.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+
+    // First cycle: no release yet, the tags fallback finds v1.2.0
+    let m_no_release = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(404)
+        .expect(1)
+        .create_async()
+        .await;
+    let _m_tags = gh
+        .mock("GET", "/repos/owner/repo/tags")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!([{ "name": "v1.2.0" }]).to_string())
+        .create_async()
+        .await;
+    let m_tg = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_no_release.assert_async().await;
+
+    // Second cycle: a proper release is published for the same tag
+    let _m_release = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({ "tag_name": "v1.2.0", "body": "Notes" }).to_string())
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_tg.assert_async().await;
+    let cached = cache_repo
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(cached.tag_name, "v1.2.0");
+    assert!(cached.body_hash.is_some());
+}
+
+#[tokio::test]
+async fn package_urls_follow_the_registry_version() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "serde", "https://crates.io/crates/serde", 5).await;
+    SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "1.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: false,
+        })
+        .await
+        .unwrap();
+
+    let m_tg = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex(
+            "crates.io/crates/serde/1.0.1".to_string(),
+        ))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let source = Arc::new(FakeSource::default());
+    poll_once_with_source(
+        state.clone(),
+        &bot,
+        source.clone(),
+        &client,
+        None,
+        Some("http://127.0.0.1:9"),
+        false,
+    )
+    .await;
+
+    m_tg.assert();
+    assert_eq!(
+        source.requests.lock().unwrap().clone(),
+        vec!["crates.io:serde"]
+    );
+}
@@ -0,0 +1,175 @@
+use super::*;
+use crate::chat_settings::ChatSettings;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+
+async fn insert_with_cached_release(state: &Arc<AppState>) -> TrackedRelease {
+    let tracked = insert_tracked(state, "repo", "https://github.com/owner/repo", 42).await;
+    SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.1.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    tracked
+}
+
+/// GitHub after `v1.1.0` was deleted: `v1.0.0` is the latest release again.
+async fn mock_reverted_releases(gh: &mut mockito::ServerGuard) -> (mockito::Mock, mockito::Mock) {
+    let older = serde_json::json!({
+        "tag_name": "v1.0.0",
+        "published_at": "2020-01-01T00:00:00Z",
+        "draft": false,
+        "prerelease": false
+    });
+    let latest = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(older.to_string())
+        .create_async()
+        .await;
+    let list = gh
+        .mock("GET", "/repos/owner/repo/releases")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!([older]).to_string())
+        .create_async()
+        .await;
+    (latest, list)
+}
+
+#[tokio::test]
+async fn deleted_release_is_announced_instead_of_the_older_one() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    let tracked = insert_with_cached_release(&state).await;
+    let mut chat_settings = ChatSettings::default_for(42);
+    chat_settings.notify_removed_releases = true;
+    SqliteChatSettingsRepository::new(state.db.clone())
+        .save(&chat_settings)
+        .await
+        .unwrap();
+    let (_latest, list) = mock_reverted_releases(&mut gh).await;
+
+    let m_removed = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex(
+            "Release v1.1.0 of .* was removed".to_string(),
+        ))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+
+    list.assert_async().await;
+    m_removed.assert_async().await;
+    let cached = SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(cached.tag_name, "v1.0.0");
+}
+
+#[tokio::test]
+async fn releases_are_not_listed_unless_the_chat_opted_in() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    insert_with_cached_release(&state).await;
+    let (_latest, list) = mock_reverted_releases(&mut gh).await;
+    let list = list.expect(0);
+
+    let m_release = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex("v1.0.0".to_string()))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+
+    list.assert_async().await;
+    m_release.assert_async().await;
+}
+
+/// Enables removal notices for chat 42.
+async fn opt_in(state: &Arc<AppState>) {
+    let mut chat_settings = ChatSettings::default_for(42);
+    chat_settings.notify_removed_releases = true;
+    SqliteChatSettingsRepository::new(state.db.clone())
+        .save(&chat_settings)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn tags_cached_through_the_fallback_are_not_looked_for_among_releases() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 42).await;
+    SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v0.9.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: false,
+        })
+        .await
+        .unwrap();
+    opt_in(&state).await;
+    let (_latest, list) = mock_reverted_releases(&mut gh).await;
+    let list = list.expect(0);
+
+    let m_release = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex(
+            "owner/repo/releases/tag/v1.0.0".to_string(),
+        ))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+
+    list.assert_async().await;
+    m_release.assert_async().await;
+    let cached = SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(cached.from_release);
+}
@@ -0,0 +1,203 @@
+use super::*;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+#[tokio::test]
+async fn snoozed_repository_is_cached_silently_then_caught_up() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 42).await;
+    let cache_repo = SqliteCachedRepositoryReleasesRepository::new(state.db.clone());
+    cache_repo
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    let settings_repo = SqliteRepositorySettingsRepository::new(state.db.clone());
+    let mut settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    settings.snoozed_until = Some(Utc::now() + chrono::Duration::hours(1));
+    settings_repo.save(&settings).await.unwrap();
+
+    let _m_gh = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v1.1.0"}).to_string())
+        .create_async()
+        .await;
+
+    // While snoozed the new tag is cached but not announced
+    let m_silent = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(0)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_silent.assert();
+    let cached = cache_repo
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(cached.tag_name, "v1.1.0");
+    m_silent.remove_async().await;
+
+    // Once the snooze has passed the missed release is sent as a catch-up
+    let mut settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    assert_eq!(settings.snooze_missed_tag.as_deref(), Some("v1.1.0"));
+    settings.snoozed_until = Some(Utc::now() - chrono::Duration::minutes(1));
+    settings_repo.save(&settings).await.unwrap();
+
+    let m_catch_up = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex("while snoozed".to_string()))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_catch_up.assert();
+
+    let settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    assert_eq!(settings.snoozed_until, None);
+    assert_eq!(settings.snooze_missed_tag, None);
+}
+
+#[tokio::test]
+async fn snoozed_chat_is_cached_silently_then_summarised() {
+    use crate::chat_settings::ChatSettings;
+    use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 42).await;
+    let cache_repo = SqliteCachedRepositoryReleasesRepository::new(state.db.clone());
+    cache_repo
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    let chat_repo = SqliteChatSettingsRepository::new(state.db.clone());
+    let mut chat_settings = ChatSettings::default_for(42);
+    chat_settings.snoozed_until = Some(Utc::now() + chrono::Duration::hours(1));
+    chat_repo.save(&chat_settings).await.unwrap();
+
+    let m_v11 = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v1.1.0"}).to_string())
+        .create_async()
+        .await;
+
+    // While the chat is snoozed the new tag is cached but not announced
+    let m_silent = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(0)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_silent.assert();
+    let cached = cache_repo
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(cached.tag_name, "v1.1.0");
+    m_silent.remove_async().await;
+
+    // Once the snooze has passed the chat gets a summary, not the release again
+    let mut chat_settings = chat_repo.find_or_default(42).await.unwrap();
+    assert_eq!(chat_settings.snooze_missed, vec!["repo"]);
+    chat_settings.snoozed_until = Some(Utc::now() - chrono::Duration::minutes(1));
+    chat_repo.save(&chat_settings).await.unwrap();
+
+    let m_summary = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::Regex("while this chat was snoozed".to_string()),
+            mockito::Matcher::Regex("repo".to_string()),
+        ]))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_summary.assert();
+    m_summary.remove_async().await;
+
+    let chat_settings = chat_repo.find_or_default(42).await.unwrap();
+    assert_eq!(chat_settings.snoozed_until, None);
+    assert!(chat_settings.snooze_missed.is_empty());
+
+    // Later releases are announced as usual
+    m_v11.remove_async().await;
+    let _m_v12 = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v1.2.0"}).to_string())
+        .create_async()
+        .await;
+    let m_release = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex("v1.2.0".to_string()))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_release.assert();
+}
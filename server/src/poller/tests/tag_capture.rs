@@ -0,0 +1,95 @@
+use super::*;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+const TOKEN: &str = "TESTTOKEN";
+
+/// Polls a tags-only repository whose versions are captured from `build-<version>-r<n>`
+/// tags, with `cached_tag` seen before and `tags` listed now, and returns the tag cached
+/// afterwards once `expected_notifications` announcing `<b>{announced}</b>` were sent.
+async fn poll_captured(
+    cached_tag: &str,
+    tags: &[&str],
+    announced: &str,
+    expected_notifications: usize,
+) -> String {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let bot = Bot::new(TOKEN).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 5).await;
+    let cache_repo = SqliteCachedRepositoryReleasesRepository::new(state.db.clone());
+    cache_repo
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: cached_tag.to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    let settings_repo = SqliteRepositorySettingsRepository::new(state.db.clone());
+    let mut settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    settings.tags_only = true;
+    settings.tag_capture = Some(r"^build-(\d+\.\d+)-r\d+$".to_string());
+    settings_repo.save(&settings).await.unwrap();
+
+    let listed: Vec<_> = tags
+        .iter()
+        .map(|name| serde_json::json!({ "name": name }))
+        .collect();
+    let _m_tags = gh
+        .mock("GET", "/repos/owner/repo/tags")
+        .match_query(mockito::Matcher::Exact("per_page=100".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::Value::Array(listed).to_string())
+        .create_async()
+        .await;
+    let m_tg = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{TOKEN}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex(format!("<b>{announced}</b>")))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(expected_notifications)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_tg.assert_async().await;
+
+    cache_repo
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .expect("cached row")
+        .tag_name
+}
+
+#[tokio::test]
+async fn announces_the_captured_version_of_the_newest_matching_tag() {
+    let cached = poll_captured(
+        "build-1.2-r3",
+        &["nightly", "build-1.2-r9", "build-1.10-r1"],
+        "1.10",
+        1,
+    )
+    .await;
+
+    assert_eq!(cached, "build-1.10-r1");
+}
+
+#[tokio::test]
+async fn tags_capturing_the_same_version_are_not_announced_again() {
+    let cached = poll_captured("build-1.3-r1", &["build-1.3-r2"], "1.3", 0).await;
+
+    // The raw tag is still cached, for links
+    assert_eq!(cached, "build-1.3-r2");
+}
@@ -0,0 +1,123 @@
+use super::*;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+async fn ignoring(state: &Arc<AppState>, tags_only: bool) -> TrackedRelease {
+    let tracked = insert_tracked(state, "repo", "https://github.com/owner/repo", 5).await;
+    SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    let settings_repo = SqliteRepositorySettingsRepository::new(state.db.clone());
+    let mut settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    settings.tag_ignore = Some("*nightly*".to_string());
+    settings.tags_only = tags_only;
+    settings_repo.save(&settings).await.unwrap();
+    tracked
+}
+
+#[tokio::test]
+async fn ignored_release_falls_through_to_the_next_one() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    let tracked = ignoring(&state, false).await;
+
+    let m_latest = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .expect(0)
+        .create_async()
+        .await;
+    let _m_releases = gh
+        .mock("GET", "/repos/owner/repo/releases?per_page=30")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                {"tag_name": "v1.2.0-NIGHTLY.3", "draft": false, "prerelease": false},
+                {"tag_name": "v1.1.0", "draft": false, "prerelease": false},
+                {"tag_name": "v1.0.0", "draft": false, "prerelease": false}
+            ])
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let m_tg = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .match_body(mockito::Matcher::Regex(
+            "owner/repo/releases/tag/v1.1.0".to_string(),
+        ))
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_tg.assert();
+    m_latest.assert();
+
+    let cached = SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(cached.tag_name, "v1.1.0");
+}
+
+#[tokio::test]
+async fn only_ignored_tags_announce_nothing() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+    let tracked = ignoring(&state, true).await;
+
+    let _m_tags = gh
+        .mock(
+            "GET",
+            mockito::Matcher::Exact("/repos/owner/repo/tags".to_string()),
+        )
+        .match_query(mockito::Matcher::UrlEncoded(
+            "per_page".into(),
+            "100".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!([{ "name": "nightly-2026-10-16" }]).to_string())
+        .create_async()
+        .await;
+    let m_tg = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .expect(0)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_tg.assert();
+
+    let cached = SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(cached.tag_name, "v1.0.0");
+}
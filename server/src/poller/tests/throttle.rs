@@ -0,0 +1,88 @@
+use super::*;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+async fn mock_latest(gh: &mut mockito::ServerGuard, tag: &str) -> mockito::Mock {
+    gh.mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({ "tag_name": tag }).to_string())
+        .create_async()
+        .await
+}
+
+async fn mock_send(
+    tg: &mut mockito::ServerGuard,
+    token: &str,
+    tag: &str,
+    hits: usize,
+) -> mockito::Mock {
+    tg.mock(
+        "POST",
+        mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+    )
+    .match_body(mockito::Matcher::Regex(format!(
+        "owner/repo/releases/tag/{tag}"
+    )))
+    .with_status(200)
+    .with_body("invalid-json")
+    .expect(hits)
+    .create_async()
+    .await
+}
+
+#[tokio::test]
+async fn releases_within_the_window_collapse_into_one_notification() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 5).await;
+    SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    let settings_repo = SqliteRepositorySettingsRepository::new(state.db.clone());
+    let mut settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    settings.throttle_window_secs = Some(3600);
+    settings_repo.save(&settings).await.unwrap();
+
+    let m_first = mock_send(&mut tg, token, "v1.1.0", 1).await;
+    let m_skipped = mock_send(&mut tg, token, "v1.2.0", 0).await;
+    let m_newest = mock_send(&mut tg, token, "v1.3.0", 1).await;
+
+    // The first release opens the window
+    let m_gh = mock_latest(&mut gh, "v1.1.0").await;
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_first.assert_async().await;
+    let settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    assert!(settings.last_notified_at.is_some());
+
+    // Two more releases inside the window are held
+    m_gh.remove_async().await;
+    let m_gh = mock_latest(&mut gh, "v1.2.0").await;
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_gh.remove_async().await;
+    let _m_gh = mock_latest(&mut gh, "v1.3.0").await;
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_skipped.assert_async().await;
+
+    // The window ends: only the newest release is announced
+    let mut settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+    settings.last_notified_at = Some(Utc::now() - chrono::Duration::minutes(61));
+    settings_repo.save(&settings).await.unwrap();
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_newest.assert_async().await;
+    m_first.assert_async().await;
+    m_skipped.assert_async().await;
+}
@@ -0,0 +1,68 @@
+use super::*;
+use crate::tracked_repositories::settings::RepositorySettings;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+use crate::tracked_repositories::tracked_repositories_releases::FetchStatus;
+
+#[tokio::test]
+async fn repository_timeout_fails_only_that_repository() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+    let mut gh = Server::new_async().await;
+    let tg = Server::new_async().await;
+    let bot = Bot::new("TESTTOKEN").set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let slow = insert_tracked(&state, "slow", "https://github.com/owner/slow", 1).await;
+    let patient = insert_tracked(&state, "patient", "https://github.com/owner/patient", 1).await;
+    let mut settings = RepositorySettings::default_for(slow.id);
+    settings.request_timeout_secs = Some(1);
+    SqliteRepositorySettingsRepository::new(state.db.clone())
+        .save(&settings)
+        .await
+        .unwrap();
+
+    // Both answer after two seconds: too late for the one second of `slow`, well within
+    // the default for `patient`
+    let _m = gh
+        .mock(
+            "GET",
+            mockito::Matcher::Regex("^/repos/owner/[a-z]+/releases/latest$".to_string()),
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_chunked_body(|w| {
+            std::thread::sleep(std::time::Duration::from_secs(2));
+            w.write_all(br#"{"tag_name": "v1.0.0"}"#)
+        })
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+
+    let cache = SqliteCachedRepositoryReleasesRepository::new(state.db.clone());
+    assert_eq!(
+        cache.find_fetch_status(&slow.id).await.unwrap(),
+        Some(FetchStatus::Failed)
+    );
+    assert!(
+        cache
+            .find_by_tracked_release_id(&slow.id)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert_eq!(
+        cache.find_fetch_status(&patient.id).await.unwrap(),
+        Some(FetchStatus::Ok)
+    );
+    assert_eq!(
+        cache
+            .find_by_tracked_release_id(&patient.id)
+            .await
+            .unwrap()
+            .unwrap()
+            .tag_name,
+        "v1.0.0"
+    );
+}
@@ -0,0 +1,76 @@
+use super::*;
+use crate::tracked_repositories::settings::repository::{
+    RepositorySettingsRepository, SqliteRepositorySettingsRepository,
+};
+
+/// Polls a repository cached at `1.2.3` whose latest release is `v1.2.3`, returning
+/// whether a notification was sent and what got cached.
+async fn poll_retag(exact_tags: bool) -> (bool, Option<CachedRepositoryRelease>) {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 6).await;
+    let cache_repo = SqliteCachedRepositoryReleasesRepository::new(state.db.clone());
+    cache_repo
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "1.2.3".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    if exact_tags {
+        let settings_repo = SqliteRepositorySettingsRepository::new(state.db.clone());
+        let mut settings = settings_repo.find_or_default(&tracked.id).await.unwrap();
+        settings.exact_tags = true;
+        settings_repo.save(&settings).await.unwrap();
+    }
+
+    let _m_gh = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v1.2.3"}).to_string())
+        .create_async()
+        .await;
+    let m_tg = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .with_status(200)
+        .with_body("invalid-json")
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    let cached = cache_repo
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap();
+    (m_tg.matched_async().await, cached)
+}
+
+#[tokio::test]
+async fn v_prefixed_retag_of_same_version_does_not_notify() {
+    let (notified, cached) = poll_retag(false).await;
+
+    assert!(!notified);
+    // The exact tag GitHub reports is still what gets cached
+    assert_eq!(cached.unwrap().tag_name, "v1.2.3");
+}
+
+#[tokio::test]
+async fn exact_tags_notifies_on_v_prefixed_retag() {
+    let (notified, _) = poll_retag(true).await;
+
+    assert!(notified);
+}
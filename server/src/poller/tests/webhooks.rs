@@ -0,0 +1,69 @@
+use super::*;
+use crate::chat_settings::repository::{ChatSettingsRepository, SqliteChatSettingsRepository};
+use crate::chat_settings::{ChatWebhook, WebhookKind};
+
+#[tokio::test]
+async fn new_release_is_mirrored_to_chat_webhook() {
+    let state = setup_state().await;
+    let client = reqwest::Client::new();
+
+    let mut gh = Server::new_async().await;
+    let mut tg = Server::new_async().await;
+    let mut hook = Server::new_async().await;
+
+    let token = "TESTTOKEN";
+    let bot = Bot::new(token).set_api_url(reqwest::Url::parse(&tg.url()).unwrap());
+
+    let tracked = insert_tracked(&state, "repo", "https://github.com/owner/repo", 11).await;
+    SqliteCachedRepositoryReleasesRepository::new(state.db.clone())
+        .save(&CachedRepositoryRelease {
+            tracked_repository_id: tracked.id,
+            tag_name: "v1.0.0".to_string(),
+            first_seen_at: Utc::now(),
+            body_hash: None,
+            from_release: true,
+        })
+        .await
+        .unwrap();
+    let chat_settings_repo = SqliteChatSettingsRepository::new(state.db.clone());
+    let mut chat_settings = chat_settings_repo.find_or_default(11).await.unwrap();
+    chat_settings.webhook = Some(ChatWebhook {
+        kind: WebhookKind::Discord,
+        url: format!("{}/hook", hook.url()),
+    });
+    chat_settings_repo.save(&chat_settings).await.unwrap();
+
+    let _m_gh = gh
+        .mock("GET", "/repos/owner/repo/releases/latest")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"tag_name": "v1.1.0"}).to_string())
+        .create_async()
+        .await;
+    let m_tg = tg
+        .mock(
+            "POST",
+            mockito::Matcher::Exact(format!("/bot{token}/SendMessage")),
+        )
+        .with_status(200)
+        .with_body("invalid-json")
+        .expect(1)
+        .create_async()
+        .await;
+    let m_hook = hook
+        .mock("POST", "/hook")
+        .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+            "embeds": [{
+                "title": "owner/repo v1.1.0",
+                "url": "https://github.com/owner/repo/releases/tag/v1.1.0",
+            }],
+        })))
+        .with_status(204)
+        .expect(1)
+        .create_async()
+        .await;
+
+    poll_once(state.clone(), &bot, &client, None, Some(&gh.url()), false).await;
+    m_tg.assert_async().await;
+    m_hook.assert_async().await;
+}
@@ -0,0 +1,62 @@
+use chrono::{DateTime, Duration, Utc};
+
+use crate::tracked_repositories::TrackedRelease;
+use crate::tracked_repositories::settings::RepositorySettings;
+
+use super::cycle::PollCycle;
+use super::fetch::LatestRelease;
+
+/// When the repository's throttle window started by its last notification ends, or
+/// `None` when a new release may be announced right away.
+pub(crate) fn throttled_until(
+    settings: &RepositorySettings,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let window = settings.throttle_window_secs?;
+    let until = settings.last_notified_at? + Duration::seconds(window);
+    (until > now).then_some(until)
+}
+
+impl PollCycle<'_> {
+    /// Holds back a new release of `r` while its throttle window is open, returning
+    /// whether it was held. The release is not cached, so the first poll after the window
+    /// announces whatever is newest by then.
+    pub(super) fn hold_if_throttled(
+        &self,
+        r: &TrackedRelease,
+        settings: &RepositorySettings,
+        latest: &LatestRelease,
+    ) -> bool {
+        let Some(until) = throttled_until(settings, self.now) else {
+            return false;
+        };
+        log::debug!(
+            "Throttling {} of {} until {}",
+            latest.tag,
+            r.repository_url,
+            until
+        );
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn window_counts_from_the_last_notification() {
+        let now = Utc::now();
+        let mut s = RepositorySettings::default_for(Uuid::now_v7());
+        s.last_notified_at = Some(now - Duration::minutes(20));
+        assert_eq!(throttled_until(&s, now), None);
+
+        s.throttle_window_secs = Some(3600);
+        assert_eq!(throttled_until(&s, now), Some(now + Duration::minutes(40)));
+        assert_eq!(throttled_until(&s, now + Duration::hours(1)), None);
+
+        s.last_notified_at = None;
+        assert_eq!(throttled_until(&s, now), None);
+    }
+}
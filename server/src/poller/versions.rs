@@ -0,0 +1,126 @@
+use crate::tracked_repositories::settings::tag_capture::capture;
+
+/// Splits `tag` into its numeric version core, prerelease and whether the core parsed,
+/// ignoring a leading `v`/`V` and `+build` metadata.
+fn version_parts(tag: &str) -> Option<(Vec<u64>, &str)> {
+    let tag = tag.strip_prefix(['v', 'V']).unwrap_or(tag);
+    let tag = tag.split_once('+').map_or(tag, |(version, _)| version);
+    let (core, prerelease) = tag.split_once('-').unwrap_or((tag, ""));
+    let mut numbers = core
+        .split('.')
+        .map(|part| part.parse::<u64>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    // 1.2 and 1.2.0 name the same version
+    while numbers.len() > 1 && numbers.last() == Some(&0) {
+        numbers.pop();
+    }
+    Some((numbers, prerelease))
+}
+
+/// Whether two tags name the same version, e.g. `1.2.3` and `v1.2.3`. Tags that are not
+/// version numbers are the same only when equal apart from a leading `v`.
+pub(crate) fn same_version(a: &str, b: &str) -> bool {
+    if a == b {
+        return true;
+    }
+    match (version_parts(a), version_parts(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => a.strip_prefix(['v', 'V']).unwrap_or(a) == b.strip_prefix(['v', 'V']).unwrap_or(b),
+    }
+}
+
+/// The tag naming the highest version once `prefix` is stripped, like `pkg-a/v1.10.0`
+/// among `pkg-a/v1.9.0` and `pkg-a/v1.10.0`. A stable version beats its prereleases. Tags
+/// that are not version numbers only win when none is, and then the first does.
+pub(crate) fn newest_tag<'a>(tags: &'a [String], prefix: &str) -> Option<&'a String> {
+    let versions: Vec<&str> = tags
+        .iter()
+        .map(|tag| tag.strip_prefix(prefix).unwrap_or(tag))
+        .collect();
+    newest_version(&versions).map(|i| &tags[i])
+}
+
+/// The tag whose version captured by `pattern` is the highest, compared like
+/// [`newest_tag`].
+pub(crate) fn newest_captured<'a>(tags: &'a [String], pattern: &str) -> Option<&'a String> {
+    let captured: Vec<String> = tags
+        .iter()
+        .map(|tag| capture(pattern, tag).unwrap_or_default())
+        .collect();
+    let versions: Vec<&str> = captured.iter().map(String::as_str).collect();
+    newest_version(&versions).map(|i| &tags[i])
+}
+
+/// Index of the highest of `versions`, or of the first when none is a version number.
+fn newest_version(versions: &[&str]) -> Option<usize> {
+    let mut newest: Option<(usize, (Vec<u64>, &str))> = None;
+    for (i, version) in versions.iter().enumerate() {
+        let Some((core, prerelease)) = version_parts(version) else {
+            continue;
+        };
+        let newer = newest
+            .as_ref()
+            .is_none_or(|(_, (best, best_pre))| match core.cmp(best) {
+                std::cmp::Ordering::Equal => {
+                    !best_pre.is_empty() && (prerelease.is_empty() || prerelease > *best_pre)
+                }
+                ordering => ordering.is_gt(),
+            });
+        if newer {
+            newest = Some((i, (core, prerelease)));
+        }
+    }
+    newest
+        .map(|(i, _)| i)
+        .or((!versions.is_empty()).then_some(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn newest_tag_compares_versions_not_names() {
+        let mixed = tags(&["pkg-a/v1.9.0", "pkg-a/v1.10.0", "pkg-a/v1.10.0-rc.1"]);
+        assert_eq!(newest_tag(&mixed, "pkg-a/").unwrap(), "pkg-a/v1.10.0");
+
+        let prereleases = tags(&["v2.0.0-rc.1", "v2.0.0-rc.2", "v1.0.0"]);
+        assert_eq!(newest_tag(&prereleases, "").unwrap(), "v2.0.0-rc.2");
+
+        let names = tags(&["nightly", "latest"]);
+        assert_eq!(newest_tag(&names, "").unwrap(), "nightly");
+        assert_eq!(newest_tag(&[], ""), None);
+    }
+
+    #[test]
+    fn newest_captured_compares_the_captured_versions() {
+        let schemes = tags(&["build-2024.10-r7", "build-2024.9-r12", "build-2023.12-r40"]);
+        assert_eq!(
+            newest_captured(&schemes, r"^build-(\d+\.\d+)-r\d+$").unwrap(),
+            "build-2024.10-r7"
+        );
+    }
+
+    #[test]
+    fn v_prefix_and_build_metadata_are_ignored() {
+        assert!(same_version("1.2.3", "v1.2.3"));
+        assert!(same_version("V1.2.3", "1.2.3"));
+        assert!(same_version("v1.2", "1.2.0"));
+        assert!(same_version("v1.2.3+build.5", "1.2.3"));
+        assert!(same_version("v1.0.0-rc.1", "1.0.0-rc.1"));
+        assert!(same_version("vnext", "next"));
+    }
+
+    #[test]
+    fn different_versions_differ() {
+        assert!(!same_version("1.2.3", "v1.2.4"));
+        assert!(!same_version("v1.0.0-rc.1", "v1.0.0"));
+        assert!(!same_version("v1.0.0-rc.1", "v1.0.0-rc.2"));
+        assert!(!same_version("1", "10"));
+        assert!(!same_version("release-1", "v1"));
+    }
+}
@@ -0,0 +1,22 @@
+use crate::webhooks::deliver;
+
+use super::cycle::PollCycle;
+
+impl PollCycle<'_> {
+    /// Mirrors the release notifications of the cycle to the chats' Slack or Discord
+    /// webhooks. A failed delivery is logged and not retried.
+    pub(crate) async fn deliver_webhooks(&mut self) {
+        for (webhook, release) in std::mem::take(&mut self.webhook_deliveries) {
+            if let Err(e) = deliver(self.client, &webhook, &release).await {
+                log::warn!(
+                    "Failed to mirror {} {} to a {} webhook: {}",
+                    release.repository,
+                    release.tag,
+                    webhook.kind.as_str(),
+                    // The URL carries the webhook's secret
+                    e.without_url()
+                );
+            }
+        }
+    }
+}
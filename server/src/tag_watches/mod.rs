@@ -0,0 +1,44 @@
+pub mod repository;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{FromRow, Row};
+use uuid::Uuid;
+
+use crate::tracked_repositories::RepositoryUrl;
+
+/// A request to be told once, when a specific tag is published on a repository.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagWatch {
+    pub id: Uuid,
+    pub chat_id: i64,
+    /// The bot that serves the watch; empty for the bot of `TELOXIDE_TOKEN`.
+    pub bot_id: String,
+    pub repository_url: RepositoryUrl,
+    pub tag_name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl FromRow<'_, SqliteRow> for TagWatch {
+    fn from_row(row: &SqliteRow) -> Result<Self, sqlx::Error> {
+        let id_str: String = row.try_get("id")?;
+        let id = Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+
+        let chat_id: i64 = row.try_get("chat_id")?;
+        let bot_id: String = row.try_get("bot_id")?;
+        let repository_url_str: String = row.try_get("repository_url")?;
+        let repository_url = RepositoryUrl::from_trusted(repository_url_str);
+        let tag_name: String = row.try_get("tag_name")?;
+        let created_at: DateTime<Utc> = row.try_get("created_at")?;
+
+        Ok(Self {
+            id,
+            chat_id,
+            bot_id,
+            repository_url,
+            tag_name,
+            created_at,
+        })
+    }
+}
@@ -0,0 +1,155 @@
+use crate::tag_watches::TagWatch;
+use async_trait::async_trait;
+use sqlx::{self, sqlite::SqlitePool};
+use std::error::Error;
+
+#[async_trait]
+pub trait TagWatchesRepository: Send + Sync {
+    /// Stores the watch. Returns `false` if the chat already watches this tag.
+    async fn save(&self, watch: &TagWatch) -> Result<bool, Box<dyn Error + Send + Sync>>;
+    #[allow(dead_code)]
+    async fn find_all(&self) -> Result<Vec<TagWatch>, Box<dyn Error + Send + Sync>>;
+    /// Every watch served by the bot `bot_id`.
+    async fn find_all_for_bot(
+        &self,
+        bot_id: &str,
+    ) -> Result<Vec<TagWatch>, Box<dyn Error + Send + Sync>>;
+    async fn delete(&self, id: &uuid::Uuid) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
+
+pub struct SqliteTagWatchesRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteTagWatchesRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TagWatchesRepository for SqliteTagWatchesRepository {
+    async fn save(&self, watch: &TagWatch) -> Result<bool, Box<dyn Error + Send + Sync>> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO tag_watches (id, chat_id, bot_id, repository_url, tag_name, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            ON CONFLICT(chat_id, repository_url, tag_name) DO NOTHING
+            "#,
+        )
+        .bind(watch.id.to_string())
+        .bind(watch.chat_id)
+        .bind(&watch.bot_id)
+        .bind(watch.repository_url.url())
+        .bind(&watch.tag_name)
+        .bind(watch.created_at)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_all(&self) -> Result<Vec<TagWatch>, Box<dyn Error + Send + Sync>> {
+        let watches = sqlx::query_as::<_, TagWatch>(
+            r#"
+            SELECT id, chat_id, bot_id, repository_url, tag_name, created_at
+            FROM tag_watches
+            ORDER BY created_at ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(watches)
+    }
+
+    async fn find_all_for_bot(
+        &self,
+        bot_id: &str,
+    ) -> Result<Vec<TagWatch>, Box<dyn Error + Send + Sync>> {
+        let watches = sqlx::query_as::<_, TagWatch>(
+            r#"
+            SELECT id, chat_id, bot_id, repository_url, tag_name, created_at
+            FROM tag_watches
+            WHERE bot_id = ?1
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(bot_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(watches)
+    }
+
+    async fn delete(&self, id: &uuid::Uuid) -> Result<(), Box<dyn Error + Send + Sync>> {
+        sqlx::query("DELETE FROM tag_watches WHERE id = ?1")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracked_repositories::RepositoryUrl;
+    use chrono::Utc;
+    use sqlx::sqlite::SqlitePoolOptions;
+    use uuid::Uuid;
+
+    async fn setup_repo() -> SqliteTagWatchesRepository {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        SqliteTagWatchesRepository::new(pool)
+    }
+
+    fn make_watch(chat_id: i64, tag: &str) -> TagWatch {
+        TagWatch {
+            id: Uuid::now_v7(),
+            chat_id,
+            bot_id: String::new(),
+            repository_url: RepositoryUrl::new("https://github.com/owner/repo".to_string())
+                .unwrap(),
+            tag_name: tag.to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn save_find_and_delete() {
+        let repo = setup_repo().await;
+        let watch = make_watch(1, "v2.0.0");
+
+        assert!(repo.save(&watch).await.unwrap());
+
+        let all = repo.find_all().await.unwrap();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].id, watch.id);
+        assert_eq!(all[0].tag_name, "v2.0.0");
+
+        repo.delete(&watch.id).await.unwrap();
+        assert!(repo.find_all().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn save_duplicate_watch_is_ignored() {
+        let repo = setup_repo().await;
+
+        assert!(repo.save(&make_watch(1, "v2.0.0")).await.unwrap());
+        assert!(!repo.save(&make_watch(1, "v2.0.0")).await.unwrap());
+        assert!(repo.save(&make_watch(2, "v2.0.0")).await.unwrap());
+
+        assert_eq!(repo.find_all().await.unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,43 @@
+pub mod repository;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{FromRow, Row};
+use uuid::Uuid;
+
+use crate::tracked_repositories::RepositoryUrl;
+
+/// An alternate location polled, in `position` order, when the primary repository
+/// does not yield a release.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryMirror {
+    pub id: Uuid,
+    pub tracked_repository_id: Uuid,
+    pub repository_url: RepositoryUrl,
+    pub position: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl FromRow<'_, SqliteRow> for RepositoryMirror {
+    fn from_row(row: &SqliteRow) -> Result<Self, sqlx::Error> {
+        let id_str: String = row.try_get("id")?;
+        let id = Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let tracked_repository_id_str: String = row.try_get("tracked_repository_id")?;
+        let tracked_repository_id = Uuid::parse_str(&tracked_repository_id_str)
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+
+        let repository_url_str: String = row.try_get("repository_url")?;
+        let repository_url = RepositoryUrl::from_trusted(repository_url_str);
+        let position: i64 = row.try_get("position")?;
+        let created_at: DateTime<Utc> = row.try_get("created_at")?;
+
+        Ok(Self {
+            id,
+            tracked_repository_id,
+            repository_url,
+            position,
+            created_at,
+        })
+    }
+}
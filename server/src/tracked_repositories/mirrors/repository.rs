@@ -0,0 +1,167 @@
+use crate::tracked_repositories::RepositoryUrl;
+use crate::tracked_repositories::mirrors::RepositoryMirror;
+use async_trait::async_trait;
+use sqlx::{self, sqlite::SqlitePool};
+use std::error::Error;
+
+#[async_trait]
+pub trait RepositoryMirrorsRepository: Send + Sync {
+    /// Appends a mirror after any existing ones. Returns `None` if the URL is already
+    /// a mirror of this repository.
+    async fn append(
+        &self,
+        tracked_repository_id: &uuid::Uuid,
+        repository_url: &RepositoryUrl,
+    ) -> Result<Option<RepositoryMirror>, Box<dyn Error + Send + Sync>>;
+    async fn find_by_tracked_repository_id(
+        &self,
+        tracked_repository_id: &uuid::Uuid,
+    ) -> Result<Vec<RepositoryMirror>, Box<dyn Error + Send + Sync>>;
+}
+
+pub struct SqliteRepositoryMirrorsRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteRepositoryMirrorsRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RepositoryMirrorsRepository for SqliteRepositoryMirrorsRepository {
+    async fn append(
+        &self,
+        tracked_repository_id: &uuid::Uuid,
+        repository_url: &RepositoryUrl,
+    ) -> Result<Option<RepositoryMirror>, Box<dyn Error + Send + Sync>> {
+        let rec = sqlx::query_as::<_, RepositoryMirror>(
+            r#"
+            INSERT INTO tracked_repository_mirrors (id, tracked_repository_id, repository_url, position, created_at)
+            VALUES (
+                ?1, ?2, ?3,
+                (SELECT COALESCE(MAX(position), -1) + 1 FROM tracked_repository_mirrors WHERE tracked_repository_id = ?2),
+                ?4
+            )
+            ON CONFLICT(tracked_repository_id, repository_url) DO NOTHING
+            RETURNING id, tracked_repository_id, repository_url, position, created_at
+            "#,
+        )
+        .bind(uuid::Uuid::now_v7().to_string())
+        .bind(tracked_repository_id.to_string())
+        .bind(repository_url.url())
+        .bind(chrono::Utc::now())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(rec)
+    }
+
+    async fn find_by_tracked_repository_id(
+        &self,
+        tracked_repository_id: &uuid::Uuid,
+    ) -> Result<Vec<RepositoryMirror>, Box<dyn Error + Send + Sync>> {
+        let recs = sqlx::query_as::<_, RepositoryMirror>(
+            r#"
+            SELECT id, tracked_repository_id, repository_url, position, created_at
+            FROM tracked_repository_mirrors
+            WHERE tracked_repository_id = ?1
+            ORDER BY position ASC
+            "#,
+        )
+        .bind(tracked_repository_id.to_string())
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(recs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracked_repositories::TrackedRelease;
+    use crate::tracked_repositories::repository::{
+        SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+    };
+    use chrono::Utc;
+    use sqlx::sqlite::SqlitePoolOptions;
+    use uuid::Uuid;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to connect to sqlite in-memory");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("migrations should run");
+
+        pool
+    }
+
+    async fn insert_tracked_repository(pool: &SqlitePool) -> TrackedRelease {
+        let repo_repo = SqliteTrackedRepositoriesRepository::new(pool.clone());
+        let now = Utc::now();
+        let mut tracked = TrackedRelease {
+            id: Uuid::now_v7(),
+            repository_name: "owner/repo".to_string(),
+            repository_url: RepositoryUrl::new("https://github.com/owner/repo".to_string())
+                .unwrap(),
+            chat_id: 1,
+            bot_id: String::new(),
+            default_branch: None,
+            created_at: now,
+            updated_at: now,
+        };
+        repo_repo.save(&mut tracked).await.unwrap();
+        tracked
+    }
+
+    fn url(s: &str) -> RepositoryUrl {
+        RepositoryUrl::new(s.to_string()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn append_keeps_order_and_ignores_duplicates() {
+        let pool = setup_pool().await;
+        let tracked = insert_tracked_repository(&pool).await;
+        let repo = SqliteRepositoryMirrorsRepository::new(pool.clone());
+
+        let first = repo
+            .append(&tracked.id, &url("https://github.com/mirror/one"))
+            .await
+            .unwrap()
+            .expect("inserted");
+        let second = repo
+            .append(&tracked.id, &url("https://github.com/mirror/two"))
+            .await
+            .unwrap()
+            .expect("inserted");
+        let duplicate = repo
+            .append(&tracked.id, &url("https://github.com/mirror/one"))
+            .await
+            .unwrap();
+
+        assert_eq!(first.position, 0);
+        assert_eq!(second.position, 1);
+        assert!(duplicate.is_none());
+
+        let mirrors = repo
+            .find_by_tracked_repository_id(&tracked.id)
+            .await
+            .unwrap();
+        let urls: Vec<String> = mirrors.iter().map(|m| m.repository_url.url()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://github.com/mirror/one",
+                "https://github.com/mirror/two"
+            ]
+        );
+    }
+}
@@ -1,4 +1,8 @@
+pub mod mirrors;
+mod names;
+pub mod release_history;
 pub mod repository;
+pub mod settings;
 pub mod tracked_repositories_releases;
 // subscriptions module removed; chat_id stored on tracked_repositories
 
@@ -9,12 +13,21 @@ use sqlx::{FromRow, Row};
 use std::fmt;
 use uuid::Uuid;
 
+use crate::chat_settings::Lang;
+use crate::messages::{TrackingText, render};
+use crate::packages::Package;
+use names::{is_valid_owner, is_valid_repo};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrackedRelease {
     pub id: Uuid,
     pub repository_name: String,
     pub repository_url: RepositoryUrl,
     pub chat_id: i64,
+    /// The bot that serves the repository; empty for the bot of `TELOXIDE_TOKEN`.
+    pub bot_id: String,
+    /// The branch GitHub reported as the default when the repository was tracked.
+    pub default_branch: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -24,20 +37,77 @@ pub struct RepositoryUrl {
     url: String,
 }
 
+/// Why `RepositoryUrl::new` rejected a URL, carrying the offending part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UrlError {
+    NotGithub(String),
+    Owner(String),
+    Repository(String),
+}
+
+impl UrlError {
+    /// The error as a chat reply in `lang`.
+    pub fn message(&self, lang: Lang) -> String {
+        match self {
+            Self::NotGithub(url) => render(lang, TrackingText::InvalidUrl, &[("url", url)]),
+            Self::Owner(owner) => render(lang, TrackingText::InvalidOwner, &[("owner", owner)]),
+            Self::Repository(repo) => render(
+                lang,
+                TrackingText::InvalidRepositoryName,
+                &[("repository", repo)],
+            ),
+        }
+    }
+}
+
+impl fmt::Display for UrlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message(Lang::En))
+    }
+}
+
 impl RepositoryUrl {
-    pub fn new(url: String) -> Result<Self, String> {
+    pub fn new(url: String) -> Result<Self, UrlError> {
+        if Package::from_url(&url).is_some() {
+            return Ok(Self { url });
+        }
         if !url.starts_with("https://github.com/") {
             log::warn!("Invalid GitHub repository URL: {url}");
-            return Err(format!("Invalid GitHub repository URL: {url}"));
+            return Err(UrlError::NotGithub(url));
+        }
+
+        // A missing repository is left for `owner_and_repo` to report
+        let mut parts = url["https://github.com/".len()..].split('/');
+        if let Some(owner) = parts.next().filter(|o| !o.is_empty())
+            && !is_valid_owner(owner)
+        {
+            return Err(UrlError::Owner(owner.to_string()));
+        }
+        if let Some(repo) = parts.next().filter(|r| !r.is_empty())
+            && !is_valid_repo(repo.trim_end_matches(".git"))
+        {
+            return Err(UrlError::Repository(repo.to_string()));
         }
 
         Ok(Self { url })
     }
 
+    /// Wraps a URL read back from the database without re-validating it.
+    pub(crate) fn from_trusted(url: String) -> Self {
+        Self { url }
+    }
+
     pub fn url(&self) -> String {
         self.url.clone()
     }
 
+    /// The crates.io or npm package the URL names, when it is not a GitHub repository.
+    pub fn package(&self) -> Option<Package> {
+        Package::from_url(&self.url)
+    }
+
+    /// The owner and repository of a github.com URL. GitHub's naming rules are only
+    /// enforced by `new`, so repositories tracked before they were keep being polled.
     pub fn owner_and_repo(&self) -> Option<(String, String)> {
         let trimmed = self.url.strip_prefix("https://github.com/")?;
         let mut parts = trimmed.split('/');
@@ -65,6 +135,8 @@ impl FromRow<'_, SqliteRow> for TrackedRelease {
         };
 
         let chat_id: i64 = row.try_get("chat_id")?;
+        let bot_id: String = row.try_get("bot_id")?;
+        let default_branch: Option<String> = row.try_get("default_branch")?;
 
         let created_at: DateTime<Utc> = row.try_get("created_at")?;
         let updated_at: DateTime<Utc> = row.try_get("updated_at")?;
@@ -74,6 +146,8 @@ impl FromRow<'_, SqliteRow> for TrackedRelease {
             repository_name,
             repository_url,
             chat_id,
+            bot_id,
+            default_branch,
             created_at,
             updated_at,
         })
@@ -87,3 +161,42 @@ impl fmt::Display for RepositoryUrl {
 }
 
 // CachedRepositoryRelease moved to tracked_repositories_releases module
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_invalid_names_before_any_request() {
+        let err = RepositoryUrl::new("https://github.com/ /repo".to_string()).unwrap_err();
+        assert_eq!(err.to_string(), "' ' is not a valid GitHub owner name.");
+        let err = RepositoryUrl::new("https://github.com/owner/my repo".to_string()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "'my repo' is not a valid GitHub repository name."
+        );
+        assert_eq!(
+            err.message(Lang::It),
+            "'my repo' non è un nome di repository GitHub valido."
+        );
+        assert!(RepositoryUrl::new("https://github.com/settings/profile".to_string()).is_err());
+    }
+
+    #[test]
+    fn parses_valid_owner_and_repo() {
+        let url = RepositoryUrl::new("https://github.com/rust-lang/rust.git".to_string()).unwrap();
+        assert_eq!(
+            url.owner_and_repo(),
+            Some(("rust-lang".to_string(), "rust".to_string()))
+        );
+        assert!(RepositoryUrl::new("https://github.com/own--er/repo".to_string()).is_err());
+    }
+
+    #[test]
+    fn accepts_package_registry_urls() {
+        let url = RepositoryUrl::new("https://crates.io/crates/serde".to_string()).unwrap();
+        assert_eq!(url.package().map(|p| p.name).as_deref(), Some("serde"));
+        assert_eq!(url.owner_and_repo(), None);
+        assert!(RepositoryUrl::new("https://crates.io/crates/".to_string()).is_err());
+    }
+}
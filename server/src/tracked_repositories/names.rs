@@ -0,0 +1,84 @@
+//! GitHub's naming rules for owners and repositories, checked before any API call.
+
+/// Longest user or organization name GitHub allows.
+const MAX_OWNER_CHARS: usize = 39;
+/// Longest repository name GitHub allows.
+const MAX_REPO_CHARS: usize = 100;
+
+/// First path segments of github.com that are GitHub's own pages, never an owner.
+const RESERVED_OWNERS: &[&str] = &[
+    "about",
+    "apps",
+    "collections",
+    "explore",
+    "features",
+    "issues",
+    "login",
+    "marketplace",
+    "new",
+    "notifications",
+    "organizations",
+    "orgs",
+    "pricing",
+    "pulls",
+    "search",
+    "settings",
+    "sponsors",
+    "topics",
+    "trending",
+];
+
+/// Alphanumerics and single hyphens, not at either end.
+pub(crate) fn is_valid_owner(owner: &str) -> bool {
+    !owner.is_empty()
+        && owner.len() <= MAX_OWNER_CHARS
+        && owner.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        && !owner.starts_with('-')
+        && !owner.ends_with('-')
+        && !owner.contains("--")
+        && !RESERVED_OWNERS.contains(&owner.to_ascii_lowercase().as_str())
+}
+
+/// Alphanumerics, `-`, `_` and `.`, except the names `.` and `..`.
+pub(crate) fn is_valid_repo(repo: &str) -> bool {
+    !repo.is_empty()
+        && repo.len() <= MAX_REPO_CHARS
+        && repo
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        && repo != "."
+        && repo != ".."
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_names_github_allows() {
+        assert!(is_valid_owner("rust-lang"));
+        assert!(is_valid_owner("User42"));
+        assert!(is_valid_repo("github-releases_bot.rs"));
+        assert!(is_valid_repo(".github"));
+    }
+
+    #[test]
+    fn rejects_invalid_characters() {
+        assert!(!is_valid_owner(" "));
+        assert!(!is_valid_owner("my_org"));
+        assert!(!is_valid_owner("-owner"));
+        assert!(!is_valid_owner("own--er"));
+        assert!(!is_valid_owner(&"a".repeat(40)));
+        assert!(!is_valid_repo("my repo"));
+        assert!(!is_valid_repo("repo?tab=readme"));
+        assert!(!is_valid_repo("répo"));
+    }
+
+    #[test]
+    fn rejects_reserved_names() {
+        assert!(!is_valid_owner("settings"));
+        assert!(!is_valid_owner("Orgs"));
+        assert!(!is_valid_repo("."));
+        assert!(!is_valid_repo(".."));
+    }
+}
@@ -0,0 +1,94 @@
+pub mod repository;
+
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{FromRow, Row};
+use uuid::Uuid;
+
+/// A release detected by the poller, stored so it can be looked at without asking GitHub.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseHistoryEntry {
+    pub id: Uuid,
+    pub tracked_repository_id: Uuid,
+    pub tag_name: String,
+    pub body: Option<String>,
+    pub html_url: Option<String>,
+    pub published_at: Option<DateTime<Utc>>,
+    pub author: Option<String>,
+    pub detected_at: DateTime<Utc>,
+}
+
+impl FromRow<'_, SqliteRow> for ReleaseHistoryEntry {
+    fn from_row(row: &SqliteRow) -> Result<Self, sqlx::Error> {
+        let id_str: String = row.try_get("id")?;
+        let id = Uuid::parse_str(&id_str).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let tracked_repository_id_str: String = row.try_get("tracked_repository_id")?;
+        let tracked_repository_id = Uuid::parse_str(&tracked_repository_id_str)
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+
+        Ok(Self {
+            id,
+            tracked_repository_id,
+            tag_name: row.try_get("tag_name")?,
+            body: row.try_get("body")?,
+            html_url: row.try_get("html_url")?,
+            published_at: row.try_get("published_at")?,
+            author: row.try_get("author")?,
+            detected_at: row.try_get("detected_at")?,
+        })
+    }
+}
+
+/// How often a repository released, going by its recorded release history. Releases count
+/// at their publication time, or when they were detected if GitHub gave none.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct ReleaseVelocity {
+    pub recorded: i64,
+    pub last_30_days: i64,
+    pub last_90_days: i64,
+    pub first_release_at: Option<DateTime<Utc>>,
+    pub last_release_at: Option<DateTime<Utc>>,
+}
+
+impl ReleaseVelocity {
+    /// Average days between consecutive recorded releases; `None` with fewer than two.
+    pub fn average_days_between(&self) -> Option<f64> {
+        let (Some(first), Some(last)) = (self.first_release_at, self.last_release_at) else {
+            return None;
+        };
+        if self.recorded < 2 {
+            return None;
+        }
+        let days = (last - first).num_seconds() as f64 / 86_400.0;
+        Some(days / (self.recorded - 1) as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn velocity(recorded: i64, span_days: i64) -> ReleaseVelocity {
+        let last = Utc::now();
+        ReleaseVelocity {
+            recorded,
+            last_30_days: 0,
+            last_90_days: 0,
+            first_release_at: (recorded > 0).then(|| last - Duration::days(span_days)),
+            last_release_at: (recorded > 0).then_some(last),
+        }
+    }
+
+    #[test]
+    fn averages_the_gaps_between_releases() {
+        assert_eq!(velocity(5, 60).average_days_between(), Some(15.0));
+        assert_eq!(velocity(2, 3).average_days_between(), Some(3.0));
+    }
+
+    #[test]
+    fn needs_two_releases_for_an_average() {
+        assert_eq!(velocity(0, 0).average_days_between(), None);
+        assert_eq!(velocity(1, 0).average_days_between(), None);
+    }
+}
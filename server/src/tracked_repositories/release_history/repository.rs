@@ -0,0 +1,342 @@
+use crate::tracked_repositories::release_history::{ReleaseHistoryEntry, ReleaseVelocity};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{self, sqlite::SqlitePool};
+use std::error::Error;
+
+#[async_trait]
+pub trait ReleaseHistoryRepository: Send + Sync {
+    /// Saves every entry in a single transaction, then keeps only the newest `keep`
+    /// entries of each repository involved.
+    async fn save_all(
+        &self,
+        entries: &[ReleaseHistoryEntry],
+        keep: usize,
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+    /// The newest entries of the repository, newest first.
+    async fn find_recent(
+        &self,
+        tracked_repository_id: &uuid::Uuid,
+        limit: usize,
+    ) -> Result<Vec<ReleaseHistoryEntry>, Box<dyn Error + Send + Sync>>;
+    /// Counts the repository's recorded releases, overall and in the 30 and 90 days
+    /// before `now`.
+    async fn velocity(
+        &self,
+        tracked_repository_id: &uuid::Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<ReleaseVelocity, Box<dyn Error + Send + Sync>>;
+    /// When each of the repository's recorded releases came out, for those since `since`,
+    /// oldest first. Releases without a publish date count from when they were detected.
+    async fn release_times_since(
+        &self,
+        tracked_repository_id: &uuid::Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<DateTime<Utc>>, Box<dyn Error + Send + Sync>>;
+}
+
+pub struct SqliteReleaseHistoryRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteReleaseHistoryRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ReleaseHistoryRepository for SqliteReleaseHistoryRepository {
+    async fn save_all(
+        &self,
+        entries: &[ReleaseHistoryEntry],
+        keep: usize,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let mut tx = self.pool.begin().await?;
+        for entry in entries {
+            sqlx::query(
+                r#"
+                INSERT INTO release_history (
+                    id, tracked_repository_id, tag_name, body, html_url, published_at, author,
+                    detected_at
+                )
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                "#,
+            )
+            .bind(entry.id.to_string())
+            .bind(entry.tracked_repository_id.to_string())
+            .bind(&entry.tag_name)
+            .bind(&entry.body)
+            .bind(&entry.html_url)
+            .bind(entry.published_at)
+            .bind(&entry.author)
+            .bind(entry.detected_at)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        let mut repositories: Vec<_> = entries.iter().map(|e| e.tracked_repository_id).collect();
+        repositories.sort();
+        repositories.dedup();
+        for tracked_repository_id in repositories {
+            sqlx::query(
+                r#"
+                DELETE FROM release_history
+                WHERE tracked_repository_id = ?1 AND id NOT IN (
+                    SELECT id FROM release_history
+                    WHERE tracked_repository_id = ?1
+                    ORDER BY detected_at DESC, id DESC
+                    LIMIT ?2
+                )
+                "#,
+            )
+            .bind(tracked_repository_id.to_string())
+            .bind(keep as i64)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn find_recent(
+        &self,
+        tracked_repository_id: &uuid::Uuid,
+        limit: usize,
+    ) -> Result<Vec<ReleaseHistoryEntry>, Box<dyn Error + Send + Sync>> {
+        let rows = sqlx::query_as::<_, ReleaseHistoryEntry>(
+            r#"
+            SELECT id, tracked_repository_id, tag_name, body, html_url, published_at, author,
+                detected_at
+            FROM release_history
+            WHERE tracked_repository_id = ?1
+            ORDER BY detected_at DESC, id DESC
+            LIMIT ?2
+            "#,
+        )
+        .bind(tracked_repository_id.to_string())
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows)
+    }
+
+    async fn velocity(
+        &self,
+        tracked_repository_id: &uuid::Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<ReleaseVelocity, Box<dyn Error + Send + Sync>> {
+        let velocity = sqlx::query_as::<_, ReleaseVelocity>(
+            r#"
+            SELECT COUNT(*) AS recorded,
+                COALESCE(SUM(released_at >= ?2), 0) AS last_30_days,
+                COALESCE(SUM(released_at >= ?3), 0) AS last_90_days,
+                MIN(released_at) AS first_release_at,
+                MAX(released_at) AS last_release_at
+            FROM (
+                SELECT COALESCE(published_at, detected_at) AS released_at
+                FROM release_history
+                WHERE tracked_repository_id = ?1
+            )
+            "#,
+        )
+        .bind(tracked_repository_id.to_string())
+        .bind(now - Duration::days(30))
+        .bind(now - Duration::days(90))
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(velocity)
+    }
+
+    async fn release_times_since(
+        &self,
+        tracked_repository_id: &uuid::Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<DateTime<Utc>>, Box<dyn Error + Send + Sync>> {
+        let times = sqlx::query_scalar::<_, DateTime<Utc>>(
+            r#"
+            SELECT released_at
+            FROM (
+                SELECT COALESCE(published_at, detected_at) AS released_at
+                FROM release_history
+                WHERE tracked_repository_id = ?1
+            )
+            WHERE released_at >= ?2
+            ORDER BY released_at
+            "#,
+        )
+        .bind(tracked_repository_id.to_string())
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(times)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracked_repositories::repository::{
+        SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+    };
+    use crate::tracked_repositories::{RepositoryUrl, TrackedRelease};
+    use sqlx::sqlite::SqlitePoolOptions;
+    use uuid::Uuid;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to create in-memory sqlite pool");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("failed to run migrations");
+
+        pool
+    }
+
+    async fn insert_tracked(pool: &SqlitePool, url: &str) -> TrackedRelease {
+        let mut tracked = TrackedRelease {
+            id: Uuid::now_v7(),
+            repository_name: "repo".to_string(),
+            repository_url: RepositoryUrl::new(url.to_string()).unwrap(),
+            chat_id: 1,
+            bot_id: String::new(),
+            default_branch: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        SqliteTrackedRepositoriesRepository::new(pool.clone())
+            .save(&mut tracked)
+            .await
+            .unwrap();
+        tracked
+    }
+
+    fn entry(tracked: &TrackedRelease, tag: &str, minutes_ago: i64) -> ReleaseHistoryEntry {
+        ReleaseHistoryEntry {
+            id: Uuid::now_v7(),
+            tracked_repository_id: tracked.id,
+            tag_name: tag.to_string(),
+            body: Some(format!("Notes for {tag}")),
+            html_url: Some(format!("https://github.com/owner/repo/releases/tag/{tag}")),
+            published_at: None,
+            author: Some("octocat".to_string()),
+            detected_at: Utc::now() - Duration::minutes(minutes_ago),
+        }
+    }
+
+    #[tokio::test]
+    async fn inserts_and_reads_newest_first() {
+        let pool = setup_pool().await;
+        let tracked = insert_tracked(&pool, "https://github.com/owner/repo").await;
+        let repo = SqliteReleaseHistoryRepository::new(pool);
+        let older = entry(&tracked, "v1.0.0", 10);
+        let newer = entry(&tracked, "v1.1.0", 0);
+
+        repo.save_all(&[older.clone(), newer.clone()], 10)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            repo.find_recent(&tracked.id, 10).await.unwrap(),
+            vec![newer, older]
+        );
+    }
+
+    #[tokio::test]
+    async fn prunes_to_retention_count_per_repository() {
+        let pool = setup_pool().await;
+        let first = insert_tracked(&pool, "https://github.com/owner/first").await;
+        let second = insert_tracked(&pool, "https://github.com/owner/second").await;
+        let repo = SqliteReleaseHistoryRepository::new(pool);
+
+        repo.save_all(
+            &[
+                entry(&first, "v1", 30),
+                entry(&first, "v2", 20),
+                entry(&second, "v9", 40),
+            ],
+            2,
+        )
+        .await
+        .unwrap();
+        repo.save_all(&[entry(&first, "v3", 0)], 2).await.unwrap();
+
+        let tags: Vec<_> = repo
+            .find_recent(&first.id, 10)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| e.tag_name)
+            .collect();
+        assert_eq!(tags, ["v3", "v2"]);
+        assert_eq!(repo.find_recent(&second.id, 10).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn counts_releases_per_window() {
+        let pool = setup_pool().await;
+        let tracked = insert_tracked(&pool, "https://github.com/owner/repo").await;
+        let other = insert_tracked(&pool, "https://github.com/owner/other").await;
+        let repo = SqliteReleaseHistoryRepository::new(pool);
+        let now = Utc::now();
+
+        let empty = repo.velocity(&tracked.id, now).await.unwrap();
+        assert_eq!(empty.recorded, 0);
+        assert_eq!(empty.last_90_days, 0);
+        assert_eq!(empty.first_release_at, None);
+
+        let mut published = entry(&tracked, "v1", 0);
+        published.published_at = Some(now - Duration::days(120));
+        repo.save_all(
+            &[
+                published,
+                entry(&tracked, "v2", 60 * 24 * 60),
+                entry(&tracked, "v3", 60 * 24 * 10),
+                entry(&tracked, "v4", 0),
+                entry(&other, "v9", 0),
+            ],
+            10,
+        )
+        .await
+        .unwrap();
+
+        let velocity = repo.velocity(&tracked.id, now).await.unwrap();
+        assert_eq!(velocity.recorded, 4);
+        assert_eq!(velocity.last_30_days, 2);
+        assert_eq!(velocity.last_90_days, 3);
+        assert_eq!(velocity.first_release_at, Some(now - Duration::days(120)));
+        let average = velocity.average_days_between().unwrap();
+        assert!((39.9..40.1).contains(&average), "{average}");
+    }
+
+    #[tokio::test]
+    async fn lists_release_times_since_a_date() {
+        let pool = setup_pool().await;
+        let tracked = insert_tracked(&pool, "https://github.com/owner/repo").await;
+        let repo = SqliteReleaseHistoryRepository::new(pool);
+        let now = Utc::now();
+
+        let mut published = entry(&tracked, "v1", 0);
+        published.published_at = Some(now - Duration::days(3));
+        let old = entry(&tracked, "v0", 60 * 24 * 100);
+        let recent = entry(&tracked, "v2", 60);
+        repo.save_all(&[published, old, recent.clone()], 10)
+            .await
+            .unwrap();
+
+        let times = repo
+            .release_times_since(&tracked.id, now - Duration::days(84))
+            .await
+            .unwrap();
+        assert_eq!(times, vec![now - Duration::days(3), recent.detected_at]);
+    }
+}
@@ -0,0 +1,453 @@
+use crate::db::retry_on_busy;
+use crate::tracked_repositories::TrackedRelease;
+use async_trait::async_trait;
+use sqlx::{self, sqlite::SqlitePool};
+use std::error::Error;
+
+/// How much is tracked across every bot and chat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::FromRow)]
+pub struct TrackingCounts {
+    pub repositories: i64,
+    pub chats: i64,
+}
+
+#[async_trait]
+pub trait TrackedRepositoriesRepository: Send + Sync {
+    async fn save(
+        &self,
+        tracked_release: &mut TrackedRelease,
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+    #[allow(dead_code)]
+    async fn find_all(&self) -> Result<Vec<TrackedRelease>, Box<dyn Error + Send + Sync>>;
+    /// Every repository served by the bot `bot_id`.
+    async fn find_all_for_bot(
+        &self,
+        bot_id: &str,
+    ) -> Result<Vec<TrackedRelease>, Box<dyn Error + Send + Sync>>;
+    /// The repositories the bot `bot_id` tracks for the chat, newest first.
+    async fn find_all_by_chat_id(
+        &self,
+        bot_id: &str,
+        chat_id: i64,
+    ) -> Result<Vec<TrackedRelease>, Box<dyn Error + Send + Sync>>;
+    /// The repositories every bot tracks for the chat, newest first.
+    #[cfg(feature = "rest-api")]
+    async fn find_all_in_chat(
+        &self,
+        chat_id: i64,
+    ) -> Result<Vec<TrackedRelease>, Box<dyn Error + Send + Sync>>;
+    /// Repositories the bot tracks for the chat whose name or URL contains `term`, ignoring ASCII case.
+    async fn find_by_chat_id_and_name_like(
+        &self,
+        bot_id: &str,
+        chat_id: i64,
+        term: &str,
+    ) -> Result<Vec<TrackedRelease>, Box<dyn Error + Send + Sync>>;
+    #[allow(dead_code)]
+    async fn find_by_id(
+        &self,
+        id: &str,
+    ) -> Result<Option<TrackedRelease>, Box<dyn Error + Send + Sync>>;
+    /// The row the bot `bot_id` keeps for the chat and `repository_url`.
+    async fn find_by_chat_id_and_repository_url(
+        &self,
+        bot_id: &str,
+        chat_id: i64,
+        repository_url: &str,
+    ) -> Result<Option<TrackedRelease>, Box<dyn Error + Send + Sync>>;
+    /// Every row for `repository_url`, across bots and chats.
+    async fn find_all_by_repository_url(
+        &self,
+        repository_url: &str,
+    ) -> Result<Vec<TrackedRelease>, Box<dyn Error + Send + Sync>>;
+    /// Every row for the GitHub repository `owner/repo`, across bots and chats, however its
+    /// URL was written: GitHub names are case-insensitive and may end in `.git` or `/`.
+    #[cfg(feature = "rest-api")]
+    async fn find_all_by_github_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<TrackedRelease>, Box<dyn Error + Send + Sync>>;
+    /// The bot's repository in the chat with the short name `alias`.
+    async fn find_by_chat_id_and_alias(
+        &self,
+        bot_id: &str,
+        chat_id: i64,
+        alias: &str,
+    ) -> Result<Option<TrackedRelease>, Box<dyn Error + Send + Sync>>;
+    /// Sets or clears the repository's short name; a name another repository of the chat
+    /// already has fails with a unique violation.
+    async fn set_alias(
+        &self,
+        id: &uuid::Uuid,
+        alias: Option<&str>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+    /// Records the repository's default branch without touching anything else.
+    async fn update_default_branch(
+        &self,
+        id: &uuid::Uuid,
+        default_branch: &str,
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+    /// Counts tracked repositories and the chats tracking them without loading any rows.
+    async fn count_tracking(&self) -> Result<TrackingCounts, Box<dyn Error + Send + Sync>>;
+    #[allow(dead_code)]
+    async fn delete(&self, id: &str) -> Result<(), Box<dyn Error + Send + Sync>>;
+    /// Deletes every listed repository in a single transaction, returning how many existed.
+    async fn delete_all(&self, ids: &[uuid::Uuid]) -> Result<u64, Box<dyn Error + Send + Sync>>;
+}
+
+/// Escapes the `LIKE` wildcards in `term` so it only matches literally (with `ESCAPE '\'`).
+fn escape_like(term: &str) -> String {
+    let mut escaped = String::with_capacity(term.len());
+    for c in term.chars() {
+        if matches!(c, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+pub struct SqliteTrackedRepositoriesRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteTrackedRepositoriesRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TrackedRepositoriesRepository for SqliteTrackedRepositoriesRepository {
+    async fn save(
+        &self,
+        tracked_release: &mut TrackedRelease,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let tracked_release = &*tracked_release;
+        retry_on_busy(|| {
+            sqlx::query(
+                r#"
+                INSERT INTO tracked_repositories (
+                    id, repository_name, repository_url, chat_id, bot_id, default_branch,
+                    created_at, updated_at
+                )
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                ON CONFLICT(id) DO UPDATE SET
+                    repository_name = excluded.repository_name,
+                    repository_url = excluded.repository_url,
+                    chat_id = excluded.chat_id,
+                    default_branch = excluded.default_branch,
+                    updated_at = excluded.updated_at,
+                    -- A row stays with the bot that created it, and an alias belongs
+                    -- to the chat that set it
+                    alias = CASE
+                        WHEN tracked_repositories.chat_id = excluded.chat_id
+                        THEN tracked_repositories.alias
+                    END
+                "#,
+            )
+            .bind(tracked_release.id.to_string())
+            .bind(&tracked_release.repository_name)
+            .bind(tracked_release.repository_url.url())
+            .bind(tracked_release.chat_id)
+            .bind(&tracked_release.bot_id)
+            .bind(&tracked_release.default_branch)
+            .bind(tracked_release.created_at)
+            .bind(tracked_release.updated_at)
+            .execute(&self.pool)
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_all(&self) -> Result<Vec<TrackedRelease>, Box<dyn Error + Send + Sync>> {
+        let releases = sqlx::query_as::<_, TrackedRelease>(
+            r#"
+            SELECT id, repository_name, repository_url, chat_id, bot_id, default_branch, created_at,
+                updated_at
+            FROM tracked_repositories
+            ORDER BY created_at DESC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(releases)
+    }
+
+    async fn find_all_for_bot(
+        &self,
+        bot_id: &str,
+    ) -> Result<Vec<TrackedRelease>, Box<dyn Error + Send + Sync>> {
+        let releases = sqlx::query_as::<_, TrackedRelease>(
+            r#"
+            SELECT id, repository_name, repository_url, chat_id, bot_id, default_branch, created_at,
+                updated_at
+            FROM tracked_repositories
+            WHERE bot_id = ?1
+            ORDER BY created_at DESC, id
+            "#,
+        )
+        .bind(bot_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(releases)
+    }
+
+    async fn find_all_by_chat_id(
+        &self,
+        bot_id: &str,
+        chat_id: i64,
+    ) -> Result<Vec<TrackedRelease>, Box<dyn Error + Send + Sync>> {
+        let releases = sqlx::query_as::<_, TrackedRelease>(
+            r#"
+            SELECT id, repository_name, repository_url, chat_id, bot_id, default_branch, created_at,
+                updated_at
+            FROM tracked_repositories
+            WHERE bot_id = ?1 AND chat_id = ?2
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(bot_id)
+        .bind(chat_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(releases)
+    }
+
+    #[cfg(feature = "rest-api")]
+    async fn find_all_in_chat(
+        &self,
+        chat_id: i64,
+    ) -> Result<Vec<TrackedRelease>, Box<dyn Error + Send + Sync>> {
+        let releases = sqlx::query_as::<_, TrackedRelease>(
+            r#"
+            SELECT id, repository_name, repository_url, chat_id, bot_id, default_branch, created_at,
+                updated_at
+            FROM tracked_repositories
+            WHERE chat_id = ?1
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(chat_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(releases)
+    }
+
+    async fn find_by_chat_id_and_name_like(
+        &self,
+        bot_id: &str,
+        chat_id: i64,
+        term: &str,
+    ) -> Result<Vec<TrackedRelease>, Box<dyn Error + Send + Sync>> {
+        let pattern = format!("%{}%", escape_like(term));
+        let releases = sqlx::query_as::<_, TrackedRelease>(
+            r#"
+            SELECT id, repository_name, repository_url, chat_id, bot_id, default_branch, created_at,
+                updated_at
+            FROM tracked_repositories
+            WHERE bot_id = ?1 AND chat_id = ?2
+                AND (repository_name LIKE ?3 ESCAPE '\' OR repository_url LIKE ?3 ESCAPE '\')
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(bot_id)
+        .bind(chat_id)
+        .bind(pattern)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(releases)
+    }
+
+    async fn find_by_id(
+        &self,
+        id: &str,
+    ) -> Result<Option<TrackedRelease>, Box<dyn Error + Send + Sync>> {
+        let rec = sqlx::query_as::<_, TrackedRelease>(
+            r#"
+            SELECT id, repository_name, repository_url, chat_id, bot_id, default_branch, created_at,
+                updated_at
+            FROM tracked_repositories WHERE id = ?1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(rec)
+    }
+
+    async fn find_by_chat_id_and_repository_url(
+        &self,
+        bot_id: &str,
+        chat_id: i64,
+        repository_url: &str,
+    ) -> Result<Option<TrackedRelease>, Box<dyn Error + Send + Sync>> {
+        let rec = sqlx::query_as::<_, TrackedRelease>(
+            r#"
+            SELECT id, repository_name, repository_url, chat_id, bot_id, default_branch, created_at,
+                updated_at
+            FROM tracked_repositories WHERE bot_id = ?1 AND chat_id = ?2 AND repository_url = ?3
+            ORDER BY created_at, id
+            "#,
+        )
+        .bind(bot_id)
+        .bind(chat_id)
+        .bind(repository_url)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(rec)
+    }
+
+    async fn find_all_by_repository_url(
+        &self,
+        repository_url: &str,
+    ) -> Result<Vec<TrackedRelease>, Box<dyn Error + Send + Sync>> {
+        let releases = sqlx::query_as::<_, TrackedRelease>(
+            r#"
+            SELECT id, repository_name, repository_url, chat_id, bot_id, default_branch, created_at,
+                updated_at
+            FROM tracked_repositories WHERE repository_url = ?1
+            ORDER BY created_at, id
+            "#,
+        )
+        .bind(repository_url)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(releases)
+    }
+
+    #[cfg(feature = "rest-api")]
+    async fn find_all_by_github_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<TrackedRelease>, Box<dyn Error + Send + Sync>> {
+        // LIKE compares ASCII case-insensitively; the prefix also matches longer names
+        // like `repo-cli`, which the exact comparison below drops
+        let prefix = format!(
+            "https://github.com/{}/{}%",
+            escape_like(owner),
+            escape_like(repo)
+        );
+        let candidates = sqlx::query_as::<_, TrackedRelease>(
+            r#"
+            SELECT id, repository_name, repository_url, chat_id, bot_id, default_branch, created_at,
+                updated_at
+            FROM tracked_repositories WHERE repository_url LIKE ?1 ESCAPE '\'
+            ORDER BY created_at, id
+            "#,
+        )
+        .bind(prefix)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(candidates
+            .into_iter()
+            .filter(|r| {
+                r.repository_url.owner_and_repo().is_some_and(|(o, n)| {
+                    o.eq_ignore_ascii_case(owner) && n.eq_ignore_ascii_case(repo)
+                })
+            })
+            .collect())
+    }
+
+    async fn find_by_chat_id_and_alias(
+        &self,
+        bot_id: &str,
+        chat_id: i64,
+        alias: &str,
+    ) -> Result<Option<TrackedRelease>, Box<dyn Error + Send + Sync>> {
+        let rec = sqlx::query_as::<_, TrackedRelease>(
+            r#"
+            SELECT id, repository_name, repository_url, chat_id, bot_id, default_branch, created_at,
+                updated_at
+            FROM tracked_repositories WHERE bot_id = ?1 AND chat_id = ?2 AND alias = ?3
+            "#,
+        )
+        .bind(bot_id)
+        .bind(chat_id)
+        .bind(alias)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(rec)
+    }
+
+    async fn set_alias(
+        &self,
+        id: &uuid::Uuid,
+        alias: Option<&str>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        sqlx::query("UPDATE tracked_repositories SET alias = ?1 WHERE id = ?2")
+            .bind(alias)
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn update_default_branch(
+        &self,
+        id: &uuid::Uuid,
+        default_branch: &str,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        sqlx::query("UPDATE tracked_repositories SET default_branch = ?1 WHERE id = ?2")
+            .bind(default_branch)
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn count_tracking(&self) -> Result<TrackingCounts, Box<dyn Error + Send + Sync>> {
+        let counts = sqlx::query_as::<_, TrackingCounts>(
+            r#"
+            SELECT COUNT(*) AS repositories, COUNT(DISTINCT chat_id) AS chats
+            FROM tracked_repositories
+            "#,
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(counts)
+    }
+
+    async fn delete(&self, id: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        sqlx::query("DELETE FROM tracked_repositories WHERE id = ?1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_all(&self, ids: &[uuid::Uuid]) -> Result<u64, Box<dyn Error + Send + Sync>> {
+        let mut tx = self.pool.begin().await?;
+        let mut deleted = 0;
+        for id in ids {
+            deleted += sqlx::query("DELETE FROM tracked_repositories WHERE id = ?1")
+                .bind(id.to_string())
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+        }
+        tx.commit().await?;
+        Ok(deleted)
+    }
+}
+
+// Cached releases repository moved under tracked_repositories_releases
+
+#[cfg(test)]
+mod tests;
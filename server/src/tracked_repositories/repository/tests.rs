@@ -0,0 +1,430 @@
+use super::*;
+use crate::tracked_repositories::{RepositoryUrl, TrackedRelease};
+use chrono::{Duration, Utc};
+use sqlx::sqlite::SqlitePoolOptions;
+use uuid::Uuid;
+
+async fn setup_repo() -> SqliteTrackedRepositoriesRepository {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("failed to create in-memory sqlite pool");
+
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("failed to run migrations");
+
+    SqliteTrackedRepositoriesRepository::new(pool)
+}
+
+fn make_release(
+    repository_name: &str,
+    repository_url: &str,
+    chat_id: i64,
+    created_at: chrono::DateTime<Utc>,
+    updated_at: chrono::DateTime<Utc>,
+) -> TrackedRelease {
+    TrackedRelease {
+        id: Uuid::now_v7(),
+        repository_name: repository_name.to_string(),
+        repository_url: RepositoryUrl::new(repository_url.to_string()).expect("valid github url"),
+        chat_id,
+        bot_id: String::new(),
+        default_branch: None,
+        created_at,
+        updated_at,
+    }
+}
+
+#[tokio::test]
+async fn save_and_find_by_id_roundtrip() {
+    let repo = setup_repo().await;
+    let now = Utc::now();
+    let mut rel = make_release(
+        "repo-one",
+        "https://github.com/owner/repo-one",
+        42,
+        now,
+        now,
+    );
+
+    TrackedRepositoriesRepository::save(&repo, &mut rel)
+        .await
+        .expect("save should succeed");
+
+    let fetched = TrackedRepositoriesRepository::find_by_id(&repo, &rel.id.to_string())
+        .await
+        .expect("find_by_id should succeed")
+        .expect("record should exist");
+
+    assert_eq!(fetched.id, rel.id);
+    assert_eq!(fetched.repository_name, "repo-one");
+    assert_eq!(
+        fetched.repository_url.url(),
+        "https://github.com/owner/repo-one"
+    );
+    assert_eq!(fetched.chat_id, 42);
+}
+
+#[tokio::test]
+async fn find_by_chat_id_and_repository_url() {
+    let repo = setup_repo().await;
+    let now = Utc::now();
+    let url = "https://github.com/owner/repo-two";
+    let mut rel = make_release("repo-two", url, 7, now, now);
+
+    TrackedRepositoriesRepository::save(&repo, &mut rel)
+        .await
+        .expect("save should succeed");
+
+    let fetched =
+        TrackedRepositoriesRepository::find_by_chat_id_and_repository_url(&repo, "", 7, url)
+            .await
+            .expect("find_by_chat_id_and_repository_url should succeed")
+            .expect("record should exist");
+
+    assert_eq!(fetched.id, rel.id);
+    assert_eq!(fetched.repository_name, "repo-two");
+    assert_eq!(fetched.repository_url.url(), url);
+    assert!(
+        TrackedRepositoriesRepository::find_by_chat_id_and_repository_url(&repo, "staging", 7, url)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        TrackedRepositoriesRepository::find_by_chat_id_and_repository_url(&repo, "", 8, url)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn bots_track_the_same_url_separately() {
+    let repo = setup_repo().await;
+    let now = Utc::now();
+    let url = "https://github.com/owner/shared";
+    let mut prod = make_release("shared", url, 1, now, now);
+    let mut staging = make_release("shared", url, 2, now, now);
+    staging.bot_id = "staging".to_string();
+
+    TrackedRepositoriesRepository::save(&repo, &mut prod)
+        .await
+        .unwrap();
+    TrackedRepositoriesRepository::save(&repo, &mut staging)
+        .await
+        .expect("another bot may track the same URL");
+
+    // Saving never hands a row to another bot
+    prod.bot_id = "staging".to_string();
+    TrackedRepositoriesRepository::save(&repo, &mut prod)
+        .await
+        .unwrap();
+    let rows = TrackedRepositoriesRepository::find_all_by_repository_url(&repo, url)
+        .await
+        .unwrap();
+    let bots: Vec<_> = rows
+        .iter()
+        .map(|r| (r.chat_id, r.bot_id.as_str()))
+        .collect();
+    assert_eq!(bots.len(), 2);
+    assert!(bots.contains(&(1, "")));
+    assert!(bots.contains(&(2, "staging")));
+
+    // The same chat cannot track a URL twice through one bot
+    let mut duplicate = make_release("shared", url, 1, now, now);
+    assert!(
+        TrackedRepositoriesRepository::save(&repo, &mut duplicate)
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn find_all_and_by_chat_id() {
+    let repo = setup_repo().await;
+    let now = Utc::now();
+    let earlier = now - Duration::minutes(5);
+
+    let mut a = make_release(
+        "alpha",
+        "https://github.com/owner/alpha",
+        100,
+        earlier,
+        earlier,
+    );
+    let mut b = make_release("beta", "https://github.com/owner/beta", 200, now, now);
+
+    TrackedRepositoriesRepository::save(&repo, &mut a)
+        .await
+        .unwrap();
+    TrackedRepositoriesRepository::save(&repo, &mut b)
+        .await
+        .unwrap();
+
+    // find_all ordered by created_at DESC -> b then a
+    let all = TrackedRepositoriesRepository::find_all(&repo)
+        .await
+        .unwrap();
+    assert_eq!(all.len(), 2);
+    assert_eq!(all[0].id, b.id);
+    assert_eq!(all[1].id, a.id);
+
+    // by chat id
+    let only_100 = TrackedRepositoriesRepository::find_all_by_chat_id(&repo, "", 100)
+        .await
+        .unwrap();
+    assert_eq!(only_100.len(), 1);
+    assert_eq!(only_100[0].id, a.id);
+}
+
+#[tokio::test]
+async fn count_tracking_counts_repositories_and_distinct_chats() {
+    let repo = setup_repo().await;
+    assert_eq!(
+        repo.count_tracking().await.unwrap(),
+        TrackingCounts {
+            repositories: 0,
+            chats: 0
+        }
+    );
+
+    let now = Utc::now();
+    for (name, chat_id) in [("a", 1), ("b", 1), ("c", 2)] {
+        let url = format!("https://github.com/owner/{name}");
+        let mut rel = make_release(name, &url, chat_id, now, now);
+        repo.save(&mut rel).await.unwrap();
+    }
+
+    assert_eq!(
+        repo.count_tracking().await.unwrap(),
+        TrackingCounts {
+            repositories: 3,
+            chats: 2
+        }
+    );
+}
+
+#[tokio::test]
+async fn save_updates_on_conflict_by_id() {
+    let repo = setup_repo().await;
+    let now = Utc::now();
+    let later = now + Duration::minutes(1);
+    let mut rel = make_release("gamma", "https://github.com/owner/gamma", 1, now, now);
+
+    TrackedRepositoriesRepository::save(&repo, &mut rel)
+        .await
+        .unwrap();
+
+    // modify fields and save again
+    rel.repository_name = "gamma-renamed".to_string();
+    rel.chat_id = 2;
+    rel.repository_url =
+        RepositoryUrl::new("https://github.com/owner/gamma-renamed".to_string()).unwrap();
+    rel.default_branch = Some("main".to_string());
+    rel.updated_at = later;
+
+    TrackedRepositoriesRepository::save(&repo, &mut rel)
+        .await
+        .unwrap();
+
+    let fetched = TrackedRepositoriesRepository::find_by_id(&repo, &rel.id.to_string())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(fetched.repository_name, "gamma-renamed");
+    assert_eq!(fetched.chat_id, 2);
+    assert_eq!(fetched.default_branch.as_deref(), Some("main"));
+    assert_eq!(
+        fetched.repository_url.url(),
+        "https://github.com/owner/gamma-renamed"
+    );
+    assert_eq!(fetched.updated_at, later);
+}
+
+#[tokio::test]
+async fn delete_removes_record() {
+    let repo = setup_repo().await;
+    let now = Utc::now();
+    let mut rel = make_release("delta", "https://github.com/owner/delta", 99, now, now);
+
+    TrackedRepositoriesRepository::save(&repo, &mut rel)
+        .await
+        .unwrap();
+
+    TrackedRepositoriesRepository::delete(&repo, &rel.id.to_string())
+        .await
+        .expect("delete should succeed");
+
+    let fetched = TrackedRepositoriesRepository::find_by_id(&repo, &rel.id.to_string())
+        .await
+        .unwrap();
+    assert!(fetched.is_none());
+}
+
+#[tokio::test]
+async fn delete_all_removes_every_listed_record() {
+    let repo = setup_repo().await;
+    let now = Utc::now();
+    let mut ids = Vec::new();
+    for name in ["one", "two", "three"] {
+        let url = format!("https://github.com/owner/{name}");
+        let mut rel = make_release(name, &url, 5, now, now);
+        TrackedRepositoriesRepository::save(&repo, &mut rel)
+            .await
+            .unwrap();
+        ids.push(rel.id);
+    }
+
+    let deleted = repo.delete_all(&ids[..2]).await.unwrap();
+    assert_eq!(deleted, 2);
+
+    let remaining = repo.find_all_by_chat_id("", 5).await.unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].id, ids[2]);
+}
+
+#[tokio::test]
+async fn search_matches_name_or_url_case_insensitively() {
+    let repo = setup_repo().await;
+    let now = Utc::now();
+    for (name, url, chat_id) in [
+        ("Tokio", "https://github.com/tokio-rs/tokio", 1),
+        ("web", "https://github.com/SergioBenitez/Rocket", 1),
+        ("Tokio elsewhere", "https://github.com/tokio-rs/axum", 2),
+    ] {
+        let mut rel = make_release(name, url, chat_id, now, now);
+        repo.save(&mut rel).await.unwrap();
+    }
+
+    let found = repo
+        .find_by_chat_id_and_name_like("", 1, "TOKIO")
+        .await
+        .unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].repository_name, "Tokio");
+
+    let found = repo
+        .find_by_chat_id_and_name_like("", 1, "rocket")
+        .await
+        .unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].repository_name, "web");
+}
+
+#[cfg(feature = "rest-api")]
+#[tokio::test]
+async fn github_repository_matches_however_its_url_was_written() {
+    let repo = setup_repo().await;
+    let now = Utc::now();
+    for (i, url) in [
+        "https://github.com/Owner/Repo",
+        "https://github.com/owner/repo.git",
+        "https://github.com/owner/repo/",
+        "https://github.com/owner/repo-cli",
+        "https://github.com/other/repo",
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let mut rel = make_release("repo", url, i as i64, now, now);
+        TrackedRepositoriesRepository::save(&repo, &mut rel)
+            .await
+            .expect("save should succeed");
+    }
+
+    let found = repo
+        .find_all_by_github_repository("owner", "repo")
+        .await
+        .expect("query should succeed");
+
+    let chats: Vec<i64> = found.iter().map(|r| r.chat_id).collect();
+    assert_eq!(chats, vec![0, 1, 2]);
+}
+
+#[tokio::test]
+async fn search_escapes_like_wildcards() {
+    let repo = setup_repo().await;
+    let now = Utc::now();
+    for (name, url) in [
+        ("my_lib", "https://github.com/owner/one"),
+        ("mylib", "https://github.com/owner/two"),
+        ("100% safe", "https://github.com/owner/three"),
+        ("100 safe", "https://github.com/owner/four"),
+    ] {
+        let mut rel = make_release(name, url, 1, now, now);
+        repo.save(&mut rel).await.unwrap();
+    }
+
+    let found = repo
+        .find_by_chat_id_and_name_like("", 1, "y_l")
+        .await
+        .unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].repository_name, "my_lib");
+
+    let found = repo
+        .find_by_chat_id_and_name_like("", 1, "0%")
+        .await
+        .unwrap();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].repository_name, "100% safe");
+
+    assert!(
+        repo.find_by_chat_id_and_name_like("", 1, "%")
+            .await
+            .unwrap()
+            .len()
+            == 1
+    );
+}
+
+#[tokio::test]
+async fn aliases_are_unique_within_a_chat() {
+    let repo = setup_repo().await;
+    let now = Utc::now();
+    let mut one = make_release("one", "https://github.com/owner/one", 1, now, now);
+    let mut two = make_release("two", "https://github.com/owner/two", 1, now, now);
+    let mut other = make_release("other", "https://github.com/owner/other", 2, now, now);
+    for r in [&mut one, &mut two, &mut other] {
+        repo.save(r).await.unwrap();
+    }
+
+    repo.set_alias(&one.id, Some("one")).await.unwrap();
+    repo.set_alias(&other.id, Some("one")).await.unwrap();
+    let err = repo.set_alias(&two.id, Some("one")).await.unwrap_err();
+    assert!(crate::db::is_unique_violation(&*err));
+
+    let found = repo.find_by_chat_id_and_alias("", 1, "one").await.unwrap();
+    assert_eq!(found.map(|r| r.id), Some(one.id));
+    assert_eq!(
+        repo.find_by_chat_id_and_alias("", 1, "two")
+            .await
+            .unwrap()
+            .map(|r| r.id),
+        None
+    );
+
+    // Saving keeps the alias, moving to another chat drops it
+    repo.save(&mut one).await.unwrap();
+    assert!(
+        repo.find_by_chat_id_and_alias("", 1, "one")
+            .await
+            .unwrap()
+            .is_some()
+    );
+    one.chat_id = 2;
+    repo.save(&mut one).await.unwrap();
+    assert!(
+        repo.find_by_chat_id_and_alias("", 1, "one")
+            .await
+            .unwrap()
+            .is_none()
+    );
+    let found = repo.find_by_chat_id_and_alias("", 2, "one").await.unwrap();
+    assert_eq!(found.map(|r| r.id), Some(other.id));
+}
@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+
+use crate::chat_settings::MessageFormat;
+
+/// Longest display name accepted for a mention by user id.
+const MAX_MENTION_NAME_CHARS: usize = 64;
+
+/// Who a repository's release notifications ping in a group.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Mention {
+    /// A public `@username`, stored without the `@`.
+    Username(String),
+    /// A user without a username, linked by id and shown as `name`.
+    UserId { id: i64, name: String },
+}
+
+impl Mention {
+    /// Reads `@username`, `<user_id>` or `<user_id> <name>`. Usernames follow Telegram's
+    /// rules: 5 to 32 letters, digits or underscores, starting with a letter.
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let input = input.trim();
+        if let Some(username) = input.strip_prefix('@') {
+            let valid = (5..=32).contains(&username.len())
+                && username.starts_with(|c: char| c.is_ascii_alphabetic())
+                && username
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if !valid {
+                return Err(format!("{input} is not a valid Telegram username."));
+            }
+            return Ok(Self::Username(username.to_string()));
+        }
+
+        let (id, name) = match input.split_once(char::is_whitespace) {
+            Some((id, name)) => (id, name.trim()),
+            None => (input, ""),
+        };
+        let id: i64 = id
+            .parse()
+            .ok()
+            .filter(|id| *id > 0)
+            .ok_or_else(|| "Please give an @username or a numeric user id.".to_string())?;
+        if name.chars().count() > MAX_MENTION_NAME_CHARS {
+            return Err(format!(
+                "Please keep the name under {MAX_MENTION_NAME_CHARS} characters."
+            ));
+        }
+        let name = if name.is_empty() {
+            id.to_string()
+        } else {
+            name.to_string()
+        };
+        Ok(Self::UserId { id, name })
+    }
+
+    /// The form stored in the database, which `parse` reads back.
+    pub fn as_stored(&self) -> String {
+        match self {
+            Self::Username(username) => format!("@{username}"),
+            Self::UserId { id, name } => format!("{id} {name}"),
+        }
+    }
+
+    /// The mention as markup: usernames are linked by Telegram itself, user ids through
+    /// a `tg://user` link.
+    pub fn render(&self, format: MessageFormat) -> String {
+        match self {
+            Self::Username(username) => format.escape(&format!("@{username}")).into_owned(),
+            Self::UserId { id, name } => format.link(&format!("tg://user?id={id}"), name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_usernames_and_user_ids() {
+        assert_eq!(
+            Mention::parse("@release_bot"),
+            Ok(Mention::Username("release_bot".to_string()))
+        );
+        assert_eq!(
+            Mention::parse("12345 Ada Lovelace"),
+            Ok(Mention::UserId {
+                id: 12345,
+                name: "Ada Lovelace".to_string()
+            })
+        );
+        assert_eq!(
+            Mention::parse("12345").map(|m| m.as_stored()),
+            Ok("12345 12345".to_string())
+        );
+        for invalid in ["@abc", "@1abcdef", "@with-dash", "-5", "someone", ""] {
+            assert!(Mention::parse(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn renders_escaped_mentions() {
+        let mention = Mention::parse("42 <Ops & Co>").unwrap();
+        assert_eq!(
+            mention.render(MessageFormat::Html),
+            "<a href=\"tg://user?id=42\">&lt;Ops &amp; Co&gt;</a>"
+        );
+        assert_eq!(
+            mention.render(MessageFormat::MarkdownV2),
+            "[<Ops & Co\\>](tg://user?id=42)"
+        );
+        assert_eq!(
+            Mention::parse("@release_bot")
+                .unwrap()
+                .render(MessageFormat::MarkdownV2),
+            "@release\\_bot"
+        );
+    }
+}
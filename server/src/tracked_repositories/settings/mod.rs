@@ -0,0 +1,147 @@
+mod mention;
+pub mod repository;
+pub mod tag_capture;
+
+pub use mention::Mention;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{FromRow, Row};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositorySettings {
+    pub tracked_repository_id: Uuid,
+    /// Prereleases of the same base version notified within this many seconds of each
+    /// other are collapsed into the first notification.
+    pub prerelease_collapse_secs: Option<i64>,
+    pub last_prerelease_base: Option<String>,
+    pub last_prerelease_notified_at: Option<DateTime<Utc>>,
+    /// Notifications are held back until this time.
+    pub snoozed_until: Option<DateTime<Utc>>,
+    /// The newest tag detected while snoozed, announced once the snooze ends.
+    pub snooze_missed_tag: Option<String>,
+    /// Follow the newest git tag and ignore GitHub Releases entirely.
+    pub tags_only: bool,
+    /// Any change of tag notifies, even `1.2.3` becoming `v1.2.3`.
+    pub exact_tags: bool,
+    /// The repository is still polled, but no notifications are sent for it.
+    pub muted: bool,
+    /// Follow new discussions in this category (name or slug) instead of releases.
+    pub discussion_category: Option<String>,
+    /// Number of the newest discussion seen in `discussion_category`.
+    pub last_discussion_number: Option<i64>,
+    /// New releases notify only once they are at least this many seconds old.
+    pub min_release_age_secs: Option<i64>,
+    /// The newest tag held back for being too young, and when the poller first saw it.
+    pub young_release_tag: Option<String>,
+    pub young_release_seen_at: Option<DateTime<Utc>>,
+    /// Follow the default branch's commit count instead of releases, announcing every
+    /// multiple of this many commits.
+    pub commit_milestone: Option<i64>,
+    /// The highest commit milestone announced, or already passed when milestones were
+    /// turned on.
+    pub last_notified_count: Option<i64>,
+    /// Releases and tags matching this glob are skipped, falling back to the newest one
+    /// that does not match.
+    pub tag_ignore: Option<String>,
+    /// Only releases and tags starting with this prefix are followed, like `pkg-a/` for one
+    /// component of a monorepo. Notifications show the tag without it.
+    pub tag_prefix: Option<String>,
+    /// Only tags matching this regex are followed, and the version its first capture
+    /// group yields is what notifications show and what counts as a new release.
+    pub tag_capture: Option<String>,
+    /// At most one release notification per this many seconds; releases found in between
+    /// are held and only the newest is announced once the window ends.
+    pub throttle_window_secs: Option<i64>,
+    /// When the last release notification was queued, for `throttle_window_secs`.
+    pub last_notified_at: Option<DateTime<Utc>>,
+    /// Telegram user mentioned at the start of each release notification.
+    pub mention: Option<Mention>,
+    /// Since when GitHub answers 404 for the repository itself: it was deleted or made
+    /// private. The chat was told once.
+    pub inaccessible_since: Option<DateTime<Utc>>,
+    /// Requests for the repository may take this many seconds instead of
+    /// `GITHUB_REQUEST_TIMEOUT_SECS`, for slow Enterprise instances.
+    pub request_timeout_secs: Option<i64>,
+    /// Releases notify right away, whatever would mute, snooze or hold them back.
+    pub critical: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RepositorySettings {
+    /// Settings used for repositories that never changed anything.
+    pub fn default_for(tracked_repository_id: Uuid) -> Self {
+        Self {
+            tracked_repository_id,
+            prerelease_collapse_secs: None,
+            last_prerelease_base: None,
+            last_prerelease_notified_at: None,
+            snoozed_until: None,
+            snooze_missed_tag: None,
+            tags_only: false,
+            exact_tags: false,
+            muted: false,
+            discussion_category: None,
+            last_discussion_number: None,
+            min_release_age_secs: None,
+            young_release_tag: None,
+            young_release_seen_at: None,
+            commit_milestone: None,
+            last_notified_count: None,
+            tag_ignore: None,
+            tag_prefix: None,
+            tag_capture: None,
+            throttle_window_secs: None,
+            last_notified_at: None,
+            mention: None,
+            inaccessible_since: None,
+            request_timeout_secs: None,
+            critical: false,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+impl FromRow<'_, SqliteRow> for RepositorySettings {
+    fn from_row(row: &SqliteRow) -> Result<Self, sqlx::Error> {
+        let tracked_repository_id_str: String = row.try_get("tracked_repository_id")?;
+        let tracked_repository_id = Uuid::parse_str(&tracked_repository_id_str)
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let mention: Option<String> = row.try_get("mention")?;
+        let mention = mention
+            .map(|m| Mention::parse(&m))
+            .transpose()
+            .map_err(|e| sqlx::Error::Decode(e.into()))?;
+
+        Ok(Self {
+            tracked_repository_id,
+            prerelease_collapse_secs: row.try_get("prerelease_collapse_secs")?,
+            last_prerelease_base: row.try_get("last_prerelease_base")?,
+            last_prerelease_notified_at: row.try_get("last_prerelease_notified_at")?,
+            snoozed_until: row.try_get("snoozed_until")?,
+            snooze_missed_tag: row.try_get("snooze_missed_tag")?,
+            tags_only: row.try_get("tags_only")?,
+            exact_tags: row.try_get("exact_tags")?,
+            muted: row.try_get("muted")?,
+            discussion_category: row.try_get("discussion_category")?,
+            last_discussion_number: row.try_get("last_discussion_number")?,
+            min_release_age_secs: row.try_get("min_release_age_secs")?,
+            young_release_tag: row.try_get("young_release_tag")?,
+            young_release_seen_at: row.try_get("young_release_seen_at")?,
+            commit_milestone: row.try_get("commit_milestone")?,
+            last_notified_count: row.try_get("last_notified_count")?,
+            tag_ignore: row.try_get("tag_ignore")?,
+            tag_prefix: row.try_get("tag_prefix")?,
+            tag_capture: row.try_get("tag_capture")?,
+            throttle_window_secs: row.try_get("throttle_window_secs")?,
+            last_notified_at: row.try_get("last_notified_at")?,
+            mention,
+            inaccessible_since: row.try_get("inaccessible_since")?,
+            request_timeout_secs: row.try_get("request_timeout_secs")?,
+            critical: row.try_get("critical")?,
+            updated_at: row.try_get("updated_at")?,
+        })
+    }
+}
@@ -0,0 +1,240 @@
+use crate::tracked_repositories::settings::{Mention, RepositorySettings};
+use async_trait::async_trait;
+use sqlx::{self, sqlite::SqlitePool};
+use std::error::Error;
+
+#[async_trait]
+pub trait RepositorySettingsRepository: Send + Sync {
+    async fn save(&self, settings: &RepositorySettings)
+    -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn find_by_tracked_repository_id(
+        &self,
+        id: &uuid::Uuid,
+    ) -> Result<Option<RepositorySettings>, Box<dyn Error + Send + Sync>>;
+
+    /// Returns the stored settings for the repository, or the defaults if none were saved.
+    async fn find_or_default(
+        &self,
+        id: &uuid::Uuid,
+    ) -> Result<RepositorySettings, Box<dyn Error + Send + Sync>> {
+        Ok(self
+            .find_by_tracked_repository_id(id)
+            .await?
+            .unwrap_or_else(|| RepositorySettings::default_for(*id)))
+    }
+}
+
+pub struct SqliteRepositorySettingsRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteRepositorySettingsRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RepositorySettingsRepository for SqliteRepositorySettingsRepository {
+    async fn save(
+        &self,
+        settings: &RepositorySettings,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        sqlx::query(
+            r#"
+            INSERT INTO tracked_repository_settings (
+                tracked_repository_id, prerelease_collapse_secs, last_prerelease_base,
+                last_prerelease_notified_at, snoozed_until, snooze_missed_tag, tags_only,
+                muted, discussion_category, last_discussion_number, exact_tags, updated_at,
+                min_release_age_secs, young_release_tag, young_release_seen_at,
+                commit_milestone, last_notified_count, tag_ignore, tag_prefix,
+                throttle_window_secs, last_notified_at, mention, inaccessible_since,
+                request_timeout_secs, critical, tag_capture
+            )
+            VALUES (
+                ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17,
+                ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26
+            )
+            ON CONFLICT(tracked_repository_id) DO UPDATE SET
+                prerelease_collapse_secs = excluded.prerelease_collapse_secs,
+                last_prerelease_base = excluded.last_prerelease_base,
+                last_prerelease_notified_at = excluded.last_prerelease_notified_at,
+                snoozed_until = excluded.snoozed_until,
+                snooze_missed_tag = excluded.snooze_missed_tag,
+                tags_only = excluded.tags_only,
+                muted = excluded.muted,
+                discussion_category = excluded.discussion_category,
+                last_discussion_number = excluded.last_discussion_number,
+                exact_tags = excluded.exact_tags,
+                updated_at = excluded.updated_at,
+                min_release_age_secs = excluded.min_release_age_secs,
+                young_release_tag = excluded.young_release_tag,
+                young_release_seen_at = excluded.young_release_seen_at,
+                commit_milestone = excluded.commit_milestone,
+                last_notified_count = excluded.last_notified_count,
+                tag_ignore = excluded.tag_ignore,
+                tag_prefix = excluded.tag_prefix,
+                throttle_window_secs = excluded.throttle_window_secs,
+                last_notified_at = excluded.last_notified_at,
+                mention = excluded.mention,
+                inaccessible_since = excluded.inaccessible_since,
+                request_timeout_secs = excluded.request_timeout_secs,
+                critical = excluded.critical,
+                tag_capture = excluded.tag_capture
+            "#,
+        )
+        .bind(settings.tracked_repository_id.to_string())
+        .bind(settings.prerelease_collapse_secs)
+        .bind(&settings.last_prerelease_base)
+        .bind(settings.last_prerelease_notified_at)
+        .bind(settings.snoozed_until)
+        .bind(&settings.snooze_missed_tag)
+        .bind(settings.tags_only)
+        .bind(settings.muted)
+        .bind(&settings.discussion_category)
+        .bind(settings.last_discussion_number)
+        .bind(settings.exact_tags)
+        .bind(settings.updated_at)
+        .bind(settings.min_release_age_secs)
+        .bind(&settings.young_release_tag)
+        .bind(settings.young_release_seen_at)
+        .bind(settings.commit_milestone)
+        .bind(settings.last_notified_count)
+        .bind(&settings.tag_ignore)
+        .bind(&settings.tag_prefix)
+        .bind(settings.throttle_window_secs)
+        .bind(settings.last_notified_at)
+        .bind(settings.mention.as_ref().map(Mention::as_stored))
+        .bind(settings.inaccessible_since)
+        .bind(settings.request_timeout_secs)
+        .bind(settings.critical)
+        .bind(&settings.tag_capture)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_tracked_repository_id(
+        &self,
+        id: &uuid::Uuid,
+    ) -> Result<Option<RepositorySettings>, Box<dyn Error + Send + Sync>> {
+        let rec = sqlx::query_as::<_, RepositorySettings>(
+            r#"
+            SELECT tracked_repository_id, prerelease_collapse_secs, last_prerelease_base,
+                last_prerelease_notified_at, snoozed_until, snooze_missed_tag, tags_only,
+                muted, discussion_category, last_discussion_number, exact_tags, updated_at,
+                min_release_age_secs, young_release_tag, young_release_seen_at,
+                commit_milestone, last_notified_count, tag_ignore, tag_prefix,
+                throttle_window_secs, last_notified_at, mention, inaccessible_since,
+                request_timeout_secs, critical, tag_capture
+            FROM tracked_repository_settings
+            WHERE tracked_repository_id = ?1
+            "#,
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(rec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tracked_repositories::repository::{
+        SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+    };
+    use crate::tracked_repositories::{RepositoryUrl, TrackedRelease};
+    use chrono::Utc;
+    use sqlx::sqlite::SqlitePoolOptions;
+    use uuid::Uuid;
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("failed to connect to sqlite in-memory");
+
+        sqlx::migrate!("./migrations")
+            .run(&pool)
+            .await
+            .expect("migrations should run");
+
+        pool
+    }
+
+    async fn insert_tracked_repository(pool: &SqlitePool) -> TrackedRelease {
+        let repo_repo = SqliteTrackedRepositoriesRepository::new(pool.clone());
+        let now = Utc::now();
+        let mut tracked = TrackedRelease {
+            id: Uuid::now_v7(),
+            repository_name: "owner/repo".to_string(),
+            repository_url: RepositoryUrl::new("https://github.com/owner/repo".to_string())
+                .unwrap(),
+            chat_id: 1,
+            bot_id: String::new(),
+            default_branch: None,
+            created_at: now,
+            updated_at: now,
+        };
+        repo_repo.save(&mut tracked).await.unwrap();
+        tracked
+    }
+
+    #[tokio::test]
+    async fn defaults_then_roundtrip() {
+        let pool = setup_pool().await;
+        let tracked = insert_tracked_repository(&pool).await;
+        let repo = SqliteRepositorySettingsRepository::new(pool.clone());
+
+        let mut settings = repo.find_or_default(&tracked.id).await.unwrap();
+        assert_eq!(settings.prerelease_collapse_secs, None);
+
+        let now = Utc::now();
+        settings.prerelease_collapse_secs = Some(3600);
+        settings.last_prerelease_base = Some("v1.0.0".to_string());
+        settings.last_prerelease_notified_at = Some(now);
+        settings.snoozed_until = Some(now);
+        settings.snooze_missed_tag = Some("v1.0.1".to_string());
+        settings.tags_only = true;
+        settings.muted = true;
+        settings.exact_tags = true;
+        settings.discussion_category = Some("Announcements".to_string());
+        settings.last_discussion_number = Some(42);
+        settings.tag_ignore = Some("*nightly*".to_string());
+        settings.tag_prefix = Some("pkg-a/".to_string());
+        settings.throttle_window_secs = Some(3600);
+        settings.mention = Some(Mention::Username("release_bot".to_string()));
+        settings.last_notified_at = Some(Utc::now());
+        settings.inaccessible_since = Some(Utc::now());
+        repo.save(&settings).await.unwrap();
+
+        let fetched = repo
+            .find_by_tracked_repository_id(&tracked.id)
+            .await
+            .unwrap()
+            .expect("row should exist");
+        assert_eq!(fetched.prerelease_collapse_secs, Some(3600));
+        assert_eq!(fetched.last_prerelease_base.as_deref(), Some("v1.0.0"));
+        assert_eq!(fetched.last_prerelease_notified_at, Some(now));
+        assert_eq!(fetched.snoozed_until, Some(now));
+        assert_eq!(fetched.snooze_missed_tag.as_deref(), Some("v1.0.1"));
+        assert!(fetched.tags_only);
+        assert!(fetched.muted);
+        assert!(fetched.exact_tags);
+        assert_eq!(
+            fetched.discussion_category.as_deref(),
+            Some("Announcements")
+        );
+        assert_eq!(fetched.last_discussion_number, Some(42));
+        assert_eq!(fetched.tag_ignore.as_deref(), Some("*nightly*"));
+        assert_eq!(fetched.tag_prefix.as_deref(), Some("pkg-a/"));
+        assert_eq!(fetched.throttle_window_secs, Some(3600));
+        assert_eq!(fetched.mention, settings.mention);
+        assert_eq!(fetched.last_notified_at, settings.last_notified_at);
+        assert_eq!(fetched.inaccessible_since, settings.inaccessible_since);
+    }
+}
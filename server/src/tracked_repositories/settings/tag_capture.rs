@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+
+use regex::{Regex, RegexBuilder};
+
+/// Longest tag capture pattern accepted, in characters.
+pub const MAX_CAPTURE_PATTERN_CHARS: usize = 200;
+/// Bytes the compiled pattern may take. The regex engine runs in time linear in the tag
+/// and never backtracks, so this bounds what remains: patterns like `(a{1000}){1000}`
+/// that blow up when compiled.
+const COMPILED_SIZE_LIMIT: usize = 256 * 1024;
+/// How deeply groups and repetitions may nest.
+const NEST_LIMIT: u32 = 32;
+/// Patterns kept compiled; the cache starts over once it holds more.
+const MAX_CACHED_PATTERNS: usize = 256;
+
+static COMPILED: LazyLock<Mutex<HashMap<String, Arc<Regex>>>> = LazyLock::new(Default::default);
+
+/// Compiles a tag capture pattern, reusing the compiled regex of earlier calls. The
+/// pattern needs a capture group for the version, like `^release-(\d+\.\d+)$`.
+pub fn compile(pattern: &str) -> Result<Arc<Regex>, String> {
+    if let Some(regex) = COMPILED
+        .lock()
+        .ok()
+        .and_then(|compiled| compiled.get(pattern).cloned())
+    {
+        return Ok(regex);
+    }
+
+    if pattern.chars().count() > MAX_CAPTURE_PATTERN_CHARS {
+        return Err(format!(
+            "Please keep the pattern under {MAX_CAPTURE_PATTERN_CHARS} characters."
+        ));
+    }
+    let regex = RegexBuilder::new(pattern)
+        .size_limit(COMPILED_SIZE_LIMIT)
+        .dfa_size_limit(COMPILED_SIZE_LIMIT)
+        .nest_limit(NEST_LIMIT)
+        .build()
+        .map_err(|e| match e {
+            regex::Error::CompiledTooBig(_) => "That pattern is too complex.".to_string(),
+            e => format!("That is not a valid regex: {e}"),
+        })?;
+    if regex.captures_len() < 2 {
+        return Err(
+            "The pattern needs a capture group for the version, e.g. ^release-(.+)$".to_string(),
+        );
+    }
+
+    let regex = Arc::new(regex);
+    if let Ok(mut compiled) = COMPILED.lock() {
+        if compiled.len() >= MAX_CACHED_PATTERNS {
+            compiled.clear();
+        }
+        compiled.insert(pattern.to_string(), regex.clone());
+    }
+    Ok(regex)
+}
+
+/// The version `pattern` captures from `tag`: the first of its groups that took part in
+/// the match. `None` when the tag does not match, or the pattern does not compile.
+pub fn capture(pattern: &str, tag: &str) -> Option<String> {
+    let regex = compile(pattern).ok()?;
+    let captures = regex.captures(tag)?;
+    captures
+        .iter()
+        .skip(1)
+        .flatten()
+        .next()
+        .map(|m| m.as_str().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn captures_the_version_from_the_tag() {
+        let pattern = r"^release-(\d+\.\d+\.\d+)$";
+        assert_eq!(capture(pattern, "release-1.2.3").as_deref(), Some("1.2.3"));
+        assert_eq!(capture(pattern, "nightly-2024-01-01"), None);
+        // The first group that matched names the version
+        let pattern = r"^(?:app-v(\d+)|v(\d+\.\d+))$";
+        assert_eq!(capture(pattern, "v2.1").as_deref(), Some("2.1"));
+        assert!(Arc::ptr_eq(
+            &compile(pattern).unwrap(),
+            &compile(pattern).unwrap()
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_or_too_complex_patterns() {
+        assert!(compile("release-(").is_err());
+        assert!(compile(r"^v\d+$").is_err());
+        assert!(compile(r"(a{1000}){1000}").is_err());
+        assert!(compile(&format!("({})", "a".repeat(MAX_CAPTURE_PATTERN_CHARS))).is_err());
+        assert!(capture("release-(", "release-1").is_none());
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// Outcome of the latest attempt to fetch a repository's newest release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FetchStatus {
+    /// A release or tag was found.
+    Ok,
+    /// The repository has neither releases nor tags.
+    NoReleases,
+    /// GitHub could not be reached or answered with an error.
+    Failed,
+}
+
+impl FetchStatus {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "ok" => Some(Self::Ok),
+            "no_releases" => Some(Self::NoReleases),
+            "failed" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::NoReleases => "no_releases",
+            Self::Failed => "failed",
+        }
+    }
+}
@@ -1,5 +1,8 @@
+mod fetch_status;
 pub mod repository;
 
+pub use fetch_status::FetchStatus;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::sqlite::SqliteRow;
@@ -11,6 +14,11 @@ pub struct CachedRepositoryRelease {
     pub tracked_repository_id: Uuid,
     pub tag_name: String,
     pub first_seen_at: DateTime<Utc>,
+    /// SHA-256 of the release body, used to spot notes copied between releases.
+    pub body_hash: Option<String>,
+    /// Whether the tag was a published release, not one found through the tags fallback,
+    /// so its disappearance from the releases means it was deleted.
+    pub from_release: bool,
 }
 
 impl FromRow<'_, SqliteRow> for CachedRepositoryRelease {
@@ -21,11 +29,33 @@ impl FromRow<'_, SqliteRow> for CachedRepositoryRelease {
 
         let tag_name: String = row.try_get("tag_name")?;
         let first_seen_at: DateTime<Utc> = row.try_get("first_seen_at")?;
+        let body_hash: Option<String> = row.try_get("body_hash")?;
+        let from_release: bool = row.try_get("from_release")?;
 
         Ok(Self {
             tracked_repository_id,
             tag_name,
             first_seen_at,
+            body_hash,
+            from_release,
+        })
+    }
+}
+
+/// A tracked repository whose newest cached release was first seen a while ago.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaleRepository {
+    pub repository_name: String,
+    pub tag_name: String,
+    pub first_seen_at: DateTime<Utc>,
+}
+
+impl FromRow<'_, SqliteRow> for StaleRepository {
+    fn from_row(row: &SqliteRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            repository_name: row.try_get("repository_name")?,
+            tag_name: row.try_get("tag_name")?,
+            first_seen_at: row.try_get("first_seen_at")?,
         })
     }
 }
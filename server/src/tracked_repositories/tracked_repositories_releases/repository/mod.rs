@@ -0,0 +1,230 @@
+use crate::db::retry_on_busy;
+use crate::tracked_repositories::tracked_repositories_releases::{
+    CachedRepositoryRelease, FetchStatus, StaleRepository,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{self, sqlite::SqlitePool};
+use std::error::Error;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait CachedRepositoryReleasesRepository: Send + Sync {
+    async fn save(
+        &self,
+        cached: &CachedRepositoryRelease,
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+    /// Saves every row in a single transaction.
+    async fn save_all(
+        &self,
+        cached: &[CachedRepositoryRelease],
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn find_by_tracked_release_id(
+        &self,
+        id: &uuid::Uuid,
+    ) -> Result<Option<CachedRepositoryRelease>, Box<dyn Error + Send + Sync>>;
+    /// Records the outcome of the latest poll of each repository in a single transaction.
+    async fn save_fetch_statuses(
+        &self,
+        statuses: &[(Uuid, FetchStatus)],
+        checked_at: DateTime<Utc>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn find_fetch_status(
+        &self,
+        id: &Uuid,
+    ) -> Result<Option<FetchStatus>, Box<dyn Error + Send + Sync>>;
+    /// The outcome of the latest poll of the repository and when it happened.
+    async fn find_last_check(
+        &self,
+        id: &Uuid,
+    ) -> Result<Option<(FetchStatus, DateTime<Utc>)>, Box<dyn Error + Send + Sync>>;
+    /// The chat's repositories whose cached release was first seen before `before`, oldest
+    /// first. Repositories without a cached release are left out.
+    async fn find_stale_for_chat(
+        &self,
+        chat_id: i64,
+        before: DateTime<Utc>,
+    ) -> Result<Vec<StaleRepository>, Box<dyn Error + Send + Sync>>;
+}
+
+const UPSERT_SQL: &str = r#"
+    INSERT INTO tracked_repository_releases (
+        tracked_repository_id, tag_name, first_seen_at, body_hash, from_release
+    )
+    VALUES (?1, ?2, ?3, ?4, ?5)
+    ON CONFLICT(tracked_repository_id) DO UPDATE SET
+        tag_name = excluded.tag_name,
+        body_hash = excluded.body_hash,
+        from_release = excluded.from_release,
+        first_seen_at = CASE
+            WHEN excluded.tag_name != tag_name THEN excluded.first_seen_at
+            ELSE first_seen_at
+        END
+"#;
+
+fn upsert(
+    cached: &CachedRepositoryRelease,
+) -> sqlx::query::Query<'_, sqlx::Sqlite, sqlx::sqlite::SqliteArguments<'_>> {
+    sqlx::query(UPSERT_SQL)
+        .bind(cached.tracked_repository_id.to_string())
+        .bind(&cached.tag_name)
+        .bind(cached.first_seen_at)
+        .bind(&cached.body_hash)
+        .bind(cached.from_release)
+}
+
+/// Writes `cached` through `conn`, so a caller can commit it with writes of its own.
+pub(crate) async fn upsert_all(
+    conn: &mut sqlx::SqliteConnection,
+    cached: &[CachedRepositoryRelease],
+) -> Result<(), sqlx::Error> {
+    for row in cached {
+        upsert(row).execute(&mut *conn).await?;
+    }
+    Ok(())
+}
+
+pub struct SqliteCachedRepositoryReleasesRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteCachedRepositoryReleasesRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CachedRepositoryReleasesRepository for SqliteCachedRepositoryReleasesRepository {
+    async fn save(
+        &self,
+        cached: &CachedRepositoryRelease,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        retry_on_busy(|| upsert(cached).execute(&self.pool)).await?;
+
+        Ok(())
+    }
+
+    async fn save_all(
+        &self,
+        cached: &[CachedRepositoryRelease],
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        retry_on_busy(|| async {
+            let mut tx = self.pool.begin().await?;
+            upsert_all(&mut tx, cached).await?;
+            tx.commit().await
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_by_tracked_release_id(
+        &self,
+        id: &uuid::Uuid,
+    ) -> Result<Option<CachedRepositoryRelease>, Box<dyn Error + Send + Sync>> {
+        let rec = sqlx::query_as::<_, CachedRepositoryRelease>(
+            r#"
+            SELECT tracked_repository_id, tag_name, first_seen_at, body_hash, from_release
+            FROM tracked_repository_releases
+            WHERE tracked_repository_id = ?1
+            "#,
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(rec)
+    }
+
+    async fn save_fetch_statuses(
+        &self,
+        statuses: &[(Uuid, FetchStatus)],
+        checked_at: DateTime<Utc>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        retry_on_busy(|| async {
+            let mut tx = self.pool.begin().await?;
+            for (id, status) in statuses {
+                sqlx::query(
+                    r#"
+                    INSERT INTO tracked_repository_fetch_status (tracked_repository_id, status, checked_at)
+                    VALUES (?1, ?2, ?3)
+                    ON CONFLICT(tracked_repository_id) DO UPDATE SET
+                        status = excluded.status,
+                        checked_at = excluded.checked_at
+                    "#,
+                )
+                .bind(id.to_string())
+                .bind(status.as_str())
+                .bind(checked_at)
+                .execute(&mut *tx)
+                .await?;
+            }
+            tx.commit().await
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn find_fetch_status(
+        &self,
+        id: &Uuid,
+    ) -> Result<Option<FetchStatus>, Box<dyn Error + Send + Sync>> {
+        let status: Option<String> = sqlx::query_scalar(
+            r#"
+            SELECT status FROM tracked_repository_fetch_status
+            WHERE tracked_repository_id = ?1
+            "#,
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(status.as_deref().and_then(FetchStatus::parse))
+    }
+
+    async fn find_last_check(
+        &self,
+        id: &Uuid,
+    ) -> Result<Option<(FetchStatus, DateTime<Utc>)>, Box<dyn Error + Send + Sync>> {
+        let row: Option<(String, DateTime<Utc>)> = sqlx::query_as(
+            r#"
+            SELECT status, checked_at FROM tracked_repository_fetch_status
+            WHERE tracked_repository_id = ?1
+            "#,
+        )
+        .bind(id.to_string())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.and_then(|(status, checked_at)| {
+            FetchStatus::parse(&status).map(|status| (status, checked_at))
+        }))
+    }
+
+    async fn find_stale_for_chat(
+        &self,
+        chat_id: i64,
+        before: DateTime<Utc>,
+    ) -> Result<Vec<StaleRepository>, Box<dyn Error + Send + Sync>> {
+        let stale = sqlx::query_as::<_, StaleRepository>(
+            r#"
+            SELECT t.repository_name, r.tag_name, r.first_seen_at
+            FROM tracked_repositories t
+            JOIN tracked_repository_releases r ON r.tracked_repository_id = t.id
+            WHERE t.chat_id = ?1 AND r.first_seen_at < ?2
+            ORDER BY r.first_seen_at, t.repository_name
+            "#,
+        )
+        .bind(chat_id)
+        .bind(before)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(stale)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,172 @@
+use super::*;
+use crate::tracked_repositories::repository::{
+    SqliteTrackedRepositoriesRepository, TrackedRepositoriesRepository,
+};
+use crate::tracked_repositories::{RepositoryUrl, TrackedRelease};
+use chrono::Duration;
+use sqlx::sqlite::SqlitePoolOptions;
+
+async fn setup_pool() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("failed to connect to sqlite in-memory");
+
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("migrations should run");
+
+    pool
+}
+
+async fn insert_tracked_repository(pool: &SqlitePool) -> TrackedRelease {
+    let repo_repo = SqliteTrackedRepositoriesRepository::new(pool.clone());
+    let now = Utc::now();
+    let mut tracked = TrackedRelease {
+        id: Uuid::now_v7(),
+        repository_name: "owner/repo".to_string(),
+        repository_url: RepositoryUrl::new("https://github.com/owner/repo".to_string()).unwrap(),
+        chat_id: 1,
+        bot_id: String::new(),
+        default_branch: None,
+        created_at: now,
+        updated_at: now,
+    };
+    repo_repo.save(&mut tracked).await.unwrap();
+    tracked
+}
+
+#[tokio::test]
+async fn save_and_find_roundtrip() {
+    let pool = setup_pool().await;
+    let tracked = insert_tracked_repository(&pool).await;
+    let repo = SqliteCachedRepositoryReleasesRepository::new(pool.clone());
+
+    let first_seen = Utc::now();
+    let cached = CachedRepositoryRelease {
+        tracked_repository_id: tracked.id,
+        tag_name: "v1.0.0".to_string(),
+        first_seen_at: first_seen,
+        body_hash: Some("abc123".to_string()),
+        from_release: true,
+    };
+
+    repo.save(&cached).await.expect("save should succeed");
+
+    let fetched = repo
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .expect("query should succeed")
+        .expect("row should exist");
+
+    assert_eq!(fetched.tracked_repository_id, tracked.id);
+    assert_eq!(fetched.tag_name, "v1.0.0");
+    assert_eq!(fetched.first_seen_at, first_seen);
+    assert_eq!(fetched.body_hash.as_deref(), Some("abc123"));
+}
+
+#[tokio::test]
+async fn upsert_same_tag_keeps_first_seen_at() {
+    let pool = setup_pool().await;
+    let tracked = insert_tracked_repository(&pool).await;
+    let repo = SqliteCachedRepositoryReleasesRepository::new(pool.clone());
+
+    let t1 = Utc::now();
+    let initial = CachedRepositoryRelease {
+        tracked_repository_id: tracked.id,
+        tag_name: "v1.0.0".to_string(),
+        first_seen_at: t1,
+        body_hash: None,
+        from_release: true,
+    };
+    repo.save(&initial).await.unwrap();
+
+    // same tag, later timestamp; first_seen_at should NOT change
+    let t2 = t1 + Duration::minutes(10);
+    let same_tag = CachedRepositoryRelease {
+        tracked_repository_id: tracked.id,
+        tag_name: "v1.0.0".to_string(),
+        first_seen_at: t2,
+        body_hash: None,
+        from_release: true,
+    };
+    repo.save(&same_tag).await.unwrap();
+
+    let fetched = repo
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(fetched.tag_name, "v1.0.0");
+    assert_eq!(fetched.first_seen_at, t1);
+}
+
+#[tokio::test]
+async fn upsert_new_tag_updates_first_seen_at() {
+    let pool = setup_pool().await;
+    let tracked = insert_tracked_repository(&pool).await;
+    let repo = SqliteCachedRepositoryReleasesRepository::new(pool.clone());
+
+    let t1 = Utc::now();
+    let initial = CachedRepositoryRelease {
+        tracked_repository_id: tracked.id,
+        tag_name: "v1.0.0".to_string(),
+        first_seen_at: t1,
+        body_hash: None,
+        from_release: true,
+    };
+    repo.save(&initial).await.unwrap();
+
+    // different tag, later timestamp; first_seen_at SHOULD change
+    let t2 = t1 + Duration::minutes(5);
+    let new_tag = CachedRepositoryRelease {
+        tracked_repository_id: tracked.id,
+        tag_name: "v1.1.0".to_string(),
+        first_seen_at: t2,
+        body_hash: None,
+        from_release: true,
+    };
+    repo.save(&new_tag).await.unwrap();
+
+    let fetched = repo
+        .find_by_tracked_release_id(&tracked.id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(fetched.tag_name, "v1.1.0");
+    assert_eq!(fetched.first_seen_at, t2);
+}
+
+#[tokio::test]
+async fn fetch_status_roundtrip() {
+    let pool = setup_pool().await;
+    let tracked = insert_tracked_repository(&pool).await;
+    let repo = SqliteCachedRepositoryReleasesRepository::new(pool.clone());
+
+    assert_eq!(repo.find_fetch_status(&tracked.id).await.unwrap(), None);
+
+    repo.save_fetch_statuses(&[(tracked.id, FetchStatus::NoReleases)], Utc::now())
+        .await
+        .unwrap();
+    assert_eq!(
+        repo.find_fetch_status(&tracked.id).await.unwrap(),
+        Some(FetchStatus::NoReleases)
+    );
+
+    let checked_at = Utc::now();
+    repo.save_fetch_statuses(&[(tracked.id, FetchStatus::Failed)], checked_at)
+        .await
+        .unwrap();
+    assert_eq!(
+        repo.find_fetch_status(&tracked.id).await.unwrap(),
+        Some(FetchStatus::Failed)
+    );
+    assert_eq!(
+        repo.find_last_check(&tracked.id).await.unwrap(),
+        Some((FetchStatus::Failed, checked_at))
+    );
+}
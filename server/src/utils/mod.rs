@@ -0,0 +1,300 @@
+mod split;
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::chat_settings::Lang;
+use crate::messages::{CommandText, render};
+
+pub use split::{TELEGRAM_MESSAGE_LIMIT, split_message};
+
+pub fn html_escape(input: &str) -> Cow<'_, str> {
+    let mut needs_escaping = false;
+    for ch in input.chars() {
+        match ch {
+            '&' | '<' | '>' | '"' | '\'' => {
+                needs_escaping = true;
+                break;
+            }
+            _ => {}
+        }
+    }
+    if !needs_escaping {
+        return Cow::Borrowed(input);
+    }
+    let mut escaped = String::with_capacity(input.len());
+    for ch in input.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+const MARKDOWN_V2_RESERVED: &[char] = &[
+    '\\', '_', '*', '[', ']', '(', ')', '~', '`', '>', '#', '+', '-', '=', '|', '{', '}', '.', '!',
+];
+
+fn escape_chars<'a>(input: &'a str, reserved: &[char]) -> Cow<'a, str> {
+    if !input.contains(reserved) {
+        return Cow::Borrowed(input);
+    }
+    let mut escaped = String::with_capacity(input.len() * 2);
+    for ch in input.chars() {
+        if reserved.contains(&ch) {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    Cow::Owned(escaped)
+}
+
+/// Escapes text for Telegram's MarkdownV2 parse mode.
+pub fn markdown_v2_escape(input: &str) -> Cow<'_, str> {
+    escape_chars(input, MARKDOWN_V2_RESERVED)
+}
+
+/// Escapes the URL part of a MarkdownV2 inline link, where only `)` and `\` are reserved.
+pub fn markdown_v2_escape_url(input: &str) -> Cow<'_, str> {
+    escape_chars(input, &['\\', ')'])
+}
+
+/// Why `parse_duration` rejected its input, which each variant carries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DurationError {
+    Invalid(String),
+    TooLong(String),
+}
+
+impl DurationError {
+    /// The error as a chat reply in `lang`.
+    pub fn message(&self, lang: Lang) -> String {
+        match self {
+            Self::Invalid(input) => render(lang, CommandText::InvalidDuration, &[("input", input)]),
+            Self::TooLong(input) => {
+                render(lang, CommandText::DurationOverflow, &[("input", input)])
+            }
+        }
+    }
+}
+
+impl fmt::Display for DurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message(Lang::En))
+    }
+}
+
+/// Parses a duration such as `90`, `45s`, `30m`, `2h` or `7d`. A bare number is seconds.
+///
+/// Only a single amount and unit is accepted: compound forms like `1h30m` are rejected
+/// rather than guessed at, as are signs, whitespace inside the value and amounts that
+/// overflow.
+pub fn parse_duration(input: &str) -> Result<std::time::Duration, DurationError> {
+    let input = input.trim();
+    let invalid = || DurationError::Invalid(input.to_string());
+    let too_long = || DurationError::TooLong(input.to_string());
+
+    let (digits, multiplier): (&str, u64) = match input.char_indices().last() {
+        Some((i, 's')) => (&input[..i], 1),
+        Some((i, 'm')) => (&input[..i], 60),
+        Some((i, 'h')) => (&input[..i], 60 * 60),
+        Some((i, 'd')) => (&input[..i], 24 * 60 * 60),
+        Some(_) => (input, 1),
+        None => return Err(invalid()),
+    };
+    // `u64::from_str` accepts a leading `+`, so insist on plain digits
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let amount: u64 = digits.parse().map_err(|_| too_long())?;
+    let secs = amount.checked_mul(multiplier).ok_or_else(too_long)?;
+
+    Ok(std::time::Duration::from_secs(secs))
+}
+
+/// Matches `text` against a glob where `*` stands for any run of characters and `?` for
+/// exactly one. The whole text must match; ASCII case is ignored.
+pub fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().map(|c| c.to_ascii_lowercase()).collect();
+    let text: Vec<char> = text.chars().map(|c| c.to_ascii_lowercase()).collect();
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` and the text position it currently absorbs up to
+    let mut star: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Longest repository name shown in a message, in characters.
+pub const MAX_NAME_CHARS: usize = 256;
+
+/// Longest tag shown in a message, in characters.
+pub const MAX_TAG_CHARS: usize = 128;
+
+/// Shortens `text` to at most `max_chars` characters, ending in an ellipsis when cut.
+/// Apply it to raw text before escaping so an escape sequence is never split.
+pub fn truncate_chars(text: &str, max_chars: usize) -> Cow<'_, str> {
+    match text.char_indices().nth(max_chars) {
+        None => Cow::Borrowed(text),
+        Some(_) => {
+            let keep = max_chars.saturating_sub(1);
+            let end = text.char_indices().nth(keep).map_or(text.len(), |(i, _)| i);
+            Cow::Owned(format!("{}…", &text[..end]))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn markdown_v2_escape_leaves_plain_text_borrowed() {
+        assert!(matches!(
+            markdown_v2_escape("plain text 123"),
+            Cow::Borrowed("plain text 123")
+        ));
+    }
+
+    #[test]
+    fn markdown_v2_escape_escapes_every_reserved_char() {
+        let input = "_*[]()~`>#+-=|{}.!";
+        let expected = "\\_\\*\\[\\]\\(\\)\\~\\`\\>\\#\\+\\-\\=\\|\\{\\}\\.\\!";
+        assert_eq!(markdown_v2_escape(input), expected);
+    }
+
+    #[test]
+    fn markdown_v2_escape_escapes_backslash_first_class() {
+        assert_eq!(markdown_v2_escape("a\\b"), "a\\\\b");
+        assert_eq!(markdown_v2_escape("\\_"), "\\\\\\_");
+    }
+
+    #[test]
+    fn markdown_v2_escape_handles_realistic_tags_and_names() {
+        assert_eq!(markdown_v2_escape("v1.2.3-rc.1"), "v1\\.2\\.3\\-rc\\.1");
+        assert_eq!(
+            markdown_v2_escape("my_repo (fork)!"),
+            "my\\_repo \\(fork\\)\\!"
+        );
+        assert_eq!(markdown_v2_escape("ünïcødé ✓"), "ünïcødé ✓");
+    }
+
+    #[test]
+    fn markdown_v2_escape_url_only_escapes_paren_and_backslash() {
+        assert_eq!(
+            markdown_v2_escape_url("https://github.com/o/r/releases/tag/v1.0_(x)"),
+            "https://github.com/o/r/releases/tag/v1.0_(x\\)"
+        );
+        assert_eq!(markdown_v2_escape_url("a\\b"), "a\\\\b");
+    }
+
+    #[test]
+    fn parse_duration_supports_units() {
+        use std::time::Duration;
+        assert_eq!(parse_duration("90"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("45s"), Ok(Duration::from_secs(45)));
+        assert_eq!(parse_duration("30m"), Ok(Duration::from_secs(30 * 60)));
+        assert_eq!(parse_duration(" 2h "), Ok(Duration::from_secs(2 * 3600)));
+        assert_eq!(parse_duration("7d"), Ok(Duration::from_secs(7 * 86400)));
+    }
+
+    #[test]
+    fn parse_duration_rejects_garbage() {
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("h").is_err());
+        assert!(parse_duration("-5m").is_err());
+        assert!(parse_duration("2w").is_err());
+        assert!(parse_duration("soon").is_err());
+        assert!(parse_duration("+5m").is_err());
+        assert!(parse_duration("5 m").is_err());
+        assert!(parse_duration("1.5h").is_err());
+        assert!(parse_duration("5M").is_err());
+        assert!(parse_duration("٣m").is_err());
+        assert!(parse_duration("5é").is_err());
+    }
+
+    #[test]
+    fn parse_duration_accepts_zero() {
+        use std::time::Duration;
+        assert_eq!(parse_duration("0"), Ok(Duration::ZERO));
+        assert_eq!(parse_duration("0s"), Ok(Duration::ZERO));
+        assert_eq!(parse_duration("0d"), Ok(Duration::ZERO));
+    }
+
+    #[test]
+    fn parse_duration_rejects_overflow() {
+        use std::time::Duration;
+        assert_eq!(
+            parse_duration("999999999999999999999"),
+            Err(DurationError::TooLong("999999999999999999999".to_string()))
+        );
+        assert_eq!(
+            parse_duration("999999999999999d"),
+            Err(DurationError::TooLong("999999999999999d".to_string()))
+        );
+        assert_eq!(
+            parse_duration("soon").unwrap_err().message(Lang::De),
+            "Ungültige Dauer 'soon'. Verwende z. B. 30m, 2h oder 7d."
+        );
+        assert_eq!(
+            parse_duration(&format!("{}s", u64::MAX)),
+            Ok(Duration::from_secs(u64::MAX))
+        );
+        // Fits in u64 seconds, so it parses; callers bound it further where they need to
+        assert_eq!(
+            parse_duration("999999999999d"),
+            Ok(Duration::from_secs(999_999_999_999 * 86400))
+        );
+    }
+
+    #[test]
+    fn parse_duration_rejects_compound_durations() {
+        assert!(parse_duration("1h30m").is_err());
+        assert!(parse_duration("1d2h").is_err());
+        assert!(parse_duration("30m30m").is_err());
+    }
+
+    #[test]
+    fn truncate_chars_caps_long_text() {
+        assert!(matches!(truncate_chars("v1.0", 4), Cow::Borrowed("v1.0")));
+        assert_eq!(truncate_chars("v1.0.0", 4), "v1.…");
+        assert_eq!(truncate_chars("ééééé", 3), "éé…");
+        assert_eq!(
+            truncate_chars(&"x".repeat(10_000), 128).chars().count(),
+            128
+        );
+    }
+
+    #[test]
+    fn glob_matches_wildcards() {
+        assert!(glob_matches("tokio-*", "tokio-rs"));
+        assert!(glob_matches(
+            "*/TOKIO*",
+            "https://github.com/tokio-rs/tokio"
+        ));
+        assert!(glob_matches("v?.0", "v1.0"));
+        assert!(glob_matches("*", ""));
+        assert!(!glob_matches("tokio", "tokio-rs"));
+        assert!(!glob_matches("v?.0", "v10.0"));
+        assert!(!glob_matches("*-rs", "tokio-rs-extra"));
+    }
+}
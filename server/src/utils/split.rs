@@ -0,0 +1,111 @@
+/// Maximum length of a single Telegram message.
+pub const TELEGRAM_MESSAGE_LIMIT: usize = 4096;
+
+/// Splits `text` into chunks of at most `limit` characters, breaking on line boundaries.
+/// A single line longer than `limit` is hard-split, outside HTML tags and entities and
+/// MarkdownV2 escapes where it can be.
+pub fn split_message(text: &str, limit: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+
+    for line in text.split('\n') {
+        let line_len = line.chars().count();
+        let separator = usize::from(!current.is_empty());
+
+        if current_len + separator + line_len <= limit {
+            if separator == 1 {
+                current.push('\n');
+            }
+            current.push_str(line);
+            current_len += separator + line_len;
+            continue;
+        }
+
+        if !current.is_empty() {
+            chunks.push(std::mem::take(&mut current));
+            current_len = 0;
+        }
+
+        if line_len <= limit {
+            current.push_str(line);
+            current_len = line_len;
+        } else {
+            let chars: Vec<char> = line.chars().collect();
+            let mut rest = chars.as_slice();
+            while !rest.is_empty() {
+                let cut = hard_split_at(rest, limit);
+                chunks.push(rest[..cut].iter().collect());
+                rest = &rest[cut..];
+            }
+        }
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+/// Longest entity `html_escape` writes, `&quot;`, counting the `&` and `;`.
+const MAX_ENTITY_CHARS: usize = 6;
+
+/// Where to cut a line hard-split into pieces of `limit` characters: before an HTML tag or
+/// entity the cut would fall into, or before a backslash escaping the next character.
+/// Falls back to `limit` when the piece would be empty.
+fn hard_split_at(chars: &[char], limit: usize) -> usize {
+    if chars.len() <= limit {
+        return chars.len();
+    }
+    let head = &chars[..limit];
+    let mut cut = limit;
+    if let Some(open) = head.iter().rposition(|c| *c == '<')
+        && !head[open..].contains(&'>')
+    {
+        cut = open;
+    } else if let Some(open) = head.iter().rposition(|c| *c == '&')
+        && head.len() - open < MAX_ENTITY_CHARS
+        && head[open + 1..]
+            .iter()
+            .all(|c| c.is_ascii_alphanumeric() || *c == '#')
+    {
+        cut = open;
+    }
+    let backslashes = head[..cut].iter().rev().take_while(|c| **c == '\\').count();
+    if backslashes % 2 == 1 {
+        cut -= 1;
+    }
+    if cut == 0 { limit } else { cut }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_message_keeps_short_text_whole() {
+        assert_eq!(split_message("a\nb", 10), vec!["a\nb".to_string()]);
+    }
+
+    #[test]
+    fn split_message_breaks_on_lines() {
+        let chunks = split_message("aaaa\nbbbb\ncccc", 9);
+        assert_eq!(chunks, vec!["aaaa\nbbbb".to_string(), "cccc".to_string()]);
+    }
+
+    #[test]
+    fn split_message_hard_splits_long_lines() {
+        let chunks = split_message("abcdefg", 3);
+        assert_eq!(chunks, vec!["abc", "def", "g"]);
+    }
+
+    #[test]
+    fn split_message_keeps_entities_and_tags_whole() {
+        let chunks = split_message("ab&amp;cd", 5);
+        assert_eq!(chunks, vec!["ab", "&amp;", "cd"]);
+        let chunks = split_message("x<b>y</b>", 3);
+        assert_eq!(chunks, vec!["x", "<b>", "y", "</b", ">"]);
+        let chunks = split_message("ab\\.c", 3);
+        assert_eq!(chunks, vec!["ab", "\\.c"]);
+    }
+}
@@ -0,0 +1,182 @@
+use serde_json::{Value, json};
+
+use crate::chat_settings::{ChatWebhook, ReleaseInfo, WebhookKind};
+use crate::utils::{MAX_NAME_CHARS, MAX_TAG_CHARS, truncate_chars};
+
+/// Escapes the characters Slack's mrkdwn treats as control characters.
+fn slack_escape(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Escapes the characters Discord treats as markdown.
+fn discord_escape(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for c in input.chars() {
+        if matches!(c, '\\' | '*' | '_' | '~' | '`' | '|' | '>' | '[' | ']') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// The JSON body announcing `release` to a webhook of the given kind: Slack gets a `text`
+/// fallback and a section block, Discord gets `content` and a linked embed.
+pub(crate) fn payload(kind: WebhookKind, release: &ReleaseInfo) -> Value {
+    let name = truncate_chars(&release.name, MAX_NAME_CHARS);
+    let tag = truncate_chars(&release.tag, MAX_TAG_CHARS);
+    match kind {
+        WebhookKind::Slack => json!({
+            "text": format!("New release for {}: {}", slack_escape(&name), slack_escape(&tag)),
+            "blocks": [{
+                "type": "section",
+                "text": {
+                    "type": "mrkdwn",
+                    "text": format!(
+                        "New release for *{}*: <{}|{}>",
+                        slack_escape(&name),
+                        release.url,
+                        slack_escape(&tag)
+                    ),
+                },
+            }],
+        }),
+        WebhookKind::Discord => json!({
+            "content": format!(
+                "New release for **{}**: {}",
+                discord_escape(&name),
+                discord_escape(&tag)
+            ),
+            "embeds": [{
+                "title": format!("{} {}", release.repository, tag),
+                "url": release.url,
+            }],
+        }),
+    }
+}
+
+/// POSTs the announcement of `release` to the chat's webhook.
+pub(crate) async fn deliver(
+    client: &reqwest::Client,
+    webhook: &ChatWebhook,
+    release: &ReleaseInfo,
+) -> Result<(), reqwest::Error> {
+    client
+        .post(&webhook.url)
+        .json(&payload(webhook.kind, release))
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+
+    fn release() -> ReleaseInfo {
+        ReleaseInfo {
+            name: "my_project".to_string(),
+            repository: "octocat/my-project".to_string(),
+            tag: "v1.2.3".to_string(),
+            url: "https://github.com/octocat/my-project/releases/tag/v1.2.3".to_string(),
+        }
+    }
+
+    fn webhook(kind: WebhookKind, server: &Server) -> ChatWebhook {
+        ChatWebhook {
+            kind,
+            url: format!("{}/hook", server.url()),
+        }
+    }
+
+    #[tokio::test]
+    async fn slack_payload_has_text_and_blocks() {
+        let mut server = Server::new_async().await;
+        let m = server
+            .mock("POST", "/hook")
+            .match_header("content-type", "application/json")
+            .match_body(Matcher::Json(json!({
+                "text": "New release for my_project: v1.2.3",
+                "blocks": [{
+                    "type": "section",
+                    "text": {
+                        "type": "mrkdwn",
+                        "text": "New release for *my_project*: \
+                                 <https://github.com/octocat/my-project/releases/tag/v1.2.3|v1.2.3>",
+                    },
+                }],
+            })))
+            .with_status(200)
+            .with_body("ok")
+            .expect(1)
+            .create_async()
+            .await;
+
+        deliver(
+            &reqwest::Client::new(),
+            &webhook(WebhookKind::Slack, &server),
+            &release(),
+        )
+        .await
+        .expect("delivered");
+        m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn discord_payload_has_content_and_embed() {
+        let mut server = Server::new_async().await;
+        let m = server
+            .mock("POST", "/hook")
+            .match_body(Matcher::Json(json!({
+                "content": "New release for **my\\_project**: v1.2.3",
+                "embeds": [{
+                    "title": "octocat/my-project v1.2.3",
+                    "url": "https://github.com/octocat/my-project/releases/tag/v1.2.3",
+                }],
+            })))
+            .with_status(204)
+            .expect(1)
+            .create_async()
+            .await;
+
+        deliver(
+            &reqwest::Client::new(),
+            &webhook(WebhookKind::Discord, &server),
+            &release(),
+        )
+        .await
+        .expect("delivered");
+        m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn rejected_delivery_is_an_error() {
+        let mut server = Server::new_async().await;
+        let _m = server
+            .mock("POST", "/hook")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let res = deliver(
+            &reqwest::Client::new(),
+            &webhook(WebhookKind::Slack, &server),
+            &release(),
+        )
+        .await;
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn slack_text_is_escaped() {
+        let mut info = release();
+        info.name = "a<b>&c".to_string();
+        let body = payload(WebhookKind::Slack, &info);
+        assert_eq!(body["text"], "New release for a&lt;b&gt;&amp;c: v1.2.3");
+    }
+}